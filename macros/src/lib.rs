@@ -245,6 +245,26 @@ pub fn rpc_id_getter(_: TokenStream, item: TokenStream) -> TokenStream {
     TokenStream::from(ast.to_token_stream())
 }
 
+/// Add `__str__` and `from_string` to the impl using the `EnumString` trait.
+#[proc_macro_attribute]
+pub fn enum_string(_: TokenStream, item: TokenStream) -> TokenStream {
+    let mut ast = parse_macro_input!(item as ItemImpl);
+    let methods = vec![
+        ImplItem::Verbatim(quote! {
+        pub fn __str__(&self) -> String {
+            solders_traits::EnumString::to_canonical_string(self)
+        }}),
+        ImplItem::Verbatim(quote! {
+        /// Parse from the string used in RPC JSON, e.g. ``"finalized"``.
+        #[staticmethod]
+        pub fn from_string(s: &str) -> pyo3::prelude::PyResult<Self> {
+            solders_traits::EnumString::from_canonical_string(s)
+        }}),
+    ];
+    ast.items.extend_from_slice(&methods);
+    TokenStream::from(ast.to_token_stream())
+}
+
 /// Add mappings to and from another enum that has the exact same fields.
 ///
 /// # Example
@@ -1,4 +1,4 @@
-use std::hash::Hasher;
+use std::{collections::HashMap, hash::Hasher};
 
 use crate::pubkey::Pubkey;
 use derive_more::{From, Into};
@@ -11,10 +11,10 @@ use solana_sdk::{
     },
     pubkey::Pubkey as PubkeyOriginal,
 };
-use solders_macros::{common_methods, pyhash, richcmp_eq_only};
+use solders_macros::{common_methods, pyhash, richcmp_eq_only, EnumIntoPy};
 
 use solders_traits::{
-    impl_display, py_from_bytes_general_via_bincode, pybytes_general_via_bincode,
+    impl_display, py_from_bytes_general_via_bincode, pybytes_general_via_bincode, to_py_value_err,
     CommonMethodsCore, PyHash, RichcmpEqualityOnly,
 };
 
@@ -256,6 +256,200 @@ impl AsRef<InstructionOriginal> for Instruction {
     }
 }
 
+/// The key of an :class:`AccountMetaTemplate`: a pubkey, or the name of a placeholder.
+#[derive(FromPyObject, Clone, Debug, PartialEq, Eq, EnumIntoPy)]
+pub enum TemplateKey {
+    Fixed(Pubkey),
+    Placeholder(String),
+}
+
+/// An :class:`AccountMeta` whose pubkey may be a placeholder filled in later.
+///
+/// Args:
+///     key (Pubkey | str): The account's pubkey, or the name of a placeholder.
+///     is_signer (bool): True if the instruction requires a signature from the account.
+///     is_writable (bool): True if the account may be mutated during program execution.
+///
+#[pyclass(module = "solders.instruction", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccountMetaTemplate {
+    /// Pubkey | str: The account's pubkey, or the name of a placeholder.
+    #[pyo3(get)]
+    pub key: TemplateKey,
+    /// bool: True if the instruction requires a signature from the account.
+    #[pyo3(get)]
+    pub is_signer: bool,
+    /// bool: True if the account may be mutated during program execution.
+    #[pyo3(get)]
+    pub is_writable: bool,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl AccountMetaTemplate {
+    #[new]
+    pub fn new(key: TemplateKey, is_signer: bool, is_writable: bool) -> Self {
+        Self {
+            key,
+            is_signer,
+            is_writable,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for AccountMetaTemplate {}
+
+/// An :class:`Instruction` with placeholder accounts, resolved into concrete instructions later.
+///
+/// Build the template once, then call :meth:`resolve` with the pubkeys for each
+/// user, e.g. their wallet and associated token account.
+///
+/// Args:
+///     program_id (Pubkey): Pubkey of the program that executes the instruction.
+///     data (bytes): Opaque data passed to the program.
+///     accounts (Sequence[AccountMetaTemplate]): The accounts, some of which may be placeholders.
+///
+/// Example:
+///     >>> from solders.pubkey import Pubkey
+///     >>> from solders.instruction import AccountMetaTemplate, InstructionTemplate
+///     >>> vault = Pubkey.new_unique()
+///     >>> accounts = [AccountMetaTemplate("user", True, True), AccountMetaTemplate(vault, False, True)]
+///     >>> template = InstructionTemplate(Pubkey.new_unique(), bytes([1]), accounts)
+///     >>> template.placeholders()
+///     ['user']
+///     >>> user = Pubkey.new_unique()
+///     >>> [meta.pubkey for meta in template.resolve({"user": user}).accounts] == [user, vault]
+///     True
+///
+#[pyclass(module = "solders.instruction", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InstructionTemplate {
+    program_id: Pubkey,
+    data: Vec<u8>,
+    accounts: Vec<AccountMetaTemplate>,
+}
+
+impl InstructionTemplate {
+    fn resolve_one(&self, placeholders: &HashMap<String, Pubkey>) -> PyResult<Instruction> {
+        let mut missing = Vec::new();
+        let mut accounts = Vec::with_capacity(self.accounts.len());
+        for meta in &self.accounts {
+            let pubkey = match &meta.key {
+                TemplateKey::Fixed(pubkey) => *pubkey,
+                TemplateKey::Placeholder(name) => match placeholders.get(name) {
+                    Some(pubkey) => *pubkey,
+                    None => {
+                        if !missing.contains(&name.as_str()) {
+                            missing.push(name.as_str());
+                        }
+                        continue;
+                    }
+                },
+            };
+            accounts.push(AccountMeta::new(&pubkey, meta.is_signer, meta.is_writable));
+        }
+        if !missing.is_empty() {
+            return Err(to_py_value_err(&format!(
+                "missing placeholders: {}",
+                missing.join(", ")
+            )));
+        }
+        Ok(Instruction::new(&self.program_id, &self.data, accounts))
+    }
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl InstructionTemplate {
+    #[new]
+    pub fn new(program_id: Pubkey, data: &[u8], accounts: Vec<AccountMetaTemplate>) -> Self {
+        Self {
+            program_id,
+            data: data.to_vec(),
+            accounts,
+        }
+    }
+
+    /// Pubkey: Pubkey of the program that executes the instruction.
+    #[getter]
+    pub fn program_id(&self) -> Pubkey {
+        self.program_id
+    }
+
+    /// bytes: Opaque data passed to the program.
+    #[getter]
+    pub fn data<'a>(&self, py: Python<'a>) -> &'a PyBytes {
+        PyBytes::new(py, &self.data)
+    }
+
+    /// list[AccountMetaTemplate]: The accounts, some of which may be placeholders.
+    #[getter]
+    pub fn accounts(&self) -> Vec<AccountMetaTemplate> {
+        self.accounts.clone()
+    }
+
+    /// The names of the placeholders in this template.
+    ///
+    /// Returns:
+    ///     list[str]: The names, in order of first appearance.
+    ///
+    pub fn placeholders(&self) -> Vec<String> {
+        let mut names: Vec<String> = Vec::new();
+        for meta in &self.accounts {
+            if let TemplateKey::Placeholder(name) = &meta.key {
+                if !names.contains(name) {
+                    names.push(name.clone());
+                }
+            }
+        }
+        names
+    }
+
+    /// Fill in the placeholders.
+    ///
+    /// Args:
+    ///     placeholders (Dict[str, Pubkey]): The pubkey for each placeholder name.
+    ///         Names the template doesn't use are ignored.
+    ///
+    /// Returns:
+    ///     Instruction: The concrete instruction.
+    ///
+    /// Raises:
+    ///     ValueError: If a placeholder has no pubkey.
+    ///
+    pub fn resolve(&self, placeholders: HashMap<String, Pubkey>) -> PyResult<Instruction> {
+        self.resolve_one(&placeholders)
+    }
+
+    /// Fill in the placeholders once per mapping, e.g. once per user.
+    ///
+    /// Args:
+    ///     placeholders (Sequence[Dict[str, Pubkey]]): One mapping per instruction.
+    ///
+    /// Returns:
+    ///     list[Instruction]: The concrete instructions, in the same order.
+    ///
+    /// Raises:
+    ///     ValueError: If a mapping lacks a placeholder.
+    ///
+    pub fn resolve_many(
+        &self,
+        placeholders: Vec<HashMap<String, Pubkey>>,
+    ) -> PyResult<Vec<Instruction>> {
+        placeholders.iter().map(|p| self.resolve_one(p)).collect()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for InstructionTemplate {}
+
 /// A compact encoding of an instruction.
 ///
 /// A ``CompiledInstruction`` is a component of a multi-instruction :class:`~solders.message.Message`,
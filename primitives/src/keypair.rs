@@ -1,21 +1,64 @@
-use crate::{pubkey::Pubkey, signature::Signature};
+use crate::{pubkey::Pubkey, signature::Signature, signer::Signer};
 use derive_more::{From, Into};
-use pyo3::{prelude::*, types::PyBytes};
+use hkdf::Hkdf;
+use hmac::{Hmac, Mac};
+use pyo3::{basic::CompareOp, prelude::*, types::PyBytes};
+use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
 use serde::{Deserialize, Serialize};
+use sha2::{Sha256, Sha512};
 use solana_sdk::signer::{
     keypair::{
         keypair_from_seed, keypair_from_seed_phrase_and_passphrase, Keypair as KeypairOriginal,
     },
     Signer as SignerTrait,
 };
-use solders_macros::{common_methods, pyhash, richcmp_signer};
+use solders_macros::{common_methods, pyhash};
+use subtle::ConstantTimeEq;
 
 use solders_traits::{
-    handle_py_value_err, impl_display, impl_signer_hash, CommonMethods, CommonMethodsCore,
-    PyBytesGeneral, PyFromBytesGeneral, PyHash, RichcmpSigner, SignerTraitWrapper,
-    ToSignerOriginal,
+    handle_py_value_err, impl_display, impl_signer_hash, to_py_value_err, CommonMethods,
+    CommonMethodsCore, PyBytesGeneral, PyFromBytesGeneral, PyHash, RichcmpSigner,
+    SignerTraitWrapper, ToSignerOriginal,
 };
 
+/// The length of a keypair seed in bytes.
+const SEED_LENGTH: usize = 32;
+
+fn hmac_sha512(key: &[u8], parts: &[&[u8]]) -> [u8; 64] {
+    let mut mac = Hmac::<Sha512>::new_from_slice(key).expect("HMAC accepts keys of any size");
+    for part in parts {
+        mac.update(part);
+    }
+    let mut out = [0u8; 64];
+    out.copy_from_slice(&mac.finalize().into_bytes());
+    out
+}
+
+/// Domain separation salt for :meth:`Keypair.derive_ephemeral`.
+const EPHEMERAL_SALT: &[u8] = b"solders ephemeral keypair";
+
+/// HKDF-SHA256 (RFC 5869) with a 32-byte output.
+fn hkdf_sha256(salt: &[u8], ikm: &[u8], info: &[u8]) -> [u8; 32] {
+    let mut okm = [0u8; 32];
+    Hkdf::<Sha256>::new(Some(salt), ikm)
+        .expand(info, &mut okm)
+        .expect("32 bytes is a valid HKDF-SHA256 output length");
+    okm
+}
+
+/// SLIP-0010 ed25519 derivation, with each path segment used verbatim
+/// in place of the 4-byte child index.
+fn derive_ed25519_secret(seed: &[u8], path: &[Vec<u8>]) -> [u8; 32] {
+    let mut node = hmac_sha512(b"ed25519 seed", &[seed]);
+    for segment in path {
+        let (key, chain_code) = node.split_at(32);
+        node = hmac_sha512(chain_code, &[&[0u8], key, segment]);
+    }
+    let mut secret = [0u8; 32];
+    secret.copy_from_slice(&node[..32]);
+    secret
+}
+
 mod keypair_serde {
     use serde::{self, Deserialize, Deserializer, Serializer};
     use solana_sdk::signer::keypair::Keypair as KeypairOriginal;
@@ -37,7 +80,7 @@ mod keypair_serde {
 }
 
 #[pyclass(module = "solders.keypair", subclass)]
-#[derive(PartialEq, Debug, Serialize, Deserialize, From, Into)]
+#[derive(Debug, Serialize, Deserialize, From, Into)]
 /// A vanilla Ed25519 key pair.
 ///
 /// Calling ``Keypair()`` creates a new, random ``Keypair``.
@@ -49,7 +92,6 @@ mod keypair_serde {
 pub struct Keypair(#[serde(with = "keypair_serde")] pub KeypairOriginal);
 
 #[pyhash]
-#[richcmp_signer]
 #[common_methods]
 #[pymethods]
 impl Keypair {
@@ -70,14 +112,75 @@ impl Keypair {
     /// Returns:
     ///     Keypair: a keypair object.
     ///
+    /// Raises:
+    ///     ValueError: If ``raw_bytes`` is not a valid 64-byte keypair. A 32-byte input
+    ///         is most likely a seed, and the error points to :meth:`from_seed`.
+    ///
     /// Example:
     ///     >>> from solders.keypair import Keypair
     ///     >>> kp = Keypair()
     ///     >>> assert kp == Keypair.from_bytes(bytes(kp))
     ///
     #[staticmethod]
-    pub fn from_bytes(raw_bytes: [u8; Self::LENGTH]) -> PyResult<Self> {
-        Self::py_from_bytes(&raw_bytes)
+    pub fn from_bytes(raw_bytes: Vec<u8>) -> PyResult<Self> {
+        match raw_bytes.len() {
+            Self::LENGTH => Self::py_from_bytes(&raw_bytes),
+            SEED_LENGTH => Err(to_py_value_err(&format!(
+                "expected a sequence of length {} (got {}). \
+                This looks like a 32-byte seed: use Keypair.from_seed instead",
+                Self::LENGTH,
+                SEED_LENGTH
+            ))),
+            len => Err(to_py_value_err(&format!(
+                "expected a sequence of length {} (got {})",
+                Self::LENGTH,
+                len
+            ))),
+        }
+    }
+
+    /// Recovers a ``Keypair`` from either a 32-byte seed or 64 keypair bytes.
+    ///
+    /// Useful when reading keys from sources that don't say which format they use.
+    ///
+    /// Args:
+    ///     raw_bytes (bytes | Sequence[int]): a 32-byte seed or a 64-byte keypair.
+    ///
+    /// Returns:
+    ///     Keypair: a keypair object.
+    ///
+    /// Raises:
+    ///     ValueError: If ``raw_bytes`` is neither 32 nor 64 bytes long,
+    ///         or is not a valid keypair.
+    ///
+    /// Example:
+    ///     >>> from solders.keypair import Keypair
+    ///     >>> kp = Keypair()
+    ///     >>> assert Keypair.from_seed_or_bytes(kp.secret()) == kp
+    ///     >>> assert Keypair.from_seed_or_bytes(bytes(kp)) == kp
+    ///
+    #[staticmethod]
+    pub fn from_seed_or_bytes(raw_bytes: Vec<u8>) -> PyResult<Self> {
+        match raw_bytes.len() {
+            Self::LENGTH => Self::py_from_bytes(&raw_bytes),
+            SEED_LENGTH => Self::from_seed(raw_bytes.try_into().unwrap()),
+            len => Err(to_py_value_err(&format!(
+                "expected a {}-byte seed or a {}-byte keypair (got {} bytes)",
+                SEED_LENGTH,
+                Self::LENGTH,
+                len
+            ))),
+        }
+    }
+
+    /// Two keypairs compare their full bytes in constant time, so ``==`` doesn't
+    /// leak how much of the secret matched. Other signers compare by pubkey.
+    fn __richcmp__(&self, other: Signer, op: CompareOp) -> PyResult<bool> {
+        match (other, op) {
+            (Signer::KeypairWrapper(other), CompareOp::Eq) => Ok(*self == other),
+            (Signer::KeypairWrapper(other), CompareOp::Ne) => Ok(*self != other),
+            (other, op) => RichcmpSigner::richcmp(self, other, op),
+        }
     }
 
     /// Returns this ``Keypair`` as a byte array.
@@ -188,6 +291,88 @@ impl Keypair {
         handle_py_value_err(keypair_from_seed(&seed))
     }
 
+    /// Generate a keypair from a ChaCha20 random number generator seeded with ``seed``.
+    ///
+    /// The same seed always gives the same keypair and different seeds give
+    /// different keypairs, so ``range(n)`` yields a reproducible set of distinct
+    /// keypairs for deterministic test suites and simulations. Unlike
+    /// :meth:`from_seed`, the seed is a small integer rather than the key material
+    /// itself. Not for production keys: the seed is easy to guess.
+    ///
+    /// Args:
+    ///     seed (int): The generator seed, from 0 to 2**64 - 1.
+    ///
+    /// Returns:
+    ///     Keypair: The generated keypair.
+    ///
+    /// Example:
+    ///     >>> from solders.keypair import Keypair
+    ///     >>> assert Keypair.new_with_rng(42) == Keypair.new_with_rng(42)
+    ///     >>> assert Keypair.new_with_rng(42) != Keypair.new_with_rng(43)
+    ///
+    #[staticmethod]
+    pub fn new_with_rng(seed: u64) -> Self {
+        KeypairOriginal::generate(&mut ChaCha20Rng::seed_from_u64(seed)).into()
+    }
+
+    /// Derive a keypair from a seed using ed25519 hierarchical derivation over arbitrary path segments.
+    ///
+    /// This follows SLIP-0010, except that each path segment is fed to the HMAC as-is
+    /// instead of as a 4-byte index. For a standard hardened index ``i``, pass
+    /// ``(i | 0x80000000).to_bytes(4, "big")``. This supports derivation trees that
+    /// don't follow BIP44.
+    ///
+    /// Args:
+    ///     seed (bytes): The master seed.
+    ///     path (Sequence[bytes]): The derivation path segments. An empty path gives the master key.
+    ///
+    /// Returns:
+    ///     Keypair: The derived keypair.
+    ///
+    /// Example:
+    ///     >>> from solders.keypair import Keypair
+    ///     >>> seed = bytes(range(16))
+    ///     >>> hardened_zero = (0 | 0x80000000).to_bytes(4, "big")
+    ///     >>> kp = Keypair.from_seed_and_derivation_bytes(seed, [hardened_zero])
+    ///     >>> kp.secret().hex()
+    ///     '68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3'
+    ///
+    #[staticmethod]
+    pub fn from_seed_and_derivation_bytes(seed: &[u8], path: Vec<Vec<u8>>) -> PyResult<Self> {
+        Self::from_seed(derive_ed25519_secret(seed, &path))
+    }
+
+    /// Derive a deterministic ephemeral keypair from a master keypair.
+    ///
+    /// The seed is HKDF-SHA256 of the master secret, with the big-endian 8-byte
+    /// ``index`` followed by ``label`` as the info. The same master, label and
+    /// index always give the same keypair, so a service can rebuild per-purpose
+    /// signing keys from one stored secret. Different labels give unrelated keys,
+    /// and the master key can't be recovered from a derived one.
+    ///
+    /// Args:
+    ///     master (Keypair): The master keypair. Keep it secret.
+    ///     label (str): What the keys are for, e.g. ``"deposits"``.
+    ///     index (int): The key number within the label.
+    ///
+    /// Returns:
+    ///     Keypair: The derived keypair.
+    ///
+    /// Example:
+    ///     >>> from solders.keypair import Keypair
+    ///     >>> master = Keypair.from_seed(bytes(32))
+    ///     >>> kp = Keypair.derive_ephemeral(master, "deposits", 0)
+    ///     >>> kp.secret().hex()
+    ///     '7ef46b859fa9c6e64c4abccd79a1dadc34dafe064540f268e8d6820d4ca1565a'
+    ///     >>> kp == Keypair.derive_ephemeral(master, "deposits", 0)
+    ///     True
+    ///
+    #[staticmethod]
+    pub fn derive_ephemeral(master: &Self, label: &str, index: u64) -> PyResult<Self> {
+        let info = [&index.to_be_bytes()[..], label.as_bytes()].concat();
+        Self::from_seed(hkdf_sha256(EPHEMERAL_SALT, master.secret(), &info))
+    }
+
     #[staticmethod]
     /// Generate a keypair from a seed phrase and passphrase.
     ///
@@ -254,6 +439,16 @@ impl Default for Keypair {
     }
 }
 
+/// Compares the full keypair bytes in constant time,
+/// so the comparison doesn't leak how much of the secret matched.
+impl PartialEq for Keypair {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_bytes()[..].ct_eq(&other.0.to_bytes()[..]).into()
+    }
+}
+
+impl Eq for Keypair {}
+
 impl AsRef<KeypairOriginal> for Keypair {
     fn as_ref(&self) -> &KeypairOriginal {
         &self.0
@@ -262,7 +457,7 @@ impl AsRef<KeypairOriginal> for Keypair {
 
 impl Clone for Keypair {
     fn clone(&self) -> Self {
-        Self::from_bytes(self.to_bytes_array()).unwrap()
+        Self::py_from_bytes(&self.to_bytes_array()).unwrap()
     }
 }
 
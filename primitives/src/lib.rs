@@ -20,6 +20,36 @@ pub fn convert_optional_pubkey(pubkey: Option<&Pubkey>) -> Option<&PubkeyOrigina
     pubkey.map(|p| p.as_ref())
 }
 
+/// Shorten a base58 string to ``prefix..suffix``, leaving short strings intact.
+pub(crate) fn short_string(s: &str, prefix_len: usize, suffix_len: usize) -> String {
+    if s.len() <= prefix_len.saturating_add(suffix_len).saturating_add(2) {
+        s.to_string()
+    } else {
+        format!("{}..{}", &s[..prefix_len], &s[s.len() - suffix_len..])
+    }
+}
+
+/// Build a Solana Explorer link. ``cluster`` is a cluster name or a custom RPC URL.
+pub(crate) fn explorer_url(path: &str, cluster: &str) -> String {
+    let base = format!("https://explorer.solana.com/{}", path);
+    match cluster {
+        "mainnet-beta" => base,
+        "devnet" | "testnet" => format!("{}?cluster={}", base, cluster),
+        custom_url => {
+            let encoded: String = custom_url
+                .bytes()
+                .map(|b| match b {
+                    b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                        (b as char).to_string()
+                    }
+                    _ => format!("%{:02X}", b),
+                })
+                .collect();
+            format!("{}?cluster=custom&customUrl={}", base, encoded)
+        }
+    }
+}
+
 pub fn convert_instructions(instructions: Vec<Instruction>) -> Vec<InstructionOriginal> {
     instructions
         .into_iter()
@@ -19,8 +19,9 @@ use solana_sdk::{
 };
 use solders_macros::{common_methods, richcmp_eq_only, EnumIntoPy};
 use solders_traits::{
-    handle_py_err, impl_display, py_from_bytes_general_via_bincode, pybytes_general_via_bincode,
-    CommonMethodsCore, PyBytesGeneral, PyErrWrapper, RichcmpEqualityOnly,
+    bincode_pybytes, handle_py_err, impl_display, py_from_bytes_general_via_bincode,
+    pybytes_general_via_bincode, to_py_value_err, CommonMethodsCore, PyBytesGeneral, PyErrWrapper,
+    RichcmpEqualityOnly,
 };
 
 use crate::{
@@ -475,6 +476,35 @@ impl Message {
         self.0.signer_keys().into_iter().map(Pubkey::from).collect()
     }
 
+    /// The signers a message compiled from these instructions will require, in signing order.
+    ///
+    /// The fee payer comes first, then writable signers, then read-only signers.
+    /// This is the order of the transaction's signatures. Keypairs passed to
+    /// :class:`~solders.transaction.VersionedTransaction` can be in any order, as each
+    /// signature is placed by its signer's pubkey.
+    ///
+    /// Args:
+    ///     instructions (Sequence[Instruction]): The instructions to compile.
+    ///     payer (Optional[Pubkey]): The fee payer.
+    ///
+    /// Returns:
+    ///     list[Pubkey]: The required signers.
+    ///
+    /// Example:
+    ///     >>> from solders.message import Message
+    ///     >>> from solders.pubkey import Pubkey
+    ///     >>> from solders.instruction import Instruction, AccountMeta
+    ///     >>> payer, reader, writer = Pubkey.new_unique(), Pubkey.new_unique(), Pubkey.new_unique()
+    ///     >>> metas = [AccountMeta(reader, True, False), AccountMeta(writer, True, True)]
+    ///     >>> ix = Instruction(Pubkey.default(), b"", metas)
+    ///     >>> Message.required_signers([ix], payer) == [payer, writer, reader]
+    ///     True
+    ///
+    #[staticmethod]
+    pub fn required_signers(instructions: Vec<Instruction>, payer: Option<&Pubkey>) -> Vec<Pubkey> {
+        Self::new(instructions, payer).signer_keys()
+    }
+
     /// Check if ``account_keys`` has any duplicate keys.
     ///
     /// Returns:
@@ -530,7 +560,7 @@ impl Message {
 impl RichcmpEqualityOnly for Message {}
 impl PyBytesGeneral for Message {
     fn pybytes_general<'a>(&self, py: Python<'a>) -> &'a PyBytes {
-        PyBytes::new(py, &self.0.serialize())
+        bincode_pybytes(py, &self.0)
     }
 }
 impl_display!(Message);
@@ -707,6 +737,90 @@ impl MessageV0 {
             .collect()
     }
 
+    /// Copy the message with different address table lookups.
+    ///
+    /// The new lookups must load the same number of writable and readonly
+    /// addresses as the old ones, so the instruction account indexes stay valid.
+    /// Signatures over the old message are not valid for the new one.
+    ///
+    /// Args:
+    ///     address_table_lookups (Sequence[MessageAddressTableLookup]): The new lookups.
+    ///
+    /// Returns:
+    ///     MessageV0: The modified message.
+    ///
+    /// Raises:
+    ///     ValueError: If the number of loaded addresses changes.
+    ///
+    pub fn with_address_table_lookups(
+        &self,
+        address_table_lookups: Vec<MessageAddressTableLookup>,
+    ) -> PyResult<Self> {
+        let counts = |lookups: &[MessageAddressTableLookupOriginal]| {
+            lookups.iter().fold((0, 0), |(w, r), l| {
+                (w + l.writable_indexes.len(), r + l.readonly_indexes.len())
+            })
+        };
+        let lookups: Vec<MessageAddressTableLookupOriginal> = address_table_lookups
+            .into_iter()
+            .map(|l| l.into())
+            .collect();
+        let (old_writable, old_readonly) = counts(&self.0.address_table_lookups);
+        let (new_writable, new_readonly) = counts(&lookups);
+        if (old_writable, old_readonly) != (new_writable, new_readonly) {
+            return Err(to_py_value_err(&format!(
+                "lookups load {new_writable} writable and {new_readonly} readonly addresses, expected {old_writable} and {old_readonly}"
+            )));
+        }
+        let mut msg = self.0.clone();
+        msg.address_table_lookups = lookups;
+        Ok(msg.into())
+    }
+
+    /// Copy the message, pointing lookups at a different address lookup table.
+    ///
+    /// The indexes are kept, so the new table must hold the same addresses
+    /// at the same positions, as when migrating to a copy of a table.
+    ///
+    /// Args:
+    ///     old (Pubkey): The table to replace.
+    ///     new (Pubkey): The replacement table.
+    ///
+    /// Returns:
+    ///     MessageV0: The modified message.
+    ///
+    /// Raises:
+    ///     ValueError: If the message doesn't use ``old``.
+    ///
+    /// Example:
+    ///     >>> from solders.message import MessageV0, MessageHeader, MessageAddressTableLookup
+    ///     >>> from solders.pubkey import Pubkey
+    ///     >>> from solders.hash import Hash
+    ///     >>> old, new = Pubkey.new_unique(), Pubkey.new_unique()
+    ///     >>> lookups = [MessageAddressTableLookup(old, bytes([0]), bytes([1]))]
+    ///     >>> msg = MessageV0(MessageHeader(1, 0, 0), [Pubkey.new_unique()], Hash.default(), [], lookups)
+    ///     >>> msg.replace_lookup_table(old, new).address_table_lookups[0].account_key == new
+    ///     True
+    ///
+    pub fn replace_lookup_table(&self, old: Pubkey, new: Pubkey) -> PyResult<Self> {
+        let mut msg = self.0.clone();
+        let mut found = false;
+        for lookup in msg
+            .address_table_lookups
+            .iter_mut()
+            .filter(|l| l.account_key == old.0)
+        {
+            lookup.account_key = new.0;
+            found = true;
+        }
+        if !found {
+            return Err(to_py_value_err(&format!(
+                "message does not use lookup table {old}"
+            )));
+        }
+        Ok(msg.into())
+    }
+
     /// Create a signable transaction message from a ``payer`` public key, ``recent_blockhash``,
     /// list of ``instructions``, and a list of ``address_lookup_table_accounts``.
     ///
@@ -768,6 +882,142 @@ impl MessageV0 {
         handle_py_err(self.0.sanitize(reject_dynamic_program_ids))
     }
 
+    /// Resolve the address table lookups and build the equivalent legacy ``Message``.
+    ///
+    /// Useful for simulating against RPC nodes that do not accept versioned transactions.
+    ///
+    /// Args:
+    ///     address_lookup_table_accounts (Sequence[AddressLookupTableAccount]): The lookup tables
+    ///         referenced by this message.
+    ///
+    /// Returns:
+    ///     Message: The legacy message, with loaded addresses as static account keys.
+    ///
+    /// Raises:
+    ///     CompileError: If a lookup table is missing or the message cannot be expressed as legacy.
+    ///
+    /// Example:
+    ///     >>> from solders.pubkey import Pubkey
+    ///     >>> from solders.instruction import Instruction, AccountMeta
+    ///     >>> from solders.message import MessageV0
+    ///     >>> from solders.address_lookup_table_account import AddressLookupTableAccount
+    ///     >>> from solders.hash import Hash
+    ///     >>> payer, program_id, looked_up = Pubkey.new_unique(), Pubkey.new_unique(), Pubkey.new_unique()
+    ///     >>> ix = Instruction(program_id, bytes([]), [AccountMeta(looked_up, False, True)])
+    ///     >>> table = AddressLookupTableAccount(key=Pubkey.new_unique(), addresses=[looked_up])
+    ///     >>> msg = MessageV0.try_compile(payer, [ix], [table], Hash.new_unique())
+    ///     >>> legacy = msg.expand_to_legacy([table])
+    ///     >>> legacy.account_keys == [payer, looked_up, program_id]
+    ///     True
+    ///
+    pub fn expand_to_legacy(
+        &self,
+        address_lookup_table_accounts: Vec<AddressLookupTableAccount>,
+    ) -> PyResult<Message> {
+        self.sanitize(false)?;
+        let msg = &self.0;
+        let tables: Vec<AddressLookupTableAccountOriginal> = address_lookup_table_accounts
+            .into_iter()
+            .map(|a| a.into())
+            .collect();
+        let mut loaded_writable: Vec<PubkeyOriginal> = Vec::new();
+        let mut loaded_readonly: Vec<PubkeyOriginal> = Vec::new();
+        for lookup in &msg.address_table_lookups {
+            let table = tables
+                .iter()
+                .find(|t| t.key == lookup.account_key)
+                .ok_or_else(|| {
+                    CompileError::new_err(format!(
+                        "Address lookup table {} was not provided",
+                        lookup.account_key
+                    ))
+                })?;
+            for (indexes, loaded) in [
+                (&lookup.writable_indexes, &mut loaded_writable),
+                (&lookup.readonly_indexes, &mut loaded_readonly),
+            ] {
+                for idx in indexes {
+                    let address = table.addresses.get(*idx as usize).ok_or_else(|| {
+                        CompileError::new_err(format!(
+                            "Index {} is out of range for address lookup table {}",
+                            idx, lookup.account_key
+                        ))
+                    })?;
+                    loaded.push(*address);
+                }
+            }
+        }
+        let num_static = msg.account_keys.len();
+        let num_writable_loaded = loaded_writable.len();
+        let static_writable_end = num_static - msg.header.num_readonly_unsigned_accounts as usize;
+        let loaded_start = num_static;
+        let readonly_loaded_start = loaded_start + num_writable_loaded;
+        let all_keys: Vec<PubkeyOriginal> = msg
+            .account_keys
+            .iter()
+            .chain(loaded_writable.iter())
+            .chain(loaded_readonly.iter())
+            .copied()
+            .collect();
+        // legacy order: static writable, loaded writable, static readonly unsigned, loaded readonly
+        let new_order: Vec<usize> = (0..static_writable_end)
+            .chain(loaded_start..readonly_loaded_start)
+            .chain(static_writable_end..num_static)
+            .chain(readonly_loaded_start..all_keys.len())
+            .collect();
+        if new_order.len() > usize::from(u8::MAX) + 1 {
+            return Err(CompileError::new_err(
+                "Too many account keys for a legacy message",
+            ));
+        }
+        let mut old_to_new = vec![0u8; new_order.len()];
+        for (new_idx, old_idx) in new_order.iter().enumerate() {
+            old_to_new[*old_idx] = new_idx as u8;
+        }
+        let account_keys: Vec<PubkeyOriginal> = new_order.iter().map(|i| all_keys[*i]).collect();
+        let mut unique_keys = account_keys.clone();
+        unique_keys.sort();
+        unique_keys.dedup();
+        if unique_keys.len() != account_keys.len() {
+            return Err(CompileError::new_err(
+                "Loaded addresses duplicate static account keys",
+            ));
+        }
+        let remap = |idx: u8| -> PyResult<u8> {
+            old_to_new.get(idx as usize).copied().ok_or_else(|| {
+                CompileError::new_err(format!("Account index {} is out of range", idx))
+            })
+        };
+        let instructions = msg
+            .instructions
+            .iter()
+            .map(|ix| {
+                Ok(CompiledInstructionOriginal {
+                    program_id_index: remap(ix.program_id_index)?,
+                    accounts: ix
+                        .accounts
+                        .iter()
+                        .map(|a| remap(*a))
+                        .collect::<PyResult<Vec<u8>>>()?,
+                    data: ix.data.clone(),
+                })
+            })
+            .collect::<PyResult<Vec<CompiledInstructionOriginal>>>()?;
+        let num_readonly_unsigned_accounts = u8::try_from(
+            msg.header.num_readonly_unsigned_accounts as usize + loaded_readonly.len(),
+        )
+        .map_err(|_| CompileError::new_err("Too many readonly accounts for a legacy message"))?;
+        Ok(MessageOriginal::new_with_compiled_instructions(
+            msg.header.num_required_signatures,
+            msg.header.num_readonly_signed_accounts,
+            num_readonly_unsigned_accounts,
+            account_keys,
+            msg.recent_blockhash,
+            instructions,
+        )
+        .into())
+    }
+
     /// Returns true if the account at the specified index is called as a program by an instruction
     pub fn is_key_called_as_program(&self, key_index: usize) -> bool {
         self.0.is_key_called_as_program(key_index)
@@ -6,10 +6,15 @@ use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::{ParsePubkeyError, Pubkey as PubkeyOriginal, PUBKEY_BYTES};
 use solders_macros::{common_methods, pyhash, richcmp_full};
 use solders_traits::{
-    handle_py_err, handle_py_value_err, pybytes_general_via_slice, CommonMethodsCore,
-    PyFromBytesGeneral, PyHash, RichcmpFull,
+    handle_py_err, handle_py_value_err, pybytes_general_via_slice, to_py_value_err,
+    CommonMethodsCore, PyFromBytesGeneral, PyHash, RichcmpFull,
 };
 
+use crate::{explorer_url, short_string};
+
+/// The length of a keypair in bytes, which is easily passed where a pubkey is expected.
+const KEYPAIR_BYTES: usize = 64;
+
 /// A public key.
 ///
 /// Args:
@@ -218,6 +223,48 @@ impl Pubkey {
         self.0.is_on_curve()
     }
 
+    /// Abbreviate the base58 string for display, e.g. ``"4vJ9..kLKi"``.
+    ///
+    /// Args:
+    ///     prefix_len (int): Number of leading characters to keep. Defaults to 4.
+    ///     suffix_len (int): Number of trailing characters to keep. Defaults to 4.
+    ///
+    /// Returns:
+    ///     str: The abbreviated string.
+    ///
+    /// Example:
+    ///     >>> from solders.pubkey import Pubkey
+    ///     >>> pubkey = Pubkey(bytes([1] * 32))
+    ///     >>> pubkey.to_short_string()
+    ///     '4vJ9..kLKi'
+    ///     >>> pubkey.to_short_string(6, 3)
+    ///     '4vJ9JU..LKi'
+    ///
+    #[pyo3(signature = (prefix_len = 4, suffix_len = 4))]
+    pub fn to_short_string(&self, prefix_len: usize, suffix_len: usize) -> String {
+        short_string(&self.to_string(), prefix_len, suffix_len)
+    }
+
+    /// Link to this address on Solana Explorer.
+    ///
+    /// Args:
+    ///     cluster (str): ``"mainnet-beta"``, ``"devnet"``, ``"testnet"`` or a custom RPC URL.
+    ///         Defaults to ``"mainnet-beta"``.
+    ///
+    /// Returns:
+    ///     str: The explorer URL.
+    ///
+    /// Example:
+    ///     >>> from solders.pubkey import Pubkey
+    ///     >>> pubkey = Pubkey(bytes([1] * 32))
+    ///     >>> pubkey.explorer_url("devnet")
+    ///     'https://explorer.solana.com/address/4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi?cluster=devnet'
+    ///
+    #[pyo3(signature = (cluster = "mainnet-beta"))]
+    pub fn explorer_url(&self, cluster: &str) -> String {
+        explorer_url(&format!("address/{}", self), cluster)
+    }
+
     #[staticmethod]
     /// Construct from ``bytes``. Equivalent to ``Pubkey.__init__`` but included for the sake of consistency.
     ///
@@ -227,6 +274,10 @@ impl Pubkey {
     /// Returns:
     ///     Pubkey: a ``Pubkey`` object.
     ///
+    /// Raises:
+    ///     ValueError: If ``raw`` is not 32 bytes long. A 64-byte input is most likely
+    ///         a keypair, and the error says so.
+    ///
     pub fn from_bytes(raw: &[u8]) -> PyResult<Self> {
         Self::py_from_bytes(raw)
     }
@@ -256,7 +307,18 @@ impl std::fmt::Display for Pubkey {
 pybytes_general_via_slice!(Pubkey);
 impl PyFromBytesGeneral for Pubkey {
     fn py_from_bytes_general(raw: &[u8]) -> PyResult<Self> {
-        Ok(PubkeyOriginal::new(raw).into())
+        match raw.len() {
+            PUBKEY_BYTES => Ok(PubkeyOriginal::new(raw).into()),
+            KEYPAIR_BYTES => Err(to_py_value_err(&format!(
+                "expected a sequence of length {} (got {}). \
+                This looks like a 64-byte keypair: use Keypair.from_bytes(raw).pubkey() instead",
+                PUBKEY_BYTES, KEYPAIR_BYTES
+            ))),
+            len => Err(to_py_value_err(&format!(
+                "expected a sequence of length {} (got {})",
+                PUBKEY_BYTES, len
+            ))),
+        }
     }
 }
 solders_traits::common_methods_default!(Pubkey);
@@ -1,6 +1,6 @@
 use std::str::FromStr;
 
-use crate::Pubkey;
+use crate::{explorer_url, short_string, Pubkey};
 use derive_more::{From, Into};
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -132,6 +132,34 @@ impl Signature {
         self.0.into()
     }
 
+    /// Abbreviate the base58 string for display.
+    ///
+    /// Args:
+    ///     prefix_len (int): Number of leading characters to keep. Defaults to 4.
+    ///     suffix_len (int): Number of trailing characters to keep. Defaults to 4.
+    ///
+    /// Returns:
+    ///     str: The abbreviated string.
+    ///
+    #[pyo3(signature = (prefix_len = 4, suffix_len = 4))]
+    pub fn to_short_string(&self, prefix_len: usize, suffix_len: usize) -> String {
+        short_string(&self.0.to_string(), prefix_len, suffix_len)
+    }
+
+    /// Link to this transaction on Solana Explorer.
+    ///
+    /// Args:
+    ///     cluster (str): ``"mainnet-beta"``, ``"devnet"``, ``"testnet"`` or a custom RPC URL.
+    ///         Defaults to ``"mainnet-beta"``.
+    ///
+    /// Returns:
+    ///     str: The explorer URL.
+    ///
+    #[pyo3(signature = (cluster = "mainnet-beta"))]
+    pub fn explorer_url(&self, cluster: &str) -> String {
+        explorer_url(&format!("tx/{}", self.0), cluster)
+    }
+
     #[staticmethod]
     /// Construct from ``bytes``. Equivalent to ``Signature.__init__`` but included for the sake of consistency.
     ///
@@ -1,8 +1,17 @@
 #![allow(deprecated)]
+use std::{fmt, str::FromStr};
+
 use derive_more::{From, Into};
-use pyo3::{prelude::*, types::PyBytes};
-use serde::{Deserialize, Serialize};
+use pyo3::{
+    basic::CompareOp,
+    prelude::*,
+    types::{PyBytes, PyString},
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
 use solana_sdk::{
+    clock::UnixTimestamp,
+    packet::PACKET_DATA_SIZE,
     pubkey::Pubkey as PubkeyOriginal,
     sanitize::Sanitize,
     signature::Signature as SignatureOriginal,
@@ -12,23 +21,39 @@ use solana_sdk::{
         VersionedTransaction as VersionedTransactionOriginal,
     },
 };
-use solders_macros::{common_methods, richcmp_eq_only, EnumIntoPy};
+use solders_macros::{common_methods, enum_string, richcmp_eq_only};
 use solders_traits::{
-    handle_py_err, impl_display, py_from_bytes_general_via_bincode, pybytes_general_via_bincode,
-    CommonMethodsCore, RichcmpEqualityOnly,
+    bincode_pybytes, handle_py_err, impl_display, py_from_bytes_general_via_bincode,
+    py_from_bytes_general_via_cbor, pybytes_general_via_bincode, pybytes_general_via_cbor,
+    richcmp_type_error, to_py_value_err, CommonMethods, CommonMethodsCore, EnumString,
+    PyBytesGeneral, RichcmpEqualityOnly,
 };
 
 use crate::{
+    address_lookup_table_account::AddressLookupTableAccount,
     convert_instructions, convert_optional_pubkey,
     hash::Hash as SolderHash,
     instruction::{CompiledInstruction, Instruction},
-    message::{Message, VersionedMessage},
+    message::{CompileError, Message, VersionedMessage},
     pubkey::Pubkey,
     signature::Signature,
     signer::Signer,
     signer::SignerVec,
 };
 
+/// Whether every signature is valid for ``message_bytes`` under the key at its position.
+fn signatures_match(
+    signatures: &[SignatureOriginal],
+    message_bytes: &[u8],
+    key: impl Fn(usize) -> Option<PubkeyOriginal>,
+) -> bool {
+    signatures.iter().enumerate().all(|(i, signature)| {
+        key(i).map_or(false, |pubkey| {
+            signature.verify(pubkey.as_ref(), message_bytes)
+        })
+    })
+}
+
 /// An atomic transaction
 ///
 /// The ``__init__`` method signs a versioned message to
@@ -37,9 +62,55 @@ use crate::{
 /// Args:
 ///     message (Message | MessageV0): The message to sign.
 ///     keypairs (Sequence[Keypair | Presigner]): The keypairs that are to sign the transaction.
-#[derive(Debug, PartialEq, Default, Eq, Clone, Serialize, Deserialize, From, Into)]
+///
+/// A transaction can also carry sender-side expiry metadata, ``last_valid_block_height``
+/// and ``created_at``. It is kept by ``to_json``, ``from_json``, pickling and
+/// :meth:`from_bytes_with_metadata`, but is not part of the wire format: ``bytes(tx)``
+/// and equality ignore it. Once signed, the transaction can be frozen with
+/// :meth:`freeze`, after which the metadata setters raise instead.
+///
+/// Example:
+///     >>> from solders.message import Message
+///     >>> from solders.keypair import Keypair
+///     >>> from solders.transaction import VersionedTransaction
+///     >>> payer = Keypair()
+///     >>> tx = VersionedTransaction(Message([], payer.pubkey()), [payer])
+///     >>> tx.last_valid_block_height = 100
+///     >>> VersionedTransaction.from_json(tx.to_json()).last_valid_block_height
+///     100
+///     >>> VersionedTransaction.from_bytes(bytes(tx)).last_valid_block_height is None
+///     True
+///     >>> tx.is_expired(101)
+///     True
+///
+#[derive(Debug, Default, Eq, Clone)]
 #[pyclass(module = "solders.transaction", subclass)]
-pub struct VersionedTransaction(VersionedTransactionOriginal);
+pub struct VersionedTransaction(VersionedTransactionOriginal, TransactionMetadata);
+
+/// Sender-side metadata that travels with a :class:`VersionedTransaction`
+/// but is never sent to the cluster.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TransactionMetadata {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    last_valid_block_height: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    created_at: Option<UnixTimestamp>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    frozen: bool,
+}
+
+impl From<VersionedTransactionOriginal> for VersionedTransaction {
+    fn from(tx: VersionedTransactionOriginal) -> Self {
+        Self(tx, TransactionMetadata::default())
+    }
+}
+
+impl From<VersionedTransaction> for VersionedTransactionOriginal {
+    fn from(tx: VersionedTransaction) -> Self {
+        tx.0
+    }
+}
 
 impl From<Transaction> for VersionedTransaction {
     fn from(t: Transaction) -> Self {
@@ -47,11 +118,91 @@ impl From<Transaction> for VersionedTransaction {
     }
 }
 
+impl PartialEq for VersionedTransaction {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Serialize for VersionedTransaction {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for VersionedTransaction {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        VersionedTransactionOriginal::deserialize(deserializer).map(Self::from)
+    }
+}
+
 impl RichcmpEqualityOnly for VersionedTransaction {}
 pybytes_general_via_bincode!(VersionedTransaction);
 py_from_bytes_general_via_bincode!(VersionedTransaction);
 impl_display!(VersionedTransaction);
-solders_traits::common_methods_default!(VersionedTransaction);
+
+impl CommonMethodsCore for VersionedTransaction {
+    fn pyreduce(&self) -> PyResult<(PyObject, PyObject)> {
+        let cloned = self.clone();
+        Python::with_gil(|py| {
+            let constructor = cloned.into_py(py).getattr(py, "from_bytes_with_metadata")?;
+            let args = (
+                self.pybytes_general(py),
+                self.1.last_valid_block_height,
+                self.1.created_at,
+                self.1.frozen,
+            );
+            Ok((constructor, args.to_object(py)))
+        })
+    }
+}
+
+// The JSON form is the wire fields with the metadata fields added alongside.
+impl CommonMethods<'_> for VersionedTransaction {
+    fn py_to_json(&self) -> String {
+        let mut value = serde_json::to_value(&self.0).unwrap();
+        if let (Value::Object(fields), Value::Object(metadata)) =
+            (&mut value, serde_json::to_value(self.1).unwrap())
+        {
+            fields.extend(metadata);
+        }
+        value.to_string()
+    }
+
+    fn py_from_json(raw: &str) -> PyResult<Self> {
+        let value: Value = serde_json::from_str(raw).map_err(|e| to_py_value_err(&e))?;
+        let tx =
+            VersionedTransactionOriginal::deserialize(&value).map_err(|e| to_py_value_err(&e))?;
+        let metadata = TransactionMetadata::deserialize(&value).map_err(|e| to_py_value_err(&e))?;
+        Self::with_metadata(tx, metadata)
+    }
+}
+
+impl VersionedTransaction {
+    fn with_metadata(
+        tx: VersionedTransactionOriginal,
+        metadata: TransactionMetadata,
+    ) -> PyResult<Self> {
+        let mut tx = Self(
+            tx,
+            TransactionMetadata {
+                frozen: false,
+                ..metadata
+            },
+        );
+        if metadata.frozen {
+            tx.freeze()?;
+        }
+        Ok(tx)
+    }
+
+    fn check_not_frozen(&self) -> PyResult<()> {
+        if self.1.frozen {
+            return Err(to_py_value_err(&"transaction is frozen"));
+        }
+        Ok(())
+    }
+}
 
 #[richcmp_eq_only]
 #[common_methods]
@@ -82,6 +233,122 @@ impl VersionedTransaction {
             .collect()
     }
 
+    /// Optional[int]: The block height after which the transaction expires, if attached.
+    #[getter]
+    pub fn last_valid_block_height(&self) -> Option<u64> {
+        self.1.last_valid_block_height
+    }
+
+    #[setter]
+    pub fn set_last_valid_block_height(
+        &mut self,
+        last_valid_block_height: Option<u64>,
+    ) -> PyResult<()> {
+        self.check_not_frozen()?;
+        self.1.last_valid_block_height = last_valid_block_height;
+        Ok(())
+    }
+
+    /// Optional[int]: When the transaction was created, as a Unix timestamp, if attached.
+    #[getter]
+    pub fn created_at(&self) -> Option<UnixTimestamp> {
+        self.1.created_at
+    }
+
+    #[setter]
+    pub fn set_created_at(&mut self, created_at: Option<UnixTimestamp>) -> PyResult<()> {
+        self.check_not_frozen()?;
+        self.1.created_at = created_at;
+        Ok(())
+    }
+
+    /// Make the transaction immutable.
+    ///
+    /// Afterwards setting ``last_valid_block_height`` or ``created_at`` raises
+    /// ``ValueError``. Copies made with ``from_json`` or pickling stay frozen, and
+    /// :meth:`from_bytes_with_metadata` takes a ``frozen`` flag.
+    ///
+    /// Raises:
+    ///     ValueError: If :meth:`signatures_match_message` is ``False``.
+    ///
+    /// Example:
+    ///     >>> from solders.message import Message
+    ///     >>> from solders.keypair import Keypair
+    ///     >>> from solders.transaction import VersionedTransaction
+    ///     >>> payer = Keypair()
+    ///     >>> tx = VersionedTransaction(Message([], payer.pubkey()), [payer])
+    ///     >>> tx.freeze()
+    ///     >>> tx.last_valid_block_height = 100
+    ///     Traceback (most recent call last):
+    ///     ...
+    ///     ValueError: transaction is frozen
+    ///
+    pub fn freeze(&mut self) -> PyResult<()> {
+        if !self.signatures_match_message() {
+            return Err(to_py_value_err(
+                &"cannot freeze a transaction whose signatures don't match its message",
+            ));
+        }
+        self.1.frozen = true;
+        Ok(())
+    }
+
+    /// bool: Whether :meth:`freeze` has been called.
+    #[getter]
+    pub fn is_frozen(&self) -> bool {
+        self.1.frozen
+    }
+
+    /// Deserialize from bytes and attach expiry metadata.
+    ///
+    /// This is how pickling restores a transaction, since ``bytes(tx)`` leaves the metadata out.
+    ///
+    /// Args:
+    ///     data (bytes): The serialized transaction.
+    ///     last_valid_block_height (Optional[int]): The block height after which the transaction expires.
+    ///     created_at (Optional[int]): When the transaction was created, as a Unix timestamp.
+    ///     frozen (bool): Whether to :meth:`freeze` the transaction.
+    ///
+    /// Returns:
+    ///     VersionedTransaction: The transaction with the metadata attached.
+    ///
+    /// Raises:
+    ///     ValueError: If ``frozen`` is true and the signatures don't match the message.
+    ///
+    #[staticmethod]
+    #[pyo3(signature = (data, last_valid_block_height = None, created_at = None, frozen = false))]
+    pub fn from_bytes_with_metadata(
+        data: &[u8],
+        last_valid_block_height: Option<u64>,
+        created_at: Option<UnixTimestamp>,
+        frozen: bool,
+    ) -> PyResult<Self> {
+        let tx = Self::py_from_bytes(data)?;
+        Self::with_metadata(
+            tx.0,
+            TransactionMetadata {
+                last_valid_block_height,
+                created_at,
+                frozen,
+            },
+        )
+    }
+
+    /// Check the attached expiry against the current block height.
+    ///
+    /// Args:
+    ///     block_height (int): The current block height.
+    ///
+    /// Returns:
+    ///     Optional[bool]: Whether the transaction has expired, or ``None`` if no
+    ///     ``last_valid_block_height`` is attached.
+    ///
+    pub fn is_expired(&self, block_height: u64) -> Option<bool> {
+        self.1
+            .last_valid_block_height
+            .map(|last_valid| block_height > last_valid)
+    }
+
     /// Create a fully-signed transaction from a message and its signatures.
     ///
     /// Args:
@@ -124,7 +391,7 @@ impl VersionedTransaction {
     /// Returns the version of the transaction.
     ///
     /// Returns:
-    ///     Legacy | int: Transaction version.
+    ///     TransactionVersion: Transaction version.
     pub fn version(&self) -> TransactionVersion {
         self.0.version().into()
     }
@@ -137,6 +404,45 @@ impl VersionedTransaction {
         self.0.clone().into_legacy_transaction().map(|t| t.into())
     }
 
+    /// Build an equivalent legacy transaction by resolving address table lookups.
+    ///
+    /// Intended for ``simulateTransaction`` against RPC providers that reject
+    /// versioned transactions. The original signatures are kept, so simulate
+    /// with ``sig_verify=False``.
+    ///
+    /// Args:
+    ///     address_lookup_table_accounts (Sequence[AddressLookupTableAccount]): The lookup tables
+    ///         referenced by the message. Ignored for legacy messages.
+    ///
+    /// Returns:
+    ///     Transaction: The expanded legacy transaction.
+    ///
+    /// Raises:
+    ///     CompileError: If the message cannot be expanded or the result exceeds the packet size.
+    ///
+    pub fn expand_to_legacy_transaction(
+        &self,
+        address_lookup_table_accounts: Vec<AddressLookupTableAccount>,
+    ) -> PyResult<Transaction> {
+        let message = match self.message() {
+            VersionedMessage::Legacy(m) => m,
+            VersionedMessage::V0(m) => m.expand_to_legacy(address_lookup_table_accounts)?,
+        };
+        let tx = TransactionOriginal {
+            signatures: self.0.signatures.clone(),
+            message: message.into(),
+        };
+        let size =
+            bincode::serialized_size(&tx).map_err(|e| CompileError::new_err(e.to_string()))?;
+        if size > PACKET_DATA_SIZE as u64 {
+            return Err(CompileError::new_err(format!(
+                "Expanded transaction is {} bytes, exceeding the {} byte limit",
+                size, PACKET_DATA_SIZE
+            )));
+        }
+        Ok(tx.into())
+    }
+
     /// Verify the transaction and hash its message
     pub fn verify_and_hash_message(&self) -> PyResult<SolderHash> {
         handle_py_err(self.0.verify_and_hash_message())
@@ -147,6 +453,20 @@ impl VersionedTransaction {
         self.0.verify_with_results()
     }
 
+    /// Check that the transaction has every required signature and that each
+    /// one is valid for the message.
+    ///
+    /// Returns:
+    ///     bool: ``False`` if a signature is missing or doesn't match the message.
+    ///
+    pub fn signatures_match_message(&self) -> bool {
+        let keys = self.0.message.static_account_keys();
+        signatures_match(&self.0.signatures, &self.0.message.serialize(), |i| {
+            keys.get(i).copied()
+        }) && self.0.signatures.len()
+            == usize::from(self.0.message.header().num_required_signatures)
+    }
+
     #[staticmethod]
     #[pyo3(name = "default")]
     /// Return a new default transaction.
@@ -159,7 +479,7 @@ impl VersionedTransaction {
 }
 
 #[pyclass(module = "solders.transaction", subclass)]
-#[derive(Debug, PartialEq, Default, Eq, Clone, Serialize, Deserialize, From, Into)]
+#[derive(Debug, Default, Eq, Clone)]
 /// An atomically-commited sequence of instructions.
 ///
 /// While :class:`~solders.instruction.Instruction`\s are the basic unit of computation in Solana,
@@ -180,6 +500,9 @@ impl VersionedTransaction {
 ///
 /// The main ``Transaction()`` constructor creates a fully-signed transaction from a ``Message``.
 ///
+/// Once signed, a transaction can be frozen with :meth:`freeze`, after which
+/// methods that would change its message or signatures raise instead.
+///
 /// Args:
 ///     from_keypairs (Sequence[Keypair | Presigner]): The keypairs that are to sign the transaction.
 ///     message (Message): The message to sign.
@@ -201,7 +524,54 @@ impl VersionedTransaction {
 ///     >>> blockhash = Hash.default()  # replace with a real blockhash
 ///     >>> tx = Transaction([payer], message, blockhash)
 ///
-pub struct Transaction(TransactionOriginal);
+pub struct Transaction(TransactionOriginal, bool);
+
+impl From<TransactionOriginal> for Transaction {
+    fn from(tx: TransactionOriginal) -> Self {
+        Self(tx, false)
+    }
+}
+
+impl From<Transaction> for TransactionOriginal {
+    fn from(tx: Transaction) -> Self {
+        tx.0
+    }
+}
+
+impl PartialEq for Transaction {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Serialize for Transaction {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Transaction {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        TransactionOriginal::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl Transaction {
+    fn with_frozen(tx: TransactionOriginal, frozen: bool) -> PyResult<Self> {
+        let mut tx = Self::from(tx);
+        if frozen {
+            tx.freeze()?;
+        }
+        Ok(tx)
+    }
+
+    fn check_not_frozen(&self) -> PyResult<()> {
+        if self.1 {
+            return Err(to_py_value_err(&"transaction is frozen"));
+        }
+        Ok(())
+    }
+}
 
 #[richcmp_eq_only]
 #[common_methods]
@@ -484,7 +854,7 @@ impl Transaction {
     ///     bytes: The serialized message data.
     ///
     pub fn message_data<'a>(&self, py: Python<'a>) -> &'a PyBytes {
-        PyBytes::new(py, &self.0.message_data())
+        bincode_pybytes(py, &self.0.message)
     }
 
     /// Sign the transaction, returning any errors.
@@ -514,6 +884,7 @@ impl Transaction {
     ///     recent_blockhash (Hash): The id of a recent ledger entry.
     ///
     pub fn sign(&mut self, keypairs: Vec<Signer>, recent_blockhash: SolderHash) -> PyResult<()> {
+        self.check_not_frozen()?;
         handle_py_err(
             self.0
                 .try_sign(&SignerVec(keypairs), recent_blockhash.into()),
@@ -555,6 +926,7 @@ impl Transaction {
         keypairs: Vec<Signer>,
         recent_blockhash: SolderHash,
     ) -> PyResult<()> {
+        self.check_not_frozen()?;
         handle_py_err(
             self.0
                 .try_partial_sign(&SignerVec(keypairs), recent_blockhash.into()),
@@ -612,6 +984,7 @@ impl Transaction {
     ///     signers (Sequence[Tuple[Pubkey, Signature]]): The replacement pubkeys and signatures.
     ///
     pub fn replace_signatures(&mut self, signers: Vec<(Pubkey, Signature)>) -> PyResult<()> {
+        self.check_not_frozen()?;
         let converted_signers: Vec<(PubkeyOriginal, SignatureOriginal)> = signers
             .into_iter()
             .map(|(pubkey, signature)| {
@@ -633,6 +1006,76 @@ impl Transaction {
         self.0.is_signed()
     }
 
+    /// Check that the transaction has every required signature and that each
+    /// one is valid for the current message.
+    ///
+    /// Returns:
+    ///     bool: ``False`` if a signature is missing or the message changed after signing.
+    ///
+    pub fn signatures_match_message(&self) -> bool {
+        signatures_match(&self.0.signatures, &self.0.message.serialize(), |i| {
+            self.0.message.account_keys.get(i).copied()
+        }) && self.0.signatures.len() == usize::from(self.0.message.header.num_required_signatures)
+    }
+
+    /// Make the transaction immutable.
+    ///
+    /// Afterwards :meth:`sign`, :meth:`partial_sign` and :meth:`replace_signatures`
+    /// raise ``ValueError``. Copies made with ``from_json`` or pickling stay frozen.
+    /// ``bytes(tx)`` is the wire format, which has no room for the flag, so use
+    /// :meth:`from_bytes_frozen` to get a frozen copy back from it.
+    ///
+    /// Raises:
+    ///     ValueError: If :meth:`signatures_match_message` is ``False``.
+    ///
+    /// Example:
+    ///     >>> from solders.message import Message
+    ///     >>> from solders.keypair import Keypair
+    ///     >>> from solders.hash import Hash
+    ///     >>> from solders.transaction import Transaction
+    ///     >>> payer = Keypair()
+    ///     >>> tx = Transaction([payer], Message([], payer.pubkey()), Hash.default())
+    ///     >>> tx.freeze()
+    ///     >>> tx.sign([payer], Hash.new_unique())
+    ///     Traceback (most recent call last):
+    ///     ...
+    ///     ValueError: transaction is frozen
+    ///
+    pub fn freeze(&mut self) -> PyResult<()> {
+        if !self.signatures_match_message() {
+            return Err(to_py_value_err(
+                &"cannot freeze a transaction whose signatures don't match its message",
+            ));
+        }
+        self.1 = true;
+        Ok(())
+    }
+
+    /// bool: Whether :meth:`freeze` has been called.
+    #[getter]
+    pub fn is_frozen(&self) -> bool {
+        self.1
+    }
+
+    /// Deserialize a transaction from its wire bytes, optionally freezing it.
+    ///
+    /// Args:
+    ///     data (bytes): The serialized transaction.
+    ///     frozen (bool): Whether to :meth:`freeze` the transaction.
+    ///
+    /// Returns:
+    ///     Transaction: The deserialized transaction.
+    ///
+    /// Raises:
+    ///     ValueError: If ``frozen`` is true and the signatures don't match the message.
+    ///
+    #[staticmethod]
+    #[pyo3(signature = (data, frozen = true))]
+    pub fn from_bytes_frozen(data: &[u8], frozen: bool) -> PyResult<Self> {
+        let tx = Self::py_from_bytes(data)?;
+        Self::with_frozen(tx.0, frozen)
+    }
+
     /// See https://docs.rs/solana-sdk/latest/solana_sdk/transaction/fn.uses_durable_nonce.html
     pub fn uses_durable_nonce(&self) -> Option<CompiledInstruction> {
         uses_durable_nonce(&self.0).map(|x| CompiledInstruction::from(x.clone()))
@@ -681,7 +1124,41 @@ impl RichcmpEqualityOnly for Transaction {}
 pybytes_general_via_bincode!(Transaction);
 py_from_bytes_general_via_bincode!(Transaction);
 impl_display!(Transaction);
-solders_traits::common_methods_default!(Transaction);
+
+impl CommonMethodsCore for Transaction {
+    fn pyreduce(&self) -> PyResult<(PyObject, PyObject)> {
+        let cloned = self.clone();
+        Python::with_gil(|py| {
+            let constructor = cloned.into_py(py).getattr(py, "from_bytes_frozen")?;
+            let args = (self.pybytes_general(py), self.1);
+            Ok((constructor, args.to_object(py)))
+        })
+    }
+}
+
+// The JSON form is the wire fields, plus ``"frozen": true`` for frozen transactions.
+impl CommonMethods<'_> for Transaction {
+    fn py_to_json(&self) -> String {
+        if !self.1 {
+            return serde_json::to_string(&self.0).unwrap();
+        }
+        let mut value = serde_json::to_value(&self.0).unwrap();
+        if let Value::Object(fields) = &mut value {
+            fields.insert("frozen".to_owned(), Value::Bool(true));
+        }
+        value.to_string()
+    }
+
+    fn py_from_json(raw: &str) -> PyResult<Self> {
+        let value: Value = serde_json::from_str(raw).map_err(|e| to_py_value_err(&e))?;
+        let tx = TransactionOriginal::deserialize(&value).map_err(|e| to_py_value_err(&e))?;
+        let frozen = value
+            .get("frozen")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+        Self::with_frozen(tx, frozen)
+    }
+}
 
 impl AsRef<TransactionOriginal> for Transaction {
     fn as_ref(&self) -> &TransactionOriginal {
@@ -697,6 +1174,12 @@ pub enum Legacy {
     Legacy,
 }
 
+impl EnumString for Legacy {}
+
+#[enum_string]
+#[pymethods]
+impl Legacy {}
+
 impl From<Legacy> for LegacyOriginal {
     fn from(x: Legacy) -> Self {
         match x {
@@ -713,27 +1196,148 @@ impl From<LegacyOriginal> for Legacy {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, FromPyObject, EnumIntoPy)]
-#[serde(rename_all = "camelCase", untagged)]
-pub enum TransactionVersion {
+/// A transaction version: legacy or a version number.
+///
+/// Compares equal to ``Legacy.Legacy`` and to the way RPC writes a version,
+/// ``"legacy"`` for legacy transactions and the version number otherwise, and
+/// hashes like the latter. Parameters that take a version also accept strings of
+/// version numbers, so code written against the old ``Union[Legacy, int]`` alias
+/// keeps working.
+///
+/// Args:
+///     version (TransactionVersion | Legacy | int | str): ``Legacy.Legacy``, ``"legacy"`` or a version number.
+///
+/// Raises:
+///     ValueError: If ``version`` is a string other than ``"legacy"`` or a number.
+///
+/// Example:
+///     >>> from solders.transaction import Legacy, TransactionVersion
+///     >>> version = TransactionVersion("legacy")
+///     >>> version == Legacy.Legacy, version == "legacy", version.is_legacy
+///     (True, True, True)
+///     >>> TransactionVersion(0) == 0, TransactionVersion.V0.to_json()
+///     (True, '0')
+///     >>> TransactionVersion.from_json('"legacy"') == TransactionVersion.LEGACY
+///     True
+///
+#[pyclass(module = "solders.transaction", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, From, Into)]
+#[serde(transparent)]
+pub struct TransactionVersion(TransactionVersionOriginal);
+
+/// The ways a transaction version can be written in Python.
+#[derive(FromPyObject, Clone, Debug, PartialEq, Eq)]
+pub enum TransactionVersionLike {
+    Version(TransactionVersion),
     Legacy(Legacy),
     Number(u8),
+    String(String),
 }
 
-impl From<TransactionVersion> for TransactionVersionOriginal {
-    fn from(v: TransactionVersion) -> Self {
+impl TryFrom<TransactionVersionLike> for TransactionVersion {
+    type Error = PyErr;
+
+    fn try_from(v: TransactionVersionLike) -> Result<Self, Self::Error> {
         match v {
-            TransactionVersion::Legacy(x) => Self::Legacy(x.into()),
-            TransactionVersion::Number(n) => Self::Number(n),
+            TransactionVersionLike::Version(v) => Ok(v),
+            TransactionVersionLike::Legacy(x) => {
+                Ok(Self(TransactionVersionOriginal::Legacy(x.into())))
+            }
+            TransactionVersionLike::Number(n) => Ok(Self(TransactionVersionOriginal::Number(n))),
+            TransactionVersionLike::String(s) => s.parse().map_err(|e| to_py_value_err(&e)),
         }
     }
 }
 
-impl From<TransactionVersionOriginal> for TransactionVersion {
-    fn from(v: TransactionVersionOriginal) -> Self {
-        match v {
-            TransactionVersionOriginal::Legacy(x) => Self::Legacy(x.into()),
-            TransactionVersionOriginal::Number(n) => Self::Number(n),
+#[common_methods]
+#[pymethods]
+impl TransactionVersion {
+    #[new]
+    pub fn new(version: TransactionVersionLike) -> PyResult<Self> {
+        version.try_into()
+    }
+
+    /// TransactionVersion: The legacy version.
+    #[classattr]
+    #[pyo3(name = "LEGACY")]
+    pub fn legacy() -> Self {
+        Self(TransactionVersionOriginal::Legacy(LegacyOriginal::Legacy))
+    }
+
+    /// TransactionVersion: Version 0.
+    #[classattr]
+    #[pyo3(name = "V0")]
+    pub fn v0() -> Self {
+        Self(TransactionVersionOriginal::Number(0))
+    }
+
+    /// bool: Whether this is the legacy version.
+    #[getter]
+    pub fn is_legacy(&self) -> bool {
+        self.number().is_none()
+    }
+
+    /// Optional[int]: The version number, or ``None`` for legacy.
+    #[getter]
+    pub fn number(&self) -> Option<u8> {
+        match self.0 {
+            TransactionVersionOriginal::Legacy(_) => None,
+            TransactionVersionOriginal::Number(n) => Some(n),
+        }
+    }
+
+    fn __richcmp__(&self, other: TransactionVersionLike, op: CompareOp) -> PyResult<bool> {
+        // Only values whose hash ``__hash__`` can match compare equal.
+        let eq_val = match other {
+            TransactionVersionLike::Version(other) => *self == other,
+            TransactionVersionLike::Number(n) => self.number() == Some(n),
+            TransactionVersionLike::String(s) => self.is_legacy() && s == "legacy",
+            TransactionVersionLike::Legacy(_) => self.is_legacy(),
+        };
+        match op {
+            CompareOp::Eq => Ok(eq_val),
+            CompareOp::Ne => Ok(!eq_val),
+            CompareOp::Lt => Err(richcmp_type_error("<")),
+            CompareOp::Gt => Err(richcmp_type_error(">")),
+            CompareOp::Le => Err(richcmp_type_error("<=")),
+            CompareOp::Ge => Err(richcmp_type_error(">=")),
+        }
+    }
+
+    /// Hashes like ``"legacy"`` or the version number, matching ``__eq__``.
+    fn __hash__(&self, py: Python<'_>) -> PyResult<isize> {
+        match self.number() {
+            Some(n) => Ok(n as isize),
+            None => PyString::new(py, "legacy").hash(),
+        }
+    }
+}
+
+pybytes_general_via_cbor!(TransactionVersion);
+py_from_bytes_general_via_cbor!(TransactionVersion);
+solders_traits::common_methods_default!(TransactionVersion);
+
+impl fmt::Display for TransactionVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.number() {
+            None => write!(f, "legacy"),
+            Some(n) => write!(f, "{n}"),
+        }
+    }
+}
+
+impl FromStr for TransactionVersion {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "legacy" => Ok(Self(TransactionVersionOriginal::Legacy(
+                LegacyOriginal::Legacy,
+            ))),
+            _ => s
+                .parse::<u8>()
+                .map(|n| Self(TransactionVersionOriginal::Number(n)))
+                .map_err(|_| format!("Invalid transaction version: {s}")),
         }
     }
 }
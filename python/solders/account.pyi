@@ -1,4 +1,5 @@
-from typing import Sequence, Union, List
+from typing import Optional, Sequence, Union, List
+from solders.hash import Hash
 from solders.pubkey import Pubkey
 from solders.account_decoder import ParsedAccount
 
@@ -32,6 +33,7 @@ class Account:
     def executable(self) -> bool: ...
     @property
     def rent_epoch(self) -> int: ...
+    def data_slice(self, offset: int, length: int) -> bytes: ...
 
 class AccountJSON:
     def __init__(
@@ -61,3 +63,58 @@ class AccountJSON:
     def executable(self) -> bool: ...
     @property
     def rent_epoch(self) -> int: ...
+
+def validate_token_account(
+    account: Account,
+    expected_mint: Optional[Pubkey] = None,
+    expected_owner: Optional[Pubkey] = None,
+) -> None: ...
+def validate_mint_account(account: Account) -> None: ...
+def validate_program_account(account: Account) -> Optional[Pubkey]: ...
+def program_data_elf(account: Account) -> bytes: ...
+def executable_hash(elf: bytes) -> str: ...
+def program_data_hash(account: Account) -> str: ...
+
+class ProgramDataDiff:
+    def __init__(
+        self,
+        before_slot: int,
+        after_slot: int,
+        before_authority: Optional[Pubkey],
+        after_authority: Optional[Pubkey],
+        before_size: int,
+        after_size: int,
+        before_hash: Hash,
+        after_hash: Hash,
+    ) -> None: ...
+    @property
+    def before_slot(self) -> int: ...
+    @property
+    def after_slot(self) -> int: ...
+    @property
+    def before_authority(self) -> Optional[Pubkey]: ...
+    @property
+    def after_authority(self) -> Optional[Pubkey]: ...
+    @property
+    def before_size(self) -> int: ...
+    @property
+    def after_size(self) -> int: ...
+    @property
+    def before_hash(self) -> Hash: ...
+    @property
+    def after_hash(self) -> Hash: ...
+    @property
+    def redeployed(self) -> bool: ...
+    @property
+    def code_changed(self) -> bool: ...
+    @property
+    def authority_changed(self) -> bool: ...
+    @property
+    def made_immutable(self) -> bool: ...
+    @property
+    def has_changes(self) -> bool: ...
+    def summary(self) -> List[str]: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+def diff_program_data(before: Account, after: Account) -> ProgramDataDiff: ...
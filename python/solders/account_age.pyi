@@ -0,0 +1,44 @@
+from typing import Optional
+from solders.commitment_config import CommitmentLevel
+from solders.pubkey import Pubkey
+from solders.rpc.requests import GetSignaturesForAddress
+from solders.rpc.responses import (
+    GetSignaturesForAddressResp,
+    RpcConfirmedTransactionStatusWithSignature,
+)
+
+RENT_EXEMPT_RENT_EPOCH: int
+
+class RentEpochKind:
+    Exempt: "RentEpochKind"
+    Unset: "RentEpochKind"
+    Scheduled: "RentEpochKind"
+    def __int__(self) -> int: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_string(s: str) -> "RentEpochKind": ...
+
+def rent_epoch_kind(rent_epoch: int) -> RentEpochKind: ...
+def latest_creation_epoch(rent_epoch: int) -> Optional[int]: ...
+
+class CreationSlotSearch:
+    def __init__(
+        self,
+        address: Pubkey,
+        limit: int = 1000,
+        commitment: Optional[CommitmentLevel] = None,
+    ) -> None: ...
+    @property
+    def address(self) -> Pubkey: ...
+    @property
+    def is_done(self) -> bool: ...
+    @property
+    def oldest(self) -> Optional[RpcConfirmedTransactionStatusWithSignature]: ...
+    @property
+    def creation_slot(self) -> Optional[int]: ...
+    def next_request(self, id: int = 0) -> Optional[GetSignaturesForAddress]: ...
+    def handle_response(self, response: GetSignaturesForAddressResp) -> None: ...
+    def age_slots(self, current_slot: int) -> Optional[int]: ...
+    def __repr__(self) -> str: ...
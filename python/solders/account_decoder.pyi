@@ -1,5 +1,8 @@
-from typing import Optional, Dict, List
+from typing import Optional, Dict, List, Union
 from jsonalias import Json
+from solders.clock import Clock
+from solders.epoch_schedule import EpochSchedule
+from solders.rent import Rent
 
 class UiDataSliceConfig:
     def __init__(self, offset: int, length: int) -> None: ...
@@ -20,6 +23,8 @@ class UiAccountEncoding:
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
     def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_string(s: str) -> "UiAccountEncoding": ...
 
 class ParsedAccount:
     def __init__(self, program: str, parsed: Dict[str, Json], space: int) -> None: ...
@@ -38,6 +43,7 @@ class ParsedAccount:
     def parsed(self) -> Dict[str, Json]: ...
     @property
     def space(self) -> int: ...
+    def to_sysvar(self) -> Union[Clock, Rent, EpochSchedule]: ...
 
 class UiTokenAmount:
     def __init__(
@@ -0,0 +1,25 @@
+from typing import Dict, List, Optional
+from solders.account import Account
+from solders.pubkey import Pubkey
+
+class AccountsMap:
+    def __init__(self, accounts: Optional[Dict[Pubkey, Account]] = None) -> None: ...
+    def get(self, pubkey: Pubkey) -> Optional[Account]: ...
+    def set(self, pubkey: Pubkey, account: Account) -> None: ...
+    def delete(self, pubkey: Pubkey) -> bool: ...
+    def keys(self) -> List[Pubkey]: ...
+    def to_dict(self) -> Dict[Pubkey, Account]: ...
+    def base(self) -> Dict[Pubkey, Account]: ...
+    def pending_writes(self) -> Dict[Pubkey, Optional[Account]]: ...
+    @property
+    def is_dirty(self) -> bool: ...
+    def commit(self) -> None: ...
+    def rollback(self) -> None: ...
+    def fork(self) -> "AccountsMap": ...
+    def __getitem__(self, pubkey: Pubkey) -> Account: ...
+    def __setitem__(self, pubkey: Pubkey, account: Account) -> None: ...
+    def __delitem__(self, pubkey: Pubkey) -> None: ...
+    def __contains__(self, pubkey: Pubkey) -> bool: ...
+    def __len__(self) -> int: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
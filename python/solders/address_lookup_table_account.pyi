@@ -1,4 +1,5 @@
-from typing import Sequence, List
+from typing import Dict, Sequence, List, Optional, Tuple
+from solders.account import Account
 from solders.pubkey import Pubkey
 
 class AddressLookupTableAccount:
@@ -20,3 +21,56 @@ class AddressLookupTableAccount:
     def key(self) -> Pubkey: ...
     @property
     def addresses(self) -> List[Pubkey]: ...
+
+class LookupTableAction:
+    Keep: "LookupTableAction"
+    Deactivate: "LookupTableAction"
+    Wait: "LookupTableAction"
+    Close: "LookupTableAction"
+    def __int__(self) -> int: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_string(s: str) -> "LookupTableAction": ...
+
+class LookupTableAdvice:
+    def __init__(
+        self,
+        key: Pubkey,
+        action: LookupTableAction,
+        lamports: int,
+        num_addresses: int,
+        authority: Optional[Pubkey],
+        last_used_slot: int,
+        deactivation_slot: Optional[int],
+        earliest_closable_slot: Optional[int],
+    ) -> None: ...
+    @property
+    def key(self) -> Pubkey: ...
+    @property
+    def action(self) -> LookupTableAction: ...
+    @property
+    def lamports(self) -> int: ...
+    @property
+    def num_addresses(self) -> int: ...
+    @property
+    def authority(self) -> Optional[Pubkey]: ...
+    @property
+    def last_used_slot(self) -> int: ...
+    @property
+    def deactivation_slot(self) -> Optional[int]: ...
+    @property
+    def earliest_closable_slot(self) -> Optional[int]: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+def lookup_table_earliest_closable_slot(deactivation_slot: int) -> int: ...
+def advise_lookup_table_cleanup(
+    tables: Sequence[Tuple[Pubkey, Account]],
+    last_used: Dict[Pubkey, int],
+    current_slot: int,
+    idle_slots: int = 432000,
+    authority: Optional[Pubkey] = None,
+    slot_hashes: Optional[Sequence[int]] = None,
+) -> List[LookupTableAdvice]: ...
@@ -0,0 +1,81 @@
+from typing import List, Optional, Sequence
+
+from solders.pubkey import Pubkey
+from solders.signature import Signature
+from solders.squads import Proposal
+
+class ApprovalStatus:
+    Pending: "ApprovalStatus"
+    Approved: "ApprovalStatus"
+    Rejected: "ApprovalStatus"
+    Executed: "ApprovalStatus"
+    Cancelled: "ApprovalStatus"
+    def __int__(self) -> int: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_string(s: str) -> "ApprovalStatus": ...
+
+class ApprovalVote:
+    def __init__(
+        self, member: Pubkey, approve: bool, signature: Optional[Signature]
+    ) -> None: ...
+    @property
+    def member(self) -> Pubkey: ...
+    @property
+    def approve(self) -> bool: ...
+    @property
+    def signature(self) -> Optional[Signature]: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+class ApprovalProposal:
+    @property
+    def id(self) -> str: ...
+    @property
+    def payload(self) -> bytes: ...
+    @property
+    def status(self) -> ApprovalStatus: ...
+    @property
+    def votes(self) -> List[ApprovalVote]: ...
+    @property
+    def approvals(self) -> List[Pubkey]: ...
+    @property
+    def rejections(self) -> List[Pubkey]: ...
+    @property
+    def execution_signature(self) -> Optional[Signature]: ...
+    @property
+    def is_ready(self) -> bool: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+class ThresholdApproval:
+    def __init__(self, members: Sequence[Pubkey], threshold: int) -> None: ...
+    @property
+    def members(self) -> List[Pubkey]: ...
+    @property
+    def threshold(self) -> int: ...
+    @property
+    def proposals(self) -> List[ApprovalProposal]: ...
+    def propose(self, id: str, payload: bytes) -> bool: ...
+    def proposal(self, id: str) -> Optional[ApprovalProposal]: ...
+    def approve(
+        self, id: str, member: Pubkey, signature: Signature
+    ) -> ApprovalStatus: ...
+    def record_vote(self, id: str, member: Pubkey, approve: bool) -> ApprovalStatus: ...
+    def sync_squads(self, id: str, proposal: Proposal) -> ApprovalStatus: ...
+    def mark_executed(self, id: str, signature: Optional[Signature] = None) -> None: ...
+    def cancel(self, id: str) -> None: ...
+    def missing(self, id: str) -> List[Pubkey]: ...
+    def ready(self) -> List[str]: ...
+    def __len__(self) -> int: ...
+    def __bytes__(self) -> bytes: ...
+    @staticmethod
+    def from_bytes(data: bytes) -> "ThresholdApproval": ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(raw: str) -> "ThresholdApproval": ...
+    def __richcmp__(self, other: "ThresholdApproval", op: int) -> bool: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
@@ -0,0 +1,62 @@
+from typing import List, Optional, Sequence
+from solders.address_lookup_table_account import AddressLookupTableAccount
+from solders.pubkey import Pubkey
+from solders.transaction import VersionedTransaction
+
+class PrivilegeFlag:
+    UnknownProgram: "PrivilegeFlag"
+    SignerToUnknownProgram: "PrivilegeFlag"
+    WritableSignerToUnknownProgram: "PrivilegeFlag"
+    SystemAssign: "PrivilegeFlag"
+    TokenApprove: "PrivilegeFlag"
+    TokenSetAuthority: "PrivilegeFlag"
+    def __int__(self) -> int: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_string(s: str) -> "PrivilegeFlag": ...
+
+class AccountPrivilege:
+    def __init__(self, pubkey: Pubkey, is_signer: bool, is_writable: bool) -> None: ...
+    @property
+    def pubkey(self) -> Pubkey: ...
+    @property
+    def is_signer(self) -> bool: ...
+    @property
+    def is_writable(self) -> bool: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+class InstructionAudit:
+    def __init__(
+        self,
+        index: int,
+        program_id: Pubkey,
+        accounts: Sequence[AccountPrivilege],
+        flags: Sequence[PrivilegeFlag],
+    ) -> None: ...
+    @property
+    def index(self) -> int: ...
+    @property
+    def program_id(self) -> Pubkey: ...
+    @property
+    def accounts(self) -> List[AccountPrivilege]: ...
+    @property
+    def flags(self) -> List[PrivilegeFlag]: ...
+    @property
+    def signers(self) -> List[Pubkey]: ...
+    @property
+    def writable(self) -> List[Pubkey]: ...
+    @property
+    def is_suspicious(self) -> bool: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+def audit_privileges(
+    tx: VersionedTransaction,
+    trusted_programs: Optional[Sequence[Pubkey]] = None,
+    address_lookup_table_accounts: Optional[
+        Sequence[AddressLookupTableAccount]
+    ] = None,
+) -> List[InstructionAudit]: ...
@@ -0,0 +1,17 @@
+from typing import Dict, Optional
+from solders.account import Account
+from solders.hash import Hash
+from solders.pubkey import Pubkey
+
+MERKLE_FANOUT: int
+
+def account_hash(pubkey: Pubkey, account: Account) -> Hash: ...
+def accounts_delta_hash(accounts: Dict[Pubkey, Account]) -> Hash: ...
+def bank_hash(
+    parent_hash: Hash,
+    accounts_delta_hash: Hash,
+    signature_count: int,
+    last_blockhash: Hash,
+    epoch_accounts_hash: Optional[Hash] = None,
+    hard_fork_count: Optional[int] = None,
+) -> Hash: ...
@@ -0,0 +1,56 @@
+from typing import List, Optional, Sequence
+
+DEFAULT_BATCH_SIZE: int
+MAX_THREADS: int
+
+class BenchOperation:
+    Serialize: "BenchOperation"
+    Deserialize: "BenchOperation"
+    Sign: "BenchOperation"
+    Verify: "BenchOperation"
+    def __int__(self) -> int: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_string(s: str) -> "BenchOperation": ...
+
+class BenchResult:
+    def __init__(
+        self,
+        operation: BenchOperation,
+        batch_size: int,
+        threads: int,
+        rounds: int,
+        seconds: float,
+    ) -> None: ...
+    @property
+    def operation(self) -> BenchOperation: ...
+    @property
+    def batch_size(self) -> int: ...
+    @property
+    def threads(self) -> int: ...
+    @property
+    def rounds(self) -> int: ...
+    @property
+    def seconds(self) -> float: ...
+    @property
+    def operations(self) -> int: ...
+    @property
+    def ops_per_second(self) -> float: ...
+    def speedup(self, baseline: "BenchResult") -> float: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+def run_benchmark(
+    operation: BenchOperation,
+    batch_size: int = 1000,
+    threads: int = 1,
+    rounds: int = 1,
+) -> BenchResult: ...
+def run_suite(
+    batch_sizes: Sequence[int],
+    threads: Sequence[int] = [1],
+    operations: Optional[Sequence[BenchOperation]] = None,
+    rounds: int = 1,
+) -> List[BenchResult]: ...
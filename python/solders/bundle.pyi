@@ -0,0 +1,25 @@
+from typing import List, Optional, Sequence, Tuple
+from solders.instruction import Instruction
+from solders.message import Message
+from solders.pubkey import Pubkey
+
+class PlannedTransaction:
+    def __init__(
+        self, message: Message, instruction_indexes: Sequence[int], stage: int
+    ) -> None: ...
+    @property
+    def message(self) -> Message: ...
+    @property
+    def instruction_indexes(self) -> List[int]: ...
+    @property
+    def stage(self) -> int: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+def plan_transactions(
+    instructions: Sequence[Instruction],
+    payer: Pubkey,
+    dependencies: Optional[Sequence[Tuple[int, int]]] = None,
+    max_accounts: int = 64,
+    jito_bundle: bool = False,
+) -> List[PlannedTransaction]: ...
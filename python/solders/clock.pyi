@@ -0,0 +1,30 @@
+class Clock:
+    def __init__(
+        self,
+        slot: int,
+        epoch_start_timestamp: int,
+        epoch: int,
+        leader_schedule_epoch: int,
+        unix_timestamp: int,
+    ) -> None: ...
+    @property
+    def slot(self) -> int: ...
+    @property
+    def epoch_start_timestamp(self) -> int: ...
+    @property
+    def epoch(self) -> int: ...
+    @property
+    def leader_schedule_epoch(self) -> int: ...
+    @property
+    def unix_timestamp(self) -> int: ...
+    @staticmethod
+    def default() -> "Clock": ...
+    def __bytes__(self) -> bytes: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __richcmp__(self, other: "Clock", op: int) -> bool: ...
+    @staticmethod
+    def from_bytes(raw_bytes: bytes) -> "Clock": ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(raw: str) -> "Clock": ...
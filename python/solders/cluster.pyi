@@ -0,0 +1,29 @@
+from typing import Optional, Union
+from solders.hash import Hash
+from solders.rpc.responses import GetGenesisHashResp
+
+MAINNET_BETA_GENESIS_HASH: str
+TESTNET_GENESIS_HASH: str
+DEVNET_GENESIS_HASH: str
+
+class Cluster:
+    MainnetBeta: "Cluster"
+    Testnet: "Cluster"
+    Devnet: "Cluster"
+    Localnet: "Cluster"
+    def __int__(self) -> int: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_string(s: str) -> "Cluster": ...
+    @property
+    def genesis_hash(self) -> Optional[Hash]: ...
+    @property
+    def rpc_url(self) -> str: ...
+    @property
+    def ws_url(self) -> str: ...
+
+def detect_cluster(
+    genesis_hash: Union[Hash, GetGenesisHashResp]
+) -> Optional[Cluster]: ...
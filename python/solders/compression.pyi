@@ -0,0 +1,28 @@
+from typing import Dict, Optional, Sequence
+from solders.account import Account
+from solders.pubkey import Pubkey
+
+DEFAULT_COMPRESSION_LEVEL: int
+DEFAULT_MAX_DECOMPRESSED_SIZE: int
+
+def compress(
+    data: bytes, level: int = 3, dictionary: Optional[bytes] = None
+) -> bytes: ...
+def decompress(
+    data: bytes, dictionary: Optional[bytes] = None, max_size: int = 134217728
+) -> bytes: ...
+def train_dictionary(samples: Sequence[bytes], max_size: int = 112640) -> bytes: ...
+def compress_account(
+    account: Account, level: int = 3, dictionary: Optional[bytes] = None
+) -> bytes: ...
+def decompress_account(
+    data: bytes, dictionary: Optional[bytes] = None, max_size: int = 134217728
+) -> Account: ...
+def compress_accounts(
+    accounts: Dict[Pubkey, Account],
+    level: int = 3,
+    dictionary: Optional[bytes] = None,
+) -> bytes: ...
+def decompress_accounts(
+    data: bytes, dictionary: Optional[bytes] = None, max_size: int = 134217728
+) -> Dict[Pubkey, Account]: ...
@@ -0,0 +1,25 @@
+from typing import Optional, Sequence, Union
+from solders.message import Message, MessageV0
+from solders.rpc.responses import RpcPrioritizationFee
+
+class ComputeBudgetLimits:
+    def __init__(
+        self,
+        compute_unit_limit: int,
+        compute_unit_price: int,
+        heap_size: Optional[int] = None,
+    ) -> None: ...
+    @property
+    def compute_unit_limit(self) -> int: ...
+    @property
+    def compute_unit_price(self) -> int: ...
+    @property
+    def heap_size(self) -> Optional[int]: ...
+    def prioritization_fee(self) -> int: ...
+    def __richcmp__(self, other: "ComputeBudgetLimits", op: int) -> bool: ...
+    def __repr__(self) -> str: ...
+
+def compute_budget_limits(message: Union[Message, MessageV0]) -> ComputeBudgetLimits: ...
+def estimate_compute_unit_price(
+    fees: Sequence[RpcPrioritizationFee], percentile: float = 50.0
+) -> int: ...
@@ -0,0 +1,32 @@
+from typing import Callable, Dict, List, Optional, Sequence, Union
+from solders.address_lookup_table_account import AddressLookupTableAccount
+from solders.instruction import Instruction
+from solders.message import Message, MessageV0
+from solders.pubkey import Pubkey
+
+class CpiNode:
+    def __init__(
+        self,
+        instruction: Instruction,
+        stack_height: int,
+        children: Sequence["CpiNode"],
+        resolved: bool,
+    ) -> None: ...
+    @property
+    def instruction(self) -> Instruction: ...
+    @property
+    def stack_height(self) -> int: ...
+    @property
+    def children(self) -> List["CpiNode"]: ...
+    @property
+    def resolved(self) -> bool: ...
+    def program_ids(self) -> List[Pubkey]: ...
+    def is_fully_resolved(self) -> bool: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+def preview_cpi(
+    message: Union[Message, MessageV0],
+    parsers: Dict[Pubkey, Callable[[Instruction], Sequence[Instruction]]],
+    address_lookup_table_accounts: Optional[Sequence[AddressLookupTableAccount]] = None,
+) -> List[CpiNode]: ...
@@ -0,0 +1,17 @@
+from typing import Optional
+from solders.keypair import Keypair
+from solders.pubkey import Pubkey
+from solders.transaction import VersionedTransaction
+from solders.transaction_status import UiTransactionStatusMeta
+
+def keypair_to_json(keypair: Keypair) -> str: ...
+def keypair_from_json(raw: str) -> Keypair: ...
+def pubkey_to_json(pubkey: Pubkey) -> str: ...
+def pubkey_from_json(raw: str) -> Pubkey: ...
+def format_transaction(
+    transaction: VersionedTransaction,
+    meta: Optional[UiTransactionStatusMeta] = None,
+    prefix: str = "",
+    sigverify: bool = False,
+    block_time: Optional[int] = None,
+) -> str: ...
@@ -0,0 +1,230 @@
+from typing import List, Optional, Sequence
+from solders.pubkey import Pubkey
+from solders.signature import Signature
+
+class NativeTransfer:
+    def __init__(
+        self,
+        from_user_account: Optional[Pubkey],
+        to_user_account: Optional[Pubkey],
+        amount: int,
+    ) -> None: ...
+    @property
+    def from_user_account(self) -> Optional[Pubkey]: ...
+    @property
+    def to_user_account(self) -> Optional[Pubkey]: ...
+    @property
+    def amount(self) -> int: ...
+    def __bytes__(self) -> bytes: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_bytes(raw_bytes: bytes) -> "NativeTransfer": ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(raw: str) -> "NativeTransfer": ...
+
+class TokenTransfer:
+    def __init__(
+        self,
+        from_user_account: Optional[Pubkey],
+        to_user_account: Optional[Pubkey],
+        from_token_account: Optional[Pubkey],
+        to_token_account: Optional[Pubkey],
+        token_amount: float,
+        mint: Pubkey,
+        token_standard: Optional[str],
+    ) -> None: ...
+    @property
+    def from_user_account(self) -> Optional[Pubkey]: ...
+    @property
+    def to_user_account(self) -> Optional[Pubkey]: ...
+    @property
+    def from_token_account(self) -> Optional[Pubkey]: ...
+    @property
+    def to_token_account(self) -> Optional[Pubkey]: ...
+    @property
+    def token_amount(self) -> float: ...
+    @property
+    def mint(self) -> Pubkey: ...
+    @property
+    def token_standard(self) -> Optional[str]: ...
+    def __bytes__(self) -> bytes: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_bytes(raw_bytes: bytes) -> "TokenTransfer": ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(raw: str) -> "TokenTransfer": ...
+
+class RawTokenAmount:
+    def __init__(self, token_amount: str, decimals: int) -> None: ...
+    @property
+    def token_amount(self) -> str: ...
+    @property
+    def decimals(self) -> int: ...
+    def __bytes__(self) -> bytes: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_bytes(raw_bytes: bytes) -> "RawTokenAmount": ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(raw: str) -> "RawTokenAmount": ...
+
+class TokenBalanceChange:
+    def __init__(
+        self,
+        user_account: Pubkey,
+        token_account: Pubkey,
+        mint: Pubkey,
+        raw_token_amount: RawTokenAmount,
+    ) -> None: ...
+    @property
+    def user_account(self) -> Pubkey: ...
+    @property
+    def token_account(self) -> Pubkey: ...
+    @property
+    def mint(self) -> Pubkey: ...
+    @property
+    def raw_token_amount(self) -> RawTokenAmount: ...
+    def __bytes__(self) -> bytes: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_bytes(raw_bytes: bytes) -> "TokenBalanceChange": ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(raw: str) -> "TokenBalanceChange": ...
+
+class AccountData:
+    def __init__(
+        self,
+        account: Pubkey,
+        native_balance_change: int,
+        token_balance_changes: Sequence[TokenBalanceChange],
+    ) -> None: ...
+    @property
+    def account(self) -> Pubkey: ...
+    @property
+    def native_balance_change(self) -> int: ...
+    @property
+    def token_balance_changes(self) -> List[TokenBalanceChange]: ...
+    def __bytes__(self) -> bytes: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_bytes(raw_bytes: bytes) -> "AccountData": ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(raw: str) -> "AccountData": ...
+
+class EnhancedInnerInstruction:
+    def __init__(
+        self, accounts: Sequence[Pubkey], data: str, program_id: Pubkey
+    ) -> None: ...
+    @property
+    def accounts(self) -> List[Pubkey]: ...
+    @property
+    def data(self) -> str: ...
+    @property
+    def program_id(self) -> Pubkey: ...
+    def decoded_data(self) -> bytes: ...
+    def __bytes__(self) -> bytes: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_bytes(raw_bytes: bytes) -> "EnhancedInnerInstruction": ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(raw: str) -> "EnhancedInnerInstruction": ...
+
+class EnhancedInstruction:
+    def __init__(
+        self,
+        accounts: Sequence[Pubkey],
+        data: str,
+        program_id: Pubkey,
+        inner_instructions: Sequence[EnhancedInnerInstruction],
+    ) -> None: ...
+    @property
+    def accounts(self) -> List[Pubkey]: ...
+    @property
+    def data(self) -> str: ...
+    @property
+    def program_id(self) -> Pubkey: ...
+    @property
+    def inner_instructions(self) -> List[EnhancedInnerInstruction]: ...
+    def decoded_data(self) -> bytes: ...
+    def __bytes__(self) -> bytes: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_bytes(raw_bytes: bytes) -> "EnhancedInstruction": ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(raw: str) -> "EnhancedInstruction": ...
+
+class EnhancedTransaction:
+    def __init__(
+        self,
+        description: str,
+        transaction_type: str,
+        source: str,
+        fee: int,
+        fee_payer: Pubkey,
+        signature: Signature,
+        slot: int,
+        timestamp: int,
+        native_transfers: Sequence[NativeTransfer],
+        token_transfers: Sequence[TokenTransfer],
+        account_data: Sequence[AccountData],
+        instructions: Sequence[EnhancedInstruction],
+        transaction_error: Optional[str],
+    ) -> None: ...
+    @property
+    def description(self) -> str: ...
+    @property
+    def transaction_type(self) -> str: ...
+    @property
+    def source(self) -> str: ...
+    @property
+    def fee(self) -> int: ...
+    @property
+    def fee_payer(self) -> Pubkey: ...
+    @property
+    def signature(self) -> Signature: ...
+    @property
+    def slot(self) -> int: ...
+    @property
+    def timestamp(self) -> int: ...
+    @property
+    def native_transfers(self) -> List[NativeTransfer]: ...
+    @property
+    def token_transfers(self) -> List[TokenTransfer]: ...
+    @property
+    def account_data(self) -> List[AccountData]: ...
+    @property
+    def instructions(self) -> List[EnhancedInstruction]: ...
+    @property
+    def transaction_error(self) -> Optional[str]: ...
+    def is_err(self) -> bool: ...
+    def __bytes__(self) -> bytes: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_bytes(raw_bytes: bytes) -> "EnhancedTransaction": ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(raw: str) -> "EnhancedTransaction": ...
+
+def parse_enhanced_transactions(raw: str) -> List[EnhancedTransaction]: ...
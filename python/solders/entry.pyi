@@ -0,0 +1,37 @@
+from typing import List, Optional, Sequence
+from solders.hash import Hash
+from solders.transaction import VersionedTransaction
+
+class Entry:
+    def __init__(
+        self,
+        num_hashes: int,
+        hash: Hash,
+        transactions: Sequence[VersionedTransaction],
+    ) -> None: ...
+    @staticmethod
+    def new_tick(num_hashes: int, prev_hash: Hash) -> "Entry": ...
+    @property
+    def num_hashes(self) -> int: ...
+    @property
+    def hash(self) -> Hash: ...
+    @property
+    def transactions(self) -> List[VersionedTransaction]: ...
+    def is_tick(self) -> bool: ...
+    def verify(self, start_hash: Hash) -> bool: ...
+    def __bytes__(self) -> bytes: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_bytes(raw_bytes: bytes) -> "Entry": ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(raw: str) -> "Entry": ...
+
+def next_hash(
+    start_hash: Hash, num_hashes: int, transactions: Sequence[VersionedTransaction]
+) -> Hash: ...
+def hash_transactions(transactions: Sequence[VersionedTransaction]) -> Hash: ...
+def entries_from_bytes(data: bytes) -> List[Entry]: ...
+def verify_entries(entries: Sequence[Entry], start_hash: Hash) -> Optional[int]: ...
@@ -0,0 +1,19 @@
+from typing import Dict, Optional
+
+from solders.keypair import Keypair
+from solders.pubkey import Pubkey
+
+class TestAddressAllocator:
+    def __init__(self, namespace: str = "solders") -> None: ...
+    @property
+    def namespace(self) -> str: ...
+    def pubkey(self, label: str) -> Pubkey: ...
+    def keypair(self, label: str) -> Keypair: ...
+    def register(self, label: str, address: Pubkey) -> Pubkey: ...
+    def label_of(self, address: Pubkey) -> Optional[str]: ...
+    def annotate(self, text: str) -> str: ...
+    def manifest(self) -> Dict[str, Pubkey]: ...
+    def manifest_json(self) -> str: ...
+    def __len__(self) -> int: ...
+    def __contains__(self, label: str) -> bool: ...
+    def __repr__(self) -> str: ...
@@ -0,0 +1,16 @@
+from typing import Any, List
+
+class GoldenVector:
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> Any: ...
+    @property
+    def serialized(self) -> bytes: ...
+    @property
+    def json(self) -> str: ...
+    def __repr__(self) -> str: ...
+
+def golden_vectors() -> List[GoldenVector]: ...
+def dump_golden_vectors() -> str: ...
+def diff_golden_vectors(previous: str) -> List[str]: ...
@@ -0,0 +1,17 @@
+from typing import List, Optional, Sequence
+from solders.instruction import Instruction
+from solders.keypair import Keypair
+from solders.pubkey import Pubkey
+from solders.rpc.responses import RpcConfirmedTransactionStatusWithSignature
+from solders.transaction_status import EncodedConfirmedTransactionWithStatusMeta
+
+IDEMPOTENCY_MEMO_PREFIX: str
+
+def idempotency_memo(key: str, signer: Optional[Pubkey] = None) -> Instruction: ...
+def tag_keypair(secret: bytes, key: str) -> Keypair: ...
+def find_tagged(
+    statuses: Sequence[RpcConfirmedTransactionStatusWithSignature], key: str
+) -> List[RpcConfirmedTransactionStatusWithSignature]: ...
+def verify_tagged(
+    transaction: EncodedConfirmedTransactionWithStatusMeta, key: str, signer: Pubkey
+) -> bool: ...
@@ -1,4 +1,4 @@
-from typing import Sequence, Union, List
+from typing import Dict, Sequence, Union, List
 from solders.pubkey import Pubkey
 
 class AccountMeta:
@@ -62,3 +62,37 @@ class CompiledInstruction:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> "CompiledInstruction": ...
+
+class AccountMetaTemplate:
+    def __init__(
+        self, key: Union[Pubkey, str], is_signer: bool, is_writable: bool
+    ) -> None: ...
+    @property
+    def key(self) -> Union[Pubkey, str]: ...
+    @property
+    def is_signer(self) -> bool: ...
+    @property
+    def is_writable(self) -> bool: ...
+    def __eq__(self, o: object) -> bool: ...
+    def __repr__(self) -> str: ...
+
+class InstructionTemplate:
+    def __init__(
+        self,
+        program_id: Pubkey,
+        data: bytes,
+        accounts: Sequence[AccountMetaTemplate],
+    ) -> None: ...
+    @property
+    def program_id(self) -> Pubkey: ...
+    @property
+    def data(self) -> bytes: ...
+    @property
+    def accounts(self) -> List[AccountMetaTemplate]: ...
+    def placeholders(self) -> List[str]: ...
+    def resolve(self, placeholders: Dict[str, Pubkey]) -> Instruction: ...
+    def resolve_many(
+        self, placeholders: Sequence[Dict[str, Pubkey]]
+    ) -> List[Instruction]: ...
+    def __eq__(self, o: object) -> bool: ...
+    def __repr__(self) -> str: ...
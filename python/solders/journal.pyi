@@ -0,0 +1,82 @@
+from typing import List, Optional, Sequence
+from solders.account import Account
+from solders.accounts_map import AccountsMap
+from solders.address_lookup_table_account import AddressLookupTableAccount
+from solders.pubkey import Pubkey
+from solders.signature import Signature
+from solders.simulation import RuntimeParams
+from solders.transaction import VersionedTransaction
+from solders.transaction_status import TransactionErrorType
+
+class AccountChange:
+    def __init__(
+        self, pubkey: Pubkey, before: Optional[Account], after: Optional[Account]
+    ) -> None: ...
+    @property
+    def pubkey(self) -> Pubkey: ...
+    @property
+    def before(self) -> Optional[Account]: ...
+    @property
+    def after(self) -> Optional[Account]: ...
+    @property
+    def lamport_delta(self) -> int: ...
+    @property
+    def created(self) -> bool: ...
+    @property
+    def closed(self) -> bool: ...
+    @property
+    def data_changed(self) -> bool: ...
+    @property
+    def owner_changed(self) -> bool: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+class JournalEntry:
+    def __init__(
+        self,
+        signature: Signature,
+        err: Optional[TransactionErrorType],
+        fee: int,
+        changes: Sequence[AccountChange],
+        unsimulated_instructions: Sequence[int] = ...,
+    ) -> None: ...
+    @property
+    def signature(self) -> Signature: ...
+    @property
+    def err(self) -> Optional[TransactionErrorType]: ...
+    @property
+    def fee(self) -> int: ...
+    @property
+    def changes(self) -> List[AccountChange]: ...
+    @property
+    def unsimulated_instructions(self) -> List[int]: ...
+    @property
+    def fully_simulated(self) -> bool: ...
+    def change(self, pubkey: Pubkey) -> Optional[AccountChange]: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+class AccountJournal:
+    def __init__(
+        self,
+        accounts: AccountsMap,
+        programs: Optional[Sequence[Pubkey]] = None,
+        params: Optional[RuntimeParams] = None,
+    ) -> None: ...
+    def process(
+        self,
+        tx: VersionedTransaction,
+        address_lookup_table_accounts: Optional[
+            Sequence[AddressLookupTableAccount]
+        ] = None,
+    ) -> JournalEntry: ...
+    @property
+    def accounts(self) -> AccountsMap: ...
+    @property
+    def entries(self) -> List[JournalEntry]: ...
+    def history(self, pubkey: Pubkey) -> List[AccountChange]: ...
+    def net_lamport_delta(self, pubkey: Pubkey) -> int: ...
+    def clear(self) -> None: ...
+    def __len__(self) -> int: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
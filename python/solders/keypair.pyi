@@ -11,8 +11,18 @@ class Keypair:
     @staticmethod
     def from_seed(seed: Union[bytes, Sequence[int]]) -> "Keypair": ...
     @staticmethod
+    def from_seed_or_bytes(raw_bytes: Union[bytes, Sequence[int]]) -> "Keypair": ...
+    @staticmethod
     def from_base58_string(s: str) -> "Keypair": ...
     @staticmethod
+    def new_with_rng(seed: int) -> "Keypair": ...
+    @staticmethod
+    def from_seed_and_derivation_bytes(
+        seed: bytes, path: Sequence[bytes]
+    ) -> "Keypair": ...
+    @staticmethod
+    def derive_ephemeral(master: "Keypair", label: str, index: int) -> "Keypair": ...
+    @staticmethod
     def from_seed_phrase_and_passphrase(
         seed_phrase: str, passphrase: str
     ) -> "Keypair": ...
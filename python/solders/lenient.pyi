@@ -0,0 +1,18 @@
+from typing import Any, List, Type
+
+class LenientResult:
+    def __init__(
+        self, value: Any, warnings: List[str], dropped: List[str] = ...
+    ) -> None: ...
+    @property
+    def value(self) -> Any: ...
+    @property
+    def warnings(self) -> List[str]: ...
+    @property
+    def dropped(self) -> List[str]: ...
+    @property
+    def is_clean(self) -> bool: ...
+    def __repr__(self) -> str: ...
+
+def from_bytes_lenient(raw: bytes, cls: Type[Any]) -> LenientResult: ...
+def from_json_lenient(raw: str, parser: Type[Any]) -> LenientResult: ...
@@ -0,0 +1,34 @@
+from typing import Callable, Dict, Optional
+
+class LogLevel:
+    Debug: "LogLevel"
+    Info: "LogLevel"
+    Warning: "LogLevel"
+    Error: "LogLevel"
+    def __int__(self) -> int: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_string(s: str) -> "LogLevel": ...
+
+class LogEvent:
+    def __init__(
+        self, level: LogLevel, target: str, message: str, context: Dict[str, str]
+    ) -> None: ...
+    @property
+    def level(self) -> LogLevel: ...
+    @property
+    def target(self) -> str: ...
+    @property
+    def message(self) -> str: ...
+    @property
+    def context(self) -> Dict[str, str]: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+def set_log_handler(
+    handler: Optional[Callable[[LogEvent], None]],
+    min_level: LogLevel = LogLevel.Debug,
+) -> None: ...
+def use_python_logging(min_level: LogLevel = LogLevel.Debug) -> None: ...
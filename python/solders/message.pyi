@@ -80,6 +80,10 @@ class Message:
     def is_writable(self, i: int) -> bool: ...
     def is_signer(self, i: int) -> bool: ...
     def signer_keys(self) -> List[Pubkey]: ...
+    @staticmethod
+    def required_signers(
+        instructions: Sequence[Instruction], payer: Optional[Pubkey] = None
+    ) -> List[Pubkey]: ...
     def has_duplicates(self) -> bool: ...
     @staticmethod
     def default() -> "Message": ...
@@ -138,7 +142,14 @@ class MessageV0:
     def instructions(self) -> List[CompiledInstruction]: ...
     @property
     def address_table_lookups(self) -> List[MessageAddressTableLookup]: ...
+    def with_address_table_lookups(
+        self, address_table_lookups: Sequence[MessageAddressTableLookup]
+    ) -> "MessageV0": ...
+    def replace_lookup_table(self, old: Pubkey, new: Pubkey) -> "MessageV0": ...
     def sanitize(self, reject_dynamic_program_ids: bool) -> None: ...
+    def expand_to_legacy(
+        self, address_lookup_table_accounts: Sequence[AddressLookupTableAccount]
+    ) -> Message: ...
     def hash(self) -> Hash: ...
     @staticmethod
     def hash_raw_message(message_bytes: bytes) -> Hash: ...
@@ -157,3 +168,5 @@ class MessageV0:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> "MessageV0": ...
+
+class CompileError(Exception): ...
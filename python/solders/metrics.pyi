@@ -0,0 +1,31 @@
+from typing import Dict, List, Sequence, Tuple
+
+class HistogramSnapshot:
+    def __init__(
+        self, buckets: Sequence[Tuple[float, int]], sum: float, count: int
+    ) -> None: ...
+    @property
+    def buckets(self) -> List[Tuple[float, int]]: ...
+    @property
+    def sum(self) -> float: ...
+    @property
+    def count(self) -> int: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+class MetricsSnapshot:
+    def __init__(
+        self, counters: Dict[str, int], histograms: Dict[str, HistogramSnapshot]
+    ) -> None: ...
+    @property
+    def counters(self) -> Dict[str, int]: ...
+    @property
+    def histograms(self) -> Dict[str, HistogramSnapshot]: ...
+    def to_prometheus(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+def metrics() -> MetricsSnapshot: ...
+def reset_metrics() -> None: ...
+def set_metrics_enabled(enabled: bool) -> None: ...
+def record(name: str, by: int = 1) -> None: ...
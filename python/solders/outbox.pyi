@@ -0,0 +1,67 @@
+from typing import List, Optional
+from solders.signature import Signature
+from solders.transaction import VersionedTransaction
+
+class OutboxStatus:
+    Pending: "OutboxStatus"
+    Confirmed: "OutboxStatus"
+    Failed: "OutboxStatus"
+    Expired: "OutboxStatus"
+    def __int__(self) -> int: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_string(s: str) -> "OutboxStatus": ...
+
+class OutboxEntry:
+    def __init__(
+        self,
+        transaction: VersionedTransaction,
+        last_valid_block_height: int,
+        status: OutboxStatus,
+    ) -> None: ...
+    @property
+    def transaction(self) -> VersionedTransaction: ...
+    @property
+    def last_valid_block_height(self) -> int: ...
+    @property
+    def status(self) -> OutboxStatus: ...
+    @property
+    def signature(self) -> Signature: ...
+    def __bytes__(self) -> bytes: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_bytes(raw_bytes: bytes) -> "OutboxEntry": ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(raw: str) -> "OutboxEntry": ...
+
+class Outbox:
+    def __init__(self) -> None: ...
+    @property
+    def entries(self) -> List[OutboxEntry]: ...
+    def add(
+        self,
+        transaction: VersionedTransaction,
+        last_valid_block_height: Optional[int] = None,
+    ) -> bool: ...
+    def get(self, signature: Signature) -> Optional[OutboxEntry]: ...
+    def set_status(self, signature: Signature, status: OutboxStatus) -> bool: ...
+    def remove(self, signature: Signature) -> Optional[OutboxEntry]: ...
+    def expire(self, block_height: int) -> List[Signature]: ...
+    def pending(self) -> List[OutboxEntry]: ...
+    def prune(self) -> int: ...
+    def __len__(self) -> int: ...
+    def __contains__(self, signature: Signature) -> bool: ...
+    def __bytes__(self) -> bytes: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_bytes(raw_bytes: bytes) -> "Outbox": ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(raw: str) -> "Outbox": ...
@@ -0,0 +1,120 @@
+from typing import Callable, Dict, List, Optional, Sequence, Tuple, Union
+from solders.account import Account
+from solders.accounts_map import AccountsMap
+from solders.bundle import PlannedTransaction
+from solders.instruction import Instruction
+from solders.pubkey import Pubkey
+from solders.rpc.responses import RpcKeyedAccount, RpcKeyedAccountJsonParsed
+
+NATIVE_MINT: Pubkey
+NATIVE_MINT_2022: Pubkey
+
+def get_associated_token_address(
+    wallet: Pubkey, mint: Pubkey, token_program_id: Optional[Pubkey] = None
+) -> Pubkey: ...
+def filter_associated_token_accounts(
+    accounts: Sequence[RpcKeyedAccount],
+) -> List[RpcKeyedAccount]: ...
+def transfer_to_wallet(
+    payer: Pubkey,
+    source: Pubkey,
+    owner: Pubkey,
+    recipient: Pubkey,
+    mint: Pubkey,
+    amount: int,
+    decimals: int,
+    token_program_id: Optional[Pubkey] = None,
+) -> List[Instruction]: ...
+def wrap_sol_around(
+    owner: Pubkey,
+    lamports: int,
+    instructions: Sequence[Instruction],
+    token_program_id: Optional[Pubkey] = None,
+) -> List[Instruction]: ...
+def close_token_accounts(
+    accounts: Sequence[Pubkey],
+    destination: Pubkey,
+    owner: Pubkey,
+    token_program_id: Optional[Pubkey] = None,
+) -> List[Instruction]: ...
+
+class AssociatedTokenAccountPlan:
+    def __init__(
+        self,
+        missing: Sequence[Pubkey],
+        existing: Sequence[Pubkey],
+        instructions: Sequence[Instruction],
+        transactions: Sequence[PlannedTransaction],
+    ) -> None: ...
+    @property
+    def missing(self) -> List[Pubkey]: ...
+    @property
+    def existing(self) -> List[Pubkey]: ...
+    @property
+    def instructions(self) -> List[Instruction]: ...
+    @property
+    def transactions(self) -> List[PlannedTransaction]: ...
+    @property
+    def is_complete(self) -> bool: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+def plan_associated_token_accounts(
+    payer: Pubkey,
+    pairs: Sequence[Tuple[Pubkey, Pubkey]],
+    accounts: Union[
+        AccountsMap,
+        Dict[Pubkey, Account],
+        Callable[[List[Pubkey]], Sequence[Optional[Account]]],
+    ],
+    token_program_id: Optional[Pubkey] = None,
+    max_accounts: int = 64,
+) -> AssociatedTokenAccountPlan: ...
+
+class SweepSkipReason:
+    NotTokenAccount: "SweepSkipReason"
+    WrongOwner: "SweepSkipReason"
+    Frozen: "SweepSkipReason"
+    CloseAuthority: "SweepSkipReason"
+    HasBalance: "SweepSkipReason"
+    Destination: "SweepSkipReason"
+    def __int__(self) -> int: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_string(s: str) -> "SweepSkipReason": ...
+
+class TokenSweepPlan:
+    def __init__(
+        self,
+        closed: Sequence[Pubkey],
+        consolidated: Sequence[Pubkey],
+        skipped: Sequence[Tuple[Pubkey, SweepSkipReason]],
+        reclaimed_lamports: int,
+        instructions: Sequence[Instruction],
+        transactions: Sequence[PlannedTransaction],
+    ) -> None: ...
+    @property
+    def closed(self) -> List[Pubkey]: ...
+    @property
+    def consolidated(self) -> List[Pubkey]: ...
+    @property
+    def skipped(self) -> List[Tuple[Pubkey, SweepSkipReason]]: ...
+    @property
+    def reclaimed_lamports(self) -> int: ...
+    @property
+    def instructions(self) -> List[Instruction]: ...
+    @property
+    def transactions(self) -> List[PlannedTransaction]: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+def plan_token_sweep(
+    owner: Pubkey,
+    token_accounts: Sequence[RpcKeyedAccountJsonParsed],
+    destination: Pubkey,
+    dust_thresholds: Optional[Dict[Pubkey, int]] = None,
+    payer: Optional[Pubkey] = None,
+    max_accounts: int = 64,
+) -> TokenSweepPlan: ...
@@ -22,6 +22,8 @@ class Pubkey:
         seeds: Sequence[bytes], program_id: "Pubkey"
     ) -> Tuple["Pubkey", int]: ...
     def is_on_curve(self) -> bool: ...
+    def to_short_string(self, prefix_len: int = 4, suffix_len: int = 4) -> str: ...
+    def explorer_url(self, cluster: str = "mainnet-beta") -> str: ...
     def string(self) -> str: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -0,0 +1,54 @@
+from typing import Any, Dict, Iterator, List, Optional, Sequence, Tuple
+from solders.pubkey import Pubkey
+from solders.signature import Signature
+
+class PubkeyIterator(Iterator[Pubkey]):
+    def __iter__(self) -> "PubkeyIterator": ...
+    def __next__(self) -> Pubkey: ...
+    def __length_hint__(self) -> int: ...
+
+class PubkeySet:
+    def __init__(self, pubkeys: Optional[Sequence[Pubkey]] = None) -> None: ...
+    @staticmethod
+    def from_bytes(data: bytes) -> "PubkeySet": ...
+    def add(self, pubkey: Pubkey) -> bool: ...
+    def discard(self, pubkey: Pubkey) -> bool: ...
+    def update(self, pubkeys: Sequence[Pubkey]) -> int: ...
+    def update_from_bytes(self, data: bytes) -> int: ...
+    def insert_new(self, pubkeys: Sequence[Pubkey]) -> List[Pubkey]: ...
+    def union(self, other: "PubkeySet") -> "PubkeySet": ...
+    def intersection(self, other: "PubkeySet") -> "PubkeySet": ...
+    def difference(self, other: "PubkeySet") -> "PubkeySet": ...
+    def to_list(self) -> List[Pubkey]: ...
+    def __bytes__(self) -> bytes: ...
+    def __or__(self, other: "PubkeySet") -> "PubkeySet": ...
+    def __and__(self, other: "PubkeySet") -> "PubkeySet": ...
+    def __sub__(self, other: "PubkeySet") -> "PubkeySet": ...
+    def __contains__(self, pubkey: Pubkey) -> bool: ...
+    def __len__(self) -> int: ...
+    def __iter__(self) -> PubkeyIterator: ...
+    def __eq__(self, o: object) -> bool: ...
+    def __repr__(self) -> str: ...
+
+class PubkeyMap:
+    def __init__(self, mapping: Optional[Dict[Pubkey, Any]] = None) -> None: ...
+    @staticmethod
+    def from_bytes(keys: bytes, values: Sequence[Any]) -> "PubkeyMap": ...
+    def get(self, pubkey: Pubkey, default: Optional[Any] = None) -> Any: ...
+    def pop(self, pubkey: Pubkey, default: Any = ..., /) -> Any: ...
+    def key_set(self) -> PubkeySet: ...
+    def keys(self) -> List[Pubkey]: ...
+    def items(self) -> List[Tuple[Pubkey, Any]]: ...
+    def to_dict(self) -> Dict[Pubkey, Any]: ...
+    def __getitem__(self, pubkey: Pubkey) -> Any: ...
+    def __setitem__(self, pubkey: Pubkey, value: Any) -> None: ...
+    def __delitem__(self, pubkey: Pubkey) -> None: ...
+    def __contains__(self, pubkey: Pubkey) -> bool: ...
+    def __len__(self) -> int: ...
+    def __iter__(self) -> PubkeyIterator: ...
+    def __repr__(self) -> str: ...
+
+def pubkeys_to_array(pubkeys: Sequence[Pubkey]) -> bytes: ...
+def array_to_pubkeys(data: bytes) -> List[Pubkey]: ...
+def signatures_to_array(signatures: Sequence[Signature]) -> bytes: ...
+def array_to_signatures(data: bytes) -> List[Signature]: ...
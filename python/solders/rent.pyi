@@ -0,0 +1,26 @@
+from typing import Tuple
+
+class Rent:
+    def __init__(
+        self, lamports_per_byte_year: int, exemption_threshold: float, burn_percent: int
+    ) -> None: ...
+    @property
+    def lamports_per_byte_year(self) -> int: ...
+    @property
+    def exemption_threshold(self) -> float: ...
+    @property
+    def burn_percent(self) -> int: ...
+    @staticmethod
+    def default() -> "Rent": ...
+    def calculate_burn(self, rent_collected: int) -> Tuple[int, int]: ...
+    def minimum_balance(self, data_len: int) -> int: ...
+    def is_exempt(self, balance: int, data_len: int) -> bool: ...
+    def __bytes__(self) -> bytes: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __richcmp__(self, other: "Rent", op: int) -> bool: ...
+    @staticmethod
+    def from_bytes(raw_bytes: bytes) -> "Rent": ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(raw: str) -> "Rent": ...
@@ -207,6 +207,8 @@ class RpcLargestAccountsFilter:
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
     def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_string(s: str) -> "RpcLargestAccountsFilter": ...
 
 class RpcLargestAccountsConfig:
     def __init__(
@@ -310,6 +312,22 @@ class RpcProgramAccountsConfig:
     def filters(self) -> Optional[Sequence[Union[int, Memcmp]]]: ...
     @property
     def with_context(self) -> Optional[bool]: ...
+    @staticmethod
+    def token_accounts_by_owner(
+        owner: Pubkey, account_config: Optional[RpcAccountInfoConfig] = None
+    ) -> "RpcProgramAccountsConfig": ...
+    @staticmethod
+    def token_accounts_by_mint(
+        mint: Pubkey, account_config: Optional[RpcAccountInfoConfig] = None
+    ) -> "RpcProgramAccountsConfig": ...
+    @staticmethod
+    def stake_accounts_by_withdrawer(
+        withdrawer: Pubkey, account_config: Optional[RpcAccountInfoConfig] = None
+    ) -> "RpcProgramAccountsConfig": ...
+    @staticmethod
+    def stake_accounts_by_staker(
+        staker: Pubkey, account_config: Optional[RpcAccountInfoConfig] = None
+    ) -> "RpcProgramAccountsConfig": ...
     def __bytes__(self) -> bytes: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -327,6 +345,8 @@ class RpcTransactionLogsFilter:
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
     def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_string(s: str) -> "RpcTransactionLogsFilter": ...
 
 class RpcTransactionLogsFilterMentions:
     def __init__(self, pubkey: Pubkey): ...
@@ -391,6 +411,8 @@ class RpcBlockSubscribeFilter:
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
     def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_string(s: str) -> "RpcBlockSubscribeFilter": ...
 
 class RpcBlockSubscribeFilterMentions:
     def __init__(self, pubkey: Pubkey): ...
@@ -0,0 +1,62 @@
+from typing import List, Optional, Sequence, Tuple, Union
+from solders.pubkey import Pubkey
+from solders.rpc.responses import RpcPrioritizationFee, RpcSimulateTransactionResult
+from solders.signature import Signature
+from solders.transaction import Transaction, VersionedTransaction
+from solders.transaction_status import (
+    TransactionErrorType,
+    TransactionStatus,
+    UiConfirmedBlock,
+)
+
+class LandingVerdict:
+    Landed: "LandingVerdict"
+    FailedOnChain: "LandingVerdict"
+    PreflightFailed: "LandingVerdict"
+    StillValid: "LandingVerdict"
+    FeeTooLow: "LandingVerdict"
+    WriteLockContention: "LandingVerdict"
+    BlockhashExpired: "LandingVerdict"
+    def __int__(self) -> int: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_string(s: str) -> "LandingVerdict": ...
+
+class LandingReport:
+    signature: Signature
+    verdict: LandingVerdict
+    explanation: str
+    error: Optional[TransactionErrorType]
+    blockhash_expired: bool
+    compute_unit_price: int
+    fee_percentile: Optional[float]
+    median_compute_unit_price: Optional[int]
+    contention_ratio: Optional[float]
+    contended_accounts: List[Tuple[Pubkey, int]]
+    def __init__(
+        self,
+        signature: Signature,
+        verdict: LandingVerdict,
+        explanation: str,
+        error: Optional[TransactionErrorType],
+        blockhash_expired: bool,
+        compute_unit_price: int,
+        fee_percentile: Optional[float],
+        median_compute_unit_price: Optional[int],
+        contention_ratio: Optional[float],
+        contended_accounts: Sequence[Tuple[Pubkey, int]],
+    ) -> None: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+def diagnose_landing(
+    transaction: Union[Transaction, VersionedTransaction],
+    block_height: int,
+    last_valid_block_height: int,
+    status: Optional[TransactionStatus] = None,
+    prioritization_fees: Sequence[RpcPrioritizationFee] = (),
+    recent_blocks: Sequence[UiConfirmedBlock] = (),
+    preflight: Optional[RpcSimulateTransactionResult] = None,
+) -> LandingReport: ...
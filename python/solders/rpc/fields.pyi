@@ -0,0 +1,50 @@
+from typing import Dict, List, Optional, Sequence
+from solders.account_decoder import UiAccountEncoding, UiDataSliceConfig
+from solders.commitment_config import CommitmentLevel
+from solders.rpc.config import RpcAccountInfoConfig
+
+DEFAULT_ZSTD_THRESHOLD: int
+
+class AccountField:
+    def __init__(self, name: str, offset: int, length: int) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def offset(self) -> int: ...
+    @property
+    def length(self) -> int: ...
+    @property
+    def end(self) -> int: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+class AccountFields:
+    def __init__(
+        self,
+        fields: Optional[Sequence[AccountField]] = None,
+        data_size: Optional[int] = None,
+    ) -> None: ...
+    @staticmethod
+    def token_account(names: Optional[Sequence[str]] = None) -> "AccountFields": ...
+    @staticmethod
+    def mint(names: Optional[Sequence[str]] = None) -> "AccountFields": ...
+    @property
+    def fields(self) -> Optional[List[AccountField]]: ...
+    @property
+    def data_size(self) -> Optional[int]: ...
+    @property
+    def data_slice(self) -> Optional[UiDataSliceConfig]: ...
+    @property
+    def transfer_size(self) -> Optional[int]: ...
+    def encoding(
+        self, zstd_threshold: int = DEFAULT_ZSTD_THRESHOLD
+    ) -> UiAccountEncoding: ...
+    def account_info_config(
+        self,
+        commitment: Optional[CommitmentLevel] = None,
+        min_context_slot: Optional[int] = None,
+        zstd_threshold: int = DEFAULT_ZSTD_THRESHOLD,
+    ) -> RpcAccountInfoConfig: ...
+    def extract(self, data: bytes) -> Dict[str, bytes]: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
@@ -1,4 +1,6 @@
-from typing import Sequence, Union, Optional
+from typing import List, Sequence, Union, Optional
+from solders.account import Account
+from solders.pubkey import Pubkey
 
 class MemcmpEncoding:
     Binary: "MemcmpEncoding"
@@ -6,6 +8,8 @@ class MemcmpEncoding:
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
     def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_string(s: str) -> "MemcmpEncoding": ...
 
 class Memcmp:
     def __init__(
@@ -27,3 +31,34 @@ class Memcmp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> "Memcmp": ...
+
+class AccountFilter:
+    def __init__(
+        self,
+        owner: Optional[Pubkey] = None,
+        min_data_len: Optional[int] = None,
+        max_data_len: Optional[int] = None,
+        memcmp: Optional[Sequence[Memcmp]] = None,
+    ) -> None: ...
+    @property
+    def owner(self) -> Optional[Pubkey]: ...
+    @property
+    def min_data_len(self) -> Optional[int]: ...
+    @property
+    def max_data_len(self) -> Optional[int]: ...
+    @property
+    def memcmp(self) -> List[Memcmp]: ...
+    def matches(self, account: Account) -> bool: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+TOKEN_ACCOUNT_MINT_OFFSET: int
+TOKEN_ACCOUNT_OWNER_OFFSET: int
+STAKE_AUTHORIZED_STAKER_OFFSET: int
+STAKE_AUTHORIZED_WITHDRAWER_OFFSET: int
+METADATA_UPDATE_AUTHORITY_OFFSET: int
+METADATA_MINT_OFFSET: int
+
+def memcmp_matches(
+    account_data: bytes, offset: int, bytes_: Union[str, Sequence[int], bytes]
+) -> bool: ...
@@ -0,0 +1,69 @@
+from typing import List, Optional, Sequence, Tuple, Union
+from solders.hash import Hash
+from solders.rpc.responses import SlotInfo, SlotUpdate
+from solders.transaction_status import UiConfirmedBlock
+
+class SlotStatus:
+    Processed: "SlotStatus"
+    Confirmed: "SlotStatus"
+    Finalized: "SlotStatus"
+    Abandoned: "SlotStatus"
+    def __int__(self) -> int: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_string(s: str) -> "SlotStatus": ...
+
+class ForkTracker:
+    def __init__(self) -> None: ...
+    @property
+    def root(self) -> Optional[int]: ...
+    def update(self, event: Union[SlotInfo, SlotUpdate]) -> None: ...
+    def status(self, slot: int) -> Optional[SlotStatus]: ...
+    def prune(self, slot: int) -> None: ...
+    def __len__(self) -> int: ...
+    def __repr__(self) -> str: ...
+
+class BlockChainIssueKind:
+    OutOfOrder: "BlockChainIssueKind"
+    Gap: "BlockChainIssueKind"
+    Fork: "BlockChainIssueKind"
+    HashMismatch: "BlockChainIssueKind"
+    def __int__(self) -> int: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_string(s: str) -> "BlockChainIssueKind": ...
+
+class BlockChainIssue:
+    def __init__(
+        self,
+        slot: int,
+        kind: BlockChainIssueKind,
+        previous_slot: int,
+        parent_slot: int,
+        expected_blockhash: Hash,
+        previous_blockhash: Hash,
+    ) -> None: ...
+    @property
+    def slot(self) -> int: ...
+    @property
+    def kind(self) -> BlockChainIssueKind: ...
+    @property
+    def previous_slot(self) -> int: ...
+    @property
+    def parent_slot(self) -> int: ...
+    @property
+    def expected_blockhash(self) -> Hash: ...
+    @property
+    def previous_blockhash(self) -> Hash: ...
+    @property
+    def missing_range(self) -> Optional[Tuple[int, int]]: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+def verify_block_chain(
+    blocks: Sequence[Tuple[int, UiConfirmedBlock]]
+) -> List[BlockChainIssue]: ...
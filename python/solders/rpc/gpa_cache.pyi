@@ -0,0 +1,40 @@
+from typing import List, Optional, Sequence, Union
+from solders.account import Account
+from solders.pubkey import Pubkey
+from solders.rpc.filter import Memcmp
+from solders.rpc.responses import (
+    GetProgramAccountsWithContextResp,
+    ProgramNotification,
+    RpcKeyedAccount,
+)
+
+class ProgramAccountsSnapshot:
+    def __init__(self, slot: int, accounts: Sequence[RpcKeyedAccount]) -> None: ...
+    @property
+    def slot(self) -> int: ...
+    @property
+    def accounts(self) -> List[RpcKeyedAccount]: ...
+    def get(self, pubkey: Pubkey) -> Optional[Account]: ...
+    def __len__(self) -> int: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+class ProgramAccountsCache:
+    def __init__(self, program_id: Pubkey) -> None: ...
+    @property
+    def program_id(self) -> Pubkey: ...
+    def load(
+        self,
+        response: GetProgramAccountsWithContextResp,
+        filters: Optional[Sequence[Union[int, Memcmp]]] = None,
+    ) -> None: ...
+    def apply(self, notification: ProgramNotification) -> bool: ...
+    def snapshot(
+        self, filters: Optional[Sequence[Union[int, Memcmp]]] = None
+    ) -> Optional[ProgramAccountsSnapshot]: ...
+    def invalidate(
+        self, filters: Optional[Sequence[Union[int, Memcmp]]] = None
+    ) -> bool: ...
+    def __len__(self) -> int: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
@@ -0,0 +1,66 @@
+from typing import Any, Callable, List, Optional, Tuple
+from solders.commitment_config import CommitmentConfig, CommitmentLevel
+from solders.rpc.requests import GetBlocks, GetSignatureStatuses, GetSlot
+from solders.rpc.responses import GetBlocksResp, GetSignatureStatusesResp
+from solders.signature import Signature
+from solders.transaction_status import TransactionStatus
+
+MAX_GET_BLOCKS_RANGE: int
+
+class StatusPoller:
+    def __init__(
+        self,
+        commitment: Optional[CommitmentConfig] = None,
+        search_transaction_history: bool = False,
+    ) -> None: ...
+    @property
+    def commitment(self) -> CommitmentConfig: ...
+    def track(
+        self,
+        signature: Signature,
+        last_valid_block_height: int,
+        callback: Optional[
+            Callable[[Signature, Optional[TransactionStatus]], Any]
+        ] = None,
+    ) -> bool: ...
+    def untrack(self, signature: Signature) -> bool: ...
+    def pending(self) -> List[Signature]: ...
+    def requests(self, start_id: int = 0) -> List[GetSignatureStatuses]: ...
+    def handle_response(
+        self,
+        request: GetSignatureStatuses,
+        response: GetSignatureStatusesResp,
+        block_height: int,
+    ) -> List[Tuple[Signature, Optional[TransactionStatus]]]: ...
+    def __len__(self) -> int: ...
+    def __contains__(self, signature: Signature) -> bool: ...
+    def __repr__(self) -> str: ...
+
+class BlockBackfill:
+    def __init__(
+        self,
+        start_slot: int,
+        commitment: Optional[CommitmentLevel] = None,
+        chunk_size: int = MAX_GET_BLOCKS_RANGE,
+    ) -> None: ...
+    @property
+    def next_slot(self) -> int: ...
+    @property
+    def tip(self) -> Optional[int]: ...
+    @property
+    def commitment(self) -> CommitmentLevel: ...
+    @property
+    def chunk_size(self) -> int: ...
+    @property
+    def is_caught_up(self) -> bool: ...
+    def tip_request(self, id: int = 0) -> GetSlot: ...
+    def update_tip(self, tip: int) -> bool: ...
+    def next_request(self, id: int = 0) -> Optional[GetBlocks]: ...
+    def handle_response(
+        self,
+        request: GetBlocks,
+        response: GetBlocksResp,
+        root: Optional[int] = None,
+    ) -> List[int]: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
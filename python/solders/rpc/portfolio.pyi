@@ -0,0 +1,52 @@
+from typing import List, Optional
+from solders.commitment_config import CommitmentLevel
+from solders.pubkey import Pubkey
+from solders.rpc.requests import GetMultipleAccounts, GetTokenAccountsByOwner
+from solders.rpc.responses import GetMultipleAccountsResp, GetTokenAccountsByOwnerResp
+
+class TokenHolding:
+    def __init__(
+        self,
+        address: Pubkey,
+        mint: Pubkey,
+        amount: int,
+        decimals: int,
+        is_frozen: bool,
+        program_id: Pubkey,
+    ) -> None: ...
+    @property
+    def address(self) -> Pubkey: ...
+    @property
+    def mint(self) -> Pubkey: ...
+    @property
+    def amount(self) -> int: ...
+    @property
+    def decimals(self) -> int: ...
+    @property
+    def is_frozen(self) -> bool: ...
+    @property
+    def program_id(self) -> Pubkey: ...
+    @property
+    def ui_amount(self) -> float: ...
+    @property
+    def ui_amount_string(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+    def __repr__(self) -> str: ...
+
+class PortfolioQuery:
+    def __init__(
+        self, owner: Pubkey, commitment: Optional[CommitmentLevel] = None
+    ) -> None: ...
+    @property
+    def owner(self) -> Pubkey: ...
+    def token_account_requests(
+        self, start_id: int = 0
+    ) -> List[GetTokenAccountsByOwner]: ...
+    def handle_token_accounts(self, response: GetTokenAccountsByOwnerResp) -> None: ...
+    def mint_requests(self, start_id: int = 0) -> List[GetMultipleAccounts]: ...
+    def handle_mints(
+        self, request: GetMultipleAccounts, response: GetMultipleAccountsResp
+    ) -> None: ...
+    def set_decimals(self, mint: Pubkey, decimals: int) -> None: ...
+    def holdings(self, include_empty: bool = True) -> List[TokenHolding]: ...
+    def __repr__(self) -> str: ...
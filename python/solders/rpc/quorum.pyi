@@ -0,0 +1,27 @@
+from typing import Dict, Optional, Sequence
+from solders.pubkey import Pubkey
+from solders.rpc.responses import RpcContactInfo, RpcVoteAccountStatus
+
+SUPERMAJORITY_PERCENT: float
+
+class StakeWeights:
+    def __init__(self, vote_accounts: RpcVoteAccountStatus) -> None: ...
+    @property
+    def total_stake(self) -> int: ...
+    @property
+    def delinquent_stake(self) -> int: ...
+    def percent(self, stake: int) -> float: ...
+    def min_stake_for(self, percent: float) -> int: ...
+    def stake_of(self, node_pubkeys: Sequence[Pubkey]) -> int: ...
+    def stake_by_version(
+        self, cluster_nodes: Sequence[RpcContactInfo]
+    ) -> Dict[Optional[str], int]: ...
+    def stake_at_least_version(
+        self, cluster_nodes: Sequence[RpcContactInfo], min_version: str
+    ) -> int: ...
+    def percent_at_least_version(
+        self, cluster_nodes: Sequence[RpcContactInfo], min_version: str
+    ) -> float: ...
+    def __len__(self) -> int: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
@@ -28,8 +28,10 @@ from solders.rpc.config import (
     RpcTransactionLogsFilterMentions,
 )
 from solders.commitment_config import CommitmentLevel
+from solders.rpc.fields import AccountFields
+from solders.rpc.filter import Memcmp
 from solders.signature import Signature
-from solders.message import Message
+from solders.message import Message, MessageV0
 from solders.hash import Hash
 from solders.transaction import Transaction
 
@@ -40,6 +42,13 @@ class GetAccountInfo:
         config: Optional[RpcAccountInfoConfig] = None,
         id: Optional[int] = None,
     ): ...
+    @staticmethod
+    def for_fields(
+        pubkey: Pubkey,
+        fields: AccountFields,
+        commitment: Optional[CommitmentLevel] = None,
+        id: Optional[int] = None,
+    ) -> "GetAccountInfo": ...
     @property
     def pubkey(self) -> Pubkey: ...
     @property
@@ -255,12 +264,12 @@ class GetEpochInfo:
 class GetFeeForMessage:
     def __init__(
         self,
-        message: Message,
+        message: Union[Message, MessageV0],
         commitment: Optional[CommitmentLevel] = None,
         id: Optional[int] = None,
     ): ...
     @property
-    def message(self) -> Message: ...
+    def message(self) -> Union[Message, MessageV0]: ...
     @property
     def commitment(self) -> Optional[CommitmentLevel]: ...
     @property
@@ -432,6 +441,13 @@ class GetMultipleAccounts:
         config: Optional[RpcAccountInfoConfig] = None,
         id: Optional[int] = None,
     ): ...
+    @staticmethod
+    def for_fields(
+        accounts: Sequence[Pubkey],
+        fields: AccountFields,
+        commitment: Optional[CommitmentLevel] = None,
+        id: Optional[int] = None,
+    ) -> "GetMultipleAccounts": ...
     @property
     def accounts(self) -> List[Pubkey]: ...
     @property
@@ -456,6 +472,14 @@ class GetProgramAccounts:
         config: Optional[RpcProgramAccountsConfig] = None,
         id: Optional[int] = None,
     ): ...
+    @staticmethod
+    def for_fields(
+        program: Pubkey,
+        fields: AccountFields,
+        filters: Optional[Sequence[Union[int, Memcmp]]] = None,
+        commitment: Optional[CommitmentLevel] = None,
+        id: Optional[int] = None,
+    ) -> "GetProgramAccounts": ...
     @property
     def program(self) -> Pubkey: ...
     @property
@@ -490,6 +514,27 @@ class GetRecentPerformanceSamples:
     def __bytes__(self) -> bytes: ...
     def __hash__(self) -> int: ...
 
+class GetRecentPrioritizationFees:
+    def __init__(
+        self,
+        locked_writable_accounts: Optional[Sequence[Pubkey]] = None,
+        id: Optional[int] = None,
+    ): ...
+    @property
+    def locked_writable_accounts(self) -> Optional[List[Pubkey]]: ...
+    @property
+    def id(self) -> int: ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(raw: str) -> "GetRecentPrioritizationFees": ...
+    @staticmethod
+    def from_bytes(data: bytes) -> "GetRecentPrioritizationFees": ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+    def __bytes__(self) -> bytes: ...
+    def __hash__(self) -> int: ...
+
 class GetSignaturesForAddress:
     def __init__(
         self,
@@ -1479,6 +1524,7 @@ Body = Union[
     GetMultipleAccounts,
     GetProgramAccounts,
     GetRecentPerformanceSamples,
+    GetRecentPrioritizationFees,
     GetSignaturesForAddress,
     GetSignatureStatuses,
     GetSlot,
@@ -1,5 +1,15 @@
 from __future__ import annotations
-from typing import Sequence, List, Optional, TypeVar, Union, Tuple, Dict, Any
+from typing import (
+    Any,
+    Dict,
+    Generic,
+    List,
+    Optional,
+    Sequence,
+    Tuple,
+    TypeVar,
+    Union,
+)
 from solders.hash import Hash
 from solders.account import Account, AccountJSON
 from solders.account_decoder import UiTokenAmount
@@ -7,6 +17,7 @@ from solders.transaction_status import UiConfirmedBlock
 from solders.signature import Signature
 from solders.pubkey import Pubkey
 from solders.epoch_schedule import EpochSchedule
+from solders.rpc.filter import AccountFilter
 from solders.rpc.errors import (
     RpcCustomError,
     UnsupportedTransactionVersion,
@@ -67,12 +78,22 @@ RPCError = Union[
 T = TypeVar("T")
 Resp = Union[RPCError, T]
 
+class WithContext(Generic[T]):
+    context: RpcResponseContext
+    value: T
+    def __init__(self, value: T, context: RpcResponseContext) -> None: ...
+    @property
+    def slot(self) -> int: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
 class GetAccountInfoResp:
     context: RpcResponseContext
     value: Optional[Account]
     def __init__(
         self, value: Optional[Account], context: RpcResponseContext
     ) -> None: ...
+    def with_context(self) -> WithContext[Optional[Account]]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetAccountInfoResp]: ...
@@ -90,6 +111,7 @@ class GetAccountInfoJsonParsedResp:
     def __init__(
         self, value: Optional[AccountJSON], context: RpcResponseContext
     ) -> None: ...
+    def with_context(self) -> WithContext[Optional[AccountJSON]]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetAccountInfoJsonParsedResp]: ...
@@ -107,6 +129,7 @@ class GetAccountInfoMaybeJsonParsedResp:
     def __init__(
         self, value: Optional[Union[AccountJSON, Account]], context: RpcResponseContext
     ) -> None: ...
+    def with_context(self) -> WithContext[Optional[Union[AccountJSON, Account]]]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetAccountInfoMaybeJsonParsedResp]: ...
@@ -122,6 +145,7 @@ class GetBalanceResp:
     context: RpcResponseContext
     value: int
     def __init__(self, value: int, context: RpcResponseContext) -> None: ...
+    def with_context(self) -> WithContext[int]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetBalanceResp]: ...
@@ -139,6 +163,8 @@ class GetBlockCommitmentResp:
     def __init__(
         self, commitment: Optional[Sequence[int]], total_stake: int
     ) -> None: ...
+    def voted_stake(self, min_depth: int = 0) -> int: ...
+    def voted_stake_fraction(self, min_depth: int = 0) -> float: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetBlockCommitmentResp]: ...
@@ -215,6 +241,7 @@ class GetBlockProductionResp:
     ) -> None: ...
     @property
     def height(self) -> int: ...
+    def with_context(self) -> WithContext[RpcBlockProduction]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetBlockProductionResp]: ...
@@ -391,6 +418,7 @@ class GetFeeForMessageResp:
     context: RpcResponseContext
     value: Optional[int]
     def __init__(self, value: Optional[int], context: RpcResponseContext) -> None: ...
+    def with_context(self) -> WithContext[Optional[int]]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetFeeForMessageResp]: ...
@@ -686,6 +714,7 @@ class GetLargestAccountsResp:
     def __init__(
         self, value: Sequence[RpcAccountBalance], context: RpcResponseContext
     ) -> None: ...
+    def with_context(self) -> WithContext[List[RpcAccountBalance]]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetLargestAccountsResp]: ...
@@ -716,6 +745,7 @@ class GetLatestBlockhashResp:
     context: RpcResponseContext
     value: RpcBlockhash
     def __init__(self, value: RpcBlockhash, context: RpcResponseContext) -> None: ...
+    def with_context(self) -> WithContext[RpcBlockhash]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetLatestBlockhashResp]: ...
@@ -793,6 +823,7 @@ class GetMultipleAccountsResp:
     def __init__(
         self, value: Sequence[Optional[Account]], context: RpcResponseContext
     ) -> None: ...
+    def with_context(self) -> WithContext[List[Optional[Account]]]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetAccountInfoResp]: ...
@@ -810,6 +841,7 @@ class GetMultipleAccountsJsonParsedResp:
     def __init__(
         self, value: Sequence[Optional[AccountJSON]], context: RpcResponseContext
     ) -> None: ...
+    def with_context(self) -> WithContext[List[Optional[AccountJSON]]]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetMultipleAccountsJsonParsedResp]: ...
@@ -829,6 +861,9 @@ class GetMultipleAccountsMaybeJsonParsedResp:
         value: Sequence[Optional[Union[Account, AccountJSON]]],
         context: RpcResponseContext,
     ) -> None: ...
+    def with_context(
+        self,
+    ) -> WithContext[List[Optional[Union[Account, AccountJSON]]]]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetMultipleAccountsMaybeJsonParsedResp]: ...
@@ -876,6 +911,7 @@ class GetProgramAccountsWithContextResp:
     def __init__(
         self, value: Sequence[RpcKeyedAccount], context: RpcResponseContext
     ) -> None: ...
+    def with_context(self) -> WithContext[List[RpcKeyedAccount]]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetProgramAccountsWithContextResp]: ...
@@ -893,6 +929,7 @@ class GetProgramAccountsWithContextJsonParsedResp:
     def __init__(
         self, value: Sequence[RpcKeyedAccountJsonParsed], context: RpcResponseContext
     ) -> None: ...
+    def with_context(self) -> WithContext[List[RpcKeyedAccountJsonParsed]]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetProgramAccountsWithContextJsonParsedResp]: ...
@@ -942,6 +979,9 @@ class GetProgramAccountsWithContextMaybeJsonParsedResp:
         value: Sequence[Union[RpcKeyedAccountJsonParsed, RpcKeyedAccount]],
         context: RpcResponseContext,
     ) -> None: ...
+    def with_context(
+        self,
+    ) -> WithContext[List[Union[RpcKeyedAccountJsonParsed, RpcKeyedAccount]]]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(
@@ -1010,6 +1050,38 @@ class GetRecentPerformanceSamplesResp:
     def __bytes__(self) -> bytes: ...
     def __hash__(self) -> int: ...
 
+class RpcPrioritizationFee:
+    def __init__(self, slot: int, prioritization_fee: int) -> None: ...
+    @property
+    def slot(self) -> int: ...
+    @property
+    def prioritization_fee(self) -> int: ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(raw: str) -> RpcPrioritizationFee: ...
+    @staticmethod
+    def from_bytes(data: bytes) -> RpcPrioritizationFee: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+    def __bytes__(self) -> bytes: ...
+    def __hash__(self) -> int: ...
+
+class GetRecentPrioritizationFeesResp:
+    def __init__(self, value: Sequence[RpcPrioritizationFee]) -> None: ...
+    @property
+    def value(self) -> List[RpcPrioritizationFee]: ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(raw: str) -> Resp[GetRecentPrioritizationFeesResp]: ...
+    @staticmethod
+    def from_bytes(data: bytes) -> GetRecentPrioritizationFeesResp: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+    def __bytes__(self) -> bytes: ...
+    def __hash__(self) -> int: ...
+
 class RpcConfirmedTransactionStatusWithSignature:
     def __init__(
         self,
@@ -1066,6 +1138,7 @@ class GetSignatureStatusesResp:
     def __init__(
         self, value: Sequence[Optional[TransactionStatus]], context: RpcResponseContext
     ) -> None: ...
+    def with_context(self) -> WithContext[List[Optional[TransactionStatus]]]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetSignatureStatusesResp]: ...
@@ -1131,6 +1204,8 @@ class StakeActivationState:
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
     def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_string(s: str) -> "StakeActivationState": ...
 
 class RpcStakeActivation:
     def __init__(
@@ -1201,6 +1276,7 @@ class GetSupplyResp:
     def __init__(
         self, value: Sequence[Optional[TransactionStatus]], context: RpcResponseContext
     ) -> None: ...
+    def with_context(self) -> WithContext[RpcSupply]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetSupplyResp]: ...
@@ -1216,6 +1292,7 @@ class GetTokenAccountBalanceResp:
     context: RpcResponseContext
     value: UiTokenAmount
     def __init__(self, value: UiTokenAmount, context: RpcResponseContext) -> None: ...
+    def with_context(self) -> WithContext[UiTokenAmount]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetTokenAccountBalanceResp]: ...
@@ -1233,6 +1310,7 @@ class GetTokenAccountsByDelegateResp:
     def __init__(
         self, value: Sequence[RpcKeyedAccount], context: RpcResponseContext
     ) -> None: ...
+    def with_context(self) -> WithContext[List[RpcKeyedAccount]]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetTokenAccountsByDelegateResp]: ...
@@ -1250,6 +1328,7 @@ class GetTokenAccountsByDelegateJsonParsedResp:
     def __init__(
         self, value: Sequence[RpcKeyedAccountJsonParsed], context: RpcResponseContext
     ) -> None: ...
+    def with_context(self) -> WithContext[List[RpcKeyedAccountJsonParsed]]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetTokenAccountsByDelegateJsonParsedResp]: ...
@@ -1267,6 +1346,7 @@ class GetTokenAccountsByOwnerResp:
     def __init__(
         self, value: Sequence[RpcKeyedAccount], context: RpcResponseContext
     ) -> None: ...
+    def with_context(self) -> WithContext[List[RpcKeyedAccount]]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetTokenAccountsByOwnerResp]: ...
@@ -1284,6 +1364,7 @@ class GetTokenAccountsByOwnerJsonParsedResp:
     def __init__(
         self, value: Sequence[RpcKeyedAccountJsonParsed], context: RpcResponseContext
     ) -> None: ...
+    def with_context(self) -> WithContext[List[RpcKeyedAccountJsonParsed]]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetTokenAccountsByOwnerJsonParsedResp]: ...
@@ -1318,6 +1399,7 @@ class GetTokenLargestAccountsResp:
     def __init__(
         self, value: Sequence[RpcTokenAccountBalance], context: RpcResponseContext
     ) -> None: ...
+    def with_context(self) -> WithContext[List[RpcTokenAccountBalance]]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetTokenLargestAccountsResp]: ...
@@ -1333,6 +1415,7 @@ class GetTokenSupplyResp:
     context: RpcResponseContext
     value: UiTokenAmount
     def __init__(self, value: UiTokenAmount, context: RpcResponseContext) -> None: ...
+    def with_context(self) -> WithContext[UiTokenAmount]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetTokenSupplyResp]: ...
@@ -1487,6 +1570,7 @@ class IsBlockhashValidResp:
     context: RpcResponseContext
     value: bool
     def __init__(self, value: bool) -> None: ...
+    def with_context(self) -> WithContext[bool]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[IsBlockhashValidResp]: ...
@@ -1562,6 +1646,7 @@ class SimulateTransactionResp:
     context: RpcResponseContext
     value: RpcSimulateTransactionResult
     def __init__(self, value: RpcSimulateTransactionResult) -> None: ...
+    def with_context(self) -> WithContext[RpcSimulateTransactionResult]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[SimulateTransactionResp]: ...
@@ -1795,6 +1880,7 @@ class AccountNotificationResult:
     context: RpcResponseContext
     value: Account
     def __init__(self, value: Account, context: RpcResponseContext) -> None: ...
+    def with_context(self) -> WithContext[Account]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> AccountNotificationResult: ...
@@ -1812,6 +1898,7 @@ class AccountNotification:
     def __init__(
         self, result: AccountNotificationResult, subscription: int
     ) -> None: ...
+    def with_context(self) -> WithContext[Account]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> AccountNotification: ...
@@ -1827,6 +1914,7 @@ class AccountNotificationJsonParsedResult:
     context: RpcResponseContext
     value: AccountJSON
     def __init__(self, value: AccountJSON, context: RpcResponseContext) -> None: ...
+    def with_context(self) -> WithContext[AccountJSON]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> AccountNotificationJsonParsedResult: ...
@@ -1844,6 +1932,7 @@ class AccountNotificationJsonParsed:
     def __init__(
         self, result: AccountNotificationJsonParsedResult, subscription: int
     ) -> None: ...
+    def with_context(self) -> WithContext[AccountJSON]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> AccountNotificationJsonParsed: ...
@@ -1859,6 +1948,7 @@ class BlockNotificationResult:
     context: RpcResponseContext
     value: RpcBlockUpdate
     def __init__(self, value: RpcBlockUpdate, context: RpcResponseContext) -> None: ...
+    def with_context(self) -> WithContext[RpcBlockUpdate]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> BlockNotificationResult: ...
@@ -1874,6 +1964,7 @@ class BlockNotification:
     result: BlockNotificationResult
     subscription: int
     def __init__(self, result: BlockNotificationResult, subscription: int) -> None: ...
+    def with_context(self) -> WithContext[RpcBlockUpdate]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> BlockNotification: ...
@@ -1889,6 +1980,7 @@ class LogsNotificationResult:
     context: RpcResponseContext
     value: RpcLogsResponse
     def __init__(self, value: RpcLogsResponse, context: RpcResponseContext) -> None: ...
+    def with_context(self) -> WithContext[RpcLogsResponse]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> LogsNotificationResult: ...
@@ -1904,6 +1996,7 @@ class LogsNotification:
     result: LogsNotificationResult
     subscription: int
     def __init__(self, result: LogsNotificationResult, subscription: int) -> None: ...
+    def with_context(self) -> WithContext[RpcLogsResponse]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> LogsNotification: ...
@@ -1919,6 +2012,7 @@ class ProgramNotificationResult:
     context: RpcResponseContext
     value: RpcKeyedAccount
     def __init__(self, value: RpcKeyedAccount, context: RpcResponseContext) -> None: ...
+    def with_context(self) -> WithContext[RpcKeyedAccount]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> ProgramNotificationResult: ...
@@ -1936,6 +2030,7 @@ class ProgramNotification:
     def __init__(
         self, result: ProgramNotificationResult, subscription: int
     ) -> None: ...
+    def with_context(self) -> WithContext[RpcKeyedAccount]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> ProgramNotification: ...
@@ -1953,6 +2048,7 @@ class ProgramNotificationJsonParsedResult:
     def __init__(
         self, value: RpcKeyedAccountJsonParsed, context: RpcResponseContext
     ) -> None: ...
+    def with_context(self) -> WithContext[RpcKeyedAccountJsonParsed]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> ProgramNotificationJsonParsedResult: ...
@@ -1970,6 +2066,7 @@ class ProgramNotificationJsonParsed:
     def __init__(
         self, result: ProgramNotificationJsonParsedResult, subscription: int
     ) -> None: ...
+    def with_context(self) -> WithContext[RpcKeyedAccountJsonParsed]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> ProgramNotificationJsonParsed: ...
@@ -2004,6 +2101,7 @@ class SignatureNotificationResult:
     def __init__(
         self, value: RpcSignatureResponse, context: RpcResponseContext
     ) -> None: ...
+    def with_context(self) -> WithContext[RpcSignatureResponse]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> SignatureNotificationResult: ...
@@ -2021,6 +2119,7 @@ class SignatureNotification:
     def __init__(
         self, result: SignatureNotificationResult, subscription: int
     ) -> None: ...
+    def with_context(self) -> WithContext[RpcSignatureResponse]: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> SignatureNotification: ...
@@ -2249,6 +2348,7 @@ RPCResult = Union[
     GetProgramAccountsMaybeJsonParsedResp,
     GetProgramAccountsWithContextMaybeJsonParsedResp,
     GetRecentPerformanceSamplesResp,
+    GetRecentPrioritizationFeesResp,
     GetSignaturesForAddressResp,
     GetSignatureStatusesResp,
     GetSlotResp,
@@ -2277,5 +2377,32 @@ RPCResult = Union[
 
 def batch_to_json(resps: Sequence[RPCResult]) -> str: ...
 def batch_from_json(raw: str, parsers: Sequence[Any]) -> List[RPCResult]: ...
+
+class RespWithExtras:
+    @property
+    def value(self) -> RPCResult: ...
+    def extras(self) -> Dict[str, Any]: ...
+    @property
+    def has_extras(self) -> bool: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+def from_json_with_extras(raw: str, parser: Any) -> RespWithExtras: ...
 def parse_notification(raw: str) -> Notification: ...
-def parse_websocket_message(raw: str) -> List[WebsocketMessage]: ...
+def parse_websocket_message(
+    raw: str, filter: Optional[AccountFilter] = None
+) -> List[WebsocketMessage]: ...
+def parse_program_accounts(
+    raw: str, filter: AccountFilter
+) -> Union[RPCError, GetProgramAccountsResp, WithContext[List[RpcKeyedAccount]]]: ...
+def parse_token_accounts(
+    raw: str, filter: Optional[AccountFilter] = None
+) -> Union[RPCError, WithContext[List[RpcKeyedAccount]]]: ...
+def parse_account_notification(
+    msg: str,
+) -> Tuple[
+    int, WithContext[Union[Account, RpcKeyedAccount, RpcKeyedAccountJsonParsed]]
+]: ...
+def parse_block(
+    raw: str, filter: Optional[AccountFilter] = None, skip_votes: bool = False
+) -> Union[RPCError, GetBlockResp]: ...
@@ -0,0 +1,46 @@
+from typing import List, Optional, Sequence, Tuple
+from solders.pubkey import Pubkey
+from solders.rpc.responses import RpcBlockUpdate, RpcKeyedAccount, SlotInfo, SlotUpdate
+
+class SlotTrackerReport:
+    def __init__(
+        self,
+        gaps: List[Tuple[int, int]],
+        forks: List[Tuple[int, int]],
+        dead: List[int],
+    ) -> None: ...
+    @property
+    def gaps(self) -> List[Tuple[int, int]]: ...
+    @property
+    def forks(self) -> List[Tuple[int, int]]: ...
+    @property
+    def dead(self) -> List[int]: ...
+    def is_empty(self) -> bool: ...
+    def __bool__(self) -> bool: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+class SlotTracker:
+    def __init__(self) -> None: ...
+    @property
+    def processed(self) -> Optional[int]: ...
+    @property
+    def confirmed(self) -> Optional[int]: ...
+    @property
+    def finalized(self) -> Optional[int]: ...
+    def process_slot(self, info: SlotInfo) -> SlotTrackerReport: ...
+    def process_slot_update(self, update: SlotUpdate) -> SlotTrackerReport: ...
+    def process_root(self, root: int) -> SlotTrackerReport: ...
+    def process_block(self, update: RpcBlockUpdate) -> SlotTrackerReport: ...
+    def __repr__(self) -> str: ...
+
+class ProgramUpdateDeduper:
+    def __init__(self) -> None: ...
+    def process(self, keyed_account: RpcKeyedAccount) -> bool: ...
+    def filter(
+        self, keyed_accounts: Sequence[RpcKeyedAccount]
+    ) -> List[RpcKeyedAccount]: ...
+    def forget(self, pubkey: Pubkey) -> bool: ...
+    def clear(self) -> None: ...
+    def __len__(self) -> int: ...
+    def __repr__(self) -> str: ...
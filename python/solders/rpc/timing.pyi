@@ -0,0 +1,60 @@
+from typing import Dict, List, Optional, Sequence
+from solders.pubkey import Pubkey
+from solders.rpc.responses import EpochInfo, RpcPerfSample
+
+class BlockhashExpiry:
+    def __init__(
+        self,
+        seconds_per_slot: float,
+        blocks_remaining: int,
+        seconds_remaining: float,
+        resubmit_interval: float,
+    ) -> None: ...
+    @property
+    def seconds_per_slot(self) -> float: ...
+    @property
+    def blocks_remaining(self) -> int: ...
+    @property
+    def seconds_remaining(self) -> float: ...
+    @property
+    def resubmit_interval(self) -> float: ...
+    def is_expired(self) -> bool: ...
+    def max_resubmissions(self) -> int: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+def estimate_blockhash_expiry(
+    samples: Sequence[RpcPerfSample], block_height: int, last_valid_block_height: int
+) -> BlockhashExpiry: ...
+
+class EpochProgress:
+    def __init__(
+        self,
+        epoch: int,
+        slots_remaining: int,
+        seconds_remaining: float,
+        percent_complete: float,
+        next_epoch_start_slot: int,
+    ) -> None: ...
+    @property
+    def epoch(self) -> int: ...
+    @property
+    def slots_remaining(self) -> int: ...
+    @property
+    def seconds_remaining(self) -> float: ...
+    @property
+    def percent_complete(self) -> float: ...
+    @property
+    def next_epoch_start_slot(self) -> int: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+def epoch_progress(
+    epoch_info: EpochInfo, seconds_per_slot: float = 0.4
+) -> EpochProgress: ...
+def next_leader_slots(
+    epoch_info: EpochInfo,
+    leader_schedule: Dict[Pubkey, Sequence[int]],
+    identity: Pubkey,
+    limit: Optional[int] = None,
+) -> List[int]: ...
@@ -0,0 +1,40 @@
+from typing import List, Tuple, Union
+from solders.pubkey import Pubkey
+from solders.rpc.requests import GetClusterNodes, GetSlotLeaders
+from solders.rpc.responses import GetClusterNodesResp, GetSlotLeadersResp
+from solders.transaction import Transaction, VersionedTransaction
+
+DEFAULT_FANOUT_SLOTS: int
+MAX_FANOUT_SLOTS: int
+QUIC_PORT_OFFSET: int
+
+class TpuLeaderPlanner:
+    def __init__(
+        self,
+        fanout_slots: int = DEFAULT_FANOUT_SLOTS,
+        use_quic: bool = False,
+    ) -> None: ...
+    @property
+    def fanout_slots(self) -> int: ...
+    @property
+    def use_quic(self) -> bool: ...
+    def cluster_nodes_request(self, id: int = 0) -> GetClusterNodes: ...
+    def update_cluster_nodes(self, response: GetClusterNodesResp) -> int: ...
+    def leaders_request(self, start_slot: int, id: int = 0) -> GetSlotLeaders: ...
+    def update_leaders(
+        self, request: GetSlotLeaders, response: GetSlotLeadersResp
+    ) -> None: ...
+    def needs_leaders(self, current_slot: int) -> bool: ...
+    def leader_tpus(self, current_slot: int) -> List[Tuple[str, int]]: ...
+    def send_transaction(
+        self,
+        transaction: Union[Transaction, VersionedTransaction],
+        current_slot: int,
+        retries: int = 0,
+    ) -> List[Tuple[str, int]]: ...
+    def unknown_leaders(self, current_slot: int) -> List[Pubkey]: ...
+    def __repr__(self) -> str: ...
+
+def wire_transaction(
+    transaction: Union[Transaction, VersionedTransaction]
+) -> bytes: ...
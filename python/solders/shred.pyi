@@ -0,0 +1,66 @@
+from typing import List, Optional, Sequence
+from solders.entry import Entry
+from solders.hash import Hash
+from solders.pubkey import Pubkey
+from solders.signature import Signature
+
+class ShredType:
+    Data: "ShredType"
+    Code: "ShredType"
+    def __int__(self) -> int: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_string(s: str) -> "ShredType": ...
+
+class Shred:
+    @staticmethod
+    def from_bytes(data: bytes) -> "Shred": ...
+    @property
+    def signature(self) -> Signature: ...
+    @property
+    def shred_type(self) -> ShredType: ...
+    @property
+    def slot(self) -> int: ...
+    @property
+    def index(self) -> int: ...
+    @property
+    def version(self) -> int: ...
+    @property
+    def fec_set_index(self) -> int: ...
+    @property
+    def is_merkle(self) -> bool: ...
+    @property
+    def proof_size(self) -> Optional[int]: ...
+    @property
+    def chained(self) -> bool: ...
+    @property
+    def resigned(self) -> bool: ...
+    @property
+    def parent_slot(self) -> Optional[int]: ...
+    @property
+    def reference_tick(self) -> Optional[int]: ...
+    @property
+    def data_complete(self) -> bool: ...
+    @property
+    def last_in_slot(self) -> bool: ...
+    @property
+    def data(self) -> Optional[bytes]: ...
+    @property
+    def num_data_shreds(self) -> Optional[int]: ...
+    @property
+    def num_coding_shreds(self) -> Optional[int]: ...
+    @property
+    def position(self) -> Optional[int]: ...
+    @property
+    def merkle_root(self) -> Optional[Hash]: ...
+    @property
+    def chained_merkle_root(self) -> Optional[Hash]: ...
+    def verify(self, leader: Pubkey) -> bool: ...
+    def __bytes__(self) -> bytes: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+def deshred(shreds: Sequence[Shred]) -> bytes: ...
+def entries_from_shreds(shreds: Sequence[Shred]) -> List[Entry]: ...
@@ -12,6 +12,8 @@ class Signature:
     def from_string(s: str) -> "Signature": ...
     def verify(self, pubkey: Pubkey, message_bytes: bytes) -> bool: ...
     def to_bytes_array(self) -> List[int]: ...
+    def to_short_string(self, prefix_len: int = 4, suffix_len: int = 4) -> str: ...
+    def explorer_url(self, cluster: str = "mainnet-beta") -> str: ...
     def __bytes__(self) -> bytes: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -0,0 +1,88 @@
+from typing import Dict, List, Optional, Sequence, Union
+from solders.address_lookup_table_account import AddressLookupTableAccount
+from solders.hash import Hash
+from solders.keypair import Keypair
+from solders.message import Message, MessageV0
+from solders.presigner import Presigner
+from solders.pubkey import Pubkey
+from solders.signature import Signature
+from solders.transaction import Transaction, VersionedTransaction
+
+class SigningSession:
+    def __init__(
+        self, transaction: Union[Transaction, VersionedTransaction]
+    ) -> None: ...
+    @property
+    def message(self) -> Union[Message, MessageV0]: ...
+    @property
+    def message_bytes(self) -> bytes: ...
+    @property
+    def required_signers(self) -> List[Pubkey]: ...
+    @property
+    def signed(self) -> List[Pubkey]: ...
+    @property
+    def missing(self) -> List[Pubkey]: ...
+    @property
+    def is_complete(self) -> bool: ...
+    def add_signature(self, pubkey: Pubkey, signature: Signature) -> None: ...
+    def sign(self, signers: Sequence[Union[Keypair, Presigner]]) -> None: ...
+    def partial_transaction(self) -> VersionedTransaction: ...
+    def transaction(self) -> VersionedTransaction: ...
+    def __repr__(self) -> str: ...
+
+class RebuiltTransaction:
+    @property
+    def session(self) -> SigningSession: ...
+    @property
+    def still_valid(self) -> List[Pubkey]: ...
+    @property
+    def resign(self) -> List[Pubkey]: ...
+    @property
+    def new_signers(self) -> List[Pubkey]: ...
+    @property
+    def dropped_signers(self) -> List[Pubkey]: ...
+    def __repr__(self) -> str: ...
+
+def rebuild_transaction(
+    transaction: Union[Transaction, VersionedTransaction],
+    recent_blockhash: Hash,
+    fee_payer: Optional[Pubkey] = None,
+) -> RebuiltTransaction: ...
+
+class SigningPackage:
+    def __init__(
+        self,
+        transactions: Sequence[Union[Transaction, VersionedTransaction]],
+        address_lookup_table_accounts: Optional[
+            Sequence[AddressLookupTableAccount]
+        ] = None,
+        metadata: Optional[Dict[str, str]] = None,
+    ) -> None: ...
+    @property
+    def transactions(self) -> List[VersionedTransaction]: ...
+    @property
+    def address_lookup_table_accounts(self) -> List[AddressLookupTableAccount]: ...
+    @property
+    def metadata(self) -> Dict[str, str]: ...
+    @property
+    def required_signers(self) -> List[List[Pubkey]]: ...
+    @property
+    def missing(self) -> List[Pubkey]: ...
+    @property
+    def is_complete(self) -> bool: ...
+    def sign(self, signers: Sequence[Union[Keypair, Presigner]]) -> int: ...
+    def add_signature(
+        self, index: int, pubkey: Pubkey, signature: Signature
+    ) -> None: ...
+    def merge(self, other: "SigningPackage") -> None: ...
+    def signed_transactions(self) -> List[VersionedTransaction]: ...
+    def __len__(self) -> int: ...
+    def __bytes__(self) -> bytes: ...
+    @staticmethod
+    def from_bytes(data: bytes) -> "SigningPackage": ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(raw: str) -> "SigningPackage": ...
+    def __richcmp__(self, other: "SigningPackage", op: int) -> bool: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
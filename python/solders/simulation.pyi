@@ -0,0 +1,109 @@
+from typing import Any, Callable, List, Optional, Sequence, Tuple, Union
+from solders.accounts_map import AccountsMap
+from solders.address_lookup_table_account import AddressLookupTableAccount
+from solders.message import Message, MessageV0
+from solders.pubkey import Pubkey
+from solders.rent import Rent
+from solders.rpc.responses import RpcSimulateTransactionResult, SimulateTransactionResp
+from solders.transaction import VersionedTransaction
+from solders.transaction_status import TransactionErrorType
+
+class LocalSimulationResult:
+    def __init__(
+        self,
+        err: Optional[TransactionErrorType],
+        fee: int,
+        accounts: AccountsMap,
+        unsimulated_instructions: Sequence[int],
+    ) -> None: ...
+    @property
+    def err(self) -> Optional[TransactionErrorType]: ...
+    @property
+    def fee(self) -> int: ...
+    @property
+    def accounts(self) -> AccountsMap: ...
+    @property
+    def unsimulated_instructions(self) -> List[int]: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+def simulate_locally(
+    tx: VersionedTransaction,
+    accounts: AccountsMap,
+    feature_set: Optional[Sequence[Pubkey]] = None,
+    lamports_per_signature: int = 5000,
+    rent: Optional[Rent] = None,
+    address_lookup_table_accounts: Optional[Sequence[AddressLookupTableAccount]] = None,
+) -> LocalSimulationResult: ...
+
+class RuntimeParams:
+    def __init__(
+        self,
+        rent: Optional[Rent] = None,
+        lamports_per_signature: int = 5000,
+        feature_set: Optional[Sequence[Pubkey]] = None,
+    ) -> None: ...
+    @property
+    def rent(self) -> Rent: ...
+    @property
+    def lamports_per_signature(self) -> int: ...
+    @property
+    def feature_set(self) -> Optional[List[Pubkey]]: ...
+    def is_active(self, feature_id: Pubkey) -> bool: ...
+    def with_activated(self, feature_ids: Sequence[Pubkey]) -> "RuntimeParams": ...
+    @property
+    def require_rent_exempt_accounts(self) -> bool: ...
+    @property
+    def default_instruction_compute_unit_limit(self) -> int: ...
+    @property
+    def max_compute_unit_limit(self) -> int: ...
+    @property
+    def default_heap_frame_bytes(self) -> int: ...
+    def minimum_balance(self, data_len: int) -> int: ...
+    def calculate_fee(self, message: Union[Message, MessageV0]) -> int: ...
+    def simulate(
+        self,
+        tx: VersionedTransaction,
+        accounts: AccountsMap,
+        address_lookup_table_accounts: Optional[
+            Sequence[AddressLookupTableAccount]
+        ] = None,
+    ) -> LocalSimulationResult: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+class ComputeUnitHistogram:
+    def __init__(self) -> None: ...
+    def record(
+        self,
+        sample: Union[int, RpcSimulateTransactionResult, SimulateTransactionResp],
+    ) -> None: ...
+    def record_all(
+        self,
+        samples: Sequence[
+            Union[int, RpcSimulateTransactionResult, SimulateTransactionResp]
+        ],
+    ) -> None: ...
+    def run(
+        self,
+        inputs: Sequence[Any],
+        execute: Callable[
+            [Any], Union[int, RpcSimulateTransactionResult, SimulateTransactionResp]
+        ],
+    ) -> None: ...
+    @property
+    def samples(self) -> List[int]: ...
+    @property
+    def failures(self) -> int: ...
+    @property
+    def min(self) -> Optional[int]: ...
+    @property
+    def max(self) -> Optional[int]: ...
+    @property
+    def mean(self) -> Optional[float]: ...
+    def percentile(self, p: float) -> Optional[int]: ...
+    def buckets(self, width: int) -> List[Tuple[int, int]]: ...
+    def exceeding(self, limit: int) -> int: ...
+    def __len__(self) -> int: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
@@ -0,0 +1,28 @@
+from typing import Dict, Iterable, Iterator, List, Optional, Sequence
+from solders.account import Account
+from solders.hash import Hash
+from solders.pubkey import Pubkey
+
+class StoredAccount:
+    @property
+    def pubkey(self) -> Pubkey: ...
+    @property
+    def account(self) -> Account: ...
+    @property
+    def slot(self) -> int: ...
+    @property
+    def write_version(self) -> int: ...
+    @property
+    def hash(self) -> Hash: ...
+    def __eq__(self, o: object) -> bool: ...
+    def __repr__(self) -> str: ...
+
+class SnapshotReader:
+    def __init__(self, path: str, owners: Optional[Sequence[Pubkey]] = None) -> None: ...
+    def __iter__(self) -> Iterator[StoredAccount]: ...
+    def __next__(self) -> StoredAccount: ...
+
+def read_append_vec(data: bytes, slot: int) -> List[StoredAccount]: ...
+def latest_accounts(
+    stored: Iterable[StoredAccount], owners: Optional[Sequence[Pubkey]] = None
+) -> Dict[Pubkey, Account]: ...
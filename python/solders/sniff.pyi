@@ -0,0 +1,44 @@
+from typing import List, Optional, Sequence
+from solders.pubkey import Pubkey
+
+class LayoutKind:
+    Anchor: "LayoutKind"
+    TokenAccount: "LayoutKind"
+    Mint: "LayoutKind"
+    TokenMultisig: "LayoutKind"
+    Nonce: "LayoutKind"
+    LookupTable: "LayoutKind"
+    Stake: "LayoutKind"
+    Vote: "LayoutKind"
+    Borsh: "LayoutKind"
+    def __int__(self) -> int: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_string(s: str) -> "LayoutKind": ...
+
+class LayoutCandidate:
+    def __init__(
+        self,
+        kind: LayoutKind,
+        confidence: float,
+        name: Optional[str],
+        notes: Sequence[str],
+    ) -> None: ...
+    @property
+    def kind(self) -> LayoutKind: ...
+    @property
+    def confidence(self) -> float: ...
+    @property
+    def name(self) -> Optional[str]: ...
+    @property
+    def notes(self) -> List[str]: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+def sniff_layout(
+    account_data: bytes,
+    owner: Optional[Pubkey] = None,
+    anchor_names: Optional[Sequence[str]] = None,
+) -> List[LayoutCandidate]: ...
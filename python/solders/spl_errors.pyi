@@ -0,0 +1,9 @@
+from typing import Optional, Sequence
+from solders.pubkey import Pubkey
+from solders.transaction_status import TransactionErrorType
+
+def custom_error_name(program_id: Pubkey, code: int) -> Optional[str]: ...
+def custom_error_message(program_id: Pubkey, code: int) -> Optional[str]: ...
+def describe_transaction_error(
+    err: TransactionErrorType, log_messages: Sequence[str]
+) -> str: ...
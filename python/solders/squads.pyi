@@ -0,0 +1,122 @@
+from typing import List, Optional, Sequence, Tuple
+from solders.account import Account
+from solders.address_lookup_table_account import AddressLookupTableAccount
+from solders.instruction import Instruction
+from solders.pubkey import Pubkey
+
+SQUADS_PROGRAM_ID: Pubkey
+
+class Member:
+    def __init__(self, key: Pubkey, permissions: int) -> None: ...
+    @property
+    def key(self) -> Pubkey: ...
+    @property
+    def permissions(self) -> int: ...
+    @property
+    def can_initiate(self) -> bool: ...
+    @property
+    def can_vote(self) -> bool: ...
+    @property
+    def can_execute(self) -> bool: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+class Multisig:
+    @staticmethod
+    def from_account(account: Account) -> "Multisig": ...
+    @property
+    def create_key(self) -> Pubkey: ...
+    @property
+    def config_authority(self) -> Pubkey: ...
+    @property
+    def threshold(self) -> int: ...
+    @property
+    def time_lock(self) -> int: ...
+    @property
+    def transaction_index(self) -> int: ...
+    @property
+    def stale_transaction_index(self) -> int: ...
+    @property
+    def rent_collector(self) -> Optional[Pubkey]: ...
+    @property
+    def members(self) -> List[Member]: ...
+    def member(self, key: Pubkey) -> Optional[Member]: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+class ProposalStatus:
+    Draft: "ProposalStatus"
+    Active: "ProposalStatus"
+    Rejected: "ProposalStatus"
+    Approved: "ProposalStatus"
+    Executing: "ProposalStatus"
+    Executed: "ProposalStatus"
+    Cancelled: "ProposalStatus"
+    def __int__(self) -> int: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_string(s: str) -> "ProposalStatus": ...
+
+class Proposal:
+    @staticmethod
+    def from_account(account: Account) -> "Proposal": ...
+    @property
+    def multisig(self) -> Pubkey: ...
+    @property
+    def transaction_index(self) -> int: ...
+    @property
+    def status(self) -> ProposalStatus: ...
+    @property
+    def status_timestamp(self) -> Optional[int]: ...
+    @property
+    def approved(self) -> List[Pubkey]: ...
+    @property
+    def rejected(self) -> List[Pubkey]: ...
+    @property
+    def cancelled(self) -> List[Pubkey]: ...
+    def is_executable(self, multisig: Multisig) -> bool: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+class VaultTransaction:
+    @staticmethod
+    def from_account(account: Account) -> "VaultTransaction": ...
+    @property
+    def multisig(self) -> Pubkey: ...
+    @property
+    def creator(self) -> Pubkey: ...
+    @property
+    def index(self) -> int: ...
+    @property
+    def vault_index(self) -> int: ...
+    @property
+    def account_keys(self) -> List[Pubkey]: ...
+    @property
+    def address_lookup_tables(self) -> List[Pubkey]: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+def multisig_pda(create_key: Pubkey) -> Tuple[Pubkey, int]: ...
+def vault_pda(multisig: Pubkey, vault_index: int = 0) -> Tuple[Pubkey, int]: ...
+def transaction_pda(multisig: Pubkey, transaction_index: int) -> Tuple[Pubkey, int]: ...
+def proposal_pda(multisig: Pubkey, transaction_index: int) -> Tuple[Pubkey, int]: ...
+def proposal_create(
+    multisig: Pubkey,
+    transaction_index: int,
+    creator: Pubkey,
+    rent_payer: Optional[Pubkey] = None,
+    draft: bool = False,
+) -> Instruction: ...
+def proposal_approve(
+    multisig: Pubkey, transaction_index: int, member: Pubkey, memo: Optional[str] = None
+) -> Instruction: ...
+def proposal_reject(
+    multisig: Pubkey, transaction_index: int, member: Pubkey, memo: Optional[str] = None
+) -> Instruction: ...
+def vault_transaction_execute(
+    transaction: VaultTransaction,
+    member: Pubkey,
+    address_lookup_table_accounts: Optional[Sequence[AddressLookupTableAccount]] = None,
+) -> Instruction: ...
@@ -0,0 +1,57 @@
+from typing import Optional
+from solders.account import Account
+from solders.clock import Clock
+from solders.pubkey import Pubkey
+
+class StakeLifecycleState:
+    Uninitialized: "StakeLifecycleState"
+    Initialized: "StakeLifecycleState"
+    Activating: "StakeLifecycleState"
+    Active: "StakeLifecycleState"
+    Deactivating: "StakeLifecycleState"
+    Inactive: "StakeLifecycleState"
+    def __int__(self) -> int: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_string(s: str) -> "StakeLifecycleState": ...
+
+class StakeLifecycle:
+    def __init__(
+        self,
+        state: StakeLifecycleState,
+        voter: Optional[Pubkey],
+        delegated_stake: int,
+        effective: int,
+        activating: int,
+        deactivating: int,
+        locked_until_epoch: Optional[int],
+        locked_until_timestamp: Optional[int],
+    ) -> None: ...
+    @property
+    def state(self) -> StakeLifecycleState: ...
+    @property
+    def voter(self) -> Optional[Pubkey]: ...
+    @property
+    def delegated_stake(self) -> int: ...
+    @property
+    def effective(self) -> int: ...
+    @property
+    def activating(self) -> int: ...
+    @property
+    def deactivating(self) -> int: ...
+    @property
+    def locked_until_epoch(self) -> Optional[int]: ...
+    @property
+    def locked_until_timestamp(self) -> Optional[int]: ...
+    @property
+    def progress(self) -> float: ...
+    @property
+    def is_locked(self) -> bool: ...
+    def __eq__(self, o: object) -> bool: ...
+    def __repr__(self) -> str: ...
+
+def stake_lifecycle(
+    stake_account: Account, clock: Clock, stake_history: Optional[Account] = None
+) -> StakeLifecycle: ...
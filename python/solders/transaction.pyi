@@ -6,6 +6,7 @@ from solders.signature import Signature
 from solders.instruction import Instruction, CompiledInstruction
 from solders.pubkey import Pubkey
 from solders.hash import Hash
+from solders.address_lookup_table_account import AddressLookupTableAccount
 
 class Transaction:
     def __init__(
@@ -69,6 +70,12 @@ class Transaction:
         self, signers: Sequence[Tuple[Pubkey, Signature]]
     ) -> None: ...
     def is_signed(self) -> bool: ...
+    def signatures_match_message(self) -> bool: ...
+    def freeze(self) -> None: ...
+    @property
+    def is_frozen(self) -> bool: ...
+    @staticmethod
+    def from_bytes_frozen(data: bytes, frozen: bool = True) -> "Transaction": ...
     def uses_durable_nonce(self) -> Optional[CompiledInstruction]: ...
     def sanitize(self) -> None: ...
     def __bytes__(self) -> bytes: ...
@@ -92,6 +99,29 @@ class Legacy:
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
     def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_string(s: str) -> "Legacy": ...
+
+class TransactionVersion:
+    LEGACY: "TransactionVersion"
+    V0: "TransactionVersion"
+    def __init__(self, version: "TransactionVersionLike") -> None: ...
+    @property
+    def is_legacy(self) -> bool: ...
+    @property
+    def number(self) -> Optional[int]: ...
+    def __bytes__(self) -> bytes: ...
+    @staticmethod
+    def from_bytes(data: bytes) -> "TransactionVersion": ...
+    def __eq__(self, o: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(raw: str) -> "TransactionVersion": ...
+
+TransactionVersionLike = Union[TransactionVersion, Legacy, int, str]
 
 class VersionedTransaction:
     def __init__(
@@ -103,15 +133,38 @@ class VersionedTransaction:
     def signatures(self) -> List[Signature]: ...
     @property
     def message(self) -> Message: ...
+    @property
+    def last_valid_block_height(self) -> Optional[int]: ...
+    @last_valid_block_height.setter
+    def last_valid_block_height(self, last_valid_block_height: Optional[int]) -> None: ...
+    @property
+    def created_at(self) -> Optional[int]: ...
+    @created_at.setter
+    def created_at(self, created_at: Optional[int]) -> None: ...
+    def freeze(self) -> None: ...
+    @property
+    def is_frozen(self) -> bool: ...
+    def is_expired(self, block_height: int) -> Optional[bool]: ...
+    @staticmethod
+    def from_bytes_with_metadata(
+        data: bytes,
+        last_valid_block_height: Optional[int] = None,
+        created_at: Optional[int] = None,
+        frozen: bool = False,
+    ) -> "VersionedTransaction": ...
     @staticmethod
     def populate(
         message: Union[Message, MessageV0], signatures: Sequence[Signature]
     ) -> "VersionedTransaction": ...
     def verify_and_hash_message(self) -> Hash: ...
     def verify_with_results(self) -> List[bool]: ...
+    def signatures_match_message(self) -> bool: ...
     def sanitize(self) -> None: ...
     def version(self) -> TransactionVersion: ...
     def into_legacy_transaction(self) -> Optional[Transaction]: ...
+    def expand_to_legacy_transaction(
+        self, address_lookup_table_accounts: Sequence[AddressLookupTableAccount]
+    ) -> Transaction: ...
     def __bytes__(self) -> bytes: ...
     @staticmethod
     def default() -> "VersionedTransaction": ...
@@ -126,5 +179,3 @@ class VersionedTransaction:
 
 class SanitizeError(Exception): ...
 class TransactionError(Exception): ...
-
-TransactionVersion = Union[Legacy, int]
@@ -1,4 +1,4 @@
-from typing import Sequence, Optional, List, Union, Tuple, Dict
+from typing import Sequence, Optional, List, Union, Tuple, Dict, Any
 from jsonalias import Json
 from solders.hash import Hash
 from solders.pubkey import Pubkey
@@ -6,7 +6,11 @@ from solders.message import MessageHeader
 from solders.signature import Signature
 from solders.account_decoder import UiTokenAmount
 from solders.commitment_config import CommitmentConfig
-from solders.transaction import TransactionVersion, VersionedTransaction
+from solders.transaction import (
+    TransactionVersion,
+    TransactionVersionLike,
+    VersionedTransaction,
+)
 
 class UiTransactionEncoding:
     Binary: "UiTransactionEncoding"
@@ -18,6 +22,8 @@ class UiTransactionEncoding:
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
     def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_string(s: str) -> "UiTransactionEncoding": ...
 
 class TransactionDetails:
     Full: "TransactionDetails"
@@ -27,6 +33,8 @@ class TransactionDetails:
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
     def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_string(s: str) -> "TransactionDetails": ...
 
 class TransactionBinaryEncoding:
     Base58: "TransactionBinaryEncoding"
@@ -35,6 +43,8 @@ class TransactionBinaryEncoding:
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
     def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_string(s: str) -> "TransactionBinaryEncoding": ...
 
 class UiCompiledInstruction:
     def __init__(self, program_id_index: int, accounts: bytes, data: str) -> None: ...
@@ -319,6 +329,8 @@ class RewardType:
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
     def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_string(s: str) -> "RewardType": ...
 
 class TransactionReturnData:
     def __init__(self, program_id: Pubkey, data: Sequence[int]) -> None: ...
@@ -396,7 +408,7 @@ class EncodedTransactionWithStatusMeta:
         self,
         transaction: EncodedVersionedTransaction,
         meta: Optional[UiTransactionStatusMeta],
-        version: Optional[TransactionVersion],
+        version: Optional[TransactionVersionLike],
     ) -> None: ...
     @property
     def transaction(self) -> EncodedVersionedTransaction: ...
@@ -404,6 +416,7 @@ class EncodedTransactionWithStatusMeta:
     def meta(self) -> Optional[UiTransactionStatusMeta]: ...
     @property
     def version(self) -> Optional[TransactionVersion]: ...
+    def is_vote(self) -> bool: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
     def __bytes__(self) -> bytes: ...
@@ -657,6 +670,8 @@ class TransactionConfirmationStatus:
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
     def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_string(s: str) -> "TransactionConfirmationStatus": ...
 
 class TransactionStatus:
     def __init__(
@@ -740,6 +755,7 @@ class UiConfirmedBlock:
         block_time: Optional[int] = None,
         block_height: Optional[int] = None,
     ) -> None: ...
+    def without_votes(self) -> UiConfirmedBlock: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> UiConfirmedBlock: ...
@@ -750,3 +766,78 @@ class UiConfirmedBlock:
     def __eq__(self, o: object) -> bool: ...
     def __bytes__(self) -> bytes: ...
     def __hash__(self) -> int: ...
+
+class ProgramUsage:
+    invocations: int
+    inner_invocations: int
+    transactions: int
+    failed_transactions: int
+    compute_units: int
+    def __init__(
+        self,
+        invocations: int,
+        inner_invocations: int,
+        transactions: int,
+        failed_transactions: int,
+        compute_units: int,
+    ) -> None: ...
+    @property
+    def failure_rate(self) -> float: ...
+    @property
+    def mean_compute_units(self) -> Optional[int]: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+class ProgramUsageStats:
+    def __init__(self, skip_votes: bool = False) -> None: ...
+    @property
+    def blocks(self) -> int: ...
+    @property
+    def transactions(self) -> int: ...
+    def add_block(self, block: UiConfirmedBlock) -> None: ...
+    def add_blocks(self, blocks: Sequence[UiConfirmedBlock]) -> None: ...
+    def get(self, program_id: Pubkey) -> Optional[ProgramUsage]: ...
+    def programs(self) -> Dict[Pubkey, ProgramUsage]: ...
+    def top(self, n: int) -> List[Tuple[Pubkey, ProgramUsage]]: ...
+    def __len__(self) -> int: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+def transactions_to_columns(
+    transactions: Sequence[EncodedConfirmedTransactionWithStatusMeta],
+) -> Dict[str, List[Any]]: ...
+def block_fee_summary(
+    block: UiConfirmedBlock, lamports_per_signature: int = 5000
+) -> Dict[str, Any]: ...
+def address_index(
+    transactions: Sequence[EncodedTransactionWithStatusMeta],
+) -> Dict[Pubkey, List[Signature]]: ...
+
+class LamportConservation:
+    def __init__(
+        self,
+        pre_total: int,
+        post_total: int,
+        fee: int,
+        rewards_total: int,
+        imbalance: int,
+        anomalies: Sequence[str],
+    ) -> None: ...
+    @property
+    def pre_total(self) -> int: ...
+    @property
+    def post_total(self) -> int: ...
+    @property
+    def fee(self) -> int: ...
+    @property
+    def rewards_total(self) -> int: ...
+    @property
+    def imbalance(self) -> int: ...
+    @property
+    def anomalies(self) -> List[str]: ...
+    @property
+    def is_conserved(self) -> bool: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+def check_lamport_conservation(meta: UiTransactionStatusMeta) -> LamportConservation: ...
@@ -0,0 +1,57 @@
+from typing import Dict, List, Optional, Sequence
+from solders.pubkey import Pubkey
+from solders.transaction import VersionedTransaction
+
+class TransferKind:
+    Transfer: "TransferKind"
+    Approve: "TransferKind"
+    CloseAccount: "TransferKind"
+    SetAuthority: "TransferKind"
+    def __int__(self) -> int: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+    @staticmethod
+    def from_string(s: str) -> "TransferKind": ...
+
+class Transfer:
+    def __init__(
+        self,
+        instruction_index: int,
+        program_id: Pubkey,
+        source: Pubkey,
+        destination: Pubkey,
+        authority: Pubkey,
+        amount: int,
+        mint: Optional[Pubkey],
+        decimals: Optional[int],
+        kind: TransferKind = TransferKind.Transfer,
+    ) -> None: ...
+    @property
+    def instruction_index(self) -> int: ...
+    @property
+    def program_id(self) -> Pubkey: ...
+    @property
+    def source(self) -> Pubkey: ...
+    @property
+    def destination(self) -> Pubkey: ...
+    @property
+    def authority(self) -> Pubkey: ...
+    @property
+    def amount(self) -> int: ...
+    @property
+    def mint(self) -> Optional[Pubkey]: ...
+    @property
+    def decimals(self) -> Optional[int]: ...
+    @property
+    def kind(self) -> TransferKind: ...
+    @property
+    def is_native(self) -> bool: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+def summarize_transfers(
+    tx: VersionedTransaction,
+    account_keys: Optional[Sequence[Pubkey]] = None,
+    token_mints: Optional[Dict[Pubkey, Pubkey]] = None,
+) -> List[Transfer]: ...
@@ -0,0 +1,64 @@
+from typing import List, Optional, Sequence, Tuple, Union
+from solders.message import Message, MessageV0
+from solders.pubkey import Pubkey
+from solders.signature import Signature
+from solders.transaction import Transaction, VersionedTransaction
+
+def encode_transaction(
+    transaction: Union[Transaction, VersionedTransaction]
+) -> str: ...
+def encode_transactions(
+    transactions: Sequence[Union[Transaction, VersionedTransaction]]
+) -> List[str]: ...
+def decode_signed_transaction(
+    payload: str, expected_message: Optional[Union[Message, MessageV0]] = None
+) -> VersionedTransaction: ...
+def decode_signed_transactions(
+    payloads: Sequence[str],
+    expected_messages: Optional[Sequence[Union[Message, MessageV0]]] = None,
+) -> List[VersionedTransaction]: ...
+def verify_signed_message(
+    pubkey: Pubkey,
+    signed_message: bytes,
+    signature: bytes,
+    expected_message: Optional[bytes] = None,
+) -> Signature: ...
+
+class AddressValidation:
+    def __init__(
+        self,
+        address: str,
+        decoded_length: Optional[int],
+        pubkey: Optional[Pubkey],
+        is_on_curve: bool,
+        known_name: Optional[str],
+        associated_token_account_of: Optional[Tuple[Pubkey, Pubkey]],
+    ) -> None: ...
+    @property
+    def address(self) -> str: ...
+    @property
+    def decoded_length(self) -> Optional[int]: ...
+    @property
+    def pubkey(self) -> Optional[Pubkey]: ...
+    @property
+    def is_on_curve(self) -> bool: ...
+    @property
+    def known_name(self) -> Optional[str]: ...
+    @property
+    def associated_token_account_of(self) -> Optional[Tuple[Pubkey, Pubkey]]: ...
+    @property
+    def is_base58(self) -> bool: ...
+    @property
+    def is_valid(self) -> bool: ...
+    @property
+    def is_wallet(self) -> bool: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+def validate_address(
+    address: str, ata_candidates: Optional[Sequence[Tuple[Pubkey, Pubkey]]] = None
+) -> AddressValidation: ...
+def validate_addresses(
+    addresses: Sequence[str],
+    ata_candidates: Optional[Sequence[Tuple[Pubkey, Pubkey]]] = None,
+) -> List[AddressValidation]: ...
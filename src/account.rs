@@ -4,16 +4,30 @@ use std::str::FromStr;
 use derive_more::{From, Into};
 use pyo3::{prelude::*, types::PyBytes};
 use serde::{Deserialize, Serialize};
-use solana_sdk::{account::Account as AccountOriginal, clock::Epoch};
+use solana_sdk::{
+    account::{Account as AccountOriginal, ReadableAccount, WritableAccount},
+    bpf_loader, bpf_loader_deprecated,
+    bpf_loader_upgradeable::{self, UpgradeableLoaderState},
+    clock::Epoch,
+    hash::hash,
+    native_loader,
+    pubkey::Pubkey as PubkeyOriginal,
+};
 use solders_macros::{common_methods, richcmp_eq_only};
-use solders_primitives::pubkey::Pubkey;
+use solders_primitives::{hash::Hash as SolderHash, pubkey::Pubkey};
 use solders_traits::{
-    impl_display, py_from_bytes_general_via_bincode, pybytes_general_via_bincode,
+    impl_display, py_from_bytes_general_via_bincode, pybytes_general_via_bincode, to_py_value_err,
     RichcmpEqualityOnly,
 };
 
 use crate::{
     account_decoder::ParsedAccount,
+    layouts::{
+        MINT_IS_INITIALIZED_OFFSET, MINT_LEN, MULTISIG_LEN, TOKEN_2022_ACCOUNT_TYPE_ACCOUNT,
+        TOKEN_2022_ACCOUNT_TYPE_MINT, TOKEN_ACCOUNT_LEN, TOKEN_ACCOUNT_MINT_OFFSET,
+        TOKEN_ACCOUNT_OWNER_OFFSET, TOKEN_ACCOUNT_STATE_OFFSET,
+    },
+    spl_errors::{TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID},
     tmp_account_decoder::{UiAccount, UiAccountData, UiAccountEncoding},
 };
 
@@ -71,6 +85,33 @@ impl Account {
         self.0.owner.into()
     }
 
+    /// Get a slice of the account data without copying the rest of it.
+    ///
+    /// Unlike ``account.data[offset:offset + length]``, only the requested bytes are
+    /// copied out of the Rust-owned buffer. Out-of-range slices are truncated
+    /// the same way as the RPC ``dataSlice`` option.
+    ///
+    /// Args:
+    ///     offset (int): Where the slice starts.
+    ///     length (int): The maximum length of the slice.
+    ///
+    /// Returns:
+    ///     bytes: The requested data.
+    ///
+    /// Example:
+    ///     >>> from solders.account import Account
+    ///     >>> from solders.pubkey import Pubkey
+    ///     >>> account = Account(1, bytes(range(10)), Pubkey.default())
+    ///     >>> account.data_slice(8, 4)
+    ///     b'\x08\t'
+    ///
+    pub fn data_slice<'a>(&self, py: Python<'a>, offset: usize, length: usize) -> &'a PyBytes {
+        let data = &self.0.data;
+        let start = offset.min(data.len());
+        let end = offset.saturating_add(length).min(data.len());
+        PyBytes::new(py, &data[start..end])
+    }
+
     /// Whether this account's data contains a loaded program (and is now read-only).
     #[getter]
     pub fn executable(&self) -> bool {
@@ -102,6 +143,70 @@ py_from_bytes_general_via_bincode!(Account);
 solders_traits::common_methods_default!(Account);
 impl RichcmpEqualityOnly for Account {}
 
+impl AsRef<AccountOriginal> for Account {
+    fn as_ref(&self) -> &AccountOriginal {
+        &self.0
+    }
+}
+
+impl ReadableAccount for Account {
+    fn lamports(&self) -> u64 {
+        self.0.lamports
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.0.data
+    }
+
+    fn owner(&self) -> &PubkeyOriginal {
+        &self.0.owner
+    }
+
+    fn executable(&self) -> bool {
+        self.0.executable
+    }
+
+    fn rent_epoch(&self) -> Epoch {
+        self.0.rent_epoch
+    }
+}
+
+impl WritableAccount for Account {
+    fn set_lamports(&mut self, lamports: u64) {
+        self.0.set_lamports(lamports)
+    }
+
+    fn data_as_mut_slice(&mut self) -> &mut [u8] {
+        self.0.data_as_mut_slice()
+    }
+
+    fn set_owner(&mut self, owner: PubkeyOriginal) {
+        self.0.set_owner(owner)
+    }
+
+    fn copy_into_owner_from_slice(&mut self, source: &[u8]) {
+        self.0.copy_into_owner_from_slice(source)
+    }
+
+    fn set_executable(&mut self, executable: bool) {
+        self.0.set_executable(executable)
+    }
+
+    fn set_rent_epoch(&mut self, epoch: Epoch) {
+        self.0.set_rent_epoch(epoch)
+    }
+
+    fn create(
+        lamports: u64,
+        data: Vec<u8>,
+        owner: PubkeyOriginal,
+        executable: bool,
+        rent_epoch: Epoch,
+    ) -> Self {
+        AccountOriginal::create(lamports, data, owner, executable, rent_epoch).into()
+    }
+}
+
 impl TryFrom<UiAccount> for Account {
     type Error = &'static str;
     fn try_from(acc: UiAccount) -> Result<Self, Self::Error> {
@@ -221,9 +326,436 @@ impl From<AccountJSON> for UiAccount {
     }
 }
 
+/// Check the owner and size of an SPL Token or Token-2022 account.
+/// ``account_type`` is the Token-2022 type byte that follows the base state in extended accounts.
+fn check_token_layout(account: &Account, base_len: usize, account_type: u8) -> PyResult<()> {
+    let owner = account.0.owner;
+    if owner != TOKEN_PROGRAM_ID && owner != TOKEN_2022_PROGRAM_ID {
+        return Err(to_py_value_err(&format!(
+            "account is owned by {owner}, not a token program"
+        )));
+    }
+    if account.0.executable {
+        return Err(to_py_value_err(&"token account is executable"));
+    }
+    let data = &account.0.data;
+    let valid_len = data.len() == base_len
+        || (owner == TOKEN_2022_PROGRAM_ID
+            && data.len() > TOKEN_ACCOUNT_LEN
+            && data.len() != MULTISIG_LEN
+            && data[TOKEN_ACCOUNT_LEN] == account_type);
+    if !valid_len {
+        return Err(to_py_value_err(&format!(
+            "unexpected data length {} for owner {owner}",
+            data.len()
+        )));
+    }
+    Ok(())
+}
+
+/// Check that an account is an initialized SPL Token or Token-2022 token account.
+///
+/// Args:
+///     account (Account): The fetched account.
+///     expected_mint (Optional[Pubkey]): If given, the mint the account must hold.
+///     expected_owner (Optional[Pubkey]): If given, the wallet that must own the account.
+///
+/// Raises:
+///     ValueError: If any check fails. The message says which.
+///
+/// Example:
+///     >>> from solders.account import Account, validate_token_account
+///     >>> from solders.pubkey import Pubkey
+///     >>> validate_token_account(Account(1, bytes(165), Pubkey.default()))
+///     Traceback (most recent call last):
+///     ...
+///     ValueError: account is owned by 11111111111111111111111111111111, not a token program
+///
+#[pyfunction]
+#[pyo3(signature = (account, expected_mint = None, expected_owner = None))]
+pub fn validate_token_account(
+    account: &Account,
+    expected_mint: Option<Pubkey>,
+    expected_owner: Option<Pubkey>,
+) -> PyResult<()> {
+    check_token_layout(account, TOKEN_ACCOUNT_LEN, TOKEN_2022_ACCOUNT_TYPE_ACCOUNT)?;
+    let data = &account.0.data;
+    if data[TOKEN_ACCOUNT_STATE_OFFSET] == 0 {
+        return Err(to_py_value_err(&"token account is not initialized"));
+    }
+    let mint = PubkeyOriginal::new(&data[TOKEN_ACCOUNT_MINT_OFFSET..][..32]);
+    if let Some(expected) = expected_mint {
+        if mint != expected.0 {
+            return Err(to_py_value_err(&format!(
+                "token account mint is {mint}, expected {expected}"
+            )));
+        }
+    }
+    let owner = PubkeyOriginal::new(&data[TOKEN_ACCOUNT_OWNER_OFFSET..][..32]);
+    if let Some(expected) = expected_owner {
+        if owner != expected.0 {
+            return Err(to_py_value_err(&format!(
+                "token account owner is {owner}, expected {expected}"
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Check that an account is an initialized SPL Token or Token-2022 mint.
+///
+/// Args:
+///     account (Account): The fetched account.
+///
+/// Raises:
+///     ValueError: If any check fails. The message says which.
+///
+#[pyfunction]
+pub fn validate_mint_account(account: &Account) -> PyResult<()> {
+    check_token_layout(account, MINT_LEN, TOKEN_2022_ACCOUNT_TYPE_MINT)?;
+    if account.0.data[MINT_IS_INITIALIZED_OFFSET] != 1 {
+        return Err(to_py_value_err(&"mint is not initialized"));
+    }
+    Ok(())
+}
+
+/// Check that an account is an executable program owned by a loader.
+///
+/// Args:
+///     account (Account): The fetched program account.
+///
+/// Returns:
+///     Optional[Pubkey]: The program data address for programs owned by the
+///     upgradeable loader, otherwise ``None``.
+///
+/// Raises:
+///     ValueError: If any check fails. The message says which.
+///
+#[pyfunction]
+pub fn validate_program_account(account: &Account) -> PyResult<Option<Pubkey>> {
+    let owner = account.0.owner;
+    if !account.0.executable {
+        return Err(to_py_value_err(&"program account is not executable"));
+    }
+    if owner == bpf_loader_upgradeable::id() {
+        return match bincode::deserialize(&account.0.data) {
+            Ok(UpgradeableLoaderState::Program {
+                programdata_address,
+            }) => Ok(Some(programdata_address.into())),
+            _ => Err(to_py_value_err(
+                &"upgradeable program account does not hold program state",
+            )),
+        };
+    }
+    if owner == bpf_loader::id()
+        || owner == bpf_loader_deprecated::id()
+        || owner == native_loader::id()
+    {
+        return Ok(None);
+    }
+    Err(to_py_value_err(&format!(
+        "program account is owned by {owner}, not a loader"
+    )))
+}
+
+/// Size of a 64-bit ELF header.
+const ELF_HEADER_LEN: usize = 64;
+/// Offset of ``e_shoff``, the section header table offset, in a 64-bit ELF header.
+const ELF_SHOFF_OFFSET: usize = 0x28;
+/// Offset of ``e_shentsize``, the size of one section header, in a 64-bit ELF header.
+const ELF_SHENTSIZE_OFFSET: usize = 0x3a;
+/// Offset of ``e_shnum``, the number of section headers, in a 64-bit ELF header.
+const ELF_SHNUM_OFFSET: usize = 0x3c;
+
+/// The size of a 64-bit little-endian ELF, which ends with its section header table.
+fn elf_len(data: &[u8]) -> Option<usize> {
+    if data.len() < ELF_HEADER_LEN || !data.starts_with(b"\x7fELF\x02\x01") {
+        return None;
+    }
+    let field = |offset: usize, len: usize| {
+        let mut bytes = [0u8; 8];
+        bytes[..len].copy_from_slice(&data[offset..offset + len]);
+        u64::from_le_bytes(bytes)
+    };
+    let section_headers = field(ELF_SHENTSIZE_OFFSET, 2).checked_mul(field(ELF_SHNUM_OFFSET, 2))?;
+    let len = usize::try_from(field(ELF_SHOFF_OFFSET, 8).checked_add(section_headers)?).ok()?;
+    (ELF_HEADER_LEN..=data.len()).contains(&len).then_some(len)
+}
+
+/// Cut the padding that follows an ELF in program data, using the size its header declares.
+///
+/// All-zero data, as left by a closed program, is an empty ELF.
+fn trim_elf(data: &[u8]) -> PyResult<&[u8]> {
+    if data.iter().all(|b| *b == 0) {
+        return Ok(&[]);
+    }
+    elf_len(data)
+        .map(|len| &data[..len])
+        .ok_or_else(|| to_py_value_err(&"data does not start with a valid 64-bit ELF header"))
+}
+
+/// The deploy slot, upgrade authority and padded ELF of a program data account.
+fn programdata_state(account: &Account) -> PyResult<(u64, Option<PubkeyOriginal>, &[u8])> {
+    if account.0.owner != bpf_loader_upgradeable::id() {
+        return Err(to_py_value_err(&format!(
+            "account is owned by {}, not the upgradeable loader",
+            account.0.owner
+        )));
+    }
+    let metadata_len = UpgradeableLoaderState::size_of_programdata_metadata();
+    let data = &account.0.data;
+    match data.get(..metadata_len).map(bincode::deserialize) {
+        Some(Ok(UpgradeableLoaderState::ProgramData {
+            slot,
+            upgrade_authority_address,
+        })) => Ok((slot, upgrade_authority_address, &data[metadata_len..])),
+        _ => Err(to_py_value_err(&"account does not hold program data")),
+    }
+}
+
+fn programdata_bytes(account: &Account) -> PyResult<&[u8]> {
+    Ok(programdata_state(account)?.2)
+}
+
+/// Extract the ELF from an upgradeable loader program data account.
+///
+/// Args:
+///     account (Account): The fetched program data account.
+///
+/// Returns:
+///     bytes: The ELF, cut to the size its header declares.
+///
+/// Raises:
+///     ValueError: If the account doesn't hold program data or the ELF header is invalid.
+///
+#[pyfunction]
+pub fn program_data_elf<'a>(py: Python<'a>, account: &Account) -> PyResult<&'a PyBytes> {
+    Ok(PyBytes::new(py, trim_elf(programdata_bytes(account)?)?))
+}
+
+/// Compute the hash verifiable-build tools report for a program binary.
+///
+/// This is the hex SHA-256 of the ELF, cut to the size its header declares
+/// (the end of the section header table), so a locally built ``.so`` and the
+/// padded ELF deployed on chain hash the same. Zero bytes inside the ELF are kept.
+///
+/// Args:
+///     elf (bytes): The program binary, optionally followed by padding.
+///
+/// Returns:
+///     str: The hex digest.
+///
+/// Raises:
+///     ValueError: If ``elf`` doesn't start with a valid 64-bit ELF header.
+///
+/// Example:
+///     >>> import struct
+///     >>> from solders.account import executable_hash
+///     >>> elf = b"\x7fELF\x02\x01" + bytes(34) + struct.pack("<Q", 64) + bytes(16)
+///     >>> executable_hash(elf) == executable_hash(elf + bytes(10))
+///     True
+///
+#[pyfunction]
+pub fn executable_hash(elf: &[u8]) -> PyResult<String> {
+    Ok(hash(trim_elf(elf)?)
+        .to_bytes()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect())
+}
+
+/// Compute the :func:`executable_hash` of the program deployed in a program data account.
+///
+/// Args:
+///     account (Account): The fetched program data account.
+///
+/// Returns:
+///     str: The hex digest.
+///
+/// Raises:
+///     ValueError: If the account doesn't hold program data or the ELF header is invalid.
+///
+#[pyfunction]
+pub fn program_data_hash(account: &Account) -> PyResult<String> {
+    executable_hash(programdata_bytes(account)?)
+}
+
+fn format_authority(authority: &Option<Pubkey>) -> String {
+    authority.map_or_else(|| "none".to_owned(), |a| a.to_string())
+}
+
+/// What changed between two snapshots of a program data account, as returned by
+/// :func:`diff_program_data`.
+#[pyclass(module = "solders.account", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProgramDataDiff {
+    /// int: The slot the earlier program was deployed in.
+    #[pyo3(get)]
+    pub before_slot: u64,
+    /// int: The slot the later program was deployed in.
+    #[pyo3(get)]
+    pub after_slot: u64,
+    /// Optional[Pubkey]: The earlier upgrade authority, or ``None`` if the program was immutable.
+    #[pyo3(get)]
+    pub before_authority: Option<Pubkey>,
+    /// Optional[Pubkey]: The later upgrade authority, or ``None`` if the program is immutable.
+    #[pyo3(get)]
+    pub after_authority: Option<Pubkey>,
+    /// int: The size of the earlier ELF, from its header.
+    #[pyo3(get)]
+    pub before_size: usize,
+    /// int: The size of the later ELF, from its header.
+    #[pyo3(get)]
+    pub after_size: usize,
+    /// Hash: The SHA-256 of the earlier ELF, as in :func:`executable_hash`.
+    #[pyo3(get)]
+    pub before_hash: SolderHash,
+    /// Hash: The SHA-256 of the later ELF, as in :func:`executable_hash`.
+    #[pyo3(get)]
+    pub after_hash: SolderHash,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl ProgramDataDiff {
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        before_slot: u64,
+        after_slot: u64,
+        before_authority: Option<Pubkey>,
+        after_authority: Option<Pubkey>,
+        before_size: usize,
+        after_size: usize,
+        before_hash: SolderHash,
+        after_hash: SolderHash,
+    ) -> Self {
+        Self {
+            before_slot,
+            after_slot,
+            before_authority,
+            after_authority,
+            before_size,
+            after_size,
+            before_hash,
+            after_hash,
+        }
+    }
+
+    /// bool: Whether the program was redeployed, even with the same code.
+    #[getter]
+    pub fn redeployed(&self) -> bool {
+        self.before_slot != self.after_slot
+    }
+
+    /// bool: Whether the deployed code changed.
+    #[getter]
+    pub fn code_changed(&self) -> bool {
+        self.before_hash != self.after_hash
+    }
+
+    /// bool: Whether the upgrade authority changed, including being removed.
+    #[getter]
+    pub fn authority_changed(&self) -> bool {
+        self.before_authority != self.after_authority
+    }
+
+    /// bool: Whether the program became immutable.
+    #[getter]
+    pub fn made_immutable(&self) -> bool {
+        self.before_authority.is_some() && self.after_authority.is_none()
+    }
+
+    /// bool: Whether anything changed.
+    #[getter]
+    pub fn has_changes(&self) -> bool {
+        self.redeployed() || self.code_changed() || self.authority_changed()
+    }
+
+    /// Describe the changes, one line each.
+    ///
+    /// Returns:
+    ///     List[str]: The descriptions, empty if nothing changed.
+    ///
+    pub fn summary(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        if self.code_changed() {
+            lines.push(format!(
+                "code changed: {} -> {}",
+                self.before_hash, self.after_hash
+            ));
+        }
+        if self.before_size != self.after_size {
+            lines.push(format!(
+                "size changed: {} -> {} bytes",
+                self.before_size, self.after_size
+            ));
+        }
+        if self.redeployed() {
+            lines.push(format!(
+                "redeployed: slot {} -> {}",
+                self.before_slot, self.after_slot
+            ));
+        }
+        if self.authority_changed() {
+            lines.push(format!(
+                "upgrade authority changed: {} -> {}",
+                format_authority(&self.before_authority),
+                format_authority(&self.after_authority)
+            ));
+        }
+        lines
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for ProgramDataDiff {}
+
+/// Compare two snapshots of a program data account.
+///
+/// Use this to alert on upgrades of programs you depend on: fetch the program data
+/// account periodically and diff it against the last snapshot you reviewed.
+///
+/// Args:
+///     before (Account): The earlier program data account.
+///     after (Account): The later program data account.
+///
+/// Returns:
+///     ProgramDataDiff: The deploy slots, upgrade authorities, sizes and hashes of both.
+///
+/// Raises:
+///     ValueError: If either account doesn't hold program data or an ELF header is invalid.
+///
+#[pyfunction]
+pub fn diff_program_data(before: &Account, after: &Account) -> PyResult<ProgramDataDiff> {
+    let (before_slot, before_authority, before_elf) = programdata_state(before)?;
+    let (after_slot, after_authority, after_elf) = programdata_state(after)?;
+    Ok(ProgramDataDiff {
+        before_slot,
+        after_slot,
+        before_authority: before_authority.map(Pubkey::from),
+        after_authority: after_authority.map(Pubkey::from),
+        before_size: trim_elf(before_elf)?.len(),
+        after_size: trim_elf(after_elf)?.len(),
+        before_hash: hash(trim_elf(before_elf)?).into(),
+        after_hash: hash(trim_elf(after_elf)?).into(),
+    })
+}
+
 pub(crate) fn create_account_mod(py: Python<'_>) -> PyResult<&PyModule> {
     let m = PyModule::new(py, "account")?;
     m.add_class::<Account>()?;
     m.add_class::<AccountJSON>()?;
+    m.add_function(wrap_pyfunction!(validate_token_account, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_mint_account, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_program_account, m)?)?;
+    m.add_function(wrap_pyfunction!(program_data_elf, m)?)?;
+    m.add_function(wrap_pyfunction!(executable_hash, m)?)?;
+    m.add_function(wrap_pyfunction!(program_data_hash, m)?)?;
+    m.add_class::<ProgramDataDiff>()?;
+    m.add_function(wrap_pyfunction!(diff_program_data, m)?)?;
     Ok(m)
 }
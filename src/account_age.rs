@@ -0,0 +1,249 @@
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use solana_sdk::clock::{Epoch, Slot};
+use solders_macros::enum_string;
+use solders_primitives::pubkey::Pubkey;
+use solders_traits::{to_py_value_err, EnumString};
+
+use crate::{
+    commitment_config::CommitmentLevel,
+    rpc::{
+        config::RpcSignaturesForAddressConfig,
+        requests::GetSignaturesForAddress,
+        responses::{GetSignaturesForAddressResp, RpcConfirmedTransactionStatusWithSignature},
+    },
+};
+
+/// The ``rent_epoch`` of accounts that are exempt from rent collection.
+pub const RENT_EXEMPT_RENT_EPOCH: Epoch = Epoch::MAX;
+
+/// The most signatures ``getSignaturesForAddress`` returns per page.
+const MAX_SIGNATURES_PER_PAGE: usize = 1000;
+
+/// What an account's ``rent_epoch`` says about it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[pyclass(module = "solders.account_age")]
+pub enum RentEpochKind {
+    /// The rent-exempt sentinel ``2**64 - 1``. Says nothing about the account's age.
+    Exempt,
+    /// Never set, as for genesis accounts and sysvars.
+    Unset,
+    /// The next epoch in which rent is due. The account was created no later than this epoch.
+    Scheduled,
+}
+
+impl EnumString for RentEpochKind {}
+
+#[enum_string]
+#[pymethods]
+impl RentEpochKind {}
+
+impl From<Epoch> for RentEpochKind {
+    fn from(rent_epoch: Epoch) -> Self {
+        match rent_epoch {
+            RENT_EXEMPT_RENT_EPOCH => Self::Exempt,
+            0 => Self::Unset,
+            _ => Self::Scheduled,
+        }
+    }
+}
+
+/// Classify an account's ``rent_epoch``.
+///
+/// Args:
+///     rent_epoch (int): The account's ``rent_epoch``.
+///
+/// Returns:
+///     RentEpochKind: The kind of value.
+///
+/// Example:
+///     >>> from solders.account_age import rent_epoch_kind, RentEpochKind
+///     >>> rent_epoch_kind(2**64 - 1) == RentEpochKind.Exempt
+///     True
+///
+#[pyfunction]
+pub fn rent_epoch_kind(rent_epoch: Epoch) -> RentEpochKind {
+    rent_epoch.into()
+}
+
+/// The latest epoch the account can have been created in, according to its ``rent_epoch``.
+///
+/// New accounts get the current epoch as their ``rent_epoch``, and rent collection
+/// only ever moves it forward, so a scheduled ``rent_epoch`` bounds the creation epoch.
+///
+/// Args:
+///     rent_epoch (int): The account's ``rent_epoch``.
+///
+/// Returns:
+///     Optional[int]: The epoch, or ``None`` if ``rent_epoch`` is a sentinel.
+///
+#[pyfunction]
+pub fn latest_creation_epoch(rent_epoch: Epoch) -> Option<Epoch> {
+    match RentEpochKind::from(rent_epoch) {
+        RentEpochKind::Scheduled => Some(rent_epoch),
+        RentEpochKind::Exempt | RentEpochKind::Unset => None,
+    }
+}
+
+/// Finds the oldest transaction touching an address by paging ``getSignaturesForAddress``.
+///
+/// The search does no I/O: send :meth:`next_request` and pass its response to
+/// :meth:`handle_response` until :attr:`is_done`. The slot of the oldest transaction
+/// estimates when the account was created. Nodes only serve the history they keep,
+/// so on a pruned node it is a lower bound on the account's age.
+///
+/// Args:
+///     address (Pubkey): The account address.
+///     limit (int): Signatures to fetch per page, at most 1000.
+///     commitment (Optional[CommitmentLevel]): Bank state to query.
+///
+/// Example:
+///     >>> from solders.account_age import CreationSlotSearch
+///     >>> from solders.pubkey import Pubkey
+///     >>> from solders.rpc.responses import GetSignaturesForAddressResp, RpcConfirmedTransactionStatusWithSignature
+///     >>> from solders.signature import Signature
+///     >>> search = CreationSlotSearch(Pubkey.new_unique(), limit=2)
+///     >>> page = [RpcConfirmedTransactionStatusWithSignature(Signature.new_unique(), s, None, None, None, None) for s in (9, 7, 3)]
+///     >>> search.handle_response(GetSignaturesForAddressResp(page[:2]))
+///     >>> search.next_request().config.before == page[1].signature
+///     True
+///     >>> search.handle_response(GetSignaturesForAddressResp(page[2:]))
+///     >>> search.is_done, search.creation_slot
+///     (True, 3)
+///
+#[pyclass(module = "solders.account_age", subclass)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreationSlotSearch {
+    address: Pubkey,
+    limit: usize,
+    commitment: Option<CommitmentLevel>,
+    oldest: Option<RpcConfirmedTransactionStatusWithSignature>,
+    done: bool,
+}
+
+#[pymethods]
+impl CreationSlotSearch {
+    #[new]
+    #[pyo3(signature = (address, limit = MAX_SIGNATURES_PER_PAGE, commitment = None))]
+    pub fn new(
+        address: Pubkey,
+        limit: usize,
+        commitment: Option<CommitmentLevel>,
+    ) -> PyResult<Self> {
+        if limit == 0 || limit > MAX_SIGNATURES_PER_PAGE {
+            return Err(to_py_value_err(&format!(
+                "limit must be between 1 and {}, got {}",
+                MAX_SIGNATURES_PER_PAGE, limit
+            )));
+        }
+        Ok(Self {
+            address,
+            limit,
+            commitment,
+            oldest: None,
+            done: false,
+        })
+    }
+
+    /// Pubkey: The account address.
+    #[getter]
+    pub fn address(&self) -> Pubkey {
+        self.address
+    }
+
+    /// bool: Whether the oldest available transaction has been found.
+    #[getter]
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+
+    /// Optional[RpcConfirmedTransactionStatusWithSignature]: The oldest transaction seen so far.
+    #[getter]
+    pub fn oldest(&self) -> Option<RpcConfirmedTransactionStatusWithSignature> {
+        self.oldest.clone()
+    }
+
+    /// Optional[int]: The slot of the oldest transaction seen so far.
+    #[getter]
+    pub fn creation_slot(&self) -> Option<Slot> {
+        self.oldest.as_ref().map(|o| o.slot())
+    }
+
+    /// The request for the next page of signatures.
+    ///
+    /// Args:
+    ///     id (int): The request ID.
+    ///
+    /// Returns:
+    ///     Optional[GetSignaturesForAddress]: The request, or ``None`` if the search is done.
+    ///
+    #[pyo3(signature = (id = 0))]
+    pub fn next_request(&self, id: u64) -> Option<GetSignaturesForAddress> {
+        if self.done {
+            return None;
+        }
+        let before = self.oldest.as_ref().map(|o| o.signature());
+        let config = RpcSignaturesForAddressConfig::new(
+            before.as_ref(),
+            None,
+            Some(self.limit),
+            self.commitment,
+            None,
+        );
+        Some(GetSignaturesForAddress::new(
+            self.address,
+            Some(config),
+            Some(id),
+        ))
+    }
+
+    /// Record a page of signatures.
+    ///
+    /// Args:
+    ///     response (GetSignaturesForAddressResp): The response to :meth:`next_request`.
+    ///
+    /// Raises:
+    ///     ValueError: If the search is already done.
+    ///
+    pub fn handle_response(&mut self, response: GetSignaturesForAddressResp) -> PyResult<()> {
+        if self.done {
+            return Err(to_py_value_err(&"search is already done"));
+        }
+        let page = response.value();
+        if page.len() < self.limit {
+            self.done = true;
+        }
+        if let Some(last) = page.into_iter().last() {
+            self.oldest = Some(last);
+        }
+        Ok(())
+    }
+
+    /// The age of the account in slots, measured from its oldest transaction.
+    ///
+    /// Args:
+    ///     current_slot (int): The current slot.
+    ///
+    /// Returns:
+    ///     Optional[int]: The age, or ``None`` if no transaction has been seen.
+    ///
+    pub fn age_slots(&self, current_slot: Slot) -> Option<Slot> {
+        self.creation_slot()
+            .map(|slot| current_slot.saturating_sub(slot))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+pub(crate) fn create_account_age_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "account_age")?;
+    m.add_class::<RentEpochKind>()?;
+    m.add_class::<CreationSlotSearch>()?;
+    m.add("RENT_EXEMPT_RENT_EPOCH", RENT_EXEMPT_RENT_EPOCH)?;
+    m.add_function(wrap_pyfunction!(rent_epoch_kind, m)?)?;
+    m.add_function(wrap_pyfunction!(latest_creation_epoch, m)?)?;
+    Ok(m)
+}
@@ -1,19 +1,29 @@
+use solana_sdk::{
+    clock::{Clock as ClockOriginal, Epoch, Slot, UnixTimestamp},
+    epoch_schedule::EpochSchedule as EpochScheduleOriginal,
+    rent::Rent as RentOriginal,
+};
 use solders_traits::{
     handle_py_value_err, py_from_bytes_general_via_bincode, pybytes_general_via_bincode,
-    RichcmpEqualityOnly,
+    to_py_value_err, EnumString, RichcmpEqualityOnly,
 };
 use std::fmt::Display;
 
-use crate::tmp_account_decoder::{
-    ParsedAccount as ParsedAccountOriginal, UiDataSliceConfig as UiDataSliceConfigOriginal,
-    UiTokenAmount as UiTokenAmountOriginal,
+use crate::{
+    clock::Clock,
+    epoch_schedule::EpochSchedule,
+    rent::Rent,
+    tmp_account_decoder::{
+        ParsedAccount as ParsedAccountOriginal, UiDataSliceConfig as UiDataSliceConfigOriginal,
+        UiTokenAmount as UiTokenAmountOriginal,
+    },
 };
 use derive_more::{From, Into};
 use pyo3::prelude::*;
 use pythonize::{depythonize, pythonize};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use solders_macros::{common_methods, richcmp_eq_only};
+use solders_macros::{common_methods, enum_string, richcmp_eq_only, EnumIntoPy};
 
 /// Configuration object for limiting returned account data.
 ///
@@ -63,6 +73,30 @@ pub enum UiAccountEncoding {
     Base64Zstd,
 }
 
+impl EnumString for UiAccountEncoding {}
+
+#[enum_string]
+#[pymethods]
+impl UiAccountEncoding {}
+
+/// An account in ``jsonParsed`` encoding.
+///
+/// Typed classes are available for these account types:
+///
+/// * ``clock``, ``rent`` and ``epochSchedule`` sysvars, via :meth:`to_sysvar`.
+///
+/// These have no typed class and are only available as :attr:`parsed`:
+///
+/// * the ``fees``, ``slotHashes``, ``recentBlockhashes`` and ``stakeHistory`` sysvars;
+/// * ``nonce``, ``stake`` and ``vote`` accounts;
+/// * ``spl-token`` and ``spl-token-2022`` accounts, mints and multisigs;
+/// * every other program the node parses.
+///
+/// Args:
+///     program (str): The name of the program the node parsed the account with.
+///     parsed (dict): The parsed account data.
+///     space (int): The size of the account data in bytes.
+///
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, From, Into)]
 #[pyclass(module = "solders.account_decoder")]
 pub struct ParsedAccount(ParsedAccountOriginal);
@@ -106,6 +140,101 @@ impl ParsedAccount {
     pub fn space(&self) -> u64 {
         self.0.space
     }
+
+    /// Convert a parsed sysvar account into its typed class.
+    ///
+    /// This gives the same object as parsing the raw account bytes,
+    /// so code doesn't depend on which encoding the node returned.
+    ///
+    /// Only the ``clock``, ``rent`` and ``epochSchedule`` sysvars are covered. See
+    /// :class:`ParsedAccount` for the account types read from :attr:`parsed` instead.
+    ///
+    /// Returns:
+    ///     Union[Clock, Rent, EpochSchedule]: The sysvar.
+    ///
+    /// Raises:
+    ///     ValueError: If the account is not one of the supported sysvars.
+    ///
+    /// Example:
+    ///     >>> from solders.account_decoder import ParsedAccount
+    ///     >>> info = {"slot": 10, "epoch": 0, "epochStartTimestamp": 1, "leaderScheduleEpoch": 1, "unixTimestamp": 2}
+    ///     >>> ParsedAccount("sysvar", {"type": "clock", "info": info}, 40).to_sysvar().slot
+    ///     10
+    ///
+    pub fn to_sysvar(&self) -> PyResult<SysvarAccount> {
+        if self.0.program != "sysvar" {
+            return Err(to_py_value_err(&format!(
+                "expected a sysvar account, got {}. Only the clock, rent and epochSchedule \
+                sysvars have typed classes; read other accounts from parsed",
+                self.0.program
+            )));
+        }
+        let kind = self.0.parsed.get("type").and_then(Value::as_str);
+        if !matches!(kind, Some("clock" | "rent" | "epochSchedule")) {
+            return Err(to_py_value_err(&format!(
+                "to_sysvar supports the clock, rent and epochSchedule sysvars, got {}",
+                kind.unwrap_or("an untyped account")
+            )));
+        }
+        let parsed: UiSysvar = handle_py_value_err(serde_json::from_value(self.0.parsed.clone()))?;
+        Ok(match parsed {
+            UiSysvar::Clock(c) => SysvarAccount::Clock(
+                ClockOriginal {
+                    slot: c.slot,
+                    epoch_start_timestamp: c.epoch_start_timestamp,
+                    epoch: c.epoch,
+                    leader_schedule_epoch: c.leader_schedule_epoch,
+                    unix_timestamp: c.unix_timestamp,
+                }
+                .into(),
+            ),
+            UiSysvar::Rent(r) => SysvarAccount::Rent(
+                RentOriginal {
+                    lamports_per_byte_year: handle_py_value_err(
+                        r.lamports_per_byte_year.parse::<u64>(),
+                    )?,
+                    exemption_threshold: r.exemption_threshold,
+                    burn_percent: r.burn_percent,
+                }
+                .into(),
+            ),
+            UiSysvar::EpochSchedule(e) => SysvarAccount::EpochSchedule(e.into()),
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UiClock {
+    slot: Slot,
+    epoch: Epoch,
+    epoch_start_timestamp: UnixTimestamp,
+    leader_schedule_epoch: Epoch,
+    unix_timestamp: UnixTimestamp,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UiRent {
+    lamports_per_byte_year: String,
+    exemption_threshold: f64,
+    burn_percent: u8,
+}
+
+/// The ``jsonParsed`` sysvars that have a typed class.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase", tag = "type", content = "info")]
+enum UiSysvar {
+    Clock(UiClock),
+    Rent(UiRent),
+    EpochSchedule(EpochScheduleOriginal),
+}
+
+#[derive(Clone, Debug, PartialEq, EnumIntoPy)]
+pub enum SysvarAccount {
+    Clock(Clock),
+    Rent(Rent),
+    EpochSchedule(EpochSchedule),
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, From, Into)]
@@ -0,0 +1,260 @@
+use std::collections::HashMap;
+
+use pyo3::{exceptions::PyKeyError, prelude::*};
+use solana_sdk::{account::Account as AccountOriginal, pubkey::Pubkey as PubkeyOriginal};
+use solders_macros::richcmp_eq_only;
+use solders_primitives::pubkey::Pubkey;
+use solders_traits::RichcmpEqualityOnly;
+
+use crate::account::Account;
+
+/// A pubkey → account store made of a base snapshot plus pending writes.
+///
+/// Reads see the pending writes first and fall back to the base. Writes and
+/// deletions only touch the pending layer until :meth:`commit` merges them
+/// into the base, or :meth:`rollback` discards them. :meth:`fork` creates an
+/// independent store whose base is this store's current view, so several
+/// speculative branches can be explored from the same state.
+///
+/// Args:
+///     accounts (Optional[Dict[Pubkey, Account]]): The base snapshot.
+///
+/// Example:
+///     >>> from solders.accounts_map import AccountsMap
+///     >>> from solders.account import Account
+///     >>> from solders.pubkey import Pubkey
+///     >>> key, owner = Pubkey.new_unique(), Pubkey.new_unique()
+///     >>> accounts = AccountsMap({key: Account(1, b"", owner)})
+///     >>> accounts[key] = Account(2, b"", owner)
+///     >>> accounts[key].lamports, accounts.base()[key].lamports
+///     (2, 1)
+///     >>> accounts.rollback()
+///     >>> accounts[key].lamports
+///     1
+///
+#[pyclass(module = "solders.accounts_map", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct AccountsMap {
+    base: HashMap<PubkeyOriginal, AccountOriginal>,
+    /// ``None`` marks a pending deletion.
+    pending: HashMap<PubkeyOriginal, Option<AccountOriginal>>,
+}
+
+impl AccountsMap {
+    /// The current view of ``pubkey``, for use from Rust.
+    pub fn get_original(&self, pubkey: &PubkeyOriginal) -> Option<&AccountOriginal> {
+        match self.pending.get(pubkey) {
+            Some(pending) => pending.as_ref(),
+            None => self.base.get(pubkey),
+        }
+    }
+
+    /// Write ``account`` to the pending layer, for use from Rust.
+    pub fn set_original(&mut self, pubkey: PubkeyOriginal, account: AccountOriginal) {
+        self.pending.insert(pubkey, Some(account));
+    }
+
+    /// The pending writes, for use from Rust. ``None`` marks a pending deletion.
+    pub fn pending_original(&self) -> &HashMap<PubkeyOriginal, Option<AccountOriginal>> {
+        &self.pending
+    }
+
+    /// The addresses of the current view, without cloning any accounts.
+    fn visible_keys(&self) -> impl Iterator<Item = &PubkeyOriginal> {
+        let base = self
+            .base
+            .keys()
+            .filter(|pubkey| !self.pending.contains_key(*pubkey));
+        let written = self
+            .pending
+            .iter()
+            .filter_map(|(pubkey, pending)| pending.as_ref().map(|_| pubkey));
+        base.chain(written)
+    }
+
+    fn view(&self) -> HashMap<PubkeyOriginal, AccountOriginal> {
+        let mut view = self.base.clone();
+        for (pubkey, pending) in &self.pending {
+            match pending {
+                Some(account) => view.insert(*pubkey, account.clone()),
+                None => view.remove(pubkey),
+            };
+        }
+        view
+    }
+
+    fn to_py_dict(accounts: HashMap<PubkeyOriginal, AccountOriginal>) -> HashMap<Pubkey, Account> {
+        accounts
+            .into_iter()
+            .map(|(k, v)| (k.into(), v.into()))
+            .collect()
+    }
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl AccountsMap {
+    #[new]
+    #[pyo3(signature = (accounts = None))]
+    pub fn new(accounts: Option<HashMap<Pubkey, Account>>) -> Self {
+        Self {
+            base: accounts
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect(),
+            pending: HashMap::new(),
+        }
+    }
+
+    /// Look up an account.
+    ///
+    /// Args:
+    ///     pubkey (Pubkey): The account address.
+    ///
+    /// Returns:
+    ///     Optional[Account]: The account, or ``None`` if it doesn't exist or was deleted.
+    ///
+    pub fn get(&self, pubkey: Pubkey) -> Option<Account> {
+        self.get_original(pubkey.as_ref())
+            .cloned()
+            .map(Account::from)
+    }
+
+    /// Write an account to the pending layer.
+    ///
+    /// Args:
+    ///     pubkey (Pubkey): The account address.
+    ///     account (Account): The new account state.
+    ///
+    pub fn set(&mut self, pubkey: Pubkey, account: Account) {
+        self.set_original(pubkey.into(), account.into());
+    }
+
+    /// Delete an account in the pending layer.
+    ///
+    /// Args:
+    ///     pubkey (Pubkey): The account address.
+    ///
+    /// Returns:
+    ///     bool: Whether the account existed.
+    ///
+    pub fn delete(&mut self, pubkey: Pubkey) -> bool {
+        let existed = self.get_original(pubkey.as_ref()).is_some();
+        if existed {
+            self.pending.insert(pubkey.into(), None);
+        }
+        existed
+    }
+
+    /// The addresses of all visible accounts.
+    ///
+    /// Returns:
+    ///     List[Pubkey]: The addresses, sorted.
+    ///
+    pub fn keys(&self) -> Vec<Pubkey> {
+        let mut keys: Vec<PubkeyOriginal> = self.visible_keys().copied().collect();
+        keys.sort_unstable();
+        keys.into_iter().map(Pubkey::from).collect()
+    }
+
+    /// The current view, with pending writes applied.
+    ///
+    /// Returns:
+    ///     Dict[Pubkey, Account]: The visible accounts.
+    ///
+    pub fn to_dict(&self) -> HashMap<Pubkey, Account> {
+        Self::to_py_dict(self.view())
+    }
+
+    /// The base snapshot, without pending writes.
+    ///
+    /// Returns:
+    ///     Dict[Pubkey, Account]: The base accounts.
+    ///
+    pub fn base(&self) -> HashMap<Pubkey, Account> {
+        Self::to_py_dict(self.base.clone())
+    }
+
+    /// The pending writes. Deleted accounts map to ``None``.
+    ///
+    /// Returns:
+    ///     Dict[Pubkey, Optional[Account]]: The pending writes.
+    ///
+    pub fn pending_writes(&self) -> HashMap<Pubkey, Option<Account>> {
+        self.pending
+            .iter()
+            .map(|(k, v)| ((*k).into(), v.clone().map(Account::from)))
+            .collect()
+    }
+
+    /// bool: Whether there are pending writes.
+    #[getter]
+    pub fn is_dirty(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Merge the pending writes into the base.
+    pub fn commit(&mut self) {
+        for (pubkey, pending) in self.pending.drain() {
+            match pending {
+                Some(account) => self.base.insert(pubkey, account),
+                None => self.base.remove(&pubkey),
+            };
+        }
+    }
+
+    /// Discard the pending writes.
+    pub fn rollback(&mut self) {
+        self.pending.clear();
+    }
+
+    /// Create an independent store whose base is this store's current view.
+    ///
+    /// Returns:
+    ///     AccountsMap: The fork, with no pending writes.
+    ///
+    pub fn fork(&self) -> Self {
+        Self {
+            base: self.view(),
+            pending: HashMap::new(),
+        }
+    }
+
+    fn __getitem__(&self, pubkey: Pubkey) -> PyResult<Account> {
+        self.get(pubkey)
+            .ok_or_else(|| PyKeyError::new_err(pubkey.to_string()))
+    }
+
+    fn __setitem__(&mut self, pubkey: Pubkey, account: Account) {
+        self.set(pubkey, account);
+    }
+
+    fn __delitem__(&mut self, pubkey: Pubkey) -> PyResult<()> {
+        if self.delete(pubkey) {
+            Ok(())
+        } else {
+            Err(PyKeyError::new_err(pubkey.to_string()))
+        }
+    }
+
+    fn __contains__(&self, pubkey: Pubkey) -> bool {
+        self.get_original(pubkey.as_ref()).is_some()
+    }
+
+    fn __len__(&self) -> usize {
+        self.visible_keys().count()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for AccountsMap {}
+
+pub(crate) fn create_accounts_map_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "accounts_map")?;
+    m.add_class::<AccountsMap>()?;
+    Ok(m)
+}
@@ -1,8 +1,267 @@
+use std::collections::{HashMap, HashSet};
+
 use pyo3::prelude::*;
-use solders_primitives::address_lookup_table_account::AddressLookupTableAccount;
+use serde::{Deserialize, Serialize};
+use solana_sdk::{
+    clock::{Slot, DEFAULT_SLOTS_PER_EPOCH},
+    pubkey::Pubkey as PubkeyOriginal,
+    slot_hashes::MAX_ENTRIES,
+};
+use solders_macros::{enum_string, richcmp_eq_only};
+use solders_primitives::{address_lookup_table_account::AddressLookupTableAccount, pubkey::Pubkey};
+use solders_traits::{to_py_value_err, EnumString, RichcmpEqualityOnly};
+
+use crate::{account::Account, program_ids::ADDRESS_LOOKUP_TABLE_PROGRAM_ID};
+
+/// The size of the metadata that precedes the addresses in a lookup table account.
+const LOOKUP_TABLE_META_SIZE: usize = 56;
+const LOOKUP_TABLE_DISCRIMINATOR: u32 = 1;
+
+/// The header of an on-chain lookup table account.
+struct LookupTableMeta {
+    deactivation_slot: Slot,
+    last_extended_slot: Slot,
+    authority: Option<PubkeyOriginal>,
+    num_addresses: usize,
+}
+
+impl LookupTableMeta {
+    fn parse(account: &Account) -> PyResult<Self> {
+        let account = account.as_ref();
+        if account.owner != ADDRESS_LOOKUP_TABLE_PROGRAM_ID {
+            return Err(to_py_value_err(&format!(
+                "account is owned by {}, not the address lookup table program",
+                account.owner
+            )));
+        }
+        let data = &account.data;
+        if data.len() < LOOKUP_TABLE_META_SIZE
+            || u32::from_le_bytes(data[..4].try_into().unwrap()) != LOOKUP_TABLE_DISCRIMINATOR
+        {
+            return Err(to_py_value_err(&"account does not hold a lookup table"));
+        }
+        let slot_at = |i: usize| u64::from_le_bytes(data[i..i + 8].try_into().unwrap());
+        let authority = match data[21] {
+            0 => None,
+            _ => Some(PubkeyOriginal::new(&data[22..54])),
+        };
+        Ok(Self {
+            deactivation_slot: slot_at(4),
+            last_extended_slot: slot_at(12),
+            authority,
+            num_addresses: (data.len() - LOOKUP_TABLE_META_SIZE) / 32,
+        })
+    }
+
+    fn is_active(&self) -> bool {
+        self.deactivation_slot == Slot::MAX
+    }
+}
+
+/// What :func:`advise_lookup_table_cleanup` recommends doing with a lookup table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[pyclass(module = "solders.address_lookup_table_account")]
+pub enum LookupTableAction {
+    /// The table is in use, frozen, or not controlled by the given authority.
+    Keep,
+    /// The table has been idle long enough to deactivate.
+    Deactivate,
+    /// The table is deactivated but still cooling down.
+    Wait,
+    /// The table can be closed to reclaim its rent, assuming no skipped slots unless
+    /// ``slot_hashes`` was given.
+    Close,
+}
+
+impl EnumString for LookupTableAction {}
+
+#[enum_string]
+#[pymethods]
+impl LookupTableAction {}
+
+/// The recommendation for one lookup table, as returned by :func:`advise_lookup_table_cleanup`.
+#[pyclass(module = "solders.address_lookup_table_account", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LookupTableAdvice {
+    /// Pubkey: The lookup table address.
+    #[pyo3(get)]
+    pub key: Pubkey,
+    /// LookupTableAction: What to do with the table.
+    #[pyo3(get)]
+    pub action: LookupTableAction,
+    /// int: The table's lamports, reclaimed when it is closed.
+    #[pyo3(get)]
+    pub lamports: u64,
+    /// int: The number of addresses in the table.
+    #[pyo3(get)]
+    pub num_addresses: usize,
+    /// Optional[Pubkey]: The table authority, or ``None`` if the table is frozen.
+    #[pyo3(get)]
+    pub authority: Option<Pubkey>,
+    /// int: The last slot the table was used or extended in.
+    #[pyo3(get)]
+    pub last_used_slot: Slot,
+    /// Optional[int]: The slot the table was deactivated in, if it was.
+    #[pyo3(get)]
+    pub deactivation_slot: Option<Slot>,
+    /// Optional[int]: The earliest slot the table can be closed in, if it was deactivated.
+    /// See :func:`lookup_table_earliest_closable_slot`.
+    #[pyo3(get)]
+    pub earliest_closable_slot: Option<Slot>,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl LookupTableAdvice {
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        key: Pubkey,
+        action: LookupTableAction,
+        lamports: u64,
+        num_addresses: usize,
+        authority: Option<Pubkey>,
+        last_used_slot: Slot,
+        deactivation_slot: Option<Slot>,
+        earliest_closable_slot: Option<Slot>,
+    ) -> Self {
+        Self {
+            key,
+            action,
+            lamports,
+            num_addresses,
+            authority,
+            last_used_slot,
+            deactivation_slot,
+            earliest_closable_slot,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for LookupTableAdvice {}
+
+/// The earliest slot a lookup table deactivated in ``deactivation_slot`` can be closed in.
+///
+/// A deactivated table stays usable while its deactivation slot is in the
+/// ``SlotHashes`` sysvar, which holds the 512 most recent slots that produced a block.
+/// This assumes no slots were skipped since deactivation. Skipped slots don't enter
+/// ``SlotHashes``, so the table can become closable later than this, never sooner.
+///
+/// Args:
+///     deactivation_slot (int): The slot the table was deactivated in.
+///
+/// Returns:
+///     int: The earliest slot in which closing the table can succeed.
+///
+/// Example:
+///     >>> from solders.address_lookup_table_account import lookup_table_earliest_closable_slot
+///     >>> lookup_table_earliest_closable_slot(1000)
+///     1513
+///
+#[pyfunction]
+pub fn lookup_table_earliest_closable_slot(deactivation_slot: Slot) -> Slot {
+    deactivation_slot.saturating_add(MAX_ENTRIES as Slot + 1)
+}
+
+/// Recommend which lookup tables to deactivate or close to reclaim rent.
+///
+/// An active table is deactivated once it hasn't been used or extended for
+/// ``idle_slots``. A deactivated table is closed once its deactivation slot has left
+/// ``SlotHashes``. Without ``slot_hashes``, that is assumed to happen at
+/// :func:`lookup_table_earliest_closable_slot`, so if slots were skipped a ``Close``
+/// recommendation can still fail and should be retried later. Frozen tables, and tables whose
+/// authority isn't ``authority`` when one is given, are always kept since they
+/// can't be deactivated.
+///
+/// Args:
+///     tables (Sequence[Tuple[Pubkey, Account]]): The lookup table addresses and accounts.
+///     last_used (Dict[Pubkey, int]): The most recent slot each table was used in, e.g.
+///         from the ``addressTableLookups`` of recent transactions. Tables missing
+///         from it count as last used when they were last extended.
+///     current_slot (int): The current slot.
+///     idle_slots (int): How long a table must be idle before it is deactivated.
+///         Defaults to one epoch of 432,000 slots.
+///     authority (Optional[Pubkey]): Only recommend changes to tables with this authority.
+///     slot_hashes (Optional[Sequence[int]]): The slots in the ``SlotHashes`` sysvar as of
+///         ``current_slot``. If given, ``Close`` is only recommended for tables that can
+///         be closed now.
+///
+/// Returns:
+///     List[LookupTableAdvice]: One recommendation per table, in input order.
+///
+/// Raises:
+///     ValueError: If an account isn't a lookup table.
+///
+#[pyfunction]
+#[pyo3(signature = (tables, last_used, current_slot, idle_slots = DEFAULT_SLOTS_PER_EPOCH, authority = None, slot_hashes = None))]
+pub fn advise_lookup_table_cleanup(
+    tables: Vec<(Pubkey, Account)>,
+    last_used: HashMap<Pubkey, Slot>,
+    current_slot: Slot,
+    idle_slots: Slot,
+    authority: Option<Pubkey>,
+    slot_hashes: Option<HashSet<Slot>>,
+) -> PyResult<Vec<LookupTableAdvice>> {
+    tables
+        .into_iter()
+        .map(|(key, account)| {
+            let meta = LookupTableMeta::parse(&account)?;
+            let last_used_slot = last_used.get(&key).map_or(meta.last_extended_slot, |slot| {
+                (*slot).max(meta.last_extended_slot)
+            });
+            let controlled = meta.authority.is_some()
+                && authority.map_or(true, |a| meta.authority == Some(a.into()));
+            let (action, deactivation_slot, earliest_closable_slot) = if meta.is_active() {
+                let idle = current_slot.saturating_sub(last_used_slot) >= idle_slots;
+                let action = if controlled && idle {
+                    LookupTableAction::Deactivate
+                } else {
+                    LookupTableAction::Keep
+                };
+                (action, None, None)
+            } else {
+                let earliest = lookup_table_earliest_closable_slot(meta.deactivation_slot);
+                let closable = match &slot_hashes {
+                    Some(slots) => {
+                        meta.deactivation_slot != current_slot
+                            && !slots.contains(&meta.deactivation_slot)
+                    }
+                    None => current_slot >= earliest,
+                };
+                let action = if !controlled {
+                    LookupTableAction::Keep
+                } else if closable {
+                    LookupTableAction::Close
+                } else {
+                    LookupTableAction::Wait
+                };
+                (action, Some(meta.deactivation_slot), Some(earliest))
+            };
+            Ok(LookupTableAdvice {
+                key,
+                action,
+                lamports: account.as_ref().lamports,
+                num_addresses: meta.num_addresses,
+                authority: meta.authority.map(Pubkey::from),
+                last_used_slot,
+                deactivation_slot,
+                earliest_closable_slot,
+            })
+        })
+        .collect()
+}
 
 pub(crate) fn create_address_lookup_table_account_mod(py: Python<'_>) -> PyResult<&PyModule> {
     let m = PyModule::new(py, "address_lookup_table_account")?;
     m.add_class::<AddressLookupTableAccount>()?;
+    m.add_class::<LookupTableAction>()?;
+    m.add_class::<LookupTableAdvice>()?;
+    m.add_function(wrap_pyfunction!(lookup_table_earliest_closable_slot, m)?)?;
+    m.add_function(wrap_pyfunction!(advise_lookup_table_cleanup, m)?)?;
     Ok(m)
 }
@@ -0,0 +1,628 @@
+use std::fmt;
+
+use pyo3::{prelude::*, types::PyBytes};
+use serde::{Deserialize, Serialize};
+use solana_sdk::signature::Signature as SignatureOriginal;
+use solders_macros::{common_methods, enum_string, richcmp_eq_only};
+use solders_primitives::{pubkey::Pubkey, signature::Signature};
+use solders_traits::{
+    pybytes_general_via_bincode, to_py_value_err, EnumString, PyFromBytesGeneral,
+    RichcmpEqualityOnly,
+};
+
+use crate::squads::{Proposal, ProposalStatus};
+
+/// Bumped whenever the layout of :class:`ThresholdApproval` bytes changes.
+const THRESHOLD_APPROVAL_FORMAT_VERSION: u8 = 1;
+
+/// Where a proposal stands in a :class:`ThresholdApproval`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[pyclass(module = "solders.approval")]
+pub enum ApprovalStatus {
+    /// Collecting votes.
+    Pending,
+    /// Enough members approved; ready to execute.
+    Approved,
+    /// Too many members rejected for the threshold to be reached.
+    Rejected,
+    /// The approved action was carried out.
+    Executed,
+    /// Withdrawn before execution.
+    Cancelled,
+}
+
+impl ApprovalStatus {
+    /// Whether the proposal is closed: executed or cancelled.
+    fn is_final(self) -> bool {
+        matches!(self, Self::Executed | Self::Cancelled)
+    }
+}
+
+impl EnumString for ApprovalStatus {}
+
+#[enum_string]
+#[pymethods]
+impl ApprovalStatus {}
+
+/// A member's vote on a proposal.
+///
+/// Args:
+///     member (Pubkey): The voting member.
+///     approve (bool): Whether the member approved.
+///     signature (Optional[Signature]): The member's signature of the proposal payload,
+///         or ``None`` if the vote was read from an on-chain vote record.
+///
+#[pyclass(module = "solders.approval", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApprovalVote {
+    /// Pubkey: The voting member.
+    #[pyo3(get)]
+    pub member: Pubkey,
+    /// bool: Whether the member approved.
+    #[pyo3(get)]
+    pub approve: bool,
+    /// Optional[Signature]: The member's signature of the payload, if the vote was signed off-chain.
+    #[pyo3(get)]
+    pub signature: Option<Signature>,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl ApprovalVote {
+    #[new]
+    pub fn new(member: Pubkey, approve: bool, signature: Option<Signature>) -> Self {
+        Self {
+            member,
+            approve,
+            signature,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for ApprovalVote {}
+
+/// A proposal tracked by a :class:`ThresholdApproval`.
+#[pyclass(module = "solders.approval", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApprovalProposal {
+    id: String,
+    payload: Vec<u8>,
+    status: ApprovalStatus,
+    votes: Vec<ApprovalVote>,
+    execution_signature: Option<Signature>,
+}
+
+impl ApprovalProposal {
+    fn count(&self, approve: bool) -> usize {
+        self.votes.iter().filter(|v| v.approve == approve).count()
+    }
+
+    /// The status the votes alone lead to: approved, rejected or still pending.
+    fn tally(&self, members: usize, threshold: usize) -> ApprovalStatus {
+        if self.count(true) >= threshold {
+            ApprovalStatus::Approved
+        } else if self.count(false) > members - threshold {
+            ApprovalStatus::Rejected
+        } else {
+            ApprovalStatus::Pending
+        }
+    }
+
+    /// Check a loaded proposal: signed votes are valid, voters are distinct members,
+    /// and the status is one the votes could have led to.
+    fn check(&self, members: &[Pubkey], threshold: usize) -> Result<(), String> {
+        let id = &self.id;
+        for (i, vote) in self.votes.iter().enumerate() {
+            if !members.contains(&vote.member) {
+                return Err(format!("proposal {id:?}: {} is not a member", vote.member));
+            }
+            if self.votes[..i].iter().any(|v| v.member == vote.member) {
+                return Err(format!("proposal {id:?}: {} voted twice", vote.member));
+            }
+            if let Some(signature) = vote.signature {
+                if !SignatureOriginal::from(signature).verify(vote.member.as_ref(), &self.payload) {
+                    return Err(format!(
+                        "proposal {id:?}: invalid signature for {}",
+                        vote.member
+                    ));
+                }
+            }
+        }
+        let tally = self.tally(members.len(), threshold);
+        let consistent = match self.status {
+            ApprovalStatus::Pending | ApprovalStatus::Approved | ApprovalStatus::Rejected => {
+                self.status == tally
+            }
+            ApprovalStatus::Executed => tally == ApprovalStatus::Approved,
+            ApprovalStatus::Cancelled => true,
+        };
+        if !consistent {
+            return Err(format!(
+                "proposal {id:?} is {:?} but its votes make it {tally:?}",
+                self.status
+            ));
+        }
+        if self.execution_signature.is_some() && self.status != ApprovalStatus::Executed {
+            return Err(format!(
+                "proposal {id:?} has an execution signature but is {:?}",
+                self.status
+            ));
+        }
+        Ok(())
+    }
+
+    fn members_voting(&self, approve: bool) -> Vec<Pubkey> {
+        self.votes
+            .iter()
+            .filter(|v| v.approve == approve)
+            .map(|v| v.member)
+            .collect()
+    }
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl ApprovalProposal {
+    /// str: The proposal ID.
+    #[getter]
+    pub fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    /// bytes: What members sign to approve, e.g. a message or a proposal account address.
+    #[getter]
+    pub fn payload<'a>(&self, py: Python<'a>) -> &'a PyBytes {
+        PyBytes::new(py, &self.payload)
+    }
+
+    /// ApprovalStatus: Where the proposal stands.
+    #[getter]
+    pub fn status(&self) -> ApprovalStatus {
+        self.status
+    }
+
+    /// List[ApprovalVote]: The latest vote of each member who voted, in voting order.
+    #[getter]
+    pub fn votes(&self) -> Vec<ApprovalVote> {
+        self.votes.clone()
+    }
+
+    /// List[Pubkey]: The members who approved.
+    #[getter]
+    pub fn approvals(&self) -> Vec<Pubkey> {
+        self.members_voting(true)
+    }
+
+    /// List[Pubkey]: The members who rejected.
+    #[getter]
+    pub fn rejections(&self) -> Vec<Pubkey> {
+        self.members_voting(false)
+    }
+
+    /// Optional[Signature]: The transaction that executed the proposal, if recorded.
+    #[getter]
+    pub fn execution_signature(&self) -> Option<Signature> {
+        self.execution_signature
+    }
+
+    /// bool: Whether the proposal is approved and not yet executed.
+    #[getter]
+    pub fn is_ready(&self) -> bool {
+        self.status == ApprovalStatus::Approved
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for ApprovalProposal {}
+
+/// Tracks proposals through an M-of-N approval until they're executed.
+///
+/// This is the coordination layer shared by multisig flows, whatever enforces
+/// the threshold on-chain: votes are either signatures of the proposal payload,
+/// verified here, or read from on-chain vote records such as a Squads proposal or
+/// an SPL Governance vote record. A proposal is approved once ``threshold``
+/// members approve, and rejected once so many reject that the threshold can't be
+/// reached. Both outcomes are final, so later votes raise ``ValueError``.
+/// The state serializes with ``bytes()`` and ``to_json()`` for persistence, and
+/// loading it checks every proposal's votes and status against the members and
+/// threshold.
+///
+/// Args:
+///     members (Sequence[Pubkey]): The members who can vote.
+///     threshold (int): The approvals a proposal needs.
+///
+/// Raises:
+///     ValueError: If ``threshold`` is zero or more than the number of members, or a
+///         member is listed twice.
+///
+/// Example:
+///     >>> from solders.approval import ThresholdApproval, ApprovalStatus
+///     >>> from solders.keypair import Keypair
+///     >>> alice, bob, carol = Keypair(), Keypair(), Keypair()
+///     >>> approval = ThresholdApproval([k.pubkey() for k in (alice, bob, carol)], 2)
+///     >>> payload = b"pay 100 USDC to vendor"
+///     >>> approval.propose("invoice-7", payload)
+///     True
+///     >>> approval.approve("invoice-7", alice.pubkey(), alice.sign_message(payload)) == ApprovalStatus.Pending
+///     True
+///     >>> approval.record_vote("invoice-7", bob.pubkey(), True) == ApprovalStatus.Approved
+///     True
+///     >>> approval.ready()
+///     ['invoice-7']
+///     >>> restored = ThresholdApproval.from_bytes(bytes(approval))
+///     >>> restored.mark_executed("invoice-7")
+///     >>> restored.proposal("invoice-7").status == ApprovalStatus.Executed
+///     True
+///
+#[pyclass(module = "solders.approval", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "ThresholdApprovalFields")]
+pub struct ThresholdApproval {
+    version: u8,
+    members: Vec<Pubkey>,
+    threshold: usize,
+    proposals: Vec<ApprovalProposal>,
+}
+
+/// The serialized form of a :class:`ThresholdApproval`, checked on the way in.
+#[derive(Deserialize)]
+struct ThresholdApprovalFields {
+    version: u8,
+    members: Vec<Pubkey>,
+    threshold: usize,
+    proposals: Vec<ApprovalProposal>,
+}
+
+impl TryFrom<ThresholdApprovalFields> for ThresholdApproval {
+    type Error = String;
+
+    fn try_from(fields: ThresholdApprovalFields) -> Result<Self, Self::Error> {
+        if fields.version != THRESHOLD_APPROVAL_FORMAT_VERSION {
+            return Err(format!(
+                "unsupported threshold approval format version {}",
+                fields.version
+            ));
+        }
+        ThresholdApproval::check_members(&fields.members, fields.threshold)?;
+        for (i, proposal) in fields.proposals.iter().enumerate() {
+            if fields.proposals[..i].iter().any(|p| p.id == proposal.id) {
+                return Err(format!("proposal {:?} is listed twice", proposal.id));
+            }
+            proposal.check(&fields.members, fields.threshold)?;
+        }
+        Ok(Self {
+            version: fields.version,
+            members: fields.members,
+            threshold: fields.threshold,
+            proposals: fields.proposals,
+        })
+    }
+}
+
+impl ThresholdApproval {
+    /// Check that ``threshold`` is reachable and no member is listed twice.
+    fn check_members(members: &[Pubkey], threshold: usize) -> Result<(), String> {
+        if threshold == 0 || threshold > members.len() {
+            return Err(format!("threshold must be between 1 and {}", members.len()));
+        }
+        if let Some((i, member)) = members
+            .iter()
+            .enumerate()
+            .find(|(i, m)| members[..*i].contains(m))
+        {
+            return Err(format!("member {member} is listed twice, at index {i}"));
+        }
+        Ok(())
+    }
+
+    fn get_mut(&mut self, id: &str) -> PyResult<&mut ApprovalProposal> {
+        self.proposals
+            .iter_mut()
+            .find(|p| p.id == id)
+            .ok_or_else(|| to_py_value_err(&format!("unknown proposal {id:?}")))
+    }
+
+    /// Record a vote on a pending proposal, replacing the member's earlier one, and
+    /// update the status.
+    fn vote(&mut self, id: &str, vote: ApprovalVote) -> PyResult<ApprovalStatus> {
+        if !self.members.contains(&vote.member) {
+            return Err(to_py_value_err(&format!("{} is not a member", vote.member)));
+        }
+        let (members, threshold) = (self.members.len(), self.threshold);
+        let proposal = self.get_mut(id)?;
+        if proposal.status != ApprovalStatus::Pending {
+            return Err(to_py_value_err(&format!(
+                "proposal {id:?} is {:?} and no longer takes votes",
+                proposal.status
+            )));
+        }
+        proposal.votes.retain(|v| v.member != vote.member);
+        proposal.votes.push(vote);
+        proposal.status = proposal.tally(members, threshold);
+        Ok(proposal.status)
+    }
+}
+
+#[richcmp_eq_only]
+#[common_methods]
+#[pymethods]
+impl ThresholdApproval {
+    #[new]
+    pub fn new(members: Vec<Pubkey>, threshold: usize) -> PyResult<Self> {
+        Self::check_members(&members, threshold).map_err(|e| to_py_value_err(&e))?;
+        Ok(Self {
+            version: THRESHOLD_APPROVAL_FORMAT_VERSION,
+            members,
+            threshold,
+            proposals: Vec::new(),
+        })
+    }
+
+    /// List[Pubkey]: The members who can vote.
+    #[getter]
+    pub fn members(&self) -> Vec<Pubkey> {
+        self.members.clone()
+    }
+
+    /// int: The approvals a proposal needs.
+    #[getter]
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// List[ApprovalProposal]: Every proposal, in the order proposed.
+    #[getter]
+    pub fn proposals(&self) -> Vec<ApprovalProposal> {
+        self.proposals.clone()
+    }
+
+    /// Start tracking a proposal.
+    ///
+    /// Args:
+    ///     id (str): A unique ID, e.g. the Squads transaction index or a governance proposal address.
+    ///     payload (bytes): What members sign to approve it.
+    ///
+    /// Returns:
+    ///     bool: ``False`` if a proposal with this ID is already tracked.
+    ///
+    pub fn propose(&mut self, id: String, payload: Vec<u8>) -> bool {
+        if self.proposals.iter().any(|p| p.id == id) {
+            return false;
+        }
+        self.proposals.push(ApprovalProposal {
+            id,
+            payload,
+            status: ApprovalStatus::Pending,
+            votes: Vec::new(),
+            execution_signature: None,
+        });
+        true
+    }
+
+    /// Look up a proposal.
+    ///
+    /// Args:
+    ///     id (str): The proposal ID.
+    ///
+    /// Returns:
+    ///     Optional[ApprovalProposal]: The proposal, if tracked.
+    ///
+    pub fn proposal(&self, id: &str) -> Option<ApprovalProposal> {
+        self.proposals.iter().find(|p| p.id == id).cloned()
+    }
+
+    /// Record an approval signed off-chain.
+    ///
+    /// Args:
+    ///     id (str): The proposal ID.
+    ///     member (Pubkey): The approving member.
+    ///     signature (Signature): The member's signature of the proposal payload.
+    ///
+    /// Returns:
+    ///     ApprovalStatus: The proposal's status after the vote.
+    ///
+    /// Raises:
+    ///     ValueError: If the proposal is unknown or no longer pending, ``member``
+    ///         isn't a member, or the signature is invalid.
+    ///
+    pub fn approve(
+        &mut self,
+        id: &str,
+        member: Pubkey,
+        signature: Signature,
+    ) -> PyResult<ApprovalStatus> {
+        let payload = self.get_mut(id)?.payload.clone();
+        if !SignatureOriginal::from(signature).verify(member.as_ref(), &payload) {
+            return Err(to_py_value_err(&format!(
+                "invalid signature for {}",
+                member
+            )));
+        }
+        self.vote(id, ApprovalVote::new(member, true, Some(signature)))
+    }
+
+    /// Record a vote read from an on-chain vote record.
+    ///
+    /// Args:
+    ///     id (str): The proposal ID.
+    ///     member (Pubkey): The voting member.
+    ///     approve (bool): Whether the member approved.
+    ///
+    /// Returns:
+    ///     ApprovalStatus: The proposal's status after the vote.
+    ///
+    /// Raises:
+    ///     ValueError: If the proposal is unknown or no longer pending, or ``member``
+    ///         isn't a member.
+    ///
+    pub fn record_vote(
+        &mut self,
+        id: &str,
+        member: Pubkey,
+        approve: bool,
+    ) -> PyResult<ApprovalStatus> {
+        self.vote(id, ApprovalVote::new(member, approve, None))
+    }
+
+    /// Record the votes and outcome of a Squads proposal account.
+    ///
+    /// Votes are recorded until the proposal is approved or rejected; the rest are skipped.
+    ///
+    /// Args:
+    ///     id (str): The proposal ID.
+    ///     proposal (Proposal): The fetched Squads proposal.
+    ///
+    /// Returns:
+    ///     ApprovalStatus: The proposal's status after syncing.
+    ///
+    /// Raises:
+    ///     ValueError: If the proposal is unknown, or a voter isn't a member.
+    ///
+    pub fn sync_squads(&mut self, id: &str, proposal: &Proposal) -> PyResult<ApprovalStatus> {
+        let mut status = self.get_mut(id)?.status;
+        if status.is_final() {
+            return Ok(status);
+        }
+        'votes: for (voters, approve) in [(&proposal.approved, true), (&proposal.rejected, false)] {
+            for member in voters {
+                if status != ApprovalStatus::Pending {
+                    break 'votes;
+                }
+                status = self.record_vote(id, *member, approve)?;
+            }
+        }
+        match proposal.status {
+            ProposalStatus::Executed if status == ApprovalStatus::Approved => {
+                self.mark_executed(id, None)?;
+                status = ApprovalStatus::Executed;
+            }
+            ProposalStatus::Cancelled => {
+                self.cancel(id)?;
+                status = ApprovalStatus::Cancelled;
+            }
+            _ => {}
+        }
+        Ok(status)
+    }
+
+    /// Mark an approved proposal as executed.
+    ///
+    /// Args:
+    ///     id (str): The proposal ID.
+    ///     signature (Optional[Signature]): The executing transaction.
+    ///
+    /// Raises:
+    ///     ValueError: If the proposal is unknown or not approved.
+    ///
+    #[pyo3(signature = (id, signature = None))]
+    pub fn mark_executed(&mut self, id: &str, signature: Option<Signature>) -> PyResult<()> {
+        let proposal = self.get_mut(id)?;
+        if proposal.status != ApprovalStatus::Approved {
+            return Err(to_py_value_err(&format!(
+                "proposal {id:?} is {:?}, not approved",
+                proposal.status
+            )));
+        }
+        proposal.status = ApprovalStatus::Executed;
+        proposal.execution_signature = signature;
+        Ok(())
+    }
+
+    /// Withdraw a proposal that hasn't been executed.
+    ///
+    /// Args:
+    ///     id (str): The proposal ID.
+    ///
+    /// Raises:
+    ///     ValueError: If the proposal is unknown or already executed.
+    ///
+    pub fn cancel(&mut self, id: &str) -> PyResult<()> {
+        let proposal = self.get_mut(id)?;
+        if proposal.status == ApprovalStatus::Executed {
+            return Err(to_py_value_err(&format!("proposal {id:?} is Executed")));
+        }
+        proposal.status = ApprovalStatus::Cancelled;
+        Ok(())
+    }
+
+    /// The members who haven't voted on a proposal.
+    ///
+    /// Args:
+    ///     id (str): The proposal ID.
+    ///
+    /// Returns:
+    ///     List[Pubkey]: The members, in member order.
+    ///
+    /// Raises:
+    ///     ValueError: If the proposal is unknown.
+    ///
+    pub fn missing(&self, id: &str) -> PyResult<Vec<Pubkey>> {
+        let proposal = self
+            .proposal(id)
+            .ok_or_else(|| to_py_value_err(&format!("unknown proposal {id:?}")))?;
+        Ok(self
+            .members
+            .iter()
+            .filter(|m| proposal.votes.iter().all(|v| v.member != **m))
+            .copied()
+            .collect())
+    }
+
+    /// The proposals ready to execute.
+    ///
+    /// Returns:
+    ///     List[str]: The IDs of approved proposals, in the order proposed.
+    ///
+    pub fn ready(&self) -> Vec<String> {
+        self.proposals
+            .iter()
+            .filter(|p| p.is_ready())
+            .map(|p| p.id.clone())
+            .collect()
+    }
+
+    fn __len__(&self) -> usize {
+        self.proposals.len()
+    }
+}
+
+impl RichcmpEqualityOnly for ThresholdApproval {}
+pybytes_general_via_bincode!(ThresholdApproval);
+solders_traits::common_methods_default!(ThresholdApproval);
+
+impl fmt::Display for ThresholdApproval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl PyFromBytesGeneral for ThresholdApproval {
+    fn py_from_bytes_general(raw: &[u8]) -> PyResult<Self> {
+        match raw.first() {
+            Some(&THRESHOLD_APPROVAL_FORMAT_VERSION) => {
+                bincode::deserialize(raw).map_err(|e| to_py_value_err(&e))
+            }
+            Some(v) => Err(to_py_value_err(&format!(
+                "unsupported threshold approval format version {v}"
+            ))),
+            None => Err(to_py_value_err(&"empty threshold approval data")),
+        }
+    }
+}
+
+pub(crate) fn create_approval_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "approval")?;
+    m.add_class::<ApprovalStatus>()?;
+    m.add_class::<ApprovalVote>()?;
+    m.add_class::<ApprovalProposal>()?;
+    m.add_class::<ThresholdApproval>()?;
+    Ok(m)
+}
@@ -0,0 +1,304 @@
+use std::collections::HashSet;
+
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use solana_sdk::{
+    compute_budget, ed25519_program, message::Message as MessageOriginal,
+    pubkey::Pubkey as PubkeyOriginal, sanitize::Sanitize, secp256k1_program, stake,
+    system_instruction::SystemInstruction, system_program, vote,
+};
+use solders_macros::{enum_string, richcmp_eq_only};
+use solders_primitives::{
+    address_lookup_table_account::AddressLookupTableAccount, message::VersionedMessage,
+    pubkey::Pubkey, transaction::VersionedTransaction,
+};
+use solders_traits::{to_py_value_err, EnumString, RichcmpEqualityOnly};
+
+use crate::{
+    program_ids::{ADDRESS_LOOKUP_TABLE_PROGRAM_ID, MEMO_PROGRAM_ID, MEMO_V1_PROGRAM_ID},
+    spl_errors::{ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID},
+};
+
+const TOKEN_APPROVE: u8 = 4;
+const TOKEN_SET_AUTHORITY: u8 = 6;
+const TOKEN_APPROVE_CHECKED: u8 = 13;
+
+/// Native and SPL programs whose behaviour is well known.
+const KNOWN_PROGRAMS: [PubkeyOriginal; 12] = [
+    system_program::ID,
+    compute_budget::ID,
+    stake::program::ID,
+    vote::program::ID,
+    ed25519_program::ID,
+    secp256k1_program::ID,
+    ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
+    TOKEN_PROGRAM_ID,
+    TOKEN_2022_PROGRAM_ID,
+    ASSOCIATED_TOKEN_PROGRAM_ID,
+    MEMO_PROGRAM_ID,
+    MEMO_V1_PROGRAM_ID,
+];
+
+/// A pattern in an instruction that a transaction screener should look at.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[pyclass(module = "solders.audit")]
+pub enum PrivilegeFlag {
+    /// The program is neither well known nor trusted.
+    UnknownProgram,
+    /// A signer is passed read-only to an unknown program, which can use its
+    /// signature in CPIs.
+    SignerToUnknownProgram,
+    /// A signer is passed writable to an unknown program.
+    WritableSignerToUnknownProgram,
+    /// A System Program ``Assign`` hands an account to another owner.
+    SystemAssign,
+    /// A token ``Approve`` lets a delegate move tokens.
+    TokenApprove,
+    /// A token ``SetAuthority`` changes an account or mint authority.
+    TokenSetAuthority,
+}
+
+impl EnumString for PrivilegeFlag {}
+
+#[enum_string]
+#[pymethods]
+impl PrivilegeFlag {}
+
+/// The privileges an instruction receives for one account.
+#[pyclass(module = "solders.audit", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccountPrivilege {
+    /// Pubkey: The account address.
+    #[pyo3(get)]
+    pub pubkey: Pubkey,
+    /// bool: Whether the account signed the transaction.
+    #[pyo3(get)]
+    pub is_signer: bool,
+    /// bool: Whether the account is writable, after the runtime demotes
+    /// program IDs and reserved accounts.
+    #[pyo3(get)]
+    pub is_writable: bool,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl AccountPrivilege {
+    #[new]
+    pub fn new(pubkey: Pubkey, is_signer: bool, is_writable: bool) -> Self {
+        Self {
+            pubkey,
+            is_signer,
+            is_writable,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for AccountPrivilege {}
+
+/// The privilege audit of one top-level instruction, as returned by :func:`audit_privileges`.
+#[pyclass(module = "solders.audit", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InstructionAudit {
+    /// int: The instruction's position in the message.
+    #[pyo3(get)]
+    pub index: usize,
+    /// Pubkey: The invoked program.
+    #[pyo3(get)]
+    pub program_id: Pubkey,
+    /// List[AccountPrivilege]: The instruction's accounts, in instruction order.
+    #[pyo3(get)]
+    pub accounts: Vec<AccountPrivilege>,
+    /// List[PrivilegeFlag]: The suspicious patterns found.
+    #[pyo3(get)]
+    pub flags: Vec<PrivilegeFlag>,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl InstructionAudit {
+    #[new]
+    pub fn new(
+        index: usize,
+        program_id: Pubkey,
+        accounts: Vec<AccountPrivilege>,
+        flags: Vec<PrivilegeFlag>,
+    ) -> Self {
+        Self {
+            index,
+            program_id,
+            accounts,
+            flags,
+        }
+    }
+
+    /// List[Pubkey]: The signers passed to the instruction.
+    #[getter]
+    pub fn signers(&self) -> Vec<Pubkey> {
+        self.accounts
+            .iter()
+            .filter(|a| a.is_signer)
+            .map(|a| a.pubkey)
+            .collect()
+    }
+
+    /// List[Pubkey]: The writable accounts passed to the instruction.
+    #[getter]
+    pub fn writable(&self) -> Vec<Pubkey> {
+        self.accounts
+            .iter()
+            .filter(|a| a.is_writable)
+            .map(|a| a.pubkey)
+            .collect()
+    }
+
+    /// bool: Whether any pattern was flagged.
+    #[getter]
+    pub fn is_suspicious(&self) -> bool {
+        !self.flags.is_empty()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for InstructionAudit {}
+
+fn instruction_flags(
+    program_id: &PubkeyOriginal,
+    data: &[u8],
+    accounts: &[AccountPrivilege],
+    trusted: &HashSet<PubkeyOriginal>,
+) -> Vec<PrivilegeFlag> {
+    let mut flags = Vec::new();
+    if !KNOWN_PROGRAMS.contains(program_id) && !trusted.contains(program_id) {
+        flags.push(PrivilegeFlag::UnknownProgram);
+        let signers = accounts.iter().filter(|a| a.is_signer);
+        if signers.clone().any(|a| a.is_writable) {
+            flags.push(PrivilegeFlag::WritableSignerToUnknownProgram);
+        } else if signers.count() > 0 {
+            flags.push(PrivilegeFlag::SignerToUnknownProgram);
+        }
+    } else if program_id == &system_program::ID {
+        if let Ok(SystemInstruction::Assign { .. } | SystemInstruction::AssignWithSeed { .. }) =
+            bincode::deserialize::<SystemInstruction>(data)
+        {
+            flags.push(PrivilegeFlag::SystemAssign);
+        }
+    } else if program_id == &TOKEN_PROGRAM_ID || program_id == &TOKEN_2022_PROGRAM_ID {
+        match data.first() {
+            Some(&TOKEN_APPROVE) | Some(&TOKEN_APPROVE_CHECKED) => {
+                flags.push(PrivilegeFlag::TokenApprove)
+            }
+            Some(&TOKEN_SET_AUTHORITY) => flags.push(PrivilegeFlag::TokenSetAuthority),
+            _ => {}
+        }
+    }
+    flags
+}
+
+fn audit_message(
+    message: &MessageOriginal,
+    trusted: &HashSet<PubkeyOriginal>,
+) -> Vec<InstructionAudit> {
+    message
+        .instructions
+        .iter()
+        .enumerate()
+        .map(|(index, ix)| {
+            let program_id = message.account_keys[ix.program_id_index as usize];
+            let accounts: Vec<AccountPrivilege> = ix
+                .accounts
+                .iter()
+                .map(|&i| {
+                    let i = i as usize;
+                    AccountPrivilege {
+                        pubkey: message.account_keys[i].into(),
+                        is_signer: message.is_signer(i),
+                        is_writable: message.is_writable(i),
+                    }
+                })
+                .collect();
+            let flags = instruction_flags(&program_id, &ix.data, &accounts, trusted);
+            InstructionAudit {
+                index,
+                program_id: program_id.into(),
+                accounts,
+                flags,
+            }
+        })
+        .collect()
+}
+
+/// Report which accounts each instruction of a transaction can write to or sign
+/// for, and flag patterns a custodial wallet should screen.
+///
+/// Programs other than the well-known native and SPL ones, and those in
+/// ``trusted_programs``, are flagged as unknown, along with any signer passed to
+/// them: an unknown program can use a signer's privileges in CPIs, such as to
+/// transfer its lamports. Instructions of known programs are flagged when they
+/// reassign an account, approve a token delegate or change a token authority.
+///
+/// Args:
+///     tx (VersionedTransaction): The transaction. Signatures are not verified.
+///     trusted_programs (Optional[Sequence[Pubkey]]): Programs not to flag as unknown.
+///     address_lookup_table_accounts (Optional[Sequence[AddressLookupTableAccount]]):
+///         The lookup tables referenced by a versioned message.
+///
+/// Returns:
+///     List[InstructionAudit]: One audit per top-level instruction, in order.
+///
+/// Raises:
+///     ValueError: If the message is invalid or a lookup table is missing.
+///
+/// Example:
+///     >>> from solders.audit import audit_privileges, PrivilegeFlag
+///     >>> from solders.instruction import AccountMeta, Instruction
+///     >>> from solders.keypair import Keypair
+///     >>> from solders.message import Message
+///     >>> from solders.pubkey import Pubkey
+///     >>> from solders.transaction import VersionedTransaction
+///     >>> wallet, program = Keypair(), Pubkey.new_unique()
+///     >>> ix = Instruction(program, b"", [AccountMeta(wallet.pubkey(), True, True)])
+///     >>> tx = VersionedTransaction(Message([ix], wallet.pubkey()), [wallet])
+///     >>> [audit] = audit_privileges(tx)
+///     >>> audit.flags == [PrivilegeFlag.UnknownProgram, PrivilegeFlag.WritableSignerToUnknownProgram]
+///     True
+///     >>> audit_privileges(tx, trusted_programs=[program])[0].flags
+///     []
+///
+#[pyfunction]
+#[pyo3(signature = (tx, trusted_programs = None, address_lookup_table_accounts = None))]
+pub fn audit_privileges(
+    tx: VersionedTransaction,
+    trusted_programs: Option<Vec<Pubkey>>,
+    address_lookup_table_accounts: Option<Vec<AddressLookupTableAccount>>,
+) -> PyResult<Vec<InstructionAudit>> {
+    let legacy = match tx.message() {
+        VersionedMessage::Legacy(m) => m,
+        VersionedMessage::V0(m) => {
+            m.expand_to_legacy(address_lookup_table_accounts.unwrap_or_default())?
+        }
+    };
+    legacy.0.sanitize().map_err(|e| to_py_value_err(&e))?;
+    let trusted: HashSet<PubkeyOriginal> = trusted_programs
+        .unwrap_or_default()
+        .iter()
+        .map(PubkeyOriginal::from)
+        .collect();
+    Ok(audit_message(&legacy.0, &trusted))
+}
+
+pub(crate) fn create_audit_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "audit")?;
+    m.add_class::<PrivilegeFlag>()?;
+    m.add_class::<AccountPrivilege>()?;
+    m.add_class::<InstructionAudit>()?;
+    m.add_function(wrap_pyfunction!(audit_privileges, m)?)?;
+    Ok(m)
+}
@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+
+use pyo3::prelude::*;
+use solana_sdk::{
+    account::Account as AccountOriginal,
+    blake3,
+    hash::{hashv, Hash as HashOriginal, Hasher},
+    pubkey::Pubkey as PubkeyOriginal,
+};
+use solders_primitives::{hash::Hash as SolderHash, pubkey::Pubkey};
+
+use crate::account::Account;
+
+/// The number of hashes combined at each level of the accounts merkle tree.
+pub const MERKLE_FANOUT: usize = 16;
+
+fn account_hash_original(pubkey: &PubkeyOriginal, account: &AccountOriginal) -> HashOriginal {
+    if account.lamports == 0 {
+        return HashOriginal::default();
+    }
+    let hash = blake3::hashv(&[
+        &account.lamports.to_le_bytes(),
+        &account.rent_epoch.to_le_bytes(),
+        &account.data,
+        &[u8::from(account.executable)],
+        account.owner.as_ref(),
+        pubkey.as_ref(),
+    ]);
+    HashOriginal::new_from_array(hash.0)
+}
+
+fn merkle_root(mut hashes: Vec<HashOriginal>) -> HashOriginal {
+    loop {
+        hashes = if hashes.is_empty() {
+            vec![Hasher::default().result()]
+        } else {
+            hashes
+                .chunks(MERKLE_FANOUT)
+                .map(|chunk| {
+                    let mut hasher = Hasher::default();
+                    for hash in chunk {
+                        hasher.hash(hash.as_ref());
+                    }
+                    hasher.result()
+                })
+                .collect()
+        };
+        if hashes.len() == 1 {
+            return hashes[0];
+        }
+    }
+}
+
+/// Hash an account the way the accounts database does.
+///
+/// Accounts with zero lamports hash to the default hash, since they are deleted.
+///
+/// Args:
+///     pubkey (Pubkey): The account address.
+///     account (Account): The account.
+///
+/// Returns:
+///     Hash: The blake3 account hash.
+///
+#[pyfunction]
+pub fn account_hash(pubkey: Pubkey, account: Account) -> SolderHash {
+    account_hash_original(&pubkey.0, &account.into()).into()
+}
+
+/// Compute the accounts delta hash of a slot.
+///
+/// This is the root of a merkle tree with fanout 16 over the hashes of the
+/// accounts written in the slot, sorted by address.
+///
+/// Args:
+///     accounts (Dict[Pubkey, Account]): The final state of every account written in the slot.
+///
+/// Returns:
+///     Hash: The accounts delta hash.
+///
+#[pyfunction]
+pub fn accounts_delta_hash(accounts: HashMap<Pubkey, Account>) -> SolderHash {
+    let mut accounts: Vec<(PubkeyOriginal, AccountOriginal)> = accounts
+        .into_iter()
+        .map(|(pubkey, account)| (pubkey.into(), account.into()))
+        .collect();
+    accounts.sort_unstable_by_key(|(pubkey, _)| *pubkey);
+    let hashes = accounts
+        .iter()
+        .map(|(pubkey, account)| account_hash_original(pubkey, account))
+        .collect();
+    merkle_root(hashes).into()
+}
+
+/// Compute a bank hash from its components.
+///
+/// The bank hash commits to the parent bank hash, the accounts written in the slot,
+/// the number of signatures and the last PoH hash of the slot. Slots in the epoch
+/// accounts hash window also mix in that hash, and the first slot after a hard fork
+/// mixes in the number of hard forks at that slot.
+///
+/// Args:
+///     parent_hash (Hash): The bank hash of the parent slot.
+///     accounts_delta_hash (Hash): See :func:`accounts_delta_hash`.
+///     signature_count (int): The number of signatures in the slot's transactions.
+///     last_blockhash (Hash): The blockhash of the slot.
+///     epoch_accounts_hash (Optional[Hash]): The epoch accounts hash, if the slot mixes it in.
+///     hard_fork_count (Optional[int]): The number of hard forks, if the slot is the first after one.
+///
+/// Returns:
+///     Hash: The bank hash.
+///
+/// Example:
+///     >>> from hashlib import sha256
+///     >>> from solders.bank_hash import bank_hash
+///     >>> from solders.hash import Hash
+///     >>> parent, delta, blockhash = Hash.new_unique(), Hash.new_unique(), Hash.new_unique()
+///     >>> count = (3).to_bytes(8, "little")
+///     >>> expected = sha256(bytes(parent) + bytes(delta) + count + bytes(blockhash))
+///     >>> bank_hash(parent, delta, 3, blockhash) == Hash(expected.digest())
+///     True
+///
+#[pyfunction]
+#[pyo3(signature = (
+    parent_hash,
+    accounts_delta_hash,
+    signature_count,
+    last_blockhash,
+    epoch_accounts_hash = None,
+    hard_fork_count = None,
+))]
+pub fn bank_hash(
+    parent_hash: SolderHash,
+    accounts_delta_hash: SolderHash,
+    signature_count: u64,
+    last_blockhash: SolderHash,
+    epoch_accounts_hash: Option<SolderHash>,
+    hard_fork_count: Option<u64>,
+) -> SolderHash {
+    let mut hash = hashv(&[
+        HashOriginal::from(parent_hash).as_ref(),
+        HashOriginal::from(accounts_delta_hash).as_ref(),
+        &signature_count.to_le_bytes(),
+        HashOriginal::from(last_blockhash).as_ref(),
+    ]);
+    if let Some(epoch_accounts_hash) = epoch_accounts_hash {
+        hash = hashv(&[
+            hash.as_ref(),
+            HashOriginal::from(epoch_accounts_hash).as_ref(),
+        ]);
+    }
+    if let Some(count) = hard_fork_count {
+        hash = hashv(&[hash.as_ref(), &count.to_le_bytes()]);
+    }
+    hash.into()
+}
+
+pub(crate) fn create_bank_hash_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "bank_hash")?;
+    m.add("MERKLE_FANOUT", MERKLE_FANOUT)?;
+    m.add_function(wrap_pyfunction!(account_hash, m)?)?;
+    m.add_function(wrap_pyfunction!(accounts_delta_hash, m)?)?;
+    m.add_function(wrap_pyfunction!(bank_hash, m)?)?;
+    Ok(m)
+}
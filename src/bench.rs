@@ -0,0 +1,388 @@
+use std::time::Instant;
+
+use pyo3::{
+    prelude::*,
+    types::{PyBytes, PyDict},
+};
+use serde::{Deserialize, Serialize};
+use solana_sdk::{
+    hash::Hash as HashOriginal, pubkey::Pubkey as PubkeyOriginal,
+    signature::Keypair as KeypairOriginal, signer::Signer, system_instruction,
+    transaction::Transaction as TransactionOriginal,
+};
+use solders_macros::{enum_string, richcmp_eq_only};
+use solders_primitives::{keypair::Keypair, transaction::Transaction};
+use solders_traits::{to_py_value_err, EnumString, RichcmpEqualityOnly};
+
+/// The batch size used when none is given.
+pub const DEFAULT_BATCH_SIZE: usize = 1_000;
+/// The most threads a benchmark can run on.
+pub const MAX_THREADS: usize = 64;
+
+/// A workload measured by :func:`run_benchmark`.
+///
+/// Every operation works on signed single-transfer legacy transactions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[pyclass(module = "solders.bench")]
+pub enum BenchOperation {
+    /// Bincode-serialize a transaction.
+    Serialize,
+    /// Bincode-deserialize a transaction.
+    Deserialize,
+    /// Sign a transaction message.
+    Sign,
+    /// Verify the signatures of a transaction.
+    Verify,
+}
+
+impl EnumString for BenchOperation {}
+
+impl BenchOperation {
+    const ALL: [Self; 4] = [Self::Serialize, Self::Deserialize, Self::Sign, Self::Verify];
+}
+
+#[enum_string]
+#[pymethods]
+impl BenchOperation {}
+
+/// The outcome of a benchmark run, as returned by :func:`run_benchmark`.
+#[pyclass(module = "solders.bench", subclass)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BenchResult {
+    /// BenchOperation: The measured operation.
+    #[pyo3(get)]
+    pub operation: BenchOperation,
+    /// int: Transactions processed by each thread per round.
+    #[pyo3(get)]
+    pub batch_size: usize,
+    /// int: The number of Python threads the batches were spread over.
+    #[pyo3(get)]
+    pub threads: usize,
+    /// int: How many times each thread processed its batch.
+    #[pyo3(get)]
+    pub rounds: usize,
+    /// float: Wall-clock seconds for all threads to finish.
+    #[pyo3(get)]
+    pub seconds: f64,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl BenchResult {
+    #[new]
+    pub fn new(
+        operation: BenchOperation,
+        batch_size: usize,
+        threads: usize,
+        rounds: usize,
+        seconds: f64,
+    ) -> Self {
+        Self {
+            operation,
+            batch_size,
+            threads,
+            rounds,
+            seconds,
+        }
+    }
+
+    /// int: The total number of operations performed.
+    #[getter]
+    pub fn operations(&self) -> usize {
+        self.batch_size * self.threads * self.rounds
+    }
+
+    /// float: Operations per wall-clock second, across all threads.
+    #[getter]
+    pub fn ops_per_second(&self) -> f64 {
+        if self.seconds <= 0.0 {
+            return f64::INFINITY;
+        }
+        self.operations() as f64 / self.seconds
+    }
+
+    /// How many times the throughput of ``baseline`` this run achieved.
+    ///
+    /// Compare a multi-threaded run with a single-threaded one to check that
+    /// the operation scales with threads.
+    ///
+    /// Args:
+    ///     baseline (BenchResult): The run to compare against.
+    ///
+    /// Returns:
+    ///     float: The throughput ratio.
+    ///
+    pub fn speedup(&self, baseline: Self) -> f64 {
+        self.ops_per_second() / baseline.ops_per_second()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for BenchResult {}
+
+/// The inputs one thread works on, built before the clock starts.
+///
+/// Called as the target of a Python thread, it runs its batch ``rounds`` times
+/// through the Python API and keeps the first error for the caller.
+#[pyclass(module = "solders.bench")]
+struct Workload {
+    operation: BenchOperation,
+    rounds: usize,
+    keypair: Py<Keypair>,
+    transactions: Vec<Py<Transaction>>,
+    serialized: Vec<Py<PyBytes>>,
+    messages: Vec<Py<PyBytes>>,
+    error: Option<PyErr>,
+}
+
+impl Workload {
+    fn new(
+        py: Python<'_>,
+        operation: BenchOperation,
+        batch_size: usize,
+        rounds: usize,
+    ) -> PyResult<Self> {
+        let payer = KeypairOriginal::new();
+        let to = PubkeyOriginal::new_unique();
+        let originals: Vec<TransactionOriginal> = (0..batch_size)
+            .map(|i| {
+                let ix = system_instruction::transfer(&payer.pubkey(), &to, i as u64 + 1);
+                TransactionOriginal::new_signed_with_payer(
+                    &[ix],
+                    Some(&payer.pubkey()),
+                    &[&payer],
+                    HashOriginal::default(),
+                )
+            })
+            .collect();
+        let serialized = match operation {
+            BenchOperation::Deserialize => originals
+                .iter()
+                .map(|tx| PyBytes::new(py, &bincode::serialize(tx).unwrap()).into())
+                .collect(),
+            _ => Vec::new(),
+        };
+        let messages = match operation {
+            BenchOperation::Sign => originals
+                .iter()
+                .map(|tx| PyBytes::new(py, &tx.message_data()).into())
+                .collect(),
+            _ => Vec::new(),
+        };
+        let transactions = originals
+            .into_iter()
+            .map(|tx| Py::new(py, Transaction::from(tx)))
+            .collect::<PyResult<_>>()?;
+        Ok(Self {
+            operation,
+            rounds,
+            keypair: Py::new(py, Keypair::from(payer))?,
+            transactions,
+            serialized,
+            messages,
+            error: None,
+        })
+    }
+
+    fn run(&self, py: Python<'_>) -> PyResult<()> {
+        match self.operation {
+            BenchOperation::Serialize => {
+                for tx in &self.transactions {
+                    tx.call_method0(py, "__bytes__")?;
+                }
+            }
+            BenchOperation::Deserialize => {
+                let transaction_type = py.get_type::<Transaction>();
+                for raw in &self.serialized {
+                    transaction_type.call_method1("from_bytes", (raw.as_ref(py),))?;
+                }
+            }
+            BenchOperation::Sign => {
+                for message in &self.messages {
+                    self.keypair
+                        .call_method1(py, "sign_message", (message.as_ref(py),))?;
+                }
+            }
+            BenchOperation::Verify => {
+                for tx in &self.transactions {
+                    tx.call_method0(py, "verify")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[pymethods]
+impl Workload {
+    fn __call__(&mut self, py: Python<'_>) {
+        for _ in 0..self.rounds {
+            if let Err(e) = self.run(py) {
+                self.error = Some(e);
+                return;
+            }
+        }
+    }
+}
+
+fn check_positive(name: &str, value: usize) -> PyResult<()> {
+    if value == 0 {
+        Err(to_py_value_err(&format!("{} must be positive", name)))
+    } else {
+        Ok(())
+    }
+}
+
+fn check_threads(threads: usize) -> PyResult<()> {
+    check_positive("threads", threads)?;
+    if threads > MAX_THREADS {
+        Err(to_py_value_err(&format!(
+            "threads must be at most {} (got {})",
+            MAX_THREADS, threads
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// Measure the throughput of an operation on this machine.
+///
+/// Each thread is a Python thread with its own batch of transactions, built
+/// before timing starts, which it processes ``rounds`` times by calling the
+/// solders Python API. The timing therefore includes the GIL: an operation
+/// only gets faster with more threads if its calls release the GIL.
+///
+/// Args:
+///     operation (BenchOperation): The operation to measure.
+///     batch_size (int): Transactions per thread. Defaults to 1000.
+///     threads (int): The number of Python threads to run the batches on,
+///         at most ``MAX_THREADS``. Defaults to 1.
+///     rounds (int): How many times each thread processes its batch. Defaults to 1.
+///
+/// Returns:
+///     BenchResult: The timing.
+///
+/// Raises:
+///     ValueError: If ``batch_size``, ``threads`` or ``rounds`` is zero,
+///         or ``threads`` is above ``MAX_THREADS``.
+///
+/// Example:
+///     >>> from solders.bench import BenchOperation, run_benchmark
+///     >>> result = run_benchmark(BenchOperation.Serialize, batch_size=10, threads=2)
+///     >>> result.operations
+///     20
+///     >>> result.ops_per_second > 0
+///     True
+///
+#[pyfunction]
+#[pyo3(signature = (operation, batch_size = DEFAULT_BATCH_SIZE, threads = 1, rounds = 1))]
+pub fn run_benchmark(
+    py: Python<'_>,
+    operation: BenchOperation,
+    batch_size: usize,
+    threads: usize,
+    rounds: usize,
+) -> PyResult<BenchResult> {
+    check_positive("batch_size", batch_size)?;
+    check_threads(threads)?;
+    check_positive("rounds", rounds)?;
+    let workloads = (0..threads)
+        .map(|_| Py::new(py, Workload::new(py, operation, batch_size, rounds)?))
+        .collect::<PyResult<Vec<_>>>()?;
+    let thread_type = py.import("threading")?.getattr("Thread")?;
+    let python_threads = workloads
+        .iter()
+        .map(|workload| {
+            let kwargs = PyDict::new(py);
+            kwargs.set_item("target", workload)?;
+            thread_type.call((), Some(kwargs))
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+    let start = Instant::now();
+    for thread in &python_threads {
+        thread.call_method0("start")?;
+    }
+    for thread in &python_threads {
+        thread.call_method0("join")?;
+    }
+    let seconds = start.elapsed().as_secs_f64();
+    for workload in &workloads {
+        if let Some(e) = workload.borrow_mut(py).error.take() {
+            return Err(e);
+        }
+    }
+    Ok(BenchResult {
+        operation,
+        batch_size,
+        threads,
+        rounds,
+        seconds,
+    })
+}
+
+/// Run :func:`run_benchmark` for every combination of operation, batch size and thread count.
+///
+/// Args:
+///     batch_sizes (Sequence[int]): The batch sizes to try.
+///     threads (Sequence[int]): The thread counts to try, each at most ``MAX_THREADS``.
+///         Defaults to ``[1]``.
+///     operations (Optional[Sequence[BenchOperation]]): The operations to measure.
+///         Defaults to all of them.
+///     rounds (int): How many times each thread processes its batch. Defaults to 1.
+///
+/// Returns:
+///     List[BenchResult]: One result per combination, grouped by operation,
+///     then batch size, then thread count.
+///
+/// Raises:
+///     ValueError: If any batch size, thread count or ``rounds`` is zero,
+///         or a thread count is above ``MAX_THREADS``.
+///
+#[pyfunction]
+#[pyo3(signature = (batch_sizes, threads = vec![1], operations = None, rounds = 1))]
+pub fn run_suite(
+    py: Python<'_>,
+    batch_sizes: Vec<usize>,
+    threads: Vec<usize>,
+    operations: Option<Vec<BenchOperation>>,
+    rounds: usize,
+) -> PyResult<Vec<BenchResult>> {
+    for batch_size in &batch_sizes {
+        check_positive("batch_size", *batch_size)?;
+    }
+    for thread_count in &threads {
+        check_threads(*thread_count)?;
+    }
+    check_positive("rounds", rounds)?;
+    let operations = operations.unwrap_or_else(|| BenchOperation::ALL.to_vec());
+    let mut results = Vec::new();
+    for operation in operations {
+        for batch_size in &batch_sizes {
+            for thread_count in &threads {
+                results.push(run_benchmark(
+                    py,
+                    operation,
+                    *batch_size,
+                    *thread_count,
+                    rounds,
+                )?);
+            }
+        }
+    }
+    Ok(results)
+}
+
+pub(crate) fn create_bench_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "bench")?;
+    m.add("DEFAULT_BATCH_SIZE", DEFAULT_BATCH_SIZE)?;
+    m.add("MAX_THREADS", MAX_THREADS)?;
+    m.add_class::<BenchOperation>()?;
+    m.add_class::<BenchResult>()?;
+    m.add_function(wrap_pyfunction!(run_benchmark, m)?)?;
+    m.add_function(wrap_pyfunction!(run_suite, m)?)?;
+    Ok(m)
+}
@@ -0,0 +1,249 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashSet},
+};
+
+use pyo3::prelude::*;
+use solana_sdk::{
+    instruction::Instruction as InstructionOriginal,
+    message::Message as MessageOriginal,
+    packet::PACKET_DATA_SIZE,
+    pubkey::Pubkey as PubkeyOriginal,
+    transaction::{Transaction as TransactionOriginal, MAX_TX_ACCOUNT_LOCKS},
+};
+use solders_macros::richcmp_eq_only;
+use solders_primitives::{instruction::Instruction, message::Message, pubkey::Pubkey};
+use solders_traits::{to_py_value_err, RichcmpEqualityOnly};
+
+/// The maximum number of transactions in a Jito bundle.
+const MAX_BUNDLE_LENGTH: usize = 5;
+
+/// A transaction produced by :func:`plan_transactions`.
+#[pyclass(module = "solders.bundle", subclass)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlannedTransaction {
+    /// Message: The unsigned message, paid for by the planner's ``payer``.
+    #[pyo3(get)]
+    pub message: Message,
+    /// List[int]: Indexes of the input instructions in this transaction, in execution order.
+    #[pyo3(get)]
+    pub instruction_indexes: Vec<usize>,
+    /// int: Transactions with the same stage neither depend on each other nor
+    /// contend for a write lock, so they can be sent concurrently once every
+    /// earlier stage has landed.
+    #[pyo3(get)]
+    pub stage: usize,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl PlannedTransaction {
+    #[new]
+    pub fn new(message: Message, instruction_indexes: Vec<usize>, stage: usize) -> Self {
+        Self {
+            message,
+            instruction_indexes,
+            stage,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for PlannedTransaction {}
+
+/// Order the instructions so each comes after its dependencies,
+/// preferring input order among instructions that are ready.
+fn topological_order(
+    num_instructions: usize,
+    dependencies: &[(usize, usize)],
+) -> PyResult<Vec<usize>> {
+    let mut dependents = vec![Vec::new(); num_instructions];
+    let mut in_degree = vec![0usize; num_instructions];
+    for &(before, after) in dependencies {
+        if before >= num_instructions || after >= num_instructions {
+            return Err(to_py_value_err(&format!(
+                "dependency ({before}, {after}) references a missing instruction"
+            )));
+        }
+        dependents[before].push(after);
+        in_degree[after] += 1;
+    }
+    let mut ready: BinaryHeap<Reverse<usize>> = in_degree
+        .iter()
+        .enumerate()
+        .filter(|(_, degree)| **degree == 0)
+        .map(|(i, _)| Reverse(i))
+        .collect();
+    let mut order = Vec::with_capacity(num_instructions);
+    while let Some(Reverse(i)) = ready.pop() {
+        order.push(i);
+        for &after in &dependents[i] {
+            in_degree[after] -= 1;
+            if in_degree[after] == 0 {
+                ready.push(Reverse(after));
+            }
+        }
+    }
+    if order.len() != num_instructions {
+        return Err(to_py_value_err(&"instruction dependencies contain a cycle"));
+    }
+    Ok(order)
+}
+
+/// The message for ``instructions`` if it fits in one transaction.
+fn try_compile(
+    instructions: &[InstructionOriginal],
+    payer: &PubkeyOriginal,
+    max_accounts: usize,
+) -> PyResult<Option<MessageOriginal>> {
+    let message = MessageOriginal::new(instructions, Some(payer));
+    if message.account_keys.len() > max_accounts {
+        return Ok(None);
+    }
+    let tx = TransactionOriginal::new_unsigned(message);
+    let size = bincode::serialized_size(&tx).map_err(|e| to_py_value_err(&e))?;
+    Ok((size <= PACKET_DATA_SIZE as u64).then_some(tx.message))
+}
+
+/// The writable and readonly accounts of ``message``, including the fee payer.
+fn locks(message: &MessageOriginal) -> (HashSet<PubkeyOriginal>, HashSet<PubkeyOriginal>) {
+    let mut writable = HashSet::new();
+    let mut readonly = HashSet::new();
+    for (i, key) in message.account_keys.iter().enumerate() {
+        if message.is_writable(i) {
+            writable.insert(*key);
+        } else {
+            readonly.insert(*key);
+        }
+    }
+    (writable, readonly)
+}
+
+/// Pack instructions into as few transactions as possible, respecting their dependencies.
+///
+/// Instructions are placed in dependency order, preferring input order, and
+/// each transaction is filled until the next instruction would exceed the
+/// packet size or the account limit. Each transaction is then assigned a
+/// stage: it runs after every earlier transaction it depends on or whose
+/// write locks conflict with its own. The fee payer is always write-locked,
+/// so transactions with the same payer never share a stage.
+///
+/// Args:
+///     instructions (Sequence[Instruction]): The instructions to send.
+///     payer (Pubkey): The fee payer of every transaction.
+///     dependencies (Optional[Sequence[Tuple[int, int]]]): ``(before, after)`` pairs of
+///         instruction indexes, meaning ``after`` must execute after ``before``.
+///     max_accounts (int): The maximum number of accounts per transaction. Defaults to 64.
+///     jito_bundle (bool): Whether the transactions will be sent as one atomic Jito bundle,
+///         which holds at most 5 transactions.
+///
+/// Returns:
+///     List[PlannedTransaction]: The transactions, in the order they must land.
+///
+/// Raises:
+///     ValueError: If the dependencies are invalid or cyclic, an instruction doesn't
+///         fit in a transaction on its own, or a Jito bundle would be too long.
+///
+/// Example:
+///     >>> from solders.bundle import plan_transactions
+///     >>> from solders.system_program import transfer, TransferParams
+///     >>> from solders.pubkey import Pubkey
+///     >>> payer = Pubkey.new_unique()
+///     >>> ixs = [transfer(TransferParams(from_pubkey=payer, to_pubkey=Pubkey.new_unique(), lamports=1)) for _ in range(30)]
+///     >>> plan = plan_transactions(ixs, payer)
+///     >>> [len(p.instruction_indexes) for p in plan]
+///     [21, 9]
+///     >>> [p.stage for p in plan]
+///     [0, 1]
+///
+#[pyfunction]
+#[pyo3(signature = (instructions, payer, dependencies = None, max_accounts = MAX_TX_ACCOUNT_LOCKS, jito_bundle = false))]
+pub fn plan_transactions(
+    instructions: Vec<Instruction>,
+    payer: Pubkey,
+    dependencies: Option<Vec<(usize, usize)>>,
+    max_accounts: usize,
+    jito_bundle: bool,
+) -> PyResult<Vec<PlannedTransaction>> {
+    let instructions: Vec<InstructionOriginal> = instructions.into_iter().map(|ix| ix.0).collect();
+    let dependencies = dependencies.unwrap_or_default();
+    let order = topological_order(instructions.len(), &dependencies)?;
+    let mut groups: Vec<(Vec<usize>, MessageOriginal)> = Vec::new();
+    let mut current: Vec<usize> = Vec::new();
+    let mut current_message: Option<MessageOriginal> = None;
+    for i in order {
+        let mut candidate = current.clone();
+        candidate.push(i);
+        let candidate_ixs: Vec<InstructionOriginal> =
+            candidate.iter().map(|j| instructions[*j].clone()).collect();
+        if let Some(message) = try_compile(&candidate_ixs, &payer.0, max_accounts)? {
+            current = candidate;
+            current_message = Some(message);
+            continue;
+        }
+        let single =
+            try_compile(&[instructions[i].clone()], &payer.0, max_accounts)?.ok_or_else(|| {
+                to_py_value_err(&format!("instruction {i} does not fit in a transaction"))
+            })?;
+        if let Some(message) = current_message.take() {
+            groups.push((current, message));
+        }
+        current = vec![i];
+        current_message = Some(single);
+    }
+    if let Some(message) = current_message {
+        groups.push((current, message));
+    }
+    if jito_bundle && groups.len() > MAX_BUNDLE_LENGTH {
+        return Err(to_py_value_err(&format!(
+            "instructions need {} transactions, but a bundle holds at most {MAX_BUNDLE_LENGTH}",
+            groups.len()
+        )));
+    }
+    let mut group_of = vec![0usize; instructions.len()];
+    for (g, (indexes, _)) in groups.iter().enumerate() {
+        for i in indexes {
+            group_of[*i] = g;
+        }
+    }
+    let group_locks: Vec<_> = groups.iter().map(|(_, message)| locks(message)).collect();
+    let mut stages: Vec<usize> = Vec::with_capacity(groups.len());
+    for (g, (writable, readonly)) in group_locks.iter().enumerate() {
+        let stage = (0..g)
+            .filter(|earlier| {
+                let (earlier_writable, earlier_readonly) = &group_locks[*earlier];
+                let depends = dependencies
+                    .iter()
+                    .any(|(before, after)| group_of[*before] == *earlier && group_of[*after] == g);
+                depends
+                    || !writable.is_disjoint(earlier_writable)
+                    || !writable.is_disjoint(earlier_readonly)
+                    || !readonly.is_disjoint(earlier_writable)
+            })
+            .map(|earlier| stages[earlier] + 1)
+            .max()
+            .unwrap_or(0);
+        stages.push(stage);
+    }
+    Ok(groups
+        .into_iter()
+        .zip(stages)
+        .map(
+            |((instruction_indexes, message), stage)| PlannedTransaction {
+                message: message.into(),
+                instruction_indexes,
+                stage,
+            },
+        )
+        .collect())
+}
+
+pub(crate) fn create_bundle_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "bundle")?;
+    m.add_class::<PlannedTransaction>()?;
+    m.add_function(wrap_pyfunction!(plan_transactions, m)?)?;
+    Ok(m)
+}
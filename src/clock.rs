@@ -0,0 +1,108 @@
+use derive_more::{From, Into};
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use solana_sdk::clock::{Clock as ClockOriginal, Epoch, Slot, UnixTimestamp};
+use solders_macros::{common_methods, richcmp_eq_only};
+
+use solders_traits::{
+    impl_display, py_from_bytes_general_via_bincode, pybytes_general_via_bincode,
+    RichcmpEqualityOnly,
+};
+
+/// The contents of the Clock sysvar.
+///
+/// ``bytes(clock)`` gives the sysvar account data, so custom values
+/// can be injected into test environments.
+///
+/// Args:
+///     slot (int): The current slot.
+///     epoch_start_timestamp (int): The timestamp of the first slot in this epoch.
+///     epoch (int): The current epoch.
+///     leader_schedule_epoch (int): The future epoch for which the leader schedule has most recently been calculated.
+///     unix_timestamp (int): The approximate real world time of the current slot.
+///
+/// Example:
+///     >>> from solders.clock import Clock
+///     >>> clock = Clock(slot=1, epoch_start_timestamp=0, epoch=0, leader_schedule_epoch=1, unix_timestamp=1668000000)
+///     >>> Clock.from_bytes(bytes(clock)) == clock
+///     True
+///
+#[pyclass(module = "solders.clock", subclass)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Default, From, Into)]
+pub struct Clock(ClockOriginal);
+
+#[richcmp_eq_only]
+#[common_methods]
+#[pymethods]
+impl Clock {
+    #[new]
+    pub fn new(
+        slot: Slot,
+        epoch_start_timestamp: UnixTimestamp,
+        epoch: Epoch,
+        leader_schedule_epoch: Epoch,
+        unix_timestamp: UnixTimestamp,
+    ) -> Self {
+        ClockOriginal {
+            slot,
+            epoch_start_timestamp,
+            epoch,
+            leader_schedule_epoch,
+            unix_timestamp,
+        }
+        .into()
+    }
+
+    /// int: The current slot.
+    #[getter]
+    pub fn slot(&self) -> Slot {
+        self.0.slot
+    }
+
+    /// int: The timestamp of the first slot in this epoch.
+    #[getter]
+    pub fn epoch_start_timestamp(&self) -> UnixTimestamp {
+        self.0.epoch_start_timestamp
+    }
+
+    /// int: The current epoch.
+    #[getter]
+    pub fn epoch(&self) -> Epoch {
+        self.0.epoch
+    }
+
+    /// int: The future epoch for which the leader schedule has most recently been calculated.
+    #[getter]
+    pub fn leader_schedule_epoch(&self) -> Epoch {
+        self.0.leader_schedule_epoch
+    }
+
+    /// int: The approximate real world time of the current slot.
+    #[getter]
+    pub fn unix_timestamp(&self) -> UnixTimestamp {
+        self.0.unix_timestamp
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "default")]
+    /// Create a new default Clock.
+    ///
+    /// Returns:
+    ///     Clock: The default Clock.
+    ///
+    pub fn new_default() -> Self {
+        Self::default()
+    }
+}
+
+impl_display!(Clock);
+pybytes_general_via_bincode!(Clock);
+py_from_bytes_general_via_bincode!(Clock);
+solders_traits::common_methods_default!(Clock);
+impl RichcmpEqualityOnly for Clock {}
+
+pub(crate) fn create_clock_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "clock")?;
+    m.add_class::<Clock>()?;
+    Ok(m)
+}
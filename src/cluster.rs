@@ -0,0 +1,120 @@
+use std::str::FromStr;
+
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use solana_sdk::hash::Hash as HashOriginal;
+use solders_macros::enum_string;
+use solders_primitives::hash::Hash as SolderHash;
+use solders_traits::EnumString;
+
+use crate::rpc::responses::GetGenesisHashResp;
+
+pub const MAINNET_BETA_GENESIS_HASH: &str = "5eykt4UsFv8P8NJdTREpY1vzqKqZKvdpKuc147dw2N9d";
+pub const TESTNET_GENESIS_HASH: &str = "4uhcVJyU9pJkvQyS88uRDiswHXSCkY3zQawwpjk2NsNY";
+pub const DEVNET_GENESIS_HASH: &str = "EtWTRABZaYq6iMfeYKouRu166VU2xqa1wcaWoxPkrZBG";
+
+/// A Solana cluster.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[pyclass(module = "solders.cluster")]
+pub enum Cluster {
+    MainnetBeta,
+    Testnet,
+    Devnet,
+    /// A local test validator.
+    Localnet,
+}
+
+impl EnumString for Cluster {}
+
+impl Cluster {
+    const PUBLIC: [Self; 3] = [Self::MainnetBeta, Self::Testnet, Self::Devnet];
+
+    fn genesis_hash_original(self) -> Option<HashOriginal> {
+        let hash = match self {
+            Self::MainnetBeta => MAINNET_BETA_GENESIS_HASH,
+            Self::Testnet => TESTNET_GENESIS_HASH,
+            Self::Devnet => DEVNET_GENESIS_HASH,
+            Self::Localnet => return None,
+        };
+        Some(HashOriginal::from_str(hash).unwrap())
+    }
+}
+
+#[enum_string]
+#[pymethods]
+impl Cluster {
+    /// Optional[Hash]: The cluster's genesis hash. ``None`` for localnet, which has a new
+    /// genesis every time the validator is reset.
+    #[getter]
+    pub fn genesis_hash(&self) -> Option<SolderHash> {
+        self.genesis_hash_original().map(SolderHash::from)
+    }
+
+    /// str: The default JSON RPC URL.
+    #[getter]
+    pub fn rpc_url(&self) -> &'static str {
+        match self {
+            Self::MainnetBeta => "https://api.mainnet-beta.solana.com",
+            Self::Testnet => "https://api.testnet.solana.com",
+            Self::Devnet => "https://api.devnet.solana.com",
+            Self::Localnet => "http://127.0.0.1:8899",
+        }
+    }
+
+    /// str: The default websocket URL.
+    #[getter]
+    pub fn ws_url(&self) -> &'static str {
+        match self {
+            Self::MainnetBeta => "wss://api.mainnet-beta.solana.com",
+            Self::Testnet => "wss://api.testnet.solana.com",
+            Self::Devnet => "wss://api.devnet.solana.com",
+            Self::Localnet => "ws://127.0.0.1:8900",
+        }
+    }
+}
+
+/// A genesis hash, or the ``getGenesisHash`` response containing it.
+#[derive(FromPyObject, Clone, Debug)]
+pub enum GenesisHashArg {
+    Hash(SolderHash),
+    Resp(GetGenesisHashResp),
+}
+
+/// Identify a public cluster from its genesis hash.
+///
+/// Args:
+///     genesis_hash (Hash | GetGenesisHashResp): The genesis hash, e.g. from ``getGenesisHash``.
+///
+/// Returns:
+///     Optional[Cluster]: The cluster, or ``None`` if the hash isn't a known public cluster's,
+///     as for a local test validator.
+///
+/// Example:
+///     >>> from solders.cluster import detect_cluster, Cluster
+///     >>> from solders.hash import Hash
+///     >>> detect_cluster(Hash.from_string("EtWTRABZaYq6iMfeYKouRu166VU2xqa1wcaWoxPkrZBG"))
+///     Cluster.Devnet
+///     >>> detect_cluster(Hash.default()) is None
+///     True
+///
+#[pyfunction]
+pub fn detect_cluster(genesis_hash: GenesisHashArg) -> Option<Cluster> {
+    let hash: HashOriginal = match genesis_hash {
+        GenesisHashArg::Hash(hash) => hash.into(),
+        GenesisHashArg::Resp(resp) => resp.value().into(),
+    };
+    Cluster::PUBLIC
+        .into_iter()
+        .find(|cluster| cluster.genesis_hash_original() == Some(hash))
+}
+
+pub(crate) fn create_cluster_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "cluster")?;
+    m.add_class::<Cluster>()?;
+    m.add("MAINNET_BETA_GENESIS_HASH", MAINNET_BETA_GENESIS_HASH)?;
+    m.add("TESTNET_GENESIS_HASH", TESTNET_GENESIS_HASH)?;
+    m.add("DEVNET_GENESIS_HASH", DEVNET_GENESIS_HASH)?;
+    m.add_function(wrap_pyfunction!(detect_cluster, m)?)?;
+    Ok(m)
+}
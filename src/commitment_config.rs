@@ -7,11 +7,13 @@ use solana_sdk::commitment_config::{
     CommitmentConfig as CommitmentConfigOriginal, CommitmentLevel as CommitmentLevelOriginal,
 };
 
-use solders_traits::{handle_py_err};
+use solders_macros::enum_string;
+use solders_traits::{handle_py_err, EnumString};
 
 /// RPC request `commitment <https://docs.solana.com/developing/clients/jsonrpc-api#configuring-state-commitment>`_ options.
 #[pyclass(module = "solders.commitment_config")]
 #[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
 pub enum CommitmentLevel {
     /// The highest slot of the heaviest fork processed by the node. Ledger state at this slot is
     /// not derived from a confirmed or finalized block, but if multiple forks are present, is from
@@ -35,14 +37,15 @@ impl Default for CommitmentLevel {
     }
 }
 
-#[pymethods]
-impl CommitmentLevel {
-    #[staticmethod]
-    #[pyo3(name = "from_string")]
-    pub fn new_from_str(s: &str) -> PyResult<Self> {
+impl EnumString for CommitmentLevel {
+    fn from_canonical_string(s: &str) -> PyResult<Self> {
         handle_py_err(CommitmentLevelOriginal::from_str(s))
     }
+}
 
+#[enum_string]
+#[pymethods]
+impl CommitmentLevel {
     /// Create a new default instance.
     #[staticmethod]
     #[pyo3(name = "default")]
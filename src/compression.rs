@@ -0,0 +1,272 @@
+use std::{collections::HashMap, io::Read};
+
+use pyo3::{prelude::*, types::PyBytes};
+use solana_sdk::{account::Account as AccountOriginal, pubkey::Pubkey as PubkeyOriginal};
+use solders_primitives::pubkey::Pubkey;
+use solders_traits::to_py_value_err;
+
+use crate::account::Account;
+
+/// The zstd level used when none is given.
+pub const DEFAULT_COMPRESSION_LEVEL: i32 = 3;
+/// The dictionary size :func:`train_dictionary` aims for when none is given.
+const DEFAULT_DICTIONARY_SIZE: usize = 112_640;
+/// The most bytes the ``decompress`` functions produce when no ``max_size`` is given.
+pub const DEFAULT_MAX_DECOMPRESSED_SIZE: usize = 128 * 1024 * 1024;
+
+fn compress_bytes(data: &[u8], level: i32, dictionary: Option<&[u8]>) -> PyResult<Vec<u8>> {
+    let compressed = match dictionary {
+        Some(dict) => zstd::bulk::Compressor::with_dictionary(level, dict)
+            .and_then(|mut compressor| compressor.compress(data)),
+        None => zstd::bulk::compress(data, level),
+    };
+    compressed.map_err(|e| to_py_value_err(&e))
+}
+
+/// Decompress without the GIL, stopping as soon as the output passes ``max_size``.
+fn decompress_bytes(
+    py: Python<'_>,
+    data: &[u8],
+    dictionary: Option<&[u8]>,
+    max_size: usize,
+) -> PyResult<Vec<u8>> {
+    py.allow_threads(|| {
+        let decoder = match dictionary {
+            Some(dict) => zstd::stream::read::Decoder::with_dictionary(data, dict),
+            None => zstd::stream::read::Decoder::new(data),
+        }
+        .map_err(|e| to_py_value_err(&e))?;
+        let mut out = Vec::new();
+        decoder
+            .take(max_size as u64 + 1)
+            .read_to_end(&mut out)
+            .map_err(|e| to_py_value_err(&e))?;
+        if out.len() > max_size {
+            return Err(to_py_value_err(&format!(
+                "decompressed data exceeds max_size of {} bytes",
+                max_size
+            )));
+        }
+        Ok(out)
+    })
+}
+
+/// Compress bytes with zstd.
+///
+/// Args:
+///     data (bytes): The bytes to compress.
+///     level (int): The zstd compression level, from 1 to 22. Defaults to 3.
+///     dictionary (Optional[bytes]): A dictionary from :func:`train_dictionary`.
+///         The same dictionary must be passed to :func:`decompress`.
+///
+/// Returns:
+///     bytes: The zstd frame.
+///
+/// Example:
+///     >>> from solders.compression import compress, decompress
+///     >>> data = bytes(10_000)
+///     >>> len(compress(data)) < 100, decompress(compress(data)) == data
+///     (True, True)
+///
+#[pyfunction]
+#[pyo3(signature = (data, level = DEFAULT_COMPRESSION_LEVEL, dictionary = None))]
+pub fn compress<'a>(
+    py: Python<'a>,
+    data: &[u8],
+    level: i32,
+    dictionary: Option<&[u8]>,
+) -> PyResult<&'a PyBytes> {
+    Ok(PyBytes::new(py, &compress_bytes(data, level, dictionary)?))
+}
+
+/// Decompress a zstd frame.
+///
+/// The GIL is released while decompressing.
+///
+/// Args:
+///     data (bytes): The zstd frame.
+///     dictionary (Optional[bytes]): The dictionary it was compressed with, if any.
+///     max_size (int): The most decompressed bytes to accept, so a small malicious frame
+///         can't exhaust memory. Defaults to 128 MiB.
+///
+/// Returns:
+///     bytes: The decompressed bytes.
+///
+/// Raises:
+///     ValueError: If ``data`` isn't a valid zstd frame for ``dictionary``, or it
+///         decompresses to more than ``max_size`` bytes.
+///
+#[pyfunction]
+#[pyo3(signature = (data, dictionary = None, max_size = DEFAULT_MAX_DECOMPRESSED_SIZE))]
+pub fn decompress<'a>(
+    py: Python<'a>,
+    data: &[u8],
+    dictionary: Option<&[u8]>,
+    max_size: usize,
+) -> PyResult<&'a PyBytes> {
+    Ok(PyBytes::new(
+        py,
+        &decompress_bytes(py, data, dictionary, max_size)?,
+    ))
+}
+
+/// Train a zstd dictionary on sample data.
+///
+/// Accounts of the same program share most of their layout, so a dictionary
+/// trained on a few hundred of them compresses small accounts far better than
+/// zstd can alone.
+///
+/// Args:
+///     samples (Sequence[bytes]): Representative data, e.g. account data of one program.
+///     max_size (int): The maximum dictionary size in bytes. Defaults to 110 KiB.
+///
+/// Returns:
+///     bytes: The dictionary.
+///
+/// Raises:
+///     ValueError: If zstd can't train a dictionary, e.g. because there are too few samples.
+///
+#[pyfunction]
+#[pyo3(signature = (samples, max_size = DEFAULT_DICTIONARY_SIZE))]
+pub fn train_dictionary<'a>(
+    py: Python<'a>,
+    samples: Vec<&[u8]>,
+    max_size: usize,
+) -> PyResult<&'a PyBytes> {
+    let dictionary =
+        zstd::dict::from_samples(&samples, max_size).map_err(|e| to_py_value_err(&e))?;
+    Ok(PyBytes::new(py, &dictionary))
+}
+
+/// Compress an account, including its metadata.
+///
+/// Args:
+///     account (Account): The account.
+///     level (int): The zstd compression level. Defaults to 3.
+///     dictionary (Optional[bytes]): A dictionary from :func:`train_dictionary`.
+///
+/// Returns:
+///     bytes: The compressed account.
+///
+#[pyfunction]
+#[pyo3(signature = (account, level = DEFAULT_COMPRESSION_LEVEL, dictionary = None))]
+pub fn compress_account<'a>(
+    py: Python<'a>,
+    account: Account,
+    level: i32,
+    dictionary: Option<&[u8]>,
+) -> PyResult<&'a PyBytes> {
+    let serialized = bincode::serialize(&AccountOriginal::from(account)).unwrap();
+    compress(py, &serialized, level, dictionary)
+}
+
+/// Decompress an account compressed by :func:`compress_account`.
+///
+/// Args:
+///     data (bytes): The compressed account.
+///     dictionary (Optional[bytes]): The dictionary it was compressed with, if any.
+///     max_size (int): The most decompressed bytes to accept. Defaults to 128 MiB.
+///
+/// Returns:
+///     Account: The account.
+///
+/// Raises:
+///     ValueError: If ``data`` isn't a compressed account or decompresses to more
+///         than ``max_size`` bytes.
+///
+#[pyfunction]
+#[pyo3(signature = (data, dictionary = None, max_size = DEFAULT_MAX_DECOMPRESSED_SIZE))]
+pub fn decompress_account(
+    py: Python<'_>,
+    data: &[u8],
+    dictionary: Option<&[u8]>,
+    max_size: usize,
+) -> PyResult<Account> {
+    let serialized = decompress_bytes(py, data, dictionary, max_size)?;
+    bincode::deserialize::<AccountOriginal>(&serialized)
+        .map(Account::from)
+        .map_err(|e| to_py_value_err(&e))
+}
+
+/// Compress a set of accounts, e.g. a fixture or cache file.
+///
+/// Args:
+///     accounts (Dict[Pubkey, Account]): The accounts, e.g. from ``AccountsMap.to_dict()``.
+///     level (int): The zstd compression level. Defaults to 3.
+///     dictionary (Optional[bytes]): A dictionary from :func:`train_dictionary`.
+///
+/// Returns:
+///     bytes: The compressed accounts.
+///
+/// Example:
+///     >>> from solders.compression import compress_accounts, decompress_accounts
+///     >>> from solders.account import Account
+///     >>> from solders.pubkey import Pubkey
+///     >>> accounts = {Pubkey.new_unique(): Account(1, bytes(100), Pubkey.default())}
+///     >>> decompress_accounts(compress_accounts(accounts)) == accounts
+///     True
+///
+#[pyfunction]
+#[pyo3(signature = (accounts, level = DEFAULT_COMPRESSION_LEVEL, dictionary = None))]
+pub fn compress_accounts<'a>(
+    py: Python<'a>,
+    accounts: HashMap<Pubkey, Account>,
+    level: i32,
+    dictionary: Option<&[u8]>,
+) -> PyResult<&'a PyBytes> {
+    let mut entries: Vec<(PubkeyOriginal, AccountOriginal)> = accounts
+        .into_iter()
+        .map(|(pubkey, account)| (pubkey.into(), account.into()))
+        .collect();
+    entries.sort_unstable_by_key(|(pubkey, _)| *pubkey);
+    let serialized = bincode::serialize(&entries).unwrap();
+    compress(py, &serialized, level, dictionary)
+}
+
+/// Decompress accounts compressed by :func:`compress_accounts`.
+///
+/// Args:
+///     data (bytes): The compressed accounts.
+///     dictionary (Optional[bytes]): The dictionary they were compressed with, if any.
+///     max_size (int): The most decompressed bytes to accept. Defaults to 128 MiB.
+///
+/// Returns:
+///     Dict[Pubkey, Account]: The accounts.
+///
+/// Raises:
+///     ValueError: If ``data`` isn't a set of compressed accounts or decompresses to
+///         more than ``max_size`` bytes.
+///
+#[pyfunction]
+#[pyo3(signature = (data, dictionary = None, max_size = DEFAULT_MAX_DECOMPRESSED_SIZE))]
+pub fn decompress_accounts(
+    py: Python<'_>,
+    data: &[u8],
+    dictionary: Option<&[u8]>,
+    max_size: usize,
+) -> PyResult<HashMap<Pubkey, Account>> {
+    let serialized = decompress_bytes(py, data, dictionary, max_size)?;
+    let entries: Vec<(PubkeyOriginal, AccountOriginal)> =
+        bincode::deserialize(&serialized).map_err(|e| to_py_value_err(&e))?;
+    Ok(entries
+        .into_iter()
+        .map(|(pubkey, account)| (pubkey.into(), account.into()))
+        .collect())
+}
+
+pub(crate) fn create_compression_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "compression")?;
+    m.add("DEFAULT_COMPRESSION_LEVEL", DEFAULT_COMPRESSION_LEVEL)?;
+    m.add(
+        "DEFAULT_MAX_DECOMPRESSED_SIZE",
+        DEFAULT_MAX_DECOMPRESSED_SIZE,
+    )?;
+    m.add_function(wrap_pyfunction!(compress, m)?)?;
+    m.add_function(wrap_pyfunction!(decompress, m)?)?;
+    m.add_function(wrap_pyfunction!(train_dictionary, m)?)?;
+    m.add_function(wrap_pyfunction!(compress_account, m)?)?;
+    m.add_function(wrap_pyfunction!(decompress_account, m)?)?;
+    m.add_function(wrap_pyfunction!(compress_accounts, m)?)?;
+    m.add_function(wrap_pyfunction!(decompress_accounts, m)?)?;
+    Ok(m)
+}
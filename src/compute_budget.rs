@@ -0,0 +1,224 @@
+use pyo3::{exceptions::PyValueError, prelude::*};
+use solana_sdk::{
+    borsh::try_from_slice_unchecked,
+    compute_budget::{self, ComputeBudgetInstruction},
+    instruction::InstructionError,
+    message::VersionedMessage as VersionedMessageOriginal,
+    transaction::TransactionError,
+};
+use solders_macros::richcmp_eq_only;
+use solders_primitives::message::VersionedMessage;
+use solders_traits::{to_py_value_err, RichcmpEqualityOnly};
+
+use crate::rpc::responses::RpcPrioritizationFee;
+
+pub(crate) const DEFAULT_INSTRUCTION_COMPUTE_UNIT_LIMIT: u32 = 200_000;
+pub(crate) const MAX_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+pub(crate) const MIN_HEAP_FRAME_BYTES: u32 = 32 * 1024;
+const MAX_HEAP_FRAME_BYTES: u32 = 256 * 1024;
+const MICRO_LAMPORTS_PER_LAMPORT: u128 = 1_000_000;
+
+fn invalid_instruction_data(index: usize) -> TransactionError {
+    TransactionError::InstructionError(index as u8, InstructionError::InvalidInstructionData)
+}
+
+fn duplicate_instruction(index: usize) -> TransactionError {
+    TransactionError::DuplicateInstruction(index as u8)
+}
+
+/// The compute budget a transaction requests, as the runtime would apply it.
+///
+/// Args:
+///     compute_unit_limit (int): The effective compute unit limit.
+///     compute_unit_price (int): The compute unit price in micro-lamports.
+///     heap_size (Optional[int]): The requested heap frame size in bytes, if any.
+///
+#[pyclass(module = "solders.compute_budget", subclass)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ComputeBudgetLimits {
+    /// int: The effective compute unit limit.
+    #[pyo3(get)]
+    pub compute_unit_limit: u32,
+    /// int: The compute unit price in micro-lamports.
+    #[pyo3(get)]
+    pub compute_unit_price: u64,
+    /// Optional[int]: The requested heap frame size in bytes, if any.
+    #[pyo3(get)]
+    pub heap_size: Option<u32>,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl ComputeBudgetLimits {
+    #[new]
+    pub fn new(compute_unit_limit: u32, compute_unit_price: u64, heap_size: Option<u32>) -> Self {
+        Self {
+            compute_unit_limit,
+            compute_unit_price,
+            heap_size,
+        }
+    }
+
+    /// The prioritization fee in lamports: the limit times the price, rounded up.
+    ///
+    /// Returns:
+    ///     int: The prioritization fee.
+    ///
+    pub fn prioritization_fee(&self) -> u64 {
+        let micro_lamports =
+            u128::from(self.compute_unit_limit) * u128::from(self.compute_unit_price);
+        let fee = (micro_lamports + MICRO_LAMPORTS_PER_LAMPORT - 1) / MICRO_LAMPORTS_PER_LAMPORT;
+        u64::try_from(fee).unwrap_or(u64::MAX)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for ComputeBudgetLimits {}
+
+/// Extract the effective compute unit limit and price from a message's compute budget instructions.
+///
+/// Follows the runtime rules: duplicate compute budget instructions and malformed
+/// instruction data are errors, and without ``SetComputeUnitLimit`` each
+/// non-compute-budget instruction gets 200,000 units, capped at 1,400,000.
+///
+/// Args:
+///     message (Message | MessageV0): The message to scan.
+///
+/// Returns:
+///     ComputeBudgetLimits: The effective limits.
+///
+/// Raises:
+///     ValueError: If the runtime would reject the compute budget instructions.
+///
+/// Example:
+///     >>> from solders.compute_budget import compute_budget_limits
+///     >>> from solders.instruction import Instruction
+///     >>> from solders.message import Message
+///     >>> from solders.pubkey import Pubkey
+///     >>> program_id = Pubkey.from_string("ComputeBudget111111111111111111111111111111")
+///     >>> set_price = Instruction(program_id, bytes([3]) + (5000).to_bytes(8, "little"), [])
+///     >>> other = Instruction(Pubkey.new_unique(), b"", [])
+///     >>> limits = compute_budget_limits(Message([set_price, other], Pubkey.new_unique()))
+///     >>> limits.compute_unit_limit, limits.compute_unit_price
+///     (200000, 5000)
+///     >>> limits.prioritization_fee()
+///     1000
+///
+#[pyfunction]
+pub fn compute_budget_limits(message: VersionedMessage) -> PyResult<ComputeBudgetLimits> {
+    compute_budget_limits_original(&message.into())
+        .map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+/// The effective compute budget of ``message``, or the error the runtime would return.
+pub(crate) fn compute_budget_limits_original(
+    message: &VersionedMessageOriginal,
+) -> Result<ComputeBudgetLimits, TransactionError> {
+    let account_keys = message.static_account_keys();
+    let mut num_non_compute_budget_instructions: u32 = 0;
+    let mut compute_unit_limit: Option<u32> = None;
+    let mut compute_unit_price: Option<u64> = None;
+    let mut heap_size: Option<(u32, usize)> = None;
+    for (i, ix) in message.instructions().iter().enumerate() {
+        let is_compute_budget = account_keys
+            .get(usize::from(ix.program_id_index))
+            .map_or(false, compute_budget::check_id);
+        if !is_compute_budget {
+            num_non_compute_budget_instructions += 1;
+            continue;
+        }
+        match try_from_slice_unchecked(&ix.data) {
+            Ok(ComputeBudgetInstruction::RequestHeapFrame(bytes)) => {
+                if heap_size.is_some() {
+                    return Err(duplicate_instruction(i));
+                }
+                heap_size = Some((bytes, i));
+            }
+            Ok(ComputeBudgetInstruction::SetComputeUnitLimit(units)) => {
+                if compute_unit_limit.is_some() {
+                    return Err(duplicate_instruction(i));
+                }
+                compute_unit_limit = Some(units);
+            }
+            Ok(ComputeBudgetInstruction::SetComputeUnitPrice(price)) => {
+                if compute_unit_price.is_some() {
+                    return Err(duplicate_instruction(i));
+                }
+                compute_unit_price = Some(price);
+            }
+            _ => return Err(invalid_instruction_data(i)),
+        }
+    }
+    if let Some((bytes, i)) = heap_size {
+        if !(MIN_HEAP_FRAME_BYTES..=MAX_HEAP_FRAME_BYTES).contains(&bytes) || bytes % 1024 != 0 {
+            return Err(invalid_instruction_data(i));
+        }
+    }
+    let compute_unit_limit = compute_unit_limit
+        .unwrap_or_else(|| {
+            num_non_compute_budget_instructions
+                .saturating_mul(DEFAULT_INSTRUCTION_COMPUTE_UNIT_LIMIT)
+        })
+        .min(MAX_COMPUTE_UNIT_LIMIT);
+    Ok(ComputeBudgetLimits {
+        compute_unit_limit,
+        compute_unit_price: compute_unit_price.unwrap_or_default(),
+        heap_size: heap_size.map(|(bytes, _)| bytes),
+    })
+}
+
+/// Estimate a compute unit price from recent prioritization fees.
+///
+/// Takes the nearest-rank percentile of the fees, so ``percentile=75`` pays at
+/// least as much as three quarters of the recent slots. Scope the
+/// ``getRecentPrioritizationFees`` request to the transaction's writable accounts
+/// to price in contention for those accounts.
+///
+/// Args:
+///     fees (Sequence[RpcPrioritizationFee]): The result of ``getRecentPrioritizationFees``.
+///     percentile (float): The percentile, from 0 to 100.
+///
+/// Returns:
+///     int: The compute unit price in micro-lamports, or zero if there are no fees.
+///
+/// Raises:
+///     ValueError: If ``percentile`` is out of range.
+///
+/// Example:
+///     >>> from solders.compute_budget import estimate_compute_unit_price
+///     >>> from solders.rpc.responses import RpcPrioritizationFee
+///     >>> fees = [RpcPrioritizationFee(slot, fee) for slot, fee in enumerate([0, 500, 100, 2000])]
+///     >>> estimate_compute_unit_price(fees), estimate_compute_unit_price(fees, 75)
+///     (100, 500)
+///
+#[pyfunction]
+#[pyo3(signature = (fees, percentile = 50.0))]
+pub fn estimate_compute_unit_price(
+    fees: Vec<RpcPrioritizationFee>,
+    percentile: f64,
+) -> PyResult<u64> {
+    if !(0.0..=100.0).contains(&percentile) {
+        return Err(to_py_value_err(&format!(
+            "percentile must be between 0 and 100, got {}",
+            percentile
+        )));
+    }
+    let mut sorted: Vec<u64> = fees.iter().map(|f| f.prioritization_fee()).collect();
+    if sorted.is_empty() {
+        return Ok(0);
+    }
+    sorted.sort_unstable();
+    let rank = (percentile / 100.0 * sorted.len() as f64).ceil() as usize;
+    Ok(sorted[rank.saturating_sub(1)])
+}
+
+pub(crate) fn create_compute_budget_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "compute_budget")?;
+    m.add_class::<ComputeBudgetLimits>()?;
+    m.add_function(wrap_pyfunction!(compute_budget_limits, m)?)?;
+    m.add_function(wrap_pyfunction!(estimate_compute_unit_price, m)?)?;
+    Ok(m)
+}
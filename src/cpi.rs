@@ -0,0 +1,244 @@
+use std::collections::HashMap;
+
+use pyo3::{exceptions::PyValueError, prelude::*};
+use solana_sdk::{
+    compute_budget, config, ed25519_program,
+    instruction::{AccountMeta as AccountMetaOriginal, Instruction as InstructionOriginal},
+    message::Message as MessageOriginal,
+    pubkey::Pubkey as PubkeyOriginal,
+    secp256k1_program, stake, system_program, vote,
+};
+use solders_macros::richcmp_eq_only;
+use solders_primitives::{
+    address_lookup_table_account::AddressLookupTableAccount, instruction::Instruction,
+    message::VersionedMessage, pubkey::Pubkey,
+};
+use solders_traits::RichcmpEqualityOnly;
+
+use crate::{
+    program_ids::{MEMO_PROGRAM_ID, MEMO_V1_PROGRAM_ID},
+    spl_errors::TOKEN_PROGRAM_ID,
+};
+
+/// The deepest CPI nesting the runtime allows below a top-level instruction.
+const MAX_INVOKE_DEPTH: usize = 4;
+
+/// Programs known to never make a CPI.
+fn is_leaf_program(program_id: &PubkeyOriginal) -> bool {
+    [
+        system_program::ID,
+        compute_budget::ID,
+        vote::program::ID,
+        stake::program::ID,
+        config::program::ID,
+        ed25519_program::ID,
+        secp256k1_program::ID,
+        TOKEN_PROGRAM_ID,
+        MEMO_PROGRAM_ID,
+        MEMO_V1_PROGRAM_ID,
+    ]
+    .contains(program_id)
+}
+
+/// An instruction in the predicted invocation tree of a transaction.
+#[pyclass(module = "solders.cpi", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CpiNode {
+    /// Instruction: The instruction, with its program and accounts.
+    #[pyo3(get)]
+    pub instruction: Instruction,
+    /// int: 1 for top-level instructions, incremented for each level of CPI.
+    #[pyo3(get)]
+    pub stack_height: usize,
+    /// List[CpiNode]: The CPIs this instruction makes, in order.
+    #[pyo3(get)]
+    pub children: Vec<CpiNode>,
+    /// bool: Whether the CPIs of this instruction are known. ``False`` for programs
+    /// without a parser, whose children are then empty.
+    #[pyo3(get)]
+    pub resolved: bool,
+}
+
+impl CpiNode {
+    fn collect_program_ids(&self, out: &mut Vec<Pubkey>) {
+        let program_id = Pubkey(self.instruction.0.program_id);
+        if !out.contains(&program_id) {
+            out.push(program_id);
+        }
+        for child in &self.children {
+            child.collect_program_ids(out);
+        }
+    }
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl CpiNode {
+    #[new]
+    pub fn new(
+        instruction: Instruction,
+        stack_height: usize,
+        children: Vec<CpiNode>,
+        resolved: bool,
+    ) -> Self {
+        Self {
+            instruction,
+            stack_height,
+            children,
+            resolved,
+        }
+    }
+
+    /// Every program invoked by this instruction or its CPIs.
+    ///
+    /// Returns:
+    ///     List[Pubkey]: The program IDs, in the order they are first invoked.
+    ///
+    pub fn program_ids(&self) -> Vec<Pubkey> {
+        let mut out = Vec::new();
+        self.collect_program_ids(&mut out);
+        out
+    }
+
+    /// Whether the CPIs of this instruction and all of its descendants are known.
+    pub fn is_fully_resolved(&self) -> bool {
+        self.resolved && self.children.iter().all(|c| c.is_fully_resolved())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for CpiNode {}
+
+fn decompile(message: &MessageOriginal) -> PyResult<Vec<InstructionOriginal>> {
+    let key = |i: usize| {
+        message.account_keys.get(i).copied().ok_or_else(|| {
+            PyValueError::new_err(format!(
+                "instruction references account index {i}, but the message has only {} account keys",
+                message.account_keys.len()
+            ))
+        })
+    };
+    message
+        .instructions
+        .iter()
+        .map(|ix| {
+            let accounts = ix
+                .accounts
+                .iter()
+                .map(|a| {
+                    let i = usize::from(*a);
+                    Ok(AccountMetaOriginal {
+                        pubkey: key(i)?,
+                        is_signer: message.is_signer(i),
+                        is_writable: message.is_writable(i),
+                    })
+                })
+                .collect::<PyResult<Vec<_>>>()?;
+            Ok(InstructionOriginal {
+                program_id: key(usize::from(ix.program_id_index))?,
+                accounts,
+                data: ix.data.clone(),
+            })
+        })
+        .collect()
+}
+
+fn build_node(
+    py: Python<'_>,
+    instruction: InstructionOriginal,
+    stack_height: usize,
+    parsers: &HashMap<Pubkey, PyObject>,
+) -> PyResult<CpiNode> {
+    let program_id = Pubkey(instruction.program_id);
+    let (children, resolved) = if is_leaf_program(&instruction.program_id) {
+        (vec![], true)
+    } else if let Some(parser) = parsers.get(&program_id) {
+        let cpis: Vec<Instruction> = parser
+            .call1(py, (Instruction(instruction.clone()),))?
+            .extract(py)?;
+        if !cpis.is_empty() && stack_height > MAX_INVOKE_DEPTH {
+            return Err(PyValueError::new_err(format!(
+                "CPI from {program_id} at stack height {stack_height} exceeds the maximum invoke depth"
+            )));
+        }
+        let children = cpis
+            .into_iter()
+            .map(|cpi| build_node(py, cpi.0, stack_height + 1, parsers))
+            .collect::<PyResult<Vec<_>>>()?;
+        (children, true)
+    } else {
+        (vec![], false)
+    };
+    Ok(CpiNode {
+        instruction: Instruction(instruction),
+        stack_height,
+        children,
+        resolved,
+    })
+}
+
+/// Predict the CPI tree of a message before signing it.
+///
+/// Each parser receives an instruction for its program and returns the
+/// instructions that program would invoke. The System, Compute Budget,
+/// Vote, Stake, Config, precompile, SPL Token and Memo programs are known
+/// to make no CPIs. Instructions for other programs without a parser are
+/// left unresolved, which policy engines should treat as unknown.
+///
+/// Args:
+///     message (Message | MessageV0): The message to analyze.
+///     parsers (Dict[Pubkey, Callable[[Instruction], Sequence[Instruction]]]): CPI predictors,
+///         keyed by program ID.
+///     address_lookup_table_accounts (Optional[Sequence[AddressLookupTableAccount]]):
+///         The lookup tables referenced by a ``MessageV0``.
+///
+/// Returns:
+///     List[CpiNode]: One tree per top-level instruction.
+///
+/// Raises:
+///     ValueError: If the CPIs would exceed the maximum invoke depth, or an instruction
+///         references an account index the message doesn't have.
+///     CompileError: If a lookup table used by a ``MessageV0`` is missing.
+///
+/// Example:
+///     >>> from solders.cpi import preview_cpi
+///     >>> from solders.instruction import Instruction
+///     >>> from solders.message import Message
+///     >>> from solders.pubkey import Pubkey
+///     >>> from solders.system_program import ID, transfer, TransferParams
+///     >>> payer, program = Pubkey.new_unique(), Pubkey.new_unique()
+///     >>> cpi = transfer(TransferParams(from_pubkey=payer, to_pubkey=Pubkey.new_unique(), lamports=1))
+///     >>> msg = Message([Instruction(program, b"", [])], payer)
+///     >>> [tree] = preview_cpi(msg, {program: lambda ix: [cpi]})
+///     >>> tree.program_ids() == [program, ID], tree.is_fully_resolved()
+///     (True, True)
+///
+#[pyfunction]
+#[pyo3(signature = (message, parsers, address_lookup_table_accounts = None))]
+pub fn preview_cpi(
+    py: Python<'_>,
+    message: VersionedMessage,
+    parsers: HashMap<Pubkey, PyObject>,
+    address_lookup_table_accounts: Option<Vec<AddressLookupTableAccount>>,
+) -> PyResult<Vec<CpiNode>> {
+    let legacy = match message {
+        VersionedMessage::Legacy(m) => m,
+        VersionedMessage::V0(m) => {
+            m.expand_to_legacy(address_lookup_table_accounts.unwrap_or_default())?
+        }
+    };
+    decompile(&legacy.0)?
+        .into_iter()
+        .map(|ix| build_node(py, ix, 1, &parsers))
+        .collect()
+}
+
+pub(crate) fn create_cpi_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "cpi")?;
+    m.add_class::<CpiNode>()?;
+    m.add_function(wrap_pyfunction!(preview_cpi, m)?)?;
+    Ok(m)
+}
@@ -0,0 +1,478 @@
+use std::fmt::Write;
+
+use pyo3::prelude::*;
+use solana_sdk::{
+    clock::UnixTimestamp,
+    message::{v0::MessageAddressTableLookup, VersionedMessage as VersionedMessageOriginal},
+    native_token::lamports_to_sol,
+    pubkey::Pubkey as PubkeyOriginal,
+    signer::keypair::Keypair as KeypairOriginal,
+    stake::{self, instruction::StakeInstruction},
+    system_instruction::SystemInstruction,
+    system_program,
+    transaction::VersionedTransaction as VersionedTransactionOriginal,
+    vote::{self, instruction::VoteInstruction},
+};
+use solders_primitives::{keypair::Keypair, pubkey::Pubkey, transaction::VersionedTransaction};
+use solders_traits::to_py_value_err;
+
+use crate::{
+    program_ids::{MEMO_PROGRAM_ID, MEMO_V1_PROGRAM_ID},
+    tmp_transaction_status::{
+        RewardType, UiTransactionStatusMeta as UiTransactionStatusMetaOriginal,
+    },
+    transaction_status::UiTransactionStatusMeta,
+};
+
+/// Serialize a keypair the way ``solana-keygen new --outfile`` writes it.
+///
+/// Args:
+///     keypair (Keypair): The keypair.
+///
+/// Returns:
+///     str: The keypair bytes as a JSON array, e.g. ``[1,2,3,...]``.
+///
+#[pyfunction]
+pub fn keypair_to_json(keypair: &Keypair) -> String {
+    serde_json::to_string(&keypair.0.to_bytes().to_vec()).unwrap()
+}
+
+/// Parse a keypair file written by ``solana-keygen``.
+///
+/// Args:
+///     raw (str): The file contents.
+///
+/// Returns:
+///     Keypair: The keypair.
+///
+/// Raises:
+///     ValueError: If ``raw`` isn't a JSON array of 64 bytes forming a valid keypair.
+///
+#[pyfunction]
+pub fn keypair_from_json(raw: &str) -> PyResult<Keypair> {
+    let bytes: Vec<u8> = serde_json::from_str(raw).map_err(|e| to_py_value_err(&e))?;
+    KeypairOriginal::from_bytes(&bytes)
+        .map(Keypair)
+        .map_err(|e| to_py_value_err(&e))
+}
+
+/// Serialize a pubkey the way ``solana-keygen pubkey --outfile`` writes it.
+///
+/// Args:
+///     pubkey (Pubkey): The pubkey.
+///
+/// Returns:
+///     str: The base58 pubkey as a JSON string.
+///
+#[pyfunction]
+pub fn pubkey_to_json(pubkey: Pubkey) -> String {
+    serde_json::to_string(&pubkey.to_string()).unwrap()
+}
+
+/// Parse a pubkey file written by ``solana-keygen pubkey --outfile``.
+///
+/// Args:
+///     raw (str): The file contents.
+///
+/// Returns:
+///     Pubkey: The pubkey.
+///
+/// Raises:
+///     ValueError: If ``raw`` isn't a JSON string holding a base58 pubkey.
+///
+#[pyfunction]
+pub fn pubkey_from_json(raw: &str) -> PyResult<Pubkey> {
+    let s: String = serde_json::from_str(raw).map_err(|e| to_py_value_err(&e))?;
+    s.parse::<PubkeyOriginal>()
+        .map(Pubkey)
+        .map_err(|e| to_py_value_err(&e))
+}
+
+/// Format a unix timestamp like chrono's ``Debug`` output for ``DateTime<Local>``.
+fn format_local_timestamp(timestamp: UnixTimestamp, utc_offset: i64) -> String {
+    let local = timestamp.saturating_add(utc_offset);
+    let days = local.div_euclid(86_400);
+    let secs = local.rem_euclid(86_400);
+    // Howard Hinnant's civil_from_days.
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    let sign = if utc_offset < 0 { '-' } else { '+' };
+    let offset = utc_offset.unsigned_abs();
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}{sign}{:02}:{:02}",
+        secs / 3_600,
+        secs % 3_600 / 60,
+        secs % 60,
+        offset / 3_600,
+        offset % 3_600 / 60
+    )
+}
+
+/// The local UTC offset at ``timestamp``, in seconds, as Python's ``time`` module reports it.
+fn local_utc_offset(py: Python<'_>, timestamp: UnixTimestamp) -> PyResult<i64> {
+    py.import("time")?
+        .call_method1("localtime", (timestamp,))?
+        .getattr("tm_gmtoff")?
+        .extract()
+}
+
+/// Check that every instruction only references accounts the message has.
+fn check_account_indexes(message: &VersionedMessageOriginal) -> PyResult<()> {
+    let num_keys = message.static_account_keys().len()
+        + message
+            .address_table_lookups()
+            .unwrap_or_default()
+            .iter()
+            .map(|l| l.writable_indexes.len() + l.readonly_indexes.len())
+            .sum::<usize>();
+    for (i, ix) in message.instructions().iter().enumerate() {
+        let indexes = std::iter::once(&ix.program_id_index).chain(&ix.accounts);
+        if let Some(index) = indexes.map(|a| usize::from(*a)).find(|a| *a >= num_keys) {
+            return Err(to_py_value_err(&format!(
+                "instruction {i} references account index {index}, but the message has only {num_keys} accounts"
+            )));
+        }
+    }
+    Ok(())
+}
+
+enum AccountKey<'a> {
+    Known(&'a PubkeyOriginal),
+    Unknown {
+        lookup_index: usize,
+        table_index: u8,
+    },
+}
+
+impl std::fmt::Display for AccountKey<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Known(address) => write!(f, "{address}"),
+            Self::Unknown {
+                lookup_index,
+                table_index,
+            } => write!(
+                f,
+                "Unknown Address (uses lookup {lookup_index} and index {table_index})"
+            ),
+        }
+    }
+}
+
+/// Lookup table addresses, writable ones first, as the runtime loads them.
+fn unknown_keys(lookups: &[MessageAddressTableLookup]) -> Vec<AccountKey<'static>> {
+    let writable = lookups
+        .iter()
+        .enumerate()
+        .flat_map(|(lookup_index, lookup)| {
+            lookup
+                .writable_indexes
+                .iter()
+                .map(move |table_index| AccountKey::Unknown {
+                    lookup_index,
+                    table_index: *table_index,
+                })
+        });
+    let readonly = lookups
+        .iter()
+        .enumerate()
+        .flat_map(|(lookup_index, lookup)| {
+            lookup
+                .readonly_indexes
+                .iter()
+                .map(move |table_index| AccountKey::Unknown {
+                    lookup_index,
+                    table_index: *table_index,
+                })
+        });
+    writable.chain(readonly).collect()
+}
+
+fn account_mode(message: &VersionedMessageOriginal, index: usize, is_invoked: bool) -> String {
+    format!(
+        "{}r{}{}",
+        if message.is_signer(index) { "s" } else { "-" },
+        if message.is_maybe_writable(index) {
+            "w"
+        } else {
+            "-"
+        },
+        if is_invoked { "x" } else { "-" },
+    )
+}
+
+/// The decoded form the CLI prints for an instruction, if it knows the program.
+fn decoded_instruction(program_id: &PubkeyOriginal, data: &[u8]) -> Option<String> {
+    if program_id == &vote::program::ID {
+        bincode::deserialize::<VoteInstruction>(data)
+            .ok()
+            .map(|ix| format!("{ix:?}"))
+    } else if program_id == &stake::program::ID {
+        bincode::deserialize::<StakeInstruction>(data)
+            .ok()
+            .map(|ix| format!("{ix:?}"))
+    } else if program_id == &system_program::ID {
+        bincode::deserialize::<SystemInstruction>(data)
+            .ok()
+            .map(|ix| format!("{ix:?}"))
+    } else if program_id == &MEMO_PROGRAM_ID || program_id == &MEMO_V1_PROGRAM_ID {
+        std::str::from_utf8(data)
+            .ok()
+            .map(|memo| format!("Data: \"{memo}\""))
+    } else {
+        None
+    }
+}
+
+fn reward_type_str(reward_type: &RewardType) -> &'static str {
+    match reward_type {
+        RewardType::Fee => "fee",
+        RewardType::Rent => "rent",
+        RewardType::Staking => "staking",
+        RewardType::Voting => "voting",
+    }
+}
+
+fn write_meta(
+    w: &mut String,
+    meta: &UiTransactionStatusMetaOriginal,
+    prefix: &str,
+) -> std::fmt::Result {
+    let status = match &meta.err {
+        None => "Ok".to_string(),
+        Some(err) => err.to_string(),
+    };
+    writeln!(w, "{prefix}Status: {status}")?;
+    writeln!(w, "{prefix}  Fee: ◎{}", lamports_to_sol(meta.fee))?;
+    for (i, (pre, post)) in meta
+        .pre_balances
+        .iter()
+        .zip(&meta.post_balances)
+        .enumerate()
+    {
+        if pre == post {
+            writeln!(
+                w,
+                "{prefix}  Account {i} balance: ◎{}",
+                lamports_to_sol(*pre)
+            )?;
+        } else {
+            writeln!(
+                w,
+                "{prefix}  Account {i} balance: ◎{} -> ◎{}",
+                lamports_to_sol(*pre),
+                lamports_to_sol(*post)
+            )?;
+        }
+    }
+    if let Some(logs) = meta.log_messages.as_ref().filter(|logs| !logs.is_empty()) {
+        writeln!(w, "{prefix}Log Messages:")?;
+        for log in logs {
+            writeln!(w, "{prefix}  {log}")?;
+        }
+    }
+    if let Some(rewards) = meta.rewards.as_ref().filter(|rewards| !rewards.is_empty()) {
+        writeln!(w, "{prefix}Rewards:")?;
+        writeln!(
+            w,
+            "{prefix}  {:<44}  {:^15}  {:<16}  {:<20}",
+            "Address", "Type", "Amount", "New Balance"
+        )?;
+        for reward in rewards {
+            let sign = if reward.lamports < 0 { "-" } else { "" };
+            writeln!(
+                w,
+                "{prefix}  {:<44}  {:^15}  {}◎{:<14.9}  ◎{:<18.9}",
+                reward.pubkey,
+                reward.reward_type.as_ref().map_or("-", reward_type_str),
+                sign,
+                lamports_to_sol(reward.lamports.unsigned_abs()),
+                lamports_to_sol(reward.post_balance)
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn write_transaction(
+    w: &mut String,
+    tx: &VersionedTransactionOriginal,
+    meta: Option<&UiTransactionStatusMetaOriginal>,
+    prefix: &str,
+    sigverify: bool,
+    block_time: Option<(UnixTimestamp, i64)>,
+) -> std::fmt::Result {
+    if let Some((block_time, utc_offset)) = block_time {
+        writeln!(
+            w,
+            "{prefix}Block Time: {}",
+            format_local_timestamp(block_time, utc_offset)
+        )?;
+    }
+    let message = &tx.message;
+    let version = match message {
+        VersionedMessageOriginal::Legacy(_) => "legacy".to_string(),
+        VersionedMessageOriginal::V0(_) => "0".to_string(),
+    };
+    writeln!(w, "{prefix}Version: {version}")?;
+    writeln!(
+        w,
+        "{prefix}Recent Blockhash: {:?}",
+        message.recent_blockhash()
+    )?;
+    let sigverify_results = sigverify.then(|| tx.verify_with_results());
+    for (i, signature) in tx.signatures.iter().enumerate() {
+        let status = match &sigverify_results {
+            Some(results) if results.get(i).copied().unwrap_or(false) => " (pass)",
+            Some(_) => " (fail)",
+            None => "",
+        };
+        writeln!(w, "{prefix}Signature {i}: {signature:?}{status}")?;
+    }
+    let lookups = message.address_table_lookups().unwrap_or_default();
+    let account_keys: Vec<AccountKey> = message
+        .static_account_keys()
+        .iter()
+        .map(AccountKey::Known)
+        .chain(unknown_keys(lookups))
+        .collect();
+    let instructions = message.instructions();
+    let mut fee_payer_index = None;
+    for (i, key) in account_keys.iter().enumerate() {
+        let is_invoked = instructions
+            .iter()
+            .any(|ix| usize::from(ix.program_id_index) == i);
+        let is_passed = instructions
+            .iter()
+            .any(|ix| ix.accounts.iter().any(|a| usize::from(*a) == i));
+        if fee_payer_index.is_none() && (!is_invoked || is_passed) {
+            fee_payer_index = Some(i);
+        }
+        writeln!(
+            w,
+            "{prefix}Account {i}: {} {key}{}",
+            account_mode(message, i, is_invoked),
+            if fee_payer_index == Some(i) {
+                " (fee payer)"
+            } else {
+                ""
+            }
+        )?;
+    }
+    for (i, lookup) in lookups.iter().enumerate() {
+        writeln!(w, "{prefix}Address Table Lookup {i}")?;
+        writeln!(w, "{prefix}  Table Account: {}", lookup.account_key)?;
+        writeln!(
+            w,
+            "{prefix}  Writable Indexes: {:?}",
+            &lookup.writable_indexes[..]
+        )?;
+        writeln!(
+            w,
+            "{prefix}  Readonly Indexes: {:?}",
+            &lookup.readonly_indexes[..]
+        )?;
+    }
+    for (i, ix) in instructions.iter().enumerate() {
+        let program = account_keys
+            .get(usize::from(ix.program_id_index))
+            .ok_or(std::fmt::Error)?;
+        writeln!(w, "{prefix}Instruction {i}")?;
+        writeln!(
+            w,
+            "{prefix}  Program:   {program} ({})",
+            ix.program_id_index
+        )?;
+        for (j, account_index) in ix.accounts.iter().enumerate() {
+            let account = account_keys
+                .get(usize::from(*account_index))
+                .ok_or(std::fmt::Error)?;
+            writeln!(w, "{prefix}  Account {j}: {account} ({account_index})")?;
+        }
+        let decoded = match program {
+            AccountKey::Known(program_id) => decoded_instruction(program_id, &ix.data),
+            AccountKey::Unknown { .. } => None,
+        };
+        match decoded {
+            Some(decoded) => writeln!(w, "{prefix}  {decoded}")?,
+            None => writeln!(w, "{prefix}  Data: {:?}", ix.data)?,
+        }
+    }
+    match meta {
+        Some(meta) => write_meta(w, meta, prefix),
+        None => writeln!(w, "{prefix}Status: Unavailable"),
+    }
+}
+
+/// Format a transaction the way ``solana confirm -v`` prints it.
+///
+/// Lookup table addresses are shown as unknown, like the CLI does. The CLI's
+/// hex dump of return data is not reproduced.
+///
+/// Args:
+///     transaction (VersionedTransaction): The transaction.
+///     meta (Optional[UiTransactionStatusMeta]): The status meta. If missing,
+///         the status is printed as unavailable.
+///     prefix (str): Prepended to every line.
+///     sigverify (bool): Whether to verify the signatures and print ``(pass)`` or ``(fail)``.
+///     block_time (Optional[int]): The block time, printed in local time.
+///
+/// Returns:
+///     str: The formatted transaction, one line per field.
+///
+/// Raises:
+///     ValueError: If an instruction references an account index the message doesn't have.
+///
+/// Example:
+///     >>> from solders.display import format_transaction
+///     >>> from solders.transaction import VersionedTransaction
+///     >>> from solders.message import Message
+///     >>> from solders.keypair import Keypair
+///     >>> payer = Keypair()
+///     >>> tx = VersionedTransaction(Message([], payer.pubkey()), [payer])
+///     >>> lines = format_transaction(tx, sigverify=True).splitlines()
+///     >>> lines[:2]
+///     ['Version: legacy', 'Recent Blockhash: 11111111111111111111111111111111']
+///     >>> lines[2].endswith("(pass)"), lines[3] == f"Account 0: srw- {payer.pubkey()} (fee payer)"
+///     (True, True)
+///     >>> lines[4]
+///     'Status: Unavailable'
+///
+#[pyfunction]
+#[pyo3(signature = (transaction, meta = None, prefix = "", sigverify = false, block_time = None))]
+pub fn format_transaction(
+    py: Python<'_>,
+    transaction: VersionedTransaction,
+    meta: Option<UiTransactionStatusMeta>,
+    prefix: &str,
+    sigverify: bool,
+    block_time: Option<UnixTimestamp>,
+) -> PyResult<String> {
+    let tx = VersionedTransactionOriginal::from(transaction);
+    check_account_indexes(&tx.message)?;
+    let block_time = block_time
+        .map(|t| local_utc_offset(py, t).map(|offset| (t, offset)))
+        .transpose()?;
+    let meta = meta.map(UiTransactionStatusMetaOriginal::from);
+    let mut out = String::new();
+    write_transaction(&mut out, &tx, meta.as_ref(), prefix, sigverify, block_time)
+        .map_err(|e| to_py_value_err(&e))?;
+    Ok(out)
+}
+
+pub(crate) fn create_display_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "display")?;
+    m.add_function(wrap_pyfunction!(keypair_to_json, m)?)?;
+    m.add_function(wrap_pyfunction!(keypair_from_json, m)?)?;
+    m.add_function(wrap_pyfunction!(pubkey_to_json, m)?)?;
+    m.add_function(wrap_pyfunction!(pubkey_from_json, m)?)?;
+    m.add_function(wrap_pyfunction!(format_transaction, m)?)?;
+    Ok(m)
+}
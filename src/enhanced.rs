@@ -0,0 +1,564 @@
+use std::fmt::Display;
+
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::{serde_as, DisplayFromStr, NoneAsEmptyString};
+use solana_sdk::clock::{Slot, UnixTimestamp};
+use solders_macros::{common_methods, richcmp_eq_only};
+use solders_primitives::{pubkey::Pubkey, signature::Signature};
+use solders_traits::{
+    py_from_bytes_general_via_cbor, pybytes_general_via_cbor, to_py_value_err, RichcmpEqualityOnly,
+};
+
+macro_rules! enhanced_boilerplate {
+    ($name:ident) => {
+        impl RichcmpEqualityOnly for $name {}
+        impl Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "{:?}", self)
+            }
+        }
+        pybytes_general_via_cbor!($name);
+        py_from_bytes_general_via_cbor!($name);
+        solders_traits::common_methods_default!($name);
+    };
+}
+
+/// A SOL transfer in an enhanced transaction.
+///
+/// Args:
+///     from_user_account (Optional[Pubkey]): The sender.
+///     to_user_account (Optional[Pubkey]): The recipient.
+///     amount (int): The amount in lamports.
+///
+#[serde_as]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[pyclass(module = "solders.enhanced", subclass)]
+pub struct NativeTransfer {
+    /// Optional[Pubkey]: The sender.
+    #[pyo3(get)]
+    #[serde(default)]
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub from_user_account: Option<Pubkey>,
+    /// Optional[Pubkey]: The recipient.
+    #[pyo3(get)]
+    #[serde(default)]
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub to_user_account: Option<Pubkey>,
+    /// int: The amount in lamports.
+    #[pyo3(get)]
+    pub amount: u64,
+}
+
+enhanced_boilerplate!(NativeTransfer);
+
+#[richcmp_eq_only]
+#[common_methods]
+#[pymethods]
+impl NativeTransfer {
+    #[new]
+    pub fn new(
+        from_user_account: Option<Pubkey>,
+        to_user_account: Option<Pubkey>,
+        amount: u64,
+    ) -> Self {
+        Self {
+            from_user_account,
+            to_user_account,
+            amount,
+        }
+    }
+}
+
+/// A token transfer in an enhanced transaction.
+///
+/// Args:
+///     from_user_account (Optional[Pubkey]): The owner of the source token account.
+///     to_user_account (Optional[Pubkey]): The owner of the destination token account.
+///     from_token_account (Optional[Pubkey]): The source token account.
+///     to_token_account (Optional[Pubkey]): The destination token account.
+///     token_amount (float): The amount in UI units, i.e. divided by ``10 ** decimals``.
+///     mint (Pubkey): The token mint.
+///     token_standard (Optional[str]): The provider's token standard label, e.g. ``"Fungible"``.
+///
+#[serde_as]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[pyclass(module = "solders.enhanced", subclass)]
+pub struct TokenTransfer {
+    /// Optional[Pubkey]: The owner of the source token account.
+    #[pyo3(get)]
+    #[serde(default)]
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub from_user_account: Option<Pubkey>,
+    /// Optional[Pubkey]: The owner of the destination token account.
+    #[pyo3(get)]
+    #[serde(default)]
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub to_user_account: Option<Pubkey>,
+    /// Optional[Pubkey]: The source token account.
+    #[pyo3(get)]
+    #[serde(default)]
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub from_token_account: Option<Pubkey>,
+    /// Optional[Pubkey]: The destination token account.
+    #[pyo3(get)]
+    #[serde(default)]
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub to_token_account: Option<Pubkey>,
+    /// float: The amount in UI units, i.e. divided by ``10 ** decimals``.
+    #[pyo3(get)]
+    pub token_amount: f64,
+    /// Pubkey: The token mint.
+    #[pyo3(get)]
+    #[serde_as(as = "DisplayFromStr")]
+    pub mint: Pubkey,
+    /// Optional[str]: The provider's token standard label, e.g. ``"Fungible"``.
+    #[pyo3(get)]
+    #[serde(default)]
+    pub token_standard: Option<String>,
+}
+
+enhanced_boilerplate!(TokenTransfer);
+
+#[richcmp_eq_only]
+#[common_methods]
+#[pymethods]
+impl TokenTransfer {
+    #[allow(clippy::too_many_arguments)]
+    #[new]
+    pub fn new(
+        from_user_account: Option<Pubkey>,
+        to_user_account: Option<Pubkey>,
+        from_token_account: Option<Pubkey>,
+        to_token_account: Option<Pubkey>,
+        token_amount: f64,
+        mint: Pubkey,
+        token_standard: Option<String>,
+    ) -> Self {
+        Self {
+            from_user_account,
+            to_user_account,
+            from_token_account,
+            to_token_account,
+            token_amount,
+            mint,
+            token_standard,
+        }
+    }
+}
+
+/// A raw token amount with its decimals.
+///
+/// Args:
+///     token_amount (str): The amount in base units, as a decimal string.
+///     decimals (int): The mint decimals.
+///
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[pyclass(module = "solders.enhanced", subclass)]
+pub struct RawTokenAmount {
+    /// str: The amount in base units, as a decimal string. May be negative for balance changes.
+    #[pyo3(get)]
+    pub token_amount: String,
+    /// int: The mint decimals.
+    #[pyo3(get)]
+    pub decimals: u8,
+}
+
+enhanced_boilerplate!(RawTokenAmount);
+
+#[richcmp_eq_only]
+#[common_methods]
+#[pymethods]
+impl RawTokenAmount {
+    #[new]
+    pub fn new(token_amount: String, decimals: u8) -> Self {
+        Self {
+            token_amount,
+            decimals,
+        }
+    }
+}
+
+/// The change in one token account's balance.
+///
+/// Args:
+///     user_account (Pubkey): The owner of the token account.
+///     token_account (Pubkey): The token account.
+///     mint (Pubkey): The token mint.
+///     raw_token_amount (RawTokenAmount): The signed change in base units.
+///
+#[serde_as]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[pyclass(module = "solders.enhanced", subclass)]
+pub struct TokenBalanceChange {
+    /// Pubkey: The owner of the token account.
+    #[pyo3(get)]
+    #[serde_as(as = "DisplayFromStr")]
+    pub user_account: Pubkey,
+    /// Pubkey: The token account.
+    #[pyo3(get)]
+    #[serde_as(as = "DisplayFromStr")]
+    pub token_account: Pubkey,
+    /// Pubkey: The token mint.
+    #[pyo3(get)]
+    #[serde_as(as = "DisplayFromStr")]
+    pub mint: Pubkey,
+    /// RawTokenAmount: The signed change in base units.
+    #[pyo3(get)]
+    pub raw_token_amount: RawTokenAmount,
+}
+
+enhanced_boilerplate!(TokenBalanceChange);
+
+#[richcmp_eq_only]
+#[common_methods]
+#[pymethods]
+impl TokenBalanceChange {
+    #[new]
+    pub fn new(
+        user_account: Pubkey,
+        token_account: Pubkey,
+        mint: Pubkey,
+        raw_token_amount: RawTokenAmount,
+    ) -> Self {
+        Self {
+            user_account,
+            token_account,
+            mint,
+            raw_token_amount,
+        }
+    }
+}
+
+/// The balance changes of one account in an enhanced transaction.
+///
+/// Args:
+///     account (Pubkey): The account.
+///     native_balance_change (int): The change in lamports.
+///     token_balance_changes (Sequence[TokenBalanceChange]): Changes to token accounts owned by it.
+///
+#[serde_as]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[pyclass(module = "solders.enhanced", subclass)]
+pub struct AccountData {
+    /// Pubkey: The account.
+    #[pyo3(get)]
+    #[serde_as(as = "DisplayFromStr")]
+    pub account: Pubkey,
+    /// int: The change in lamports.
+    #[pyo3(get)]
+    pub native_balance_change: i64,
+    /// List[TokenBalanceChange]: Changes to token accounts owned by it.
+    #[pyo3(get)]
+    #[serde(default)]
+    pub token_balance_changes: Vec<TokenBalanceChange>,
+}
+
+enhanced_boilerplate!(AccountData);
+
+#[richcmp_eq_only]
+#[common_methods]
+#[pymethods]
+impl AccountData {
+    #[new]
+    pub fn new(
+        account: Pubkey,
+        native_balance_change: i64,
+        token_balance_changes: Vec<TokenBalanceChange>,
+    ) -> Self {
+        Self {
+            account,
+            native_balance_change,
+            token_balance_changes,
+        }
+    }
+}
+
+/// An inner instruction in an enhanced transaction.
+///
+/// Args:
+///     accounts (Sequence[Pubkey]): The instruction accounts.
+///     data (str): The base58-encoded instruction data.
+///     program_id (Pubkey): The program.
+///
+#[serde_as]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[pyclass(module = "solders.enhanced", subclass)]
+pub struct EnhancedInnerInstruction {
+    /// List[Pubkey]: The instruction accounts.
+    #[pyo3(get)]
+    #[serde_as(as = "Vec<DisplayFromStr>")]
+    pub accounts: Vec<Pubkey>,
+    /// str: The base58-encoded instruction data.
+    #[pyo3(get)]
+    pub data: String,
+    /// Pubkey: The program.
+    #[pyo3(get)]
+    #[serde_as(as = "DisplayFromStr")]
+    pub program_id: Pubkey,
+}
+
+enhanced_boilerplate!(EnhancedInnerInstruction);
+
+#[richcmp_eq_only]
+#[common_methods]
+#[pymethods]
+impl EnhancedInnerInstruction {
+    #[new]
+    pub fn new(accounts: Vec<Pubkey>, data: String, program_id: Pubkey) -> Self {
+        Self {
+            accounts,
+            data,
+            program_id,
+        }
+    }
+
+    /// Decode the instruction data.
+    ///
+    /// Returns:
+    ///     bytes: The raw instruction data.
+    ///
+    pub fn decoded_data(&self) -> PyResult<Vec<u8>> {
+        decode_base58(&self.data)
+    }
+}
+
+/// A top-level instruction in an enhanced transaction.
+///
+/// Args:
+///     accounts (Sequence[Pubkey]): The instruction accounts.
+///     data (str): The base58-encoded instruction data.
+///     program_id (Pubkey): The program.
+///     inner_instructions (Sequence[EnhancedInnerInstruction]): The CPIs it made.
+///
+#[serde_as]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[pyclass(module = "solders.enhanced", subclass)]
+pub struct EnhancedInstruction {
+    /// List[Pubkey]: The instruction accounts.
+    #[pyo3(get)]
+    #[serde_as(as = "Vec<DisplayFromStr>")]
+    pub accounts: Vec<Pubkey>,
+    /// str: The base58-encoded instruction data.
+    #[pyo3(get)]
+    pub data: String,
+    /// Pubkey: The program.
+    #[pyo3(get)]
+    #[serde_as(as = "DisplayFromStr")]
+    pub program_id: Pubkey,
+    /// List[EnhancedInnerInstruction]: The CPIs it made.
+    #[pyo3(get)]
+    #[serde(default)]
+    pub inner_instructions: Vec<EnhancedInnerInstruction>,
+}
+
+enhanced_boilerplate!(EnhancedInstruction);
+
+#[richcmp_eq_only]
+#[common_methods]
+#[pymethods]
+impl EnhancedInstruction {
+    #[new]
+    pub fn new(
+        accounts: Vec<Pubkey>,
+        data: String,
+        program_id: Pubkey,
+        inner_instructions: Vec<EnhancedInnerInstruction>,
+    ) -> Self {
+        Self {
+            accounts,
+            data,
+            program_id,
+            inner_instructions,
+        }
+    }
+
+    /// Decode the instruction data.
+    ///
+    /// Returns:
+    ///     bytes: The raw instruction data.
+    ///
+    pub fn decoded_data(&self) -> PyResult<Vec<u8>> {
+        decode_base58(&self.data)
+    }
+}
+
+fn decode_base58(data: &str) -> PyResult<Vec<u8>> {
+    bs58::decode(data)
+        .into_vec()
+        .map_err(|e| to_py_value_err(&e))
+}
+
+/// A transaction in the "enhanced" format used by indexing providers such as Helius,
+/// e.g. in webhook payloads.
+///
+/// Fields not listed here, such as ``events``, are ignored when parsing.
+///
+/// Args:
+///     description (str): A human-readable summary.
+///     transaction_type (str): The provider's classification, e.g. ``"TRANSFER"``. ``type`` in JSON.
+///     source (str): The program or protocol the provider attributes the transaction to.
+///     fee (int): The fee in lamports.
+///     fee_payer (Pubkey): The fee payer.
+///     signature (Signature): The transaction signature.
+///     slot (int): The slot the transaction landed in.
+///     timestamp (int): The block time.
+///     native_transfers (Sequence[NativeTransfer]): SOL transfers.
+///     token_transfers (Sequence[TokenTransfer]): Token transfers.
+///     account_data (Sequence[AccountData]): Balance changes per account.
+///     instructions (Sequence[EnhancedInstruction]): The instructions.
+///     transaction_error (Optional[str]): The error, if the transaction failed.
+///
+#[serde_as]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[pyclass(module = "solders.enhanced", subclass)]
+pub struct EnhancedTransaction {
+    /// str: A human-readable summary.
+    #[pyo3(get)]
+    #[serde(default)]
+    pub description: String,
+    /// str: The provider's classification, e.g. ``"TRANSFER"``.
+    #[pyo3(get)]
+    #[serde(rename = "type")]
+    pub transaction_type: String,
+    /// str: The program or protocol the provider attributes the transaction to.
+    #[pyo3(get)]
+    pub source: String,
+    /// int: The fee in lamports.
+    #[pyo3(get)]
+    pub fee: u64,
+    /// Pubkey: The fee payer.
+    #[pyo3(get)]
+    #[serde_as(as = "DisplayFromStr")]
+    pub fee_payer: Pubkey,
+    /// Signature: The transaction signature.
+    #[pyo3(get)]
+    #[serde_as(as = "DisplayFromStr")]
+    pub signature: Signature,
+    /// int: The slot the transaction landed in.
+    #[pyo3(get)]
+    pub slot: Slot,
+    /// int: The block time.
+    #[pyo3(get)]
+    pub timestamp: UnixTimestamp,
+    /// List[NativeTransfer]: SOL transfers.
+    #[pyo3(get)]
+    #[serde(default)]
+    pub native_transfers: Vec<NativeTransfer>,
+    /// List[TokenTransfer]: Token transfers.
+    #[pyo3(get)]
+    #[serde(default)]
+    pub token_transfers: Vec<TokenTransfer>,
+    /// List[AccountData]: Balance changes per account.
+    #[pyo3(get)]
+    #[serde(default)]
+    pub account_data: Vec<AccountData>,
+    /// List[EnhancedInstruction]: The instructions.
+    #[pyo3(get)]
+    #[serde(default)]
+    pub instructions: Vec<EnhancedInstruction>,
+    #[serde(default)]
+    transaction_error: Option<Value>,
+}
+
+enhanced_boilerplate!(EnhancedTransaction);
+
+#[richcmp_eq_only]
+#[common_methods]
+#[pymethods]
+impl EnhancedTransaction {
+    #[allow(clippy::too_many_arguments)]
+    #[new]
+    pub fn new(
+        description: String,
+        transaction_type: String,
+        source: String,
+        fee: u64,
+        fee_payer: Pubkey,
+        signature: Signature,
+        slot: Slot,
+        timestamp: UnixTimestamp,
+        native_transfers: Vec<NativeTransfer>,
+        token_transfers: Vec<TokenTransfer>,
+        account_data: Vec<AccountData>,
+        instructions: Vec<EnhancedInstruction>,
+        transaction_error: Option<String>,
+    ) -> Self {
+        Self {
+            description,
+            transaction_type,
+            source,
+            fee,
+            fee_payer,
+            signature,
+            slot,
+            timestamp,
+            native_transfers,
+            token_transfers,
+            account_data,
+            instructions,
+            transaction_error: transaction_error.map(Value::String),
+        }
+    }
+
+    /// Optional[str]: The error, if the transaction failed. Structured errors are
+    /// returned as JSON.
+    #[getter]
+    pub fn transaction_error(&self) -> Option<String> {
+        match &self.transaction_error {
+            None | Some(Value::Null) => None,
+            Some(Value::Object(o)) if o.len() == 1 && o.contains_key("error") => {
+                match &o["error"] {
+                    Value::String(s) => Some(s.clone()),
+                    other => Some(other.to_string()),
+                }
+            }
+            Some(Value::String(s)) => Some(s.clone()),
+            Some(other) => Some(other.to_string()),
+        }
+    }
+
+    /// Whether the transaction failed.
+    pub fn is_err(&self) -> bool {
+        self.transaction_error().is_some()
+    }
+}
+
+/// Parse a webhook payload or API response containing a list of enhanced transactions.
+///
+/// Args:
+///     raw (str): The JSON array.
+///
+/// Returns:
+///     List[EnhancedTransaction]: The parsed transactions.
+///
+/// Raises:
+///     ValueError: If the JSON doesn't match the enhanced transaction format.
+///
+#[pyfunction]
+pub fn parse_enhanced_transactions(raw: &str) -> PyResult<Vec<EnhancedTransaction>> {
+    serde_json::from_str(raw).map_err(|e| to_py_value_err(&e))
+}
+
+pub(crate) fn create_enhanced_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "enhanced")?;
+    m.add_class::<NativeTransfer>()?;
+    m.add_class::<TokenTransfer>()?;
+    m.add_class::<RawTokenAmount>()?;
+    m.add_class::<TokenBalanceChange>()?;
+    m.add_class::<AccountData>()?;
+    m.add_class::<EnhancedInnerInstruction>()?;
+    m.add_class::<EnhancedInstruction>()?;
+    m.add_class::<EnhancedTransaction>()?;
+    m.add_function(wrap_pyfunction!(parse_enhanced_transactions, m)?)?;
+    Ok(m)
+}
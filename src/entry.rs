@@ -0,0 +1,253 @@
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use solana_sdk::{
+    hash::{hash, hashv, Hash as HashOriginal},
+    signature::Signature as SignatureOriginal,
+    transaction::VersionedTransaction as VersionedTransactionOriginal,
+};
+use solders_macros::{common_methods, richcmp_eq_only};
+use solders_primitives::transaction::VersionedTransaction;
+use solders_traits::{
+    handle_py_value_err, impl_display, py_from_bytes_general_via_bincode,
+    pybytes_general_via_bincode, RichcmpEqualityOnly,
+};
+
+use crate::SolderHash;
+
+const LEAF_PREFIX: &[u8] = &[0];
+const INTERMEDIATE_PREFIX: &[u8] = &[1];
+
+/// The root of the merkle tree over the transaction signatures, as used for PoH mixins.
+fn hash_signatures(signatures: &[&SignatureOriginal]) -> HashOriginal {
+    let mut level: Vec<HashOriginal> = signatures
+        .iter()
+        .map(|sig| hashv(&[LEAF_PREFIX, sig.as_ref()]))
+        .collect();
+    if level.is_empty() {
+        return HashOriginal::default();
+    }
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| {
+                let right = pair.get(1).unwrap_or(&pair[0]);
+                hashv(&[INTERMEDIATE_PREFIX, pair[0].as_ref(), right.as_ref()])
+            })
+            .collect();
+    }
+    level[0]
+}
+
+fn hash_transactions_original(transactions: &[VersionedTransactionOriginal]) -> HashOriginal {
+    let signatures: Vec<&SignatureOriginal> = transactions
+        .iter()
+        .flat_map(|tx| tx.signatures.iter())
+        .collect();
+    hash_signatures(&signatures)
+}
+
+fn next_hash_original(
+    start_hash: HashOriginal,
+    num_hashes: u64,
+    transactions: &[VersionedTransactionOriginal],
+) -> HashOriginal {
+    if num_hashes == 0 && transactions.is_empty() {
+        return start_hash;
+    }
+    let mut current = start_hash;
+    for _ in 1..num_hashes {
+        current = hash(current.as_ref());
+    }
+    if transactions.is_empty() {
+        hash(current.as_ref())
+    } else {
+        let mixin = hash_transactions_original(transactions);
+        hashv(&[current.as_ref(), mixin.as_ref()])
+    }
+}
+
+/// A ledger entry: a PoH hash and the transactions recorded into it.
+///
+/// ``bytes(entry)`` and :meth:`Entry.from_bytes` use the same bincode layout as the
+/// validator, so entries from Geyser plugins or shred payloads can be parsed directly.
+///
+/// Args:
+///     num_hashes (int): The number of hashes since the previous entry.
+///     hash (Hash): The PoH hash after ``num_hashes`` hashes and the transaction mixin.
+///     transactions (Sequence[VersionedTransaction]): The transactions recorded in this entry.
+///
+/// Example:
+///     >>> from solders.entry import Entry
+///     >>> from solders.hash import Hash
+///     >>> start = Hash.default()
+///     >>> tick = Entry.new_tick(3, start)
+///     >>> tick.is_tick()
+///     True
+///     >>> tick.verify(start)
+///     True
+///     >>> Entry.from_bytes(bytes(tick)) == tick
+///     True
+///
+#[pyclass(module = "solders.entry", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Entry {
+    num_hashes: u64,
+    hash: HashOriginal,
+    transactions: Vec<VersionedTransactionOriginal>,
+}
+
+#[richcmp_eq_only]
+#[common_methods]
+#[pymethods]
+impl Entry {
+    #[new]
+    pub fn new(num_hashes: u64, hash: SolderHash, transactions: Vec<VersionedTransaction>) -> Self {
+        Self {
+            num_hashes,
+            hash: hash.into(),
+            transactions: transactions.into_iter().map(|tx| tx.into()).collect(),
+        }
+    }
+
+    /// Create the tick that follows ``prev_hash`` after ``num_hashes`` hashes.
+    ///
+    /// Args:
+    ///     num_hashes (int): The number of hashes.
+    ///     prev_hash (Hash): The hash of the previous entry.
+    ///
+    /// Returns:
+    ///     Entry: The tick.
+    ///
+    #[staticmethod]
+    pub fn new_tick(num_hashes: u64, prev_hash: SolderHash) -> Self {
+        Self {
+            num_hashes,
+            hash: next_hash_original(prev_hash.into(), num_hashes, &[]),
+            transactions: vec![],
+        }
+    }
+
+    /// int: The number of hashes since the previous entry.
+    #[getter]
+    pub fn num_hashes(&self) -> u64 {
+        self.num_hashes
+    }
+
+    /// Hash: The PoH hash after ``num_hashes`` hashes and the transaction mixin.
+    #[getter]
+    pub fn hash(&self) -> SolderHash {
+        self.hash.into()
+    }
+
+    /// List[VersionedTransaction]: The transactions recorded in this entry.
+    #[getter]
+    pub fn transactions(&self) -> Vec<VersionedTransaction> {
+        self.transactions
+            .iter()
+            .map(|tx| tx.clone().into())
+            .collect()
+    }
+
+    /// Whether this entry is a tick, i.e. has no transactions.
+    pub fn is_tick(&self) -> bool {
+        self.transactions.is_empty()
+    }
+
+    /// Check that this entry's hash follows from ``start_hash``.
+    ///
+    /// Args:
+    ///     start_hash (Hash): The hash of the previous entry.
+    ///
+    /// Returns:
+    ///     bool: Whether the hash is valid.
+    ///
+    pub fn verify(&self, start_hash: SolderHash) -> bool {
+        self.hash == next_hash_original(start_hash.into(), self.num_hashes, &self.transactions)
+    }
+}
+
+impl RichcmpEqualityOnly for Entry {}
+impl_display!(Entry);
+pybytes_general_via_bincode!(Entry);
+py_from_bytes_general_via_bincode!(Entry);
+solders_traits::common_methods_default!(Entry);
+
+/// Compute the PoH hash that follows ``start_hash``.
+///
+/// Args:
+///     start_hash (Hash): The hash of the previous entry.
+///     num_hashes (int): The number of hashes.
+///     transactions (Sequence[VersionedTransaction]): The transactions to mix in.
+///
+/// Returns:
+///     Hash: The resulting hash.
+///
+#[pyfunction]
+pub fn next_hash(
+    start_hash: SolderHash,
+    num_hashes: u64,
+    transactions: Vec<VersionedTransaction>,
+) -> SolderHash {
+    let transactions: Vec<VersionedTransactionOriginal> =
+        transactions.into_iter().map(|tx| tx.into()).collect();
+    next_hash_original(start_hash.into(), num_hashes, &transactions).into()
+}
+
+/// Compute the merkle root of the transactions' signatures, which is mixed into PoH.
+///
+/// Args:
+///     transactions (Sequence[VersionedTransaction]): The transactions.
+///
+/// Returns:
+///     Hash: The merkle root, or the default hash if there are no signatures.
+///
+#[pyfunction]
+pub fn hash_transactions(transactions: Vec<VersionedTransaction>) -> SolderHash {
+    let transactions: Vec<VersionedTransactionOriginal> =
+        transactions.into_iter().map(|tx| tx.into()).collect();
+    hash_transactions_original(&transactions).into()
+}
+
+/// Deserialize a bincode-encoded list of entries, e.g. a deshredded slot payload.
+///
+/// Args:
+///     data (bytes): The serialized ``Vec<Entry>``.
+///
+/// Returns:
+///     List[Entry]: The entries.
+///
+#[pyfunction]
+pub fn entries_from_bytes(data: &[u8]) -> PyResult<Vec<Entry>> {
+    handle_py_value_err(bincode::deserialize::<Vec<Entry>>(data))
+}
+
+/// Find the first entry that doesn't follow from the one before it.
+///
+/// Args:
+///     entries (Sequence[Entry]): The entries, in order.
+///     start_hash (Hash): The hash preceding the first entry.
+///
+/// Returns:
+///     Optional[int]: The index of the first invalid entry, or ``None`` if the whole chain is valid.
+///
+#[pyfunction]
+pub fn verify_entries(entries: Vec<Entry>, start_hash: SolderHash) -> Option<usize> {
+    let mut prev: HashOriginal = start_hash.into();
+    for (i, entry) in entries.iter().enumerate() {
+        if entry.hash != next_hash_original(prev, entry.num_hashes, &entry.transactions) {
+            return Some(i);
+        }
+        prev = entry.hash;
+    }
+    None
+}
+
+pub(crate) fn create_entry_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "entry")?;
+    m.add_class::<Entry>()?;
+    m.add_function(wrap_pyfunction!(next_hash, m)?)?;
+    m.add_function(wrap_pyfunction!(hash_transactions, m)?)?;
+    m.add_function(wrap_pyfunction!(entries_from_bytes, m)?)?;
+    m.add_function(wrap_pyfunction!(verify_entries, m)?)?;
+    Ok(m)
+}
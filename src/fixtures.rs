@@ -0,0 +1,260 @@
+use std::collections::{BTreeMap, HashMap};
+
+use pyo3::prelude::*;
+use solana_sdk::{
+    hash::hashv,
+    pubkey::Pubkey as PubkeyOriginal,
+    signer::{keypair::keypair_from_seed, Signer},
+};
+use solders_primitives::{keypair::Keypair, pubkey::Pubkey};
+use solders_traits::to_py_value_err;
+
+/// Hands out stable, labeled addresses for test fixtures.
+///
+/// Each label maps to the keypair seeded with ``sha256(namespace + ":" + label)``,
+/// so ``allocator.pubkey("alice")`` is the same in every run and on every machine,
+/// and ``allocator.keypair("alice")`` can sign for it. Fixed addresses, such as a
+/// real mint, can be given a label with :meth:`register`. A label always maps to
+/// one address and an address to one label; anything else raises.
+///
+/// Args:
+///     namespace (str): Separates the addresses of unrelated test suites. It can't
+///         contain ``":"``, so that no two namespace and label pairs share a seed.
+///
+/// Raises:
+///     ValueError: If ``namespace`` contains ``":"``.
+///
+/// Example:
+///     >>> from solders.fixtures import TestAddressAllocator
+///     >>> from solders.pubkey import Pubkey
+///     >>> addresses = TestAddressAllocator("my-tests")
+///     >>> alice = addresses.pubkey("alice")
+///     >>> alice == TestAddressAllocator("my-tests").pubkey("alice")
+///     True
+///     >>> addresses.keypair("alice").pubkey() == alice
+///     True
+///     >>> usdc = Pubkey.from_string("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v")
+///     >>> addresses.register("usdc-mint", usdc) == usdc
+///     True
+///     >>> addresses.annotate(f"{alice} sent 5 of {usdc}")
+///     'alice sent 5 of usdc-mint'
+///
+#[pyclass(module = "solders.fixtures", subclass)]
+#[derive(Clone, Debug, Default)]
+pub struct TestAddressAllocator {
+    namespace: String,
+    /// Each label's address, and whether it was derived from the label.
+    labels: BTreeMap<String, (PubkeyOriginal, bool)>,
+    by_address: HashMap<PubkeyOriginal, String>,
+}
+
+impl TestAddressAllocator {
+    fn derive(&self, label: &str) -> solana_sdk::signer::keypair::Keypair {
+        let seed = hashv(&[self.namespace.as_bytes(), b":", label.as_bytes()]);
+        keypair_from_seed(seed.as_ref()).unwrap()
+    }
+
+    fn insert(&mut self, label: &str, address: PubkeyOriginal, derived: bool) -> PyResult<()> {
+        if label.is_empty() {
+            return Err(to_py_value_err(&"labels can't be empty"));
+        }
+        if let Some((existing, _)) = self.labels.get(label) {
+            if *existing != address {
+                return Err(to_py_value_err(&format!(
+                    "{label:?} is already {existing}, not {address}"
+                )));
+            }
+            return Ok(());
+        }
+        if let Some(other) = self.by_address.get(&address) {
+            return Err(to_py_value_err(&format!(
+                "{address} is already labeled {other:?}"
+            )));
+        }
+        self.labels.insert(label.to_owned(), (address, derived));
+        self.by_address.insert(address, label.to_owned());
+        Ok(())
+    }
+}
+
+#[pymethods]
+impl TestAddressAllocator {
+    #[new]
+    #[pyo3(signature = (namespace = "solders"))]
+    pub fn new(namespace: &str) -> PyResult<Self> {
+        if namespace.contains(':') {
+            return Err(to_py_value_err(&format!(
+                "namespace {namespace:?} can't contain \":\""
+            )));
+        }
+        Ok(Self {
+            namespace: namespace.to_owned(),
+            ..Default::default()
+        })
+    }
+
+    /// Stops pytest from collecting the class as a test suite.
+    #[classattr]
+    fn __test__() -> bool {
+        false
+    }
+
+    /// str: Separates the addresses of unrelated test suites.
+    #[getter]
+    pub fn namespace(&self) -> String {
+        self.namespace.clone()
+    }
+
+    /// The address of a label, allocating it on first use.
+    ///
+    /// Args:
+    ///     label (str): A readable name, e.g. ``"alice"`` or ``"pool-vault"``.
+    ///
+    /// Returns:
+    ///     Pubkey: The address.
+    ///
+    /// Raises:
+    ///     ValueError: If ``label`` is empty, or its derived address already has another label.
+    ///
+    pub fn pubkey(&mut self, label: &str) -> PyResult<Pubkey> {
+        if let Some((address, _)) = self.labels.get(label) {
+            return Ok((*address).into());
+        }
+        let address = self.derive(label).pubkey();
+        self.insert(label, address, true)?;
+        Ok(address.into())
+    }
+
+    /// The keypair of a label, allocating it on first use.
+    ///
+    /// Args:
+    ///     label (str): A readable name.
+    ///
+    /// Returns:
+    ///     Keypair: The keypair, whose pubkey is ``pubkey(label)``.
+    ///
+    /// Raises:
+    ///     ValueError: If ``label`` was registered with a fixed address, which has no
+    ///         known keypair, or :meth:`pubkey` raises.
+    ///
+    pub fn keypair(&mut self, label: &str) -> PyResult<Keypair> {
+        if let Some((address, false)) = self.labels.get(label) {
+            return Err(to_py_value_err(&format!(
+                "{label:?} is the registered address {address}, which has no keypair"
+            )));
+        }
+        self.pubkey(label)?;
+        Ok(Keypair(self.derive(label)))
+    }
+
+    /// Label a fixed address, such as a real mint or program.
+    ///
+    /// Args:
+    ///     label (str): A readable name.
+    ///     address (Pubkey): The address.
+    ///
+    /// Returns:
+    ///     Pubkey: ``address``, for chaining.
+    ///
+    /// Raises:
+    ///     ValueError: If ``label`` is empty or already has another address, or
+    ///         ``address`` already has another label.
+    ///
+    pub fn register(&mut self, label: &str, address: Pubkey) -> PyResult<Pubkey> {
+        self.insert(label, address.into(), false)?;
+        Ok(address)
+    }
+
+    /// The label of an address.
+    ///
+    /// Args:
+    ///     address (Pubkey): The address.
+    ///
+    /// Returns:
+    ///     Optional[str]: The label, or ``None`` if the address wasn't allocated or registered.
+    ///
+    pub fn label_of(&self, address: Pubkey) -> Option<String> {
+        self.by_address.get(&PubkeyOriginal::from(address)).cloned()
+    }
+
+    /// Replace every labeled address in ``text`` with its label.
+    ///
+    /// Useful for readable assertion messages, logs and snapshots.
+    ///
+    /// Args:
+    ///     text (str): The text, e.g. a transaction's ``repr`` or program logs.
+    ///
+    /// Returns:
+    ///     str: The text with labels in place of addresses.
+    ///
+    pub fn annotate(&self, text: &str) -> String {
+        let mut out = String::with_capacity(text.len());
+        let mut word = String::new();
+        let flush = |word: &mut String, out: &mut String| {
+            let label = word
+                .parse::<PubkeyOriginal>()
+                .ok()
+                .and_then(|address| self.by_address.get(&address));
+            out.push_str(label.map_or(word.as_str(), String::as_str));
+            word.clear();
+        };
+        for c in text.chars() {
+            if c.is_ascii_alphanumeric() {
+                word.push(c);
+            } else {
+                flush(&mut word, &mut out);
+                out.push(c);
+            }
+        }
+        flush(&mut word, &mut out);
+        out
+    }
+
+    /// Every label and its address.
+    ///
+    /// Returns:
+    ///     Dict[str, Pubkey]: The labels, for printing or checking into a fixture manifest.
+    ///
+    pub fn manifest(&self) -> BTreeMap<String, Pubkey> {
+        self.labels
+            .iter()
+            .map(|(label, (address, _))| (label.clone(), (*address).into()))
+            .collect()
+    }
+
+    /// The manifest as JSON, with base58 addresses and sorted labels.
+    ///
+    /// Returns:
+    ///     str: A JSON object of labels to addresses.
+    ///
+    pub fn manifest_json(&self) -> String {
+        let manifest: BTreeMap<&str, String> = self
+            .labels
+            .iter()
+            .map(|(label, (address, _))| (label.as_str(), address.to_string()))
+            .collect();
+        serde_json::to_string_pretty(&manifest).unwrap()
+    }
+
+    fn __len__(&self) -> usize {
+        self.labels.len()
+    }
+
+    fn __contains__(&self, label: &str) -> bool {
+        self.labels.contains_key(label)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "TestAddressAllocator(namespace={:?}, labels={})",
+            self.namespace,
+            self.labels.len()
+        )
+    }
+}
+
+pub(crate) fn create_fixtures_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "fixtures")?;
+    m.add_class::<TestAddressAllocator>()?;
+    Ok(m)
+}
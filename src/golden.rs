@@ -0,0 +1,240 @@
+use std::collections::BTreeMap;
+
+use pyo3::{prelude::*, types::PyBytes};
+use serde::{Deserialize, Serialize};
+use solana_sdk::{
+    account::Account as AccountOriginal,
+    address_lookup_table_account::AddressLookupTableAccount as AddressLookupTableAccountOriginal,
+    clock::Clock as ClockOriginal,
+    epoch_schedule::EpochSchedule as EpochScheduleOriginal,
+    hash::Hash as HashOriginal,
+    instruction::{AccountMeta as AccountMetaOriginal, Instruction as InstructionOriginal},
+    message::{v0, Message as MessageOriginal, VersionedMessage as VersionedMessageOriginal},
+    pubkey::Pubkey as PubkeyOriginal,
+    rent::Rent as RentOriginal,
+    signer::{keypair::keypair_from_seed, Signer},
+    transaction::{
+        Transaction as TransactionOriginal, VersionedTransaction as VersionedTransactionOriginal,
+    },
+};
+use solders_primitives::{
+    address_lookup_table_account::AddressLookupTableAccount,
+    hash::Hash as SolderHash,
+    instruction::{AccountMeta, Instruction},
+    keypair::Keypair,
+    message::{Message, MessageV0},
+    pubkey::Pubkey,
+    signature::Signature,
+    transaction::{Transaction, VersionedTransaction},
+};
+use solders_traits::to_py_value_err;
+
+use crate::{account::Account, clock::Clock, epoch_schedule::EpochSchedule, rent::Rent};
+
+/// One golden vector: a value built from fixed inputs and its serialized forms.
+#[pyclass(module = "solders.golden", subclass)]
+#[derive(Clone, Debug)]
+pub struct GoldenVector {
+    /// str: The name of the vector, e.g. ``"versioned_transaction"``.
+    #[pyo3(get)]
+    pub name: String,
+    /// Any: The value.
+    #[pyo3(get)]
+    pub value: PyObject,
+    serialized: Vec<u8>,
+    /// str: ``value.to_json()``.
+    #[pyo3(get)]
+    pub json: String,
+}
+
+#[pymethods]
+impl GoldenVector {
+    /// bytes: ``bytes(value)``.
+    #[getter]
+    pub fn serialized<'a>(&self, py: Python<'a>) -> &'a PyBytes {
+        PyBytes::new(py, &self.serialized)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("GoldenVector(name={:?}, json={:?})", self.name, self.json)
+    }
+}
+
+/// The serialized forms of a vector, as stored by :func:`dump_golden_vectors`.
+#[derive(Serialize, Deserialize, PartialEq, Eq)]
+struct StoredVector {
+    bytes: String,
+    json: String,
+}
+
+fn vector(py: Python<'_>, name: &str, value: PyObject) -> PyResult<GoldenVector> {
+    let serialized = value
+        .call_method0(py, "__bytes__")?
+        .extract::<&PyBytes>(py)?
+        .as_bytes()
+        .to_vec();
+    let json = value.call_method0(py, "to_json")?.extract(py)?;
+    Ok(GoldenVector {
+        name: name.to_owned(),
+        value,
+        serialized,
+        json,
+    })
+}
+
+/// Build every golden vector.
+///
+/// The inputs never change, so the serialized forms only change if solders
+/// changes how it serializes a type. Store :func:`dump_golden_vectors` in your
+/// test suite and check it with :func:`diff_golden_vectors` after upgrading.
+///
+/// Returns:
+///     List[GoldenVector]: The vectors, sorted by name.
+///
+#[pyfunction]
+pub fn golden_vectors(py: Python<'_>) -> PyResult<Vec<GoldenVector>> {
+    let keypair = keypair_from_seed(&[3; 32]).unwrap();
+    let payer = keypair.pubkey();
+    let program_id = PubkeyOriginal::new_from_array([4; 32]);
+    let blockhash = HashOriginal::new_from_array([2; 32]);
+    let meta = AccountMetaOriginal::new(PubkeyOriginal::new_from_array([1; 32]), false);
+    let lookup_table = AddressLookupTableAccountOriginal {
+        key: PubkeyOriginal::new_from_array([5; 32]),
+        addresses: vec![PubkeyOriginal::new_from_array([6; 32])],
+    };
+    let instruction = InstructionOriginal::new_with_bytes(
+        program_id,
+        &[1, 2, 3],
+        vec![
+            meta.clone(),
+            AccountMetaOriginal::new_readonly(lookup_table.addresses[0], false),
+        ],
+    );
+    let message =
+        MessageOriginal::new_with_blockhash(&[instruction.clone()], Some(&payer), &blockhash);
+    let message_v0 = v0::Message::try_compile(
+        &payer,
+        &[instruction.clone()],
+        &[lookup_table.clone()],
+        blockhash,
+    )
+    .map_err(|e| to_py_value_err(&e))?;
+    let transaction = TransactionOriginal::new(&[&keypair], message.clone(), blockhash);
+    let versioned_transaction = VersionedTransactionOriginal::try_new(
+        VersionedMessageOriginal::V0(message_v0.clone()),
+        &[&keypair],
+    )
+    .map_err(|e| to_py_value_err(&e))?;
+    let account = AccountOriginal {
+        lamports: 1_000_000,
+        data: (0..8).collect(),
+        owner: program_id,
+        executable: false,
+        rent_epoch: 300,
+    };
+    let clock = ClockOriginal {
+        slot: 100,
+        epoch_start_timestamp: 1_600_000_000,
+        epoch: 2,
+        leader_schedule_epoch: 3,
+        unix_timestamp: 1_600_000_100,
+    };
+    let signature = keypair.sign_message(b"solders");
+    let values: Vec<(&str, PyObject)> = vec![
+        ("account", Account::from(account).into_py(py)),
+        ("account_meta", AccountMeta::from(meta).into_py(py)),
+        (
+            "address_lookup_table_account",
+            AddressLookupTableAccount::from(lookup_table).into_py(py),
+        ),
+        ("clock", Clock::from(clock).into_py(py)),
+        (
+            "epoch_schedule",
+            EpochSchedule::from(EpochScheduleOriginal::default()).into_py(py),
+        ),
+        ("hash", SolderHash::from(blockhash).into_py(py)),
+        ("instruction", Instruction::from(instruction).into_py(py)),
+        ("keypair", Keypair::from(keypair).into_py(py)),
+        ("message", Message::from(message).into_py(py)),
+        ("message_v0", MessageV0::from(message_v0).into_py(py)),
+        ("pubkey", Pubkey::from(payer).into_py(py)),
+        ("rent", Rent::from(RentOriginal::default()).into_py(py)),
+        ("signature", Signature::from(signature).into_py(py)),
+        ("transaction", Transaction::from(transaction).into_py(py)),
+        (
+            "versioned_transaction",
+            VersionedTransaction::from(versioned_transaction).into_py(py),
+        ),
+    ];
+    values
+        .into_iter()
+        .map(|(name, value)| vector(py, name, value))
+        .collect()
+}
+
+/// Serialize the golden vectors for storing in a test suite.
+///
+/// Returns:
+///     str: A JSON object mapping each vector name to its base64 bytes and its JSON.
+///
+/// Example:
+///     >>> from solders.golden import dump_golden_vectors, diff_golden_vectors
+///     >>> diff_golden_vectors(dump_golden_vectors())
+///     []
+///
+#[pyfunction]
+pub fn dump_golden_vectors(py: Python<'_>) -> PyResult<String> {
+    let stored: BTreeMap<String, StoredVector> = golden_vectors(py)?
+        .into_iter()
+        .map(|v| {
+            let stored = StoredVector {
+                bytes: base64::encode(&v.serialized),
+                json: v.json,
+            };
+            (v.name, stored)
+        })
+        .collect();
+    Ok(serde_json::to_string_pretty(&stored).unwrap())
+}
+
+/// Compare stored golden vectors with the current ones.
+///
+/// Vectors added since ``previous`` was dumped are not reported, since new
+/// vectors don't break compatibility.
+///
+/// Args:
+///     previous (str): The output of :func:`dump_golden_vectors` from an earlier version.
+///
+/// Returns:
+///     List[str]: The names of the stored vectors that are missing or serialize differently now.
+///
+/// Raises:
+///     ValueError: If ``previous`` isn't a dump of golden vectors.
+///
+#[pyfunction]
+pub fn diff_golden_vectors(py: Python<'_>, previous: &str) -> PyResult<Vec<String>> {
+    let previous: BTreeMap<String, StoredVector> =
+        serde_json::from_str(previous).map_err(|e| to_py_value_err(&e))?;
+    let current: BTreeMap<String, GoldenVector> = golden_vectors(py)?
+        .into_iter()
+        .map(|v| (v.name.clone(), v))
+        .collect();
+    Ok(previous
+        .into_iter()
+        .filter(|(name, stored)| {
+            current.get(name).map_or(true, |v| {
+                base64::encode(&v.serialized) != stored.bytes || v.json != stored.json
+            })
+        })
+        .map(|(name, _)| name)
+        .collect())
+}
+
+pub(crate) fn create_golden_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "golden")?;
+    m.add_class::<GoldenVector>()?;
+    m.add_function(wrap_pyfunction!(golden_vectors, m)?)?;
+    m.add_function(wrap_pyfunction!(dump_golden_vectors, m)?)?;
+    m.add_function(wrap_pyfunction!(diff_golden_vectors, m)?)?;
+    Ok(m)
+}
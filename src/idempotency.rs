@@ -0,0 +1,224 @@
+use pyo3::prelude::*;
+use solana_sdk::{
+    hash::hashv,
+    instruction::{AccountMeta as AccountMetaOriginal, Instruction as InstructionOriginal},
+};
+use solders_primitives::{instruction::Instruction, keypair::Keypair, pubkey::Pubkey};
+use solders_traits::to_py_value_err;
+
+use crate::{
+    program_ids::MEMO_PROGRAM_ID, rpc::responses::RpcConfirmedTransactionStatusWithSignature,
+    transaction_status::EncodedConfirmedTransactionWithStatusMeta,
+};
+
+/// Prepended to the idempotency key in tag memos.
+pub const IDEMPOTENCY_MEMO_PREFIX: &str = "idempotency:";
+
+/// Domain separator for tag keypair derivation.
+const TAG_KEYPAIR_DOMAIN: &[u8] = b"solders-idempotency-tag";
+
+/// The shortest secret accepted by :func:`tag_keypair`.
+const MIN_SECRET_LEN: usize = 32;
+
+fn check_key(key: &str) -> PyResult<()> {
+    if key.is_empty() {
+        Err(to_py_value_err(&"idempotency key is empty"))
+    } else {
+        Ok(())
+    }
+}
+
+/// Split the ``memo`` field of ``getSignaturesForAddress`` into the individual memos.
+///
+/// The node formats each memo as ``[<byte length>] <memo>`` and joins them with ``"; "``,
+/// so the length prefix is used rather than splitting on the separator.
+fn split_rpc_memos(field: &str) -> Vec<&str> {
+    let mut memos = Vec::new();
+    let mut rest = field;
+    while let Some((memo, tail)) = next_rpc_memo(rest) {
+        memos.push(memo);
+        rest = tail.strip_prefix("; ").unwrap_or("");
+    }
+    memos
+}
+
+fn next_rpc_memo(field: &str) -> Option<(&str, &str)> {
+    let (len, tail) = field.strip_prefix('[')?.split_once("] ")?;
+    let len: usize = len.parse().ok()?;
+    Some((tail.get(..len)?, tail.get(len..)?))
+}
+
+/// A memo instruction that tags a transaction with an idempotency key.
+///
+/// Add it to every attempt at the same logical operation, e.g. a payout, so the
+/// transaction can be found by key with :func:`find_tagged` before retrying.
+///
+/// Args:
+///     key (str): The client-generated idempotency key.
+///     signer (Optional[Pubkey]): An extra signer to attach, such as a :func:`tag_keypair`
+///         pubkey. The memo program requires it to sign the transaction.
+///
+/// Returns:
+///     Instruction: The memo instruction.
+///
+/// Raises:
+///     ValueError: If ``key`` is empty.
+///
+/// Example:
+///     >>> from solders.idempotency import idempotency_memo
+///     >>> bytes(idempotency_memo("payout-42").data)
+///     b'idempotency:payout-42'
+///
+#[pyfunction]
+#[pyo3(signature = (key, signer = None))]
+pub fn idempotency_memo(key: &str, signer: Option<Pubkey>) -> PyResult<Instruction> {
+    check_key(key)?;
+    let accounts = signer
+        .map(|signer| vec![AccountMetaOriginal::new_readonly(signer.into(), true)])
+        .unwrap_or_default();
+    Ok(InstructionOriginal::new_with_bytes(
+        MEMO_PROGRAM_ID,
+        format!("{}{}", IDEMPOTENCY_MEMO_PREFIX, key).as_bytes(),
+        accounts,
+    )
+    .into())
+}
+
+/// Derive a keypair that is unique to an idempotency key.
+///
+/// Signing a transaction with the tag keypair makes its pubkey appear in the
+/// transaction, so ``getSignaturesForAddress`` on that pubkey returns only the
+/// transactions for this key. The derivation is deterministic, so a restarted
+/// process derives the same keypair from the same secret and key. Each input is
+/// length-prefixed before hashing, so no two secret and key pairs share a seed.
+///
+/// Args:
+///     secret (bytes): A private secret of at least 32 bytes, kept by the service.
+///     key (str): The client-generated idempotency key.
+///
+/// Returns:
+///     Keypair: The tag keypair.
+///
+/// Raises:
+///     ValueError: If ``secret`` is shorter than 32 bytes or ``key`` is empty.
+///
+#[pyfunction]
+pub fn tag_keypair(secret: &[u8], key: &str) -> PyResult<Keypair> {
+    if secret.len() < MIN_SECRET_LEN {
+        return Err(to_py_value_err(&format!(
+            "secret must be at least {} bytes, got {}",
+            MIN_SECRET_LEN,
+            secret.len()
+        )));
+    }
+    check_key(key)?;
+    let parts = [TAG_KEYPAIR_DOMAIN, secret, key.as_bytes()];
+    let lens: Vec<[u8; 8]> = parts
+        .iter()
+        .map(|part| (part.len() as u64).to_le_bytes())
+        .collect();
+    let prefixed: Vec<&[u8]> = lens
+        .iter()
+        .zip(parts)
+        .flat_map(|(len, part)| [&len[..], part])
+        .collect();
+    let seed = hashv(&prefixed);
+    Keypair::from_seed(seed.to_bytes())
+}
+
+/// Find the transactions that claim an idempotency key in a ``getSignaturesForAddress`` page.
+///
+/// Query the tag keypair's pubkey, or the fee payer if only the memo is used, and
+/// pass the returned statuses here. Failed transactions are skipped because they
+/// had no effect, so a retry is still needed.
+///
+/// Anyone can send a transaction that mentions the queried address with the same
+/// memo, so a candidate doesn't prove the operation happened. Fetch each candidate
+/// with ``getTransaction`` and check it with :func:`verify_tagged` before skipping a retry.
+///
+/// Args:
+///     statuses (Sequence[RpcConfirmedTransactionStatusWithSignature]): The signatures
+///         returned by ``getSignaturesForAddress``.
+///     key (str): The idempotency key.
+///
+/// Returns:
+///     List[RpcConfirmedTransactionStatusWithSignature]: The successful transactions
+///     carrying the key's memo, in the order given.
+///
+/// Raises:
+///     ValueError: If ``key`` is empty.
+///
+/// Example:
+///     >>> from solders.idempotency import find_tagged
+///     >>> from solders.rpc.responses import RpcConfirmedTransactionStatusWithSignature
+///     >>> from solders.signature import Signature
+///     >>> status = RpcConfirmedTransactionStatusWithSignature(Signature.default(), 1, None, "[21] idempotency:payout-42", None, None)
+///     >>> find_tagged([status], "payout-42") == [status]
+///     True
+///     >>> find_tagged([status], "payout-4")
+///     []
+///
+#[pyfunction]
+pub fn find_tagged(
+    statuses: Vec<RpcConfirmedTransactionStatusWithSignature>,
+    key: &str,
+) -> PyResult<Vec<RpcConfirmedTransactionStatusWithSignature>> {
+    check_key(key)?;
+    let expected = format!("{}{}", IDEMPOTENCY_MEMO_PREFIX, key);
+    Ok(statuses
+        .into_iter()
+        .filter(|status| {
+            status.err().is_none()
+                && status.memo().map_or(false, |memo| {
+                    split_rpc_memos(&memo).contains(&expected.as_str())
+                })
+        })
+        .collect())
+}
+
+/// Check that a fetched transaction is the tagged operation and not an impostor.
+///
+/// The transaction must have succeeded, carry the key's memo and be signed by
+/// ``signer``: the fee payer that sends the operation, or the :func:`tag_keypair`
+/// pubkey. Only the holder of that keypair can produce such a transaction.
+///
+/// Fetch the transaction with ``json`` or ``base64`` encoding. A ``jsonParsed`` memo
+/// has no raw data and is not recognized.
+///
+/// Args:
+///     transaction (EncodedConfirmedTransactionWithStatusMeta): The ``getTransaction``
+///         result for a :func:`find_tagged` candidate.
+///     key (str): The idempotency key.
+///     signer (Pubkey): The pubkey that must have signed the operation.
+///
+/// Returns:
+///     bool: Whether the transaction is the tagged operation.
+///
+/// Raises:
+///     ValueError: If ``key`` is empty.
+///
+#[pyfunction]
+pub fn verify_tagged(
+    transaction: EncodedConfirmedTransactionWithStatusMeta,
+    key: &str,
+    signer: Pubkey,
+) -> PyResult<bool> {
+    check_key(key)?;
+    let expected = format!("{}{}", IDEMPOTENCY_MEMO_PREFIX, key);
+    let tx = transaction.transaction();
+    let succeeded = tx.meta().map_or(false, |meta| meta.err().is_none());
+    let has_memo = tx.instruction_data().into_iter().any(|(program_id, data)| {
+        program_id.0 == MEMO_PROGRAM_ID && data.as_deref() == Some(expected.as_bytes())
+    });
+    Ok(succeeded && has_memo && tx.signer_keys().contains(&signer))
+}
+
+pub(crate) fn create_idempotency_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "idempotency")?;
+    m.add("IDEMPOTENCY_MEMO_PREFIX", IDEMPOTENCY_MEMO_PREFIX)?;
+    m.add_function(wrap_pyfunction!(idempotency_memo, m)?)?;
+    m.add_function(wrap_pyfunction!(tag_keypair, m)?)?;
+    m.add_function(wrap_pyfunction!(find_tagged, m)?)?;
+    m.add_function(wrap_pyfunction!(verify_tagged, m)?)?;
+    Ok(m)
+}
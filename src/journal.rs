@@ -0,0 +1,385 @@
+use std::collections::HashSet;
+
+use pyo3::prelude::*;
+use solana_sdk::{account::Account as AccountOriginal, pubkey::Pubkey as PubkeyOriginal};
+use solders_macros::richcmp_eq_only;
+use solders_primitives::{
+    address_lookup_table_account::AddressLookupTableAccount, pubkey::Pubkey, signature::Signature,
+    transaction::VersionedTransaction,
+};
+use solders_traits::RichcmpEqualityOnly;
+
+use crate::{
+    account::Account,
+    accounts_map::AccountsMap,
+    log::{self, LogLevel},
+    metrics,
+    simulation::{RuntimeParams, DEFAULT_LAMPORTS_PER_SIGNATURE},
+    transaction_status::TransactionErrorType,
+};
+
+fn original(account: &Option<Account>) -> Option<&AccountOriginal> {
+    account.as_ref().map(AsRef::as_ref)
+}
+
+fn lamports(account: &Option<Account>) -> i64 {
+    original(account).map_or(0, |a| a.lamports as i64)
+}
+
+fn owner(account: &Option<Account>) -> Option<PubkeyOriginal> {
+    original(account).map(|a| a.owner)
+}
+
+/// One account's state before and after a transaction.
+///
+/// Args:
+///     pubkey (Pubkey): The account address.
+///     before (Optional[Account]): The account before the transaction, or ``None`` if it didn't exist.
+///     after (Optional[Account]): The account after the transaction, or ``None`` if it was closed.
+///
+#[pyclass(module = "solders.journal", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccountChange {
+    /// Pubkey: The account address.
+    #[pyo3(get)]
+    pub pubkey: Pubkey,
+    /// Optional[Account]: The account before the transaction.
+    #[pyo3(get)]
+    pub before: Option<Account>,
+    /// Optional[Account]: The account after the transaction.
+    #[pyo3(get)]
+    pub after: Option<Account>,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl AccountChange {
+    #[new]
+    pub fn new(pubkey: Pubkey, before: Option<Account>, after: Option<Account>) -> Self {
+        Self {
+            pubkey,
+            before,
+            after,
+        }
+    }
+
+    /// int: The change in lamports. Negative if the account lost lamports.
+    #[getter]
+    pub fn lamport_delta(&self) -> i64 {
+        lamports(&self.after) - lamports(&self.before)
+    }
+
+    /// bool: Whether the account didn't exist before the transaction.
+    #[getter]
+    pub fn created(&self) -> bool {
+        self.before.is_none() && self.after.is_some()
+    }
+
+    /// bool: Whether the account was closed by the transaction.
+    #[getter]
+    pub fn closed(&self) -> bool {
+        self.before.is_some() && self.after.is_none()
+    }
+
+    /// bool: Whether the account data changed.
+    #[getter]
+    pub fn data_changed(&self) -> bool {
+        let data = |account: &Option<Account>| original(account).map(|a| a.data.as_slice());
+        data(&self.before).unwrap_or_default() != data(&self.after).unwrap_or_default()
+    }
+
+    /// bool: Whether the account owner changed.
+    #[getter]
+    pub fn owner_changed(&self) -> bool {
+        self.before.is_some() && self.after.is_some() && owner(&self.before) != owner(&self.after)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for AccountChange {}
+
+impl AccountChange {
+    fn is_owned_by(&self, programs: &HashSet<PubkeyOriginal>) -> bool {
+        [owner(&self.before), owner(&self.after)]
+            .iter()
+            .flatten()
+            .any(|owner| programs.contains(owner))
+    }
+}
+
+/// The account changes made by one transaction in an :class:`AccountJournal`.
+#[pyclass(module = "solders.journal", subclass)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct JournalEntry {
+    /// Signature: The transaction's first signature.
+    #[pyo3(get)]
+    pub signature: Signature,
+    /// Optional[TransactionErrorType]: The error the transaction failed with, if any.
+    #[pyo3(get)]
+    pub err: Option<TransactionErrorType>,
+    /// int: The fee charged.
+    #[pyo3(get)]
+    pub fee: u64,
+    /// List[AccountChange]: The recorded changes, sorted by address.
+    #[pyo3(get)]
+    pub changes: Vec<AccountChange>,
+    /// List[int]: Indexes of instructions that were not executed because their program
+    /// isn't simulated. Their writes are missing from :attr:`changes`.
+    #[pyo3(get)]
+    pub unsimulated_instructions: Vec<usize>,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl JournalEntry {
+    #[new]
+    #[pyo3(signature = (signature, err, fee, changes, unsimulated_instructions = vec![]))]
+    pub fn new(
+        signature: Signature,
+        err: Option<TransactionErrorType>,
+        fee: u64,
+        changes: Vec<AccountChange>,
+        unsimulated_instructions: Vec<usize>,
+    ) -> Self {
+        Self {
+            signature,
+            err,
+            fee,
+            changes,
+            unsimulated_instructions,
+        }
+    }
+
+    /// bool: Whether every instruction was executed, so :attr:`changes` is complete.
+    #[getter]
+    pub fn fully_simulated(&self) -> bool {
+        self.unsimulated_instructions.is_empty()
+    }
+
+    /// Look up the change to one account.
+    ///
+    /// Args:
+    ///     pubkey (Pubkey): The account address.
+    ///
+    /// Returns:
+    ///     Optional[AccountChange]: The change, or ``None`` if the account wasn't recorded.
+    ///
+    pub fn change(&self, pubkey: Pubkey) -> Option<AccountChange> {
+        self.changes.iter().find(|c| c.pubkey == pubkey).cloned()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for JournalEntry {}
+
+/// Processes transactions against local account state and records every account they change.
+///
+/// Each transaction is run with :func:`~solders.simulation.simulate_locally` and its
+/// writes, including the fee of a failed transaction, are applied before the next one.
+/// Tests can then assert exact state transitions instead of fetching accounts
+/// before and after.
+///
+/// This is not a test bank: there is no program runtime, so only System program
+/// instructions are executed. Instructions to any other program are skipped and listed
+/// in :attr:`JournalEntry.unsimulated_instructions`, and their account writes are not
+/// recorded. Check :attr:`JournalEntry.fully_simulated` before trusting an entry's changes.
+///
+/// Args:
+///     accounts (AccountsMap): The starting state. It is not modified.
+///     programs (Optional[Sequence[Pubkey]]): Only record accounts owned by these programs,
+///         before or after the transaction. ``None`` records every account.
+///     params (Optional[RuntimeParams]): The runtime parameters. Defaults to ``RuntimeParams()``.
+///
+/// Example:
+///     >>> from solders.journal import AccountJournal
+///     >>> from solders.accounts_map import AccountsMap
+///     >>> from solders.account import Account
+///     >>> from solders.keypair import Keypair
+///     >>> from solders.message import Message
+///     >>> from solders.pubkey import Pubkey
+///     >>> from solders.system_program import ID, transfer, TransferParams
+///     >>> from solders.transaction import VersionedTransaction
+///     >>> payer, to = Keypair(), Pubkey.new_unique()
+///     >>> ix = transfer(TransferParams(from_pubkey=payer.pubkey(), to_pubkey=to, lamports=1_000_000))
+///     >>> tx = VersionedTransaction(Message([ix], payer.pubkey()), [payer])
+///     >>> journal = AccountJournal(AccountsMap({payer.pubkey(): Account(2_000_000, b"", ID)}))
+///     >>> entry = journal.process(tx)
+///     >>> entry.change(payer.pubkey()).lamport_delta, entry.change(to).lamport_delta
+///     (-1005000, 1000000)
+///     >>> entry.change(to).created
+///     True
+///
+#[pyclass(module = "solders.journal", subclass)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccountJournal {
+    accounts: AccountsMap,
+    programs: Option<Vec<Pubkey>>,
+    params: RuntimeParams,
+    entries: Vec<JournalEntry>,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl AccountJournal {
+    #[new]
+    #[pyo3(signature = (accounts, programs = None, params = None))]
+    pub fn new(
+        accounts: &AccountsMap,
+        programs: Option<Vec<Pubkey>>,
+        params: Option<RuntimeParams>,
+    ) -> Self {
+        Self {
+            accounts: accounts.fork(),
+            programs,
+            params: params
+                .unwrap_or_else(|| RuntimeParams::new(None, DEFAULT_LAMPORTS_PER_SIGNATURE, None)),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Process a transaction and record its account changes.
+    ///
+    /// Args:
+    ///     tx (VersionedTransaction): The transaction. Signatures are not verified.
+    ///     address_lookup_table_accounts (Optional[Sequence[AddressLookupTableAccount]]):
+    ///         The lookup tables referenced by a versioned message.
+    ///
+    /// Returns:
+    ///     JournalEntry: The recorded entry, which is also appended to :attr:`entries`.
+    ///
+    #[pyo3(signature = (tx, address_lookup_table_accounts = None))]
+    pub fn process(
+        &mut self,
+        py: Python<'_>,
+        tx: VersionedTransaction,
+        address_lookup_table_accounts: Option<Vec<AddressLookupTableAccount>>,
+    ) -> PyResult<JournalEntry> {
+        let signature = tx.signatures().into_iter().next().unwrap_or_default();
+        let result = self
+            .params
+            .simulate(tx, &self.accounts, address_lookup_table_accounts);
+        let programs: Option<HashSet<PubkeyOriginal>> = self
+            .programs
+            .as_ref()
+            .map(|p| p.iter().map(PubkeyOriginal::from).collect());
+        let mut changes: Vec<AccountChange> = result
+            .accounts
+            .pending_original()
+            .iter()
+            .map(|(pubkey, after)| AccountChange {
+                pubkey: (*pubkey).into(),
+                before: self
+                    .accounts
+                    .get_original(pubkey)
+                    .cloned()
+                    .map(Account::from),
+                after: after.clone().map(Account::from),
+            })
+            .filter(|change| change.before != change.after)
+            .filter(|change| programs.as_ref().map_or(true, |p| change.is_owned_by(p)))
+            .collect();
+        changes.sort_unstable_by_key(|change| change.pubkey);
+        let mut accounts = result.accounts;
+        accounts.commit();
+        self.accounts = accounts;
+        let entry = JournalEntry {
+            signature,
+            err: result.err,
+            fee: result.fee,
+            changes,
+            unsimulated_instructions: result.unsimulated_instructions,
+        };
+        metrics::inc(&metrics::JOURNAL_TRANSACTIONS, 1);
+        if entry.err.is_some() {
+            metrics::inc(&metrics::JOURNAL_FAILED, 1);
+        }
+        let outcome = match &entry.err {
+            Some(err) => format!("failed: {err:?}"),
+            None => "succeeded".to_owned(),
+        };
+        log::emit(
+            py,
+            LogLevel::Debug,
+            "solders.journal",
+            format!("transaction {signature} {outcome}"),
+            &[
+                ("signature", signature.to_string()),
+                ("fee", entry.fee.to_string()),
+                ("changes", entry.changes.len().to_string()),
+            ],
+        );
+        self.entries.push(entry.clone());
+        Ok(entry)
+    }
+
+    /// AccountsMap: The current account state.
+    #[getter]
+    pub fn accounts(&self) -> AccountsMap {
+        self.accounts.clone()
+    }
+
+    /// List[JournalEntry]: One entry per processed transaction, in order.
+    #[getter]
+    pub fn entries(&self) -> Vec<JournalEntry> {
+        self.entries.clone()
+    }
+
+    /// Every recorded change to one account, in processing order.
+    ///
+    /// Args:
+    ///     pubkey (Pubkey): The account address.
+    ///
+    /// Returns:
+    ///     List[AccountChange]: The changes.
+    ///
+    pub fn history(&self, pubkey: Pubkey) -> Vec<AccountChange> {
+        self.entries
+            .iter()
+            .filter_map(|entry| entry.change(pubkey))
+            .collect()
+    }
+
+    /// The net lamport change of one account over all processed transactions.
+    ///
+    /// Args:
+    ///     pubkey (Pubkey): The account address.
+    ///
+    /// Returns:
+    ///     int: The sum of the recorded lamport deltas.
+    ///
+    pub fn net_lamport_delta(&self, pubkey: Pubkey) -> i64 {
+        self.history(pubkey)
+            .iter()
+            .map(AccountChange::lamport_delta)
+            .sum()
+    }
+
+    /// Forget the recorded entries, keeping the current account state.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    fn __len__(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for AccountJournal {}
+
+pub(crate) fn create_journal_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "journal")?;
+    m.add_class::<AccountChange>()?;
+    m.add_class::<JournalEntry>()?;
+    m.add_class::<AccountJournal>()?;
+    Ok(m)
+}
@@ -0,0 +1,42 @@
+/// Size of an SPL Token account without extensions.
+pub(crate) const TOKEN_ACCOUNT_LEN: usize = 165;
+/// Size of an SPL Token mint without extensions.
+pub(crate) const MINT_LEN: usize = 82;
+/// Size of an SPL Token multisig account.
+pub(crate) const MULTISIG_LEN: usize = 355;
+/// Offset of the mint in an SPL Token account.
+pub(crate) const TOKEN_ACCOUNT_MINT_OFFSET: usize = 0;
+/// Offset of the owner in an SPL Token account.
+pub(crate) const TOKEN_ACCOUNT_OWNER_OFFSET: usize = 32;
+/// Offset of the little-endian ``u64`` amount in an SPL Token account.
+pub(crate) const TOKEN_ACCOUNT_AMOUNT_OFFSET: usize = 64;
+/// Offset of the optional delegate in an SPL Token account.
+pub(crate) const TOKEN_ACCOUNT_DELEGATE_OFFSET: usize = 72;
+/// Offset of the state byte in an SPL Token account. Zero means uninitialized.
+pub(crate) const TOKEN_ACCOUNT_STATE_OFFSET: usize = 108;
+/// Offset of the optional rent-exempt reserve of a native SPL Token account.
+pub(crate) const TOKEN_ACCOUNT_IS_NATIVE_OFFSET: usize = 109;
+/// Offset of the delegated amount in an SPL Token account.
+pub(crate) const TOKEN_ACCOUNT_DELEGATED_AMOUNT_OFFSET: usize = 121;
+/// Offset of the optional close authority in an SPL Token account.
+pub(crate) const TOKEN_ACCOUNT_CLOSE_AUTHORITY_OFFSET: usize = 129;
+/// Offset of the optional mint authority in an SPL Token mint.
+pub(crate) const MINT_MINT_AUTHORITY_OFFSET: usize = 0;
+/// Offset of the little-endian ``u64`` supply in an SPL Token mint.
+pub(crate) const MINT_SUPPLY_OFFSET: usize = 36;
+/// Offset of the decimals byte in an SPL Token mint.
+pub(crate) const MINT_DECIMALS_OFFSET: usize = 44;
+/// Offset of the ``is_initialized`` flag in an SPL Token mint.
+pub(crate) const MINT_IS_INITIALIZED_OFFSET: usize = 45;
+/// Offset of the optional freeze authority in an SPL Token mint.
+pub(crate) const MINT_FREEZE_AUTHORITY_OFFSET: usize = 46;
+/// The Token-2022 account type byte that follows the base state of an extended mint.
+pub(crate) const TOKEN_2022_ACCOUNT_TYPE_MINT: u8 = 1;
+/// The Token-2022 account type byte that follows the base state of an extended account.
+pub(crate) const TOKEN_2022_ACCOUNT_TYPE_ACCOUNT: u8 = 2;
+/// Size of a stake account.
+pub(crate) const STAKE_ACCOUNT_LEN: usize = 200;
+/// Offset of the staker authority in an initialized or delegated stake account.
+pub(crate) const STAKE_AUTHORIZED_STAKER_OFFSET: usize = 12;
+/// Offset of the withdrawer authority in an initialized or delegated stake account.
+pub(crate) const STAKE_AUTHORIZED_WITHDRAWER_OFFSET: usize = 44;
@@ -0,0 +1,233 @@
+use std::collections::HashMap;
+
+use pyo3::{
+    prelude::*,
+    types::{PyBytes, PyType},
+};
+use serde_json::{error::Category, Value};
+use solders_traits::{to_py_err, to_py_value_err};
+
+use crate::rpc::responses::RPCResult;
+
+/// Fields that decide whether a transaction succeeded. Replacing them with null
+/// would turn a failure into a success, so they are never replaced.
+const PROTECTED_FIELDS: [&str; 2] = ["err", "status"];
+
+/// The most values :func:`from_json_lenient` replaces before giving up.
+pub const MAX_REPLACEMENTS: usize = 64;
+
+/// A value parsed by :func:`from_bytes_lenient` or :func:`from_json_lenient`,
+/// with what had to be tolerated to parse it.
+#[pyclass(module = "solders.lenient", subclass)]
+#[derive(Clone, Debug)]
+pub struct LenientResult {
+    /// Any: The parsed value.
+    #[pyo3(get)]
+    pub value: PyObject,
+    /// List[str]: What was ignored or replaced, in the order it was found.
+    #[pyo3(get)]
+    pub warnings: Vec<String>,
+    /// List[str]: The paths of the values replaced with null, e.g. ``result.value.0.confirmationStatus``.
+    #[pyo3(get)]
+    pub dropped: Vec<String>,
+}
+
+#[pymethods]
+impl LenientResult {
+    #[new]
+    #[pyo3(signature = (value, warnings, dropped = vec![]))]
+    pub fn new(value: PyObject, warnings: Vec<String>, dropped: Vec<String>) -> Self {
+        Self {
+            value,
+            warnings,
+            dropped,
+        }
+    }
+
+    /// bool: Whether the input parsed without anything being tolerated.
+    #[getter]
+    pub fn is_clean(&self) -> bool {
+        self.warnings.is_empty()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+/// Record the path of the node that starts or ends on each line of the pretty JSON of ``value``.
+///
+/// Pretty-printed JSON puts every array item and object member on its own line, and
+/// closing brackets on their own line, so each line belongs to exactly one node.
+fn node_lines(
+    value: &Value,
+    line: usize,
+    path: &mut Vec<String>,
+    out: &mut HashMap<usize, Vec<String>>,
+) -> usize {
+    out.insert(line, path.clone());
+    let children: Vec<(String, &Value)> = match value {
+        Value::Array(items) => items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| (i.to_string(), item))
+            .collect(),
+        Value::Object(map) => map.iter().map(|(k, v)| (k.clone(), v)).collect(),
+        _ => return line,
+    };
+    if children.is_empty() {
+        return line;
+    }
+    let mut end = line;
+    for (segment, child) in children {
+        path.push(segment);
+        end = node_lines(child, end + 1, path, out);
+        path.pop();
+    }
+    out.insert(end + 1, path.clone());
+    end + 1
+}
+
+fn json_pointer(path: &[String]) -> String {
+    path.iter()
+        .map(|segment| format!("/{}", segment.replace('~', "~0").replace('/', "~1")))
+        .collect()
+}
+
+/// Parse ``result`` and, if that fails on a value, return the value's path and the error.
+fn locate(result: &Value, parser: &str) -> Result<(), (Option<Vec<String>>, serde_json::Error)> {
+    let pretty = serde_json::to_string_pretty(result).unwrap();
+    let err = match RPCResult::parse_result(&pretty, parser) {
+        Ok(()) => return Ok(()),
+        Err(err) => err,
+    };
+    if err.classify() != Category::Data {
+        return Err((None, err));
+    }
+    let mut lines = HashMap::new();
+    node_lines(result, 1, &mut Vec::new(), &mut lines);
+    Err((lines.remove(&err.line()), err))
+}
+
+/// Deserialize bytes, reporting trailing bytes instead of silently dropping them.
+///
+/// Args:
+///     raw (bytes): The serialized value.
+///     cls: A class with ``from_bytes``, e.g. ``VersionedTransaction`` or ``Account``.
+///
+/// Returns:
+///     LenientResult: The value, with a warning if ``raw`` had bytes after it.
+///
+/// Raises:
+///     ValueError: If ``raw`` doesn't start with a valid value.
+///
+/// Example:
+///     >>> from solders.lenient import from_bytes_lenient
+///     >>> from solders.pubkey import Pubkey
+///     >>> from solders.account import Account
+///     >>> raw = bytes(Account(1, b"", Pubkey.default())) + b"junk"
+///     >>> from_bytes_lenient(raw, Account).warnings
+///     ['ignored 4 trailing bytes']
+///
+#[pyfunction]
+pub fn from_bytes_lenient(py: Python<'_>, raw: &[u8], cls: &PyType) -> PyResult<LenientResult> {
+    let value = cls.call_method1("from_bytes", (PyBytes::new(py, raw),))?;
+    let used = value
+        .call_method0("__bytes__")?
+        .downcast::<PyBytes>()?
+        .as_bytes()
+        .len();
+    let warnings = if used < raw.len() {
+        vec![format!("ignored {} trailing bytes", raw.len() - used)]
+    } else {
+        vec![]
+    };
+    Ok(LenientResult {
+        value: value.into(),
+        warnings,
+        dropped: vec![],
+    })
+}
+
+/// Parse a response JSON string, replacing optional values solders can't parse with null.
+///
+/// This is for forensics on malformed responses, or responses from newer nodes with
+/// enum variants solders doesn't know yet. Each optional value that fails to parse,
+/// such as an unknown ``confirmationStatus``, is replaced with null and reported.
+/// Required fields are never replaced, and neither are ``err`` and ``status`` or
+/// anything inside them, since a null there would read as success: such responses
+/// still raise.
+///
+/// Args:
+///     raw (str): The response JSON.
+///     parser: The response class to parse with, e.g. ``GetSignatureStatusesResp``.
+///
+/// Returns:
+///     LenientResult: The parsed response, a warning per replaced value and the replaced paths.
+///
+/// Raises:
+///     SerdeJSONError: If the JSON is malformed, or a value that can't be replaced
+///         fails to parse.
+///     ValueError: If more than 64 values would need replacing.
+///
+/// Example:
+///     >>> from solders.lenient import from_json_lenient
+///     >>> from solders.rpc.responses import GetSignatureStatusesResp
+///     >>> status = '{"slot": 72, "confirmations": 10, "err": null, "status": {"Ok": null}, "confirmationStatus": "superConfirmed"}'
+///     >>> raw = '{"jsonrpc": "2.0", "result": {"context": {"slot": 82}, "value": [%s]}, "id": 1}' % status
+///     >>> parsed = from_json_lenient(raw, GetSignatureStatusesResp)
+///     >>> parsed.value.value[0].confirmation_status is None
+///     True
+///     >>> parsed.dropped
+///     ['result.value.0.confirmationStatus']
+///
+#[pyfunction]
+pub fn from_json_lenient(py: Python<'_>, raw: &str, parser: &PyType) -> PyResult<LenientResult> {
+    let parser = parser.name()?;
+    let mut doc: Value = serde_json::from_str(raw).map_err(to_py_err)?;
+    let mut warnings = Vec::new();
+    let mut dropped = Vec::new();
+    while let Some(result) = doc.get("result") {
+        let (path, err) = match locate(result, parser) {
+            Ok(()) => break,
+            Err((Some(path), err)) => (path, err),
+            Err((None, err)) => return Err(to_py_err(err)),
+        };
+        if path
+            .iter()
+            .any(|segment| PROTECTED_FIELDS.contains(&segment.as_str()))
+        {
+            return Err(to_py_err(err));
+        }
+        let node = doc
+            .pointer_mut(&format!("/result{}", json_pointer(&path)))
+            .unwrap();
+        if path.is_empty() || node.is_null() {
+            // the failing field can't be null
+            return Err(to_py_err(err));
+        }
+        if dropped.len() == MAX_REPLACEMENTS {
+            return Err(to_py_value_err(&format!(
+                "gave up after replacing {MAX_REPLACEMENTS} values"
+            )));
+        }
+        let replaced = std::mem::replace(node, Value::Null);
+        let dotted = format!("result.{}", path.join("."));
+        warnings.push(format!("replaced {replaced} at {dotted} with null"));
+        dropped.push(dotted);
+    }
+    let value = RPCResult::from_json(&doc.to_string(), parser)?;
+    Ok(LenientResult {
+        value: value.into_py(py),
+        warnings,
+        dropped,
+    })
+}
+
+pub(crate) fn create_lenient_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "lenient")?;
+    m.add_class::<LenientResult>()?;
+    m.add_function(wrap_pyfunction!(from_bytes_lenient, m)?)?;
+    m.add_function(wrap_pyfunction!(from_json_lenient, m)?)?;
+    Ok(m)
+}
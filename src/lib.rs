@@ -6,7 +6,9 @@ use address_lookup_table_account::create_address_lookup_table_account_mod;
 use commitment_config::{CommitmentConfig, CommitmentLevel};
 use pyo3::prelude::*;
 use rpc::create_rpc_mod;
-use solders_primitives::instruction::{AccountMeta, CompiledInstruction, Instruction};
+use solders_primitives::instruction::{
+    AccountMeta, AccountMetaTemplate, CompiledInstruction, Instruction, InstructionTemplate,
+};
 use solders_traits::{BincodeError, CborError, ParseHashError, SerdeJSONError, SignerError};
 use std::collections::HashMap;
 use system_program::create_system_program_mod;
@@ -19,20 +21,94 @@ use transaction::create_transaction_mod;
 pub mod account_decoder;
 use account_decoder::create_account_decoder_mod;
 pub mod account;
+pub mod account_age;
+pub mod accounts_map;
 pub mod address_lookup_table_account;
+pub mod approval;
+pub mod audit;
+pub mod bank_hash;
+pub mod bench;
+pub mod bundle;
+pub mod clock;
+pub mod cluster;
 pub mod commitment_config;
+pub mod compression;
+pub mod compute_budget;
+pub mod cpi;
+pub mod display;
+pub mod enhanced;
+pub mod entry;
 pub mod epoch_schedule;
+pub mod fixtures;
+pub mod golden;
+pub mod idempotency;
+pub mod journal;
+mod layouts;
+pub mod lenient;
+pub mod log;
+pub mod metrics;
+pub mod outbox;
+pub mod presets;
+mod program_ids;
+pub mod pubkey_collections;
+pub mod rent;
 pub mod rpc;
+pub mod shred;
+pub mod signing;
+pub mod simulation;
+pub mod snapshot;
+pub mod sniff;
+pub mod spl_errors;
+pub mod squads;
+pub mod stake;
 pub mod system_program;
 pub mod sysvar;
 mod tmp_account_decoder;
 mod tmp_transaction_status;
 pub mod transaction_status;
+pub mod transfers;
+pub mod wallet;
+use account_age::create_account_age_mod;
+use accounts_map::create_accounts_map_mod;
+use approval::create_approval_mod;
+use audit::create_audit_mod;
+use bank_hash::create_bank_hash_mod;
+use bench::create_bench_mod;
+use bundle::create_bundle_mod;
+use clock::create_clock_mod;
+use cluster::create_cluster_mod;
+use compression::create_compression_mod;
+use compute_budget::create_compute_budget_mod;
+use cpi::create_cpi_mod;
+use display::create_display_mod;
+use enhanced::create_enhanced_mod;
+use entry::create_entry_mod;
 use epoch_schedule::create_epoch_schedule_mod;
+use fixtures::create_fixtures_mod;
+use golden::create_golden_mod;
+use idempotency::create_idempotency_mod;
+use journal::create_journal_mod;
+use lenient::create_lenient_mod;
+use log::create_log_mod;
+use metrics::create_metrics_mod;
+use outbox::create_outbox_mod;
+use presets::create_presets_mod;
+use pubkey_collections::create_pubkey_collections_mod;
+use rent::create_rent_mod;
+use shred::create_shred_mod;
+use signing::create_signing_mod;
+use simulation::create_simulation_mod;
+use snapshot::create_snapshot_mod;
+use sniff::create_sniff_mod;
 use solders_primitives::{
     hash::Hash as SolderHash, keypair::Keypair, null_signer::NullSigner, presigner::Presigner,
     pubkey::Pubkey, signature::Signature,
 };
+use spl_errors::create_spl_errors_mod;
+use squads::create_squads_mod;
+use stake::create_stake_mod;
+use transfers::create_transfers_mod;
+use wallet::create_wallet_mod;
 
 #[pymodule]
 fn solders(py: Python, m: &PyModule) -> PyResult<()> {
@@ -43,6 +119,8 @@ fn solders(py: Python, m: &PyModule) -> PyResult<()> {
     instruction_mod.add_class::<AccountMeta>()?;
     instruction_mod.add_class::<Instruction>()?;
     instruction_mod.add_class::<CompiledInstruction>()?;
+    instruction_mod.add_class::<AccountMetaTemplate>()?;
+    instruction_mod.add_class::<InstructionTemplate>()?;
     let pubkey_mod = PyModule::new(py, "pubkey")?;
     pubkey_mod.add_class::<Pubkey>()?;
     let keypair_mod = PyModule::new(py, "keypair")?;
@@ -71,6 +149,42 @@ fn solders(py: Python, m: &PyModule) -> PyResult<()> {
     let account_mod = create_account_mod(py)?;
     let epoch_schedule_mod = create_epoch_schedule_mod(py)?;
     let address_lookup_table_account_mod = create_address_lookup_table_account_mod(py)?;
+    let clock_mod = create_clock_mod(py)?;
+    let rent_mod = create_rent_mod(py)?;
+    let compute_budget_mod = create_compute_budget_mod(py)?;
+    let spl_errors_mod = create_spl_errors_mod(py)?;
+    let outbox_mod = create_outbox_mod(py)?;
+    let transfers_mod = create_transfers_mod(py)?;
+    let entry_mod = create_entry_mod(py)?;
+    let bundle_mod = create_bundle_mod(py)?;
+    let accounts_map_mod = create_accounts_map_mod(py)?;
+    let simulation_mod = create_simulation_mod(py)?;
+    let cpi_mod = create_cpi_mod(py)?;
+    let enhanced_mod = create_enhanced_mod(py)?;
+    let display_mod = create_display_mod(py)?;
+    let account_age_mod = create_account_age_mod(py)?;
+    let stake_mod = create_stake_mod(py)?;
+    let wallet_mod = create_wallet_mod(py)?;
+    let cluster_mod = create_cluster_mod(py)?;
+    let compression_mod = create_compression_mod(py)?;
+    let signing_mod = create_signing_mod(py)?;
+    let squads_mod = create_squads_mod(py)?;
+    let golden_mod = create_golden_mod(py)?;
+    let shred_mod = create_shred_mod(py)?;
+    let bank_hash_mod = create_bank_hash_mod(py)?;
+    let presets_mod = create_presets_mod(py)?;
+    let snapshot_mod = create_snapshot_mod(py)?;
+    let pubkey_collections_mod = create_pubkey_collections_mod(py)?;
+    let idempotency_mod = create_idempotency_mod(py)?;
+    let bench_mod = create_bench_mod(py)?;
+    let journal_mod = create_journal_mod(py)?;
+    let log_mod = create_log_mod(py)?;
+    let lenient_mod = create_lenient_mod(py)?;
+    let audit_mod = create_audit_mod(py)?;
+    let sniff_mod = create_sniff_mod(py)?;
+    let metrics_mod = create_metrics_mod(py)?;
+    let approval_mod = create_approval_mod(py)?;
+    let fixtures_mod = create_fixtures_mod(py)?;
     let submodules = [
         errors_mod,
         hash_mod,
@@ -91,6 +205,42 @@ fn solders(py: Python, m: &PyModule) -> PyResult<()> {
         account_mod,
         address_lookup_table_account_mod,
         epoch_schedule_mod,
+        clock_mod,
+        rent_mod,
+        compute_budget_mod,
+        spl_errors_mod,
+        outbox_mod,
+        transfers_mod,
+        entry_mod,
+        bundle_mod,
+        accounts_map_mod,
+        simulation_mod,
+        cpi_mod,
+        enhanced_mod,
+        display_mod,
+        account_age_mod,
+        stake_mod,
+        wallet_mod,
+        cluster_mod,
+        compression_mod,
+        signing_mod,
+        squads_mod,
+        golden_mod,
+        shred_mod,
+        bank_hash_mod,
+        presets_mod,
+        snapshot_mod,
+        pubkey_collections_mod,
+        idempotency_mod,
+        bench_mod,
+        journal_mod,
+        log_mod,
+        lenient_mod,
+        audit_mod,
+        sniff_mod,
+        metrics_mod,
+        approval_mod,
+        fixtures_mod,
     ];
     let modules: HashMap<String, &PyModule> = submodules
         .iter()
@@ -0,0 +1,212 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use pyo3::{prelude::*, types::IntoPyDict};
+use serde::{Deserialize, Serialize};
+use solders_macros::{enum_string, richcmp_eq_only};
+use solders_traits::{EnumString, RichcmpEqualityOnly};
+
+/// The severity of a :class:`LogEvent`. ``int(level)`` is the matching
+/// level of Python's ``logging`` module.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[pyclass(module = "solders.log")]
+pub enum LogLevel {
+    Debug = 10,
+    Info = 20,
+    Warning = 30,
+    Error = 40,
+}
+
+impl EnumString for LogLevel {}
+
+#[enum_string]
+#[pymethods]
+impl LogLevel {}
+
+/// A structured event emitted by a solders component.
+///
+/// Args:
+///     level (LogLevel): The severity.
+///     target (str): The emitting module, e.g. ``"solders.outbox"``.
+///     message (str): A human-readable description.
+///     context (Dict[str, str]): Machine-readable details, such as the signature involved.
+///
+#[pyclass(module = "solders.log", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LogEvent {
+    /// LogLevel: The severity.
+    #[pyo3(get)]
+    pub level: LogLevel,
+    /// str: The emitting module.
+    #[pyo3(get)]
+    pub target: String,
+    /// str: A human-readable description.
+    #[pyo3(get)]
+    pub message: String,
+    /// Dict[str, str]: Machine-readable details.
+    #[pyo3(get)]
+    pub context: HashMap<String, String>,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl LogEvent {
+    #[new]
+    pub fn new(
+        level: LogLevel,
+        target: String,
+        message: String,
+        context: HashMap<String, String>,
+    ) -> Self {
+        Self {
+            level,
+            target,
+            message,
+            context,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for LogEvent {}
+
+enum Sink {
+    Callback(PyObject),
+    Logging,
+}
+
+struct Handler {
+    sink: Sink,
+    min_level: LogLevel,
+}
+
+static HANDLER: Mutex<Option<Handler>> = Mutex::new(None);
+
+fn install(sink: Option<Sink>, min_level: LogLevel) {
+    *HANDLER.lock().unwrap() = sink.map(|sink| Handler { sink, min_level });
+}
+
+/// Emit an event to the installed handler, if any accepts ``level``.
+///
+/// The handler runs after the lock is released, so it may reinstall itself.
+/// Logging is best-effort: an exception raised by the handler is reported with
+/// ``sys.unraisablehook`` instead of failing the caller, whose state has usually
+/// changed already.
+pub(crate) fn emit(
+    py: Python<'_>,
+    level: LogLevel,
+    target: &str,
+    message: String,
+    context: &[(&str, String)],
+) {
+    let sink = match HANDLER.lock().unwrap().as_ref() {
+        Some(handler) if level >= handler.min_level => match &handler.sink {
+            Sink::Callback(callback) => Sink::Callback(callback.clone_ref(py)),
+            Sink::Logging => Sink::Logging,
+        },
+        _ => return,
+    };
+    let context: HashMap<String, String> = context
+        .iter()
+        .map(|(k, v)| ((*k).to_owned(), v.clone()))
+        .collect();
+    let (result, source) = match sink {
+        Sink::Callback(callback) => {
+            let event = LogEvent {
+                level,
+                target: target.to_owned(),
+                message,
+                context,
+            };
+            (callback.call1(py, (event,)).map(|_| ()), Some(callback))
+        }
+        Sink::Logging => (log_to_python(py, level, target, message, context), None),
+    };
+    if let Err(err) = result {
+        write_unraisable(py, err, source.as_ref());
+    }
+}
+
+fn log_to_python(
+    py: Python<'_>,
+    level: LogLevel,
+    target: &str,
+    message: String,
+    context: HashMap<String, String>,
+) -> PyResult<()> {
+    let logger = py.import("logging")?.call_method1("getLogger", (target,))?;
+    let extra = [("solders_context", context)].into_py_dict(py);
+    let kwargs = [("extra", extra)].into_py_dict(py);
+    logger.call_method("log", (level as u8, message), Some(kwargs))?;
+    Ok(())
+}
+
+fn write_unraisable(py: Python<'_>, err: PyErr, source: Option<&PyObject>) {
+    err.restore(py);
+    let source = source.map_or(std::ptr::null_mut(), |obj| obj.as_ptr());
+    // SAFETY: the GIL is held and ``restore`` has just set the error indicator.
+    unsafe { pyo3::ffi::PyErr_WriteUnraisable(source) };
+}
+
+/// Send solders events to a callback.
+///
+/// Stateful components such as :class:`~solders.rpc.polling.StatusPoller`,
+/// :class:`~solders.outbox.Outbox`, :class:`~solders.rpc.streams.SlotTracker` and
+/// :class:`~solders.journal.AccountJournal` emit events as they work. Nothing is
+/// emitted until a handler is installed. Exceptions raised by the handler are
+/// reported through ``sys.unraisablehook`` and don't affect the component.
+///
+/// Args:
+///     handler (Optional[Callable[[LogEvent], None]]): Called with each event.
+///         ``None`` removes the current handler.
+///     min_level (LogLevel): Events below this level are dropped. Defaults to ``LogLevel.Debug``.
+///
+/// Example:
+///     >>> from solders.log import set_log_handler, LogLevel
+///     >>> from solders.outbox import Outbox
+///     >>> from solders.transaction import VersionedTransaction
+///     >>> from solders.message import Message
+///     >>> from solders.keypair import Keypair
+///     >>> events = []
+///     >>> set_log_handler(events.append)
+///     >>> payer = Keypair()
+///     >>> outbox = Outbox()
+///     >>> outbox.add(VersionedTransaction(Message([], payer.pubkey()), [payer]), 100)
+///     True
+///     >>> _ = outbox.expire(101)
+///     >>> events[0].level == LogLevel.Warning, events[0].target
+///     (True, 'solders.outbox')
+///     >>> set_log_handler(None)
+///
+#[pyfunction]
+#[pyo3(signature = (handler, min_level = LogLevel::Debug))]
+pub fn set_log_handler(handler: Option<PyObject>, min_level: LogLevel) {
+    install(handler.map(Sink::Callback), min_level);
+}
+
+/// Send solders events to Python's ``logging`` module.
+///
+/// Each event is logged to ``logging.getLogger(event.target)`` at the matching level,
+/// with its context in the record's ``solders_context`` attribute. This replaces any
+/// handler installed with :func:`set_log_handler`.
+///
+/// Args:
+///     min_level (LogLevel): Events below this level are dropped. Defaults to ``LogLevel.Debug``.
+///
+#[pyfunction]
+#[pyo3(signature = (min_level = LogLevel::Debug))]
+pub fn use_python_logging(min_level: LogLevel) {
+    install(Some(Sink::Logging), min_level);
+}
+
+pub(crate) fn create_log_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "log")?;
+    m.add_class::<LogLevel>()?;
+    m.add_class::<LogEvent>()?;
+    m.add_function(wrap_pyfunction!(set_log_handler, m)?)?;
+    m.add_function(wrap_pyfunction!(use_python_logging, m)?)?;
+    Ok(m)
+}
@@ -1,5 +1,7 @@
 use pyo3::prelude::*;
-use solders_primitives::message::{Message, MessageAddressTableLookup, MessageHeader, MessageV0};
+use solders_primitives::message::{
+    CompileError, Message, MessageAddressTableLookup, MessageHeader, MessageV0,
+};
 
 pub(crate) fn create_message_mod(py: Python<'_>) -> PyResult<&PyModule> {
     let m = PyModule::new(py, "message")?;
@@ -7,5 +9,6 @@ pub(crate) fn create_message_mod(py: Python<'_>) -> PyResult<&PyModule> {
     m.add_class::<MessageHeader>()?;
     m.add_class::<MessageV0>()?;
     m.add_class::<MessageAddressTableLookup>()?;
+    m.add("CompileError", py.get_type::<CompileError>())?;
     Ok(m)
 }
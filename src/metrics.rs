@@ -0,0 +1,469 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+    time::Instant,
+};
+
+use pyo3::prelude::*;
+use solders_macros::richcmp_eq_only;
+use solders_traits::{to_py_value_err, RichcmpEqualityOnly};
+
+/// A monotonically increasing count.
+pub(crate) struct Counter {
+    name: &'static str,
+    help: &'static str,
+    value: AtomicU64,
+}
+
+impl Counter {
+    const fn new(name: &'static str, help: &'static str) -> Self {
+        Self {
+            name,
+            help,
+            value: AtomicU64::new(0),
+        }
+    }
+}
+
+/// The most buckets a [`Histogram`] can have.
+const MAX_BUCKETS: usize = 10;
+
+/// A distribution of observed values, counted into cumulative buckets.
+pub(crate) struct Histogram {
+    name: &'static str,
+    help: &'static str,
+    buckets: &'static [f64],
+    /// Per bucket, the observations that fell in it but in no lower bucket.
+    counts: [AtomicU64; MAX_BUCKETS],
+    /// The bits of the ``f64`` sum of all observations.
+    sum: AtomicU64,
+    count: AtomicU64,
+}
+
+impl Histogram {
+    const fn new(name: &'static str, help: &'static str, buckets: &'static [f64]) -> Self {
+        #[allow(clippy::declare_interior_mutable_const)]
+        const ZERO: AtomicU64 = AtomicU64::new(0);
+        assert!(buckets.len() <= MAX_BUCKETS);
+        Self {
+            name,
+            help,
+            buckets,
+            counts: [ZERO; MAX_BUCKETS],
+            sum: AtomicU64::new(0),
+            count: AtomicU64::new(0),
+        }
+    }
+}
+
+const CONFIRMATION_BUCKETS: [f64; 10] = [0.5, 1.0, 2.0, 5.0, 10.0, 20.0, 30.0, 60.0, 90.0, 120.0];
+const PARSE_BUCKETS: [f64; 8] = [1e-6, 1e-5, 1e-4, 5e-4, 1e-3, 5e-3, 1e-2, 1e-1];
+
+pub(crate) static POLLER_REQUESTS: Counter = Counter::new(
+    "solders_status_poller_requests_total",
+    "getSignatureStatuses requests built by StatusPoller.",
+);
+pub(crate) static POLLER_RESOLVED: Counter = Counter::new(
+    "solders_status_poller_resolved_total",
+    "Signatures StatusPoller saw reach its commitment.",
+);
+pub(crate) static POLLER_EXPIRED: Counter = Counter::new(
+    "solders_status_poller_expired_total",
+    "Signatures StatusPoller gave up on after their last valid block height.",
+);
+pub(crate) static BACKFILL_RESPONSES: Counter = Counter::new(
+    "solders_block_backfill_responses_total",
+    "getBlocks responses handled by BlockBackfill.",
+);
+pub(crate) static BACKFILL_BLOCKS: Counter = Counter::new(
+    "solders_block_backfill_blocks_total",
+    "Blocks found by BlockBackfill.",
+);
+pub(crate) static OUTBOX_ADDED: Counter = Counter::new(
+    "solders_outbox_added_total",
+    "Transactions added to an Outbox.",
+);
+pub(crate) static OUTBOX_EXPIRED: Counter = Counter::new(
+    "solders_outbox_expired_total",
+    "Outbox transactions marked expired.",
+);
+pub(crate) static SLOT_TRACKER_MISSED: Counter = Counter::new(
+    "solders_slot_tracker_missed_slots_total",
+    "Slots SlotTracker never saw, e.g. while the websocket was reconnecting.",
+);
+pub(crate) static SLOT_TRACKER_FORKS: Counter = Counter::new(
+    "solders_slot_tracker_forks_total",
+    "Forks SlotTracker detected.",
+);
+pub(crate) static SLOT_TRACKER_DEAD: Counter = Counter::new(
+    "solders_slot_tracker_dead_slots_total",
+    "Dead slots SlotTracker was notified of.",
+);
+pub(crate) static JOURNAL_TRANSACTIONS: Counter = Counter::new(
+    "solders_journal_transactions_total",
+    "Transactions processed by AccountJournal.",
+);
+pub(crate) static JOURNAL_FAILED: Counter = Counter::new(
+    "solders_journal_failed_transactions_total",
+    "Transactions processed by AccountJournal that failed.",
+);
+pub(crate) static WEBSOCKET_MESSAGES: Counter = Counter::new(
+    "solders_websocket_messages_total",
+    "Websocket messages parsed.",
+);
+pub(crate) static WEBSOCKET_PARSE_ERRORS: Counter = Counter::new(
+    "solders_websocket_parse_errors_total",
+    "Websocket messages that failed to parse.",
+);
+pub(crate) static REQUESTS_SENT: Counter = Counter::new(
+    "solders_requests_sent_total",
+    "Transactions sent by TpuLeaderPlanner and requests recorded by the caller.",
+);
+pub(crate) static RETRIES: Counter = Counter::new(
+    "solders_retries_total",
+    "Sends retried by TpuLeaderPlanner or recorded by the caller.",
+);
+pub(crate) static WEBSOCKET_RECONNECTS: Counter = Counter::new(
+    "solders_websocket_reconnects_total",
+    "Websocket reconnects recorded by the caller.",
+);
+
+pub(crate) static CONFIRMATION_SECONDS: Histogram = Histogram::new(
+    "solders_status_poller_confirmation_seconds",
+    "Time from StatusPoller.track to the signature reaching the poller's commitment.",
+    &CONFIRMATION_BUCKETS,
+);
+pub(crate) static WEBSOCKET_PARSE_SECONDS: Histogram = Histogram::new(
+    "solders_websocket_parse_seconds",
+    "Time spent parsing websocket messages.",
+    &PARSE_BUCKETS,
+);
+pub(crate) static RPC_PARSE_SECONDS: Histogram = Histogram::new(
+    "solders_rpc_parse_seconds",
+    "Time spent parsing batch RPC responses.",
+    &PARSE_BUCKETS,
+);
+
+static COUNTERS: [&Counter; 17] = [
+    &POLLER_REQUESTS,
+    &POLLER_RESOLVED,
+    &POLLER_EXPIRED,
+    &BACKFILL_RESPONSES,
+    &BACKFILL_BLOCKS,
+    &OUTBOX_ADDED,
+    &OUTBOX_EXPIRED,
+    &SLOT_TRACKER_MISSED,
+    &SLOT_TRACKER_FORKS,
+    &SLOT_TRACKER_DEAD,
+    &JOURNAL_TRANSACTIONS,
+    &JOURNAL_FAILED,
+    &WEBSOCKET_MESSAGES,
+    &WEBSOCKET_PARSE_ERRORS,
+    &REQUESTS_SENT,
+    &RETRIES,
+    &WEBSOCKET_RECONNECTS,
+];
+/// The counters for I/O that solders doesn't do itself, so callers can :func:`record` them.
+static RECORDABLE: [&Counter; 3] = [&REQUESTS_SENT, &RETRIES, &WEBSOCKET_RECONNECTS];
+static HISTOGRAMS: [&Histogram; 3] = [
+    &CONFIRMATION_SECONDS,
+    &WEBSOCKET_PARSE_SECONDS,
+    &RPC_PARSE_SECONDS,
+];
+
+/// Checked before recording, so disabled metrics cost one relaxed load.
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Add ``by`` to a counter.
+pub(crate) fn inc(counter: &Counter, by: u64) {
+    if by > 0 && enabled() {
+        counter.value.fetch_add(by, Ordering::Relaxed);
+    }
+}
+
+/// Record one value in a histogram.
+pub(crate) fn observe(histogram: &Histogram, value: f64) {
+    if !enabled() {
+        return;
+    }
+    if let Some(i) = histogram.buckets.iter().position(|le| value <= *le) {
+        histogram.counts[i].fetch_add(1, Ordering::Relaxed);
+    }
+    histogram
+        .sum
+        .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |bits| {
+            Some((f64::from_bits(bits) + value).to_bits())
+        })
+        .unwrap();
+    histogram.count.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Run ``f``, recording how long it took in seconds.
+pub(crate) fn time<T>(histogram: &Histogram, f: impl FnOnce() -> T) -> T {
+    if !enabled() {
+        return f();
+    }
+    let start = Instant::now();
+    let out = f();
+    observe(histogram, start.elapsed().as_secs_f64());
+    out
+}
+
+/// A histogram in a :class:`MetricsSnapshot`.
+///
+/// Args:
+///     buckets (Sequence[Tuple[float, int]]): Each bucket's upper bound and the number of
+///         observations at or below it.
+///     sum (float): The sum of all observations.
+///     count (int): The number of observations.
+///
+#[pyclass(module = "solders.metrics", subclass)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistogramSnapshot {
+    /// List[Tuple[float, int]]: Each bucket's upper bound and the cumulative count at it.
+    #[pyo3(get)]
+    pub buckets: Vec<(f64, u64)>,
+    /// float: The sum of all observations.
+    #[pyo3(get)]
+    pub sum: f64,
+    /// int: The number of observations, including those above the last bucket.
+    #[pyo3(get)]
+    pub count: u64,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl HistogramSnapshot {
+    #[new]
+    pub fn new(buckets: Vec<(f64, u64)>, sum: f64, count: u64) -> Self {
+        Self {
+            buckets,
+            sum,
+            count,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for HistogramSnapshot {}
+
+/// The metrics of every solders component at one point in time, as returned by :func:`metrics`.
+///
+/// Args:
+///     counters (Dict[str, int]): The value of each counter.
+///     histograms (Dict[str, HistogramSnapshot]): The observations of each histogram.
+///
+#[pyclass(module = "solders.metrics", subclass)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MetricsSnapshot {
+    /// Dict[str, int]: The value of each counter.
+    #[pyo3(get)]
+    pub counters: BTreeMap<String, u64>,
+    /// Dict[str, HistogramSnapshot]: The observations of each histogram.
+    #[pyo3(get)]
+    pub histograms: BTreeMap<String, HistogramSnapshot>,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl MetricsSnapshot {
+    #[new]
+    pub fn new(
+        counters: BTreeMap<String, u64>,
+        histograms: BTreeMap<String, HistogramSnapshot>,
+    ) -> Self {
+        Self {
+            counters,
+            histograms,
+        }
+    }
+
+    /// Render the snapshot in the Prometheus text exposition format.
+    ///
+    /// Returns:
+    ///     str: The metrics, ready to serve from a ``/metrics`` endpoint.
+    ///
+    pub fn to_prometheus(&self) -> String {
+        let help = |name: &str| {
+            COUNTERS
+                .iter()
+                .map(|c| (c.name, c.help))
+                .chain(HISTOGRAMS.iter().map(|h| (h.name, h.help)))
+                .find(|(n, _)| *n == name)
+                .map(|(_, help)| help)
+        };
+        let mut out = String::new();
+        for (name, value) in &self.counters {
+            if let Some(help) = help(name) {
+                writeln!(out, "# HELP {name} {help}").unwrap();
+            }
+            writeln!(out, "# TYPE {name} counter\n{name} {value}").unwrap();
+        }
+        for (name, hist) in &self.histograms {
+            if let Some(help) = help(name) {
+                writeln!(out, "# HELP {name} {help}").unwrap();
+            }
+            writeln!(out, "# TYPE {name} histogram").unwrap();
+            for (le, count) in &hist.buckets {
+                writeln!(out, "{name}_bucket{{le=\"{le}\"}} {count}").unwrap();
+            }
+            writeln!(out, "{name}_bucket{{le=\"+Inf\"}} {}", hist.count).unwrap();
+            writeln!(out, "{name}_sum {}\n{name}_count {}", hist.sum, hist.count).unwrap();
+        }
+        out
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for MetricsSnapshot {}
+
+/// Take a snapshot of the metrics recorded by solders components.
+///
+/// Recording is off until :func:`set_metrics_enabled` turns it on. Counters and
+/// histograms are process-wide and cover every instance of a component:
+/// :class:`~solders.rpc.polling.StatusPoller` counts the requests it builds,
+/// resolutions, expiries and confirmation latency;
+/// :class:`~solders.rpc.polling.BlockBackfill`, :class:`~solders.outbox.Outbox`,
+/// :class:`~solders.rpc.streams.SlotTracker` and :class:`~solders.journal.AccountJournal`
+/// count what they process; and the websocket and batch response parsers time
+/// themselves. ``TpuLeaderPlanner.send_transaction`` counts its sends and retries.
+/// Other sends, retries and websocket reconnects happen in the caller's I/O code,
+/// which counts them with :func:`record`.
+/// Every metric is present, with zero values until something is recorded.
+///
+/// Returns:
+///     MetricsSnapshot: The current values.
+///
+/// Example:
+///     >>> from solders.metrics import metrics, reset_metrics, set_metrics_enabled
+///     >>> from solders.rpc.polling import StatusPoller
+///     >>> from solders.signature import Signature
+///     >>> reset_metrics()
+///     >>> set_metrics_enabled(True)
+///     >>> poller = StatusPoller()
+///     >>> poller.track(Signature.new_unique(), 100)
+///     True
+///     >>> _ = poller.requests()
+///     >>> metrics().counters["solders_status_poller_requests_total"]
+///     1
+///     >>> print(metrics().to_prometheus().splitlines()[0])
+///     # HELP solders_block_backfill_blocks_total Blocks found by BlockBackfill.
+///     >>> set_metrics_enabled(False)
+///
+#[pyfunction]
+pub fn metrics() -> MetricsSnapshot {
+    let counters = COUNTERS
+        .iter()
+        .map(|c| (c.name.to_owned(), c.value.load(Ordering::Relaxed)))
+        .collect();
+    let histograms = HISTOGRAMS
+        .iter()
+        .map(|h| {
+            let mut cumulative = 0;
+            let buckets = h
+                .buckets
+                .iter()
+                .zip(&h.counts)
+                .map(|(le, count)| {
+                    cumulative += count.load(Ordering::Relaxed);
+                    (*le, cumulative)
+                })
+                .collect();
+            let snapshot = HistogramSnapshot {
+                buckets,
+                sum: f64::from_bits(h.sum.load(Ordering::Relaxed)),
+                count: h.count.load(Ordering::Relaxed),
+            };
+            (h.name.to_owned(), snapshot)
+        })
+        .collect();
+    MetricsSnapshot {
+        counters,
+        histograms,
+    }
+}
+
+/// Set every metric back to zero.
+#[pyfunction]
+pub fn reset_metrics() {
+    for counter in COUNTERS {
+        counter.value.store(0, Ordering::Relaxed);
+    }
+    for histogram in HISTOGRAMS {
+        for count in &histogram.counts {
+            count.store(0, Ordering::Relaxed);
+        }
+        histogram.sum.store(0, Ordering::Relaxed);
+        histogram.count.store(0, Ordering::Relaxed);
+    }
+}
+
+/// Turn metrics recording on or off. It is off by default.
+///
+/// Metrics are plain atomic counters, so recording takes no lock, and while it is
+/// off components only check this flag. Values recorded earlier are kept.
+///
+/// Args:
+///     enabled (bool): Whether to record metrics.
+///
+#[pyfunction]
+pub fn set_metrics_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Count an event from the caller's own I/O code.
+///
+/// solders does no networking besides ``TpuLeaderPlanner.send_transaction``, so the
+/// code that sends RPC requests and runs websockets records them here, and they show
+/// up next to the component metrics. Like the rest, nothing is recorded while metrics
+/// are disabled.
+///
+/// Args:
+///     name (str): One of ``"solders_requests_sent_total"``, ``"solders_retries_total"``
+///         and ``"solders_websocket_reconnects_total"``.
+///     by (int): How much to add. Defaults to 1.
+///
+/// Raises:
+///     ValueError: If ``name`` isn't one of those counters.
+///
+/// Example:
+///     >>> from solders.metrics import metrics, record, reset_metrics, set_metrics_enabled
+///     >>> reset_metrics()
+///     >>> set_metrics_enabled(True)
+///     >>> record("solders_websocket_reconnects_total")
+///     >>> metrics().counters["solders_websocket_reconnects_total"]
+///     1
+///     >>> set_metrics_enabled(False)
+///
+#[pyfunction]
+#[pyo3(signature = (name, by = 1))]
+pub fn record(name: &str, by: u64) -> PyResult<()> {
+    let counter = RECORDABLE
+        .iter()
+        .find(|c| c.name == name)
+        .ok_or_else(|| to_py_value_err(&format!("{name} can't be recorded by the caller")))?;
+    inc(counter, by);
+    Ok(())
+}
+
+pub(crate) fn create_metrics_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "metrics")?;
+    m.add_class::<HistogramSnapshot>()?;
+    m.add_class::<MetricsSnapshot>()?;
+    m.add_function(wrap_pyfunction!(metrics, m)?)?;
+    m.add_function(wrap_pyfunction!(reset_metrics, m)?)?;
+    m.add_function(wrap_pyfunction!(set_metrics_enabled, m)?)?;
+    m.add_function(wrap_pyfunction!(record, m)?)?;
+    Ok(m)
+}
@@ -0,0 +1,405 @@
+use std::collections::HashMap;
+
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use solders_macros::{common_methods, enum_string, richcmp_eq_only};
+use solders_primitives::{signature::Signature, transaction::VersionedTransaction};
+use solders_traits::{
+    impl_display, py_from_bytes_general_via_bincode, pybytes_general_via_bincode, to_py_value_err,
+    EnumString, PyFromBytesGeneral, RichcmpEqualityOnly,
+};
+
+use crate::{
+    log::{self, LogLevel},
+    metrics,
+};
+
+/// Bumped whenever the layout of :class:`Outbox` bytes changes.
+const OUTBOX_FORMAT_VERSION: u8 = 1;
+
+/// The state of a transaction in an :class:`Outbox`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[pyclass(module = "solders.outbox")]
+pub enum OutboxStatus {
+    Pending,
+    Confirmed,
+    Failed,
+    Expired,
+}
+
+impl EnumString for OutboxStatus {}
+
+#[enum_string]
+#[pymethods]
+impl OutboxStatus {}
+
+/// A transaction tracked by an :class:`Outbox`.
+///
+/// Args:
+///     transaction (VersionedTransaction): The signed transaction.
+///     last_valid_block_height (int): The block height after which the transaction expires.
+///     status (OutboxStatus): The state of the transaction.
+///
+#[pyclass(module = "solders.outbox", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "OutboxEntryFields")]
+pub struct OutboxEntry {
+    transaction: VersionedTransaction,
+    last_valid_block_height: u64,
+    status: OutboxStatus,
+}
+
+/// The serialized form of an :class:`OutboxEntry`, checked on the way in.
+#[derive(Deserialize)]
+struct OutboxEntryFields {
+    transaction: VersionedTransaction,
+    last_valid_block_height: u64,
+    status: OutboxStatus,
+}
+
+impl TryFrom<OutboxEntryFields> for OutboxEntry {
+    type Error = String;
+
+    fn try_from(fields: OutboxEntryFields) -> Result<Self, Self::Error> {
+        if fields.transaction.signatures().is_empty() {
+            return Err("transaction has no signatures".to_owned());
+        }
+        Ok(Self {
+            transaction: fields.transaction,
+            last_valid_block_height: fields.last_valid_block_height,
+            status: fields.status,
+        })
+    }
+}
+
+#[richcmp_eq_only]
+#[common_methods]
+#[pymethods]
+impl OutboxEntry {
+    #[new]
+    pub fn new(
+        transaction: VersionedTransaction,
+        last_valid_block_height: u64,
+        status: OutboxStatus,
+    ) -> PyResult<Self> {
+        if transaction.signatures().is_empty() {
+            return Err(to_py_value_err(&"transaction has no signatures"));
+        }
+        Ok(Self {
+            transaction,
+            last_valid_block_height,
+            status,
+        })
+    }
+
+    /// VersionedTransaction: The signed transaction.
+    #[getter]
+    pub fn transaction(&self) -> VersionedTransaction {
+        self.transaction.clone()
+    }
+
+    /// int: The block height after which the transaction expires.
+    #[getter]
+    pub fn last_valid_block_height(&self) -> u64 {
+        self.last_valid_block_height
+    }
+
+    /// OutboxStatus: The state of the transaction.
+    #[getter]
+    pub fn status(&self) -> OutboxStatus {
+        self.status
+    }
+
+    /// Signature: The transaction's first signature, which identifies it.
+    #[getter]
+    pub fn signature(&self) -> Signature {
+        self.transaction.signatures()[0]
+    }
+}
+
+impl RichcmpEqualityOnly for OutboxEntry {}
+impl_display!(OutboxEntry);
+pybytes_general_via_bincode!(OutboxEntry);
+py_from_bytes_general_via_bincode!(OutboxEntry);
+solders_traits::common_methods_default!(OutboxEntry);
+
+/// A set of in-flight transactions that can be persisted and restored.
+///
+/// Transactions are keyed by their first signature, so adding the same
+/// transaction twice is a no-op. ``bytes(outbox)`` is a compact bincode
+/// encoding, prefixed with a format version, that sender services can write
+/// to disk and load with :meth:`Outbox.from_bytes` after a crash.
+///
+/// Example:
+///     >>> from solders.outbox import Outbox, OutboxStatus
+///     >>> from solders.transaction import VersionedTransaction
+///     >>> from solders.message import Message
+///     >>> from solders.keypair import Keypair
+///     >>> payer = Keypair()
+///     >>> tx = VersionedTransaction(Message([], payer.pubkey()), [payer])
+///     >>> outbox = Outbox()
+///     >>> outbox.add(tx, 100)
+///     True
+///     >>> outbox.add(tx, 100)
+///     False
+///     >>> restored = Outbox.from_bytes(bytes(outbox))
+///     >>> restored.expire(101) == [tx.signatures[0]]
+///     True
+///     >>> restored.get(tx.signatures[0]).status == OutboxStatus.Expired
+///     True
+///
+#[pyclass(module = "solders.outbox", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "OutboxFields")]
+pub struct Outbox {
+    version: u8,
+    entries: Vec<OutboxEntry>,
+    /// The position of each entry in ``entries``, by signature.
+    #[serde(skip)]
+    index: HashMap<Signature, usize>,
+}
+
+/// The serialized form of an :class:`Outbox`, checked on the way in.
+#[derive(Deserialize)]
+struct OutboxFields {
+    version: u8,
+    entries: Vec<OutboxEntry>,
+}
+
+impl TryFrom<OutboxFields> for Outbox {
+    type Error = String;
+
+    fn try_from(fields: OutboxFields) -> Result<Self, Self::Error> {
+        if fields.version != OUTBOX_FORMAT_VERSION {
+            return Err(format!(
+                "unsupported outbox format version {}",
+                fields.version
+            ));
+        }
+        let mut outbox = Self {
+            version: fields.version,
+            entries: fields.entries,
+            index: HashMap::new(),
+        };
+        outbox.reindex();
+        if outbox.index.len() != outbox.entries.len() {
+            return Err("outbox has duplicate signatures".to_owned());
+        }
+        Ok(outbox)
+    }
+}
+
+impl Default for Outbox {
+    fn default() -> Self {
+        Self {
+            version: OUTBOX_FORMAT_VERSION,
+            entries: Vec::new(),
+            index: HashMap::new(),
+        }
+    }
+}
+
+impl Outbox {
+    fn position(&self, signature: &Signature) -> Option<usize> {
+        self.index.get(signature).copied()
+    }
+
+    /// Rebuild ``index`` after entries moved.
+    fn reindex(&mut self) {
+        self.index = self
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(i, e)| (e.signature(), i))
+            .collect();
+    }
+}
+
+#[richcmp_eq_only]
+#[common_methods]
+#[pymethods]
+impl Outbox {
+    #[new]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// List[OutboxEntry]: All tracked transactions, in insertion order.
+    #[getter]
+    pub fn entries(&self) -> Vec<OutboxEntry> {
+        self.entries.clone()
+    }
+
+    /// Track a new pending transaction.
+    ///
+    /// Args:
+    ///     transaction (VersionedTransaction): The signed transaction.
+    ///     last_valid_block_height (Optional[int]): The block height after which the transaction
+    ///         expires. Defaults to the one attached to ``transaction``.
+    ///
+    /// Returns:
+    ///     bool: ``False`` if a transaction with the same signature is already tracked.
+    ///
+    /// Raises:
+    ///     ValueError: If no block height is given or attached.
+    ///
+    #[pyo3(signature = (transaction, last_valid_block_height = None))]
+    pub fn add(
+        &mut self,
+        transaction: VersionedTransaction,
+        last_valid_block_height: Option<u64>,
+    ) -> PyResult<bool> {
+        let last_valid_block_height = last_valid_block_height
+            .or_else(|| transaction.last_valid_block_height())
+            .ok_or_else(|| to_py_value_err(&"transaction has no last_valid_block_height"))?;
+        let entry = OutboxEntry::new(transaction, last_valid_block_height, OutboxStatus::Pending)?;
+        if self.position(&entry.signature()).is_some() {
+            return Ok(false);
+        }
+        self.index.insert(entry.signature(), self.entries.len());
+        self.entries.push(entry);
+        metrics::inc(&metrics::OUTBOX_ADDED, 1);
+        Ok(true)
+    }
+
+    /// Look up a transaction by signature.
+    ///
+    /// Args:
+    ///     signature (Signature): The transaction's first signature.
+    ///
+    /// Returns:
+    ///     Optional[OutboxEntry]: The entry, if tracked.
+    ///
+    pub fn get(&self, signature: Signature) -> Option<OutboxEntry> {
+        self.position(&signature).map(|i| self.entries[i].clone())
+    }
+
+    /// Update the status of a transaction.
+    ///
+    /// Args:
+    ///     signature (Signature): The transaction's first signature.
+    ///     status (OutboxStatus): The new status.
+    ///
+    /// Returns:
+    ///     bool: Whether the transaction was tracked.
+    ///
+    pub fn set_status(&mut self, signature: Signature, status: OutboxStatus) -> bool {
+        match self.position(&signature) {
+            Some(i) => {
+                self.entries[i].status = status;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Stop tracking a transaction.
+    ///
+    /// Args:
+    ///     signature (Signature): The transaction's first signature.
+    ///
+    /// Returns:
+    ///     Optional[OutboxEntry]: The removed entry, if tracked.
+    ///
+    pub fn remove(&mut self, signature: Signature) -> Option<OutboxEntry> {
+        let i = self.position(&signature)?;
+        let entry = self.entries.remove(i);
+        self.reindex();
+        Some(entry)
+    }
+
+    /// Mark pending transactions whose blockhash has expired as ``Expired``.
+    ///
+    /// Args:
+    ///     block_height (int): The current block height.
+    ///
+    /// Returns:
+    ///     List[Signature]: The signatures of the newly expired transactions.
+    ///
+    pub fn expire(&mut self, py: Python<'_>, block_height: u64) -> Vec<Signature> {
+        let mut expired = Vec::new();
+        for entry in self.entries.iter_mut().filter(|e| {
+            e.status == OutboxStatus::Pending && e.last_valid_block_height < block_height
+        }) {
+            entry.status = OutboxStatus::Expired;
+            expired.push((entry.signature(), entry.last_valid_block_height));
+        }
+        metrics::inc(&metrics::OUTBOX_EXPIRED, expired.len() as u64);
+        for (signature, last_valid_block_height) in &expired {
+            log::emit(
+                py,
+                LogLevel::Warning,
+                "solders.outbox",
+                format!("transaction {signature} expired"),
+                &[
+                    ("signature", signature.to_string()),
+                    (
+                        "last_valid_block_height",
+                        last_valid_block_height.to_string(),
+                    ),
+                    ("block_height", block_height.to_string()),
+                ],
+            );
+        }
+        expired
+            .into_iter()
+            .map(|(signature, _)| signature)
+            .collect()
+    }
+
+    /// The transactions that are still pending.
+    ///
+    /// Returns:
+    ///     List[OutboxEntry]: The pending entries, in insertion order.
+    ///
+    pub fn pending(&self) -> Vec<OutboxEntry> {
+        self.entries
+            .iter()
+            .filter(|e| e.status == OutboxStatus::Pending)
+            .cloned()
+            .collect()
+    }
+
+    /// Stop tracking every transaction that is no longer pending.
+    ///
+    /// Returns:
+    ///     int: The number of entries removed.
+    ///
+    pub fn prune(&mut self) -> usize {
+        let before = self.entries.len();
+        self.entries.retain(|e| e.status == OutboxStatus::Pending);
+        self.reindex();
+        before - self.entries.len()
+    }
+
+    fn __len__(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn __contains__(&self, signature: Signature) -> bool {
+        self.position(&signature).is_some()
+    }
+}
+
+impl RichcmpEqualityOnly for Outbox {}
+impl_display!(Outbox);
+pybytes_general_via_bincode!(Outbox);
+solders_traits::common_methods_default!(Outbox);
+
+impl PyFromBytesGeneral for Outbox {
+    fn py_from_bytes_general(raw: &[u8]) -> PyResult<Self> {
+        if raw.is_empty() {
+            return Err(to_py_value_err(&"empty outbox data"));
+        }
+        bincode::deserialize(raw).map_err(|e| to_py_value_err(&e))
+    }
+}
+
+pub(crate) fn create_outbox_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "outbox")?;
+    m.add_class::<OutboxStatus>()?;
+    m.add_class::<OutboxEntry>()?;
+    m.add_class::<Outbox>()?;
+    Ok(m)
+}
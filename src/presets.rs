@@ -0,0 +1,867 @@
+use std::collections::{HashMap, HashSet};
+
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use solana_sdk::{
+    account::ReadableAccount,
+    instruction::{AccountMeta as AccountMetaOriginal, Instruction as InstructionOriginal},
+    pubkey,
+    pubkey::Pubkey as PubkeyOriginal,
+    system_instruction, system_program,
+    transaction::MAX_TX_ACCOUNT_LOCKS,
+};
+use solders_macros::{enum_string, richcmp_eq_only};
+use solders_primitives::{instruction::Instruction, pubkey::Pubkey};
+use solders_traits::{to_py_value_err, EnumString, RichcmpEqualityOnly};
+
+use crate::{
+    account::{Account, AccountJSON},
+    accounts_map::AccountsMap,
+    bundle::{plan_transactions, PlannedTransaction},
+    layouts::{
+        MULTISIG_LEN, TOKEN_2022_ACCOUNT_TYPE_ACCOUNT, TOKEN_ACCOUNT_LEN,
+        TOKEN_ACCOUNT_MINT_OFFSET, TOKEN_ACCOUNT_OWNER_OFFSET, TOKEN_ACCOUNT_STATE_OFFSET,
+    },
+    program_ids::{NATIVE_MINT, NATIVE_MINT_2022},
+    rpc::responses::{RpcKeyedAccount, RpcKeyedAccountJsonParsed},
+    spl_errors::{ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID},
+    tmp_account_decoder::ParsedAccount as ParsedAccountOriginal,
+};
+
+/// The most accounts ``getMultipleAccounts`` returns per request.
+const MAX_MULTIPLE_ACCOUNTS: usize = 100;
+
+const CREATE_IDEMPOTENT: u8 = 1;
+const TOKEN_CLOSE_ACCOUNT: u8 = 9;
+const TOKEN_TRANSFER_CHECKED: u8 = 12;
+const TOKEN_SYNC_NATIVE: u8 = 17;
+
+fn token_program(token_program_id: Option<Pubkey>) -> PyResult<PubkeyOriginal> {
+    let id = token_program_id.map_or(TOKEN_PROGRAM_ID, PubkeyOriginal::from);
+    if id == TOKEN_PROGRAM_ID || id == TOKEN_2022_PROGRAM_ID {
+        Ok(id)
+    } else {
+        Err(to_py_value_err(&format!("{} is not a token program", id)))
+    }
+}
+
+pub(crate) fn ata_address(
+    wallet: &PubkeyOriginal,
+    mint: &PubkeyOriginal,
+    token_program_id: &PubkeyOriginal,
+) -> PubkeyOriginal {
+    PubkeyOriginal::find_program_address(
+        &[wallet.as_ref(), token_program_id.as_ref(), mint.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    )
+    .0
+}
+
+fn create_ata_idempotent(
+    payer: &PubkeyOriginal,
+    wallet: &PubkeyOriginal,
+    mint: &PubkeyOriginal,
+    token_program_id: &PubkeyOriginal,
+) -> InstructionOriginal {
+    InstructionOriginal::new_with_bytes(
+        ASSOCIATED_TOKEN_PROGRAM_ID,
+        &[CREATE_IDEMPOTENT],
+        vec![
+            AccountMetaOriginal::new(*payer, true),
+            AccountMetaOriginal::new(ata_address(wallet, mint, token_program_id), false),
+            AccountMetaOriginal::new_readonly(*wallet, false),
+            AccountMetaOriginal::new_readonly(*mint, false),
+            AccountMetaOriginal::new_readonly(system_program::ID, false),
+            AccountMetaOriginal::new_readonly(*token_program_id, false),
+        ],
+    )
+}
+
+fn close_account(
+    account: &PubkeyOriginal,
+    destination: &PubkeyOriginal,
+    owner: &PubkeyOriginal,
+    token_program_id: &PubkeyOriginal,
+) -> InstructionOriginal {
+    InstructionOriginal::new_with_bytes(
+        *token_program_id,
+        &[TOKEN_CLOSE_ACCOUNT],
+        vec![
+            AccountMetaOriginal::new(*account, false),
+            AccountMetaOriginal::new(*destination, false),
+            AccountMetaOriginal::new_readonly(*owner, true),
+        ],
+    )
+}
+
+fn transfer_checked(
+    source: &PubkeyOriginal,
+    mint: &PubkeyOriginal,
+    destination: &PubkeyOriginal,
+    owner: &PubkeyOriginal,
+    amount: u64,
+    decimals: u8,
+    token_program_id: &PubkeyOriginal,
+) -> InstructionOriginal {
+    let mut data = vec![TOKEN_TRANSFER_CHECKED];
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.push(decimals);
+    InstructionOriginal::new_with_bytes(
+        *token_program_id,
+        &data,
+        vec![
+            AccountMetaOriginal::new(*source, false),
+            AccountMetaOriginal::new_readonly(*mint, false),
+            AccountMetaOriginal::new(*destination, false),
+            AccountMetaOriginal::new_readonly(*owner, true),
+        ],
+    )
+}
+
+fn to_instructions(ixs: Vec<InstructionOriginal>) -> Vec<Instruction> {
+    ixs.into_iter().map(Instruction::from).collect()
+}
+
+/// Derive the associated token account of a wallet.
+///
+/// Args:
+///     wallet (Pubkey): The owner of the token account.
+///     mint (Pubkey): The token mint.
+///     token_program_id (Optional[Pubkey]): The Token or Token-2022 program. Defaults to Token.
+///
+/// Returns:
+///     Pubkey: The associated token account address.
+///
+/// Raises:
+///     ValueError: If ``token_program_id`` isn't a token program.
+///
+#[pyfunction]
+#[pyo3(signature = (wallet, mint, token_program_id = None))]
+pub fn get_associated_token_address(
+    wallet: Pubkey,
+    mint: Pubkey,
+    token_program_id: Option<Pubkey>,
+) -> PyResult<Pubkey> {
+    let token_program_id = token_program(token_program_id)?;
+    Ok(ata_address(wallet.as_ref(), mint.as_ref(), &token_program_id).into())
+}
+
+/// Keep only the associated token accounts among scanned token accounts.
+///
+/// ``getProgramAccounts`` filters can't tell associated token accounts from other
+/// token accounts, so scan with
+/// :meth:`~solders.rpc.config.RpcProgramAccountsConfig.token_accounts_by_mint` and
+/// pass the result here. An account is kept if its address is the associated token
+/// account of the owner and mint in its data, for the token program that owns it.
+///
+/// Args:
+///     accounts (Sequence[RpcKeyedAccount]): The scanned accounts, fetched with a binary encoding.
+///
+/// Returns:
+///     List[RpcKeyedAccount]: The associated token accounts, in input order.
+///
+#[pyfunction]
+pub fn filter_associated_token_accounts(accounts: Vec<RpcKeyedAccount>) -> Vec<RpcKeyedAccount> {
+    accounts
+        .into_iter()
+        .filter(|keyed| {
+            let account = keyed.account.as_ref();
+            let data = &account.data;
+            let pubkey_at = |offset: usize| PubkeyOriginal::new(&data[offset..offset + 32]);
+            (account.owner == TOKEN_PROGRAM_ID || account.owner == TOKEN_2022_PROGRAM_ID)
+                && data.len() >= TOKEN_ACCOUNT_LEN
+                && ata_address(
+                    &pubkey_at(TOKEN_ACCOUNT_OWNER_OFFSET),
+                    &pubkey_at(TOKEN_ACCOUNT_MINT_OFFSET),
+                    &account.owner,
+                ) == PubkeyOriginal::from(keyed.pubkey)
+        })
+        .collect()
+}
+
+/// Transfer tokens to a wallet, creating its associated token account if it's missing.
+///
+/// The account is created with ``CreateIdempotent``, which succeeds if the account
+/// already exists, so the sequence is safe to send without checking first. The
+/// transfer uses ``TransferChecked`` so a wrong mint or decimals fails the transaction.
+///
+/// Args:
+///     payer (Pubkey): Pays for the account if it's created.
+///     source (Pubkey): The token account to debit.
+///     owner (Pubkey): The owner of ``source``, who signs the transfer.
+///     recipient (Pubkey): The wallet to credit.
+///     mint (Pubkey): The token mint.
+///     amount (int): The amount in raw token units.
+///     decimals (int): The mint decimals.
+///     token_program_id (Optional[Pubkey]): The Token or Token-2022 program. Defaults to Token.
+///
+/// Returns:
+///     List[Instruction]: The create and transfer instructions, in order.
+///
+/// Raises:
+///     ValueError: If ``token_program_id`` isn't a token program.
+///
+#[pyfunction]
+#[pyo3(signature = (
+    payer,
+    source,
+    owner,
+    recipient,
+    mint,
+    amount,
+    decimals,
+    token_program_id = None,
+))]
+pub fn transfer_to_wallet(
+    payer: Pubkey,
+    source: Pubkey,
+    owner: Pubkey,
+    recipient: Pubkey,
+    mint: Pubkey,
+    amount: u64,
+    decimals: u8,
+    token_program_id: Option<Pubkey>,
+) -> PyResult<Vec<Instruction>> {
+    let token_program_id = token_program(token_program_id)?;
+    let (recipient, mint) = (recipient.into(), mint.into());
+    let transfer = transfer_checked(
+        source.as_ref(),
+        &mint,
+        &ata_address(&recipient, &mint, &token_program_id),
+        owner.as_ref(),
+        amount,
+        decimals,
+        &token_program_id,
+    );
+    Ok(to_instructions(vec![
+        create_ata_idempotent(payer.as_ref(), &recipient, &mint, &token_program_id),
+        transfer,
+    ]))
+}
+
+/// Wrap SOL around instructions that spend wrapped SOL, and unwrap what's left.
+///
+/// The sequence is: create the owner's wrapped SOL account if missing, fund it,
+/// ``SyncNative`` so the token balance reflects the lamports, the given instructions,
+/// then close the account to return the remaining SOL to the owner. Closing last
+/// means leftover wrapped SOL is never stranded, even if the swap returns some.
+///
+/// Args:
+///     owner (Pubkey): The wallet, which pays and signs.
+///     lamports (int): The lamports to wrap.
+///     instructions (Sequence[Instruction]): The instructions spending the wrapped SOL,
+///         e.g. a swap.
+///     token_program_id (Optional[Pubkey]): The Token or Token-2022 program. Defaults to Token.
+///
+/// Returns:
+///     List[Instruction]: The full instruction sequence.
+///
+/// Raises:
+///     ValueError: If ``lamports`` is zero, ``token_program_id`` isn't a token program,
+///         or ``instructions`` already close the wrapped SOL account.
+///
+#[pyfunction]
+#[pyo3(signature = (owner, lamports, instructions, token_program_id = None))]
+pub fn wrap_sol_around(
+    owner: Pubkey,
+    lamports: u64,
+    instructions: Vec<Instruction>,
+    token_program_id: Option<Pubkey>,
+) -> PyResult<Vec<Instruction>> {
+    if lamports == 0 {
+        return Err(to_py_value_err(&"nothing to wrap"));
+    }
+    let token_program_id = token_program(token_program_id)?;
+    let native_mint = if token_program_id == TOKEN_PROGRAM_ID {
+        NATIVE_MINT
+    } else {
+        NATIVE_MINT_2022
+    };
+    let owner = PubkeyOriginal::from(owner);
+    let wrapped = ata_address(&owner, &native_mint, &token_program_id);
+    let instructions: Vec<InstructionOriginal> =
+        instructions.into_iter().map(|ix| ix.into()).collect();
+    let closes_wrapped = instructions.iter().any(|ix| {
+        ix.program_id == token_program_id
+            && ix.data.first() == Some(&TOKEN_CLOSE_ACCOUNT)
+            && ix.accounts.first().map(|meta| meta.pubkey) == Some(wrapped)
+    });
+    if closes_wrapped {
+        return Err(to_py_value_err(&format!(
+            "instructions already close the wrapped SOL account {}",
+            wrapped
+        )));
+    }
+    let mut ixs = vec![
+        create_ata_idempotent(&owner, &owner, &native_mint, &token_program_id),
+        system_instruction::transfer(&owner, &wrapped, lamports),
+        InstructionOriginal::new_with_bytes(
+            token_program_id,
+            &[TOKEN_SYNC_NATIVE],
+            vec![AccountMetaOriginal::new(wrapped, false)],
+        ),
+    ];
+    ixs.extend(instructions);
+    ixs.push(close_account(&wrapped, &owner, &owner, &token_program_id));
+    Ok(to_instructions(ixs))
+}
+
+/// Close temporary token accounts and reclaim their rent.
+///
+/// Append these after every instruction that uses the accounts. Duplicates are
+/// closed once. Only empty accounts and wrapped SOL accounts can be closed.
+///
+/// Args:
+///     accounts (Sequence[Pubkey]): The token accounts to close.
+///     destination (Pubkey): Receives the rent.
+///     owner (Pubkey): The owner of the accounts, who signs.
+///     token_program_id (Optional[Pubkey]): The Token or Token-2022 program. Defaults to Token.
+///
+/// Returns:
+///     List[Instruction]: One ``CloseAccount`` per account.
+///
+/// Raises:
+///     ValueError: If ``destination`` is one of the accounts or ``token_program_id``
+///         isn't a token program.
+///
+#[pyfunction]
+#[pyo3(signature = (accounts, destination, owner, token_program_id = None))]
+pub fn close_token_accounts(
+    accounts: Vec<Pubkey>,
+    destination: Pubkey,
+    owner: Pubkey,
+    token_program_id: Option<Pubkey>,
+) -> PyResult<Vec<Instruction>> {
+    let token_program_id = token_program(token_program_id)?;
+    if accounts.contains(&destination) {
+        return Err(to_py_value_err(&format!(
+            "cannot send rent to {}, which is being closed",
+            destination
+        )));
+    }
+    let mut seen = HashSet::new();
+    Ok(accounts
+        .into_iter()
+        .filter(|account| seen.insert(*account))
+        .map(|account| {
+            close_account(
+                account.as_ref(),
+                destination.as_ref(),
+                owner.as_ref(),
+                &token_program_id,
+            )
+            .into()
+        })
+        .collect())
+}
+
+/// Where :func:`plan_associated_token_accounts` looks up existing accounts.
+#[derive(FromPyObject, Clone, Debug)]
+pub enum AccountSource {
+    Map(AccountsMap),
+    Dict(HashMap<Pubkey, Account>),
+    Fetch(PyObject),
+}
+
+/// Whether ``account`` is an initialized token account owned by ``token_program_id``.
+fn is_initialized_token_account(
+    account: &impl ReadableAccount,
+    token_program_id: &PubkeyOriginal,
+) -> bool {
+    let data = account.data();
+    let token_account_len = data.len() == TOKEN_ACCOUNT_LEN
+        || (data.len() > TOKEN_ACCOUNT_LEN
+            && data.len() != MULTISIG_LEN
+            && data[TOKEN_ACCOUNT_LEN] == TOKEN_2022_ACCOUNT_TYPE_ACCOUNT);
+    account.owner() == token_program_id
+        && token_account_len
+        && data[TOKEN_ACCOUNT_STATE_OFFSET] != 0
+}
+
+impl AccountSource {
+    /// Whether each address holds an initialized token account of ``token_program_id``.
+    fn exists(
+        &self,
+        py: Python<'_>,
+        addresses: &[PubkeyOriginal],
+        token_program_id: &PubkeyOriginal,
+    ) -> PyResult<Vec<bool>> {
+        let initialized = |account: Option<&Account>| {
+            account.map_or(false, |a| is_initialized_token_account(a, token_program_id))
+        };
+        match self {
+            Self::Map(map) => Ok(addresses
+                .iter()
+                .map(|address| {
+                    map.get_original(address)
+                        .map_or(false, |a| is_initialized_token_account(a, token_program_id))
+                })
+                .collect()),
+            Self::Dict(dict) => Ok(addresses
+                .iter()
+                .map(|address| initialized(dict.get(&Pubkey(*address))))
+                .collect()),
+            Self::Fetch(fetch) => {
+                let mut exists = Vec::with_capacity(addresses.len());
+                for chunk in addresses.chunks(MAX_MULTIPLE_ACCOUNTS) {
+                    let keys: Vec<Pubkey> = chunk.iter().map(|a| Pubkey(*a)).collect();
+                    let fetched: Vec<Option<Account>> = fetch.call1(py, (keys,))?.extract(py)?;
+                    if fetched.len() != chunk.len() {
+                        return Err(to_py_value_err(&format!(
+                            "fetch returned {} accounts for {} addresses",
+                            fetched.len(),
+                            chunk.len()
+                        )));
+                    }
+                    exists.extend(fetched.iter().map(|a| initialized(a.as_ref())));
+                }
+                Ok(exists)
+            }
+        }
+    }
+}
+
+/// The result of :func:`plan_associated_token_accounts`.
+#[pyclass(module = "solders.presets", subclass)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AssociatedTokenAccountPlan {
+    /// List[Pubkey]: The associated token accounts to create, in input order.
+    #[pyo3(get)]
+    pub missing: Vec<Pubkey>,
+    /// List[Pubkey]: The associated token accounts that already exist and are initialized, in input order.
+    #[pyo3(get)]
+    pub existing: Vec<Pubkey>,
+    /// List[Instruction]: One ``CreateIdempotent`` instruction per missing account.
+    #[pyo3(get)]
+    pub instructions: Vec<Instruction>,
+    /// List[PlannedTransaction]: The instructions packed into transactions.
+    /// Their ``instruction_indexes`` refer to :attr:`instructions`.
+    #[pyo3(get)]
+    pub transactions: Vec<PlannedTransaction>,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl AssociatedTokenAccountPlan {
+    #[new]
+    pub fn new(
+        missing: Vec<Pubkey>,
+        existing: Vec<Pubkey>,
+        instructions: Vec<Instruction>,
+        transactions: Vec<PlannedTransaction>,
+    ) -> Self {
+        Self {
+            missing,
+            existing,
+            instructions,
+            transactions,
+        }
+    }
+
+    /// bool: Whether every account already exists.
+    #[getter]
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for AssociatedTokenAccountPlan {}
+
+/// Work out which associated token accounts are missing and batch their creation.
+///
+/// Each ``(wallet, mint)`` pair is mapped to its associated token account.
+/// Duplicate pairs are planned once. An account only counts as existing if it is an
+/// initialized token account owned by ``token_program_id``. Every other account,
+/// including one that is missing, uninitialized or owned by another program, gets a
+/// ``CreateIdempotent`` instruction, and those instructions are packed into as few transactions as fit,
+/// as in :func:`~solders.bundle.plan_transactions`. Since the instructions are
+/// idempotent, an account created by someone else in the meantime doesn't fail
+/// the transaction.
+///
+/// Args:
+///     payer (Pubkey): Pays for the new accounts and the transaction fees.
+///     pairs (Sequence[Tuple[Pubkey, Pubkey]]): ``(wallet, mint)`` pairs.
+///     accounts (Union[AccountsMap, Dict[Pubkey, Account], Callable[[List[Pubkey]], Sequence[Optional[Account]]]]):
+///         The current account states, or a function that fetches them like
+///         ``getMultipleAccounts``. The function is called with at most 100 addresses at a time
+///         and must return one account, or ``None``, per address.
+///     token_program_id (Optional[Pubkey]): The Token or Token-2022 program. Defaults to Token.
+///     max_accounts (int): The maximum number of accounts per transaction. Defaults to 64.
+///
+/// Returns:
+///     AssociatedTokenAccountPlan: The missing accounts and the transactions that create them.
+///
+/// Raises:
+///     ValueError: If ``token_program_id`` isn't a token program or ``accounts``
+///         returns the wrong number of accounts.
+///
+/// Example:
+///     >>> from solders.presets import plan_associated_token_accounts, get_associated_token_address
+///     >>> from solders.account import Account
+///     >>> from solders.pubkey import Pubkey
+///     >>> token_program = Pubkey.from_string("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+///     >>> payer, mint = Pubkey.new_unique(), Pubkey.new_unique()
+///     >>> wallets = [Pubkey.new_unique() for _ in range(21)]
+///     >>> existing = get_associated_token_address(wallets[0], mint)
+///     >>> initialized = bytes(108) + b"\x01" + bytes(56)
+///     >>> accounts = {existing: Account(2039280, initialized, token_program)}
+///     >>> plan = plan_associated_token_accounts(payer, [(w, mint) for w in wallets], accounts)
+///     >>> len(plan.missing), len(plan.existing)
+///     (20, 1)
+///     >>> [len(tx.instruction_indexes) for tx in plan.transactions]
+///     [13, 7]
+///
+#[pyfunction]
+#[pyo3(signature = (payer, pairs, accounts, token_program_id = None, max_accounts = MAX_TX_ACCOUNT_LOCKS))]
+pub fn plan_associated_token_accounts(
+    py: Python<'_>,
+    payer: Pubkey,
+    pairs: Vec<(Pubkey, Pubkey)>,
+    accounts: AccountSource,
+    token_program_id: Option<Pubkey>,
+    max_accounts: usize,
+) -> PyResult<AssociatedTokenAccountPlan> {
+    let token_program_id = token_program(token_program_id)?;
+    let mut seen = HashSet::new();
+    let mut pairs_by_address: Vec<(PubkeyOriginal, PubkeyOriginal, PubkeyOriginal)> = Vec::new();
+    for (wallet, mint) in pairs {
+        let (wallet, mint) = (wallet.into(), mint.into());
+        let address = ata_address(&wallet, &mint, &token_program_id);
+        if seen.insert(address) {
+            pairs_by_address.push((address, wallet, mint));
+        }
+    }
+    let addresses: Vec<PubkeyOriginal> = pairs_by_address.iter().map(|(a, _, _)| *a).collect();
+    let exists = accounts.exists(py, &addresses, &token_program_id)?;
+    let mut missing = Vec::new();
+    let mut existing = Vec::new();
+    let mut instructions = Vec::new();
+    for ((address, wallet, mint), exists) in pairs_by_address.into_iter().zip(exists) {
+        if exists {
+            existing.push(address.into());
+        } else {
+            missing.push(address.into());
+            instructions.push(Instruction(create_ata_idempotent(
+                payer.as_ref(),
+                &wallet,
+                &mint,
+                &token_program_id,
+            )));
+        }
+    }
+    let transactions = plan_transactions(instructions.clone(), payer, None, max_accounts, false)?;
+    Ok(AssociatedTokenAccountPlan {
+        missing,
+        existing,
+        instructions,
+        transactions,
+    })
+}
+
+/// Why :func:`plan_token_sweep` leaves a token account alone.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[pyclass(module = "solders.presets")]
+pub enum SweepSkipReason {
+    /// The account isn't a parsed SPL Token or Token-2022 account.
+    NotTokenAccount,
+    /// The account belongs to another wallet.
+    WrongOwner,
+    /// The account is frozen, so it can neither be closed nor transferred from.
+    Frozen,
+    /// Only a separate close authority can close the account.
+    CloseAuthority,
+    /// The balance is above the mint's dust threshold.
+    HasBalance,
+    /// The account is the destination's associated token account for its mint.
+    Destination,
+}
+
+impl EnumString for SweepSkipReason {}
+
+#[enum_string]
+#[pymethods]
+impl SweepSkipReason {}
+
+/// The fields of a ``jsonParsed`` token account that decide how it's swept.
+struct SweepableAccount {
+    address: PubkeyOriginal,
+    token_program_id: PubkeyOriginal,
+    lamports: u64,
+    mint: PubkeyOriginal,
+    owner: PubkeyOriginal,
+    amount: u64,
+    decimals: u8,
+    frozen: bool,
+    is_native: bool,
+    close_authority: Option<PubkeyOriginal>,
+}
+
+impl SweepableAccount {
+    fn parse(address: PubkeyOriginal, account: &AccountJSON) -> Option<Self> {
+        let token_program_id = PubkeyOriginal::from(account.owner);
+        if token_program_id != TOKEN_PROGRAM_ID && token_program_id != TOKEN_2022_PROGRAM_ID {
+            return None;
+        }
+        let parsed = ParsedAccountOriginal::from(account.data.clone()).parsed;
+        if parsed.get("type")?.as_str()? != "account" {
+            return None;
+        }
+        let info = parsed.get("info")?;
+        let pubkey = |v: &Value| v.as_str()?.parse::<PubkeyOriginal>().ok();
+        let token_amount = info.get("tokenAmount")?;
+        Some(Self {
+            address,
+            token_program_id,
+            lamports: account.lamports,
+            mint: pubkey(info.get("mint")?)?,
+            owner: pubkey(info.get("owner")?)?,
+            amount: token_amount.get("amount")?.as_str()?.parse().ok()?,
+            decimals: token_amount.get("decimals")?.as_u64()?.try_into().ok()?,
+            frozen: info.get("state")?.as_str()? == "frozen",
+            is_native: info.get("isNative")?.as_bool()?,
+            close_authority: match info.get("closeAuthority") {
+                None | Some(Value::Null) => None,
+                Some(v) => Some(pubkey(v)?),
+            },
+        })
+    }
+}
+
+/// The result of :func:`plan_token_sweep`.
+#[pyclass(module = "solders.presets", subclass)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenSweepPlan {
+    /// List[Pubkey]: The token accounts the plan closes, in input order.
+    #[pyo3(get)]
+    pub closed: Vec<Pubkey>,
+    /// List[Pubkey]: The closed accounts whose dust is first moved to the destination.
+    #[pyo3(get)]
+    pub consolidated: Vec<Pubkey>,
+    /// List[Tuple[Pubkey, SweepSkipReason]]: The accounts left alone, and why.
+    #[pyo3(get)]
+    pub skipped: Vec<(Pubkey, SweepSkipReason)>,
+    /// int: The lamports the closed accounts hold, including wrapped SOL balances.
+    #[pyo3(get)]
+    pub reclaimed_lamports: u64,
+    /// List[Instruction]: The create, transfer and close instructions.
+    #[pyo3(get)]
+    pub instructions: Vec<Instruction>,
+    /// List[PlannedTransaction]: The instructions packed into transactions.
+    /// Their ``instruction_indexes`` refer to :attr:`instructions`.
+    #[pyo3(get)]
+    pub transactions: Vec<PlannedTransaction>,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl TokenSweepPlan {
+    #[new]
+    pub fn new(
+        closed: Vec<Pubkey>,
+        consolidated: Vec<Pubkey>,
+        skipped: Vec<(Pubkey, SweepSkipReason)>,
+        reclaimed_lamports: u64,
+        instructions: Vec<Instruction>,
+        transactions: Vec<PlannedTransaction>,
+    ) -> Self {
+        Self {
+            closed,
+            consolidated,
+            skipped,
+            reclaimed_lamports,
+            instructions,
+            transactions,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for TokenSweepPlan {}
+
+/// Plan closing a wallet's token accounts to reclaim their rent.
+///
+/// Empty accounts and wrapped SOL accounts are closed; closing wrapped SOL returns
+/// its balance along with the rent. Accounts holding at most their mint's
+/// ``dust_thresholds`` amount first transfer it to ``destination``'s associated token
+/// account, which is created if missing, and are then closed. Frozen accounts,
+/// accounts with a separate close authority, accounts of other wallets,
+/// ``destination``'s own associated token accounts, even empty ones, and accounts
+/// with a larger balance are skipped. The instructions are packed into
+/// as few transactions as fit, as in :func:`~solders.bundle.plan_transactions`;
+/// every transaction must be signed by ``owner`` and ``payer``.
+///
+/// Args:
+///     owner (Pubkey): The wallet owning the token accounts.
+///     token_accounts (Sequence[RpcKeyedAccountJsonParsed]): The wallet's token accounts,
+///         e.g. from ``getTokenAccountsByOwner`` with ``jsonParsed`` encoding.
+///     destination (Pubkey): Receives the rent and, in its associated token accounts, the dust.
+///     dust_thresholds (Optional[Dict[Pubkey, int]]): Per mint, the largest raw amount
+///         worth consolidating. Accounts of other mints are only closed if empty.
+///     payer (Optional[Pubkey]): Pays for destination accounts and fees. Defaults to ``owner``.
+///     max_accounts (int): The maximum number of accounts per transaction. Defaults to 64.
+///
+/// Returns:
+///     TokenSweepPlan: The accounts to close and the transactions that close them.
+///
+/// Raises:
+///     ValueError: If an instruction doesn't fit in a transaction.
+///
+/// Example:
+///     >>> from solders.presets import plan_token_sweep, NATIVE_MINT, SweepSkipReason
+///     >>> from solders.account import AccountJSON
+///     >>> from solders.account_decoder import ParsedAccount
+///     >>> from solders.rpc.responses import RpcKeyedAccountJsonParsed
+///     >>> from solders.pubkey import Pubkey
+///     >>> token_program = Pubkey.from_string("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+///     >>> owner, usdc = Pubkey.new_unique(), Pubkey.new_unique()
+///     >>> def account(mint, amount, state="initialized"):
+///     ...     info = {"mint": str(mint), "owner": str(owner), "state": state, "isNative": mint == NATIVE_MINT,
+///     ...             "tokenAmount": {"amount": str(amount), "decimals": 6, "uiAmount": None, "uiAmountString": ""}}
+///     ...     data = ParsedAccount("spl-token", {"type": "account", "info": info}, 165)
+///     ...     return RpcKeyedAccountJsonParsed(Pubkey.new_unique(), AccountJSON(2039280, data, token_program))
+///     >>> accounts = [account(usdc, 0), account(usdc, 5), account(usdc, 10**9), account(NATIVE_MINT, 10**6), account(usdc, 0, "frozen")]
+///     >>> plan = plan_token_sweep(owner, accounts, Pubkey.new_unique(), {usdc: 100})
+///     >>> len(plan.closed), len(plan.consolidated), plan.reclaimed_lamports
+///     (3, 1, 6117840)
+///     >>> [reason for _, reason in plan.skipped] == [SweepSkipReason.HasBalance, SweepSkipReason.Frozen]
+///     True
+///     >>> len(plan.instructions), len(plan.transactions)
+///     (5, 1)
+///
+#[pyfunction]
+#[pyo3(signature = (owner, token_accounts, destination, dust_thresholds = None, payer = None, max_accounts = MAX_TX_ACCOUNT_LOCKS))]
+pub fn plan_token_sweep(
+    owner: Pubkey,
+    token_accounts: Vec<RpcKeyedAccountJsonParsed>,
+    destination: Pubkey,
+    dust_thresholds: Option<HashMap<Pubkey, u64>>,
+    payer: Option<Pubkey>,
+    max_accounts: usize,
+) -> PyResult<TokenSweepPlan> {
+    let dust_thresholds = dust_thresholds.unwrap_or_default();
+    let payer = payer.unwrap_or(owner);
+    let (owner, destination) = (
+        PubkeyOriginal::from(owner),
+        PubkeyOriginal::from(destination),
+    );
+    let mut closed = Vec::new();
+    let mut consolidated = Vec::new();
+    let mut skipped = Vec::new();
+    let mut reclaimed_lamports = 0u64;
+    let mut instructions = Vec::new();
+    let mut dependencies = Vec::new();
+    let mut created: HashMap<(PubkeyOriginal, PubkeyOriginal), usize> = HashMap::new();
+    let mut seen = HashSet::new();
+    for keyed in token_accounts {
+        if !seen.insert(keyed.pubkey) {
+            continue;
+        }
+        let account = match SweepableAccount::parse(keyed.pubkey.into(), &keyed.account) {
+            Some(account) => account,
+            None => {
+                skipped.push((keyed.pubkey, SweepSkipReason::NotTokenAccount));
+                continue;
+            }
+        };
+        let dust_threshold = dust_thresholds.get(&Pubkey(account.mint)).copied();
+        let destination_ata =
+            || ata_address(&destination, &account.mint, &account.token_program_id);
+        let skip = if account.owner != owner {
+            Some(SweepSkipReason::WrongOwner)
+        } else if account.frozen {
+            Some(SweepSkipReason::Frozen)
+        } else if account.close_authority.map_or(false, |a| a != owner) {
+            Some(SweepSkipReason::CloseAuthority)
+        } else if account.address == destination_ata() {
+            Some(SweepSkipReason::Destination)
+        } else if account.is_native || account.amount == 0 {
+            None
+        } else if dust_threshold.map_or(true, |t| account.amount > t) {
+            Some(SweepSkipReason::HasBalance)
+        } else {
+            None
+        };
+        if let Some(reason) = skip {
+            skipped.push((keyed.pubkey, reason));
+            continue;
+        }
+        let mut close_after = None;
+        if !account.is_native && account.amount > 0 {
+            let key = (account.mint, account.token_program_id);
+            let create = *created.entry(key).or_insert_with(|| {
+                instructions.push(Instruction(create_ata_idempotent(
+                    payer.as_ref(),
+                    &destination,
+                    &account.mint,
+                    &account.token_program_id,
+                )));
+                instructions.len() - 1
+            });
+            instructions.push(Instruction(transfer_checked(
+                &account.address,
+                &account.mint,
+                &destination_ata(),
+                &owner,
+                account.amount,
+                account.decimals,
+                &account.token_program_id,
+            )));
+            let transfer = instructions.len() - 1;
+            dependencies.push((create, transfer));
+            close_after = Some(transfer);
+            consolidated.push(keyed.pubkey);
+        }
+        instructions.push(Instruction(close_account(
+            &account.address,
+            &destination,
+            &owner,
+            &account.token_program_id,
+        )));
+        if let Some(transfer) = close_after {
+            dependencies.push((transfer, instructions.len() - 1));
+        }
+        closed.push(keyed.pubkey);
+        reclaimed_lamports += account.lamports;
+    }
+    let transactions = plan_transactions(
+        instructions.clone(),
+        payer,
+        Some(dependencies),
+        max_accounts,
+        false,
+    )?;
+    Ok(TokenSweepPlan {
+        closed,
+        consolidated,
+        skipped,
+        reclaimed_lamports,
+        instructions,
+        transactions,
+    })
+}
+
+pub(crate) fn create_presets_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "presets")?;
+    m.add("NATIVE_MINT", Pubkey::from(NATIVE_MINT))?;
+    m.add("NATIVE_MINT_2022", Pubkey::from(NATIVE_MINT_2022))?;
+    m.add_function(wrap_pyfunction!(get_associated_token_address, m)?)?;
+    m.add_function(wrap_pyfunction!(filter_associated_token_accounts, m)?)?;
+    m.add_function(wrap_pyfunction!(transfer_to_wallet, m)?)?;
+    m.add_function(wrap_pyfunction!(wrap_sol_around, m)?)?;
+    m.add_function(wrap_pyfunction!(close_token_accounts, m)?)?;
+    m.add_class::<AssociatedTokenAccountPlan>()?;
+    m.add_function(wrap_pyfunction!(plan_associated_token_accounts, m)?)?;
+    m.add_class::<SweepSkipReason>()?;
+    m.add_class::<TokenSweepPlan>()?;
+    m.add_function(wrap_pyfunction!(plan_token_sweep, m)?)?;
+    Ok(m)
+}
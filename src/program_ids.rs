@@ -0,0 +1,13 @@
+use solana_sdk::{pubkey, pubkey::Pubkey as PubkeyOriginal};
+
+pub(crate) const MEMO_PROGRAM_ID: PubkeyOriginal =
+    pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+pub(crate) const MEMO_V1_PROGRAM_ID: PubkeyOriginal =
+    pubkey!("Memo1UhkJRfHyvLMcVucJwxXeuD728EqVDDwQDxFMNo");
+/// Not exported by solana-sdk 1.14, which only has the table account type.
+pub(crate) const ADDRESS_LOOKUP_TABLE_PROGRAM_ID: PubkeyOriginal =
+    pubkey!("AddressLookupTab1e1111111111111111111111111");
+pub(crate) const NATIVE_MINT: PubkeyOriginal =
+    pubkey!("So11111111111111111111111111111111111111112");
+pub(crate) const NATIVE_MINT_2022: PubkeyOriginal =
+    pubkey!("9pan9bMn5HatX4EJdBwg9VgCa7Uz5HL8N1m5D3NdXejP");
@@ -0,0 +1,542 @@
+use std::collections::{HashMap, HashSet};
+
+use pyo3::{
+    exceptions::{PyKeyError, PyTypeError},
+    prelude::*,
+    types::{PyBytes, PyTuple},
+    PyTraverseError, PyVisit,
+};
+use solana_sdk::{
+    pubkey::{Pubkey as PubkeyOriginal, PUBKEY_BYTES},
+    signature::{Signature as SignatureOriginal, SIGNATURE_BYTES},
+};
+use solders_macros::richcmp_eq_only;
+use solders_primitives::{pubkey::Pubkey, signature::Signature};
+use solders_traits::{to_py_value_err, RichcmpEqualityOnly};
+
+/// Split a buffer of concatenated fixed-width values.
+fn split_buffer(data: &[u8], width: usize) -> PyResult<std::slice::ChunksExact<'_, u8>> {
+    if data.len() % width != 0 {
+        return Err(to_py_value_err(&format!(
+            "buffer length {} is not a multiple of {}",
+            data.len(),
+            width
+        )));
+    }
+    Ok(data.chunks_exact(width))
+}
+
+/// Split a buffer of concatenated 32-byte pubkeys.
+fn pubkeys_from_buffer(data: &[u8]) -> PyResult<impl Iterator<Item = PubkeyOriginal> + '_> {
+    Ok(split_buffer(data, PUBKEY_BYTES)?.map(PubkeyOriginal::new))
+}
+
+/// Concatenate fixed-width values into one buffer.
+fn pack<T: AsRef<[u8]>>(values: impl ExactSizeIterator<Item = T>, width: usize) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(values.len() * width);
+    for value in values {
+        bytes.extend_from_slice(value.as_ref());
+    }
+    bytes
+}
+
+/// An iterator over the keys of a :class:`PubkeySet` or :class:`PubkeyMap`.
+///
+/// It holds a copy of the raw 32-byte keys taken when iteration started, so changing
+/// the collection while iterating is safe, and creates one
+/// :class:`~solders.pubkey.Pubkey` per step rather than a list of them up front.
+#[pyclass(module = "solders.pubkey_collections")]
+pub struct PubkeyIterator(std::vec::IntoIter<PubkeyOriginal>);
+
+impl PubkeyIterator {
+    fn new<'a>(pubkeys: impl Iterator<Item = &'a PubkeyOriginal>) -> Self {
+        Self(pubkeys.copied().collect::<Vec<_>>().into_iter())
+    }
+}
+
+#[pymethods]
+impl PubkeyIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self) -> Option<Pubkey> {
+        self.0.next().map(Pubkey::from)
+    }
+
+    fn __length_hint__(&self) -> usize {
+        self.0.len()
+    }
+}
+
+fn sorted_pubkeys<'a>(pubkeys: impl Iterator<Item = &'a PubkeyOriginal>) -> Vec<Pubkey> {
+    let mut sorted: Vec<PubkeyOriginal> = pubkeys.copied().collect();
+    sorted.sort_unstable();
+    sorted.into_iter().map(Pubkey::from).collect()
+}
+
+/// A set of pubkeys stored in Rust.
+///
+/// Uses far less memory than a Python ``set`` of :class:`~solders.pubkey.Pubkey`
+/// or ``bytes``, and bulk operations don't create a Python object per key.
+/// :meth:`to_list` and ``bytes()`` return the pubkeys sorted; iteration, like a
+/// Python ``set``, is in arbitrary order and creates each pubkey as it's reached.
+///
+/// Args:
+///     pubkeys (Optional[Sequence[Pubkey]]): The initial members.
+///
+/// Example:
+///     >>> from solders.pubkey_collections import PubkeySet
+///     >>> from solders.pubkey import Pubkey
+///     >>> seen = PubkeySet()
+///     >>> a, b = Pubkey.new_unique(), Pubkey.new_unique()
+///     >>> seen.insert_new([a, b, a]) == [a, b]
+///     True
+///     >>> seen.insert_new([a]), len(seen)
+///     ([], 2)
+///
+#[pyclass(module = "solders.pubkey_collections", subclass)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PubkeySet(HashSet<PubkeyOriginal>);
+
+#[richcmp_eq_only]
+#[pymethods]
+impl PubkeySet {
+    #[new]
+    #[pyo3(signature = (pubkeys = None))]
+    pub fn new(pubkeys: Option<Vec<Pubkey>>) -> Self {
+        Self(
+            pubkeys
+                .unwrap_or_default()
+                .into_iter()
+                .map(PubkeyOriginal::from)
+                .collect(),
+        )
+    }
+
+    /// Build a set from concatenated 32-byte pubkeys.
+    ///
+    /// Args:
+    ///     data (bytes): The pubkeys.
+    ///
+    /// Returns:
+    ///     PubkeySet: The set.
+    ///
+    /// Raises:
+    ///     ValueError: If the length of ``data`` isn't a multiple of 32.
+    ///
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<Self> {
+        Ok(Self(pubkeys_from_buffer(data)?.collect()))
+    }
+
+    /// Add a pubkey.
+    ///
+    /// Args:
+    ///     pubkey (Pubkey): The pubkey.
+    ///
+    /// Returns:
+    ///     bool: Whether the pubkey was not already present.
+    ///
+    pub fn add(&mut self, pubkey: Pubkey) -> bool {
+        self.0.insert(pubkey.into())
+    }
+
+    /// Remove a pubkey if present.
+    ///
+    /// Args:
+    ///     pubkey (Pubkey): The pubkey.
+    ///
+    /// Returns:
+    ///     bool: Whether the pubkey was present.
+    ///
+    pub fn discard(&mut self, pubkey: Pubkey) -> bool {
+        self.0.remove(pubkey.as_ref())
+    }
+
+    /// Add several pubkeys.
+    ///
+    /// Args:
+    ///     pubkeys (Sequence[Pubkey]): The pubkeys.
+    ///
+    /// Returns:
+    ///     int: How many were not already present.
+    ///
+    pub fn update(&mut self, pubkeys: Vec<Pubkey>) -> usize {
+        let before = self.0.len();
+        self.0.extend(pubkeys.into_iter().map(PubkeyOriginal::from));
+        self.0.len() - before
+    }
+
+    /// Add concatenated 32-byte pubkeys.
+    ///
+    /// Args:
+    ///     data (bytes): The pubkeys.
+    ///
+    /// Returns:
+    ///     int: How many were not already present.
+    ///
+    /// Raises:
+    ///     ValueError: If the length of ``data`` isn't a multiple of 32.
+    ///
+    pub fn update_from_bytes(&mut self, data: &[u8]) -> PyResult<usize> {
+        let before = self.0.len();
+        self.0.extend(pubkeys_from_buffer(data)?);
+        Ok(self.0.len() - before)
+    }
+
+    /// Add pubkeys and return the ones that were not already present.
+    ///
+    /// This is the dedup step of an indexer in one call.
+    ///
+    /// Args:
+    ///     pubkeys (Sequence[Pubkey]): The pubkeys.
+    ///
+    /// Returns:
+    ///     List[Pubkey]: The new pubkeys, in input order and without repeats.
+    ///
+    pub fn insert_new(&mut self, pubkeys: Vec<Pubkey>) -> Vec<Pubkey> {
+        pubkeys
+            .into_iter()
+            .filter(|pubkey| self.0.insert(pubkey.0))
+            .collect()
+    }
+
+    /// Returns:
+    ///     PubkeySet: The pubkeys in either set.
+    ///
+    pub fn union(&self, other: &Self) -> Self {
+        Self(self.0.union(&other.0).copied().collect())
+    }
+
+    /// Returns:
+    ///     PubkeySet: The pubkeys in both sets.
+    ///
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self(self.0.intersection(&other.0).copied().collect())
+    }
+
+    /// Returns:
+    ///     PubkeySet: The pubkeys in this set but not ``other``.
+    ///
+    pub fn difference(&self, other: &Self) -> Self {
+        Self(self.0.difference(&other.0).copied().collect())
+    }
+
+    /// Returns:
+    ///     List[Pubkey]: The members, sorted.
+    ///
+    pub fn to_list(&self) -> Vec<Pubkey> {
+        sorted_pubkeys(self.0.iter())
+    }
+
+    /// The members as concatenated 32-byte pubkeys, sorted. The inverse of :meth:`from_bytes`.
+    pub fn __bytes__<'a>(&self, py: Python<'a>) -> &'a PyBytes {
+        let mut sorted: Vec<&PubkeyOriginal> = self.0.iter().collect();
+        sorted.sort_unstable();
+        PyBytes::new(py, &pack(sorted.into_iter(), PUBKEY_BYTES))
+    }
+
+    fn __or__(&self, other: &Self) -> Self {
+        self.union(other)
+    }
+
+    fn __and__(&self, other: &Self) -> Self {
+        self.intersection(other)
+    }
+
+    fn __sub__(&self, other: &Self) -> Self {
+        self.difference(other)
+    }
+
+    fn __contains__(&self, pubkey: Pubkey) -> bool {
+        self.0.contains(pubkey.as_ref())
+    }
+
+    fn __len__(&self) -> usize {
+        self.0.len()
+    }
+
+    fn __iter__(&self) -> PubkeyIterator {
+        PubkeyIterator::new(self.0.iter())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("PubkeySet(len={})", self.0.len())
+    }
+}
+
+impl RichcmpEqualityOnly for PubkeySet {}
+
+/// A mapping from pubkeys to arbitrary values, with keys stored in Rust.
+///
+/// Args:
+///     mapping (Optional[Dict[Pubkey, Any]]): The initial items.
+///
+/// Example:
+///     >>> from solders.pubkey_collections import PubkeyMap
+///     >>> from solders.pubkey import Pubkey
+///     >>> a, b = Pubkey.new_unique(), Pubkey.new_unique()
+///     >>> balances = PubkeyMap.from_bytes(bytes(a) + bytes(b), [10, 20])
+///     >>> balances[b], balances.get(Pubkey.new_unique(), 0)
+///     (20, 0)
+///
+#[pyclass(module = "solders.pubkey_collections", subclass)]
+#[derive(Clone, Debug, Default)]
+pub struct PubkeyMap(HashMap<PubkeyOriginal, PyObject>);
+
+#[pymethods]
+impl PubkeyMap {
+    #[new]
+    #[pyo3(signature = (mapping = None))]
+    pub fn new(mapping: Option<HashMap<Pubkey, PyObject>>) -> Self {
+        Self(
+            mapping
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(k, v)| (k.into(), v))
+                .collect(),
+        )
+    }
+
+    /// Build a map from concatenated 32-byte pubkeys and their values.
+    ///
+    /// Later duplicates of a key overwrite earlier ones.
+    ///
+    /// Args:
+    ///     keys (bytes): The pubkeys.
+    ///     values (Sequence[Any]): One value per pubkey.
+    ///
+    /// Returns:
+    ///     PubkeyMap: The map.
+    ///
+    /// Raises:
+    ///     ValueError: If the length of ``keys`` isn't a multiple of 32 or the
+    ///         number of values doesn't match.
+    ///
+    #[staticmethod]
+    pub fn from_bytes(keys: &[u8], values: Vec<PyObject>) -> PyResult<Self> {
+        let pubkeys = pubkeys_from_buffer(keys)?;
+        let num_keys = keys.len() / PUBKEY_BYTES;
+        if num_keys != values.len() {
+            return Err(to_py_value_err(&format!(
+                "got {} values for {} keys",
+                values.len(),
+                num_keys
+            )));
+        }
+        Ok(Self(pubkeys.zip(values).collect()))
+    }
+
+    /// Look up a value.
+    ///
+    /// Args:
+    ///     pubkey (Pubkey): The key.
+    ///     default (Optional[Any]): Returned if the key is missing.
+    ///
+    /// Returns:
+    ///     Any: The value, or ``default``.
+    ///
+    #[pyo3(signature = (pubkey, default = None))]
+    pub fn get(&self, pubkey: Pubkey, default: Option<PyObject>) -> Option<PyObject> {
+        self.0.get(pubkey.as_ref()).cloned().or(default)
+    }
+
+    /// Remove a key and return its value.
+    ///
+    /// Like ``dict.pop``, ``default`` is positional and may be ``None``.
+    ///
+    /// Args:
+    ///     pubkey (Pubkey): The key.
+    ///     default (Any): Returned if the key is missing.
+    ///
+    /// Returns:
+    ///     Any: The removed value, or ``default``.
+    ///
+    /// Raises:
+    ///     KeyError: If the key is missing and no default is given.
+    ///
+    #[pyo3(signature = (pubkey, *default))]
+    pub fn pop(&mut self, pubkey: Pubkey, default: &PyTuple) -> PyResult<PyObject> {
+        if default.len() > 1 {
+            return Err(PyTypeError::new_err(format!(
+                "pop expected at most 2 arguments, got {}",
+                default.len() + 1
+            )));
+        }
+        match self.0.remove(pubkey.as_ref()) {
+            Some(value) => Ok(value),
+            None if default.is_empty() => Err(PyKeyError::new_err(pubkey.to_string())),
+            None => Ok(default.get_item(0)?.into()),
+        }
+    }
+
+    /// Returns:
+    ///     PubkeySet: The keys.
+    ///
+    pub fn key_set(&self) -> PubkeySet {
+        PubkeySet(self.0.keys().copied().collect())
+    }
+
+    /// Returns:
+    ///     List[Pubkey]: The keys, sorted.
+    ///
+    pub fn keys(&self) -> Vec<Pubkey> {
+        sorted_pubkeys(self.0.keys())
+    }
+
+    /// Returns:
+    ///     List[Tuple[Pubkey, Any]]: The items, sorted by key.
+    ///
+    pub fn items(&self) -> Vec<(Pubkey, PyObject)> {
+        self.keys()
+            .into_iter()
+            .map(|pubkey| {
+                let value = self.0[pubkey.as_ref()].clone();
+                (pubkey, value)
+            })
+            .collect()
+    }
+
+    /// Returns:
+    ///     Dict[Pubkey, Any]: The items as a ``dict``.
+    ///
+    pub fn to_dict(&self) -> HashMap<Pubkey, PyObject> {
+        self.0
+            .iter()
+            .map(|(k, v)| ((*k).into(), v.clone()))
+            .collect()
+    }
+
+    fn __getitem__(&self, pubkey: Pubkey) -> PyResult<PyObject> {
+        self.0
+            .get(pubkey.as_ref())
+            .cloned()
+            .ok_or_else(|| PyKeyError::new_err(pubkey.to_string()))
+    }
+
+    fn __setitem__(&mut self, pubkey: Pubkey, value: PyObject) {
+        self.0.insert(pubkey.into(), value);
+    }
+
+    fn __delitem__(&mut self, pubkey: Pubkey) -> PyResult<()> {
+        self.0
+            .remove(pubkey.as_ref())
+            .map(|_| ())
+            .ok_or_else(|| PyKeyError::new_err(pubkey.to_string()))
+    }
+
+    fn __contains__(&self, pubkey: Pubkey) -> bool {
+        self.0.contains_key(pubkey.as_ref())
+    }
+
+    fn __len__(&self) -> usize {
+        self.0.len()
+    }
+
+    fn __iter__(&self) -> PubkeyIterator {
+        PubkeyIterator::new(self.0.keys())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("PubkeyMap(len={})", self.0.len())
+    }
+
+    fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
+        self.0.values().try_for_each(|value| visit.call(value))
+    }
+
+    fn __clear__(&mut self) {
+        self.0.clear();
+    }
+}
+
+/// Pack pubkeys into one contiguous buffer of 32-byte rows.
+///
+/// The buffer can be viewed as a numpy array without a Python loop, e.g.
+/// ``np.frombuffer(buf, dtype=np.uint8).reshape(-1, 32)`` or ``dtype="S32"``.
+///
+/// Args:
+///     pubkeys (Sequence[Pubkey]): The pubkeys.
+///
+/// Returns:
+///     bytes: ``32 * len(pubkeys)`` bytes, one pubkey after another.
+///
+/// Example:
+///     >>> from solders.pubkey_collections import pubkeys_to_array, array_to_pubkeys
+///     >>> from solders.pubkey import Pubkey
+///     >>> pubkeys = [Pubkey.new_unique(), Pubkey.new_unique()]
+///     >>> buf = pubkeys_to_array(pubkeys)
+///     >>> len(buf)
+///     64
+///     >>> array_to_pubkeys(buf) == pubkeys
+///     True
+///
+#[pyfunction]
+pub fn pubkeys_to_array<'a>(py: Python<'a>, pubkeys: Vec<Pubkey>) -> &'a PyBytes {
+    let values = pubkeys.into_iter().map(PubkeyOriginal::from);
+    PyBytes::new(py, &pack(values, PUBKEY_BYTES))
+}
+
+/// Unpack a buffer made by :func:`pubkeys_to_array`, such as ``array.tobytes()``.
+///
+/// Args:
+///     data (bytes): Concatenated 32-byte pubkeys.
+///
+/// Returns:
+///     List[Pubkey]: The pubkeys, in buffer order.
+///
+/// Raises:
+///     ValueError: If the length isn't a multiple of 32.
+///
+#[pyfunction]
+pub fn array_to_pubkeys(data: &[u8]) -> PyResult<Vec<Pubkey>> {
+    Ok(pubkeys_from_buffer(data)?.map(Pubkey::from).collect())
+}
+
+/// Pack signatures into one contiguous buffer of 64-byte rows.
+///
+/// The buffer can be viewed as a numpy array without a Python loop, e.g.
+/// ``np.frombuffer(buf, dtype=np.uint8).reshape(-1, 64)`` or ``dtype="S64"``.
+///
+/// Args:
+///     signatures (Sequence[Signature]): The signatures.
+///
+/// Returns:
+///     bytes: ``64 * len(signatures)`` bytes, one signature after another.
+///
+#[pyfunction]
+pub fn signatures_to_array<'a>(py: Python<'a>, signatures: Vec<Signature>) -> &'a PyBytes {
+    let values = signatures.into_iter().map(SignatureOriginal::from);
+    PyBytes::new(py, &pack(values, SIGNATURE_BYTES))
+}
+
+/// Unpack a buffer made by :func:`signatures_to_array`, such as ``array.tobytes()``.
+///
+/// Args:
+///     data (bytes): Concatenated 64-byte signatures.
+///
+/// Returns:
+///     List[Signature]: The signatures, in buffer order.
+///
+/// Raises:
+///     ValueError: If the length isn't a multiple of 64.
+///
+#[pyfunction]
+pub fn array_to_signatures(data: &[u8]) -> PyResult<Vec<Signature>> {
+    Ok(split_buffer(data, SIGNATURE_BYTES)?
+        .map(|chunk| SignatureOriginal::new(chunk).into())
+        .collect())
+}
+
+pub(crate) fn create_pubkey_collections_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "pubkey_collections")?;
+    m.add_class::<PubkeySet>()?;
+    m.add_class::<PubkeyMap>()?;
+    m.add_class::<PubkeyIterator>()?;
+    m.add_function(wrap_pyfunction!(pubkeys_to_array, m)?)?;
+    m.add_function(wrap_pyfunction!(array_to_pubkeys, m)?)?;
+    m.add_function(wrap_pyfunction!(signatures_to_array, m)?)?;
+    m.add_function(wrap_pyfunction!(array_to_signatures, m)?)?;
+    Ok(m)
+}
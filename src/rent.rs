@@ -0,0 +1,107 @@
+use derive_more::{From, Into};
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use solana_sdk::rent::Rent as RentOriginal;
+use solders_macros::{common_methods, richcmp_eq_only};
+
+use solders_traits::{
+    impl_display, py_from_bytes_general_via_bincode, pybytes_general_via_bincode,
+    RichcmpEqualityOnly,
+};
+
+/// Configuration of network rent.
+///
+/// ``bytes(rent)`` gives the Rent sysvar account data, so custom values
+/// can be injected into test environments.
+///
+/// Args:
+///     lamports_per_byte_year (int): Rental rate.
+///     exemption_threshold (float): Amount of time (in years) a balance must include rent for the account to be rent exempt.
+///     burn_percent (int): The percentage of collected rent that is burned.
+///
+/// Example:
+///     >>> from solders.rent import Rent
+///     >>> rent = Rent.default()
+///     >>> Rent.from_bytes(bytes(rent)) == rent
+///     True
+///     >>> rent.minimum_balance(0)
+///     890880
+///
+#[pyclass(module = "solders.rent", subclass)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default, From, Into)]
+pub struct Rent(RentOriginal);
+
+#[richcmp_eq_only]
+#[common_methods]
+#[pymethods]
+impl Rent {
+    #[new]
+    pub fn new(lamports_per_byte_year: u64, exemption_threshold: f64, burn_percent: u8) -> Self {
+        RentOriginal {
+            lamports_per_byte_year,
+            exemption_threshold,
+            burn_percent,
+        }
+        .into()
+    }
+
+    /// int: Rental rate.
+    #[getter]
+    pub fn lamports_per_byte_year(&self) -> u64 {
+        self.0.lamports_per_byte_year
+    }
+
+    /// float: Amount of time (in years) a balance must include rent for the account to be rent exempt.
+    #[getter]
+    pub fn exemption_threshold(&self) -> f64 {
+        self.0.exemption_threshold
+    }
+
+    /// int: The percentage of collected rent that is burned.
+    #[getter]
+    pub fn burn_percent(&self) -> u8 {
+        self.0.burn_percent
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "default")]
+    /// Create a new default Rent.
+    ///
+    /// Returns:
+    ///     Rent: The default Rent.
+    ///
+    pub fn new_default() -> Self {
+        Self::default()
+    }
+
+    /// Calculate how much rent to burn from the collected rent.
+    ///
+    /// Returns:
+    ///     Tuple[int, int]: The amount to burn and the amount to distribute.
+    ///
+    pub fn calculate_burn(&self, rent_collected: u64) -> (u64, u64) {
+        self.0.calculate_burn(rent_collected)
+    }
+
+    /// Minimum balance due for rent-exemption of a given account data size.
+    pub fn minimum_balance(&self, data_len: usize) -> u64 {
+        self.0.minimum_balance(data_len)
+    }
+
+    /// Whether a given balance and data length would be exempt.
+    pub fn is_exempt(&self, balance: u64, data_len: usize) -> bool {
+        self.0.is_exempt(balance, data_len)
+    }
+}
+
+impl_display!(Rent);
+pybytes_general_via_bincode!(Rent);
+py_from_bytes_general_via_bincode!(Rent);
+solders_traits::common_methods_default!(Rent);
+impl RichcmpEqualityOnly for Rent {}
+
+pub(crate) fn create_rent_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "rent")?;
+    m.add_class::<Rent>()?;
+    Ok(m)
+}
@@ -4,19 +4,32 @@ use crate::rpc::tmp_config as rpc_config;
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
 use solana_sdk::commitment_config::CommitmentLevel as CommitmentLevelOriginal;
-use solders_macros::{common_methods, richcmp_eq_only, EnumIntoPy};
+use solders_macros::{common_methods, enum_string, richcmp_eq_only, EnumIntoPy};
 use solders_primitives::{hash::Hash as SolderHash, pubkey::Pubkey, signature::Signature};
 use solders_traits::{
-    impl_display, py_from_bytes_general_via_cbor, pybytes_general_via_cbor, RichcmpEqualityOnly,
+    impl_display, py_from_bytes_general_via_cbor, pybytes_general_via_cbor, EnumString,
+    RichcmpEqualityOnly,
 };
 
 use crate::{
     account_decoder::{UiAccountEncoding, UiDataSliceConfig},
     commitment_config::CommitmentLevel,
+    layouts::{
+        STAKE_ACCOUNT_LEN, STAKE_AUTHORIZED_STAKER_OFFSET, STAKE_AUTHORIZED_WITHDRAWER_OFFSET,
+        TOKEN_ACCOUNT_LEN, TOKEN_ACCOUNT_MINT_OFFSET, TOKEN_ACCOUNT_OWNER_OFFSET,
+    },
     transaction_status::{TransactionDetails, UiTransactionEncoding},
 };
 
-use super::filter::RpcFilterType;
+use super::filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType};
+
+fn pubkey_memcmp(offset: usize, pubkey: &Pubkey) -> RpcFilterType {
+    RpcFilterType::Memcmp(Memcmp::new(
+        offset,
+        MemcmpEncodedBytes::Base58(pubkey.to_string()),
+        None,
+    ))
+}
 
 macro_rules! rpc_config_impls {
     ($ident:ident) => {
@@ -540,6 +553,12 @@ pub enum RpcLargestAccountsFilter {
     NonCirculating,
 }
 
+impl EnumString for RpcLargestAccountsFilter {}
+
+#[enum_string]
+#[pymethods]
+impl RpcLargestAccountsFilter {}
+
 impl From<RpcLargestAccountsFilter> for rpc_config::RpcLargestAccountsFilter {
     fn from(f: RpcLargestAccountsFilter) -> Self {
         match f {
@@ -817,6 +836,124 @@ impl RpcProgramAccountsConfig {
         self.0.account_config.clone().into()
     }
 
+    /// Scan the SPL Token program for all token accounts owned by a wallet.
+    ///
+    /// Use with the SPL Token program ID. The ``DataSize(165)`` filter only matches
+    /// accounts without extensions, so with the Token-2022 program ID it misses
+    /// accounts that have any extension.
+    ///
+    /// Args:
+    ///     owner (Pubkey): The wallet that owns the token accounts.
+    ///     account_config (Optional[RpcAccountInfoConfig]): Account info config.
+    ///
+    /// Returns:
+    ///     RpcProgramAccountsConfig: The config.
+    ///
+    /// Example:
+    ///     >>> from solders.pubkey import Pubkey
+    ///     >>> from solders.rpc.config import RpcProgramAccountsConfig
+    ///     >>> config = RpcProgramAccountsConfig.token_accounts_by_owner(Pubkey.default())
+    ///     >>> config.filters[0]
+    ///     165
+    ///     >>> config.filters[1].offset
+    ///     32
+    ///
+    #[staticmethod]
+    pub fn token_accounts_by_owner(
+        owner: Pubkey,
+        account_config: Option<RpcAccountInfoConfig>,
+    ) -> Self {
+        Self::new(
+            account_config.unwrap_or_default(),
+            Some(vec![
+                RpcFilterType::DataSize(TOKEN_ACCOUNT_LEN as u64),
+                pubkey_memcmp(TOKEN_ACCOUNT_OWNER_OFFSET, &owner),
+            ]),
+            None,
+        )
+    }
+
+    /// Scan the SPL Token program for all token accounts of a mint.
+    ///
+    /// Use with the SPL Token program ID. As with :meth:`token_accounts_by_owner`,
+    /// Token-2022 accounts with extensions are not matched. This includes token accounts
+    /// that are not associated token accounts; pass the result to
+    /// :func:`~solders.presets.filter_associated_token_accounts` to keep only ATAs.
+    ///
+    /// Args:
+    ///     mint (Pubkey): The token mint.
+    ///     account_config (Optional[RpcAccountInfoConfig]): Account info config.
+    ///
+    /// Returns:
+    ///     RpcProgramAccountsConfig: The config.
+    ///
+    #[staticmethod]
+    pub fn token_accounts_by_mint(
+        mint: Pubkey,
+        account_config: Option<RpcAccountInfoConfig>,
+    ) -> Self {
+        Self::new(
+            account_config.unwrap_or_default(),
+            Some(vec![
+                RpcFilterType::DataSize(TOKEN_ACCOUNT_LEN as u64),
+                pubkey_memcmp(TOKEN_ACCOUNT_MINT_OFFSET, &mint),
+            ]),
+            None,
+        )
+    }
+
+    /// Scan the Stake program for all stake accounts with the given withdraw authority.
+    ///
+    /// Use with the Stake program ID.
+    ///
+    /// Args:
+    ///     withdrawer (Pubkey): The withdraw authority.
+    ///     account_config (Optional[RpcAccountInfoConfig]): Account info config.
+    ///
+    /// Returns:
+    ///     RpcProgramAccountsConfig: The config.
+    ///
+    #[staticmethod]
+    pub fn stake_accounts_by_withdrawer(
+        withdrawer: Pubkey,
+        account_config: Option<RpcAccountInfoConfig>,
+    ) -> Self {
+        Self::new(
+            account_config.unwrap_or_default(),
+            Some(vec![
+                RpcFilterType::DataSize(STAKE_ACCOUNT_LEN as u64),
+                pubkey_memcmp(STAKE_AUTHORIZED_WITHDRAWER_OFFSET, &withdrawer),
+            ]),
+            None,
+        )
+    }
+
+    /// Scan the Stake program for all stake accounts with the given stake authority.
+    ///
+    /// Use with the Stake program ID.
+    ///
+    /// Args:
+    ///     staker (Pubkey): The stake authority.
+    ///     account_config (Optional[RpcAccountInfoConfig]): Account info config.
+    ///
+    /// Returns:
+    ///     RpcProgramAccountsConfig: The config.
+    ///
+    #[staticmethod]
+    pub fn stake_accounts_by_staker(
+        staker: Pubkey,
+        account_config: Option<RpcAccountInfoConfig>,
+    ) -> Self {
+        Self::new(
+            account_config.unwrap_or_default(),
+            Some(vec![
+                RpcFilterType::DataSize(STAKE_ACCOUNT_LEN as u64),
+                pubkey_memcmp(STAKE_AUTHORIZED_STAKER_OFFSET, &staker),
+            ]),
+            None,
+        )
+    }
+
     #[getter]
     pub fn filters(&self) -> Option<Vec<PyObject>> {
         let cloned = self.0.filters.clone();
@@ -844,6 +981,12 @@ pub enum RpcTransactionLogsFilter {
     AllWithVotes,
 }
 
+impl EnumString for RpcTransactionLogsFilter {}
+
+#[enum_string]
+#[pymethods]
+impl RpcTransactionLogsFilter {}
+
 /// ``mentions`` filter for ``logsSubscribe``.
 ///
 /// Args:
@@ -901,16 +1044,26 @@ impl From<TransactionLogsFilterWrapper> for rpc_config::RpcTransactionLogsFilter
     }
 }
 
-impl From<rpc_config::RpcTransactionLogsFilter> for TransactionLogsFilterWrapper {
-    fn from(f: rpc_config::RpcTransactionLogsFilter) -> Self {
+impl TryFrom<rpc_config::RpcTransactionLogsFilter> for TransactionLogsFilterWrapper {
+    type Error = String;
+
+    fn try_from(f: rpc_config::RpcTransactionLogsFilter) -> Result<Self, Self::Error> {
         match f {
-            rpc_config::RpcTransactionLogsFilter::All => Self::Plain(RpcTransactionLogsFilter::All),
-            rpc_config::RpcTransactionLogsFilter::AllWithVotes => {
-                Self::Plain(RpcTransactionLogsFilter::AllWithVotes)
+            rpc_config::RpcTransactionLogsFilter::All => {
+                Ok(Self::Plain(RpcTransactionLogsFilter::All))
             }
-            rpc_config::RpcTransactionLogsFilter::Mentions(v) => {
-                Self::Mentions(RpcTransactionLogsFilterMentions(v))
+            rpc_config::RpcTransactionLogsFilter::AllWithVotes => {
+                Ok(Self::Plain(RpcTransactionLogsFilter::AllWithVotes))
             }
+            rpc_config::RpcTransactionLogsFilter::Mentions(v) => match v.as_slice() {
+                [addr] => Pubkey::from_str(addr)
+                    .map(|_| Self::Mentions(RpcTransactionLogsFilterMentions(v)))
+                    .map_err(|e| format!("invalid mentions address {addr}: {e}")),
+                _ => Err(format!(
+                    "mentions filter must contain exactly one address, got {}",
+                    v.len()
+                )),
+            },
         }
     }
 }
@@ -1082,6 +1235,12 @@ pub enum RpcBlockSubscribeFilter {
     All,
 }
 
+impl EnumString for RpcBlockSubscribeFilter {}
+
+#[enum_string]
+#[pymethods]
+impl RpcBlockSubscribeFilter {}
+
 /// ``mentions`` filter for ``blockSubscribe``.
 ///
 /// Args:
@@ -1234,7 +1393,7 @@ pyclass_boilerplate_with_default!(
 #[pymethods]
 impl RpcSignaturesForAddressConfig {
     #[new]
-    fn new(
+    pub(crate) fn new(
         before: Option<&Signature>,
         until: Option<&Signature>,
         limit: Option<usize>,
@@ -0,0 +1,342 @@
+use std::collections::{BTreeMap, HashSet};
+
+use pyo3::{exceptions::PyValueError, prelude::*};
+use serde::{Deserialize, Serialize};
+use solana_sdk::transaction::VersionedTransaction as VersionedTransactionOriginal;
+use solders_macros::{enum_string, richcmp_eq_only};
+use solders_primitives::{pubkey::Pubkey, signature::Signature};
+use solders_traits::{EnumString, RichcmpEqualityOnly};
+
+use crate::{
+    compute_budget::{compute_budget_limits_original, estimate_compute_unit_price},
+    rpc::responses::{RpcPrioritizationFee, RpcSimulateTransactionResult},
+    transaction_status::{
+        is_writable_index, TransactionErrorType, TransactionStatus, UiConfirmedBlock,
+    },
+    wallet::WalletTransaction,
+};
+
+/// Matching the fee of at most this share of recent slots counts as underpaying.
+const LOW_FEE_PERCENTILE: f64 = 25.0;
+/// The share of recent transactions locking an account that counts as contention.
+const HIGH_CONTENTION_RATIO: f64 = 0.1;
+
+/// The most likely reason a transaction did or didn't land, from :func:`diagnose_landing`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[pyclass(module = "solders.rpc.diagnostics")]
+pub enum LandingVerdict {
+    /// The transaction landed and succeeded.
+    Landed,
+    /// The transaction landed but its execution failed.
+    FailedOnChain,
+    /// Preflight simulation failed, so the RPC node didn't forward the transaction.
+    PreflightFailed,
+    /// The blockhash hasn't expired, so the transaction may still land.
+    StillValid,
+    /// The blockhash expired and the priority fee was low for recent slots.
+    FeeTooLow,
+    /// The blockhash expired while other transactions held the same write locks.
+    WriteLockContention,
+    /// The blockhash expired with no other sign of why; the transaction was likely dropped.
+    BlockhashExpired,
+}
+
+impl EnumString for LandingVerdict {}
+
+#[enum_string]
+#[pymethods]
+impl LandingVerdict {}
+
+/// The evidence gathered by :func:`diagnose_landing` and the verdict it supports.
+#[pyclass(module = "solders.rpc.diagnostics", subclass)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LandingReport {
+    /// Signature: The transaction's signature.
+    #[pyo3(get)]
+    pub signature: Signature,
+    /// LandingVerdict: The most likely reason.
+    #[pyo3(get)]
+    pub verdict: LandingVerdict,
+    /// str: A one-line explanation of the verdict.
+    #[pyo3(get)]
+    pub explanation: String,
+    /// Optional[TransactionErrorType]: The on-chain or preflight error, if any.
+    #[pyo3(get)]
+    pub error: Option<TransactionErrorType>,
+    /// bool: Whether the block height has passed the blockhash's ``last_valid_block_height``.
+    #[pyo3(get)]
+    pub blockhash_expired: bool,
+    /// int: The compute unit price the transaction paid, in micro-lamports.
+    #[pyo3(get)]
+    pub compute_unit_price: u64,
+    /// Optional[float]: The share of recent slots whose fee the price matched, from 0 to 100.
+    #[pyo3(get)]
+    pub fee_percentile: Option<f64>,
+    /// Optional[int]: The median recent compute unit price.
+    #[pyo3(get)]
+    pub median_compute_unit_price: Option<u64>,
+    /// Optional[float]: The share of recent non-vote transactions that write-locked one of
+    /// the transaction's writable accounts.
+    #[pyo3(get)]
+    pub contention_ratio: Option<f64>,
+    /// List[Tuple[Pubkey, int]]: The transaction's writable accounts that recent transactions
+    /// also write-locked, with how many did, most contended first.
+    #[pyo3(get)]
+    pub contended_accounts: Vec<(Pubkey, u64)>,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl LandingReport {
+    #[allow(clippy::too_many_arguments)]
+    #[new]
+    pub fn new(
+        signature: Signature,
+        verdict: LandingVerdict,
+        explanation: String,
+        error: Option<TransactionErrorType>,
+        blockhash_expired: bool,
+        compute_unit_price: u64,
+        fee_percentile: Option<f64>,
+        median_compute_unit_price: Option<u64>,
+        contention_ratio: Option<f64>,
+        contended_accounts: Vec<(Pubkey, u64)>,
+    ) -> Self {
+        Self {
+            signature,
+            verdict,
+            explanation,
+            error,
+            blockhash_expired,
+            compute_unit_price,
+            fee_percentile,
+            median_compute_unit_price,
+            contention_ratio,
+            contended_accounts,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for LandingReport {}
+
+/// Write-lock contention on ``writable`` among the non-vote transactions of ``blocks``.
+fn write_lock_contention(
+    blocks: &[UiConfirmedBlock],
+    signature: &Signature,
+    writable: &HashSet<Pubkey>,
+) -> (Option<f64>, Vec<(Pubkey, u64)>) {
+    let mut counts: BTreeMap<Pubkey, u64> = BTreeMap::new();
+    let (mut total, mut contending) = (0u64, 0u64);
+    for tx in blocks
+        .iter()
+        .flat_map(|b| b.transactions().unwrap_or_default())
+    {
+        if tx.is_vote() || tx.first_signature().as_ref() == Some(signature) {
+            continue;
+        }
+        total += 1;
+        let locked: HashSet<Pubkey> = tx
+            .writable_account_keys()
+            .into_iter()
+            .filter(|k| writable.contains(k))
+            .collect();
+        if !locked.is_empty() {
+            contending += 1;
+        }
+        for key in locked {
+            *counts.entry(key).or_default() += 1;
+        }
+    }
+    let mut contended: Vec<(Pubkey, u64)> = counts.into_iter().collect();
+    contended.sort_by(|a, b| b.1.cmp(&a.1));
+    let ratio = (total > 0).then(|| contending as f64 / total as f64);
+    (ratio, contended)
+}
+
+/// Work out why a transaction did or didn't land.
+///
+/// Pass whatever evidence is at hand; missing evidence is skipped. The checks run in order
+/// and the first that applies is the verdict:
+///
+/// 1. ``status``: the transaction landed, and succeeded or failed.
+/// 2. ``preflight``: simulation failed, so the RPC node never forwarded it.
+/// 3. The blockhash is still valid, so it may yet land.
+/// 4. ``prioritization_fees``: its compute unit price matched at most 25% of recent slots.
+/// 5. ``recent_blocks``: over 10% of their non-vote transactions write-locked one of its accounts.
+/// 6. Otherwise, it expired without a visible cause, e.g. dropped on the way to the leader.
+///
+/// Scope the ``getRecentPrioritizationFees`` request to the transaction's writable accounts,
+/// fetch the block height *before* the signature status, and request the blocks with
+/// ``maxSupportedTransactionVersion`` set. Accounts loaded from lookup tables aren't
+/// known from the transaction alone, so contention is only checked for its static keys.
+///
+/// Args:
+///     transaction (Transaction | VersionedTransaction): The transaction that was sent.
+///     block_height (int): The current block height.
+///     last_valid_block_height (int): The ``lastValidBlockHeight`` returned with its blockhash.
+///     status (Optional[TransactionStatus]): Its ``getSignatureStatuses`` result, searching
+///         the transaction history.
+///     prioritization_fees (Sequence[RpcPrioritizationFee]): The result of
+///         ``getRecentPrioritizationFees``.
+///     recent_blocks (Sequence[UiConfirmedBlock]): Blocks produced while it was valid.
+///     preflight (Optional[RpcSimulateTransactionResult]): Its preflight or simulation result.
+///
+/// Returns:
+///     LandingReport: The evidence and verdict.
+///
+/// Raises:
+///     ValueError: If the transaction's compute budget instructions are invalid.
+///
+/// Example:
+///     >>> from solders.hash import Hash
+///     >>> from solders.instruction import Instruction
+///     >>> from solders.keypair import Keypair
+///     >>> from solders.pubkey import Pubkey
+///     >>> from solders.rpc.diagnostics import LandingVerdict, diagnose_landing
+///     >>> from solders.rpc.responses import RpcPrioritizationFee
+///     >>> from solders.transaction import Transaction
+///     >>> program_id = Pubkey.from_string("ComputeBudget111111111111111111111111111111")
+///     >>> set_price = Instruction(program_id, bytes([3]) + (10).to_bytes(8, "little"), [])
+///     >>> payer = Keypair()
+///     >>> tx = Transaction.new_signed_with_payer([set_price], payer.pubkey(), [payer], Hash.default())
+///     >>> fees = [RpcPrioritizationFee(slot, fee) for slot, fee in enumerate([0, 5000, 8000, 12000])]
+///     >>> report = diagnose_landing(tx, 200, 150, prioritization_fees=fees)
+///     >>> report.verdict == LandingVerdict.FeeTooLow, report.fee_percentile
+///     (True, 25.0)
+///     >>> diagnose_landing(tx, 100, 150).verdict == LandingVerdict.StillValid
+///     True
+///
+#[allow(clippy::too_many_arguments)]
+#[pyfunction]
+#[pyo3(signature = (
+    transaction,
+    block_height,
+    last_valid_block_height,
+    status = None,
+    prioritization_fees = Vec::new(),
+    recent_blocks = Vec::new(),
+    preflight = None
+))]
+pub fn diagnose_landing(
+    transaction: WalletTransaction,
+    block_height: u64,
+    last_valid_block_height: u64,
+    status: Option<TransactionStatus>,
+    prioritization_fees: Vec<RpcPrioritizationFee>,
+    recent_blocks: Vec<UiConfirmedBlock>,
+    preflight: Option<RpcSimulateTransactionResult>,
+) -> PyResult<LandingReport> {
+    let tx = VersionedTransactionOriginal::from(transaction);
+    let signature = Signature::from(tx.signatures.first().copied().unwrap_or_default());
+    let compute_unit_price = compute_budget_limits_original(&tx.message)
+        .map_err(|e| PyValueError::new_err(e.to_string()))?
+        .compute_unit_price;
+    let keys = tx.message.static_account_keys();
+    let writable: HashSet<Pubkey> = keys
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| is_writable_index(tx.message.header(), keys.len(), *i))
+        .map(|(_, k)| Pubkey(*k))
+        .collect();
+
+    let blockhash_expired = block_height > last_valid_block_height;
+    let (fee_percentile, median_compute_unit_price) = if prioritization_fees.is_empty() {
+        (None, None)
+    } else {
+        let matched = prioritization_fees
+            .iter()
+            .filter(|f| f.prioritization_fee() <= compute_unit_price)
+            .count();
+        (
+            Some(matched as f64 / prioritization_fees.len() as f64 * 100.0),
+            Some(estimate_compute_unit_price(prioritization_fees, 50.0)?),
+        )
+    };
+    let (contention_ratio, contended_accounts) =
+        write_lock_contention(&recent_blocks, &signature, &writable);
+
+    let landed = status.map(|s| (s.slot(), s.err()));
+    let preflight_error = preflight.and_then(|p| p.err);
+    let (verdict, explanation, error) = if let Some((slot, err)) = landed {
+        match err {
+            Some(err) => (
+                LandingVerdict::FailedOnChain,
+                format!("landed in slot {slot} but failed with {err:?}"),
+                Some(err),
+            ),
+            None => (
+                LandingVerdict::Landed,
+                format!("landed in slot {slot}"),
+                None,
+            ),
+        }
+    } else if let Some(err) = preflight_error {
+        (
+            LandingVerdict::PreflightFailed,
+            format!("preflight failed with {err:?}, so the RPC node didn't forward it"),
+            Some(err),
+        )
+    } else if !blockhash_expired {
+        (
+            LandingVerdict::StillValid,
+            format!(
+                "the blockhash is valid for {} more blocks, so it may still land",
+                last_valid_block_height - block_height
+            ),
+            None,
+        )
+    } else if let Some(percentile) = fee_percentile.filter(|p| *p <= LOW_FEE_PERCENTILE) {
+        (
+            LandingVerdict::FeeTooLow,
+            format!(
+                "the blockhash expired, and a compute unit price of {compute_unit_price} \
+                 matched only {percentile:.0}% of recent slots (median {})",
+                median_compute_unit_price.unwrap_or_default()
+            ),
+            None,
+        )
+    } else if let Some(ratio) = contention_ratio.filter(|r| *r > HIGH_CONTENTION_RATIO) {
+        (
+            LandingVerdict::WriteLockContention,
+            format!(
+                "the blockhash expired while {:.0}% of recent transactions write-locked {}",
+                ratio * 100.0,
+                contended_accounts[0].0
+            ),
+            None,
+        )
+    } else {
+        (
+            LandingVerdict::BlockhashExpired,
+            "the blockhash expired with no sign of low fees or contention; \
+             it was likely dropped before reaching a leader"
+                .to_owned(),
+            None,
+        )
+    };
+    Ok(LandingReport {
+        signature,
+        verdict,
+        explanation,
+        error,
+        blockhash_expired,
+        compute_unit_price,
+        fee_percentile,
+        median_compute_unit_price,
+        contention_ratio,
+        contended_accounts,
+    })
+}
+
+pub(crate) fn create_diagnostics_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "diagnostics")?;
+    m.add_class::<LandingVerdict>()?;
+    m.add_class::<LandingReport>()?;
+    m.add_function(wrap_pyfunction!(diagnose_landing, m)?)?;
+    Ok(m)
+}
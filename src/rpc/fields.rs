@@ -0,0 +1,358 @@
+use std::collections::HashMap;
+
+use pyo3::{prelude::*, types::PyBytes};
+use solders_macros::richcmp_eq_only;
+use solders_traits::{to_py_value_err, RichcmpEqualityOnly};
+
+use crate::{
+    account_decoder::{UiAccountEncoding, UiDataSliceConfig},
+    commitment_config::CommitmentLevel,
+    layouts::{
+        MINT_DECIMALS_OFFSET, MINT_FREEZE_AUTHORITY_OFFSET, MINT_IS_INITIALIZED_OFFSET, MINT_LEN,
+        MINT_MINT_AUTHORITY_OFFSET, MINT_SUPPLY_OFFSET, TOKEN_ACCOUNT_AMOUNT_OFFSET,
+        TOKEN_ACCOUNT_CLOSE_AUTHORITY_OFFSET, TOKEN_ACCOUNT_DELEGATED_AMOUNT_OFFSET,
+        TOKEN_ACCOUNT_DELEGATE_OFFSET, TOKEN_ACCOUNT_IS_NATIVE_OFFSET, TOKEN_ACCOUNT_LEN,
+        TOKEN_ACCOUNT_MINT_OFFSET, TOKEN_ACCOUNT_OWNER_OFFSET, TOKEN_ACCOUNT_STATE_OFFSET,
+    },
+    rpc::config::RpcAccountInfoConfig,
+    tmp_account_decoder::UiDataSliceConfig as UiDataSliceConfigOriginal,
+};
+
+/// Responses carrying at least this many bytes of account data per account are
+/// requested as ``base64+zstd``. Below it, compression saves too little to pay
+/// for the frame overhead and the node's CPU time.
+pub const DEFAULT_ZSTD_THRESHOLD: usize = 128;
+
+/// The size of a pubkey.
+const PUBKEY_LEN: usize = 32;
+/// The size of a ``COption<Pubkey>``: a four-byte tag and the pubkey.
+const OPTION_PUBKEY_LEN: usize = 36;
+/// The size of a ``COption<u64>``: a four-byte tag and the value.
+const OPTION_U64_LEN: usize = 12;
+
+const TOKEN_ACCOUNT_LAYOUT: [(&str, usize, usize); 8] = [
+    ("mint", TOKEN_ACCOUNT_MINT_OFFSET, PUBKEY_LEN),
+    ("owner", TOKEN_ACCOUNT_OWNER_OFFSET, PUBKEY_LEN),
+    ("amount", TOKEN_ACCOUNT_AMOUNT_OFFSET, 8),
+    ("delegate", TOKEN_ACCOUNT_DELEGATE_OFFSET, OPTION_PUBKEY_LEN),
+    ("state", TOKEN_ACCOUNT_STATE_OFFSET, 1),
+    ("is_native", TOKEN_ACCOUNT_IS_NATIVE_OFFSET, OPTION_U64_LEN),
+    ("delegated_amount", TOKEN_ACCOUNT_DELEGATED_AMOUNT_OFFSET, 8),
+    (
+        "close_authority",
+        TOKEN_ACCOUNT_CLOSE_AUTHORITY_OFFSET,
+        OPTION_PUBKEY_LEN,
+    ),
+];
+const MINT_LAYOUT: [(&str, usize, usize); 5] = [
+    (
+        "mint_authority",
+        MINT_MINT_AUTHORITY_OFFSET,
+        OPTION_PUBKEY_LEN,
+    ),
+    ("supply", MINT_SUPPLY_OFFSET, 8),
+    ("decimals", MINT_DECIMALS_OFFSET, 1),
+    ("is_initialized", MINT_IS_INITIALIZED_OFFSET, 1),
+    (
+        "freeze_authority",
+        MINT_FREEZE_AUTHORITY_OFFSET,
+        OPTION_PUBKEY_LEN,
+    ),
+];
+
+/// A named byte range of account data.
+///
+/// Args:
+///     name (str): The field name, used as the key in :meth:`AccountFields.extract`.
+///     offset (int): Where the field starts in the account data.
+///     length (int): The field size in bytes.
+///
+#[pyclass(module = "solders.rpc.fields", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AccountField {
+    /// str: The field name.
+    #[pyo3(get)]
+    pub name: String,
+    /// int: Where the field starts in the account data.
+    #[pyo3(get)]
+    pub offset: usize,
+    /// int: The field size in bytes.
+    #[pyo3(get)]
+    pub length: usize,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl AccountField {
+    #[new]
+    pub fn new(name: String, offset: usize, length: usize) -> Self {
+        Self {
+            name,
+            offset,
+            length,
+        }
+    }
+
+    /// int: The offset just past the field.
+    #[getter]
+    pub fn end(&self) -> usize {
+        self.offset + self.length
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for AccountField {}
+
+/// The parts of account data a request needs, from which the cheapest sufficient
+/// ``encoding`` and ``dataSlice`` are picked.
+///
+/// Only the range covering every field is fetched. If that range is at least
+/// ``zstd_threshold`` bytes, or its size is unknown, the data is requested as
+/// ``base64+zstd``; otherwise as plain ``base64``. ``fields=None`` means the whole
+/// account data is needed, and an empty list means none of it, e.g. for lamports only.
+///
+/// Args:
+///     fields (Optional[Sequence[AccountField]]): The fields needed. ``None`` for all data.
+///     data_size (Optional[int]): The account data size, if every account has the same one.
+///
+/// Raises:
+///     ValueError: If a field extends past ``data_size``.
+///
+/// Example:
+///     >>> from solders.rpc.fields import AccountFields
+///     >>> from solders.rpc.requests import GetMultipleAccounts
+///     >>> from solders.account_decoder import UiAccountEncoding
+///     >>> from solders.pubkey import Pubkey
+///     >>> fields = AccountFields.token_account(["owner", "amount"])
+///     >>> config = fields.account_info_config()
+///     >>> config.encoding == UiAccountEncoding.Base64
+///     True
+///     >>> config.data_slice.offset, config.data_slice.length
+///     (32, 40)
+///     >>> AccountFields.token_account().encoding() == UiAccountEncoding.Base64Zstd
+///     True
+///     >>> request = GetMultipleAccounts.for_fields([Pubkey.default()], fields)
+///     >>> request.config.data_slice.length
+///     40
+///
+#[pyclass(module = "solders.rpc.fields", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccountFields {
+    fields: Option<Vec<AccountField>>,
+    data_size: Option<usize>,
+}
+
+impl AccountFields {
+    fn from_layout(
+        layout: &[(&str, usize, usize)],
+        data_size: usize,
+        names: Option<Vec<String>>,
+    ) -> PyResult<Self> {
+        let fields = match names {
+            None => None,
+            Some(names) => Some(
+                names
+                    .iter()
+                    .map(|name| {
+                        layout
+                            .iter()
+                            .find(|(field, _, _)| field == name)
+                            .map(|(field, offset, length)| {
+                                AccountField::new((*field).to_owned(), *offset, *length)
+                            })
+                            .ok_or_else(|| to_py_value_err(&format!("unknown field {name:?}")))
+                    })
+                    .collect::<PyResult<Vec<_>>>()?,
+            ),
+        };
+        Self::new(fields, Some(data_size))
+    }
+
+    pub(crate) fn config(&self, commitment: Option<CommitmentLevel>) -> RpcAccountInfoConfig {
+        self.account_info_config(commitment, None, DEFAULT_ZSTD_THRESHOLD)
+    }
+
+    fn slice(&self) -> Option<UiDataSliceConfigOriginal> {
+        let fields = self.fields.as_ref()?;
+        let start = fields.iter().map(|f| f.offset).min().unwrap_or(0);
+        let end = fields.iter().map(AccountField::end).max().unwrap_or(0);
+        Some(UiDataSliceConfigOriginal {
+            offset: start,
+            length: end - start,
+        })
+    }
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl AccountFields {
+    #[new]
+    #[pyo3(signature = (fields = None, data_size = None))]
+    pub fn new(fields: Option<Vec<AccountField>>, data_size: Option<usize>) -> PyResult<Self> {
+        if let (Some(fields), Some(size)) = (&fields, data_size) {
+            if let Some(field) = fields.iter().find(|f| f.end() > size) {
+                return Err(to_py_value_err(&format!(
+                    "field {:?} ends at {} but the account data is {} bytes",
+                    field.name,
+                    field.end(),
+                    size
+                )));
+            }
+        }
+        Ok(Self { fields, data_size })
+    }
+
+    /// Fields of an SPL Token account: ``mint``, ``owner``, ``amount``, ``delegate``,
+    /// ``state``, ``is_native``, ``delegated_amount`` and ``close_authority``.
+    ///
+    /// Args:
+    ///     names (Optional[Sequence[str]]): The fields needed. ``None`` for all data.
+    ///
+    /// Returns:
+    ///     AccountFields: The spec.
+    ///
+    /// Raises:
+    ///     ValueError: If a name isn't a token account field.
+    ///
+    #[staticmethod]
+    #[pyo3(signature = (names = None))]
+    pub fn token_account(names: Option<Vec<String>>) -> PyResult<Self> {
+        Self::from_layout(&TOKEN_ACCOUNT_LAYOUT, TOKEN_ACCOUNT_LEN, names)
+    }
+
+    /// Fields of an SPL Token mint: ``mint_authority``, ``supply``, ``decimals``,
+    /// ``is_initialized`` and ``freeze_authority``.
+    ///
+    /// Args:
+    ///     names (Optional[Sequence[str]]): The fields needed. ``None`` for all data.
+    ///
+    /// Returns:
+    ///     AccountFields: The spec.
+    ///
+    /// Raises:
+    ///     ValueError: If a name isn't a mint field.
+    ///
+    #[staticmethod]
+    #[pyo3(signature = (names = None))]
+    pub fn mint(names: Option<Vec<String>>) -> PyResult<Self> {
+        Self::from_layout(&MINT_LAYOUT, MINT_LEN, names)
+    }
+
+    /// Optional[List[AccountField]]: The fields needed, or ``None`` for all data.
+    #[getter]
+    pub fn fields(&self) -> Option<Vec<AccountField>> {
+        self.fields.clone()
+    }
+
+    /// Optional[int]: The account data size, if known.
+    #[getter]
+    pub fn data_size(&self) -> Option<usize> {
+        self.data_size
+    }
+
+    /// Optional[UiDataSliceConfig]: The smallest slice covering every field,
+    /// or ``None`` if all data is needed.
+    #[getter]
+    pub fn data_slice(&self) -> Option<UiDataSliceConfig> {
+        self.slice().map(UiDataSliceConfig::from)
+    }
+
+    /// Optional[int]: The bytes of data returned per account, if known.
+    #[getter]
+    pub fn transfer_size(&self) -> Option<usize> {
+        self.slice().map(|s| s.length).or(self.data_size)
+    }
+
+    /// The cheapest encoding that returns the needed data.
+    ///
+    /// Args:
+    ///     zstd_threshold (int): Compress data of at least this many bytes.
+    ///
+    /// Returns:
+    ///     UiAccountEncoding: ``Base64Zstd`` or ``Base64``.
+    ///
+    #[pyo3(signature = (zstd_threshold = DEFAULT_ZSTD_THRESHOLD))]
+    pub fn encoding(&self, zstd_threshold: usize) -> UiAccountEncoding {
+        match self.transfer_size() {
+            Some(size) if size < zstd_threshold => UiAccountEncoding::Base64,
+            _ => UiAccountEncoding::Base64Zstd,
+        }
+    }
+
+    /// Build the account config that fetches the needed data.
+    ///
+    /// Args:
+    ///     commitment (Optional[CommitmentLevel]): Bank state to query.
+    ///     min_context_slot (Optional[int]): The minimum slot that the request can be evaluated at.
+    ///     zstd_threshold (int): Compress data of at least this many bytes.
+    ///
+    /// Returns:
+    ///     RpcAccountInfoConfig: The config.
+    ///
+    #[pyo3(signature = (commitment = None, min_context_slot = None, zstd_threshold = DEFAULT_ZSTD_THRESHOLD))]
+    pub fn account_info_config(
+        &self,
+        commitment: Option<CommitmentLevel>,
+        min_context_slot: Option<u64>,
+        zstd_threshold: usize,
+    ) -> RpcAccountInfoConfig {
+        RpcAccountInfoConfig::new(
+            Some(self.encoding(zstd_threshold)),
+            self.data_slice(),
+            commitment,
+            min_context_slot,
+        )
+    }
+
+    /// Split data fetched with this spec into its fields.
+    ///
+    /// Args:
+    ///     data (bytes): The account data as returned, starting at the slice offset.
+    ///
+    /// Returns:
+    ///     Dict[str, bytes]: The bytes of each field. Empty if ``fields`` is ``None``.
+    ///
+    /// Raises:
+    ///     ValueError: If ``data`` is too short to hold every field.
+    ///
+    pub fn extract<'a>(
+        &self,
+        py: Python<'a>,
+        data: &[u8],
+    ) -> PyResult<HashMap<String, &'a PyBytes>> {
+        let base = self.slice().map_or(0, |s| s.offset);
+        self.fields
+            .iter()
+            .flatten()
+            .map(|field| {
+                let start = field.offset - base;
+                data.get(start..start + field.length)
+                    .map(|bytes| (field.name.clone(), PyBytes::new(py, bytes)))
+                    .ok_or_else(|| {
+                        to_py_value_err(&format!(
+                            "data is {} bytes, too short for field {:?}",
+                            data.len(),
+                            field.name
+                        ))
+                    })
+            })
+            .collect()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for AccountFields {}
+
+pub(crate) fn create_fields_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "fields")?;
+    m.add("DEFAULT_ZSTD_THRESHOLD", DEFAULT_ZSTD_THRESHOLD)?;
+    m.add_class::<AccountField>()?;
+    m.add_class::<AccountFields>()?;
+    Ok(m)
+}
@@ -1,15 +1,25 @@
+use crate::account::{Account, AccountJSON};
+use crate::layouts::{
+    STAKE_AUTHORIZED_STAKER_OFFSET, STAKE_AUTHORIZED_WITHDRAWER_OFFSET, TOKEN_ACCOUNT_MINT_OFFSET,
+    TOKEN_ACCOUNT_OWNER_OFFSET,
+};
 use crate::rpc::tmp_filter::{
     Memcmp as MemcmpOriginal, MemcmpEncodedBytes as MemcmpEncodedBytesOriginal,
     MemcmpEncoding as MemcmpEncodingOriginal, RpcFilterType as RpcFilterTypeOriginal,
 };
-use pyo3::prelude::*;
+use crate::transaction_status::EncodedTransactionWithStatusMeta;
+use pyo3::{exceptions::PyValueError, prelude::*};
 use serde::{Deserialize, Serialize};
+use solana_sdk::account::Account as AccountOriginal;
+use solders_primitives::pubkey::Pubkey;
 
 use derive_more::{From, Into};
-use solders_macros::{common_methods, enum_original_mapping, richcmp_eq_only, EnumIntoPy};
+use solders_macros::{
+    common_methods, enum_original_mapping, enum_string, richcmp_eq_only, EnumIntoPy,
+};
 
 use solders_traits::{
-    impl_display, py_from_bytes_general_via_bincode, pybytes_general_via_bincode,
+    impl_display, py_from_bytes_general_via_bincode, pybytes_general_via_bincode, EnumString,
     RichcmpEqualityOnly,
 };
 
@@ -47,6 +57,12 @@ pub enum MemcmpEncoding {
     Binary,
 }
 
+impl EnumString for MemcmpEncoding {}
+
+#[enum_string]
+#[pymethods]
+impl MemcmpEncoding {}
+
 /// Compares a provided series of bytes with program account data at a particular offset.
 ///
 /// Args:
@@ -123,9 +139,191 @@ impl From<RpcFilterTypeOriginal> for RpcFilterType {
     }
 }
 
+/// A filter applied to accounts while parsing, so non-matching accounts never become Python objects.
+///
+/// An account matches when every condition that is set holds. Pass the filter to
+/// :func:`~solders.rpc.responses.parse_program_accounts`,
+/// :func:`~solders.rpc.responses.parse_block` or
+/// :func:`~solders.rpc.responses.parse_websocket_message`.
+///
+/// Block transactions match when one of their instructions, inner ones included,
+/// invokes ``owner`` with instruction data that meets the data conditions.
+/// Fully parsed instructions have no raw data, so they only match filters without data conditions.
+///
+/// Args:
+///     owner (Optional[Pubkey]): The program that must own the account.
+///     min_data_len (Optional[int]): The minimum data length, inclusive.
+///     max_data_len (Optional[int]): The maximum data length, inclusive.
+///     memcmp (Optional[Sequence[Memcmp]]): Filters the data must all match.
+///
+/// Raises:
+///     ValueError: If ``min_data_len`` is greater than ``max_data_len``.
+///
+/// Example:
+///     >>> from solders.rpc.filter import AccountFilter, Memcmp
+///     >>> from solders.account import Account
+///     >>> from solders.pubkey import Pubkey
+///     >>> owner = Pubkey.new_unique()
+///     >>> account_filter = AccountFilter(owner, min_data_len=4, memcmp=[Memcmp(0, b"ab")])
+///     >>> account_filter.matches(Account(1, b"abcd", owner))
+///     True
+///     >>> account_filter.matches(Account(1, b"abc", owner))
+///     False
+///
+#[pyclass(module = "solders.rpc.filter", subclass)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AccountFilter {
+    /// Optional[Pubkey]: The program that must own the account.
+    #[pyo3(get)]
+    pub owner: Option<Pubkey>,
+    /// Optional[int]: The minimum data length, inclusive.
+    #[pyo3(get)]
+    pub min_data_len: Option<usize>,
+    /// Optional[int]: The maximum data length, inclusive.
+    #[pyo3(get)]
+    pub max_data_len: Option<usize>,
+    /// List[Memcmp]: Filters the data must all match.
+    #[pyo3(get)]
+    pub memcmp: Vec<Memcmp>,
+}
+
+impl AccountFilter {
+    fn has_data_conditions(&self) -> bool {
+        self.min_data_len.is_some() || self.max_data_len.is_some() || !self.memcmp.is_empty()
+    }
+
+    fn data_matches(&self, data: &[u8]) -> bool {
+        let len = data.len();
+        self.min_data_len.map_or(true, |min| len >= min)
+            && self.max_data_len.map_or(true, |max| len <= max)
+            && self.memcmp.iter().all(|m| m.0.bytes_match(data))
+    }
+
+    pub(crate) fn matches_original(&self, account: &AccountOriginal) -> bool {
+        self.owner.map_or(true, |owner| owner.0 == account.owner)
+            && self.data_matches(&account.data)
+    }
+
+    pub(crate) fn matches_transaction(&self, tx: &EncodedTransactionWithStatusMeta) -> bool {
+        if *self == Self::default() {
+            return true;
+        }
+        tx.instruction_data().iter().any(|(program_id, data)| {
+            self.owner.map_or(true, |owner| owner == *program_id)
+                && data.as_ref().map_or_else(
+                    || !self.has_data_conditions(),
+                    |data| self.data_matches(data),
+                )
+        })
+    }
+
+    /// JSON-parsed accounts have no raw data, so they only match filters without data conditions.
+    pub(crate) fn matches_json(&self, account: &AccountJSON) -> bool {
+        !self.has_data_conditions() && self.owner.map_or(true, |owner| owner == account.owner)
+    }
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl AccountFilter {
+    #[new]
+    #[pyo3(signature = (owner = None, min_data_len = None, max_data_len = None, memcmp = None))]
+    pub fn new(
+        owner: Option<Pubkey>,
+        min_data_len: Option<usize>,
+        max_data_len: Option<usize>,
+        memcmp: Option<Vec<Memcmp>>,
+    ) -> PyResult<Self> {
+        if let (Some(min), Some(max)) = (min_data_len, max_data_len) {
+            if min > max {
+                return Err(PyValueError::new_err(format!(
+                    "min_data_len ({min}) is greater than max_data_len ({max})"
+                )));
+            }
+        }
+        Ok(Self {
+            owner,
+            min_data_len,
+            max_data_len,
+            memcmp: memcmp.unwrap_or_default(),
+        })
+    }
+
+    /// Check an account against the filter.
+    ///
+    /// Args:
+    ///     account (Account): The account.
+    ///
+    /// Returns:
+    ///     bool: Whether the account matches.
+    ///
+    pub fn matches(&self, account: Account) -> bool {
+        self.matches_original(account.as_ref())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for AccountFilter {}
+
+/// Offset of the update authority in a Metaplex token metadata account.
+pub const METADATA_UPDATE_AUTHORITY_OFFSET: usize = 1;
+/// Offset of the mint in a Metaplex token metadata account.
+pub const METADATA_MINT_OFFSET: usize = 33;
+
+/// Check account data against a memcmp filter, exactly as the RPC does.
+///
+/// Data shorter than ``offset + len(bytes_)`` never matches, and neither
+/// does a string that isn't valid base58.
+///
+/// Args:
+///     account_data (bytes): The account data.
+///     offset (int): Data offset to begin match.
+///     bytes_ (str | Sequence[int]): The bytes to compare, or a base58 string.
+///
+/// Returns:
+///     bool: Whether the filter matches.
+///
+/// Example:
+///     >>> from solders.rpc.filter import memcmp_matches, TOKEN_ACCOUNT_OWNER_OFFSET
+///     >>> from solders.pubkey import Pubkey
+///     >>> owner = Pubkey.new_unique()
+///     >>> data = bytes(32) + bytes(owner) + bytes(101)
+///     >>> memcmp_matches(data, TOKEN_ACCOUNT_OWNER_OFFSET, str(owner))
+///     True
+///
+#[pyfunction]
+pub fn memcmp_matches(account_data: &[u8], offset: usize, bytes_: MemcmpEncodedBytes) -> bool {
+    MemcmpOriginal {
+        offset,
+        bytes: bytes_.into(),
+        encoding: None,
+    }
+    .bytes_match(account_data)
+}
+
 pub(crate) fn create_filter_mod(py: Python<'_>) -> PyResult<&PyModule> {
     let m = PyModule::new(py, "filter")?;
     m.add_class::<MemcmpEncoding>()?;
     m.add_class::<Memcmp>()?;
+    m.add_class::<AccountFilter>()?;
+    m.add_function(wrap_pyfunction!(memcmp_matches, m)?)?;
+    m.add("TOKEN_ACCOUNT_MINT_OFFSET", TOKEN_ACCOUNT_MINT_OFFSET)?;
+    m.add("TOKEN_ACCOUNT_OWNER_OFFSET", TOKEN_ACCOUNT_OWNER_OFFSET)?;
+    m.add(
+        "STAKE_AUTHORIZED_STAKER_OFFSET",
+        STAKE_AUTHORIZED_STAKER_OFFSET,
+    )?;
+    m.add(
+        "STAKE_AUTHORIZED_WITHDRAWER_OFFSET",
+        STAKE_AUTHORIZED_WITHDRAWER_OFFSET,
+    )?;
+    m.add(
+        "METADATA_UPDATE_AUTHORITY_OFFSET",
+        METADATA_UPDATE_AUTHORITY_OFFSET,
+    )?;
+    m.add("METADATA_MINT_OFFSET", METADATA_MINT_OFFSET)?;
     Ok(m)
 }
@@ -0,0 +1,342 @@
+use std::collections::HashMap;
+
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use solana_sdk::clock::Slot;
+use solders_macros::{enum_string, richcmp_eq_only};
+use solders_primitives::hash::Hash as SolderHash;
+use solders_traits::{EnumString, RichcmpEqualityOnly};
+
+use crate::{
+    rpc::{
+        responses::{SlotInfo, SlotUpdate},
+        tmp_response::SlotUpdate as SlotUpdateOriginal,
+    },
+    transaction_status::UiConfirmedBlock,
+};
+
+/// How far a slot has progressed towards finality, or whether it was abandoned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[pyclass(module = "solders.rpc.forks")]
+pub enum SlotStatus {
+    Processed,
+    Confirmed,
+    Finalized,
+    Abandoned,
+}
+
+impl EnumString for SlotStatus {}
+
+#[enum_string]
+#[pymethods]
+impl SlotStatus {}
+
+impl SlotStatus {
+    /// Whether a slot with this status may move to ``next``.
+    fn can_become(self, next: Self) -> bool {
+        match (self, next) {
+            (Self::Finalized | Self::Abandoned, _) => false,
+            (Self::Confirmed, Self::Processed) => false,
+            (current, next) => current != next,
+        }
+    }
+}
+
+/// A notification from ``slotSubscribe`` or ``slotsUpdatesSubscribe``.
+#[derive(FromPyObject, Clone, Debug)]
+pub enum SlotEvent {
+    Info(SlotInfo),
+    Update(SlotUpdate),
+}
+
+/// Classifies slots as processed, confirmed, finalized or abandoned from slot notifications.
+///
+/// Feed every ``slotsUpdatesSubscribe`` (or ``slotSubscribe``) notification to
+/// :meth:`update`. Parent links from ``createdBank`` updates let confirmation and
+/// rooting propagate to ancestors. Once a slot is rooted, every slot seen at or
+/// below it that isn't an ancestor of the root is abandoned, so this relies on
+/// seeing a ``root`` update (or a parent link) for each rooted slot.
+///
+/// Example:
+///     >>> from solders.rpc.forks import ForkTracker, SlotStatus
+///     >>> from solders.rpc.responses import SlotUpdateCreatedBank, SlotUpdateRoot
+///     >>> tracker = ForkTracker()
+///     >>> tracker.update(SlotUpdateCreatedBank(11, 0, 10))
+///     >>> tracker.update(SlotUpdateCreatedBank(12, 0, 10))
+///     >>> tracker.update(SlotUpdateRoot(12, 0))
+///     >>> tracker.status(11) == SlotStatus.Abandoned, tracker.status(10) == SlotStatus.Finalized
+///     (True, True)
+///
+#[pyclass(module = "solders.rpc.forks", subclass)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ForkTracker {
+    statuses: HashMap<Slot, SlotStatus>,
+    parents: HashMap<Slot, Slot>,
+    root: Option<Slot>,
+}
+
+impl ForkTracker {
+    fn set(&mut self, slot: Slot, status: SlotStatus) -> bool {
+        let current = self.statuses.get(&slot).copied();
+        if current.map_or(true, |c| c.can_become(status)) {
+            self.statuses.insert(slot, status);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Set ``status`` on ``slot`` and its known ancestors, stopping at the first
+    /// ancestor that already has it.
+    fn set_with_ancestors(&mut self, slot: Slot, status: SlotStatus) {
+        let mut next = Some(slot);
+        while let Some(slot) = next {
+            if !self.set(slot, status) {
+                break;
+            }
+            next = self.parents.get(&slot).copied();
+        }
+    }
+
+    fn set_root(&mut self, root: Slot) {
+        self.set_with_ancestors(root, SlotStatus::Finalized);
+        if self.root.map_or(true, |r| root > r) {
+            self.root = Some(root);
+        }
+        for (slot, status) in self.statuses.iter_mut() {
+            if *slot <= root && *status != SlotStatus::Finalized {
+                *status = SlotStatus::Abandoned;
+            }
+        }
+    }
+}
+
+#[pymethods]
+impl ForkTracker {
+    #[new]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Optional[int]: The highest rooted slot seen.
+    #[getter]
+    pub fn root(&self) -> Option<Slot> {
+        self.root
+    }
+
+    /// Record a slot notification.
+    ///
+    /// Args:
+    ///     event (SlotInfo | SlotUpdate): The notification result.
+    ///
+    pub fn update(&mut self, event: SlotEvent) {
+        match event {
+            SlotEvent::Info(info) => {
+                self.parents.insert(info.slot(), info.parent());
+                self.set(info.slot(), SlotStatus::Processed);
+                self.set_root(info.root());
+            }
+            SlotEvent::Update(update) => match SlotUpdateOriginal::from(update) {
+                SlotUpdateOriginal::CreatedBank { slot, parent, .. } => {
+                    self.parents.insert(slot, parent);
+                    self.set(slot, SlotStatus::Processed);
+                }
+                SlotUpdateOriginal::FirstShredReceived { slot, .. }
+                | SlotUpdateOriginal::Completed { slot, .. }
+                | SlotUpdateOriginal::Frozen { slot, .. } => {
+                    self.set(slot, SlotStatus::Processed);
+                }
+                SlotUpdateOriginal::OptimisticConfirmation { slot, .. } => {
+                    self.set_with_ancestors(slot, SlotStatus::Confirmed);
+                }
+                SlotUpdateOriginal::Root { slot, .. } => self.set_root(slot),
+                SlotUpdateOriginal::Dead { slot, .. } => {
+                    self.set(slot, SlotStatus::Abandoned);
+                }
+            },
+        }
+    }
+
+    /// The status of a slot.
+    ///
+    /// Args:
+    ///     slot (int): The slot.
+    ///
+    /// Returns:
+    ///     Optional[SlotStatus]: The status, or ``None`` if no notification mentioned the slot.
+    ///
+    pub fn status(&self, slot: Slot) -> Option<SlotStatus> {
+        self.statuses.get(&slot).copied()
+    }
+
+    /// Forget every slot below ``slot``.
+    ///
+    /// Args:
+    ///     slot (int): The lowest slot to keep.
+    ///
+    pub fn prune(&mut self, slot: Slot) {
+        self.statuses.retain(|s, _| *s >= slot);
+        self.parents.retain(|s, _| *s >= slot);
+    }
+
+    fn __len__(&self) -> usize {
+        self.statuses.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+/// What is wrong with a block in a sequence checked by :func:`verify_block_chain`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[pyclass(module = "solders.rpc.forks")]
+pub enum BlockChainIssueKind {
+    /// The block's slot is not above the previous block's slot.
+    OutOfOrder,
+    /// The block's parent comes after the previous block, so blocks are missing in between.
+    Gap,
+    /// The block's parent comes before the previous block, so the two are on different forks.
+    Fork,
+    /// The block's parent is the previous block, but its ``previousBlockhash`` doesn't match.
+    HashMismatch,
+}
+
+impl EnumString for BlockChainIssueKind {}
+
+#[enum_string]
+#[pymethods]
+impl BlockChainIssueKind {}
+
+/// A break in the chain of blocks, as returned by :func:`verify_block_chain`.
+#[pyclass(module = "solders.rpc.forks", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockChainIssue {
+    /// int: The slot of the block that doesn't chain to the previous one.
+    #[pyo3(get)]
+    pub slot: Slot,
+    /// BlockChainIssueKind: What is wrong.
+    #[pyo3(get)]
+    pub kind: BlockChainIssueKind,
+    /// int: The slot of the previous block in the sequence.
+    #[pyo3(get)]
+    pub previous_slot: Slot,
+    /// int: The block's ``parentSlot``.
+    #[pyo3(get)]
+    pub parent_slot: Slot,
+    /// Hash: The previous block's ``blockhash``.
+    #[pyo3(get)]
+    pub expected_blockhash: SolderHash,
+    /// Hash: The block's ``previousBlockhash``.
+    #[pyo3(get)]
+    pub previous_blockhash: SolderHash,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl BlockChainIssue {
+    #[new]
+    pub fn new(
+        slot: Slot,
+        kind: BlockChainIssueKind,
+        previous_slot: Slot,
+        parent_slot: Slot,
+        expected_blockhash: SolderHash,
+        previous_blockhash: SolderHash,
+    ) -> Self {
+        Self {
+            slot,
+            kind,
+            previous_slot,
+            parent_slot,
+            expected_blockhash,
+            previous_blockhash,
+        }
+    }
+
+    /// Optional[Tuple[int, int]]: For a ``Gap``, the range of slots to fetch to close it,
+    /// from the slot after the previous block up to and including the block's parent.
+    #[getter]
+    pub fn missing_range(&self) -> Option<(Slot, Slot)> {
+        (self.kind == BlockChainIssueKind::Gap).then(|| (self.previous_slot + 1, self.parent_slot))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for BlockChainIssue {}
+
+/// Check that a sequence of blocks forms an unbroken hash chain.
+///
+/// Each block's ``parentSlot`` must be the previous block's slot and its
+/// ``previousBlockhash`` the previous block's ``blockhash``. Skipped slots have no
+/// block, so a parent several slots back is fine as long as it is the previous block
+/// in the sequence. Use this to catch providers that drop or mix up blocks in a backfill.
+///
+/// Args:
+///     blocks (Sequence[Tuple[int, UiConfirmedBlock]]): The ``(slot, block)`` pairs,
+///         in ascending slot order, as returned by ``getBlock``.
+///
+/// Returns:
+///     List[BlockChainIssue]: One issue per block that doesn't chain to the previous one.
+///     Empty if the chain is intact.
+///
+/// Example:
+///     >>> from solders.rpc.forks import verify_block_chain, BlockChainIssueKind
+///     >>> from solders.transaction_status import UiConfirmedBlock
+///     >>> from solders.hash import Hash
+///     >>> a, b, c = Hash.new_unique(), Hash.new_unique(), Hash.new_unique()
+///     >>> first = UiConfirmedBlock(Hash.default(), a, 9, None, None, None, None, None)
+///     >>> second = UiConfirmedBlock(a, b, 10, None, None, None, None, None)
+///     >>> third = UiConfirmedBlock(c, Hash.new_unique(), 14, None, None, None, None, None)
+///     >>> verify_block_chain([(10, first), (12, second)])
+///     []
+///     >>> [issue.kind for issue in verify_block_chain([(10, first), (12, second), (15, third)])]
+///     [BlockChainIssueKind.Gap]
+///
+#[pyfunction]
+pub fn verify_block_chain(blocks: Vec<(Slot, UiConfirmedBlock)>) -> Vec<BlockChainIssue> {
+    blocks
+        .windows(2)
+        .filter_map(|pair| {
+            let (previous_slot, previous) = &pair[0];
+            let (slot, block) = &pair[1];
+            let parent_slot = block.parent_slot();
+            let expected_blockhash = previous.blockhash();
+            let previous_blockhash = block.previous_blockhash();
+            let kind = if slot <= previous_slot {
+                BlockChainIssueKind::OutOfOrder
+            } else if parent_slot > *previous_slot {
+                BlockChainIssueKind::Gap
+            } else if parent_slot < *previous_slot {
+                BlockChainIssueKind::Fork
+            } else if previous_blockhash != expected_blockhash {
+                BlockChainIssueKind::HashMismatch
+            } else {
+                return None;
+            };
+            Some(BlockChainIssue {
+                slot: *slot,
+                kind,
+                previous_slot: *previous_slot,
+                parent_slot,
+                expected_blockhash,
+                previous_blockhash,
+            })
+        })
+        .collect()
+}
+
+pub(crate) fn create_forks_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "forks")?;
+    m.add_class::<SlotStatus>()?;
+    m.add_class::<ForkTracker>()?;
+    m.add_class::<BlockChainIssueKind>()?;
+    m.add_class::<BlockChainIssue>()?;
+    m.add_function(wrap_pyfunction!(verify_block_chain, m)?)?;
+    Ok(m)
+}
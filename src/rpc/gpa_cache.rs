@@ -0,0 +1,261 @@
+use std::collections::HashMap;
+
+use pyo3::prelude::*;
+use solana_sdk::{account::ReadableAccount, clock::Slot, pubkey::Pubkey as PubkeyOriginal};
+use solders_macros::richcmp_eq_only;
+use solders_primitives::pubkey::Pubkey;
+use solders_traits::RichcmpEqualityOnly;
+
+use crate::{
+    account::Account,
+    rpc::{
+        filter::RpcFilterType,
+        responses::{GetProgramAccountsWithContextResp, ProgramNotification, RpcKeyedAccount},
+        tmp_filter::Memcmp as MemcmpOriginal,
+    },
+};
+
+/// The accounts matching one set of filters, as of a single slot.
+#[pyclass(module = "solders.rpc.gpa_cache", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProgramAccountsSnapshot {
+    /// int: The slot this view is consistent with.
+    #[pyo3(get)]
+    pub slot: Slot,
+    /// List[RpcKeyedAccount]: The matching accounts, sorted by pubkey.
+    #[pyo3(get)]
+    pub accounts: Vec<RpcKeyedAccount>,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl ProgramAccountsSnapshot {
+    #[new]
+    pub fn new(slot: Slot, accounts: Vec<RpcKeyedAccount>) -> Self {
+        Self { slot, accounts }
+    }
+
+    /// Look up an account in the snapshot.
+    ///
+    /// Args:
+    ///     pubkey (Pubkey): The account address.
+    ///
+    /// Returns:
+    ///     Optional[Account]: The account, or ``None`` if it isn't in the snapshot.
+    ///
+    pub fn get(&self, pubkey: Pubkey) -> Option<Account> {
+        self.accounts
+            .binary_search_by(|keyed| keyed.pubkey.cmp(&pubkey))
+            .ok()
+            .map(|i| self.accounts[i].account.clone())
+    }
+
+    fn __len__(&self) -> usize {
+        self.accounts.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for ProgramAccountsSnapshot {}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct CachedQuery {
+    /// The latest slot applied, which every later notification must reach.
+    slot: Slot,
+    accounts: HashMap<PubkeyOriginal, Account>,
+}
+
+impl CachedQuery {
+    fn snapshot(&self) -> ProgramAccountsSnapshot {
+        let mut accounts: Vec<RpcKeyedAccount> = self
+            .accounts
+            .iter()
+            .map(|(pubkey, account)| RpcKeyedAccount {
+                pubkey: (*pubkey).into(),
+                account: account.clone(),
+            })
+            .collect();
+        accounts.sort_unstable_by(|a, b| a.pubkey.cmp(&b.pubkey));
+        ProgramAccountsSnapshot {
+            slot: self.slot,
+            accounts,
+        }
+    }
+}
+
+/// Whether ``account`` would be returned by ``getProgramAccounts`` with ``filters``.
+fn account_matches(
+    program_id: &PubkeyOriginal,
+    filters: &[RpcFilterType],
+    account: &Account,
+) -> bool {
+    account.owner() == program_id
+        && account.lamports() > 0
+        && filters.iter().all(|f| filter_allows(f, account))
+}
+
+fn filter_allows(filter: &RpcFilterType, account: &Account) -> bool {
+    match filter {
+        RpcFilterType::DataSize(size) => account.data().len() as u64 == *size,
+        RpcFilterType::Memcmp(memcmp) => {
+            MemcmpOriginal::from(memcmp.clone()).bytes_match(account.data())
+        }
+    }
+}
+
+/// A cache of ``getProgramAccounts`` results for one program, kept up to date
+/// from ``programSubscribe`` notifications.
+///
+/// Each snapshot is keyed by the filters it was fetched with, in the order given.
+/// Every notification is checked against each snapshot's filters locally: it updates
+/// the snapshots the account matches and removes it from the ones it no longer
+/// matches, including when it is closed or reassigned to another program.
+/// Notifications older than the latest slot applied to a snapshot are ignored, so a
+/// snapshot never goes back in time.
+///
+/// Feed it one ``programSubscribe`` subscription *without filters*. A filtered
+/// subscription isn't notified when an account stops matching its filters, so the
+/// account would never be removed.
+///
+/// To avoid missing updates, subscribe first, then fetch with ``withContext``
+/// and :meth:`load` the response, then :meth:`apply` the buffered notifications.
+///
+/// Args:
+///     program_id (Pubkey): The program whose accounts are cached.
+///
+/// Example:
+///     >>> from solders.rpc.gpa_cache import ProgramAccountsCache
+///     >>> from solders.rpc.responses import GetProgramAccountsWithContextResp, ProgramNotification, ProgramNotificationResult, RpcKeyedAccount, RpcResponseContext
+///     >>> from solders.account import Account
+///     >>> from solders.pubkey import Pubkey
+///     >>> program, key = Pubkey.new_unique(), Pubkey.new_unique()
+///     >>> cache = ProgramAccountsCache(program)
+///     >>> cache.load(GetProgramAccountsWithContextResp([], RpcResponseContext(10)))
+///     >>> keyed = RpcKeyedAccount(key, Account(1, b"", program))
+///     >>> cache.apply(ProgramNotification(ProgramNotificationResult(keyed, RpcResponseContext(11)), 0))
+///     True
+///     >>> snapshot = cache.snapshot()
+///     >>> snapshot.slot, snapshot.get(key).lamports
+///     (11, 1)
+///
+#[pyclass(module = "solders.rpc.gpa_cache", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProgramAccountsCache {
+    program_id: PubkeyOriginal,
+    queries: HashMap<Vec<RpcFilterType>, CachedQuery>,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl ProgramAccountsCache {
+    #[new]
+    pub fn new(program_id: Pubkey) -> Self {
+        Self {
+            program_id: program_id.into(),
+            queries: HashMap::new(),
+        }
+    }
+
+    /// Pubkey: The program whose accounts are cached.
+    #[getter]
+    pub fn program_id(&self) -> Pubkey {
+        self.program_id.into()
+    }
+
+    /// Replace the snapshot for ``filters`` with a ``getProgramAccounts`` response.
+    ///
+    /// Args:
+    ///     response (GetProgramAccountsWithContextResp): The response, fetched with ``withContext``.
+    ///     filters (Optional[Sequence[int | Memcmp]]): The filters the response was fetched with.
+    ///
+    #[pyo3(signature = (response, filters = None))]
+    pub fn load(
+        &mut self,
+        response: GetProgramAccountsWithContextResp,
+        filters: Option<Vec<RpcFilterType>>,
+    ) {
+        let (slot, keyed_accounts) = response.slot_and_accounts();
+        let accounts = keyed_accounts
+            .iter()
+            .map(|keyed| (keyed.pubkey.into(), keyed.account.clone()))
+            .collect();
+        self.queries
+            .insert(filters.unwrap_or_default(), CachedQuery { slot, accounts });
+    }
+
+    /// Update the snapshots with a ``programSubscribe`` notification.
+    ///
+    /// Args:
+    ///     notification (ProgramNotification): The notification, from a subscription
+    ///         without filters.
+    ///
+    /// Returns:
+    ///     bool: Whether any snapshot changed.
+    ///
+    pub fn apply(&mut self, notification: ProgramNotification) -> bool {
+        let (slot, keyed) = notification.slot_and_account();
+        let pubkey: PubkeyOriginal = keyed.pubkey.into();
+        let mut changed = false;
+        for (filters, query) in self.queries.iter_mut() {
+            if slot < query.slot {
+                continue;
+            }
+            query.slot = slot;
+            if account_matches(&self.program_id, filters, &keyed.account) {
+                query.accounts.insert(pubkey, keyed.account.clone());
+                changed = true;
+            } else if query.accounts.remove(&pubkey).is_some() {
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// The current view for a set of filters.
+    ///
+    /// Args:
+    ///     filters (Optional[Sequence[int | Memcmp]]): The filters the snapshot was loaded with.
+    ///
+    /// Returns:
+    ///     Optional[ProgramAccountsSnapshot]: The view, or ``None`` if nothing was loaded for ``filters``.
+    ///
+    #[pyo3(signature = (filters = None))]
+    pub fn snapshot(&self, filters: Option<Vec<RpcFilterType>>) -> Option<ProgramAccountsSnapshot> {
+        self.queries
+            .get(&filters.unwrap_or_default())
+            .map(CachedQuery::snapshot)
+    }
+
+    /// Drop the snapshot for a set of filters, e.g. after the subscription disconnects.
+    ///
+    /// Args:
+    ///     filters (Optional[Sequence[int | Memcmp]]): The filters the snapshot was loaded with.
+    ///
+    /// Returns:
+    ///     bool: Whether a snapshot was dropped.
+    ///
+    #[pyo3(signature = (filters = None))]
+    pub fn invalidate(&mut self, filters: Option<Vec<RpcFilterType>>) -> bool {
+        self.queries.remove(&filters.unwrap_or_default()).is_some()
+    }
+
+    fn __len__(&self) -> usize {
+        self.queries.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for ProgramAccountsCache {}
+
+pub(crate) fn create_gpa_cache_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "gpa_cache")?;
+    m.add_class::<ProgramAccountsSnapshot>()?;
+    m.add_class::<ProgramAccountsCache>()?;
+    Ok(m)
+}
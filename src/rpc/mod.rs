@@ -1,19 +1,32 @@
 use std::collections::HashMap;
 
 use self::{
-    config::create_config_mod, errors::create_errors_mod, filter::create_filter_mod,
-    requests::create_requests_mod, responses::create_responses_mod,
+    config::create_config_mod, diagnostics::create_diagnostics_mod, errors::create_errors_mod,
+    fields::create_fields_mod, filter::create_filter_mod, forks::create_forks_mod,
+    gpa_cache::create_gpa_cache_mod, polling::create_polling_mod, portfolio::create_portfolio_mod,
+    quorum::create_quorum_mod, requests::create_requests_mod, responses::create_responses_mod,
+    streams::create_streams_mod, timing::create_timing_mod, tpu::create_tpu_mod,
 };
 use pyo3::prelude::*;
 
 pub mod config;
+pub mod diagnostics;
 pub mod errors;
+pub mod fields;
 pub mod filter;
+pub mod forks;
+pub mod gpa_cache;
+pub mod polling;
+pub mod portfolio;
+pub mod quorum;
 pub mod requests;
 pub mod responses;
+pub mod streams;
+pub mod timing;
 mod tmp_config;
 mod tmp_filter;
 mod tmp_response;
+pub mod tpu;
 
 pub fn create_rpc_mod(py: Python<'_>) -> PyResult<&PyModule> {
     let rpc_mod = PyModule::new(py, "rpc")?;
@@ -22,12 +35,32 @@ pub fn create_rpc_mod(py: Python<'_>) -> PyResult<&PyModule> {
     let filter_mod = create_filter_mod(py)?;
     let responses_mod = create_responses_mod(py)?;
     let errors_mod = create_errors_mod(py)?;
+    let streams_mod = create_streams_mod(py)?;
+    let timing_mod = create_timing_mod(py)?;
+    let gpa_cache_mod = create_gpa_cache_mod(py)?;
+    let polling_mod = create_polling_mod(py)?;
+    let portfolio_mod = create_portfolio_mod(py)?;
+    let forks_mod = create_forks_mod(py)?;
+    let fields_mod = create_fields_mod(py)?;
+    let quorum_mod = create_quorum_mod(py)?;
+    let tpu_mod = create_tpu_mod(py)?;
+    let diagnostics_mod = create_diagnostics_mod(py)?;
     let submodules = [
         config_mod,
         requests_mod,
         filter_mod,
         responses_mod,
         errors_mod,
+        streams_mod,
+        timing_mod,
+        gpa_cache_mod,
+        polling_mod,
+        portfolio_mod,
+        forks_mod,
+        fields_mod,
+        quorum_mod,
+        tpu_mod,
+        diagnostics_mod,
     ];
     let modules: HashMap<String, &PyModule> = submodules
         .iter()
@@ -0,0 +1,521 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    time::Instant,
+};
+
+use pyo3::prelude::*;
+use solana_sdk::clock::Slot;
+use solders_macros::richcmp_eq_only;
+use solders_primitives::signature::Signature;
+use solders_traits::{to_py_value_err, RichcmpEqualityOnly};
+
+use crate::{
+    commitment_config::{CommitmentConfig, CommitmentLevel},
+    log::{self, LogLevel},
+    metrics,
+    rpc::{
+        config::{RpcContextConfig, RpcSignatureStatusConfig},
+        requests::{GetBlocks, GetSignatureStatuses, GetSlot},
+        responses::{GetBlocksResp, GetSignatureStatusesResp},
+    },
+    transaction_status::TransactionStatus,
+};
+
+/// The most signatures ``getSignatureStatuses`` accepts in one request.
+const MAX_SIGNATURES_PER_REQUEST: usize = 256;
+/// The widest slot range ``getBlocks`` accepts in one request.
+pub const MAX_GET_BLOCKS_RANGE: u64 = 500_000;
+
+#[derive(Clone, Debug)]
+struct PendingSignature {
+    signature: Signature,
+    last_valid_block_height: u64,
+    callback: Option<PyObject>,
+    tracked_at: Instant,
+}
+
+/// Tracks pending signatures and batches them into ``getSignatureStatuses`` requests.
+///
+/// The poller does no I/O: call :meth:`requests`, send them, and pass each
+/// request and its response to :meth:`handle_response`. A signature resolves
+/// once its status reaches the poller's commitment, or expires when it has no
+/// status and the block height has passed its ``last_valid_block_height``.
+/// Fetch the block height *before* the statuses, so a transaction that lands
+/// in between isn't reported as expired.
+///
+/// Args:
+///     commitment (Optional[CommitmentConfig]): The commitment a status must reach. Defaults to finalized.
+///     search_transaction_history (bool): Whether to search the ledger beyond the recent status cache.
+///
+/// Example:
+///     >>> from solders.rpc.polling import StatusPoller
+///     >>> from solders.rpc.responses import GetSignatureStatusesResp, RpcResponseContext
+///     >>> from solders.transaction_status import TransactionStatus, TransactionConfirmationStatus
+///     >>> from solders.signature import Signature
+///     >>> landed, dropped = Signature.new_unique(), Signature.new_unique()
+///     >>> poller = StatusPoller()
+///     >>> poller.track(landed, 100), poller.track(dropped, 100)
+///     (True, True)
+///     >>> [request] = poller.requests()
+///     >>> status = TransactionStatus(5, None, None, None, TransactionConfirmationStatus.Finalized)
+///     >>> resp = GetSignatureStatusesResp([status, None], RpcResponseContext(5))
+///     >>> resolved = poller.handle_response(request, resp, 101)
+///     >>> resolved == [(landed, status), (dropped, None)], len(poller)
+///     (True, 0)
+///
+#[pyclass(module = "solders.rpc.polling", subclass)]
+#[derive(Clone, Debug)]
+pub struct StatusPoller {
+    commitment: CommitmentConfig,
+    search_transaction_history: bool,
+    /// Keyed by tracking order, so requests poll the oldest signatures first.
+    pending: BTreeMap<u64, PendingSignature>,
+    index: HashMap<Signature, u64>,
+    next_order: u64,
+}
+
+impl StatusPoller {
+    fn remove(&mut self, signature: &Signature) -> Option<PendingSignature> {
+        let order = self.index.remove(signature)?;
+        self.pending.remove(&order)
+    }
+
+    /// Remove the signatures resolved by ``response``, returning them with their callbacks.
+    fn resolve(
+        &mut self,
+        request: &GetSignatureStatuses,
+        response: &GetSignatureStatusesResp,
+        block_height: u64,
+    ) -> PyResult<Vec<(PendingSignature, Option<TransactionStatus>)>> {
+        let signatures = request.signatures();
+        let statuses = response.statuses();
+        if signatures.len() != statuses.len() {
+            return Err(to_py_value_err(&format!(
+                "request has {} signatures but response has {} statuses",
+                signatures.len(),
+                statuses.len()
+            )));
+        }
+        let mut resolved = Vec::new();
+        for (signature, status) in signatures.iter().zip(statuses) {
+            let pending = match self.index.get(signature) {
+                Some(order) => &self.pending[order],
+                None => continue,
+            };
+            let done = match status {
+                Some(status) => status.satisfies_commitment(self.commitment),
+                None => block_height > pending.last_valid_block_height,
+            };
+            if done {
+                if let Some(pending) = self.remove(signature) {
+                    resolved.push((pending, status.clone()));
+                }
+            }
+        }
+        Ok(resolved)
+    }
+}
+
+#[pymethods]
+impl StatusPoller {
+    #[new]
+    #[pyo3(signature = (commitment = None, search_transaction_history = false))]
+    pub fn new(commitment: Option<CommitmentConfig>, search_transaction_history: bool) -> Self {
+        Self {
+            commitment: commitment.unwrap_or_default(),
+            search_transaction_history,
+            pending: BTreeMap::new(),
+            index: HashMap::new(),
+            next_order: 0,
+        }
+    }
+
+    /// CommitmentConfig: The commitment a status must reach.
+    #[getter]
+    pub fn commitment(&self) -> CommitmentConfig {
+        self.commitment
+    }
+
+    /// Start tracking a signature.
+    ///
+    /// Args:
+    ///     signature (Signature): The transaction signature.
+    ///     last_valid_block_height (int): The block height after which the transaction expires.
+    ///     callback (Optional[Callable[[Signature, Optional[TransactionStatus]], Any]]): Called
+    ///         when the signature resolves, with ``None`` if it expired.
+    ///
+    /// Returns:
+    ///     bool: ``False`` if the signature is already tracked.
+    ///
+    #[pyo3(signature = (signature, last_valid_block_height, callback = None))]
+    pub fn track(
+        &mut self,
+        signature: Signature,
+        last_valid_block_height: u64,
+        callback: Option<PyObject>,
+    ) -> bool {
+        if self.index.contains_key(&signature) {
+            return false;
+        }
+        let order = self.next_order;
+        self.next_order += 1;
+        self.index.insert(signature, order);
+        self.pending.insert(
+            order,
+            PendingSignature {
+                signature,
+                last_valid_block_height,
+                callback,
+                tracked_at: Instant::now(),
+            },
+        );
+        true
+    }
+
+    /// Stop tracking a signature without invoking its callback.
+    ///
+    /// Args:
+    ///     signature (Signature): The transaction signature.
+    ///
+    /// Returns:
+    ///     bool: Whether the signature was tracked.
+    ///
+    pub fn untrack(&mut self, signature: Signature) -> bool {
+        self.remove(&signature).is_some()
+    }
+
+    /// The tracked signatures.
+    ///
+    /// Returns:
+    ///     List[Signature]: The signatures, in the order they were tracked.
+    ///
+    pub fn pending(&self) -> Vec<Signature> {
+        self.pending.values().map(|p| p.signature).collect()
+    }
+
+    /// Build the requests that poll every tracked signature, 256 per request.
+    ///
+    /// Args:
+    ///     start_id (int): The ID of the first request. Later requests get consecutive IDs.
+    ///
+    /// Returns:
+    ///     List[GetSignatureStatuses]: The requests.
+    ///
+    #[pyo3(signature = (start_id = 0))]
+    pub fn requests(&self, start_id: u64) -> Vec<GetSignatureStatuses> {
+        let config = self
+            .search_transaction_history
+            .then(|| RpcSignatureStatusConfig::new(true));
+        let signatures = self.pending();
+        let requests: Vec<GetSignatureStatuses> = signatures
+            .chunks(MAX_SIGNATURES_PER_REQUEST)
+            .zip(start_id..)
+            .map(|(chunk, id)| GetSignatureStatuses::new(chunk.to_vec(), config.clone(), Some(id)))
+            .collect();
+        metrics::inc(&metrics::POLLER_REQUESTS, requests.len() as u64);
+        requests
+    }
+
+    /// Resolve the signatures in a request from its response.
+    ///
+    /// Resolved and expired signatures stop being tracked before their callbacks
+    /// are invoked, so callbacks may track new signatures. Every callback is invoked
+    /// even if an earlier one raises. The first exception is raised once they have
+    /// all run, so no resolved signature is lost.
+    ///
+    /// Args:
+    ///     request (GetSignatureStatuses): A request returned by :meth:`requests`.
+    ///     response (GetSignatureStatusesResp): Its response.
+    ///     block_height (int): The block height, fetched before the request was sent.
+    ///
+    /// Returns:
+    ///     List[Tuple[Signature, Optional[TransactionStatus]]]: The resolved signatures,
+    ///     with ``None`` for those that expired.
+    ///
+    /// Raises:
+    ///     ValueError: If the response doesn't have one status per requested signature.
+    ///
+    pub fn handle_response(
+        slf: &PyCell<Self>,
+        request: GetSignatureStatuses,
+        response: GetSignatureStatusesResp,
+        block_height: u64,
+    ) -> PyResult<Vec<(Signature, Option<TransactionStatus>)>> {
+        let resolved = slf
+            .borrow_mut()
+            .resolve(&request, &response, block_height)?;
+        let py = slf.py();
+        let mut out = Vec::with_capacity(resolved.len());
+        let mut first_err = None;
+        for (pending, status) in resolved {
+            let (level, outcome) = if status.is_some() {
+                metrics::inc(&metrics::POLLER_RESOLVED, 1);
+                metrics::observe(
+                    &metrics::CONFIRMATION_SECONDS,
+                    pending.tracked_at.elapsed().as_secs_f64(),
+                );
+                (LogLevel::Debug, "resolved")
+            } else {
+                metrics::inc(&metrics::POLLER_EXPIRED, 1);
+                (LogLevel::Warning, "expired")
+            };
+            log::emit(
+                py,
+                level,
+                "solders.rpc.polling",
+                format!("signature {} {outcome}", pending.signature),
+                &[
+                    ("signature", pending.signature.to_string()),
+                    ("block_height", block_height.to_string()),
+                    (
+                        "last_valid_block_height",
+                        pending.last_valid_block_height.to_string(),
+                    ),
+                ],
+            );
+            if let Some(callback) = pending.callback {
+                if let Err(err) = callback.call1(py, (pending.signature, status.clone())) {
+                    first_err.get_or_insert(err);
+                }
+            }
+            out.push((pending.signature, status));
+        }
+        first_err.map_or(Ok(out), Err)
+    }
+
+    fn __len__(&self) -> usize {
+        self.pending.len()
+    }
+
+    fn __contains__(&self, signature: Signature) -> bool {
+        self.index.contains_key(&signature)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+/// Walks the confirmed blocks from a start slot up to a moving tip with ``getBlocks``.
+///
+/// Like :class:`StatusPoller`, this does no I/O. Send :meth:`tip_request` to learn
+/// the tip, then send :meth:`next_request` and pass each request and its response to
+/// :meth:`handle_response` until it returns ``None``. Long ranges are split into
+/// ``chunk_size`` slots per request. Refresh the tip to keep following the chain.
+///
+/// A node that lags behind the tip returns fewer blocks than exist, so a response only
+/// covers its range up to the highest block it returned, or up to the answering node's
+/// root if :meth:`handle_response` is given one. The rest of the range is requested
+/// again.
+///
+/// Args:
+///     start_slot (int): The first slot to include.
+///     commitment (Optional[CommitmentLevel]): ``Confirmed`` or ``Finalized``. Defaults to finalized.
+///     chunk_size (int): Slots per ``getBlocks`` request. At most 500,000, the default.
+///
+/// Raises:
+///     ValueError: If ``commitment`` is ``Processed`` or ``chunk_size`` is out of range.
+///
+/// Example:
+///     >>> from solders.rpc.polling import BlockBackfill
+///     >>> from solders.rpc.responses import GetBlocksResp
+///     >>> backfill = BlockBackfill(100, chunk_size=10)
+///     >>> backfill.update_tip(125)
+///     True
+///     >>> blocks = []
+///     >>> while (request := backfill.next_request()) is not None:
+///     ...     found = [s for s in range(request.start, request.end + 1) if s % 3]
+///     ...     blocks += backfill.handle_response(request, GetBlocksResp(found))
+///     >>> len(blocks), blocks[-1], backfill.next_slot, backfill.is_caught_up
+///     (17, 125, 126, True)
+///
+#[pyclass(module = "solders.rpc.polling", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockBackfill {
+    next_slot: Slot,
+    tip: Option<Slot>,
+    commitment: CommitmentLevel,
+    chunk_size: u64,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl BlockBackfill {
+    #[new]
+    #[pyo3(signature = (start_slot, commitment = None, chunk_size = MAX_GET_BLOCKS_RANGE))]
+    pub fn new(
+        start_slot: Slot,
+        commitment: Option<CommitmentLevel>,
+        chunk_size: u64,
+    ) -> PyResult<Self> {
+        let commitment = commitment.unwrap_or(CommitmentLevel::Finalized);
+        if commitment == CommitmentLevel::Processed {
+            return Err(to_py_value_err(
+                &"getBlocks does not support processed commitment",
+            ));
+        }
+        if chunk_size == 0 || chunk_size > MAX_GET_BLOCKS_RANGE {
+            return Err(to_py_value_err(&format!(
+                "chunk_size must be between 1 and {MAX_GET_BLOCKS_RANGE}"
+            )));
+        }
+        Ok(Self {
+            next_slot: start_slot,
+            tip: None,
+            commitment,
+            chunk_size,
+        })
+    }
+
+    /// int: The first slot not yet covered by a handled response.
+    #[getter]
+    pub fn next_slot(&self) -> Slot {
+        self.next_slot
+    }
+
+    /// Optional[int]: The latest known tip, or ``None`` before the first update.
+    #[getter]
+    pub fn tip(&self) -> Option<Slot> {
+        self.tip
+    }
+
+    /// CommitmentLevel: The commitment blocks must reach.
+    #[getter]
+    pub fn commitment(&self) -> CommitmentLevel {
+        self.commitment
+    }
+
+    /// int: Slots per ``getBlocks`` request.
+    #[getter]
+    pub fn chunk_size(&self) -> u64 {
+        self.chunk_size
+    }
+
+    /// bool: Whether every slot up to the known tip has been covered.
+    #[getter]
+    pub fn is_caught_up(&self) -> bool {
+        self.tip.map_or(false, |tip| self.next_slot > tip)
+    }
+
+    /// Build the ``getSlot`` request that fetches the tip at the backfill's commitment.
+    ///
+    /// Args:
+    ///     id (int): The request ID.
+    ///
+    /// Returns:
+    ///     GetSlot: The request. Pass its result to :meth:`update_tip`.
+    ///
+    #[pyo3(signature = (id = 0))]
+    pub fn tip_request(&self, id: u64) -> GetSlot {
+        GetSlot::new(
+            Some(RpcContextConfig::new(Some(self.commitment), None)),
+            Some(id),
+        )
+    }
+
+    /// Record a new tip. Tips behind the known one are ignored, since the
+    /// node answering may lag.
+    ///
+    /// Args:
+    ///     tip (int): The latest slot at the backfill's commitment.
+    ///
+    /// Returns:
+    ///     bool: Whether the tip advanced.
+    ///
+    pub fn update_tip(&mut self, tip: Slot) -> bool {
+        if self.tip.map_or(false, |known| tip <= known) {
+            return false;
+        }
+        self.tip = Some(tip);
+        true
+    }
+
+    /// Build the ``getBlocks`` request for the next chunk.
+    ///
+    /// Args:
+    ///     id (int): The request ID.
+    ///
+    /// Returns:
+    ///     Optional[GetBlocks]: The request, or ``None`` if the tip is unknown or reached.
+    ///
+    #[pyo3(signature = (id = 0))]
+    pub fn next_request(&self, id: u64) -> Option<GetBlocks> {
+        let tip = self.tip?;
+        if self.next_slot > tip {
+            return None;
+        }
+        let end = tip.min(self.next_slot.saturating_add(self.chunk_size - 1));
+        Some(GetBlocks::new(
+            self.next_slot,
+            Some(end),
+            Some(self.commitment),
+            Some(id),
+        ))
+    }
+
+    /// Take the blocks from a ``getBlocks`` response and advance past the slots it covers.
+    ///
+    /// The response covers its range up to the highest block returned, or up to
+    /// ``root`` if that is further. :attr:`next_slot` moves just past that, so the
+    /// next request asks again for any slots a lagging node didn't know about yet.
+    ///
+    /// Args:
+    ///     request (GetBlocks): The request returned by :meth:`next_request`.
+    ///     response (GetBlocksResp): Its response.
+    ///     root (Optional[int]): The answering node's latest slot at the backfill's
+    ///         commitment, e.g. from :meth:`tip_request` sent to the same node. Slots
+    ///         up to it without a block were skipped.
+    ///
+    /// Returns:
+    ///     List[int]: The slots with blocks in the request's range, ascending.
+    ///
+    /// Raises:
+    ///     ValueError: If the request doesn't start at :attr:`next_slot` or has no end,
+    ///         for example because it was already handled.
+    ///
+    #[pyo3(signature = (request, response, root = None))]
+    pub fn handle_response(
+        &mut self,
+        request: GetBlocks,
+        response: GetBlocksResp,
+        root: Option<Slot>,
+    ) -> PyResult<Vec<Slot>> {
+        let end = match request.end() {
+            Some(end) if request.start() == self.next_slot => end,
+            _ => {
+                return Err(to_py_value_err(&format!(
+                    "expected a request for slots from {}",
+                    self.next_slot
+                )))
+            }
+        };
+        let mut blocks: Vec<Slot> = response
+            .value()
+            .into_iter()
+            .filter(|slot| (request.start()..=end).contains(slot))
+            .collect();
+        blocks.sort_unstable();
+        blocks.dedup();
+        let covered = blocks.last().copied().max(root.map(|root| root.min(end)));
+        if let Some(covered) = covered.filter(|covered| *covered >= self.next_slot) {
+            self.next_slot = covered + 1;
+        }
+        metrics::inc(&metrics::BACKFILL_RESPONSES, 1);
+        metrics::inc(&metrics::BACKFILL_BLOCKS, blocks.len() as u64);
+        Ok(blocks)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for BlockBackfill {}
+
+pub(crate) fn create_polling_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "polling")?;
+    m.add_class::<StatusPoller>()?;
+    m.add("MAX_GET_BLOCKS_RANGE", MAX_GET_BLOCKS_RANGE)?;
+    m.add_class::<BlockBackfill>()?;
+    Ok(m)
+}
@@ -0,0 +1,349 @@
+use std::collections::{HashMap, HashSet};
+
+use pyo3::prelude::*;
+use solana_sdk::{account::ReadableAccount, pubkey::Pubkey as PubkeyOriginal};
+use solders_macros::richcmp_eq_only;
+use solders_primitives::pubkey::Pubkey;
+use solders_traits::{to_py_value_err, RichcmpEqualityOnly};
+
+use crate::{
+    account::{validate_mint_account, validate_token_account, Account},
+    account_decoder::UiAccountEncoding,
+    commitment_config::CommitmentLevel,
+    layouts::{
+        MINT_DECIMALS_OFFSET, TOKEN_ACCOUNT_AMOUNT_OFFSET, TOKEN_ACCOUNT_MINT_OFFSET,
+        TOKEN_ACCOUNT_STATE_OFFSET,
+    },
+    rpc::{
+        config::{
+            RpcAccountInfoConfig, RpcTokenAccountsFilterProgramId, RpcTokenAccountsFilterWrapper,
+        },
+        requests::{GetMultipleAccounts, GetTokenAccountsByOwner},
+        responses::{GetMultipleAccountsResp, GetTokenAccountsByOwnerResp},
+    },
+    spl_errors::{TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID},
+};
+
+/// The most accounts ``getMultipleAccounts`` accepts in one request.
+const MAX_ACCOUNTS_PER_REQUEST: usize = 100;
+/// The ``state`` byte of a frozen token account.
+const ACCOUNT_STATE_FROZEN: u8 = 2;
+
+fn token_account_mint(account: &Account) -> Pubkey {
+    let offset = TOKEN_ACCOUNT_MINT_OFFSET;
+    PubkeyOriginal::new(&account.data()[offset..offset + 32]).into()
+}
+
+/// Format a raw token amount as a decimal string without trailing zeros.
+fn ui_amount_string(amount: u64, decimals: u8) -> String {
+    let decimals = usize::from(decimals);
+    let digits = format!("{:0>width$}", amount, width = decimals + 1);
+    let (whole, fraction) = digits.split_at(digits.len() - decimals);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        whole.to_owned()
+    } else {
+        format!("{}.{}", whole, fraction)
+    }
+}
+
+/// A token balance held by a wallet.
+///
+/// Args:
+///     address (Pubkey): The token account address.
+///     mint (Pubkey): The token mint.
+///     amount (int): The raw token amount.
+///     decimals (int): The mint's decimals.
+///     is_frozen (bool): Whether the token account is frozen.
+///     program_id (Pubkey): The token program that owns the account.
+///
+#[pyclass(module = "solders.rpc.portfolio", subclass)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenHolding {
+    /// Pubkey: The token account address.
+    #[pyo3(get)]
+    pub address: Pubkey,
+    /// Pubkey: The token mint.
+    #[pyo3(get)]
+    pub mint: Pubkey,
+    /// int: The raw token amount.
+    #[pyo3(get)]
+    pub amount: u64,
+    /// int: The mint's decimals.
+    #[pyo3(get)]
+    pub decimals: u8,
+    /// bool: Whether the token account is frozen.
+    #[pyo3(get)]
+    pub is_frozen: bool,
+    /// Pubkey: The token program that owns the account.
+    #[pyo3(get)]
+    pub program_id: Pubkey,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl TokenHolding {
+    #[new]
+    pub fn new(
+        address: Pubkey,
+        mint: Pubkey,
+        amount: u64,
+        decimals: u8,
+        is_frozen: bool,
+        program_id: Pubkey,
+    ) -> Self {
+        Self {
+            address,
+            mint,
+            amount,
+            decimals,
+            is_frozen,
+            program_id,
+        }
+    }
+
+    /// float: The amount divided by ``10 ** decimals``.
+    #[getter]
+    pub fn ui_amount(&self) -> f64 {
+        self.amount as f64 / 10f64.powi(i32::from(self.decimals))
+    }
+
+    /// str: The exact UI amount, e.g. ``"1.5"``.
+    #[getter]
+    pub fn ui_amount_string(&self) -> String {
+        ui_amount_string(self.amount, self.decimals)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for TokenHolding {}
+
+/// Builds a wallet's token portfolio from ``getTokenAccountsByOwner`` and ``getMultipleAccounts``.
+///
+/// The query does no I/O. Send :meth:`token_account_requests` and pass each response
+/// to :meth:`handle_token_accounts`, then do the same with :meth:`mint_requests` and
+/// :meth:`handle_mints` to look up the decimals of every mint held. :meth:`holdings`
+/// then returns one :class:`TokenHolding` per token account.
+///
+/// Args:
+///     owner (Pubkey): The wallet address.
+///     commitment (Optional[CommitmentLevel]): Bank state to query.
+///
+/// Example:
+///     >>> from solders.rpc.portfolio import PortfolioQuery
+///     >>> from solders.pubkey import Pubkey
+///     >>> query = PortfolioQuery(Pubkey.new_unique())
+///     >>> [str(r.filter_.program_id) for r in query.token_account_requests()]
+///     ['TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA', 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb']
+///
+#[pyclass(module = "solders.rpc.portfolio", subclass)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PortfolioQuery {
+    owner: Pubkey,
+    commitment: Option<CommitmentLevel>,
+    token_accounts: Vec<(Pubkey, Account)>,
+    decimals: HashMap<Pubkey, u8>,
+}
+
+impl PortfolioQuery {
+    fn config(&self) -> RpcAccountInfoConfig {
+        RpcAccountInfoConfig::new(Some(UiAccountEncoding::Base64), None, self.commitment, None)
+    }
+
+    fn holding(&self, address: Pubkey, account: &Account) -> PyResult<TokenHolding> {
+        let data = account.data();
+        let mint = token_account_mint(account);
+        let decimals = *self
+            .decimals
+            .get(&mint)
+            .ok_or_else(|| to_py_value_err(&format!("decimals of mint {} are unknown", mint)))?;
+        Ok(TokenHolding {
+            address,
+            mint,
+            amount: u64::from_le_bytes(
+                data[TOKEN_ACCOUNT_AMOUNT_OFFSET..TOKEN_ACCOUNT_AMOUNT_OFFSET + 8]
+                    .try_into()
+                    .unwrap(),
+            ),
+            decimals,
+            is_frozen: data[TOKEN_ACCOUNT_STATE_OFFSET] == ACCOUNT_STATE_FROZEN,
+            program_id: (*account.owner()).into(),
+        })
+    }
+}
+
+#[pymethods]
+impl PortfolioQuery {
+    #[new]
+    #[pyo3(signature = (owner, commitment = None))]
+    pub fn new(owner: Pubkey, commitment: Option<CommitmentLevel>) -> Self {
+        Self {
+            owner,
+            commitment,
+            token_accounts: Vec::new(),
+            decimals: HashMap::new(),
+        }
+    }
+
+    /// Pubkey: The wallet address.
+    #[getter]
+    pub fn owner(&self) -> Pubkey {
+        self.owner
+    }
+
+    /// The requests for the wallet's SPL Token and Token-2022 accounts.
+    ///
+    /// Args:
+    ///     start_id (int): The ID of the first request. The second gets the next ID.
+    ///
+    /// Returns:
+    ///     List[GetTokenAccountsByOwner]: The requests.
+    ///
+    #[pyo3(signature = (start_id = 0))]
+    pub fn token_account_requests(&self, start_id: u64) -> Vec<GetTokenAccountsByOwner> {
+        [TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID]
+            .into_iter()
+            .zip(start_id..)
+            .map(|(program_id, id)| {
+                let filter = RpcTokenAccountsFilterWrapper::ProgramId(
+                    RpcTokenAccountsFilterProgramId::new(program_id.into()),
+                );
+                GetTokenAccountsByOwner::new(self.owner, filter, Some(self.config()), Some(id))
+            })
+            .collect()
+    }
+
+    /// Record the token accounts in a response.
+    ///
+    /// Args:
+    ///     response (GetTokenAccountsByOwnerResp): The response to a request from
+    ///         :meth:`token_account_requests`.
+    ///
+    /// Raises:
+    ///     ValueError: If an account isn't a token account owned by the wallet.
+    ///
+    pub fn handle_token_accounts(&mut self, response: GetTokenAccountsByOwnerResp) -> PyResult<()> {
+        for keyed in response.accounts() {
+            validate_token_account(&keyed.account, None, Some(self.owner))?;
+            match self
+                .token_accounts
+                .iter_mut()
+                .find(|(address, _)| *address == keyed.pubkey)
+            {
+                Some(entry) => entry.1 = keyed.account.clone(),
+                None => self
+                    .token_accounts
+                    .push((keyed.pubkey, keyed.account.clone())),
+            }
+        }
+        Ok(())
+    }
+
+    /// The requests for the mints whose decimals are still unknown, 100 per request.
+    ///
+    /// Args:
+    ///     start_id (int): The ID of the first request. Later requests get consecutive IDs.
+    ///
+    /// Returns:
+    ///     List[GetMultipleAccounts]: The requests.
+    ///
+    #[pyo3(signature = (start_id = 0))]
+    pub fn mint_requests(&self, start_id: u64) -> Vec<GetMultipleAccounts> {
+        let mut seen = HashSet::new();
+        let mut mints: Vec<Pubkey> = Vec::new();
+        for (_, account) in &self.token_accounts {
+            let mint = token_account_mint(account);
+            if !self.decimals.contains_key(&mint) && seen.insert(mint) {
+                mints.push(mint);
+            }
+        }
+        mints
+            .chunks(MAX_ACCOUNTS_PER_REQUEST)
+            .zip(start_id..)
+            .map(|(chunk, id)| {
+                GetMultipleAccounts::new(chunk.to_vec(), Some(self.config()), Some(id))
+            })
+            .collect()
+    }
+
+    /// Record the mint decimals in a response.
+    ///
+    /// Args:
+    ///     request (GetMultipleAccounts): A request returned by :meth:`mint_requests`.
+    ///     response (GetMultipleAccountsResp): Its response.
+    ///
+    /// Raises:
+    ///     ValueError: If the response doesn't have one entry per requested mint,
+    ///         or an account isn't a mint.
+    ///
+    pub fn handle_mints(
+        &mut self,
+        request: GetMultipleAccounts,
+        response: GetMultipleAccountsResp,
+    ) -> PyResult<()> {
+        let mints = request.accounts();
+        let accounts = response.accounts();
+        if mints.len() != accounts.len() {
+            return Err(to_py_value_err(&format!(
+                "request has {} accounts but response has {}",
+                mints.len(),
+                accounts.len()
+            )));
+        }
+        for (mint, account) in mints.into_iter().zip(accounts) {
+            if let Some(account) = account {
+                validate_mint_account(account)?;
+                self.decimals
+                    .insert(mint, account.data()[MINT_DECIMALS_OFFSET]);
+            }
+        }
+        Ok(())
+    }
+
+    /// Set the decimals of a mint, e.g. from a cache, so it isn't requested.
+    ///
+    /// Args:
+    ///     mint (Pubkey): The mint.
+    ///     decimals (int): Its decimals.
+    ///
+    pub fn set_decimals(&mut self, mint: Pubkey, decimals: u8) {
+        self.decimals.insert(mint, decimals);
+    }
+
+    /// The wallet's token holdings.
+    ///
+    /// Args:
+    ///     include_empty (bool): Whether to include token accounts with a zero balance.
+    ///
+    /// Returns:
+    ///     List[TokenHolding]: One holding per token account, in the order they were recorded.
+    ///
+    /// Raises:
+    ///     ValueError: If the decimals of a held mint are unknown.
+    ///
+    #[pyo3(signature = (include_empty = true))]
+    pub fn holdings(&self, include_empty: bool) -> PyResult<Vec<TokenHolding>> {
+        let mut holdings = Vec::with_capacity(self.token_accounts.len());
+        for (address, account) in &self.token_accounts {
+            let holding = self.holding(*address, account)?;
+            if include_empty || holding.amount > 0 {
+                holdings.push(holding);
+            }
+        }
+        Ok(holdings)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+pub(crate) fn create_portfolio_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "portfolio")?;
+    m.add_class::<TokenHolding>()?;
+    m.add_class::<PortfolioQuery>()?;
+    Ok(m)
+}
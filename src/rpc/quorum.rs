@@ -0,0 +1,255 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use pyo3::prelude::*;
+use solders_macros::richcmp_eq_only;
+use solders_primitives::pubkey::Pubkey;
+use solders_traits::{to_py_value_err, RichcmpEqualityOnly};
+
+use crate::rpc::responses::{RpcContactInfo, RpcVoteAccountStatus};
+
+/// The share of stake, in percent, the cluster needs to confirm a block.
+pub const SUPERMAJORITY_PERCENT: f64 = 200.0 / 3.0;
+
+/// Parse the numeric core of a version such as ``1.14.5`` or ``1.14.5-rc1``.
+fn parse_version(version: &str) -> Option<Vec<u64>> {
+    version
+        .split(['-', '+'])
+        .next()?
+        .split('.')
+        .map(|part| part.parse().ok())
+        .collect()
+}
+
+fn compare_versions(a: &[u64], b: &[u64]) -> Ordering {
+    let len = a.len().max(b.len());
+    let part = |v: &[u64], i: usize| v.get(i).copied().unwrap_or(0);
+    (0..len)
+        .map(|i| part(a, i))
+        .cmp((0..len).map(|i| part(b, i)))
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct VoterStake {
+    node_pubkey: Pubkey,
+    stake: u64,
+    delinquent: bool,
+}
+
+/// Stake-weighted quorum math over ``getVoteAccounts`` data.
+///
+/// The total is the activated stake of every vote account, delinquent ones
+/// included, as the cluster counts it. Versions come from ``getClusterNodes``,
+/// joined on the validator identity; stake of validators missing from gossip or
+/// reporting no version counts towards the total but never towards a version.
+///
+/// Args:
+///     vote_accounts (RpcVoteAccountStatus): The ``getVoteAccounts`` result.
+///
+/// Example:
+///     >>> from solders.rpc.quorum import StakeWeights, SUPERMAJORITY_PERCENT
+///     >>> from solders.rpc.responses import RpcContactInfo, RpcVoteAccountInfo, RpcVoteAccountStatus
+///     >>> from solders.pubkey import Pubkey
+///     >>> nodes = [Pubkey.new_unique() for _ in range(3)]
+///     >>> voters = [RpcVoteAccountInfo(Pubkey.new_unique(), n, s, 0, True, [], 0, 0) for n, s in zip(nodes, [70, 20, 10])]
+///     >>> weights = StakeWeights(RpcVoteAccountStatus(voters[:2], voters[2:]))
+///     >>> gossip = [RpcContactInfo(n, None, None, None, v, None, None) for n, v in zip(nodes, ["1.14.7", "1.13.5", "1.14.5"])]
+///     >>> weights.percent_at_least_version(gossip, "1.14.0")
+///     80.0
+///     >>> weights.percent_at_least_version(gossip, "1.14.0") >= SUPERMAJORITY_PERCENT
+///     True
+///     >>> weights.min_stake_for(SUPERMAJORITY_PERCENT)
+///     67
+///
+#[pyclass(module = "solders.rpc.quorum", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StakeWeights {
+    voters: Vec<VoterStake>,
+}
+
+impl StakeWeights {
+    fn versions(cluster_nodes: &[RpcContactInfo]) -> HashMap<Pubkey, Option<String>> {
+        cluster_nodes
+            .iter()
+            .map(|node| (node.pubkey, node.version.clone()))
+            .collect()
+    }
+
+    fn stake_where(&self, mut pred: impl FnMut(&VoterStake) -> bool) -> u64 {
+        self.voters
+            .iter()
+            .filter(|voter| pred(voter))
+            .map(|voter| voter.stake)
+            .sum()
+    }
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl StakeWeights {
+    #[new]
+    pub fn new(vote_accounts: &RpcVoteAccountStatus) -> Self {
+        let current = vote_accounts.current().into_iter().map(|a| (a, false));
+        let delinquent = vote_accounts.delinquent().into_iter().map(|a| (a, true));
+        let voters = current
+            .chain(delinquent)
+            .map(|(account, delinquent)| VoterStake {
+                node_pubkey: account.node_pubkey(),
+                stake: account.activated_stake(),
+                delinquent,
+            })
+            .collect();
+        Self { voters }
+    }
+
+    /// int: The activated stake of every vote account.
+    #[getter]
+    pub fn total_stake(&self) -> u64 {
+        self.stake_where(|_| true)
+    }
+
+    /// int: The activated stake of delinquent vote accounts.
+    #[getter]
+    pub fn delinquent_stake(&self) -> u64 {
+        self.stake_where(|voter| voter.delinquent)
+    }
+
+    /// The share of the total stake that ``stake`` represents.
+    ///
+    /// Args:
+    ///     stake (int): A stake amount in lamports.
+    ///
+    /// Returns:
+    ///     float: The percentage, or ``0.0`` if there is no stake at all.
+    ///
+    pub fn percent(&self, stake: u64) -> f64 {
+        match self.total_stake() {
+            0 => 0.0,
+            total => stake as f64 * 100.0 / total as f64,
+        }
+    }
+
+    /// The least stake that makes up at least ``percent`` of the total.
+    ///
+    /// Args:
+    ///     percent (float): The threshold, e.g. :data:`SUPERMAJORITY_PERCENT`.
+    ///
+    /// Returns:
+    ///     int: The stake in lamports.
+    ///
+    /// Raises:
+    ///     ValueError: If ``percent`` is NaN or outside 0 to 100.
+    ///
+    pub fn min_stake_for(&self, percent: f64) -> PyResult<u64> {
+        if !(0.0..=100.0).contains(&percent) {
+            return Err(to_py_value_err(&format!(
+                "percent must be between 0 and 100, got {}",
+                percent
+            )));
+        }
+        let total = self.total_stake();
+        Ok(((total as f64 * percent / 100.0).ceil() as u64).min(total))
+    }
+
+    /// The stake of a set of validators.
+    ///
+    /// Args:
+    ///     node_pubkeys (Sequence[Pubkey]): The validator identities.
+    ///
+    /// Returns:
+    ///     int: The activated stake of their vote accounts.
+    ///
+    pub fn stake_of(&self, node_pubkeys: Vec<Pubkey>) -> u64 {
+        self.stake_where(|voter| node_pubkeys.contains(&voter.node_pubkey))
+    }
+
+    /// Group stake by the version each validator reports in gossip.
+    ///
+    /// Args:
+    ///     cluster_nodes (Sequence[RpcContactInfo]): The ``getClusterNodes`` result.
+    ///
+    /// Returns:
+    ///     Dict[Optional[str], int]: The stake per version. Stake of validators with
+    ///     no known version is under ``None``.
+    ///
+    pub fn stake_by_version(
+        &self,
+        cluster_nodes: Vec<RpcContactInfo>,
+    ) -> HashMap<Option<String>, u64> {
+        let versions = Self::versions(&cluster_nodes);
+        let mut by_version = HashMap::new();
+        for voter in &self.voters {
+            let version = versions.get(&voter.node_pubkey).cloned().flatten();
+            *by_version.entry(version).or_insert(0) += voter.stake;
+        }
+        by_version
+    }
+
+    /// The stake of validators running ``min_version`` or newer.
+    ///
+    /// Pre-release suffixes such as ``-rc1`` are ignored, and versions that
+    /// aren't dot-separated numbers never qualify.
+    ///
+    /// Args:
+    ///     cluster_nodes (Sequence[RpcContactInfo]): The ``getClusterNodes`` result.
+    ///     min_version (str): The oldest qualifying version, e.g. ``"1.14.5"``.
+    ///
+    /// Returns:
+    ///     int: The qualifying stake in lamports.
+    ///
+    /// Raises:
+    ///     ValueError: If ``min_version`` can't be parsed.
+    ///
+    pub fn stake_at_least_version(
+        &self,
+        cluster_nodes: Vec<RpcContactInfo>,
+        min_version: &str,
+    ) -> PyResult<u64> {
+        let min = parse_version(min_version)
+            .ok_or_else(|| to_py_value_err(&format!("invalid version {min_version:?}")))?;
+        let versions = Self::versions(&cluster_nodes);
+        Ok(self.stake_where(|voter| {
+            versions
+                .get(&voter.node_pubkey)
+                .and_then(|v| v.as_deref())
+                .and_then(parse_version)
+                .map_or(false, |v| compare_versions(&v, &min) != Ordering::Less)
+        }))
+    }
+
+    /// The share of stake on validators running ``min_version`` or newer.
+    ///
+    /// Args:
+    ///     cluster_nodes (Sequence[RpcContactInfo]): The ``getClusterNodes`` result.
+    ///     min_version (str): The oldest qualifying version, e.g. ``"1.14.5"``.
+    ///
+    /// Returns:
+    ///     float: The percentage of the total stake.
+    ///
+    /// Raises:
+    ///     ValueError: If ``min_version`` can't be parsed.
+    ///
+    pub fn percent_at_least_version(
+        &self,
+        cluster_nodes: Vec<RpcContactInfo>,
+        min_version: &str,
+    ) -> PyResult<f64> {
+        Ok(self.percent(self.stake_at_least_version(cluster_nodes, min_version)?))
+    }
+
+    fn __len__(&self) -> usize {
+        self.voters.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for StakeWeights {}
+
+pub(crate) fn create_quorum_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "quorum")?;
+    m.add("SUPERMAJORITY_PERCENT", SUPERMAJORITY_PERCENT)?;
+    m.add_class::<StakeWeights>()?;
+    Ok(m)
+}
@@ -1,7 +1,7 @@
 #![allow(deprecated)]
 use crate::commitment_config::{CommitmentConfig, CommitmentLevel};
 use pyo3::{exceptions::PyValueError, prelude::*, types::PyTuple, PyTypeInfo};
-use solders_primitives::{message::Message, pubkey::Pubkey, transaction::Transaction};
+use solders_primitives::{message::VersionedMessage, pubkey::Pubkey, transaction::Transaction};
 use solders_traits::{
     py_from_bytes_general_via_cbor, pybytes_general_via_cbor, to_py_err,
     RichcmpEqualityOnly,
@@ -12,14 +12,17 @@ use crate::rpc::tmp_config::{
 };
 use camelpaste::paste;
 use serde::{Deserialize, Serialize};
-use serde_with::{serde_as, skip_serializing_none, DisplayFromStr, FromInto};
+use serde_with::{serde_as, skip_serializing_none, DisplayFromStr, FromInto, TryFromInto};
 use solana_sdk::{
-    message::Message as MessageOriginal, transaction::Transaction as TransactionOriginal,
+    message::VersionedMessage as VersionedMessageOriginal,
+    transaction::Transaction as TransactionOriginal,
 };
 use solders_macros::{common_methods, richcmp_eq_only, rpc_id_getter, EnumIntoPy};
 
 use crate::{Signature, SolderHash};
 
+use super::{fields::AccountFields, filter::RpcFilterType};
+
 use super::config::{
     RpcAccountInfoConfig, RpcBlockConfig, RpcBlockProductionConfig, RpcBlockSubscribeConfig,
     RpcBlockSubscribeFilterWrapper, RpcContextConfig, RpcEpochConfig, RpcGetVoteAccountsConfig,
@@ -146,7 +149,7 @@ Example:
                 #[pymethods]
                 impl $name {
                     #[new]
-                    fn new(id: Option<u64>) -> Self {
+                    pub fn new(id: Option<u64>) -> Self {
                         let base = RequestBase::new(id);
                         Self { base }
                     }
@@ -232,6 +235,28 @@ impl GetAccountInfo {
         Self { base, params }
     }
 
+    /// Build a request that fetches only the needed data, in the cheapest sufficient encoding.
+    ///
+    /// Args:
+    ///     pubkey (Pubkey): Pubkey of account to query.
+    ///     fields (AccountFields): The data needed.
+    ///     commitment (Optional[CommitmentLevel]): Bank state to query.
+    ///     id (Optional[int]): Request ID.
+    ///
+    /// Returns:
+    ///     GetAccountInfo: The request.
+    ///
+    #[staticmethod]
+    #[pyo3(signature = (pubkey, fields, commitment = None, id = None))]
+    pub fn for_fields(
+        pubkey: Pubkey,
+        fields: &AccountFields,
+        commitment: Option<CommitmentLevel>,
+        id: Option<u64>,
+    ) -> Self {
+        Self::new(pubkey, Some(fields.config(commitment)), id)
+    }
+
     /// Pubkey: Pubkey of account to query.
     #[getter]
     pub fn pubkey(&self) -> Pubkey {
@@ -527,7 +552,7 @@ pub struct GetBlocks {
 #[pymethods]
 impl GetBlocks {
     #[new]
-    fn new(
+    pub(crate) fn new(
         start: u64,
         end: Option<u64>,
         commitment: Option<CommitmentLevel>,
@@ -711,18 +736,21 @@ zero_param_req_def!(GetEpochSchedule);
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 struct MessageBase64(pub String);
 
-impl From<Message> for MessageBase64 {
-    fn from(m: Message) -> Self {
-        Self(base64::encode(m.0.serialize()))
+impl From<VersionedMessage> for MessageBase64 {
+    fn from(m: VersionedMessage) -> Self {
+        let bytes = bincode::serialize(&VersionedMessageOriginal::from(m)).unwrap();
+        Self(base64::encode(bytes))
     }
 }
 
-impl From<MessageBase64> for Message {
-    fn from(m: MessageBase64) -> Self {
-        let bytes = base64::decode(m.0).unwrap();
-        bincode::deserialize::<MessageOriginal>(&bytes)
-            .unwrap()
-            .into()
+impl TryFrom<MessageBase64> for VersionedMessage {
+    type Error = String;
+
+    fn try_from(m: MessageBase64) -> Result<Self, Self::Error> {
+        let bytes = base64::decode(m.0).map_err(|e| e.to_string())?;
+        bincode::deserialize::<VersionedMessageOriginal>(&bytes)
+            .map(Into::into)
+            .map_err(|e| e.to_string())
     }
 }
 
@@ -730,7 +758,7 @@ impl From<MessageBase64> for Message {
 #[skip_serializing_none]
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct GetFeeForMessageParams(
-    #[serde_as(as = "FromInto<MessageBase64>")] Message,
+    #[serde_as(as = "TryFromInto<MessageBase64>")] VersionedMessage,
     #[serde_as(as = "Option<FromInto<CommitmentConfig>>")]
     #[serde(default)]
     Option<CommitmentLevel>,
@@ -739,7 +767,7 @@ pub struct GetFeeForMessageParams(
 /// A ``getFeeForMessage`` request.
 ///
 /// Args:
-///     message (Message): The message for which to calculate the fee.
+///     message (Message | MessageV0): The message for which to calculate the fee.
 ///     commitment (Optional[CommitmentLevel]): Bank state to query.
 ///     id (Optional[int]): Request ID.
 ///
@@ -764,15 +792,19 @@ pub struct GetFeeForMessage {
 #[pymethods]
 impl GetFeeForMessage {
     #[new]
-    fn new(message: Message, commitment: Option<CommitmentLevel>, id: Option<u64>) -> Self {
+    fn new(
+        message: VersionedMessage,
+        commitment: Option<CommitmentLevel>,
+        id: Option<u64>,
+    ) -> Self {
         let params = GetFeeForMessageParams(message, commitment);
         let base = RequestBase::new(id);
         Self { base, params }
     }
 
-    /// Message: The message for which to calculate the fee.
+    /// Union[Message, MessageV0]: The message for which to calculate the fee.
     #[getter]
-    pub fn message(&self) -> Message {
+    pub fn message(&self) -> VersionedMessage {
         self.params.0.clone()
     }
 
@@ -1173,12 +1205,38 @@ pub struct GetMultipleAccounts {
 #[pymethods]
 impl GetMultipleAccounts {
     #[new]
-    fn new(accounts: Vec<Pubkey>, config: Option<RpcAccountInfoConfig>, id: Option<u64>) -> Self {
+    pub(crate) fn new(
+        accounts: Vec<Pubkey>,
+        config: Option<RpcAccountInfoConfig>,
+        id: Option<u64>,
+    ) -> Self {
         let params = GetMultipleAccountsParams(accounts, config);
         let base = RequestBase::new(id);
         Self { base, params }
     }
 
+    /// Build a request that fetches only the needed data, in the cheapest sufficient encoding.
+    ///
+    /// Args:
+    ///     accounts (Sequence[Pubkey]): Accounts to query.
+    ///     fields (AccountFields): The data needed.
+    ///     commitment (Optional[CommitmentLevel]): Bank state to query.
+    ///     id (Optional[int]): Request ID.
+    ///
+    /// Returns:
+    ///     GetMultipleAccounts: The request.
+    ///
+    #[staticmethod]
+    #[pyo3(signature = (accounts, fields, commitment = None, id = None))]
+    pub fn for_fields(
+        accounts: Vec<Pubkey>,
+        fields: &AccountFields,
+        commitment: Option<CommitmentLevel>,
+        id: Option<u64>,
+    ) -> Self {
+        Self::new(accounts, Some(fields.config(commitment)), id)
+    }
+
     /// Sequence[Pubkey]: Accounts to query.
     #[getter]
     pub fn accounts(&self) -> Vec<Pubkey> {
@@ -1240,6 +1298,31 @@ impl GetProgramAccounts {
         Self { base, params }
     }
 
+    /// Build a scan that fetches only the needed data, in the cheapest sufficient encoding.
+    ///
+    /// Args:
+    ///     program (Pubkey): The program that owns the accounts
+    ///     fields (AccountFields): The data needed.
+    ///     filters (Optional[Sequence[int | Memcmp]]): Filters every account must match.
+    ///     commitment (Optional[CommitmentLevel]): Bank state to query.
+    ///     id (Optional[int]): Request ID.
+    ///
+    /// Returns:
+    ///     GetProgramAccounts: The request.
+    ///
+    #[staticmethod]
+    #[pyo3(signature = (program, fields, filters = None, commitment = None, id = None))]
+    pub fn for_fields(
+        program: Pubkey,
+        fields: &AccountFields,
+        filters: Option<Vec<RpcFilterType>>,
+        commitment: Option<CommitmentLevel>,
+        id: Option<u64>,
+    ) -> Self {
+        let config = RpcProgramAccountsConfig::new(fields.config(commitment), filters, None);
+        Self::new(program, Some(config), id)
+    }
+
     /// Pubkey: The program that owns the accounts
     #[getter]
     pub fn program(&self) -> Pubkey {
@@ -1297,6 +1380,55 @@ impl GetRecentPerformanceSamples {
 
 request_boilerplate!(GetRecentPerformanceSamples);
 
+/// A ``getRecentPrioritizationFees`` request.
+///
+/// Pass the accounts a transaction will write to, so the fees reflect contention
+/// for those accounts rather than the whole cluster.
+///
+/// Args:
+///     locked_writable_accounts (Optional[Sequence[Pubkey]]): Up to 128 writable accounts
+///         to scope the fees to.
+///     id (Optional[int]): Request ID.
+///
+/// Example:
+///     >>> from solders.rpc.requests import GetRecentPrioritizationFees
+///     >>> from solders.pubkey import Pubkey
+///     >>> GetRecentPrioritizationFees([Pubkey.default()]).to_json()
+///     '{"method":"getRecentPrioritizationFees","jsonrpc":"2.0","id":0,"params":[["11111111111111111111111111111111"]]}'
+///
+#[serde_as]
+#[pyclass(module = "solders.rpc.requests")]
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetRecentPrioritizationFees {
+    #[serde(flatten)]
+    base: RequestBase,
+    #[serde_as(as = "Option<(Vec<DisplayFromStr>,)>")]
+    #[serde(default)]
+    params: Option<(Vec<Pubkey>,)>,
+}
+
+#[richcmp_eq_only]
+#[common_methods]
+#[rpc_id_getter]
+#[pymethods]
+impl GetRecentPrioritizationFees {
+    #[new]
+    fn new(locked_writable_accounts: Option<Vec<Pubkey>>, id: Option<u64>) -> Self {
+        let params = locked_writable_accounts.map(|x| (x,));
+        let base = RequestBase::new(id);
+        Self { base, params }
+    }
+
+    /// Optional[Sequence[Pubkey]]: The writable accounts the fees are scoped to.
+    #[getter]
+    pub fn locked_writable_accounts(&self) -> Option<Vec<Pubkey>> {
+        self.params.as_ref().map(|x| x.0.clone())
+    }
+}
+
+request_boilerplate!(GetRecentPrioritizationFees);
+
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
@@ -1333,7 +1465,7 @@ pub struct GetSignaturesForAddress {
 #[pymethods]
 impl GetSignaturesForAddress {
     #[new]
-    fn new(
+    pub(crate) fn new(
         address: Pubkey,
         config: Option<RpcSignaturesForAddressConfig>,
         id: Option<u64>,
@@ -1395,7 +1527,7 @@ pub struct GetSignatureStatuses {
 #[pymethods]
 impl GetSignatureStatuses {
     #[new]
-    fn new(
+    pub(crate) fn new(
         signatures: Vec<Signature>,
         config: Option<RpcSignatureStatusConfig>,
         id: Option<u64>,
@@ -1449,7 +1581,7 @@ pub struct GetSlot {
 #[pymethods]
 impl GetSlot {
     #[new]
-    fn new(config: Option<RpcContextConfig>, id: Option<u64>) -> Self {
+    pub(crate) fn new(config: Option<RpcContextConfig>, id: Option<u64>) -> Self {
         let params = config.map(|c| (c,));
         let base = RequestBase::new(id);
         Self { base, params }
@@ -1534,7 +1666,7 @@ pub struct GetSlotLeaders {
 #[pymethods]
 impl GetSlotLeaders {
     #[new]
-    fn new(start: u64, limit: u64, id: Option<u64>) -> Self {
+    pub fn new(start: u64, limit: u64, id: Option<u64>) -> Self {
         let params = (start, limit);
         let base = RequestBase::new(id);
         Self { base, params }
@@ -1833,7 +1965,7 @@ pub struct GetTokenAccountsByOwner {
 #[pymethods]
 impl GetTokenAccountsByOwner {
     #[new]
-    fn new(
+    pub(crate) fn new(
         account: Pubkey,
         filter_: RpcTokenAccountsFilterWrapper,
         config: Option<RpcAccountInfoConfig>,
@@ -2528,7 +2660,7 @@ request_boilerplate!(BlockSubscribe);
 #[skip_serializing_none]
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize, Default)]
 pub struct LogsSubscribeParams(
-    #[serde_as(as = "FromInto<RpcTransactionLogsFilter>")] TransactionLogsFilterWrapper,
+    #[serde_as(as = "TryFromInto<RpcTransactionLogsFilter>")] TransactionLogsFilterWrapper,
     #[serde(default)] Option<RpcTransactionLogsConfig>,
 );
 
@@ -2750,6 +2882,7 @@ pyunion!(
     GetMultipleAccounts,
     GetProgramAccounts,
     GetRecentPerformanceSamples,
+    GetRecentPrioritizationFees,
     GetSignaturesForAddress,
     GetSignatureStatuses,
     GetSlot,
@@ -2878,6 +3011,7 @@ pub fn create_requests_mod(py: Python<'_>) -> PyResult<&PyModule> {
             GetMultipleAccounts::type_object(py),
             GetProgramAccounts::type_object(py),
             GetRecentPerformanceSamples::type_object(py),
+            GetRecentPrioritizationFees::type_object(py),
             GetSignaturesForAddress::type_object(py),
             GetSignatureStatuses::type_object(py),
             GetSlot::type_object(py),
@@ -2952,6 +3086,7 @@ pub fn create_requests_mod(py: Python<'_>) -> PyResult<&PyModule> {
     requests_mod.add_class::<GetMultipleAccounts>()?;
     requests_mod.add_class::<GetProgramAccounts>()?;
     requests_mod.add_class::<GetRecentPerformanceSamples>()?;
+    requests_mod.add_class::<GetRecentPrioritizationFees>()?;
     requests_mod.add_class::<GetSignaturesForAddress>()?;
     requests_mod.add_class::<GetSignatureStatuses>()?;
     requests_mod.add_class::<GetSlot>()?;
@@ -1,5 +1,5 @@
 #![allow(clippy::large_enum_variant, clippy::too_many_arguments)]
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::Display;
 use std::str::FromStr;
 
@@ -13,6 +13,7 @@ use crate::rpc::tmp_response::{
     RpcContactInfo as RpcContactInfoOriginal, RpcInflationGovernor as RpcInflationGovernorOriginal,
     RpcInflationRate as RpcInflationRateOriginal, RpcInflationReward as RpcInflationRewardOriginal,
     RpcLogsResponse as RpcLogsResponseOriginal, RpcPerfSample as RpcPerfSampleOriginal,
+    RpcPrioritizationFee as RpcPrioritizationFeeOriginal,
     RpcSnapshotSlotInfo as RpcSnapshotSlotInfoOriginal,
     RpcStakeActivation as RpcStakeActivationOriginal, RpcSupply as RpcSupplyOriginal,
     RpcVote as RpcVoteOriginal, SlotInfo as SlotInfoOriginal,
@@ -37,6 +38,7 @@ use crate::transaction_status::{
 use crate::{
     self as solders,
     account::{Account, AccountJSON},
+    metrics,
     tmp_account_decoder::{UiAccount, UiAccountData, UiTokenAmount as UiTokenAmountOriginal},
     tmp_transaction_status::{
         TransactionConfirmationStatus as TransactionConfirmationStatusOriginal,
@@ -50,10 +52,12 @@ use derive_more::{From, Into};
 use pyo3::exceptions::PyValueError;
 use pyo3::types::PyType;
 use pyo3::{
+    basic::CompareOp,
     prelude::*,
     types::{PyBytes, PyTuple},
     PyClass, PyTypeInfo,
 };
+use pythonize::pythonize;
 use serde::{de::Error, Deserialize, Serialize, Serializer};
 use serde_json::Value;
 use serde_with::{serde_as, DisplayFromStr, FromInto, OneOrMany, TryFromInto};
@@ -64,12 +68,14 @@ use solana_sdk::{
     transaction_context::TransactionReturnData as TransactionReturnDataOriginal,
 };
 use solders_macros::{
-    common_methods, common_methods_rpc_resp, enum_original_mapping, richcmp_eq_only, EnumIntoPy,
+    common_methods, common_methods_rpc_resp, enum_original_mapping, enum_string, richcmp_eq_only,
+    EnumIntoPy,
 };
 use solders_primitives::{pubkey::Pubkey, signature::Signature};
 use solders_traits::{
-    py_from_bytes_general_via_bincode, pybytes_general_via_bincode, to_py_err, PyBytesBincode,
-    PyFromBytesBincode, RichcmpEqualityOnly,
+    handle_py_value_err, py_from_bytes_general_via_bincode, pybytes_general_via_bincode,
+    richcmp_type_error, to_py_err, to_py_value_err, EnumString, PyBytesBincode, PyFromBytesBincode,
+    RichcmpEqualityOnly,
 };
 
 use super::errors::{
@@ -81,6 +87,7 @@ use super::errors::{
     SlotSkippedMessage, TransactionPrecompileVerificationFailureMessage,
     UnsupportedTransactionVersion, UnsupportedTransactionVersionMessage,
 };
+use super::filter::AccountFilter;
 
 pub trait CommonMethodsRpcResp<'a>:
     std::fmt::Display
@@ -555,6 +562,74 @@ impl RpcResponseContext {
 
 response_data_boilerplate!(RpcResponseContext);
 
+/// A value paired with the context of the response it came from.
+///
+/// Every response and notification with a context has a ``with_context()``
+/// method returning one of these, so the slot travels with the value. The
+/// :func:`parse_program_accounts`, :func:`parse_token_accounts` and
+/// :func:`parse_account_notification` parsers return them directly.
+///
+/// Args:
+///     value (Any): The value.
+///     context (RpcResponseContext): The response context.
+///
+/// Example:
+///     >>> from solders.rpc.responses import GetBalanceResp, RpcResponseContext
+///     >>> resp = GetBalanceResp(5000, RpcResponseContext(slot=100))
+///     >>> with_context = resp.with_context()
+///     >>> with_context.slot, with_context.value
+///     (100, 5000)
+///
+#[pyclass(module = "solders.rpc.responses", subclass)]
+#[derive(Debug, Clone)]
+pub struct WithContext {
+    /// RpcResponseContext: The response context.
+    #[pyo3(get)]
+    pub context: RpcResponseContext,
+    /// Any: The value.
+    #[pyo3(get)]
+    pub value: PyObject,
+}
+
+impl WithContext {
+    fn py_eq(&self, py: Python<'_>, other: &Self) -> PyResult<bool> {
+        Ok(self.context == other.context && self.value.as_ref(py).eq(other.value.as_ref(py))?)
+    }
+}
+
+#[pymethods]
+impl WithContext {
+    #[new]
+    pub fn new(value: PyObject, context: RpcResponseContext) -> Self {
+        Self { context, value }
+    }
+
+    /// int: The slot the value was read at.
+    #[getter]
+    pub fn slot(&self) -> Slot {
+        self.context.slot
+    }
+
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        Ok(format!(
+            "WithContext(context={:?}, value={})",
+            self.context,
+            self.value.as_ref(py).repr()?
+        ))
+    }
+
+    fn __richcmp__(&self, py: Python<'_>, other: &Self, op: CompareOp) -> PyResult<bool> {
+        match op {
+            CompareOp::Eq => self.py_eq(py, other),
+            CompareOp::Ne => self.py_eq(py, other).map(|eq| !eq),
+            CompareOp::Lt => Err(richcmp_type_error("<")),
+            CompareOp::Gt => Err(richcmp_type_error(">")),
+            CompareOp::Le => Err(richcmp_type_error("<=")),
+            CompareOp::Ge => Err(richcmp_type_error(">=")),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 #[serde(untagged)]
 pub enum Resp<T: IntoPy<PyObject>> {
@@ -661,6 +736,37 @@ pub enum WebsocketMessage {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct WebsocketMessages(#[serde_as(deserialize_as = "OneOrMany<_>")] Vec<WebsocketMessage>);
 
+impl WebsocketMessage {
+    /// Apply ``filter`` and return whether to keep the message. Account and program
+    /// notifications are dropped when they don't match, and block notifications keep
+    /// only the matching transactions.
+    fn apply_filter(&mut self, filter: &AccountFilter) -> bool {
+        match self {
+            Self::Notification(Notification::BlockNotification { params, .. }) => {
+                let update = &mut params.result.value.0;
+                if let Some(block) = update.block.take() {
+                    let mut block = UiConfirmedBlock::from(block);
+                    block.retain_transactions(|tx| filter.matches_transaction(tx));
+                    update.block = Some(block.into());
+                }
+                true
+            }
+            Self::Notification(Notification::AccountNotification { params, .. }) => {
+                filter.matches_original(params.result.value.as_ref())
+            }
+            Self::Notification(Notification::ProgramNotification { params, .. }) => match params {
+                ProgramNotificationType::Binary(n) => {
+                    filter.matches_original(n.result.value.account.as_ref())
+                }
+                ProgramNotificationType::JsonParsed(n) => {
+                    filter.matches_json(&n.result.value.account)
+                }
+            },
+            _ => true,
+        }
+    }
+}
+
 impl IntoPy<PyObject> for WebsocketMessages {
     fn into_py(self, py: Python<'_>) -> PyObject {
         self.0.into_py(py)
@@ -817,6 +923,15 @@ macro_rules! contextful_resp_boilerplate {
             pub fn new(value: $inner, context: RpcResponseContext) -> Self {
                 Self { value, context }
             }
+
+            /// The value together with the context it was read at.
+            ///
+            /// Returns:
+            ///     WithContext: The context and value.
+            ///
+            pub fn with_context(&self, py: Python<'_>) -> WithContext {
+                WithContext::new(self.value.clone().into_py(py), self.context.clone())
+            }
         }
     };
 }
@@ -832,6 +947,15 @@ macro_rules! notification_boilerplate {
                 pub fn new(value: $inner, context: RpcResponseContext) -> Self {
                     Self { value, context }
                 }
+
+                /// The value together with the context it was read at.
+                ///
+                /// Returns:
+                ///     WithContext: The context and value.
+                ///
+                pub fn with_context(&self, py: Python<'_>) -> WithContext {
+                    WithContext::new(self.value.clone().into_py(py), self.context.clone())
+                }
             }
             response_data_boilerplate!($name);
             #[common_methods]
@@ -841,6 +965,15 @@ macro_rules! notification_boilerplate {
                 pub fn new(result: [<$name Result>], subscription: u64) -> Self {
                     Self { result, subscription }
                 }
+
+                /// The notification value together with the context it was read at.
+                ///
+                /// Returns:
+                ///     WithContext: The context and value.
+                ///
+                pub fn with_context(&self, py: Python<'_>) -> WithContext {
+                    self.result.with_context(py)
+                }
             }
         }
     };
@@ -953,6 +1086,36 @@ impl GetBlockCommitmentResp {
             total_stake,
         }
     }
+
+    /// The stake that has voted on the block with at least ``min_depth`` confirmations.
+    ///
+    /// Args:
+    ///     min_depth (int): The index into ``commitment`` to start counting from.
+    ///
+    /// Returns:
+    ///     int: The voted stake in lamports, or 0 if the node has no commitment for the block.
+    ///
+    #[pyo3(signature = (min_depth = 0))]
+    pub fn voted_stake(&self, min_depth: usize) -> u64 {
+        self.commitment
+            .map_or(0, |c| c.iter().skip(min_depth).sum())
+    }
+
+    /// :meth:`voted_stake` as a fraction of ``total_stake``.
+    ///
+    /// Args:
+    ///     min_depth (int): The index into ``commitment`` to start counting from.
+    ///
+    /// Returns:
+    ///     float: The fraction, or 0.0 if ``total_stake`` is zero.
+    ///
+    #[pyo3(signature = (min_depth = 0))]
+    pub fn voted_stake_fraction(&self, min_depth: usize) -> f64 {
+        if self.total_stake == 0 {
+            return 0.0;
+        }
+        self.voted_stake(min_depth) as f64 / self.total_stake as f64
+    }
 }
 
 contextless_resp_eq!(GetBlockHeightResp, u64);
@@ -1567,6 +1730,12 @@ contextful_resp_eq!(
     Vec<Option<Account>>,
     "Vec<Option<TryFromInto<UiAccount>>>"
 );
+
+impl GetMultipleAccountsResp {
+    pub(crate) fn accounts(&self) -> &[Option<Account>] {
+        &self.value
+    }
+}
 contextful_resp_eq!(
     GetMultipleAccountsJsonParsedResp,
     Vec<Option<AccountJSON>>,
@@ -1691,6 +1860,13 @@ impl RpcKeyedAccountJsonParsed {
 }
 
 contextful_resp_eq!(GetProgramAccountsWithContextResp, Vec<RpcKeyedAccount>);
+
+impl GetProgramAccountsWithContextResp {
+    pub(crate) fn slot_and_accounts(&self) -> (Slot, &[RpcKeyedAccount]) {
+        (self.context.slot, &self.value)
+    }
+}
+
 contextful_resp_eq!(
     GetProgramAccountsWithContextJsonParsedResp,
     Vec<RpcKeyedAccountJsonParsed>
@@ -1761,6 +1937,41 @@ impl RpcPerfSample {
 
 contextless_resp_eq!(GetRecentPerformanceSamplesResp, Vec<RpcPerfSample>, clone);
 
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone, From, Into)]
+#[pyclass(module = "solders.rpc.responses", subclass)]
+pub struct RpcPrioritizationFee(RpcPrioritizationFeeOriginal);
+
+response_data_boilerplate!(RpcPrioritizationFee);
+
+#[richcmp_eq_only]
+#[common_methods]
+#[pymethods]
+impl RpcPrioritizationFee {
+    #[new]
+    pub fn new(slot: Slot, prioritization_fee: u64) -> Self {
+        RpcPrioritizationFeeOriginal {
+            slot,
+            prioritization_fee,
+        }
+        .into()
+    }
+
+    #[getter]
+    pub fn slot(&self) -> Slot {
+        self.0.slot
+    }
+    #[getter]
+    pub fn prioritization_fee(&self) -> u64 {
+        self.0.prioritization_fee
+    }
+}
+
+contextless_resp_eq!(
+    GetRecentPrioritizationFeesResp,
+    Vec<RpcPrioritizationFee>,
+    clone
+);
+
 // the one in solana_client uses transaction_status
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -1843,6 +2054,12 @@ contextful_resp_eq!(
     "Vec<Option<FromInto<TransactionStatusOriginal>>>"
 );
 
+impl GetSignatureStatusesResp {
+    pub(crate) fn statuses(&self) -> &[Option<TransactionStatus>] {
+        &self.value
+    }
+}
+
 contextless_resp_eq!(GetSlotResp, Slot);
 contextless_resp_eq!(GetSlotLeaderResp, Pubkey, "DisplayFromStr");
 contextless_resp_eq!(
@@ -1863,6 +2080,12 @@ pub enum StakeActivationState {
     Inactive,
 }
 
+impl EnumString for StakeActivationState {}
+
+#[enum_string]
+#[pymethods]
+impl StakeActivationState {}
+
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone, From, Into)]
 #[pyclass(module = "solders.rpc.responses", subclass)]
 pub struct RpcStakeActivation(RpcStakeActivationOriginal);
@@ -1958,6 +2181,12 @@ contextful_resp_eq!(
     Vec<RpcKeyedAccountJsonParsed>
 );
 contextful_resp_eq!(GetTokenAccountsByOwnerResp, Vec<RpcKeyedAccount>);
+
+impl GetTokenAccountsByOwnerResp {
+    pub(crate) fn accounts(&self) -> &[RpcKeyedAccount] {
+        &self.value
+    }
+}
 contextful_resp_eq!(
     GetTokenAccountsByOwnerJsonParsedResp,
     Vec<RpcKeyedAccountJsonParsed>
@@ -2614,6 +2843,12 @@ impl RpcBlockUpdate {
     }
 }
 
+impl RpcBlockUpdate {
+    pub(crate) fn parent_slot(&self) -> Option<Slot> {
+        self.0.block.as_ref().map(|b| b.parent_slot)
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
 #[pyclass(module = "solders.rpc.responses", subclass)]
 pub struct SubscriptionResult {
@@ -2676,6 +2911,12 @@ contextless_resp_eq!(RequestAirdropResp, Signature, "DisplayFromStr");
 contextless_resp_eq!(SendTransactionResp, Signature, "DisplayFromStr");
 contextful_resp_eq!(SimulateTransactionResp, RpcSimulateTransactionResult);
 
+impl SimulateTransactionResp {
+    pub(crate) fn result(&self) -> &RpcSimulateTransactionResult {
+        &self.value
+    }
+}
+
 notification!(AccountNotification, Account, "TryFromInto<UiAccount>");
 notification!(
     AccountNotificationJsonParsed,
@@ -2685,6 +2926,13 @@ notification!(
 notification_no_eq!(BlockNotification, RpcBlockUpdate);
 notification!(LogsNotification, RpcLogsResponse);
 notification!(ProgramNotification, RpcKeyedAccount);
+
+impl ProgramNotification {
+    pub(crate) fn slot_and_account(&self) -> (Slot, &RpcKeyedAccount) {
+        (self.result.context.slot, &self.result.value)
+    }
+}
+
 notification!(ProgramNotificationJsonParsed, RpcKeyedAccountJsonParsed);
 notification!(SignatureNotification, RpcSignatureResponse);
 notification_contextless!(SlotNotification, SlotInfo);
@@ -2725,13 +2973,37 @@ macro_rules ! pyunion_resp {
                 }
             }
 
-            fn from_json(raw: &str, parser: &str) -> PyResult<Self> {
+            pub(crate) fn from_json(raw: &str, parser: &str) -> PyResult<Self> {
                 match parser {
                     stringify!($err_variant) => {let parsed = $err_variant::py_from_json(raw)?; let as_enum = Self::RPCError(parsed); Ok(as_enum)},
                     $(stringify!($variant) => {let parsed = $variant::py_from_json(raw)?; let as_enum = match parsed {Resp::Error {error, ..} => Self::RPCError(error), Resp::Result {result, ..} => Self::$variant(result)};Ok(as_enum)},)+
                     _ => Err(PyValueError::new_err(format!("Unrecognised parser: {}", parser)))
                 }
             }
+
+            /// Parse only the ``result`` of a response, so errors keep their position.
+            pub(crate) fn parse_result(raw: &str, parser: &str) -> Result<(), serde_json::Error> {
+                match parser {
+                    $(stringify!($variant) => serde_json::from_str::<$variant>(raw).map(|_| ()),)+
+                    _ => Ok(()),
+                }
+            }
+
+            /// The paths of the fields in ``result`` or ``error`` that the parser ignores.
+            fn ignored_fields(field: &str, value: Value, parser: &str) -> Result<Vec<Vec<String>>, serde_json::Error> {
+                let mut ignored = Vec::new();
+                let mut record = |path: serde_ignored::Path| {
+                    let mut segments = Vec::new();
+                    path_segments(&path, &mut segments);
+                    ignored.push(segments);
+                };
+                match (field, parser) {
+                    ("error", _) => serde_ignored::deserialize(value, &mut record).map(|_: $err_variant| ())?,
+                    $(("result", stringify!($variant)) => serde_ignored::deserialize(value, &mut record).map(|_: $variant| ())?,)+
+                    _ => {}
+                }
+                Ok(ignored)
+            }
         }
 
         impl IntoPy<PyObject> for $name {
@@ -2787,6 +3059,7 @@ pyunion_resp!(
     GetProgramAccountsWithContextMaybeJsonParsedResp,
     GetProgramAccountsMaybeJsonParsedResp,
     GetRecentPerformanceSamplesResp,
+    GetRecentPrioritizationFeesResp,
     GetSignaturesForAddressResp,
     GetSignatureStatusesResp,
     GetSlotResp,
@@ -2856,6 +3129,12 @@ pub fn batch_to_json(resps: Vec<RPCResult>) -> String {
 ///
 #[pyfunction]
 pub fn batch_from_json(raw: &str, parsers: Vec<&PyType>) -> PyResult<Vec<PyObject>> {
+    metrics::time(&metrics::RPC_PARSE_SECONDS, || {
+        batch_from_json_untimed(raw, parsers)
+    })
+}
+
+fn batch_from_json_untimed(raw: &str, parsers: Vec<&PyType>) -> PyResult<Vec<PyObject>> {
     let raw_objects: Vec<serde_json::Map<String, Value>> =
         serde_json::from_str(raw).map_err(to_py_err)?;
     let raw_objects_len = raw_objects.len();
@@ -2871,6 +3150,129 @@ pub fn batch_from_json(raw: &str, parsers: Vec<&PyType>) -> PyResult<Vec<PyObjec
     }
 }
 
+fn path_segments(path: &serde_ignored::Path<'_>, segments: &mut Vec<String>) {
+    use serde_ignored::Path;
+    match path {
+        Path::Root => {}
+        Path::Seq { parent, index } => {
+            path_segments(parent, segments);
+            segments.push(index.to_string());
+        }
+        Path::Map { parent, key } => {
+            path_segments(parent, segments);
+            segments.push(key.clone());
+        }
+        Path::Some { parent }
+        | Path::NewtypeStruct { parent }
+        | Path::NewtypeVariant { parent } => path_segments(parent, segments),
+    }
+}
+
+/// Follow ``segments`` from ``value``, using them as object keys or array indexes.
+fn value_at(value: &Value, segments: &[String]) -> Value {
+    segments
+        .iter()
+        .try_fold(value, |v, segment| match v {
+            Value::Array(items) => segment.parse::<usize>().ok().and_then(|i| items.get(i)),
+            _ => v.get(segment),
+        })
+        .cloned()
+        .unwrap_or(Value::Null)
+}
+
+/// A response parsed by :func:`from_json_with_extras`, with the fields solders doesn't know about.
+#[pyclass(module = "solders.rpc.responses", subclass)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RespWithExtras {
+    value: RPCResult,
+    extras: BTreeMap<String, Value>,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl RespWithExtras {
+    /// RPCResult: The parsed response.
+    #[getter]
+    pub fn value(&self, py: Python<'_>) -> PyObject {
+        self.value.clone().into_py(py)
+    }
+
+    /// The fields of the raw response that the parser dropped.
+    ///
+    /// Returns:
+    ///     Dict[str, Any]: The unknown fields keyed by their dotted path, e.g.
+    ///     ``"result.value.newField"``. Array elements use their index as the key.
+    ///
+    pub fn extras(&self, py: Python<'_>) -> PyResult<PyObject> {
+        handle_py_value_err(pythonize(py, &self.extras))
+    }
+
+    /// bool: Whether the raw response had fields the parser dropped.
+    #[getter]
+    pub fn has_extras(&self) -> bool {
+        !self.extras.is_empty()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for RespWithExtras {}
+
+/// Parse a response JSON string, keeping the fields solders doesn't know about.
+///
+/// The parsers ignore unknown fields so that new RPC node versions don't break
+/// parsing. This parses the same way but also records what was ignored, so
+/// consumers can read new fields before solders supports them.
+///
+/// Unknown fields are recorded as the parser skips them. Values the parser
+/// reads as raw JSON first, such as error objects, aren't inspected.
+///
+/// Args:
+///     raw (str): The response JSON.
+///     parser: The response class to parse with, e.g. ``GetBalanceResp``.
+///
+/// Returns:
+///     RespWithExtras: The parsed response and its unknown fields.
+///
+/// Example:
+///     >>> from solders.rpc.responses import from_json_with_extras, GetBlockHeightResp
+///     >>> raw = '{"jsonrpc": "2.0", "result": 1233, "id": 1, "served_by": "node-3"}'
+///     >>> parsed = from_json_with_extras(raw, GetBlockHeightResp)
+///     >>> parsed.value, parsed.extras()
+///     (GetBlockHeightResp(
+///         1233,
+///     ), {'served_by': 'node-3'})
+///
+#[pyfunction]
+pub fn from_json_with_extras(raw: &str, parser: &PyType) -> PyResult<RespWithExtras> {
+    let parser = parser.name()?;
+    let value = RPCResult::from_json(raw, parser)?;
+    let envelope: serde_json::Map<String, Value> = serde_json::from_str(raw).map_err(to_py_err)?;
+    let mut extras = BTreeMap::new();
+    for (key, field) in envelope {
+        match key.as_str() {
+            "jsonrpc" | "id" => {}
+            "result" | "error" => {
+                let ignored =
+                    RPCResult::ignored_fields(&key, field.clone(), parser).map_err(to_py_err)?;
+                for segments in ignored {
+                    let path = std::iter::once(key.as_str())
+                        .chain(segments.iter().map(String::as_str))
+                        .collect::<Vec<_>>()
+                        .join(".");
+                    extras.insert(path, value_at(&field, &segments));
+                }
+            }
+            _ => {
+                extras.insert(key, field);
+            }
+        }
+    }
+    Ok(RespWithExtras { value, extras })
+}
+
 /// Parse a notification received by a Solana websocket subscription.
 ///
 /// Args:
@@ -2890,7 +3292,19 @@ pub fn batch_from_json(raw: &str, parsers: Vec<&PyType>) -> PyResult<Vec<PyObjec
 ///
 #[pyfunction]
 pub fn parse_notification(msg: &str) -> PyResult<Notification> {
-    serde_json::from_str(msg).map_err(to_py_err)
+    let parsed: Notification = parse_counted(msg)?;
+    metrics::inc(&metrics::WEBSOCKET_MESSAGES, 1);
+    Ok(parsed)
+}
+
+/// Parse a websocket message, timing it and counting it if it fails to parse.
+fn parse_counted<T: serde::de::DeserializeOwned>(msg: &str) -> PyResult<T> {
+    metrics::time(&metrics::WEBSOCKET_PARSE_SECONDS, || {
+        serde_json::from_str(msg).map_err(|e| {
+            metrics::inc(&metrics::WEBSOCKET_PARSE_ERRORS, 1);
+            to_py_err(e)
+        })
+    })
 }
 
 /// Parse a message or array of messages received by a Solana websocket subscription.
@@ -2922,8 +3336,196 @@ pub fn parse_notification(msg: &str) -> PyResult<Notification> {
 ///     }]
 ///
 #[pyfunction]
-pub fn parse_websocket_message(msg: &str) -> PyResult<WebsocketMessages> {
-    serde_json::from_str(msg).map_err(to_py_err)
+#[pyo3(signature = (msg, filter = None))]
+pub fn parse_websocket_message(
+    msg: &str,
+    filter: Option<AccountFilter>,
+) -> PyResult<WebsocketMessages> {
+    let mut parsed: WebsocketMessages = parse_counted(msg)?;
+    metrics::inc(&metrics::WEBSOCKET_MESSAGES, parsed.0.len() as u64);
+    if let Some(filter) = filter {
+        parsed.0.retain_mut(|message| message.apply_filter(&filter));
+    }
+    Ok(parsed)
+}
+
+/// Parse a ``getBlock`` response, dropping the transactions that don't match ``filter``.
+///
+/// Transactions are checked before they are converted to Python objects, so this is
+/// much cheaper than filtering the parsed block.
+///
+/// Args:
+///     raw (str): The raw response JSON.
+///     filter (Optional[AccountFilter]): The filter to apply. See :class:`~solders.rpc.filter.AccountFilter`
+///         for how transactions are matched.
+///     skip_votes (bool): Drop vote transactions too. Indexers usually discard them, and
+///         this never converts them to Python objects.
+///
+/// Returns:
+///     Union[RPCError, GetBlockResp]: The parsed response, with only the matching transactions.
+///
+/// Example:
+///     >>> from solders.rpc.responses import parse_block
+///     >>> from solders.rpc.filter import AccountFilter
+///     >>> from solders.pubkey import Pubkey
+///     >>> raw = '{"jsonrpc":"2.0","result":{"blockHeight":1,"blockTime":null,"blockhash":"11111111111111111111111111111111","parentSlot":0,"previousBlockhash":"11111111111111111111111111111111","transactions":[{"meta":null,"transaction":{"message":{"accountKeys":["11111111111111111111111111111111"],"header":{"numReadonlySignedAccounts":0,"numReadonlyUnsignedAccounts":0,"numRequiredSignatures":1},"instructions":[{"accounts":[],"data":"2","programIdIndex":0}],"recentBlockhash":"11111111111111111111111111111111"},"signatures":["1111111111111111111111111111111111111111111111111111111111111111"]}}]},"id":1}'
+///     >>> len(parse_block(raw, AccountFilter(Pubkey.default())).value.transactions)
+///     1
+///     >>> len(parse_block(raw, AccountFilter(Pubkey.default(), min_data_len=2)).value.transactions)
+///     0
+///     >>> len(parse_block(raw, skip_votes=True).value.transactions)
+///     1
+///
+#[pyfunction]
+#[pyo3(signature = (raw, filter = None, skip_votes = false))]
+pub fn parse_block(
+    py: Python<'_>,
+    raw: &str,
+    filter: Option<AccountFilter>,
+    skip_votes: bool,
+) -> PyResult<PyObject> {
+    let parsed: Resp<GetBlockResp> = serde_json::from_str(raw).map_err(to_py_err)?;
+    Ok(match parsed {
+        Resp::Error { error, .. } => error.into_py(py),
+        Resp::Result { mut result, .. } => {
+            if let Some(block) = result.0.as_mut() {
+                block.retain_transactions(|tx| {
+                    !(skip_votes && tx.is_vote())
+                        && filter.as_ref().map_or(true, |f| f.matches_transaction(tx))
+                });
+            }
+            result.into_py(py)
+        }
+    })
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, EnumIntoPy)]
+#[serde(untagged)]
+enum ProgramAccountsResult {
+    WithContext(GetProgramAccountsWithContextResp),
+    Plain(GetProgramAccountsResp),
+}
+
+/// Parse a ``getProgramAccounts`` response, dropping the accounts that don't match ``filter``.
+///
+/// The response can use any binary encoding, with or without ``withContext``.
+/// Accounts are checked before they are converted to Python objects, so this is
+/// much cheaper than filtering the parsed response.
+///
+/// Args:
+///     raw (str): The raw response JSON.
+///     filter (AccountFilter): The filter to apply.
+///
+/// Returns:
+///     Union[RPCError, GetProgramAccountsResp, WithContext[List[RpcKeyedAccount]]]: The
+///     parsed response, with only the matching accounts. Responses fetched with
+///     ``withContext`` come back as a :class:`WithContext` so the slot is kept.
+///
+/// Example:
+///     >>> from solders.rpc.responses import parse_program_accounts
+///     >>> from solders.rpc.filter import AccountFilter
+///     >>> raw = '{"jsonrpc":"2.0","result":[{"pubkey":"11111111111111111111111111111111","account":{"data":["AQID","base64"],"executable":false,"lamports":1,"owner":"11111111111111111111111111111111","rentEpoch":0}}],"id":1}'
+///     >>> len(parse_program_accounts(raw, AccountFilter(min_data_len=3)).value)
+///     1
+///     >>> len(parse_program_accounts(raw, AccountFilter(min_data_len=4)).value)
+///     0
+///
+#[pyfunction]
+pub fn parse_program_accounts(
+    py: Python<'_>,
+    raw: &str,
+    filter: AccountFilter,
+) -> PyResult<PyObject> {
+    let parsed: Resp<ProgramAccountsResult> = serde_json::from_str(raw).map_err(to_py_err)?;
+    let keep = |keyed: &RpcKeyedAccount| filter.matches_original(keyed.account.as_ref());
+    Ok(match parsed {
+        Resp::Error { error, .. } => error.into_py(py),
+        Resp::Result { result, .. } => match result {
+            ProgramAccountsResult::WithContext(mut resp) => {
+                resp.value.retain(keep);
+                WithContext::new(resp.value.into_py(py), resp.context).into_py(py)
+            }
+            ProgramAccountsResult::Plain(mut resp) => {
+                resp.value.retain(keep);
+                resp.into_py(py)
+            }
+        },
+    })
+}
+
+/// Parse a ``getTokenAccountsByOwner`` or ``getTokenAccountsByDelegate`` response.
+///
+/// The response can use any binary encoding. Like :func:`parse_program_accounts`,
+/// accounts that don't match ``filter`` are dropped before they are converted to
+/// Python objects.
+///
+/// Args:
+///     raw (str): The raw response JSON.
+///     filter (Optional[AccountFilter]): The filter to apply.
+///
+/// Returns:
+///     Union[RPCError, WithContext[List[RpcKeyedAccount]]]: The matching token accounts
+///     and the context they were read at.
+///
+/// Example:
+///     >>> from solders.rpc.responses import parse_token_accounts
+///     >>> raw = '{"jsonrpc":"2.0","result":{"context":{"slot":7},"value":[]},"id":1}'
+///     >>> parse_token_accounts(raw).slot
+///     7
+///
+#[pyfunction]
+#[pyo3(signature = (raw, filter = None))]
+pub fn parse_token_accounts(
+    py: Python<'_>,
+    raw: &str,
+    filter: Option<AccountFilter>,
+) -> PyResult<PyObject> {
+    let parsed: Resp<GetTokenAccountsByOwnerResp> = serde_json::from_str(raw).map_err(to_py_err)?;
+    Ok(match parsed {
+        Resp::Error { error, .. } => error.into_py(py),
+        Resp::Result { result, .. } => {
+            let GetTokenAccountsByOwnerResp { mut value, context } = result;
+            if let Some(filter) = filter {
+                value.retain(|keyed| filter.matches_original(keyed.account.as_ref()));
+            }
+            WithContext::new(value.into_py(py), context).into_py(py)
+        }
+    })
+}
+
+/// Parse an ``accountNotification`` or ``programNotification`` subscription message.
+///
+/// Args:
+///     msg (str): The raw message JSON.
+///
+/// Returns:
+///     Tuple[int, WithContext[Union[Account, RpcKeyedAccount, RpcKeyedAccountJsonParsed]]]: The
+///     subscription ID, and the account with the context it was read at.
+///
+/// Raises:
+///     ValueError: If the message is a different notification.
+///
+/// Example:
+///     >>> from solders.rpc.responses import parse_account_notification
+///     >>> raw = '{"jsonrpc":"2.0","method":"accountNotification","params":{"result":{"context":{"slot":5},"value":{"data":["","base64"],"executable":false,"lamports":1,"owner":"11111111111111111111111111111111","rentEpoch":0}},"subscription":3}}'
+///     >>> subscription, account = parse_account_notification(raw)
+///     >>> subscription, account.slot, account.value.lamports
+///     (3, 5, 1)
+///
+#[pyfunction]
+pub fn parse_account_notification(py: Python<'_>, msg: &str) -> PyResult<(u64, WithContext)> {
+    let parsed: Notification = parse_counted(msg)?;
+    metrics::inc(&metrics::WEBSOCKET_MESSAGES, 1);
+    match parsed {
+        Notification::AccountNotification { params, .. } => {
+            Ok((params.subscription, params.with_context(py)))
+        }
+        Notification::ProgramNotification { params, .. } => Ok(match params {
+            ProgramNotificationType::Binary(n) => (n.subscription, n.with_context(py)),
+            ProgramNotificationType::JsonParsed(n) => (n.subscription, n.with_context(py)),
+        }),
+        _ => Err(to_py_value_err(&"not an account or program notification")),
+    }
 }
 
 pub(crate) fn create_responses_mod(py: Python<'_>) -> PyResult<&PyModule> {
@@ -3000,6 +3602,7 @@ pub(crate) fn create_responses_mod(py: Python<'_>) -> PyResult<&PyModule> {
         GetProgramAccountsMaybeJsonParsedResp::type_object(py),
         GetProgramAccountsWithContextMaybeJsonParsedResp::type_object(py),
         GetRecentPerformanceSamplesResp::type_object(py),
+        GetRecentPrioritizationFeesResp::type_object(py),
         GetSignaturesForAddressResp::type_object(py),
         GetSignatureStatusesResp::type_object(py),
         GetSlotResp::type_object(py),
@@ -3125,6 +3728,8 @@ pub(crate) fn create_responses_mod(py: Python<'_>) -> PyResult<&PyModule> {
     m.add_class::<GetProgramAccountsMaybeJsonParsedResp>()?;
     m.add_class::<RpcPerfSample>()?;
     m.add_class::<GetRecentPerformanceSamplesResp>()?;
+    m.add_class::<RpcPrioritizationFee>()?;
+    m.add_class::<GetRecentPrioritizationFeesResp>()?;
     m.add_class::<RpcConfirmedTransactionStatusWithSignature>()?;
     m.add_class::<GetSignaturesForAddressResp>()?;
     m.add_class::<GetSignatureStatusesResp>()?;
@@ -3190,6 +3795,8 @@ pub(crate) fn create_responses_mod(py: Python<'_>) -> PyResult<&PyModule> {
     m.add_class::<VoteNotification>()?;
     m.add_class::<RpcBlockUpdate>()?;
     m.add_class::<BlockStoreError>()?;
+    m.add_class::<RespWithExtras>()?;
+    m.add_class::<WithContext>()?;
     m.add("RPCError", rpc_error_alias)?;
     m.add("RPCResult", rpc_result_alias)?;
     m.add("SlotUpdate", slot_update_alias)?;
@@ -3199,7 +3806,12 @@ pub(crate) fn create_responses_mod(py: Python<'_>) -> PyResult<&PyModule> {
     let funcs = [
         wrap_pyfunction!(batch_to_json, m)?,
         wrap_pyfunction!(batch_from_json, m)?,
+        wrap_pyfunction!(from_json_with_extras, m)?,
         wrap_pyfunction!(parse_websocket_message, m)?,
+        wrap_pyfunction!(parse_program_accounts, m)?,
+        wrap_pyfunction!(parse_token_accounts, m)?,
+        wrap_pyfunction!(parse_account_notification, m)?,
+        wrap_pyfunction!(parse_block, m)?,
         wrap_pyfunction!(parse_notification, m)?,
     ];
     for func in funcs {
@@ -0,0 +1,385 @@
+use std::collections::{BTreeMap, HashMap};
+
+use pyo3::prelude::*;
+use solana_sdk::{
+    account::Account as AccountOriginal,
+    clock::Slot,
+    hash::{hashv, Hash as HashOriginal},
+};
+use solders_macros::richcmp_eq_only;
+use solders_primitives::pubkey::Pubkey;
+use solders_traits::RichcmpEqualityOnly;
+
+use crate::{
+    log::{self, LogLevel},
+    metrics,
+    rpc::{
+        responses::{RpcBlockUpdate, RpcKeyedAccount, SlotInfo, SlotUpdate},
+        tmp_response::SlotUpdate as SlotUpdateOriginal,
+    },
+};
+
+/// What a :class:`SlotTracker` noticed while processing a message.
+#[pyclass(module = "solders.rpc.streams", subclass)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SlotTrackerReport {
+    /// List[Tuple[int, int]]: Inclusive ranges of ancestor slots that were never observed.
+    #[pyo3(get)]
+    pub gaps: Vec<(Slot, Slot)>,
+    /// List[Tuple[int, int]]: ``(slot, parent)`` pairs for slots that branch off below the current tip.
+    #[pyo3(get)]
+    pub forks: Vec<(Slot, Slot)>,
+    /// List[int]: Slots reported dead by the validator.
+    #[pyo3(get)]
+    pub dead: Vec<Slot>,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl SlotTrackerReport {
+    #[new]
+    pub fn new(gaps: Vec<(Slot, Slot)>, forks: Vec<(Slot, Slot)>, dead: Vec<Slot>) -> Self {
+        Self { gaps, forks, dead }
+    }
+
+    /// Whether nothing unusual was observed.
+    pub fn is_empty(&self) -> bool {
+        self.gaps.is_empty() && self.forks.is_empty() && self.dead.is_empty()
+    }
+
+    fn __bool__(&self) -> bool {
+        !self.is_empty()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for SlotTrackerReport {}
+
+/// Tracks the slot frontier from slot, slot update, root and block notifications.
+///
+/// Feed it the ``result`` of each notification as it arrives.
+/// Every ``process_*`` method returns a :class:`SlotTrackerReport` describing
+/// any gaps (ancestor slots that were never seen) or forks detected.
+///
+/// Example:
+///     >>> from solders.rpc.streams import SlotTracker
+///     >>> from solders.rpc.responses import SlotInfo
+///     >>> tracker = SlotTracker()
+///     >>> tracker.process_slot(SlotInfo(slot=10, parent=9, root=0)).is_empty()
+///     True
+///     >>> tracker.process_slot(SlotInfo(slot=14, parent=13, root=0)).gaps
+///     [(11, 13)]
+///     >>> tracker.process_slot(SlotInfo(slot=15, parent=10, root=0)).forks
+///     [(15, 10)]
+///     >>> tracker.processed
+///     15
+///
+#[pyclass(module = "solders.rpc.streams", subclass)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SlotTracker {
+    parents: BTreeMap<Slot, Slot>,
+    processed: Option<Slot>,
+    confirmed: Option<Slot>,
+    finalized: Option<Slot>,
+}
+
+impl SlotTracker {
+    fn observe(&mut self, slot: Slot) {
+        self.processed = Some(self.processed.map_or(slot, |p| p.max(slot)));
+    }
+
+    fn observe_with_parent(&mut self, slot: Slot, parent: Slot) -> SlotTrackerReport {
+        let mut report = SlotTrackerReport::default();
+        if self.parents.contains_key(&slot) {
+            return report;
+        }
+        if let Some(tip) = self.processed {
+            if parent > tip {
+                report.gaps.push((tip + 1, parent));
+            } else if parent < tip && !self.parents.values().any(|p| *p == slot) {
+                report.forks.push((slot, parent));
+            }
+        }
+        self.parents.insert(slot, parent);
+        self.observe(slot);
+        report
+    }
+
+    /// Emit a warning for everything in ``report``.
+    fn logged(py: Python<'_>, report: SlotTrackerReport) -> SlotTrackerReport {
+        let target = "solders.rpc.streams";
+        let missed = report
+            .gaps
+            .iter()
+            .map(|(first, last)| last - first + 1)
+            .sum();
+        metrics::inc(&metrics::SLOT_TRACKER_MISSED, missed);
+        metrics::inc(&metrics::SLOT_TRACKER_FORKS, report.forks.len() as u64);
+        metrics::inc(&metrics::SLOT_TRACKER_DEAD, report.dead.len() as u64);
+        for (first, last) in &report.gaps {
+            log::emit(
+                py,
+                LogLevel::Warning,
+                target,
+                format!("missed slots {first} to {last}"),
+                &[("first", first.to_string()), ("last", last.to_string())],
+            );
+        }
+        for (slot, parent) in &report.forks {
+            log::emit(
+                py,
+                LogLevel::Warning,
+                target,
+                format!("slot {slot} forks from {parent}"),
+                &[("slot", slot.to_string()), ("parent", parent.to_string())],
+            );
+        }
+        for slot in &report.dead {
+            log::emit(
+                py,
+                LogLevel::Warning,
+                target,
+                format!("slot {slot} is dead"),
+                &[("slot", slot.to_string())],
+            );
+        }
+        report
+    }
+
+    fn set_finalized(&mut self, root: Slot) {
+        if self.finalized.map_or(true, |f| root > f) {
+            self.finalized = Some(root);
+            self.parents = self.parents.split_off(&root);
+        }
+        if self.confirmed.map_or(true, |c| root > c) {
+            self.confirmed = Some(root);
+        }
+        self.observe(root);
+    }
+}
+
+#[pymethods]
+impl SlotTracker {
+    #[new]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Optional[int]: The highest slot observed.
+    #[getter]
+    pub fn processed(&self) -> Option<Slot> {
+        self.processed
+    }
+
+    /// Optional[int]: The highest optimistically confirmed slot observed.
+    #[getter]
+    pub fn confirmed(&self) -> Option<Slot> {
+        self.confirmed
+    }
+
+    /// Optional[int]: The highest root observed.
+    #[getter]
+    pub fn finalized(&self) -> Option<Slot> {
+        self.finalized
+    }
+
+    /// Process the result of a ``slotNotification``.
+    ///
+    /// Args:
+    ///     info (SlotInfo): The notification result.
+    ///
+    /// Returns:
+    ///     SlotTrackerReport: Gaps and forks detected.
+    ///
+    pub fn process_slot(&mut self, py: Python<'_>, info: SlotInfo) -> SlotTrackerReport {
+        let report = self.observe_with_parent(info.slot(), info.parent());
+        self.set_finalized(info.root());
+        Self::logged(py, report)
+    }
+
+    /// Process the result of a ``slotsUpdatesNotification``.
+    ///
+    /// Args:
+    ///     update (SlotUpdate): The notification result.
+    ///
+    /// Returns:
+    ///     SlotTrackerReport: Gaps, forks and dead slots detected.
+    ///
+    pub fn process_slot_update(&mut self, py: Python<'_>, update: SlotUpdate) -> SlotTrackerReport {
+        let report = match SlotUpdateOriginal::from(update) {
+            SlotUpdateOriginal::CreatedBank { slot, parent, .. } => {
+                self.observe_with_parent(slot, parent)
+            }
+            SlotUpdateOriginal::OptimisticConfirmation { slot, .. } => {
+                if self.confirmed.map_or(true, |c| slot > c) {
+                    self.confirmed = Some(slot);
+                }
+                self.observe(slot);
+                SlotTrackerReport::default()
+            }
+            SlotUpdateOriginal::Root { slot, .. } => {
+                self.set_finalized(slot);
+                SlotTrackerReport::default()
+            }
+            SlotUpdateOriginal::Dead { slot, .. } => {
+                self.parents.remove(&slot);
+                SlotTrackerReport {
+                    dead: vec![slot],
+                    ..Default::default()
+                }
+            }
+            SlotUpdateOriginal::FirstShredReceived { slot, .. }
+            | SlotUpdateOriginal::Completed { slot, .. }
+            | SlotUpdateOriginal::Frozen { slot, .. } => {
+                self.observe(slot);
+                SlotTrackerReport::default()
+            }
+        };
+        Self::logged(py, report)
+    }
+
+    /// Process the result of a ``rootNotification``.
+    ///
+    /// Args:
+    ///     root (int): The new root slot.
+    ///
+    /// Returns:
+    ///     SlotTrackerReport: Always empty; provided for a uniform interface.
+    ///
+    pub fn process_root(&mut self, root: Slot) -> SlotTrackerReport {
+        self.set_finalized(root);
+        SlotTrackerReport::default()
+    }
+
+    /// Process the result of a ``blockNotification``.
+    ///
+    /// Args:
+    ///     update (RpcBlockUpdate): The notification result.
+    ///
+    /// Returns:
+    ///     SlotTrackerReport: Gaps and forks detected.
+    ///
+    pub fn process_block(&mut self, py: Python<'_>, update: &RpcBlockUpdate) -> SlotTrackerReport {
+        let report = match update.parent_slot() {
+            Some(parent) => self.observe_with_parent(update.slot(), parent),
+            None => {
+                self.observe(update.slot());
+                SlotTrackerReport::default()
+            }
+        };
+        Self::logged(py, report)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "SlotTracker(processed={:?}, confirmed={:?}, finalized={:?})",
+            self.processed, self.confirmed, self.finalized
+        )
+    }
+}
+
+/// Suppresses program notifications whose account did not change.
+///
+/// An update is a duplicate only if the lamports, data, owner, executable flag
+/// and rent epoch all match the last update for the account. Keeps a hash of
+/// those fields for each account, so memory use is constant per account
+/// regardless of data size.
+///
+/// Example:
+///     >>> from solders.rpc.streams import ProgramUpdateDeduper
+///     >>> from solders.rpc.responses import RpcKeyedAccount
+///     >>> from solders.account import Account
+///     >>> from solders.pubkey import Pubkey
+///     >>> deduper = ProgramUpdateDeduper()
+///     >>> pubkey, owner = Pubkey.new_unique(), Pubkey.new_unique()
+///     >>> deduper.process(RpcKeyedAccount(pubkey, Account(1, b"abc", owner)))
+///     True
+///     >>> deduper.process(RpcKeyedAccount(pubkey, Account(1, b"abc", owner)))
+///     False
+///     >>> deduper.process(RpcKeyedAccount(pubkey, Account(2, b"abc", owner)))
+///     True
+///
+#[pyclass(module = "solders.rpc.streams", subclass)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProgramUpdateDeduper {
+    hashes: HashMap<Pubkey, HashOriginal>,
+}
+
+#[pymethods]
+impl ProgramUpdateDeduper {
+    #[new]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record an account update.
+    ///
+    /// Args:
+    ///     keyed_account (RpcKeyedAccount): The ``value`` of a ``programNotification``.
+    ///
+    /// Returns:
+    ///     bool: ``True`` if the account changed (or is new) and the update should be forwarded.
+    ///
+    pub fn process(&mut self, keyed_account: RpcKeyedAccount) -> bool {
+        let account = AccountOriginal::from(keyed_account.account);
+        let account_hash = hashv(&[
+            &account.lamports.to_le_bytes(),
+            account.owner.as_ref(),
+            &[u8::from(account.executable)],
+            &account.rent_epoch.to_le_bytes(),
+            &account.data,
+        ]);
+        self.hashes.insert(keyed_account.pubkey, account_hash) != Some(account_hash)
+    }
+
+    /// Record many account updates, e.g. a ``getProgramAccounts`` snapshot.
+    ///
+    /// Args:
+    ///     keyed_accounts (Sequence[RpcKeyedAccount]): The updates.
+    ///
+    /// Returns:
+    ///     List[RpcKeyedAccount]: The updates whose account changed.
+    ///
+    pub fn filter(&mut self, keyed_accounts: Vec<RpcKeyedAccount>) -> Vec<RpcKeyedAccount> {
+        keyed_accounts
+            .into_iter()
+            .filter(|k| self.process(k.clone()))
+            .collect()
+    }
+
+    /// Forget an account, so its next update is always forwarded.
+    ///
+    /// Args:
+    ///     pubkey (Pubkey): The account address.
+    ///
+    /// Returns:
+    ///     bool: Whether the account was being tracked.
+    ///
+    pub fn forget(&mut self, pubkey: Pubkey) -> bool {
+        self.hashes.remove(&pubkey).is_some()
+    }
+
+    /// Forget all accounts.
+    pub fn clear(&mut self) {
+        self.hashes.clear();
+    }
+
+    fn __len__(&self) -> usize {
+        self.hashes.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ProgramUpdateDeduper(tracked={})", self.hashes.len())
+    }
+}
+
+pub(crate) fn create_streams_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "streams")?;
+    m.add_class::<SlotTrackerReport>()?;
+    m.add_class::<SlotTracker>()?;
+    m.add_class::<ProgramUpdateDeduper>()?;
+    Ok(m)
+}
@@ -0,0 +1,277 @@
+use std::collections::HashMap;
+
+use pyo3::{exceptions::PyValueError, prelude::*};
+use solana_sdk::clock::{Epoch, Slot, DEFAULT_MS_PER_SLOT, NUM_CONSECUTIVE_LEADER_SLOTS};
+use solders_macros::richcmp_eq_only;
+use solders_primitives::pubkey::Pubkey;
+use solders_traits::RichcmpEqualityOnly;
+
+use crate::rpc::responses::{EpochInfo, RpcPerfSample};
+
+const DEFAULT_SECONDS_PER_SLOT: f64 = DEFAULT_MS_PER_SLOT as f64 / 1000.0;
+
+/// Estimated lifetime of a blockhash, as returned by :func:`estimate_blockhash_expiry`.
+#[pyclass(module = "solders.rpc.timing", subclass)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlockhashExpiry {
+    /// float: Average slot duration observed in the performance samples.
+    #[pyo3(get)]
+    pub seconds_per_slot: f64,
+    /// int: Blocks that can still be produced before the blockhash expires.
+    #[pyo3(get)]
+    pub blocks_remaining: u64,
+    /// float: Estimated seconds until the blockhash expires.
+    #[pyo3(get)]
+    pub seconds_remaining: f64,
+    /// float: Recommended delay between resubmissions of a transaction using the blockhash.
+    #[pyo3(get)]
+    pub resubmit_interval: f64,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl BlockhashExpiry {
+    #[new]
+    pub fn new(
+        seconds_per_slot: f64,
+        blocks_remaining: u64,
+        seconds_remaining: f64,
+        resubmit_interval: f64,
+    ) -> Self {
+        Self {
+            seconds_per_slot,
+            blocks_remaining,
+            seconds_remaining,
+            resubmit_interval,
+        }
+    }
+
+    /// Whether the blockhash can no longer be used.
+    pub fn is_expired(&self) -> bool {
+        self.blocks_remaining == 0
+    }
+
+    /// How many more times a transaction can be resubmitted at ``resubmit_interval``
+    /// before the blockhash expires.
+    pub fn max_resubmissions(&self) -> u64 {
+        if self.is_expired() || self.resubmit_interval <= 0.0 {
+            return 0;
+        }
+        (self.seconds_remaining / self.resubmit_interval).floor() as u64
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for BlockhashExpiry {}
+
+/// Estimate when a blockhash expires from recent performance samples.
+///
+/// The slot duration is averaged over the samples, falling back to the
+/// nominal 400ms when no usable sample is given. The resubmit interval is
+/// one leader rotation (4 slots), so each resubmission reaches a new leader,
+/// capped at the time remaining.
+///
+/// Block height only advances on slots that produce a block, so with skipped
+/// slots the blockhash lives longer than estimated. The estimate is conservative,
+/// more so when the skip rate is high.
+///
+/// Args:
+///     samples (Sequence[RpcPerfSample]): The result of ``getRecentPerformanceSamples``.
+///     block_height (int): The current block height.
+///     last_valid_block_height (int): The ``lastValidBlockHeight`` returned with the blockhash.
+///
+/// Returns:
+///     BlockhashExpiry: The estimate.
+///
+/// Example:
+///     >>> from solders.rpc.timing import estimate_blockhash_expiry
+///     >>> from solders.rpc.responses import RpcPerfSample
+///     >>> samples = [RpcPerfSample(slot=1000, num_transactions=0, num_slots=120, sample_period_secs=60)]
+///     >>> expiry = estimate_blockhash_expiry(samples, 100, 150)
+///     >>> expiry.seconds_remaining
+///     25.0
+///     >>> expiry.resubmit_interval
+///     2.0
+///     >>> expiry.max_resubmissions()
+///     12
+///
+#[pyfunction]
+pub fn estimate_blockhash_expiry(
+    samples: Vec<RpcPerfSample>,
+    block_height: u64,
+    last_valid_block_height: u64,
+) -> BlockhashExpiry {
+    let (total_secs, total_slots) =
+        samples
+            .iter()
+            .filter(|s| s.num_slots() > 0)
+            .fold((0u64, 0u64), |(secs, slots), s| {
+                (
+                    secs + u64::from(s.sample_period_secs()),
+                    slots + s.num_slots(),
+                )
+            });
+    let seconds_per_slot = if total_slots == 0 {
+        DEFAULT_SECONDS_PER_SLOT
+    } else {
+        total_secs as f64 / total_slots as f64
+    };
+    let blocks_remaining = last_valid_block_height.saturating_sub(block_height);
+    let seconds_remaining = blocks_remaining as f64 * seconds_per_slot;
+    let resubmit_interval =
+        (NUM_CONSECUTIVE_LEADER_SLOTS as f64 * seconds_per_slot).min(seconds_remaining);
+    BlockhashExpiry {
+        seconds_per_slot,
+        blocks_remaining,
+        seconds_remaining,
+        resubmit_interval,
+    }
+}
+
+/// How far the current epoch has progressed, as returned by :func:`epoch_progress`.
+#[pyclass(module = "solders.rpc.timing", subclass)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct EpochProgress {
+    /// int: The current epoch.
+    #[pyo3(get)]
+    pub epoch: Epoch,
+    /// int: Slots left in the epoch, including the current one.
+    #[pyo3(get)]
+    pub slots_remaining: u64,
+    /// float: Estimated seconds until the next epoch starts.
+    #[pyo3(get)]
+    pub seconds_remaining: f64,
+    /// float: Percentage of the epoch's slots that have passed.
+    #[pyo3(get)]
+    pub percent_complete: f64,
+    /// int: The first slot of the next epoch.
+    #[pyo3(get)]
+    pub next_epoch_start_slot: Slot,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl EpochProgress {
+    #[new]
+    pub fn new(
+        epoch: Epoch,
+        slots_remaining: u64,
+        seconds_remaining: f64,
+        percent_complete: f64,
+        next_epoch_start_slot: Slot,
+    ) -> Self {
+        Self {
+            epoch,
+            slots_remaining,
+            seconds_remaining,
+            percent_complete,
+            next_epoch_start_slot,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for EpochProgress {}
+
+/// Report how far the current epoch has progressed.
+///
+/// Args:
+///     epoch_info (EpochInfo): The result of ``getEpochInfo``.
+///     seconds_per_slot (float): The slot duration to assume, e.g.
+///         ``estimate_blockhash_expiry(...).seconds_per_slot``. Defaults to 0.4.
+///
+/// Returns:
+///     EpochProgress: The progress report.
+///
+/// Example:
+///     >>> from solders.rpc.timing import epoch_progress
+///     >>> from solders.rpc.responses import EpochInfo
+///     >>> info = EpochInfo(epoch=10, slot_index=108000, slots_in_epoch=432000, absolute_slot=4428000, block_height=4000000)
+///     >>> progress = epoch_progress(info)
+///     >>> progress.percent_complete, progress.slots_remaining, progress.next_epoch_start_slot
+///     (25.0, 324000, 4752000)
+///
+#[pyfunction]
+#[pyo3(signature = (epoch_info, seconds_per_slot = DEFAULT_SECONDS_PER_SLOT))]
+pub fn epoch_progress(epoch_info: &EpochInfo, seconds_per_slot: f64) -> EpochProgress {
+    let slots_remaining = epoch_info
+        .slots_in_epoch
+        .saturating_sub(epoch_info.slot_index);
+    let percent_complete = if epoch_info.slots_in_epoch == 0 {
+        100.0
+    } else {
+        epoch_info.slot_index as f64 * 100.0 / epoch_info.slots_in_epoch as f64
+    };
+    EpochProgress {
+        epoch: epoch_info.epoch,
+        slots_remaining,
+        seconds_remaining: slots_remaining as f64 * seconds_per_slot,
+        percent_complete,
+        next_epoch_start_slot: epoch_info.absolute_slot.saturating_add(slots_remaining),
+    }
+}
+
+/// List the upcoming slots in the current epoch where a validator is leader.
+///
+/// Args:
+///     epoch_info (EpochInfo): The result of ``getEpochInfo``.
+///     leader_schedule (Dict[Pubkey, Sequence[int]]): The result of ``getLeaderSchedule``
+///         for the current epoch, mapping identities to slot indexes within the epoch.
+///     identity (Pubkey): The validator identity.
+///     limit (Optional[int]): The maximum number of slots to return.
+///
+/// Returns:
+///     List[int]: Absolute slots, from the current slot onwards, in ascending order.
+///
+/// Raises:
+///     ValueError: If ``epoch_info.slot_index`` is larger than ``epoch_info.absolute_slot``.
+///
+#[pyfunction]
+#[pyo3(signature = (epoch_info, leader_schedule, identity, limit = None))]
+pub fn next_leader_slots(
+    epoch_info: &EpochInfo,
+    leader_schedule: HashMap<Pubkey, Vec<u64>>,
+    identity: Pubkey,
+    limit: Option<usize>,
+) -> PyResult<Vec<Slot>> {
+    let first_slot = epoch_info
+        .absolute_slot
+        .checked_sub(epoch_info.slot_index)
+        .ok_or_else(|| {
+            PyValueError::new_err(format!(
+                "slot_index {} is larger than absolute_slot {}",
+                epoch_info.slot_index, epoch_info.absolute_slot
+            ))
+        })?;
+    let mut slots: Vec<Slot> = leader_schedule
+        .get(&identity)
+        .map(|indexes| {
+            indexes
+                .iter()
+                .filter(|i| **i >= epoch_info.slot_index)
+                .filter_map(|i| first_slot.checked_add(*i))
+                .collect()
+        })
+        .unwrap_or_default();
+    slots.sort_unstable();
+    if let Some(limit) = limit {
+        slots.truncate(limit);
+    }
+    Ok(slots)
+}
+
+pub(crate) fn create_timing_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "timing")?;
+    m.add_class::<BlockhashExpiry>()?;
+    m.add_function(wrap_pyfunction!(estimate_blockhash_expiry, m)?)?;
+    m.add_class::<EpochProgress>()?;
+    m.add_function(wrap_pyfunction!(epoch_progress, m)?)?;
+    m.add_function(wrap_pyfunction!(next_leader_slots, m)?)?;
+    Ok(m)
+}
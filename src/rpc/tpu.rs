@@ -0,0 +1,397 @@
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    io,
+    net::{Ipv4Addr, Ipv6Addr, SocketAddr, UdpSocket},
+};
+
+use pyo3::{prelude::*, types::PyBytes};
+use solana_sdk::{
+    clock::Slot, packet::PACKET_DATA_SIZE, pubkey::Pubkey as PubkeyOriginal,
+    transaction::VersionedTransaction as VersionedTransactionOriginal,
+};
+use solders_primitives::pubkey::Pubkey;
+use solders_traits::to_py_value_err;
+
+use crate::{
+    metrics,
+    rpc::{
+        requests::{GetClusterNodes, GetSlotLeaders},
+        responses::{GetClusterNodesResp, GetSlotLeadersResp},
+    },
+    wallet::WalletTransaction,
+};
+
+/// The number of upcoming slots whose leaders receive each transaction by default.
+pub const DEFAULT_FANOUT_SLOTS: u64 = 12;
+/// The most slots a :class:`TpuLeaderPlanner` fans out to.
+pub const MAX_FANOUT_SLOTS: u64 = 100;
+/// How far a validator's TPU QUIC port is from its advertised TPU port.
+pub const QUIC_PORT_OFFSET: u16 = 6;
+
+/// Send ``packet`` to each address, trying each up to ``1 + retries`` times.
+///
+/// Returns the addresses that took the packet, or the last error if none did.
+fn send_udp(packet: &[u8], addrs: &[SocketAddr], retries: usize) -> io::Result<Vec<SocketAddr>> {
+    let mut v4 = None;
+    let mut v6 = None;
+    let mut sent = Vec::with_capacity(addrs.len());
+    let mut last_err = None;
+    for addr in addrs {
+        let socket = if addr.is_ipv4() { &mut v4 } else { &mut v6 };
+        if socket.is_none() {
+            *socket = Some(if addr.is_ipv4() {
+                UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0))?
+            } else {
+                UdpSocket::bind((Ipv6Addr::UNSPECIFIED, 0))?
+            });
+        }
+        let socket = socket.as_ref().unwrap();
+        for attempt in 0..=retries {
+            if attempt > 0 {
+                metrics::inc(&metrics::RETRIES, 1);
+            }
+            match socket.send_to(packet, addr) {
+                Ok(_) => {
+                    metrics::inc(&metrics::REQUESTS_SENT, 1);
+                    sent.push(*addr);
+                    break;
+                }
+                Err(e) => last_err = Some(e),
+            }
+        }
+    }
+    match last_err {
+        Some(e) if sent.is_empty() => Err(e),
+        _ => Ok(sent),
+    }
+}
+
+fn wire_bytes(transaction: WalletTransaction) -> PyResult<Vec<u8>> {
+    let tx = VersionedTransactionOriginal::from(transaction);
+    let bytes = bincode::serialize(&tx).map_err(|e| to_py_value_err(&e))?;
+    if bytes.len() > PACKET_DATA_SIZE {
+        return Err(to_py_value_err(&format!(
+            "transaction is {} bytes but a packet holds {PACKET_DATA_SIZE}",
+            bytes.len()
+        )));
+    }
+    Ok(bytes)
+}
+
+/// Finds the TPU addresses of the upcoming leaders, for sending transactions to them directly.
+///
+/// Sending to the leaders skips the RPC node's own forwarding. The planner makes no RPC
+/// calls itself: send :meth:`cluster_nodes_request` and :meth:`leaders_request` and pass
+/// their responses to :meth:`update_cluster_nodes` and :meth:`update_leaders`. Then
+/// :meth:`send_transaction` sends a transaction over UDP to each leader in the fanout
+/// window. Refresh the leaders whenever :meth:`needs_leaders` says so, and the cluster
+/// nodes every few minutes.
+///
+/// Only UDP sending is implemented. With ``use_quic`` the planner returns the leaders'
+/// TPU QUIC addresses from :meth:`leader_tpus` instead, and the QUIC handshake (ALPN
+/// ``solana-tpu``, a self-signed client certificate, one unidirectional stream per
+/// transaction) is up to the caller's QUIC library.
+///
+/// Args:
+///     fanout_slots (int): How many upcoming slots' leaders receive each transaction.
+///     use_quic (bool): Whether to return TPU QUIC addresses instead of UDP ones.
+///
+/// Raises:
+///     ValueError: If ``fanout_slots`` is zero or above 100.
+///
+/// Example:
+///     >>> from solders.pubkey import Pubkey
+///     >>> from solders.rpc.responses import GetClusterNodesResp, GetSlotLeadersResp, RpcContactInfo
+///     >>> from solders.rpc.tpu import TpuLeaderPlanner
+///     >>> a, b = Pubkey.new_unique(), Pubkey.new_unique()
+///     >>> planner = TpuLeaderPlanner(fanout_slots=8, use_quic=True)
+///     >>> nodes = [RpcContactInfo(a, None, "10.0.0.1:8003", None), RpcContactInfo(b, None, "10.0.0.2:8003", None)]
+///     >>> planner.update_cluster_nodes(GetClusterNodesResp(nodes))
+///     2
+///     >>> request = planner.leaders_request(500)
+///     >>> planner.update_leaders(request, GetSlotLeadersResp([a] * 4 + [b] * 4 + [a] * 92))
+///     >>> planner.needs_leaders(550), planner.needs_leaders(600)
+///     (False, True)
+///     >>> planner.leader_tpus(502)
+///     [('10.0.0.1', 8009), ('10.0.0.2', 8009)]
+///
+#[pyclass(module = "solders.rpc.tpu", subclass)]
+#[derive(Clone, Debug)]
+pub struct TpuLeaderPlanner {
+    fanout_slots: u64,
+    use_quic: bool,
+    leaders: BTreeMap<Slot, PubkeyOriginal>,
+    tpus: HashMap<PubkeyOriginal, SocketAddr>,
+}
+
+impl TpuLeaderPlanner {
+    /// The leaders of the fanout window starting at ``current_slot``, once each, in slot order.
+    fn upcoming_leaders(&self, current_slot: Slot) -> Vec<PubkeyOriginal> {
+        let mut seen = HashSet::new();
+        self.leaders
+            .range(current_slot..current_slot.saturating_add(self.fanout_slots))
+            .map(|(_, leader)| *leader)
+            .filter(|leader| seen.insert(*leader))
+            .collect()
+    }
+
+    fn tpu(&self, leader: &PubkeyOriginal) -> Option<SocketAddr> {
+        let mut addr = *self.tpus.get(leader)?;
+        if self.use_quic {
+            addr.set_port(addr.port().checked_add(QUIC_PORT_OFFSET)?);
+        }
+        Some(addr)
+    }
+}
+
+#[pymethods]
+impl TpuLeaderPlanner {
+    #[new]
+    #[pyo3(signature = (fanout_slots = DEFAULT_FANOUT_SLOTS, use_quic = false))]
+    pub fn new(fanout_slots: u64, use_quic: bool) -> PyResult<Self> {
+        if fanout_slots == 0 || fanout_slots > MAX_FANOUT_SLOTS {
+            return Err(to_py_value_err(&format!(
+                "fanout_slots must be between 1 and {MAX_FANOUT_SLOTS}, got {fanout_slots}"
+            )));
+        }
+        Ok(Self {
+            fanout_slots,
+            use_quic,
+            leaders: BTreeMap::new(),
+            tpus: HashMap::new(),
+        })
+    }
+
+    /// int: How many upcoming slots' leaders receive each transaction.
+    #[getter]
+    pub fn fanout_slots(&self) -> u64 {
+        self.fanout_slots
+    }
+
+    /// bool: Whether :meth:`leader_tpus` returns TPU QUIC addresses.
+    #[getter]
+    pub fn use_quic(&self) -> bool {
+        self.use_quic
+    }
+
+    /// Build the request for the cluster's contact info.
+    ///
+    /// Args:
+    ///     id (int): The request ID.
+    ///
+    /// Returns:
+    ///     GetClusterNodes: The request.
+    ///
+    #[pyo3(signature = (id = 0))]
+    pub fn cluster_nodes_request(&self, id: u64) -> GetClusterNodes {
+        GetClusterNodes::new(Some(id))
+    }
+
+    /// Replace the known TPU addresses with those in a ``getClusterNodes`` response.
+    ///
+    /// Nodes that advertise no TPU address, or one that doesn't parse, are left out.
+    ///
+    /// Args:
+    ///     response (GetClusterNodesResp): The response.
+    ///
+    /// Returns:
+    ///     int: The number of nodes with a TPU address.
+    ///
+    pub fn update_cluster_nodes(&mut self, response: GetClusterNodesResp) -> usize {
+        self.tpus = response
+            .value()
+            .into_iter()
+            .filter_map(|node| {
+                let tpu = node.tpu?.parse().ok()?;
+                Some((node.pubkey.into(), tpu))
+            })
+            .collect();
+        self.tpus.len()
+    }
+
+    /// Build the request for the leaders of the next 100 slots.
+    ///
+    /// Args:
+    ///     start_slot (int): The current slot.
+    ///     id (int): The request ID.
+    ///
+    /// Returns:
+    ///     GetSlotLeaders: The request.
+    ///
+    #[pyo3(signature = (start_slot, id = 0))]
+    pub fn leaders_request(&self, start_slot: Slot, id: u64) -> GetSlotLeaders {
+        GetSlotLeaders::new(start_slot, MAX_FANOUT_SLOTS, Some(id))
+    }
+
+    /// Store the leaders from a ``getSlotLeaders`` response.
+    ///
+    /// Leaders of slots before the request's start slot are dropped.
+    ///
+    /// Args:
+    ///     request (GetSlotLeaders): A request returned by :meth:`leaders_request`.
+    ///     response (GetSlotLeadersResp): Its response.
+    ///
+    /// Raises:
+    ///     ValueError: If the response has more leaders than requested.
+    ///
+    pub fn update_leaders(
+        &mut self,
+        request: GetSlotLeaders,
+        response: GetSlotLeadersResp,
+    ) -> PyResult<()> {
+        let leaders = response.value();
+        if leaders.len() as u64 > request.limit() {
+            return Err(to_py_value_err(&format!(
+                "requested {} leaders but response has {}",
+                request.limit(),
+                leaders.len()
+            )));
+        }
+        let start = request.start();
+        self.leaders = self.leaders.split_off(&start);
+        self.leaders.extend(
+            (start..)
+                .zip(leaders)
+                .map(|(slot, leader)| (slot, leader.into())),
+        );
+        Ok(())
+    }
+
+    /// Whether the stored leaders don't cover the fanout window.
+    ///
+    /// Args:
+    ///     current_slot (int): The current slot.
+    ///
+    /// Returns:
+    ///     bool: ``True`` if any of the next ``fanout_slots`` slots has no known leader.
+    ///
+    pub fn needs_leaders(&self, current_slot: Slot) -> bool {
+        let end = current_slot.saturating_add(self.fanout_slots);
+        self.leaders.range(current_slot..end).count() as u64 != end - current_slot
+    }
+
+    /// The TPU addresses of the leaders of the next ``fanout_slots`` slots.
+    ///
+    /// Each leader appears once, in the order of its first slot, so the current
+    /// leader comes first. Leaders with no known TPU address are skipped; see
+    /// :meth:`unknown_leaders`.
+    ///
+    /// Args:
+    ///     current_slot (int): The current slot.
+    ///
+    /// Returns:
+    ///     List[Tuple[str, int]]: The ``(host, port)`` addresses, ready for ``socket.sendto``.
+    ///
+    pub fn leader_tpus(&self, current_slot: Slot) -> Vec<(String, u16)> {
+        self.upcoming_leaders(current_slot)
+            .iter()
+            .filter_map(|leader| self.tpu(leader))
+            .map(|addr| (addr.ip().to_string(), addr.port()))
+            .collect()
+    }
+
+    /// Send a transaction over UDP to the leaders of the next ``fanout_slots`` slots.
+    ///
+    /// UDP gives no delivery guarantee, so poll the signature status and send again
+    /// until the transaction lands or its blockhash expires. The GIL is released while sending.
+    /// Each packet sent and each retry is counted in :func:`~solders.metrics.metrics`.
+    ///
+    /// Args:
+    ///     transaction (Transaction | VersionedTransaction): The signed transaction.
+    ///     current_slot (int): The current slot.
+    ///     retries (int): How many more times to try an address whose send fails.
+    ///
+    /// Returns:
+    ///     List[Tuple[str, int]]: The ``(host, port)`` addresses the transaction was sent to.
+    ///
+    /// Raises:
+    ///     ValueError: If ``use_quic`` is set, no upcoming leader has a known TPU address,
+    ///         or the transaction doesn't fit in a packet.
+    ///     OSError: If every send failed.
+    ///
+    #[pyo3(signature = (transaction, current_slot, retries = 0))]
+    pub fn send_transaction(
+        &self,
+        py: Python<'_>,
+        transaction: WalletTransaction,
+        current_slot: Slot,
+        retries: usize,
+    ) -> PyResult<Vec<(String, u16)>> {
+        if self.use_quic {
+            return Err(to_py_value_err(
+                &"send_transaction only sends over UDP; use leader_tpus with a QUIC library",
+            ));
+        }
+        let packet = wire_bytes(transaction)?;
+        let addrs: Vec<SocketAddr> = self
+            .upcoming_leaders(current_slot)
+            .iter()
+            .filter_map(|leader| self.tpu(leader))
+            .collect();
+        if addrs.is_empty() {
+            return Err(to_py_value_err(&format!(
+                "no TPU address is known for the leaders of slots {current_slot} to {}",
+                current_slot.saturating_add(self.fanout_slots - 1)
+            )));
+        }
+        let sent = py.allow_threads(|| send_udp(&packet, &addrs, retries))?;
+        Ok(sent
+            .into_iter()
+            .map(|addr| (addr.ip().to_string(), addr.port()))
+            .collect())
+    }
+
+    /// The upcoming leaders with no known TPU address.
+    ///
+    /// A non-empty result usually means the cluster nodes need refreshing.
+    ///
+    /// Args:
+    ///     current_slot (int): The current slot.
+    ///
+    /// Returns:
+    ///     List[Pubkey]: The leaders, in slot order.
+    ///
+    pub fn unknown_leaders(&self, current_slot: Slot) -> Vec<Pubkey> {
+        self.upcoming_leaders(current_slot)
+            .into_iter()
+            .filter(|leader| self.tpu(leader).is_none())
+            .map(Pubkey::from)
+            .collect()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "TpuLeaderPlanner(fanout_slots={}, use_quic={}, leaders={}, nodes={})",
+            self.fanout_slots,
+            self.use_quic,
+            self.leaders.len(),
+            self.tpus.len()
+        )
+    }
+}
+
+/// Serialize a signed transaction into the datagram a TPU expects.
+///
+/// Args:
+///     transaction (Transaction | VersionedTransaction): The signed transaction.
+///
+/// Returns:
+///     bytes: The wire-format transaction.
+///
+/// Raises:
+///     ValueError: If the transaction doesn't fit in a packet.
+///
+#[pyfunction]
+pub fn wire_transaction(py: Python<'_>, transaction: WalletTransaction) -> PyResult<&PyBytes> {
+    Ok(PyBytes::new(py, &wire_bytes(transaction)?))
+}
+
+pub(crate) fn create_tpu_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "tpu")?;
+    m.add_class::<TpuLeaderPlanner>()?;
+    m.add("DEFAULT_FANOUT_SLOTS", DEFAULT_FANOUT_SLOTS)?;
+    m.add("MAX_FANOUT_SLOTS", MAX_FANOUT_SLOTS)?;
+    m.add("QUIC_PORT_OFFSET", QUIC_PORT_OFFSET)?;
+    m.add_function(wrap_pyfunction!(wire_transaction, m)?)?;
+    Ok(m)
+}
@@ -0,0 +1,470 @@
+use pyo3::{prelude::*, types::PyBytes};
+use serde::{Deserialize, Serialize};
+use solana_sdk::{
+    clock::Slot,
+    hash::{hashv, Hash as HashOriginal},
+    signature::Signature as SignatureOriginal,
+};
+use solders_macros::{enum_string, richcmp_eq_only};
+use solders_primitives::{hash::Hash as SolderHash, pubkey::Pubkey, signature::Signature};
+use solders_traits::{to_py_value_err, EnumString, RichcmpEqualityOnly};
+
+use crate::entry::{entries_from_bytes, Entry};
+
+const SIZE_OF_SIGNATURE: usize = 64;
+const SIZE_OF_DATA_SHRED_HEADERS: usize = 88;
+const SIZE_OF_CODING_SHRED_HEADERS: usize = 89;
+const MERKLE_DATA_PAYLOAD_SIZE: usize = 1203;
+const MERKLE_CODE_PAYLOAD_SIZE: usize = 1228;
+const SIZE_OF_MERKLE_ROOT: usize = 32;
+const SIZE_OF_MERKLE_PROOF_ENTRY: usize = 20;
+
+const LEGACY_CODE_VARIANT: u8 = 0b1010_0101;
+const LEGACY_DATA_VARIANT: u8 = 0b0101_1010;
+
+const SHRED_TICK_REFERENCE_MASK: u8 = 0b0011_1111;
+const DATA_COMPLETE_SHRED: u8 = 0b0100_0000;
+const LAST_SHRED_IN_SLOT: u8 = 0b1100_0000;
+
+const MERKLE_HASH_PREFIX_LEAF: &[u8] = b"\x00SOLANA_MERKLE_SHREDS_LEAF";
+const MERKLE_HASH_PREFIX_NODE: &[u8] = b"\x01SOLANA_MERKLE_SHREDS_NODE";
+
+/// Whether a shred carries entry data or erasure codes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[pyclass(module = "solders.shred")]
+pub enum ShredType {
+    Data,
+    Code,
+}
+
+impl EnumString for ShredType {}
+
+#[enum_string]
+#[pymethods]
+impl ShredType {}
+
+/// The merkle proof layout of a merkle shred.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct MerkleVariant {
+    proof_size: u8,
+    chained: bool,
+    resigned: bool,
+}
+
+fn parse_variant(byte: u8) -> Option<(ShredType, Option<MerkleVariant>)> {
+    match byte {
+        LEGACY_CODE_VARIANT => return Some((ShredType::Code, None)),
+        LEGACY_DATA_VARIANT => return Some((ShredType::Data, None)),
+        _ => (),
+    }
+    let (shred_type, chained, resigned) = match byte & 0xF0 {
+        0x40 => (ShredType::Code, false, false),
+        0x60 => (ShredType::Code, true, false),
+        0x70 => (ShredType::Code, true, true),
+        0x80 => (ShredType::Data, false, false),
+        0x90 => (ShredType::Data, true, false),
+        0xB0 => (ShredType::Data, true, true),
+        _ => return None,
+    };
+    let merkle = MerkleVariant {
+        proof_size: byte & 0x0F,
+        chained,
+        resigned,
+    };
+    Some((shred_type, Some(merkle)))
+}
+
+fn u16_at(payload: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes(payload[offset..offset + 2].try_into().unwrap())
+}
+
+fn u32_at(payload: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(payload[offset..offset + 4].try_into().unwrap())
+}
+
+/// A shred, the unit turbine and repair send blocks in.
+///
+/// Both legacy and merkle shreds are supported, including chained and resigned
+/// merkle shreds. Data shreds carry a slice of the serialized entries; code shreds
+/// carry Reed-Solomon parity for recovering lost data shreds. Use :func:`deshred`
+/// to reassemble the entries from a run of data shreds.
+#[pyclass(module = "solders.shred", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Shred {
+    payload: Vec<u8>,
+    shred_type: ShredType,
+    merkle: Option<MerkleVariant>,
+}
+
+impl Shred {
+    fn payload_size(&self) -> usize {
+        match (self.merkle, self.shred_type) {
+            (None, _) => self.payload.len(),
+            (Some(_), ShredType::Data) => MERKLE_DATA_PAYLOAD_SIZE,
+            (Some(_), ShredType::Code) => MERKLE_CODE_PAYLOAD_SIZE,
+        }
+    }
+
+    /// Where the merkle proof starts; the merkle leaf covers the payload up to here.
+    fn proof_offset(&self, merkle: MerkleVariant) -> usize {
+        let resigned = if merkle.resigned {
+            SIZE_OF_SIGNATURE
+        } else {
+            0
+        };
+        self.payload_size() - usize::from(merkle.proof_size) * SIZE_OF_MERKLE_PROOF_ENTRY - resigned
+    }
+
+    /// The end of the data buffer of a data shred.
+    fn data_capacity_end(&self) -> usize {
+        match self.merkle {
+            None => self.payload.len(),
+            Some(merkle) => {
+                let chained = if merkle.chained {
+                    SIZE_OF_MERKLE_ROOT
+                } else {
+                    0
+                };
+                self.proof_offset(merkle) - chained
+            }
+        }
+    }
+
+    fn data_field<T>(&self, get: impl FnOnce(&[u8]) -> T) -> Option<T> {
+        (self.shred_type == ShredType::Data).then(|| get(&self.payload))
+    }
+
+    fn code_field<T>(&self, get: impl FnOnce(&[u8]) -> T) -> Option<T> {
+        (self.shred_type == ShredType::Code).then(|| get(&self.payload))
+    }
+
+    fn data_bytes(&self) -> Option<&[u8]> {
+        self.data_field(|p| usize::from(u16_at(p, 86)))
+            .map(|size| &self.payload[SIZE_OF_DATA_SHRED_HEADERS..size])
+    }
+
+    fn flags(&self) -> Option<u8> {
+        self.data_field(|p| p[85])
+    }
+
+    fn merkle_root_original(&self) -> Option<HashOriginal> {
+        let merkle = self.merkle?;
+        let proof_offset = self.proof_offset(merkle);
+        let mut index = match self.shred_type {
+            ShredType::Data => self.index().checked_sub(self.fec_set_index())? as usize,
+            ShredType::Code => {
+                usize::from(u16_at(&self.payload, 83)) + usize::from(u16_at(&self.payload, 87))
+            }
+        };
+        let mut node = hashv(&[
+            MERKLE_HASH_PREFIX_LEAF,
+            &self.payload[SIZE_OF_SIGNATURE..proof_offset],
+        ]);
+        let proof_end = proof_offset + usize::from(merkle.proof_size) * SIZE_OF_MERKLE_PROOF_ENTRY;
+        for other in self.payload[proof_offset..proof_end].chunks(SIZE_OF_MERKLE_PROOF_ENTRY) {
+            let node_prefix = &node.as_ref()[..SIZE_OF_MERKLE_PROOF_ENTRY];
+            node = if index % 2 == 0 {
+                hashv(&[MERKLE_HASH_PREFIX_NODE, node_prefix, other])
+            } else {
+                hashv(&[MERKLE_HASH_PREFIX_NODE, other, node_prefix])
+            };
+            index >>= 1;
+        }
+        (index == 0).then_some(node)
+    }
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl Shred {
+    /// Parse a shred.
+    ///
+    /// Trailing bytes after a merkle shred, such as the nonce on repair responses, are ignored.
+    ///
+    /// Args:
+    ///     data (bytes): The shred payload.
+    ///
+    /// Returns:
+    ///     Shred: The shred.
+    ///
+    /// Raises:
+    ///     ValueError: If the payload is too short, has an unknown variant or inconsistent headers.
+    ///
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<Self> {
+        let variant = *data
+            .get(SIZE_OF_SIGNATURE)
+            .ok_or_else(|| to_py_value_err(&"shred is too short"))?;
+        let (shred_type, merkle) = parse_variant(variant)
+            .ok_or_else(|| to_py_value_err(&format!("unknown shred variant {:#04x}", variant)))?;
+        let min_size = match (merkle, shred_type) {
+            (Some(_), ShredType::Data) => MERKLE_DATA_PAYLOAD_SIZE,
+            (Some(_), ShredType::Code) => MERKLE_CODE_PAYLOAD_SIZE,
+            (None, ShredType::Data) => SIZE_OF_DATA_SHRED_HEADERS,
+            (None, ShredType::Code) => SIZE_OF_CODING_SHRED_HEADERS,
+        };
+        if data.len() < min_size {
+            return Err(to_py_value_err(&format!(
+                "expected at least {} bytes, got {}",
+                min_size,
+                data.len()
+            )));
+        }
+        let payload = match merkle {
+            Some(_) => data[..min_size].to_vec(),
+            None => data.to_vec(),
+        };
+        let shred = Self {
+            payload,
+            shred_type,
+            merkle,
+        };
+        if let Some(size) = shred.data_field(|p| usize::from(u16_at(p, 86))) {
+            if size < SIZE_OF_DATA_SHRED_HEADERS || size > shred.data_capacity_end() {
+                return Err(to_py_value_err(&format!("invalid data size {}", size)));
+            }
+        }
+        Ok(shred)
+    }
+
+    /// Signature: The leader's signature.
+    #[getter]
+    pub fn signature(&self) -> Signature {
+        SignatureOriginal::new(&self.payload[..SIZE_OF_SIGNATURE]).into()
+    }
+
+    /// ShredType: Whether this is a data or code shred.
+    #[getter]
+    pub fn shred_type(&self) -> ShredType {
+        self.shred_type
+    }
+
+    /// int: The slot.
+    #[getter]
+    pub fn slot(&self) -> Slot {
+        u64::from_le_bytes(self.payload[65..73].try_into().unwrap())
+    }
+
+    /// int: The index of the shred among the slot's shreds of the same type.
+    #[getter]
+    pub fn index(&self) -> u32 {
+        u32_at(&self.payload, 73)
+    }
+
+    /// int: The shred version, derived from the genesis hash and hard forks.
+    #[getter]
+    pub fn version(&self) -> u16 {
+        u16_at(&self.payload, 77)
+    }
+
+    /// int: The index of the first data shred of the erasure batch.
+    #[getter]
+    pub fn fec_set_index(&self) -> u32 {
+        u32_at(&self.payload, 79)
+    }
+
+    /// bool: Whether this is a merkle shred.
+    #[getter]
+    pub fn is_merkle(&self) -> bool {
+        self.merkle.is_some()
+    }
+
+    /// Optional[int]: The number of merkle proof entries. ``None`` for legacy shreds.
+    #[getter]
+    pub fn proof_size(&self) -> Option<u8> {
+        self.merkle.map(|m| m.proof_size)
+    }
+
+    /// bool: Whether the shred includes the merkle root of the previous erasure batch.
+    #[getter]
+    pub fn chained(&self) -> bool {
+        self.merkle.map_or(false, |m| m.chained)
+    }
+
+    /// bool: Whether the shred carries a retransmitter signature.
+    #[getter]
+    pub fn resigned(&self) -> bool {
+        self.merkle.map_or(false, |m| m.resigned)
+    }
+
+    /// Optional[int]: The parent slot. ``None`` for code shreds.
+    #[getter]
+    pub fn parent_slot(&self) -> Option<Slot> {
+        self.data_field(|p| u64::from(u16_at(p, 83)))
+            .and_then(|offset| self.slot().checked_sub(offset))
+    }
+
+    /// Optional[int]: The tick the shred's entries belong to. ``None`` for code shreds.
+    #[getter]
+    pub fn reference_tick(&self) -> Option<u8> {
+        self.flags().map(|f| f & SHRED_TICK_REFERENCE_MASK)
+    }
+
+    /// bool: Whether this data shred ends a batch of entries.
+    #[getter]
+    pub fn data_complete(&self) -> bool {
+        self.flags()
+            .map_or(false, |f| f & DATA_COMPLETE_SHRED == DATA_COMPLETE_SHRED)
+    }
+
+    /// bool: Whether this is the last data shred of the slot.
+    #[getter]
+    pub fn last_in_slot(&self) -> bool {
+        self.flags()
+            .map_or(false, |f| f & LAST_SHRED_IN_SLOT == LAST_SHRED_IN_SLOT)
+    }
+
+    /// Optional[bytes]: The slice of serialized entries. ``None`` for code shreds.
+    #[getter]
+    pub fn data<'a>(&self, py: Python<'a>) -> Option<&'a PyBytes> {
+        self.data_bytes().map(|data| PyBytes::new(py, data))
+    }
+
+    /// Optional[int]: The number of data shreds in the erasure batch. ``None`` for data shreds.
+    #[getter]
+    pub fn num_data_shreds(&self) -> Option<u16> {
+        self.code_field(|p| u16_at(p, 83))
+    }
+
+    /// Optional[int]: The number of code shreds in the erasure batch. ``None`` for data shreds.
+    #[getter]
+    pub fn num_coding_shreds(&self) -> Option<u16> {
+        self.code_field(|p| u16_at(p, 85))
+    }
+
+    /// Optional[int]: The position of the code shred in the erasure batch. ``None`` for data shreds.
+    #[getter]
+    pub fn position(&self) -> Option<u16> {
+        self.code_field(|p| u16_at(p, 87))
+    }
+
+    /// Optional[Hash]: The merkle root of the erasure batch, computed from the merkle proof.
+    /// ``None`` for legacy shreds or if the proof doesn't match the shred's position.
+    #[getter]
+    pub fn merkle_root(&self) -> Option<SolderHash> {
+        self.merkle_root_original().map(SolderHash::from)
+    }
+
+    /// Optional[Hash]: The merkle root of the previous erasure batch, for chained shreds.
+    #[getter]
+    pub fn chained_merkle_root(&self) -> Option<SolderHash> {
+        let merkle = self.merkle.filter(|m| m.chained)?;
+        let end = self.proof_offset(merkle);
+        let root = &self.payload[end - SIZE_OF_MERKLE_ROOT..end];
+        Some(HashOriginal::new(root).into())
+    }
+
+    /// Check the leader's signature.
+    ///
+    /// Merkle shreds are signed over the merkle root, legacy shreds over the payload.
+    ///
+    /// Args:
+    ///     leader (Pubkey): The slot leader.
+    ///
+    /// Returns:
+    ///     bool: Whether the signature is valid.
+    ///
+    pub fn verify(&self, leader: Pubkey) -> bool {
+        let signature = SignatureOriginal::new(&self.payload[..SIZE_OF_SIGNATURE]);
+        match self.merkle {
+            Some(_) => self.merkle_root_original().map_or(false, |root| {
+                signature.verify(leader.as_ref(), root.as_ref())
+            }),
+            None => signature.verify(leader.as_ref(), &self.payload[SIZE_OF_SIGNATURE..]),
+        }
+    }
+
+    fn __bytes__<'a>(&self, py: Python<'a>) -> &'a PyBytes {
+        PyBytes::new(py, &self.payload)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Shred(shred_type={:?}, slot={}, index={}, fec_set_index={}, merkle={})",
+            self.shred_type,
+            self.slot(),
+            self.index(),
+            self.fec_set_index(),
+            self.is_merkle()
+        )
+    }
+}
+
+impl RichcmpEqualityOnly for Shred {}
+
+/// Reassemble the serialized entries from data shreds.
+///
+/// The shreds must be one batch of entries: consecutive data shreds of one slot,
+/// ending with a shred whose :attr:`Shred.data_complete` is set. Code shreds and
+/// duplicates are ignored, and the order doesn't matter.
+///
+/// Args:
+///     shreds (Sequence[Shred]): The shreds.
+///
+/// Returns:
+///     bytes: The serialized entries, which :func:`solders.entry.entries_from_bytes` parses.
+///
+/// Raises:
+///     ValueError: If there are no data shreds, they span slots, one is missing
+///         or the batch is incomplete.
+///
+#[pyfunction]
+pub fn deshred<'a>(py: Python<'a>, shreds: Vec<Shred>) -> PyResult<&'a PyBytes> {
+    Ok(PyBytes::new(py, &deshred_bytes(shreds)?))
+}
+
+fn deshred_bytes(shreds: Vec<Shred>) -> PyResult<Vec<u8>> {
+    let mut data_shreds: Vec<Shred> = shreds
+        .into_iter()
+        .filter(|s| s.shred_type == ShredType::Data)
+        .collect();
+    data_shreds.sort_by_key(|s| s.index());
+    data_shreds.dedup_by_key(|s| s.index());
+    let first = data_shreds
+        .first()
+        .ok_or_else(|| to_py_value_err(&"no data shreds"))?;
+    let (slot, first_index) = (first.slot(), first.index());
+    let mut payload = Vec::new();
+    for (expected_index, shred) in (first_index..).zip(&data_shreds) {
+        if shred.slot() != slot {
+            return Err(to_py_value_err(&"shreds span several slots"));
+        }
+        if shred.index() != expected_index {
+            return Err(to_py_value_err(&format!(
+                "missing data shred {} of slot {}",
+                expected_index, slot
+            )));
+        }
+        payload.extend_from_slice(shred.data_bytes().unwrap());
+    }
+    if !data_shreds.last().map_or(false, |s| s.data_complete()) {
+        return Err(to_py_value_err(&"last shred doesn't complete the batch"));
+    }
+    Ok(payload)
+}
+
+/// Reassemble and parse the entries from data shreds. See :func:`deshred`.
+///
+/// Args:
+///     shreds (Sequence[Shred]): The shreds.
+///
+/// Returns:
+///     List[Entry]: The entries.
+///
+/// Raises:
+///     ValueError: If :func:`deshred` fails or the payload isn't a list of entries.
+///
+#[pyfunction]
+pub fn entries_from_shreds(shreds: Vec<Shred>) -> PyResult<Vec<Entry>> {
+    entries_from_bytes(&deshred_bytes(shreds)?)
+}
+
+pub(crate) fn create_shred_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "shred")?;
+    m.add_class::<ShredType>()?;
+    m.add_class::<Shred>()?;
+    m.add_function(wrap_pyfunction!(deshred, m)?)?;
+    m.add_function(wrap_pyfunction!(entries_from_shreds, m)?)?;
+    Ok(m)
+}
@@ -0,0 +1,782 @@
+use std::{collections::BTreeMap, fmt};
+
+use pyo3::{prelude::*, types::PyBytes};
+use serde::{Deserialize, Serialize};
+use solana_sdk::{
+    instruction::CompiledInstruction,
+    message::{MessageHeader, VersionedMessage as VersionedMessageOriginal},
+    pubkey::Pubkey as PubkeyOriginal,
+    signature::Signature as SignatureOriginal,
+    transaction::VersionedTransaction as VersionedTransactionOriginal,
+};
+use solders_macros::{common_methods, richcmp_eq_only};
+use solders_primitives::{
+    address_lookup_table_account::AddressLookupTableAccount, hash::Hash as SolderHash,
+    message::VersionedMessage, pubkey::Pubkey, signature::Signature, signer::Signer,
+    transaction::VersionedTransaction,
+};
+use solders_traits::{
+    pybytes_general_via_bincode, to_py_value_err, PyFromBytesGeneral, RichcmpEqualityOnly,
+    ToSignerOriginal,
+};
+
+use crate::wallet::WalletTransaction;
+
+/// Collects the signatures a transaction needs from several parties.
+///
+/// Signatures already on the transaction are kept if they are valid. Each
+/// signature added with :meth:`add_signature` or :meth:`sign` is verified
+/// against the message before it is recorded, so a complete session always
+/// yields a transaction the cluster will accept.
+///
+/// Args:
+///     transaction (Transaction | VersionedTransaction): The transaction to collect signatures for.
+///
+/// Raises:
+///     ValueError: If the message fails sanitization, e.g. its header claims more signers than it has keys.
+///
+/// Example:
+///     >>> from solders.signing import SigningSession
+///     >>> from solders.keypair import Keypair
+///     >>> from solders.message import Message
+///     >>> from solders.instruction import Instruction, AccountMeta
+///     >>> from solders.pubkey import Pubkey
+///     >>> from solders.transaction import Transaction
+///     >>> payer, treasurer = Keypair(), Keypair()
+///     >>> ix = Instruction(Pubkey.new_unique(), b"", [AccountMeta(treasurer.pubkey(), True, True)])
+///     >>> session = SigningSession(Transaction.new_unsigned(Message([ix], payer.pubkey())))
+///     >>> session.sign([payer])
+///     >>> session.missing == [treasurer.pubkey()]
+///     True
+///     >>> session.add_signature(treasurer.pubkey(), treasurer.sign_message(session.message_bytes))
+///     >>> session.is_complete
+///     True
+///
+#[pyclass(module = "solders.signing", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SigningSession {
+    tx: VersionedTransactionOriginal,
+    message_bytes: Vec<u8>,
+}
+
+impl SigningSession {
+    /// Build a session for a transaction whose message has already been sanitized.
+    fn from_sanitized(mut tx: VersionedTransactionOriginal) -> Self {
+        let message_bytes = tx.message.serialize();
+        let num_signers = usize::from(tx.message.header().num_required_signatures);
+        tx.signatures
+            .resize(num_signers, SignatureOriginal::default());
+        let keys = tx.message.static_account_keys().to_vec();
+        for (signature, pubkey) in tx.signatures.iter_mut().zip(keys) {
+            if !signature.verify(pubkey.as_ref(), &message_bytes) {
+                *signature = SignatureOriginal::default();
+            }
+        }
+        Self { tx, message_bytes }
+    }
+
+    fn signers(&self) -> &[PubkeyOriginal] {
+        let num_signers = usize::from(self.tx.message.header().num_required_signatures);
+        &self.tx.message.static_account_keys()[..num_signers]
+    }
+
+    fn is_signed(signature: &SignatureOriginal) -> bool {
+        *signature != SignatureOriginal::default()
+    }
+
+    fn pubkeys_where(&self, signed: bool) -> Vec<Pubkey> {
+        self.signers()
+            .iter()
+            .zip(&self.tx.signatures)
+            .filter(|(_, signature)| Self::is_signed(signature) == signed)
+            .map(|(pubkey, _)| (*pubkey).into())
+            .collect()
+    }
+}
+
+#[pymethods]
+impl SigningSession {
+    #[new]
+    pub fn new(transaction: WalletTransaction) -> PyResult<Self> {
+        let tx = VersionedTransactionOriginal::from(transaction);
+        tx.message
+            .sanitize(false)
+            .map_err(|e| to_py_value_err(&e))?;
+        Ok(Self::from_sanitized(tx))
+    }
+
+    /// Message | MessageV0: The message being signed.
+    #[getter]
+    pub fn message(&self) -> VersionedMessage {
+        self.tx.message.clone().into()
+    }
+
+    /// bytes: The serialized message, which is what signers sign.
+    #[getter]
+    pub fn message_bytes<'a>(&self, py: Python<'a>) -> &'a PyBytes {
+        PyBytes::new(py, &self.message_bytes)
+    }
+
+    /// List[Pubkey]: Every account that must sign, in signature order.
+    #[getter]
+    pub fn required_signers(&self) -> Vec<Pubkey> {
+        self.signers()
+            .iter()
+            .map(|pubkey| (*pubkey).into())
+            .collect()
+    }
+
+    /// List[Pubkey]: The signers whose signatures have been recorded.
+    #[getter]
+    pub fn signed(&self) -> Vec<Pubkey> {
+        self.pubkeys_where(true)
+    }
+
+    /// List[Pubkey]: The signers still missing.
+    #[getter]
+    pub fn missing(&self) -> Vec<Pubkey> {
+        self.pubkeys_where(false)
+    }
+
+    /// bool: Whether every required signature has been recorded.
+    #[getter]
+    pub fn is_complete(&self) -> bool {
+        self.tx.signatures.iter().all(Self::is_signed)
+    }
+
+    /// Record a signature received from a signer.
+    ///
+    /// Args:
+    ///     pubkey (Pubkey): The signer.
+    ///     signature (Signature): Their signature of the message.
+    ///
+    /// Raises:
+    ///     ValueError: If ``pubkey`` isn't a required signer or the signature is invalid.
+    ///
+    pub fn add_signature(&mut self, pubkey: Pubkey, signature: Signature) -> PyResult<()> {
+        let pubkey = PubkeyOriginal::from(pubkey);
+        let signature = SignatureOriginal::from(signature);
+        let index = self
+            .signers()
+            .iter()
+            .position(|signer| *signer == pubkey)
+            .ok_or_else(|| to_py_value_err(&format!("{} is not a required signer", pubkey)))?;
+        if !signature.verify(pubkey.as_ref(), &self.message_bytes) {
+            return Err(to_py_value_err(&format!(
+                "invalid signature for {}",
+                pubkey
+            )));
+        }
+        self.tx.signatures[index] = signature;
+        Ok(())
+    }
+
+    /// Sign with local signers.
+    ///
+    /// Args:
+    ///     signers (Sequence[Keypair | Presigner]): Required signers to sign with.
+    ///
+    /// Raises:
+    ///     ValueError: If a signer isn't required or fails to sign.
+    ///
+    pub fn sign(&mut self, signers: Vec<Signer>) -> PyResult<()> {
+        for signer in signers {
+            let signer = signer.to_inner();
+            let signature = signer
+                .try_sign_message(&self.message_bytes)
+                .map_err(|e| to_py_value_err(&e))?;
+            self.add_signature(signer.pubkey().into(), signature.into())?;
+        }
+        Ok(())
+    }
+
+    /// The transaction with the signatures recorded so far.
+    ///
+    /// Missing signatures are the default signature.
+    ///
+    /// Returns:
+    ///     VersionedTransaction: The partially signed transaction.
+    ///
+    pub fn partial_transaction(&self) -> VersionedTransaction {
+        self.tx.clone().into()
+    }
+
+    /// The fully signed transaction.
+    ///
+    /// Returns:
+    ///     VersionedTransaction: The signed transaction.
+    ///
+    /// Raises:
+    ///     ValueError: If signatures are still missing.
+    ///
+    pub fn transaction(&self) -> PyResult<VersionedTransaction> {
+        if !self.is_complete() {
+            let missing: Vec<String> = self.missing().iter().map(|p| p.to_string()).collect();
+            return Err(to_py_value_err(&format!(
+                "missing signatures from {}",
+                missing.join(", ")
+            )));
+        }
+        Ok(self.partial_transaction())
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "SigningSession(signed={}, required={})",
+            self.signed().len(),
+            self.signers().len()
+        )
+    }
+}
+
+/// Move ``fee_payer`` to the front of the account keys and remap the instructions.
+///
+/// The old fee payer stays a writable signer if an instruction uses it, and is
+/// dropped otherwise.
+fn rotate_fee_payer(
+    header: &mut MessageHeader,
+    keys: &mut Vec<PubkeyOriginal>,
+    instructions: &mut [CompiledInstruction],
+    fee_payer: PubkeyOriginal,
+) -> PyResult<()> {
+    if keys.first() == Some(&fee_payer) {
+        return Ok(());
+    }
+    let malformed = || to_py_value_err(&"message header doesn't match its account keys");
+    let num_signed = usize::from(header.num_required_signatures);
+    let num_writable_signed = num_signed
+        .checked_sub(usize::from(header.num_readonly_signed_accounts))
+        .ok_or_else(malformed)?;
+    let readonly_unsigned_start = keys
+        .len()
+        .checked_sub(usize::from(header.num_readonly_unsigned_accounts))
+        .filter(|start| *start >= num_signed)
+        .ok_or_else(malformed)?;
+    let old_payer_used = instructions.iter().any(|ix| ix.accounts.contains(&0));
+    // writable signers, readonly signers, writable unsigned, readonly unsigned
+    let mut groups: [Vec<usize>; 4] = Default::default();
+    for (i, key) in keys.iter().enumerate() {
+        if *key == fee_payer || (i == 0 && !old_payer_used) {
+            continue;
+        }
+        let group = if i < num_writable_signed {
+            0
+        } else if i < num_signed {
+            1
+        } else if i < readonly_unsigned_start {
+            2
+        } else {
+            3
+        };
+        groups[group].push(i);
+    }
+    let old_len = keys.len();
+    let mut index_map = vec![usize::MAX; old_len];
+    let mut new_keys = vec![fee_payer];
+    if let Some(i) = keys.iter().position(|key| *key == fee_payer) {
+        index_map[i] = 0;
+    }
+    for i in groups.iter().flatten() {
+        index_map[*i] = new_keys.len();
+        new_keys.push(keys[*i]);
+    }
+    let new_len = new_keys.len();
+    let remap = |index: u8| -> PyResult<u8> {
+        let index = usize::from(index);
+        let new_index = if index < old_len {
+            index_map[index]
+        } else {
+            index - old_len + new_len
+        };
+        u8::try_from(new_index).map_err(|_| to_py_value_err(&"too many accounts"))
+    };
+    for ix in instructions.iter_mut() {
+        ix.program_id_index = remap(ix.program_id_index)?;
+        ix.accounts = ix
+            .accounts
+            .iter()
+            .map(|index| remap(*index))
+            .collect::<PyResult<_>>()?;
+    }
+    let count = |n: usize| u8::try_from(n).map_err(|_| to_py_value_err(&"too many accounts"));
+    *header = MessageHeader {
+        num_required_signatures: count(1 + groups[0].len() + groups[1].len())?,
+        num_readonly_signed_accounts: count(groups[1].len())?,
+        num_readonly_unsigned_accounts: count(groups[3].len())?,
+    };
+    *keys = new_keys;
+    Ok(())
+}
+
+/// The result of :func:`rebuild_transaction`.
+#[pyclass(module = "solders.signing", subclass)]
+#[derive(Clone, Debug)]
+pub struct RebuiltTransaction {
+    /// SigningSession: A session for the rebuilt transaction, holding the signatures that remain valid.
+    #[pyo3(get)]
+    pub session: Py<SigningSession>,
+    /// List[Pubkey]: Signers whose original signatures are still valid.
+    #[pyo3(get)]
+    pub still_valid: Vec<Pubkey>,
+    /// List[Pubkey]: Original signers who must sign again.
+    #[pyo3(get)]
+    pub resign: Vec<Pubkey>,
+    /// List[Pubkey]: Signers the original transaction didn't need.
+    #[pyo3(get)]
+    pub new_signers: Vec<Pubkey>,
+    /// List[Pubkey]: Original signers the rebuilt transaction doesn't need.
+    #[pyo3(get)]
+    pub dropped_signers: Vec<Pubkey>,
+}
+
+#[pymethods]
+impl RebuiltTransaction {
+    fn __repr__(&self) -> String {
+        format!(
+            "RebuiltTransaction(still_valid={:?}, resign={:?}, new_signers={:?}, dropped_signers={:?})",
+            self.still_valid, self.resign, self.new_signers, self.dropped_signers
+        )
+    }
+}
+
+/// Rebuild an expired transaction with a new blockhash and optionally a new fee payer.
+///
+/// Works offline: the instructions are kept as compiled, so no lookup table or
+/// account data is needed. Changing the message invalidates the signatures made
+/// over it, so in practice every signer has to sign again; the report says so
+/// exactly by checking each original signature against the new message.
+///
+/// If the old fee payer isn't used by any instruction it no longer has to sign.
+/// The new fee payer must not be an address loaded from a lookup table.
+///
+/// Args:
+///     transaction (Transaction | VersionedTransaction): The signed, expired transaction.
+///     recent_blockhash (Hash): The new blockhash.
+///     fee_payer (Optional[Pubkey]): The new fee payer. Defaults to the current one.
+///
+/// Returns:
+///     RebuiltTransaction: The session to collect signatures with, and which signers must sign.
+///
+/// Raises:
+///     ValueError: If the message fails sanitization or adding the fee payer needs more than 256 accounts.
+///
+/// Example:
+///     >>> from solders.signing import rebuild_transaction
+///     >>> from solders.keypair import Keypair
+///     >>> from solders.message import Message
+///     >>> from solders.hash import Hash
+///     >>> from solders.transaction import Transaction
+///     >>> payer, treasury_payer = Keypair(), Keypair()
+///     >>> tx = Transaction([payer], Message([], payer.pubkey()), Hash.default())
+///     >>> rebuilt = rebuild_transaction(tx, Hash.new_unique(), treasury_payer.pubkey())
+///     >>> rebuilt.new_signers == [treasury_payer.pubkey()], rebuilt.dropped_signers == [payer.pubkey()]
+///     (True, True)
+///     >>> rebuilt.session.sign([treasury_payer])
+///     >>> rebuilt.session.is_complete
+///     True
+///
+#[pyfunction]
+#[pyo3(signature = (transaction, recent_blockhash, fee_payer = None))]
+pub fn rebuild_transaction(
+    py: Python<'_>,
+    transaction: WalletTransaction,
+    recent_blockhash: SolderHash,
+    fee_payer: Option<Pubkey>,
+) -> PyResult<RebuiltTransaction> {
+    let original = VersionedTransactionOriginal::from(transaction);
+    original
+        .message
+        .sanitize(false)
+        .map_err(|e| to_py_value_err(&e))?;
+    let num_signers = usize::from(original.message.header().num_required_signatures);
+    let original_signers = &original.message.static_account_keys()[..num_signers];
+    let mut message = original.message.clone();
+    let (header, keys, instructions, blockhash) = match &mut message {
+        VersionedMessageOriginal::Legacy(m) => (
+            &mut m.header,
+            &mut m.account_keys,
+            &mut m.instructions,
+            &mut m.recent_blockhash,
+        ),
+        VersionedMessageOriginal::V0(m) => (
+            &mut m.header,
+            &mut m.account_keys,
+            &mut m.instructions,
+            &mut m.recent_blockhash,
+        ),
+    };
+    *blockhash = recent_blockhash.into();
+    if let Some(fee_payer) = fee_payer {
+        rotate_fee_payer(header, keys, instructions, fee_payer.into())?;
+    }
+    let new_num_signers = usize::from(message.header().num_required_signatures);
+    let signatures = message.static_account_keys()[..new_num_signers]
+        .iter()
+        .map(|signer| {
+            original_signers
+                .iter()
+                .position(|s| s == signer)
+                .and_then(|i| original.signatures.get(i).copied())
+                .unwrap_or_default()
+        })
+        .collect();
+    let session = SigningSession::new(WalletTransaction::Versioned(
+        VersionedTransactionOriginal {
+            signatures,
+            message,
+        }
+        .into(),
+    ))?;
+    let new_signers = session.signers().to_vec();
+    let (resign, new_only): (Vec<Pubkey>, Vec<Pubkey>) = session
+        .missing()
+        .into_iter()
+        .partition(|p| original_signers.contains(&p.0));
+    let dropped_signers = original_signers
+        .iter()
+        .filter(|s| !new_signers.contains(s))
+        .map(|s| (*s).into())
+        .collect();
+    Ok(RebuiltTransaction {
+        still_valid: session.signed(),
+        resign,
+        new_signers: new_only,
+        dropped_signers,
+        session: Py::new(py, session)?,
+    })
+}
+
+/// Bumped whenever the layout of :class:`SigningPackage` bytes changes.
+const SIGNING_PACKAGE_FORMAT_VERSION: u8 = 1;
+
+/// Transactions to sign on an air-gapped machine, with everything needed to review them.
+///
+/// A package bundles unsigned or partially signed transactions, the lookup tables
+/// their messages reference and free-form metadata such as a ceremony ID.
+/// ``bytes(package)`` is a compact bincode encoding, prefixed with a format
+/// version, to carry to the offline machine and back. There, load it with
+/// :meth:`SigningPackage.from_bytes`, review it, :meth:`sign` it and export it
+/// again. Back online, :meth:`merge` the packages returned by each signer and
+/// send :meth:`signed_transactions`.
+///
+/// Signatures are verified when added, and invalid ones already on the
+/// transactions are dropped.
+///
+/// Args:
+///     transactions (Sequence[Transaction | VersionedTransaction]): The transactions to sign.
+///     address_lookup_table_accounts (Optional[Sequence[AddressLookupTableAccount]]):
+///         The lookup tables referenced by versioned messages.
+///     metadata (Optional[Dict[str, str]]): Free-form notes for the signers.
+///
+/// Raises:
+///     ValueError: If a message fails sanitization or a versioned message references
+///         a lookup table that isn't included.
+///
+/// Example:
+///     >>> from solders.signing import SigningPackage
+///     >>> from solders.keypair import Keypair
+///     >>> from solders.message import Message
+///     >>> from solders.transaction import Transaction
+///     >>> cold = Keypair()
+///     >>> tx = Transaction.new_unsigned(Message([], cold.pubkey()))
+///     >>> exported = bytes(SigningPackage([tx], metadata={"ceremony": "42"}))
+///     >>> offline = SigningPackage.from_bytes(exported)
+///     >>> offline.metadata, offline.missing == [cold.pubkey()]
+///     ({'ceremony': '42'}, True)
+///     >>> offline.sign([cold])
+///     1
+///     >>> SigningPackage.from_bytes(bytes(offline)).is_complete
+///     True
+///
+#[pyclass(module = "solders.signing", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "SigningPackageFields")]
+pub struct SigningPackage {
+    version: u8,
+    transactions: Vec<VersionedTransaction>,
+    address_lookup_table_accounts: Vec<AddressLookupTableAccount>,
+    metadata: BTreeMap<String, String>,
+}
+
+/// The serialized form of a :class:`SigningPackage`, checked on the way in.
+#[derive(Deserialize)]
+struct SigningPackageFields {
+    version: u8,
+    transactions: Vec<VersionedTransaction>,
+    address_lookup_table_accounts: Vec<AddressLookupTableAccount>,
+    metadata: BTreeMap<String, String>,
+}
+
+impl TryFrom<SigningPackageFields> for SigningPackage {
+    type Error = String;
+
+    fn try_from(fields: SigningPackageFields) -> Result<Self, Self::Error> {
+        if fields.version != SIGNING_PACKAGE_FORMAT_VERSION {
+            return Err(format!(
+                "unsupported signing package format version {}",
+                fields.version
+            ));
+        }
+        for (i, tx) in fields.transactions.iter().enumerate() {
+            VersionedTransactionOriginal::from(tx.clone())
+                .message
+                .sanitize(false)
+                .map_err(|e| format!("transaction {i}: {e}"))?;
+        }
+        Ok(Self {
+            version: fields.version,
+            transactions: fields.transactions,
+            address_lookup_table_accounts: fields.address_lookup_table_accounts,
+            metadata: fields.metadata,
+        })
+    }
+}
+
+impl SigningPackage {
+    fn sessions(&self) -> Vec<SigningSession> {
+        self.transactions
+            .iter()
+            .map(|tx| SigningSession::from_sanitized(tx.clone().into()))
+            .collect()
+    }
+
+    fn store(&mut self, sessions: Vec<SigningSession>) {
+        self.transactions = sessions
+            .iter()
+            .map(SigningSession::partial_transaction)
+            .collect();
+    }
+}
+
+#[richcmp_eq_only]
+#[common_methods]
+#[pymethods]
+impl SigningPackage {
+    #[new]
+    #[pyo3(signature = (transactions, address_lookup_table_accounts = None, metadata = None))]
+    pub fn new(
+        transactions: Vec<WalletTransaction>,
+        address_lookup_table_accounts: Option<Vec<AddressLookupTableAccount>>,
+        metadata: Option<BTreeMap<String, String>>,
+    ) -> PyResult<Self> {
+        let address_lookup_table_accounts = address_lookup_table_accounts.unwrap_or_default();
+        let tables: Vec<Pubkey> = address_lookup_table_accounts
+            .iter()
+            .map(AddressLookupTableAccount::key)
+            .collect();
+        let transactions: Vec<VersionedTransaction> = transactions
+            .into_iter()
+            .map(|tx| SigningSession::new(tx).map(|session| session.partial_transaction()))
+            .collect::<PyResult<_>>()?;
+        for tx in &transactions {
+            if let VersionedMessage::V0(message) = tx.message() {
+                for lookup in message.address_table_lookups() {
+                    if !tables.contains(&lookup.account_key()) {
+                        return Err(to_py_value_err(&format!(
+                            "missing lookup table {}",
+                            lookup.account_key()
+                        )));
+                    }
+                }
+            }
+        }
+        Ok(Self {
+            version: SIGNING_PACKAGE_FORMAT_VERSION,
+            transactions,
+            address_lookup_table_accounts,
+            metadata: metadata.unwrap_or_default(),
+        })
+    }
+
+    /// List[VersionedTransaction]: The transactions, with the signatures recorded so far.
+    #[getter]
+    pub fn transactions(&self) -> Vec<VersionedTransaction> {
+        self.transactions.clone()
+    }
+
+    /// List[AddressLookupTableAccount]: The lookup tables referenced by the messages.
+    #[getter]
+    pub fn address_lookup_table_accounts(&self) -> Vec<AddressLookupTableAccount> {
+        self.address_lookup_table_accounts.clone()
+    }
+
+    /// Dict[str, str]: Free-form notes for the signers.
+    #[getter]
+    pub fn metadata(&self) -> BTreeMap<String, String> {
+        self.metadata.clone()
+    }
+
+    /// List[List[Pubkey]]: The signers each transaction needs, in signature order.
+    #[getter]
+    pub fn required_signers(&self) -> Vec<Vec<Pubkey>> {
+        self.sessions()
+            .iter()
+            .map(SigningSession::required_signers)
+            .collect()
+    }
+
+    /// List[Pubkey]: Every signer still missing from some transaction, without repeats.
+    #[getter]
+    pub fn missing(&self) -> Vec<Pubkey> {
+        let mut missing: Vec<Pubkey> = Vec::new();
+        for pubkey in self.sessions().iter().flat_map(SigningSession::missing) {
+            if !missing.contains(&pubkey) {
+                missing.push(pubkey);
+            }
+        }
+        missing
+    }
+
+    /// bool: Whether every transaction is fully signed.
+    #[getter]
+    pub fn is_complete(&self) -> bool {
+        self.sessions().iter().all(SigningSession::is_complete)
+    }
+
+    /// Sign every transaction that needs one of ``signers``.
+    ///
+    /// Args:
+    ///     signers (Sequence[Keypair | Presigner]): The signers to sign with.
+    ///
+    /// Returns:
+    ///     int: The number of signatures added.
+    ///
+    /// Raises:
+    ///     ValueError: If a signer isn't required by any transaction or fails to sign.
+    ///
+    pub fn sign(&mut self, signers: Vec<Signer>) -> PyResult<usize> {
+        let mut sessions = self.sessions();
+        let mut added = 0;
+        for signer in signers {
+            let signer = signer.to_inner();
+            let pubkey = Pubkey::from(signer.pubkey());
+            let mut used = false;
+            for session in sessions.iter_mut() {
+                if session.required_signers().contains(&pubkey) {
+                    let signature = signer
+                        .try_sign_message(&session.message_bytes)
+                        .map_err(|e| to_py_value_err(&e))?;
+                    session.add_signature(pubkey, signature.into())?;
+                    used = true;
+                    added += 1;
+                }
+            }
+            if !used {
+                return Err(to_py_value_err(&format!(
+                    "{} is not a required signer",
+                    pubkey
+                )));
+            }
+        }
+        self.store(sessions);
+        Ok(added)
+    }
+
+    /// Record a signature made elsewhere, such as on a hardware wallet.
+    ///
+    /// Args:
+    ///     index (int): The position of the transaction in the package.
+    ///     pubkey (Pubkey): The signer.
+    ///     signature (Signature): Their signature of the transaction's message.
+    ///
+    /// Raises:
+    ///     ValueError: If ``index`` is out of range, ``pubkey`` isn't a required
+    ///         signer or the signature is invalid.
+    ///
+    pub fn add_signature(
+        &mut self,
+        index: usize,
+        pubkey: Pubkey,
+        signature: Signature,
+    ) -> PyResult<()> {
+        let mut sessions = self.sessions();
+        sessions
+            .get_mut(index)
+            .ok_or_else(|| to_py_value_err(&format!("no transaction at index {index}")))?
+            .add_signature(pubkey, signature)?;
+        self.store(sessions);
+        Ok(())
+    }
+
+    /// Copy the signatures from a copy of this package signed elsewhere.
+    ///
+    /// Args:
+    ///     other (SigningPackage): A package with the same messages.
+    ///
+    /// Raises:
+    ///     ValueError: If the messages differ.
+    ///
+    pub fn merge(&mut self, other: &Self) -> PyResult<()> {
+        let mut sessions = self.sessions();
+        let theirs = other.sessions();
+        if sessions.len() != theirs.len()
+            || sessions
+                .iter()
+                .zip(&theirs)
+                .any(|(a, b)| a.message_bytes != b.message_bytes)
+        {
+            return Err(to_py_value_err(&"packages hold different messages"));
+        }
+        for (session, their) in sessions.iter_mut().zip(&theirs) {
+            for (i, signature) in their.tx.signatures.iter().enumerate() {
+                if SigningSession::is_signed(signature) {
+                    session.tx.signatures[i] = *signature;
+                }
+            }
+        }
+        self.store(sessions);
+        Ok(())
+    }
+
+    /// The fully signed transactions.
+    ///
+    /// Returns:
+    ///     List[VersionedTransaction]: The transactions, in package order.
+    ///
+    /// Raises:
+    ///     ValueError: If any signature is still missing.
+    ///
+    pub fn signed_transactions(&self) -> PyResult<Vec<VersionedTransaction>> {
+        self.sessions()
+            .iter()
+            .map(SigningSession::transaction)
+            .collect()
+    }
+
+    fn __len__(&self) -> usize {
+        self.transactions.len()
+    }
+}
+
+impl RichcmpEqualityOnly for SigningPackage {}
+pybytes_general_via_bincode!(SigningPackage);
+solders_traits::common_methods_default!(SigningPackage);
+
+impl fmt::Display for SigningPackage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl PyFromBytesGeneral for SigningPackage {
+    fn py_from_bytes_general(raw: &[u8]) -> PyResult<Self> {
+        match raw.first() {
+            Some(&SIGNING_PACKAGE_FORMAT_VERSION) => {
+                bincode::deserialize(raw).map_err(|e| to_py_value_err(&e))
+            }
+            Some(v) => Err(to_py_value_err(&format!(
+                "unsupported signing package format version {v}"
+            ))),
+            None => Err(to_py_value_err(&"empty signing package data")),
+        }
+    }
+}
+
+pub(crate) fn create_signing_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "signing")?;
+    m.add_class::<SigningSession>()?;
+    m.add_class::<RebuiltTransaction>()?;
+    m.add_class::<SigningPackage>()?;
+    m.add_function(wrap_pyfunction!(rebuild_transaction, m)?)?;
+    Ok(m)
+}
@@ -0,0 +1,911 @@
+use std::collections::{BTreeMap, HashSet};
+
+use pyo3::prelude::*;
+use solana_sdk::{
+    account::Account as AccountOriginal,
+    address_lookup_table_account::AddressLookupTableAccount as AddressLookupTableAccountOriginal,
+    bpf_loader, bpf_loader_deprecated, bpf_loader_upgradeable, compute_budget, config,
+    ed25519_program,
+    feature_set::require_rent_exempt_accounts,
+    instruction::InstructionError,
+    message::{
+        v0::{LoadedAddresses, LoadedMessage},
+        VersionedMessage as VersionedMessageOriginal,
+    },
+    native_loader,
+    pubkey::Pubkey as PubkeyOriginal,
+    rent::Rent as RentOriginal,
+    secp256k1_program, stake,
+    system_instruction::{SystemError, SystemInstruction, MAX_PERMITTED_DATA_LENGTH},
+    system_program,
+    transaction::{
+        TransactionError, VersionedTransaction as VersionedTransactionOriginal,
+        MAX_TX_ACCOUNT_LOCKS,
+    },
+    vote,
+};
+use solders_macros::richcmp_eq_only;
+use solders_primitives::{
+    address_lookup_table_account::AddressLookupTableAccount, message::VersionedMessage,
+    pubkey::Pubkey, transaction::VersionedTransaction,
+};
+use solders_traits::{to_py_value_err, RichcmpEqualityOnly};
+
+use crate::{
+    accounts_map::AccountsMap,
+    compute_budget::{
+        compute_budget_limits_original, DEFAULT_INSTRUCTION_COMPUTE_UNIT_LIMIT,
+        MAX_COMPUTE_UNIT_LIMIT, MIN_HEAP_FRAME_BYTES,
+    },
+    program_ids::ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
+    rent::Rent,
+    rpc::responses::{RpcSimulateTransactionResult, SimulateTransactionResp},
+    transaction_status::TransactionErrorType,
+};
+
+pub(crate) const DEFAULT_LAMPORTS_PER_SIGNATURE: u64 = 5000;
+
+/// Programs that every cluster provides without an account in the store.
+fn is_builtin(program_id: &PubkeyOriginal) -> bool {
+    [
+        system_program::ID,
+        compute_budget::ID,
+        vote::program::ID,
+        stake::program::ID,
+        config::program::ID,
+        ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
+        bpf_loader::ID,
+        bpf_loader_deprecated::ID,
+        bpf_loader_upgradeable::ID,
+        native_loader::ID,
+        ed25519_program::ID,
+        secp256k1_program::ID,
+    ]
+    .contains(program_id)
+}
+
+/// The result of :func:`simulate_locally`.
+#[pyclass(module = "solders.simulation", subclass)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LocalSimulationResult {
+    /// Optional[TransactionErrorType]: The error the transaction would fail with, if any.
+    #[pyo3(get)]
+    pub err: Option<TransactionErrorType>,
+    /// int: The fee charged, including the prioritization fee. Zero if the transaction
+    /// would be rejected before the fee is charged.
+    #[pyo3(get)]
+    pub fee: u64,
+    /// AccountsMap: The accounts after the transaction, with its writes pending.
+    #[pyo3(get)]
+    pub accounts: AccountsMap,
+    /// List[int]: Indexes of instructions that were not executed because their
+    /// program isn't simulated.
+    #[pyo3(get)]
+    pub unsimulated_instructions: Vec<usize>,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl LocalSimulationResult {
+    #[new]
+    pub fn new(
+        err: Option<TransactionErrorType>,
+        fee: u64,
+        accounts: AccountsMap,
+        unsimulated_instructions: Vec<usize>,
+    ) -> Self {
+        Self {
+            err,
+            fee,
+            accounts,
+            unsimulated_instructions,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for LocalSimulationResult {}
+
+/// Resolve all account keys of ``message`` with their writability.
+fn load_keys(
+    message: &VersionedMessageOriginal,
+    tables: &[AddressLookupTableAccountOriginal],
+) -> Result<(Vec<PubkeyOriginal>, Vec<bool>), TransactionError> {
+    let (keys, writable): (Vec<PubkeyOriginal>, Vec<bool>) = match message {
+        VersionedMessageOriginal::Legacy(m) => (
+            m.account_keys.clone(),
+            (0..m.account_keys.len())
+                .map(|i| m.is_writable(i))
+                .collect(),
+        ),
+        VersionedMessageOriginal::V0(m) => {
+            let mut loaded = LoadedAddresses::default();
+            for lookup in &m.address_table_lookups {
+                let table = tables
+                    .iter()
+                    .find(|t| t.key == lookup.account_key)
+                    .ok_or(TransactionError::AddressLookupTableNotFound)?;
+                for (indexes, out) in [
+                    (&lookup.writable_indexes, &mut loaded.writable),
+                    (&lookup.readonly_indexes, &mut loaded.readonly),
+                ] {
+                    for idx in indexes {
+                        let address = table
+                            .addresses
+                            .get(usize::from(*idx))
+                            .ok_or(TransactionError::InvalidAddressLookupTableIndex)?;
+                        out.push(*address);
+                    }
+                }
+            }
+            let loaded_message = LoadedMessage::new_borrowed(m, &loaded);
+            let keys: Vec<PubkeyOriginal> = loaded_message.account_keys().iter().copied().collect();
+            let writable = (0..keys.len())
+                .map(|i| loaded_message.is_writable(i))
+                .collect();
+            (keys, writable)
+        }
+    };
+    if keys.len() > MAX_TX_ACCOUNT_LOCKS {
+        return Err(TransactionError::TooManyAccountLocks);
+    }
+    let unique: HashSet<&PubkeyOriginal> = keys.iter().collect();
+    if unique.len() != keys.len() {
+        return Err(TransactionError::AccountLoadedTwice);
+    }
+    Ok((keys, writable))
+}
+
+fn is_rent_paying(account: &AccountOriginal, rent: &RentOriginal) -> bool {
+    account.lamports > 0 && !rent.is_exempt(account.lamports, account.data.len())
+}
+
+/// Whether the runtime allows an account to go from ``pre`` to ``post``.
+fn rent_transition_allowed(
+    pre: Option<&AccountOriginal>,
+    post: &AccountOriginal,
+    rent: &RentOriginal,
+) -> bool {
+    if !is_rent_paying(post, rent) {
+        return true;
+    }
+    match pre {
+        Some(pre) => {
+            is_rent_paying(pre, rent)
+                && pre.data.len() == post.data.len()
+                && post.lamports <= pre.lamports
+        }
+        None => false,
+    }
+}
+
+struct Executor<'a> {
+    keys: &'a [PubkeyOriginal],
+    writable: &'a [bool],
+    num_signers: usize,
+    accounts: &'a mut AccountsMap,
+}
+
+impl Executor<'_> {
+    fn account(&self, index: usize) -> AccountOriginal {
+        self.accounts
+            .get_original(&self.keys[index])
+            .cloned()
+            .unwrap_or_else(|| AccountOriginal::new(0, 0, &system_program::ID))
+    }
+
+    fn debit(&mut self, from: usize, lamports: u64) -> Result<(), InstructionError> {
+        let mut account = self.account(from);
+        if !account.data.is_empty() {
+            return Err(InstructionError::InvalidArgument);
+        }
+        if account.lamports < lamports {
+            return Err(InstructionError::Custom(
+                SystemError::ResultWithNegativeLamports as u32,
+            ));
+        }
+        if account.owner != system_program::ID {
+            return Err(InstructionError::ExternalAccountLamportSpend);
+        }
+        if !self.writable[from] {
+            return Err(InstructionError::ReadonlyLamportChange);
+        }
+        account.lamports -= lamports;
+        self.accounts.set_original(self.keys[from], account);
+        Ok(())
+    }
+
+    fn credit(&mut self, to: usize, lamports: u64) -> Result<(), InstructionError> {
+        if !self.writable[to] {
+            return Err(InstructionError::ReadonlyLamportChange);
+        }
+        let mut account = self.account(to);
+        account.lamports = account
+            .lamports
+            .checked_add(lamports)
+            .ok_or(InstructionError::ArithmeticOverflow)?;
+        self.accounts.set_original(self.keys[to], account);
+        Ok(())
+    }
+
+    fn require_signer(&self, index: usize) -> Result<(), InstructionError> {
+        if index < self.num_signers {
+            Ok(())
+        } else {
+            Err(InstructionError::MissingRequiredSignature)
+        }
+    }
+
+    /// Run a System instruction. ``Ok(false)`` means it isn't simulated.
+    fn system(&mut self, accounts: &[usize], data: &[u8]) -> Result<bool, InstructionError> {
+        let account_at = |position: usize| {
+            accounts
+                .get(position)
+                .copied()
+                .ok_or(InstructionError::NotEnoughAccountKeys)
+        };
+        let instruction: SystemInstruction =
+            bincode::deserialize(data).map_err(|_| InstructionError::InvalidInstructionData)?;
+        match instruction {
+            SystemInstruction::Transfer { lamports } => {
+                let (from, to) = (account_at(0)?, account_at(1)?);
+                self.require_signer(from)?;
+                self.debit(from, lamports)?;
+                self.credit(to, lamports)?;
+            }
+            SystemInstruction::TransferWithSeed {
+                lamports,
+                from_seed,
+                from_owner,
+            } => {
+                let (from, base, to) = (account_at(0)?, account_at(1)?, account_at(2)?);
+                self.require_signer(base)?;
+                let expected =
+                    PubkeyOriginal::create_with_seed(&self.keys[base], &from_seed, &from_owner)?;
+                if expected != self.keys[from] {
+                    return Err(InstructionError::Custom(
+                        SystemError::AddressWithSeedMismatch as u32,
+                    ));
+                }
+                self.debit(from, lamports)?;
+                self.credit(to, lamports)?;
+            }
+            SystemInstruction::CreateAccount {
+                lamports,
+                space,
+                owner,
+            } => {
+                let (from, to) = (account_at(0)?, account_at(1)?);
+                self.require_signer(from)?;
+                self.require_signer(to)?;
+                let existing = self.account(to);
+                if existing.lamports > 0
+                    || !existing.data.is_empty()
+                    || existing.owner != system_program::ID
+                {
+                    return Err(InstructionError::Custom(
+                        SystemError::AccountAlreadyInUse as u32,
+                    ));
+                }
+                if space > MAX_PERMITTED_DATA_LENGTH {
+                    return Err(InstructionError::Custom(
+                        SystemError::InvalidAccountDataLength as u32,
+                    ));
+                }
+                if !self.writable[to] {
+                    return Err(InstructionError::ReadonlyDataModified);
+                }
+                self.debit(from, lamports)?;
+                let created = AccountOriginal::new(lamports, space as usize, &owner);
+                self.accounts.set_original(self.keys[to], created);
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+}
+
+fn num_precompile_signatures(message: &VersionedMessageOriginal, keys: &[PubkeyOriginal]) -> u64 {
+    message
+        .instructions()
+        .iter()
+        .filter(|ix| {
+            keys.get(usize::from(ix.program_id_index))
+                .map_or(false, |id| {
+                    secp256k1_program::check_id(id) || ed25519_program::check_id(id)
+                })
+        })
+        .map(|ix| u64::from(ix.data.first().copied().unwrap_or_default()))
+        .sum()
+}
+
+struct Simulator<'a> {
+    accounts: &'a AccountsMap,
+    rent: RentOriginal,
+    require_rent_exempt: bool,
+    lamports_per_signature: u64,
+    tables: Vec<AddressLookupTableAccountOriginal>,
+}
+
+impl Simulator<'_> {
+    /// Fill in ``result`` as the runtime would process ``tx``.
+    fn run(
+        &self,
+        tx: &VersionedTransactionOriginal,
+        result: &mut LocalSimulationResult,
+    ) -> Result<(), TransactionError> {
+        tx.sanitize(true)
+            .map_err(|_| TransactionError::SanitizeFailure)?;
+        let message = &tx.message;
+        let (keys, writable) = load_keys(message, &self.tables)?;
+        let limits = compute_budget_limits_original(message)?;
+        for ix in message.instructions() {
+            let program_id = &keys[usize::from(ix.program_id_index)];
+            if is_builtin(program_id) {
+                continue;
+            }
+            match self.accounts.get_original(program_id) {
+                None => return Err(TransactionError::ProgramAccountNotFound),
+                Some(program) if !program.executable => {
+                    return Err(TransactionError::InvalidProgramForExecution)
+                }
+                Some(_) => {}
+            }
+        }
+
+        let payer = self
+            .accounts
+            .get_original(&keys[0])
+            .ok_or(TransactionError::AccountNotFound)?;
+        if payer.owner != system_program::ID || !payer.data.is_empty() {
+            return Err(TransactionError::InvalidAccountForFee);
+        }
+        let num_signatures = tx.signatures.len() as u64 + num_precompile_signatures(message, &keys);
+        let fee = self
+            .lamports_per_signature
+            .saturating_mul(num_signatures)
+            .saturating_add(limits.prioritization_fee());
+        let mut charged = payer.clone();
+        charged.lamports = payer
+            .lamports
+            .checked_sub(fee)
+            .ok_or(TransactionError::InsufficientFundsForFee)?;
+        if self.require_rent_exempt && !rent_transition_allowed(Some(payer), &charged, &self.rent) {
+            return Err(TransactionError::InsufficientFundsForRent { account_index: 0 });
+        }
+        result.fee = fee;
+        result.accounts.set_original(keys[0], charged);
+        let after_fee = result.accounts.clone();
+
+        let mut executor = Executor {
+            keys: &keys,
+            writable: &writable,
+            num_signers: usize::from(message.header().num_required_signatures),
+            accounts: &mut result.accounts,
+        };
+        for (i, ix) in message.instructions().iter().enumerate() {
+            if keys[usize::from(ix.program_id_index)] != system_program::ID {
+                result.unsimulated_instructions.push(i);
+                continue;
+            }
+            let indexes: Vec<usize> = ix.accounts.iter().map(|a| usize::from(*a)).collect();
+            match executor.system(&indexes, &ix.data) {
+                Ok(true) => {}
+                Ok(false) => result.unsimulated_instructions.push(i),
+                Err(e) => {
+                    *executor.accounts = after_fee;
+                    return Err(TransactionError::InstructionError(i as u8, e));
+                }
+            }
+        }
+
+        if self.require_rent_exempt {
+            for (i, key) in keys.iter().enumerate().filter(|(i, _)| writable[*i]) {
+                let allowed = result.accounts.get_original(key).map_or(true, |post| {
+                    rent_transition_allowed(self.accounts.get_original(key), post, &self.rent)
+                });
+                if !allowed {
+                    result.accounts = after_fee;
+                    return Err(TransactionError::InsufficientFundsForRent {
+                        account_index: i as u8,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Check a transaction against a set of accounts without an RPC node.
+///
+/// This covers the loading phase and the cheap native checks: sanitization,
+/// account locks, lookup table resolution, the fee payer and fee, program
+/// accounts, System ``Transfer``, ``TransferWithSeed`` and ``CreateAccount``,
+/// and rent state transitions. Instructions to other programs are not executed
+/// and are listed in ``unsimulated_instructions``, so a successful result only
+/// means the transaction doesn't fail for one of these reasons.
+///
+/// Program accounts must be in ``accounts`` unless they are builtins such as the
+/// System, Compute Budget, Stake or BPF loader programs. Missing non-program
+/// accounts are treated as empty System accounts, like the runtime does.
+///
+/// Args:
+///     tx (VersionedTransaction): The transaction to check. Signatures are not verified.
+///     accounts (AccountsMap): The account state to run against. It is not modified.
+///     feature_set (Optional[Sequence[Pubkey]]): The active feature IDs. ``None`` means
+///         all features are active. Only the rent-exempt account requirement is feature-gated here.
+///     lamports_per_signature (int): The fee per signature. Defaults to 5000.
+///     rent (Optional[Rent]): The rent parameters. Defaults to ``Rent.default()``.
+///     address_lookup_table_accounts (Optional[Sequence[AddressLookupTableAccount]]):
+///         The lookup tables referenced by a versioned message.
+///
+/// Returns:
+///     LocalSimulationResult: The outcome and resulting account state.
+///
+/// Example:
+///     >>> from solders.simulation import simulate_locally
+///     >>> from solders.accounts_map import AccountsMap
+///     >>> from solders.account import Account
+///     >>> from solders.keypair import Keypair
+///     >>> from solders.message import Message
+///     >>> from solders.pubkey import Pubkey
+///     >>> from solders.system_program import ID, transfer, TransferParams
+///     >>> from solders.transaction import VersionedTransaction
+///     >>> payer, to = Keypair(), Pubkey.new_unique()
+///     >>> ix = transfer(TransferParams(from_pubkey=payer.pubkey(), to_pubkey=to, lamports=1_000_000))
+///     >>> tx = VersionedTransaction(Message([ix], payer.pubkey()), [payer])
+///     >>> accounts = AccountsMap({payer.pubkey(): Account(2_000_000, b"", ID)})
+///     >>> result = simulate_locally(tx, accounts)
+///     >>> result.err is None, result.fee, result.accounts[to].lamports
+///     (True, 5000, 1000000)
+///     >>> simulate_locally(tx, AccountsMap()).err
+///     TransactionErrorFieldless.AccountNotFound
+///
+#[pyfunction]
+#[pyo3(signature = (
+    tx,
+    accounts,
+    feature_set = None,
+    lamports_per_signature = DEFAULT_LAMPORTS_PER_SIGNATURE,
+    rent = None,
+    address_lookup_table_accounts = None
+))]
+pub fn simulate_locally(
+    tx: VersionedTransaction,
+    accounts: &AccountsMap,
+    feature_set: Option<Vec<Pubkey>>,
+    lamports_per_signature: u64,
+    rent: Option<Rent>,
+    address_lookup_table_accounts: Option<Vec<AddressLookupTableAccount>>,
+) -> LocalSimulationResult {
+    let features = feature_set.map(|f| f.into_iter().map(PubkeyOriginal::from).collect());
+    let simulator = Simulator {
+        accounts,
+        rent: rent.unwrap_or_default().into(),
+        require_rent_exempt: features.map_or(true, |f: Vec<PubkeyOriginal>| {
+            f.contains(&require_rent_exempt_accounts::id())
+        }),
+        lamports_per_signature,
+        tables: address_lookup_table_accounts
+            .unwrap_or_default()
+            .into_iter()
+            .map(|a| a.into())
+            .collect(),
+    };
+    let mut result = LocalSimulationResult {
+        err: None,
+        fee: 0,
+        accounts: accounts.fork(),
+        unsimulated_instructions: Vec::new(),
+    };
+    if let Err(e) = simulator.run(&tx.into(), &mut result) {
+        result.err = Some(e.into());
+    }
+    result
+}
+
+/// Fee, rent and compute budget parameters of a cluster, pinned to a set of active features.
+///
+/// Local fee, rent and simulation results depend on the cluster's parameters and
+/// which features it has activated. Build one ``RuntimeParams`` from a cluster's
+/// state and use it everywhere, so every calculation agrees with that cluster.
+///
+/// Only the rent-exemption rule depends on ``feature_set``. The compute unit limits
+/// and heap size are the fixed values of the runtime this library is built against,
+/// whatever features are active.
+///
+/// Args:
+///     rent (Optional[Rent]): The rent parameters. Defaults to ``Rent.default()``.
+///     lamports_per_signature (int): The fee per signature. Defaults to 5000.
+///     feature_set (Optional[Sequence[Pubkey]]): The active feature IDs. ``None`` means
+///         all features are active.
+///
+/// Example:
+///     >>> from solders.simulation import RuntimeParams
+///     >>> from solders.message import Message
+///     >>> from solders.pubkey import Pubkey
+///     >>> params = RuntimeParams(lamports_per_signature=10_000, feature_set=[])
+///     >>> params.calculate_fee(Message([], Pubkey.new_unique()))
+///     10000
+///     >>> params.require_rent_exempt_accounts
+///     False
+///
+#[pyclass(module = "solders.simulation", subclass)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RuntimeParams {
+    /// Rent: The rent parameters.
+    #[pyo3(get)]
+    pub rent: Rent,
+    /// int: The fee per signature.
+    #[pyo3(get)]
+    pub lamports_per_signature: u64,
+    /// Optional[List[Pubkey]]: The active feature IDs, or ``None`` if all features are active.
+    #[pyo3(get)]
+    pub feature_set: Option<Vec<Pubkey>>,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl RuntimeParams {
+    #[new]
+    #[pyo3(signature = (
+        rent = None,
+        lamports_per_signature = DEFAULT_LAMPORTS_PER_SIGNATURE,
+        feature_set = None
+    ))]
+    pub fn new(
+        rent: Option<Rent>,
+        lamports_per_signature: u64,
+        feature_set: Option<Vec<Pubkey>>,
+    ) -> Self {
+        Self {
+            rent: rent.unwrap_or_default(),
+            lamports_per_signature,
+            feature_set,
+        }
+    }
+
+    /// Whether a feature is active.
+    ///
+    /// Args:
+    ///     feature_id (Pubkey): The feature ID.
+    ///
+    /// Returns:
+    ///     bool: Whether the feature is active.
+    ///
+    pub fn is_active(&self, feature_id: Pubkey) -> bool {
+        self.feature_set
+            .as_ref()
+            .map_or(true, |features| features.contains(&feature_id))
+    }
+
+    /// Copy these parameters with more features activated.
+    ///
+    /// Args:
+    ///     feature_ids (Sequence[Pubkey]): The newly activated feature IDs.
+    ///
+    /// Returns:
+    ///     RuntimeParams: The new parameters.
+    ///
+    pub fn with_activated(&self, feature_ids: Vec<Pubkey>) -> Self {
+        let mut params = self.clone();
+        if let Some(features) = params.feature_set.as_mut() {
+            for id in feature_ids {
+                if !features.contains(&id) {
+                    features.push(id);
+                }
+            }
+        }
+        params
+    }
+
+    /// bool: Whether transactions may not leave new accounts below the rent-exempt minimum.
+    #[getter]
+    pub fn require_rent_exempt_accounts(&self) -> bool {
+        self.is_active(require_rent_exempt_accounts::id().into())
+    }
+
+    /// int: The compute units each instruction gets without ``SetComputeUnitLimit``.
+    /// Fixed, not affected by ``feature_set``.
+    #[getter]
+    pub fn default_instruction_compute_unit_limit(&self) -> u32 {
+        DEFAULT_INSTRUCTION_COMPUTE_UNIT_LIMIT
+    }
+
+    /// int: The most compute units a transaction can use. Fixed, not affected by ``feature_set``.
+    #[getter]
+    pub fn max_compute_unit_limit(&self) -> u32 {
+        MAX_COMPUTE_UNIT_LIMIT
+    }
+
+    /// int: The heap size in bytes without ``RequestHeapFrame``. Fixed, not affected by
+    /// ``feature_set``.
+    #[getter]
+    pub fn default_heap_frame_bytes(&self) -> u32 {
+        MIN_HEAP_FRAME_BYTES
+    }
+
+    /// The minimum balance for a rent-exempt account.
+    ///
+    /// Args:
+    ///     data_len (int): The account data length.
+    ///
+    /// Returns:
+    ///     int: The minimum balance in lamports.
+    ///
+    pub fn minimum_balance(&self, data_len: usize) -> u64 {
+        self.rent.minimum_balance(data_len)
+    }
+
+    /// The fee for a message: its signatures, including precompile signatures,
+    /// plus the prioritization fee.
+    ///
+    /// Args:
+    ///     message (Message | MessageV0): The message.
+    ///
+    /// Returns:
+    ///     int: The fee in lamports.
+    ///
+    /// Raises:
+    ///     ValueError: If the runtime would reject the compute budget instructions.
+    ///
+    pub fn calculate_fee(&self, message: VersionedMessage) -> PyResult<u64> {
+        let message = VersionedMessageOriginal::from(message);
+        let limits = compute_budget_limits_original(&message).map_err(|e| to_py_value_err(&e))?;
+        let num_signatures = u64::from(message.header().num_required_signatures)
+            + num_precompile_signatures(&message, message.static_account_keys());
+        Ok(self
+            .lamports_per_signature
+            .saturating_mul(num_signatures)
+            .saturating_add(limits.prioritization_fee()))
+    }
+
+    /// Run :func:`simulate_locally` with these parameters.
+    ///
+    /// Args:
+    ///     tx (VersionedTransaction): The transaction to check.
+    ///     accounts (AccountsMap): The account state to run against.
+    ///     address_lookup_table_accounts (Optional[Sequence[AddressLookupTableAccount]]):
+    ///         The lookup tables referenced by a versioned message.
+    ///
+    /// Returns:
+    ///     LocalSimulationResult: The outcome and resulting account state.
+    ///
+    #[pyo3(signature = (tx, accounts, address_lookup_table_accounts = None))]
+    pub fn simulate(
+        &self,
+        tx: VersionedTransaction,
+        accounts: &AccountsMap,
+        address_lookup_table_accounts: Option<Vec<AddressLookupTableAccount>>,
+    ) -> LocalSimulationResult {
+        simulate_locally(
+            tx,
+            accounts,
+            self.feature_set.clone(),
+            self.lamports_per_signature,
+            Some(self.rent.clone()),
+            address_lookup_table_accounts,
+        )
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for RuntimeParams {}
+
+/// A compute unit sample for :class:`ComputeUnitHistogram`.
+#[derive(FromPyObject, Clone, Debug)]
+pub enum ComputeUnitSample {
+    Units(u64),
+    Result(RpcSimulateTransactionResult),
+    Resp(SimulateTransactionResp),
+}
+
+/// The distribution of compute units consumed over repeated runs of a transaction.
+///
+/// Executing transactions is out of scope: solders has no program runtime or test bank
+/// (:func:`simulate_locally` skips non-native instructions). Pass :meth:`run` a function
+/// that executes one input, e.g. by ``simulateTransaction`` against a test validator,
+/// or :meth:`record` results you collected yourself. Failed runs are counted in
+/// :attr:`failures` and don't contribute samples. Comparing :meth:`percentile`
+/// between builds catches compute budget regressions.
+///
+/// Example:
+///     >>> from solders.simulation import ComputeUnitHistogram
+///     >>> histogram = ComputeUnitHistogram()
+///     >>> histogram.record_all([1200, 1500, 1250, 3100])
+///     >>> histogram.percentile(50), histogram.max
+///     (1250, 3100)
+///     >>> histogram.buckets(1000)
+///     [(1000, 3), (3000, 1)]
+///
+#[pyclass(module = "solders.simulation", subclass)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ComputeUnitHistogram {
+    samples: Vec<u64>,
+    failures: usize,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl ComputeUnitHistogram {
+    #[new]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record one run.
+    ///
+    /// Args:
+    ///     sample (int | RpcSimulateTransactionResult | SimulateTransactionResp): The units
+    ///         consumed, or the simulation result to read them from.
+    ///
+    /// Raises:
+    ///     ValueError: If a successful simulation result doesn't report the units consumed.
+    ///
+    pub fn record(&mut self, sample: ComputeUnitSample) -> PyResult<()> {
+        let result = match sample {
+            ComputeUnitSample::Units(units) => {
+                self.samples.push(units);
+                return Ok(());
+            }
+            ComputeUnitSample::Result(result) => result,
+            ComputeUnitSample::Resp(resp) => resp.result().clone(),
+        };
+        if result.err.is_some() {
+            self.failures += 1;
+            return Ok(());
+        }
+        let units = result
+            .units_consumed
+            .ok_or_else(|| to_py_value_err(&"simulation result has no units_consumed"))?;
+        self.samples.push(units);
+        Ok(())
+    }
+
+    /// Record several runs. See :meth:`record`.
+    ///
+    /// Args:
+    ///     samples (Sequence[int | RpcSimulateTransactionResult | SimulateTransactionResp]):
+    ///         The runs.
+    ///
+    pub fn record_all(&mut self, samples: Vec<ComputeUnitSample>) -> PyResult<()> {
+        samples.into_iter().try_for_each(|s| self.record(s))
+    }
+
+    /// Execute one run per input with a caller-provided function and record each result.
+    ///
+    /// Args:
+    ///     inputs (Sequence[Any]): The inputs to vary between runs.
+    ///     execute (Callable[[Any], int | RpcSimulateTransactionResult | SimulateTransactionResp]):
+    ///         Runs the transaction for one input and returns its result.
+    ///
+    /// Raises:
+    ///     ValueError: If a successful simulation result doesn't report the units consumed.
+    ///
+    pub fn run(&mut self, inputs: Vec<PyObject>, execute: &PyAny) -> PyResult<()> {
+        for input in inputs {
+            let sample: ComputeUnitSample = execute.call1((input,))?.extract()?;
+            self.record(sample)?;
+        }
+        Ok(())
+    }
+
+    /// List[int]: The units consumed by each successful run, in the order recorded.
+    #[getter]
+    pub fn samples(&self) -> Vec<u64> {
+        self.samples.clone()
+    }
+
+    /// int: The number of failed runs.
+    #[getter]
+    pub fn failures(&self) -> usize {
+        self.failures
+    }
+
+    /// Optional[int]: The fewest units consumed.
+    #[getter]
+    pub fn min(&self) -> Option<u64> {
+        self.samples.iter().min().copied()
+    }
+
+    /// Optional[int]: The most units consumed.
+    #[getter]
+    pub fn max(&self) -> Option<u64> {
+        self.samples.iter().max().copied()
+    }
+
+    /// Optional[float]: The mean units consumed.
+    #[getter]
+    pub fn mean(&self) -> Option<f64> {
+        (!self.samples.is_empty()).then(|| {
+            self.samples.iter().map(|s| *s as f64).sum::<f64>() / self.samples.len() as f64
+        })
+    }
+
+    /// The nearest-rank percentile of the units consumed.
+    ///
+    /// Args:
+    ///     p (float): The percentile, from 0 to 100.
+    ///
+    /// Returns:
+    ///     Optional[int]: The percentile, or ``None`` if there are no samples.
+    ///
+    /// Raises:
+    ///     ValueError: If ``p`` is out of range.
+    ///
+    pub fn percentile(&self, p: f64) -> PyResult<Option<u64>> {
+        if !(0.0..=100.0).contains(&p) {
+            return Err(to_py_value_err(&format!(
+                "percentile must be between 0 and 100, got {}",
+                p
+            )));
+        }
+        if self.samples.is_empty() {
+            return Ok(None);
+        }
+        let mut sorted = self.samples.clone();
+        sorted.sort_unstable();
+        let rank = (p / 100.0 * sorted.len() as f64).ceil() as usize;
+        Ok(Some(sorted[rank.saturating_sub(1)]))
+    }
+
+    /// Count the samples in buckets of equal width.
+    ///
+    /// Args:
+    ///     width (int): The bucket width in compute units.
+    ///
+    /// Returns:
+    ///     List[Tuple[int, int]]: The lower bound and sample count of each non-empty bucket,
+    ///     in ascending order.
+    ///
+    /// Raises:
+    ///     ValueError: If ``width`` is zero.
+    ///
+    pub fn buckets(&self, width: u64) -> PyResult<Vec<(u64, usize)>> {
+        if width == 0 {
+            return Err(to_py_value_err(&"width must be positive"));
+        }
+        let mut counts: BTreeMap<u64, usize> = BTreeMap::new();
+        for sample in &self.samples {
+            *counts.entry(sample / width * width).or_default() += 1;
+        }
+        Ok(counts.into_iter().collect())
+    }
+
+    /// The number of samples that consumed more than ``limit`` units.
+    ///
+    /// Args:
+    ///     limit (int): The compute unit budget.
+    ///
+    /// Returns:
+    ///     int: The number of samples over budget.
+    ///
+    pub fn exceeding(&self, limit: u64) -> usize {
+        self.samples.iter().filter(|s| **s > limit).count()
+    }
+
+    fn __len__(&self) -> usize {
+        self.samples.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for ComputeUnitHistogram {}
+
+pub(crate) fn create_simulation_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "simulation")?;
+    m.add_class::<LocalSimulationResult>()?;
+    m.add_class::<RuntimeParams>()?;
+    m.add_class::<ComputeUnitHistogram>()?;
+    m.add_function(wrap_pyfunction!(simulate_locally, m)?)?;
+    Ok(m)
+}
@@ -0,0 +1,356 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs::File,
+    io::{self, BufRead, BufReader, Read},
+};
+
+use pyo3::prelude::*;
+use solana_sdk::{
+    account::Account as AccountOriginal, clock::Slot, hash::Hash as HashOriginal,
+    pubkey::Pubkey as PubkeyOriginal,
+};
+use solders_macros::richcmp_eq_only;
+use solders_primitives::{hash::Hash as SolderHash, pubkey::Pubkey};
+use solders_traits::{to_py_value_err, RichcmpEqualityOnly};
+
+use crate::account::Account;
+
+/// ``StoredMeta``: write version, data length and pubkey.
+const STORED_META_SIZE: usize = 48;
+/// ``AccountMeta``: lamports, rent epoch, owner and executable, padded to 8 bytes.
+const ACCOUNT_META_SIZE: usize = 56;
+const STORED_ACCOUNT_HEADER_SIZE: usize = STORED_META_SIZE + ACCOUNT_META_SIZE + 32;
+const TAR_BLOCK_SIZE: usize = 512;
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+
+/// One version of an account, as stored in an append vec.
+#[pyclass(module = "solders.snapshot", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StoredAccount {
+    /// Pubkey: The account address.
+    #[pyo3(get)]
+    pub pubkey: Pubkey,
+    /// Account: The account.
+    #[pyo3(get)]
+    pub account: Account,
+    /// int: The slot of the append vec holding this version.
+    #[pyo3(get)]
+    pub slot: Slot,
+    /// int: Orders the versions written in the same slot.
+    #[pyo3(get)]
+    pub write_version: u64,
+    /// Hash: The stored account hash. Newer validators leave this zeroed.
+    #[pyo3(get)]
+    pub hash: SolderHash,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl StoredAccount {
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for StoredAccount {}
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+}
+
+/// Parse the stored account at the start of ``data``, returning it and the offset of the next one.
+fn parse_stored_account(data: &[u8], slot: Slot) -> PyResult<Option<(StoredAccount, usize)>> {
+    let header = match data.get(..STORED_ACCOUNT_HEADER_SIZE) {
+        // Append vecs are preallocated, so the unused tail is zeroed.
+        Some(header) if header.iter().any(|b| *b != 0) => header,
+        _ => return Ok(None),
+    };
+    let data_len = usize::try_from(read_u64(header, 8)).unwrap_or(usize::MAX);
+    let end = STORED_ACCOUNT_HEADER_SIZE
+        .checked_add(data_len)
+        .filter(|end| *end <= data.len())
+        .ok_or_else(|| to_py_value_err(&"append vec is truncated"))?;
+    let executable = match header[96] {
+        0 => false,
+        1 => true,
+        b => {
+            return Err(to_py_value_err(&format!(
+                "invalid executable flag {} in append vec",
+                b
+            )))
+        }
+    };
+    let account = AccountOriginal {
+        lamports: read_u64(header, 48),
+        data: data[STORED_ACCOUNT_HEADER_SIZE..end].to_vec(),
+        owner: PubkeyOriginal::new(&header[64..96]),
+        executable,
+        rent_epoch: read_u64(header, 56),
+    };
+    let stored = StoredAccount {
+        pubkey: PubkeyOriginal::new(&header[16..48]).into(),
+        account: account.into(),
+        slot,
+        write_version: read_u64(header, 0),
+        hash: HashOriginal::new(&header[104..136]).into(),
+    };
+    let next = end.saturating_add(7) & !7;
+    Ok(Some((stored, next)))
+}
+
+fn parse_append_vec(data: &[u8], slot: Slot) -> PyResult<Vec<StoredAccount>> {
+    let mut accounts = Vec::new();
+    let mut offset = 0;
+    while let Some((stored, next)) = parse_stored_account(&data[offset..], slot)? {
+        accounts.push(stored);
+        offset = offset.saturating_add(next).min(data.len());
+    }
+    Ok(accounts)
+}
+
+/// Parse the accounts in an append vec file.
+///
+/// An account can be stored in several append vecs. The version with the highest
+/// ``(slot, write_version)`` is the current one; see :func:`latest_accounts`.
+///
+/// Args:
+///     data (bytes): The contents of the append vec.
+///     slot (int): The slot of the append vec, the first part of its file name.
+///
+/// Returns:
+///     List[StoredAccount]: The stored accounts, in file order.
+///
+/// Raises:
+///     ValueError: If the append vec is malformed.
+///
+#[pyfunction]
+pub fn read_append_vec(data: &[u8], slot: Slot) -> PyResult<Vec<StoredAccount>> {
+    parse_append_vec(data, slot)
+}
+
+/// The slot of an append vec in a snapshot archive, named ``accounts/<slot>.<id>``.
+fn append_vec_slot(path: &str) -> Option<Slot> {
+    let (slot, id) = path
+        .trim_start_matches("./")
+        .strip_prefix("accounts/")?
+        .split_once('.')?;
+    id.parse::<u64>().ok()?;
+    slot.parse().ok()
+}
+
+fn tar_size(field: &[u8]) -> io::Result<u64> {
+    if field[0] & 0x80 != 0 {
+        // GNU base-256 encoding, used for files of 8GiB or more.
+        return Ok(field[1..]
+            .iter()
+            .fold(u64::from(field[0] & 0x7f), |acc, b| {
+                (acc << 8) | u64::from(*b)
+            }));
+    }
+    let digits = String::from_utf8_lossy(field);
+    let digits = digits.trim_matches(|c: char| c == '\0' || c == ' ');
+    if digits.is_empty() {
+        return Ok(0);
+    }
+    u64::from_str_radix(digits, 8).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// The zero padding after a tar entry of ``size`` bytes.
+fn tar_padding(size: u64) -> u64 {
+    let block = TAR_BLOCK_SIZE as u64;
+    (block - size % block) % block
+}
+
+fn tar_string(field: &[u8]) -> String {
+    let end = field.iter().position(|b| *b == 0).unwrap_or(field.len());
+    String::from_utf8_lossy(&field[..end]).into_owned()
+}
+
+/// Iterate the accounts stored in a snapshot archive.
+///
+/// Reads the append vecs under ``accounts/`` in a full or incremental snapshot
+/// archive, either ``.tar.zst`` or uncompressed ``.tar``, one at a time so memory
+/// use stays bounded by the largest append vec. Every stored version is yielded,
+/// so pass the reader to :func:`latest_accounts` to get the account state.
+///
+/// Args:
+///     path (str): The path of the snapshot archive.
+///     owners (Optional[Sequence[Pubkey]]): Only yield versions owned by these programs.
+///
+/// Raises:
+///     OSError: If the archive can't be read.
+///     ValueError: If an append vec is malformed.
+///
+#[pyclass(module = "solders.snapshot", subclass)]
+pub struct SnapshotReader {
+    archive: Box<dyn Read + Send>,
+    owners: Option<HashSet<PubkeyOriginal>>,
+    pending: std::vec::IntoIter<StoredAccount>,
+}
+
+impl SnapshotReader {
+    fn skip(&mut self, len: u64) -> io::Result<()> {
+        let skipped = io::copy(&mut (&mut self.archive).take(len), &mut io::sink())?;
+        if skipped < len {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        Ok(())
+    }
+
+    /// Read the next regular file header, returning its path and size.
+    fn next_file(&mut self) -> io::Result<Option<(String, u64)>> {
+        let mut long_name = None;
+        loop {
+            let mut header = [0u8; TAR_BLOCK_SIZE];
+            match self.archive.read_exact(&mut header) {
+                Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+                result => result?,
+            }
+            if header.iter().all(|b| *b == 0) {
+                return Ok(None);
+            }
+            let size = tar_size(&header[124..136])?;
+            let padding = tar_padding(size);
+            match header[156] {
+                b'0' | 0 => {
+                    let name = long_name.take().unwrap_or_else(|| {
+                        let prefix = tar_string(&header[345..500]);
+                        let name = tar_string(&header[..100]);
+                        if prefix.is_empty() {
+                            name
+                        } else {
+                            format!("{}/{}", prefix, name)
+                        }
+                    });
+                    return Ok(Some((name, size)));
+                }
+                b'L' => {
+                    let mut name = Vec::new();
+                    (&mut self.archive).take(size).read_to_end(&mut name)?;
+                    long_name = Some(tar_string(&name));
+                    self.skip(padding)?;
+                }
+                _ => self.skip(size + padding)?,
+            }
+        }
+    }
+
+    fn next_append_vec(&mut self) -> PyResult<Option<Vec<StoredAccount>>> {
+        while let Some((path, size)) = self.next_file()? {
+            let padding = tar_padding(size);
+            let slot = match append_vec_slot(&path) {
+                Some(slot) => slot,
+                None => {
+                    self.skip(size + padding)?;
+                    continue;
+                }
+            };
+            let mut data = Vec::new();
+            (&mut self.archive).take(size).read_to_end(&mut data)?;
+            if (data.len() as u64) < size {
+                return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+            }
+            self.skip(padding)?;
+            let mut accounts = parse_append_vec(&data, slot)?;
+            if let Some(owners) = &self.owners {
+                accounts.retain(|stored| owners.contains(&stored.account.owner().0));
+            }
+            return Ok(Some(accounts));
+        }
+        Ok(None)
+    }
+}
+
+#[pymethods]
+impl SnapshotReader {
+    #[new]
+    pub fn new(path: &str, owners: Option<Vec<Pubkey>>) -> PyResult<Self> {
+        let mut file = BufReader::new(File::open(path)?);
+        let archive: Box<dyn Read + Send> = if file.fill_buf()?.starts_with(&ZSTD_MAGIC) {
+            Box::new(zstd::stream::read::Decoder::with_buffer(file)?)
+        } else {
+            Box::new(file)
+        };
+        Ok(Self {
+            archive,
+            owners: owners.map(|owners| owners.into_iter().map(|o| o.0).collect()),
+            pending: Vec::new().into_iter(),
+        })
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<'_, Self>) -> PyResult<Option<StoredAccount>> {
+        loop {
+            if let Some(stored) = slf.pending.next() {
+                return Ok(Some(stored));
+            }
+            match slf.next_append_vec()? {
+                Some(accounts) => slf.pending = accounts.into_iter(),
+                None => return Ok(None),
+            }
+        }
+    }
+}
+
+/// Reduce stored account versions to the current state of each account.
+///
+/// Keeps the version with the highest ``(slot, write_version)`` for each pubkey and
+/// drops accounts whose current version has zero lamports, since they were closed.
+/// The owner filter is applied to the current version, so an account that was closed
+/// or reassigned is not reported with a stale owner.
+///
+/// Args:
+///     stored (Iterable[StoredAccount]): The stored versions, e.g. a :class:`SnapshotReader`
+///         without an owner filter.
+///     owners (Optional[Sequence[Pubkey]]): Only return accounts owned by these programs.
+///
+/// Returns:
+///     Dict[Pubkey, Account]: The current accounts.
+///
+#[pyfunction]
+pub fn latest_accounts(
+    stored: &PyAny,
+    owners: Option<Vec<Pubkey>>,
+) -> PyResult<HashMap<Pubkey, Account>> {
+    let owners: Option<HashSet<Pubkey>> = owners.map(|owners| owners.into_iter().collect());
+    // Every pubkey keeps an entry, with ``None`` once its newest version is closed or
+    // filtered out, so an older matching version seen later can't take its place.
+    let mut latest: HashMap<Pubkey, (Slot, u64, Option<Account>)> = HashMap::new();
+    for item in stored.iter()? {
+        let stored: StoredAccount = item?.extract()?;
+        let version = (stored.slot, stored.write_version);
+        let is_newer = latest
+            .get(&stored.pubkey)
+            .map_or(true, |(slot, write_version, _)| {
+                version > (*slot, *write_version)
+            });
+        if !is_newer {
+            continue;
+        }
+        let account = stored.account;
+        let is_current = account.lamports() > 0
+            && owners
+                .as_ref()
+                .map_or(true, |owners| owners.contains(&account.owner()));
+        latest.insert(
+            stored.pubkey,
+            (version.0, version.1, is_current.then_some(account)),
+        );
+    }
+    Ok(latest
+        .into_iter()
+        .filter_map(|(pubkey, (_, _, account))| account.map(|account| (pubkey, account)))
+        .collect())
+}
+
+pub(crate) fn create_snapshot_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "snapshot")?;
+    m.add_class::<StoredAccount>()?;
+    m.add_class::<SnapshotReader>()?;
+    m.add_function(wrap_pyfunction!(read_append_vec, m)?)?;
+    m.add_function(wrap_pyfunction!(latest_accounts, m)?)?;
+    Ok(m)
+}
@@ -0,0 +1,359 @@
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use solana_sdk::{pubkey::Pubkey as PubkeyOriginal, stake, system_program, vote};
+use solders_macros::{enum_string, richcmp_eq_only};
+use solders_primitives::pubkey::Pubkey;
+use solders_traits::{EnumString, RichcmpEqualityOnly};
+
+use crate::{
+    layouts::{
+        MINT_IS_INITIALIZED_OFFSET, MINT_LEN, MULTISIG_LEN, STAKE_ACCOUNT_LEN,
+        TOKEN_2022_ACCOUNT_TYPE_ACCOUNT, TOKEN_2022_ACCOUNT_TYPE_MINT, TOKEN_ACCOUNT_LEN,
+        TOKEN_ACCOUNT_STATE_OFFSET,
+    },
+    program_ids::ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
+    spl_errors::{TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID},
+    squads::discriminator,
+};
+
+/// The layout matches and the owner is the program that defines it.
+const CONFIDENCE_HIGH: f64 = 0.9;
+/// The layout matches but the owner is unknown.
+const CONFIDENCE_MEDIUM: f64 = 0.6;
+/// The data merely looks like it could have this layout.
+const CONFIDENCE_LOW: f64 = 0.2;
+
+const MAX_MULTISIG_SIGNERS: u8 = 11;
+const NONCE_ACCOUNT_LEN: usize = 80;
+const LOOKUP_TABLE_META_LEN: usize = 56;
+const VOTE_ACCOUNT_LENS: [usize; 2] = [3731, 3762];
+
+/// The shortest and longest Borsh strings reported as hints.
+const MIN_STRING_LEN: usize = 3;
+const MAX_STRING_LEN: usize = 256;
+const MAX_STRING_HINTS: usize = 8;
+
+/// A layout that account data may have.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[pyclass(module = "solders.sniff")]
+pub enum LayoutKind {
+    /// An Anchor account, tagged with an 8-byte discriminator.
+    Anchor,
+    /// An SPL Token or Token-2022 token account.
+    TokenAccount,
+    /// An SPL Token or Token-2022 mint.
+    Mint,
+    /// An SPL Token multisig.
+    TokenMultisig,
+    /// A durable nonce account.
+    Nonce,
+    /// An address lookup table.
+    LookupTable,
+    /// A stake account.
+    Stake,
+    /// A vote account.
+    Vote,
+    /// Some other Borsh-encoded struct.
+    Borsh,
+}
+
+impl EnumString for LayoutKind {}
+
+#[enum_string]
+#[pymethods]
+impl LayoutKind {}
+
+/// A layout :func:`sniff_layout` found plausible.
+#[pyclass(module = "solders.sniff", subclass)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LayoutCandidate {
+    /// LayoutKind: The layout.
+    #[pyo3(get)]
+    pub kind: LayoutKind,
+    /// float: How likely the layout is, from 0 to 1.
+    #[pyo3(get)]
+    pub confidence: f64,
+    /// Optional[str]: The Anchor account name, if known.
+    #[pyo3(get)]
+    pub name: Option<String>,
+    /// List[str]: What the guess is based on.
+    #[pyo3(get)]
+    pub notes: Vec<String>,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl LayoutCandidate {
+    #[new]
+    pub fn new(
+        kind: LayoutKind,
+        confidence: f64,
+        name: Option<String>,
+        notes: Vec<String>,
+    ) -> Self {
+        Self {
+            kind,
+            confidence,
+            name,
+            notes,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for LayoutCandidate {}
+
+fn u32_at(data: &[u8], offset: usize) -> Option<u32> {
+    data.get(offset..offset + 4)
+        .map(|b| u32::from_le_bytes(b.try_into().unwrap()))
+}
+
+/// Whether the ``COption`` tag at ``offset`` is 0 or 1.
+fn is_option_tag(data: &[u8], offset: usize) -> bool {
+    matches!(u32_at(data, offset), Some(0 | 1))
+}
+
+/// The confidence of a layout defined by ``programs``, or ``None`` if the
+/// account is owned by some other program.
+fn owner_confidence(owner: Option<&PubkeyOriginal>, programs: &[PubkeyOriginal]) -> Option<f64> {
+    match owner {
+        None => Some(CONFIDENCE_MEDIUM),
+        Some(owner) if programs.contains(owner) => Some(CONFIDENCE_HIGH),
+        Some(_) => None,
+    }
+}
+
+fn candidate(kind: LayoutKind, confidence: f64, note: String) -> LayoutCandidate {
+    LayoutCandidate::new(kind, confidence, None, vec![note])
+}
+
+fn token_candidates(data: &[u8], owner: Option<&PubkeyOriginal>) -> Vec<LayoutCandidate> {
+    let confidence = match owner_confidence(owner, &[TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID]) {
+        Some(confidence) => confidence,
+        None => return vec![],
+    };
+    let extended = |account_type| {
+        data.len() > TOKEN_ACCOUNT_LEN
+            && data.len() != MULTISIG_LEN
+            && data[TOKEN_ACCOUNT_LEN] == account_type
+            && owner != Some(&TOKEN_PROGRAM_ID)
+    };
+    let mut found = vec![];
+    if (data.len() == MINT_LEN || extended(TOKEN_2022_ACCOUNT_TYPE_MINT))
+        && is_option_tag(data, 0)
+        && is_option_tag(data, 46)
+        && data[MINT_IS_INITIALIZED_OFFSET] <= 1
+    {
+        found.push(candidate(
+            LayoutKind::Mint,
+            confidence,
+            format!("{} bytes with valid mint option tags", data.len()),
+        ));
+    }
+    if (data.len() == TOKEN_ACCOUNT_LEN || extended(TOKEN_2022_ACCOUNT_TYPE_ACCOUNT))
+        && is_option_tag(data, 72)
+        && is_option_tag(data, 109)
+        && is_option_tag(data, 129)
+        && data[TOKEN_ACCOUNT_STATE_OFFSET] <= 2
+    {
+        found.push(candidate(
+            LayoutKind::TokenAccount,
+            confidence,
+            format!("{} bytes with valid token account option tags", data.len()),
+        ));
+    }
+    if data.len() == MULTISIG_LEN
+        && data[0] <= data[1]
+        && data[1] <= MAX_MULTISIG_SIGNERS
+        && data[2] <= 1
+    {
+        found.push(candidate(
+            LayoutKind::TokenMultisig,
+            confidence,
+            format!("{} of {} signers", data[0], data[1]),
+        ));
+    }
+    found
+}
+
+fn native_candidates(data: &[u8], owner: Option<&PubkeyOriginal>) -> Vec<LayoutCandidate> {
+    let mut found = vec![];
+    let mut check = |kind, programs: &[PubkeyOriginal], matches: bool, note: String| {
+        if let Some(confidence) = owner_confidence(owner, programs).filter(|_| matches) {
+            found.push(candidate(kind, confidence, note));
+        }
+    };
+    let tag = u32_at(data, 0);
+    check(
+        LayoutKind::Nonce,
+        &[system_program::ID],
+        data.len() == NONCE_ACCOUNT_LEN
+            && matches!(tag, Some(0 | 1))
+            && matches!(u32_at(data, 4), Some(0 | 1)),
+        format!(
+            "{} bytes with nonce version {}",
+            data.len(),
+            tag.unwrap_or_default()
+        ),
+    );
+    check(
+        LayoutKind::LookupTable,
+        &[ADDRESS_LOOKUP_TABLE_PROGRAM_ID],
+        data.len() >= LOOKUP_TABLE_META_LEN
+            && (data.len() - LOOKUP_TABLE_META_LEN) % 32 == 0
+            && tag == Some(1),
+        format!(
+            "lookup table metadata and {} addresses",
+            data.len().saturating_sub(LOOKUP_TABLE_META_LEN) / 32
+        ),
+    );
+    check(
+        LayoutKind::Stake,
+        &[stake::program::ID],
+        data.len() == STAKE_ACCOUNT_LEN && matches!(tag, Some(0..=3)),
+        format!(
+            "{} bytes with stake state {}",
+            data.len(),
+            tag.unwrap_or_default()
+        ),
+    );
+    check(
+        LayoutKind::Vote,
+        &[vote::program::ID],
+        VOTE_ACCOUNT_LENS.contains(&data.len()) && matches!(tag, Some(0..=2)),
+        format!(
+            "{} bytes with vote state version {}",
+            data.len(),
+            tag.unwrap_or_default()
+        ),
+    );
+    found
+}
+
+fn anchor_candidate(data: &[u8], anchor_names: &[String]) -> Option<LayoutCandidate> {
+    let prefix = data.get(..8)?;
+    if let Some(name) = anchor_names
+        .iter()
+        .find(|name| discriminator("account", name) == prefix)
+    {
+        return Some(LayoutCandidate::new(
+            LayoutKind::Anchor,
+            CONFIDENCE_HIGH,
+            Some(name.clone()),
+            vec![format!("discriminator matches account {name:?}")],
+        ));
+    }
+    let mut distinct = prefix.to_vec();
+    distinct.sort_unstable();
+    distinct.dedup();
+    // A hash prefix rarely repeats bytes, unlike small integers and padding.
+    (distinct.len() >= 6 && !prefix.contains(&0)).then(|| {
+        let hex: String = prefix.iter().map(|b| format!("{:02x}", b)).collect();
+        candidate(
+            LayoutKind::Anchor,
+            CONFIDENCE_LOW,
+            format!("unrecognized discriminator {hex}"),
+        )
+    })
+}
+
+/// Find length-prefixed printable strings from ``start`` on, which suggest
+/// Borsh ``String`` fields.
+fn borsh_strings(data: &[u8], start: usize) -> Vec<String> {
+    let mut hints = vec![];
+    let mut offset = start;
+    while offset + 4 <= data.len() && hints.len() < MAX_STRING_HINTS {
+        let len = u32_at(data, offset).unwrap() as usize;
+        let text = data
+            .get(offset + 4..offset + 4 + len)
+            .filter(|_| (MIN_STRING_LEN..=MAX_STRING_LEN).contains(&len))
+            .filter(|bytes| bytes.iter().all(|b| b.is_ascii_graphic() || *b == b' '));
+        match text {
+            Some(bytes) => {
+                hints.push(format!(
+                    "string {:?} at offset {offset}",
+                    String::from_utf8_lossy(bytes)
+                ));
+                offset += 4 + len;
+            }
+            None => offset += 1,
+        }
+    }
+    hints
+}
+
+/// Guess the layout of unknown account data.
+///
+/// This is best-effort triage for explorers and incident responders. Known SPL
+/// and native layouts are checked by size and tag values, Anchor accounts by their
+/// 8-byte discriminator, and anything else is searched for length-prefixed strings
+/// that suggest a Borsh struct. Each candidate's notes say what it is based on.
+///
+/// Args:
+///     account_data (bytes): The account data.
+///     owner (Optional[Pubkey]): The owning program, if known. Known layouts owned by
+///         their program get high confidence, and those owned by another are ruled out.
+///     anchor_names (Optional[Sequence[str]]): Anchor account type names to match the
+///         discriminator against, e.g. from the program's IDL.
+///
+/// Returns:
+///     List[LayoutCandidate]: The plausible layouts, most likely first. Empty if none is.
+///
+/// Example:
+///     >>> from solders.sniff import sniff_layout, LayoutKind
+///     >>> from solders.pubkey import Pubkey
+///     >>> from hashlib import sha256
+///     >>> [c.kind == LayoutKind.Mint for c in sniff_layout(bytes(82))]
+///     [True]
+///     >>> data = sha256(b"account:Vault").digest()[:8] + (5).to_bytes(4, "little") + b"alice"
+///     >>> [best] = sniff_layout(data, anchor_names=["Pool", "Vault"])
+///     >>> best.kind == LayoutKind.Anchor, best.name
+///     (True, 'Vault')
+///     >>> best.notes
+///     ['discriminator matches account "Vault"', 'string "alice" at offset 8']
+///
+#[pyfunction]
+#[pyo3(signature = (account_data, owner = None, anchor_names = None))]
+pub fn sniff_layout(
+    account_data: &[u8],
+    owner: Option<Pubkey>,
+    anchor_names: Option<Vec<String>>,
+) -> Vec<LayoutCandidate> {
+    let owner = owner.map(PubkeyOriginal::from);
+    let mut found = token_candidates(account_data, owner.as_ref());
+    found.extend(native_candidates(account_data, owner.as_ref()));
+    if found.is_empty() {
+        let anchor = anchor_candidate(account_data, &anchor_names.unwrap_or_default());
+        let start = if anchor.is_some() { 8 } else { 0 };
+        let strings = borsh_strings(account_data, start);
+        match anchor {
+            Some(mut anchor) => {
+                anchor.notes.extend(strings);
+                found.push(anchor);
+            }
+            None if !strings.is_empty() => {
+                found.push(LayoutCandidate::new(
+                    LayoutKind::Borsh,
+                    CONFIDENCE_LOW,
+                    None,
+                    strings,
+                ));
+            }
+            None => {}
+        }
+    }
+    found.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+    found
+}
+
+pub(crate) fn create_sniff_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "sniff")?;
+    m.add_class::<LayoutKind>()?;
+    m.add_class::<LayoutCandidate>()?;
+    m.add_function(wrap_pyfunction!(sniff_layout, m)?)?;
+    Ok(m)
+}
@@ -0,0 +1,285 @@
+use pyo3::prelude::*;
+use solana_sdk::{
+    instruction::InstructionError as InstructionErrorOriginal, pubkey,
+    pubkey::Pubkey as PubkeyOriginal, transaction::TransactionError as TransactionErrorOriginal,
+};
+use solders_primitives::pubkey::Pubkey;
+
+use crate::transaction_status::TransactionErrorType;
+
+pub(crate) const TOKEN_PROGRAM_ID: PubkeyOriginal =
+    pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+pub(crate) const TOKEN_2022_PROGRAM_ID: PubkeyOriginal =
+    pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+pub(crate) const ASSOCIATED_TOKEN_PROGRAM_ID: PubkeyOriginal =
+    pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+const STAKE_POOL_PROGRAM_ID: PubkeyOriginal =
+    pubkey!("SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy");
+
+/// ``TokenError`` variants. SPL Token uses the first 20; Token-2022 uses all of them.
+const TOKEN_ERRORS: &[(&str, &str)] = &[
+    ("NotRentExempt", "Lamport balance below rent-exempt threshold"),
+    ("InsufficientFunds", "Insufficient funds"),
+    ("InvalidMint", "Invalid Mint"),
+    ("MintMismatch", "Account not associated with this Mint"),
+    ("OwnerMismatch", "Owner does not match"),
+    ("FixedSupply", "Fixed supply"),
+    ("AlreadyInUse", "Already in use"),
+    ("InvalidNumberOfProvidedSigners", "Invalid number of provided signers"),
+    ("InvalidNumberOfRequiredSigners", "Invalid number of required signers"),
+    ("UninitializedState", "State is unititialized"),
+    ("NativeNotSupported", "Instruction does not support native tokens"),
+    ("NonNativeHasBalance", "Non-native account can only be closed if its balance is zero"),
+    ("InvalidInstruction", "Invalid instruction"),
+    ("InvalidState", "State is invalid for requested operation"),
+    ("Overflow", "Operation overflowed"),
+    ("AuthorityTypeNotSupported", "Account does not support specified authority type"),
+    ("MintCannotFreeze", "This token mint cannot freeze accounts"),
+    ("AccountFrozen", "Account is frozen"),
+    ("MintDecimalsMismatch", "The provided decimals value different from the Mint decimals"),
+    ("NonNativeNotSupported", "Instruction does not support non-native tokens"),
+    ("ExtensionTypeMismatch", "Extension type does not match already existing extensions"),
+    ("ExtensionBaseMismatch", "Extension does not match the base type provided"),
+    ("ExtensionAlreadyInitialized", "Extension already initialized on this account"),
+    ("ConfidentialTransferAccountHasBalance", "An account can only be closed if its confidential balance is zero"),
+    ("ConfidentialTransferAccountNotApproved", "Account not approved for confidential transfers"),
+    ("ConfidentialTransferDepositsAndTransfersDisabled", "Account not accepting deposits or transfers"),
+    ("ConfidentialTransferElGamalPubkeyMismatch", "ElGamal public key mismatch"),
+    ("ConfidentialTransferBalanceMismatch", "Balance mismatch"),
+    ("MintHasSupply", "Mint has non-zero supply. Burn all tokens before closing the mint."),
+    ("NoAuthorityExists", "No authority exists to perform the desired operation"),
+    ("TransferFeeExceedsMaximum", "Transfer fee exceeds maximum of 10,000 basis points"),
+    ("MintRequiredForTransfer", "Mint required for this account to transfer tokens, use `transfer_checked` or `transfer_checked_with_fee`"),
+    ("FeeMismatch", "Calculated fee does not match expected fee"),
+    ("FeeParametersMismatch", "Fee parameters associated with confidential transfer zero-knowledge proofs do not match fee parameters in mint"),
+    ("ImmutableOwner", "The owner authority cannot be changed"),
+    ("AccountHasWithheldTransferFees", "An account can only be closed if its withheld fee balance is zero, harvest fees to the mint and try again"),
+    ("NoMemo", "No memo in previous instruction; required for recipient to receive a transfer"),
+    ("NonTransferable", "Transfer is disabled for this mint"),
+    ("NonTransferableNeedsImmutableOwnership", "Non-transferable tokens can't be minted to an account without immutable ownership"),
+];
+
+const SPL_TOKEN_ERROR_COUNT: usize = 20;
+
+const ASSOCIATED_TOKEN_ERRORS: &[(&str, &str)] = &[(
+    "InvalidOwner",
+    "Associated token account owner does not match address derivation",
+)];
+
+const STAKE_POOL_ERRORS: &[(&str, &str)] = &[
+    ("AlreadyInUse", "Stake pool already in use"),
+    (
+        "InvalidProgramAddress",
+        "Invalid program address generated from bump seed and key",
+    ),
+    ("InvalidState", "Invalid state"),
+    ("CalculationFailure", "Calculation failure"),
+    ("FeeTooHigh", "Fee too high"),
+    ("WrongAccountMint", "Wrong account mint"),
+    ("WrongManager", "Wrong manager"),
+    ("SignatureMissing", "Signature missing"),
+    ("InvalidValidatorStakeList", "Invalid validator stake list"),
+    ("InvalidFeeAccount", "Invalid fee account"),
+    ("WrongPoolMint", "Wrong pool mint"),
+    ("WrongStakeState", "Wrong stake state"),
+    ("UserStakeNotActive", "User stake not active"),
+    ("ValidatorAlreadyAdded", "Validator already added"),
+    ("ValidatorNotFound", "Validator not found"),
+    (
+        "InvalidStakeAccountAddress",
+        "Invalid stake account address",
+    ),
+    (
+        "StakeListOutOfDate",
+        "Identify validator stake accounts with old balances and update them",
+    ),
+    (
+        "StakeListAndPoolOutOfDate",
+        "First update old validator stake account balances and then pool stake balance",
+    ),
+    (
+        "UnknownValidatorStakeAccount",
+        "Validator stake account is not found in the list storage",
+    ),
+    ("WrongMintingAuthority", "Wrong minting authority"),
+    (
+        "UnexpectedValidatorListAccountSize",
+        "The size of the given validator stake list does match the expected amount",
+    ),
+    ("WrongStaker", "Wrong pool staker account"),
+    (
+        "NonZeroPoolTokenSupply",
+        "Pool token supply is not zero on initialization",
+    ),
+    (
+        "StakeLamportsNotEqualToMinimum",
+        "The lamports in the validator stake account is not equal to the minimum",
+    ),
+    (
+        "IncorrectDepositVoteAddress",
+        "The provided deposit stake account is not delegated to the preferred deposit vote account",
+    ),
+    (
+        "IncorrectWithdrawVoteAddress",
+        "The provided withdraw stake account is not the preferred deposit vote account",
+    ),
+    (
+        "InvalidMintFreezeAuthority",
+        "The mint has an invalid freeze authority",
+    ),
+    (
+        "FeeIncreaseTooHigh",
+        "The fee cannot increase by a factor exceeding the stipulated ratio",
+    ),
+    (
+        "WithdrawalTooSmall",
+        "Not enough pool tokens provided to withdraw stake with one lamport",
+    ),
+    (
+        "DepositTooSmall",
+        "Not enough lamports provided for deposit to result in one pool token",
+    ),
+    (
+        "InvalidStakeDepositAuthority",
+        "Provided stake deposit authority does not match the program's",
+    ),
+    (
+        "InvalidSolDepositAuthority",
+        "Provided sol deposit authority does not match the program's",
+    ),
+    (
+        "InvalidPreferredValidator",
+        "Provided preferred validator is invalid",
+    ),
+    (
+        "TransientAccountInUse",
+        "Provided validator stake account already has a transient stake account in use",
+    ),
+    (
+        "InvalidSolWithdrawAuthority",
+        "Provided sol withdraw authority does not match the program's",
+    ),
+    (
+        "SolWithdrawalTooLarge",
+        "Too much SOL withdrawn from the stake pool's reserve account",
+    ),
+    (
+        "InvalidMetadataAccount",
+        "Metadata account derived from pool mint account does not match the one passed to program",
+    ),
+];
+
+fn lookup(program_id: &PubkeyOriginal, code: u32) -> Option<(&'static str, &'static str)> {
+    let table = if *program_id == TOKEN_PROGRAM_ID {
+        &TOKEN_ERRORS[..SPL_TOKEN_ERROR_COUNT]
+    } else if *program_id == TOKEN_2022_PROGRAM_ID {
+        TOKEN_ERRORS
+    } else if *program_id == ASSOCIATED_TOKEN_PROGRAM_ID {
+        ASSOCIATED_TOKEN_ERRORS
+    } else if *program_id == STAKE_POOL_PROGRAM_ID {
+        STAKE_POOL_ERRORS
+    } else {
+        return None;
+    };
+    table.get(code as usize).copied()
+}
+
+/// Look up the name of a custom program error.
+///
+/// Covers SPL Token, Token-2022, Associated Token Account and Stake Pool.
+///
+/// Args:
+///     program_id (Pubkey): The program that returned the error.
+///     code (int): The ``InstructionError::Custom`` code.
+///
+/// Returns:
+///     Optional[str]: The error name, or ``None`` if unknown.
+///
+/// Example:
+///     >>> from solders.pubkey import Pubkey
+///     >>> from solders.spl_errors import custom_error_name
+///     >>> token_program = Pubkey.from_string("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+///     >>> custom_error_name(token_program, 1)
+///     'InsufficientFunds'
+///
+#[pyfunction]
+pub fn custom_error_name(program_id: Pubkey, code: u32) -> Option<&'static str> {
+    lookup(&program_id.0, code).map(|(name, _)| name)
+}
+
+/// Look up the message of a custom program error.
+///
+/// Args:
+///     program_id (Pubkey): The program that returned the error.
+///     code (int): The ``InstructionError::Custom`` code.
+///
+/// Returns:
+///     Optional[str]: The error message, or ``None`` if unknown.
+///
+#[pyfunction]
+pub fn custom_error_message(program_id: Pubkey, code: u32) -> Option<&'static str> {
+    lookup(&program_id.0, code).map(|(_, msg)| msg)
+}
+
+/// The program that raised custom error ``code``, from the first log line reporting it.
+///
+/// A program that fails in a cross-program invocation is logged before its callers,
+/// which report the same error.
+fn failing_program(log_messages: &[String], code: u32) -> Option<PubkeyOriginal> {
+    let suffix = format!(" failed: custom program error: {:#x}", code);
+    log_messages.iter().find_map(|line| {
+        line.strip_prefix("Program ")?
+            .strip_suffix(suffix.as_str())?
+            .parse()
+            .ok()
+    })
+}
+
+/// Format a transaction error, naming custom errors from known SPL programs.
+///
+/// The failing instruction's program may have passed on an error raised by a program
+/// it invoked, so the program that raised a custom error is read from the logs.
+/// Without a matching log line the code is left undecoded.
+///
+/// Args:
+///     err (TransactionErrorType): The error from the transaction status.
+///     log_messages (Sequence[str]): The transaction's log messages, used to find the failing program.
+///
+/// Returns:
+///     str: The formatted error.
+///
+/// Example:
+///     >>> from solders.spl_errors import describe_transaction_error
+///     >>> from solders.transaction_status import TransactionErrorInstructionError, InstructionErrorCustom
+///     >>> logs = [
+///     ...     "Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA invoke [1]",
+///     ...     "Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA failed: custom program error: 0x1",
+///     ... ]
+///     >>> err = TransactionErrorInstructionError(0, InstructionErrorCustom(1))
+///     >>> describe_transaction_error(err, logs)
+///     'Error processing Instruction 0: custom program error: 0x1 (InsufficientFunds: Insufficient funds)'
+///     >>> describe_transaction_error(err, [])
+///     'Error processing Instruction 0: custom program error: 0x1'
+///
+#[pyfunction]
+pub fn describe_transaction_error(err: TransactionErrorType, log_messages: Vec<String>) -> String {
+    let err = TransactionErrorOriginal::from(err);
+    if let TransactionErrorOriginal::InstructionError(_, InstructionErrorOriginal::Custom(code)) =
+        err
+    {
+        let known =
+            failing_program(&log_messages, code).and_then(|program_id| lookup(&program_id, code));
+        if let Some((name, msg)) = known {
+            return format!("{} ({}: {})", err, name, msg);
+        }
+    }
+    err.to_string()
+}
+
+pub(crate) fn create_spl_errors_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "spl_errors")?;
+    m.add_function(wrap_pyfunction!(custom_error_name, m)?)?;
+    m.add_function(wrap_pyfunction!(custom_error_message, m)?)?;
+    m.add_function(wrap_pyfunction!(describe_transaction_error, m)?)?;
+    Ok(m)
+}
@@ -0,0 +1,806 @@
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use solana_sdk::{
+    account::ReadableAccount,
+    address_lookup_table_account::AddressLookupTableAccount as AddressLookupTableAccountOriginal,
+    clock::UnixTimestamp,
+    hash::hashv,
+    instruction::{AccountMeta as AccountMetaOriginal, Instruction as InstructionOriginal},
+    pubkey,
+    pubkey::Pubkey as PubkeyOriginal,
+    system_program,
+};
+use solders_macros::{enum_string, richcmp_eq_only};
+use solders_primitives::{
+    address_lookup_table_account::AddressLookupTableAccount, instruction::Instruction,
+    pubkey::Pubkey,
+};
+use solders_traits::{to_py_value_err, EnumString, RichcmpEqualityOnly};
+
+use crate::account::Account;
+
+/// The Squads v4 multisig program.
+pub const SQUADS_PROGRAM_ID: PubkeyOriginal =
+    pubkey!("SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf");
+
+const SEED_PREFIX: &[u8] = b"multisig";
+const SEED_MULTISIG: &[u8] = b"multisig";
+const SEED_VAULT: &[u8] = b"vault";
+const SEED_TRANSACTION: &[u8] = b"transaction";
+const SEED_PROPOSAL: &[u8] = b"proposal";
+
+const PERMISSION_INITIATE: u8 = 1 << 0;
+const PERMISSION_VOTE: u8 = 1 << 1;
+const PERMISSION_EXECUTE: u8 = 1 << 2;
+
+/// The first 8 bytes of ``sha256("<namespace>:<name>")``, as Anchor uses to tag
+/// accounts and instructions.
+pub(crate) fn discriminator(namespace: &str, name: &str) -> [u8; 8] {
+    let hash = hashv(&[namespace.as_bytes(), b":", name.as_bytes()]);
+    hash.to_bytes()[..8].try_into().unwrap()
+}
+
+/// Reads the Borsh encoding of a Squads account.
+struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    /// Check the owner and discriminator of ``account`` and read past the discriminator.
+    fn new(account: &'a Account, name: &str) -> PyResult<Self> {
+        if *account.owner() != SQUADS_PROGRAM_ID {
+            return Err(to_py_value_err(&format!(
+                "account is owned by {}, not the Squads program",
+                account.owner()
+            )));
+        }
+        let data = account.data();
+        if !data.starts_with(&discriminator("account", name)) {
+            return Err(to_py_value_err(&format!("account is not a {}", name)));
+        }
+        Ok(Self { data: &data[8..] })
+    }
+
+    fn take(&mut self, len: usize) -> PyResult<&'a [u8]> {
+        if self.data.len() < len {
+            return Err(to_py_value_err(&"account data is too short"));
+        }
+        let (taken, rest) = self.data.split_at(len);
+        self.data = rest;
+        Ok(taken)
+    }
+
+    fn u8(&mut self) -> PyResult<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> PyResult<u16> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> PyResult<u32> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> PyResult<u64> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn i64(&mut self) -> PyResult<i64> {
+        Ok(i64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn pubkey(&mut self) -> PyResult<PubkeyOriginal> {
+        Ok(PubkeyOriginal::new(self.take(32)?))
+    }
+
+    fn option_pubkey(&mut self) -> PyResult<Option<PubkeyOriginal>> {
+        match self.u8()? {
+            0 => Ok(None),
+            _ => self.pubkey().map(Some),
+        }
+    }
+
+    fn vec<T>(&mut self, mut item: impl FnMut(&mut Self) -> PyResult<T>) -> PyResult<Vec<T>> {
+        let len = self.u32()?;
+        (0..len).map(|_| item(self)).collect()
+    }
+
+    fn bytes(&mut self) -> PyResult<Vec<u8>> {
+        let len = self.u32()? as usize;
+        Ok(self.take(len)?.to_vec())
+    }
+}
+
+/// A member of a Squads multisig.
+///
+/// Args:
+///     key (Pubkey): The member's address.
+///     permissions (int): The permission bitmask: 1 to initiate, 2 to vote and 4 to execute.
+///
+#[pyclass(module = "solders.squads", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Member {
+    /// Pubkey: The member's address.
+    #[pyo3(get)]
+    pub key: Pubkey,
+    /// int: The permission bitmask.
+    #[pyo3(get)]
+    pub permissions: u8,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl Member {
+    #[new]
+    pub fn new(key: Pubkey, permissions: u8) -> Self {
+        Self { key, permissions }
+    }
+
+    /// bool: Whether the member can create transactions and proposals.
+    #[getter]
+    pub fn can_initiate(&self) -> bool {
+        self.permissions & PERMISSION_INITIATE != 0
+    }
+
+    /// bool: Whether the member can approve or reject proposals.
+    #[getter]
+    pub fn can_vote(&self) -> bool {
+        self.permissions & PERMISSION_VOTE != 0
+    }
+
+    /// bool: Whether the member can execute approved transactions.
+    #[getter]
+    pub fn can_execute(&self) -> bool {
+        self.permissions & PERMISSION_EXECUTE != 0
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for Member {}
+
+/// A Squads v4 multisig account.
+#[pyclass(module = "solders.squads", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Multisig {
+    /// Pubkey: The key the multisig address was derived from.
+    #[pyo3(get)]
+    pub create_key: Pubkey,
+    /// Pubkey: The authority that can change the configuration, or the default pubkey
+    /// if changes need a proposal.
+    #[pyo3(get)]
+    pub config_authority: Pubkey,
+    /// int: The approvals a proposal needs.
+    #[pyo3(get)]
+    pub threshold: u16,
+    /// int: Seconds between approval and execution.
+    #[pyo3(get)]
+    pub time_lock: u32,
+    /// int: The index of the latest transaction.
+    #[pyo3(get)]
+    pub transaction_index: u64,
+    /// int: Transactions up to this index can no longer be executed.
+    #[pyo3(get)]
+    pub stale_transaction_index: u64,
+    /// Optional[Pubkey]: Where rent of closed accounts goes.
+    #[pyo3(get)]
+    pub rent_collector: Option<Pubkey>,
+    /// List[Member]: The members.
+    #[pyo3(get)]
+    pub members: Vec<Member>,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl Multisig {
+    /// Parse a multisig account.
+    ///
+    /// Args:
+    ///     account (Account): The fetched account.
+    ///
+    /// Returns:
+    ///     Multisig: The multisig.
+    ///
+    /// Raises:
+    ///     ValueError: If the account isn't a Squads multisig.
+    ///
+    #[staticmethod]
+    pub fn from_account(account: &Account) -> PyResult<Self> {
+        let mut reader = Reader::new(account, "Multisig")?;
+        let create_key = reader.pubkey()?.into();
+        let config_authority = reader.pubkey()?.into();
+        let threshold = reader.u16()?;
+        let time_lock = reader.u32()?;
+        let transaction_index = reader.u64()?;
+        let stale_transaction_index = reader.u64()?;
+        let rent_collector = reader.option_pubkey()?.map(Pubkey::from);
+        let _bump = reader.u8()?;
+        let members = reader.vec(|r| Ok(Member::new(r.pubkey()?.into(), r.u8()?)))?;
+        Ok(Self {
+            create_key,
+            config_authority,
+            threshold,
+            time_lock,
+            transaction_index,
+            stale_transaction_index,
+            rent_collector,
+            members,
+        })
+    }
+
+    /// Find a member.
+    ///
+    /// Args:
+    ///     key (Pubkey): The member's address.
+    ///
+    /// Returns:
+    ///     Optional[Member]: The member, or ``None`` if ``key`` isn't one.
+    ///
+    pub fn member(&self, key: Pubkey) -> Option<Member> {
+        self.members.iter().find(|m| m.key == key).cloned()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for Multisig {}
+
+/// The status of a Squads proposal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[pyclass(module = "solders.squads")]
+pub enum ProposalStatus {
+    Draft,
+    Active,
+    Rejected,
+    Approved,
+    /// Deprecated by Squads, kept so old proposals parse.
+    Executing,
+    Executed,
+    Cancelled,
+}
+
+impl EnumString for ProposalStatus {}
+
+#[enum_string]
+#[pymethods]
+impl ProposalStatus {}
+
+/// A Squads v4 proposal account.
+#[pyclass(module = "solders.squads", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Proposal {
+    /// Pubkey: The multisig.
+    #[pyo3(get)]
+    pub multisig: Pubkey,
+    /// int: The index of the transaction being voted on.
+    #[pyo3(get)]
+    pub transaction_index: u64,
+    /// ProposalStatus: The status.
+    #[pyo3(get)]
+    pub status: ProposalStatus,
+    /// Optional[int]: When the status was set, as a unix timestamp.
+    #[pyo3(get)]
+    pub status_timestamp: Option<UnixTimestamp>,
+    /// List[Pubkey]: The members who approved.
+    #[pyo3(get)]
+    pub approved: Vec<Pubkey>,
+    /// List[Pubkey]: The members who rejected.
+    #[pyo3(get)]
+    pub rejected: Vec<Pubkey>,
+    /// List[Pubkey]: The members who voted to cancel after approval.
+    #[pyo3(get)]
+    pub cancelled: Vec<Pubkey>,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl Proposal {
+    /// Parse a proposal account.
+    ///
+    /// Args:
+    ///     account (Account): The fetched account.
+    ///
+    /// Returns:
+    ///     Proposal: The proposal.
+    ///
+    /// Raises:
+    ///     ValueError: If the account isn't a Squads proposal.
+    ///
+    #[staticmethod]
+    pub fn from_account(account: &Account) -> PyResult<Self> {
+        let mut reader = Reader::new(account, "Proposal")?;
+        let multisig = reader.pubkey()?.into();
+        let transaction_index = reader.u64()?;
+        let status = match reader.u8()? {
+            0 => ProposalStatus::Draft,
+            1 => ProposalStatus::Active,
+            2 => ProposalStatus::Rejected,
+            3 => ProposalStatus::Approved,
+            4 => ProposalStatus::Executing,
+            5 => ProposalStatus::Executed,
+            6 => ProposalStatus::Cancelled,
+            other => {
+                return Err(to_py_value_err(&format!(
+                    "unknown proposal status {}",
+                    other
+                )))
+            }
+        };
+        let status_timestamp = match status {
+            ProposalStatus::Executing => None,
+            _ => Some(reader.i64()?),
+        };
+        let _bump = reader.u8()?;
+        let mut keys = || reader.vec(|r| r.pubkey().map(Pubkey::from));
+        let approved = keys()?;
+        let rejected = keys()?;
+        let cancelled = keys()?;
+        Ok(Self {
+            multisig,
+            transaction_index,
+            status,
+            status_timestamp,
+            approved,
+            rejected,
+            cancelled,
+        })
+    }
+
+    /// Whether the proposal has enough approvals to execute.
+    ///
+    /// Args:
+    ///     multisig (Multisig): The multisig, for its threshold.
+    ///
+    /// Returns:
+    ///     bool: ``True`` if approved and not yet executed or cancelled.
+    ///
+    pub fn is_executable(&self, multisig: &Multisig) -> bool {
+        self.status == ProposalStatus::Approved
+            && self.approved.len() >= usize::from(multisig.threshold)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for Proposal {}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct MessageAddressTableLookup {
+    account_key: PubkeyOriginal,
+    writable_indexes: Vec<u8>,
+    readonly_indexes: Vec<u8>,
+}
+
+/// A Squads v4 vault transaction account: the transaction a proposal executes.
+#[pyclass(module = "solders.squads", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VaultTransaction {
+    /// Pubkey: The multisig.
+    #[pyo3(get)]
+    pub multisig: Pubkey,
+    /// Pubkey: The member who created it.
+    #[pyo3(get)]
+    pub creator: Pubkey,
+    /// int: The transaction index.
+    #[pyo3(get)]
+    pub index: u64,
+    /// int: The index of the vault that signs it.
+    #[pyo3(get)]
+    pub vault_index: u8,
+    num_signers: u8,
+    num_writable_signers: u8,
+    num_writable_non_signers: u8,
+    account_keys: Vec<PubkeyOriginal>,
+    address_table_lookups: Vec<MessageAddressTableLookup>,
+}
+
+impl VaultTransaction {
+    fn is_static_writable(&self, index: usize) -> bool {
+        let num_signers = usize::from(self.num_signers);
+        if index < num_signers {
+            index < usize::from(self.num_writable_signers)
+        } else {
+            index - num_signers < usize::from(self.num_writable_non_signers)
+        }
+    }
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl VaultTransaction {
+    /// Parse a vault transaction account.
+    ///
+    /// Args:
+    ///     account (Account): The fetched account.
+    ///
+    /// Returns:
+    ///     VaultTransaction: The vault transaction.
+    ///
+    /// Raises:
+    ///     ValueError: If the account isn't a Squads vault transaction.
+    ///
+    #[staticmethod]
+    pub fn from_account(account: &Account) -> PyResult<Self> {
+        let mut reader = Reader::new(account, "VaultTransaction")?;
+        let multisig = reader.pubkey()?.into();
+        let creator = reader.pubkey()?.into();
+        let index = reader.u64()?;
+        let _bump = reader.u8()?;
+        let vault_index = reader.u8()?;
+        let _vault_bump = reader.u8()?;
+        let _ephemeral_signer_bumps = reader.bytes()?;
+        let num_signers = reader.u8()?;
+        let num_writable_signers = reader.u8()?;
+        let num_writable_non_signers = reader.u8()?;
+        let account_keys = reader.vec(Reader::pubkey)?;
+        let _instructions = reader.vec(|r| {
+            r.u8()?;
+            r.bytes()?;
+            r.bytes()
+        })?;
+        let address_table_lookups = reader.vec(|r| {
+            Ok(MessageAddressTableLookup {
+                account_key: r.pubkey()?,
+                writable_indexes: r.bytes()?,
+                readonly_indexes: r.bytes()?,
+            })
+        })?;
+        Ok(Self {
+            multisig,
+            creator,
+            index,
+            vault_index,
+            num_signers,
+            num_writable_signers,
+            num_writable_non_signers,
+            account_keys,
+            address_table_lookups,
+        })
+    }
+
+    /// List[Pubkey]: The static account keys of the transaction message.
+    #[getter]
+    pub fn account_keys(&self) -> Vec<Pubkey> {
+        self.account_keys.iter().map(|k| (*k).into()).collect()
+    }
+
+    /// List[Pubkey]: The lookup tables the transaction message uses.
+    #[getter]
+    pub fn address_lookup_tables(&self) -> Vec<Pubkey> {
+        self.address_table_lookups
+            .iter()
+            .map(|l| l.account_key.into())
+            .collect()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for VaultTransaction {}
+
+fn find_address(seeds: &[&[u8]]) -> (Pubkey, u8) {
+    let (address, bump) = PubkeyOriginal::find_program_address(seeds, &SQUADS_PROGRAM_ID);
+    (address.into(), bump)
+}
+
+/// The address of the multisig created with ``create_key``.
+///
+/// Args:
+///     create_key (Pubkey): The key the multisig was created with.
+///
+/// Returns:
+///     Tuple[Pubkey, int]: The address and bump seed.
+///
+#[pyfunction]
+pub fn multisig_pda(create_key: Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_PREFIX, SEED_MULTISIG, create_key.as_ref()])
+}
+
+/// The address of a multisig's vault, which holds its funds and signs its transactions.
+///
+/// Args:
+///     multisig (Pubkey): The multisig address.
+///     vault_index (int): The vault index. Defaults to 0.
+///
+/// Returns:
+///     Tuple[Pubkey, int]: The address and bump seed.
+///
+#[pyfunction]
+#[pyo3(signature = (multisig, vault_index = 0))]
+pub fn vault_pda(multisig: Pubkey, vault_index: u8) -> (Pubkey, u8) {
+    find_address(&[SEED_PREFIX, multisig.as_ref(), SEED_VAULT, &[vault_index]])
+}
+
+/// The address of a multisig's transaction account.
+///
+/// Args:
+///     multisig (Pubkey): The multisig address.
+///     transaction_index (int): The transaction index.
+///
+/// Returns:
+///     Tuple[Pubkey, int]: The address and bump seed.
+///
+#[pyfunction]
+pub fn transaction_pda(multisig: Pubkey, transaction_index: u64) -> (Pubkey, u8) {
+    find_address(&[
+        SEED_PREFIX,
+        multisig.as_ref(),
+        SEED_TRANSACTION,
+        &transaction_index.to_le_bytes(),
+    ])
+}
+
+/// The address of the proposal for a multisig transaction.
+///
+/// Args:
+///     multisig (Pubkey): The multisig address.
+///     transaction_index (int): The transaction index.
+///
+/// Returns:
+///     Tuple[Pubkey, int]: The address and bump seed.
+///
+#[pyfunction]
+pub fn proposal_pda(multisig: Pubkey, transaction_index: u64) -> (Pubkey, u8) {
+    find_address(&[
+        SEED_PREFIX,
+        multisig.as_ref(),
+        SEED_TRANSACTION,
+        &transaction_index.to_le_bytes(),
+        SEED_PROPOSAL,
+    ])
+}
+
+fn squads_instruction(name: &str, args: &[u8], accounts: Vec<AccountMetaOriginal>) -> Instruction {
+    let mut data = discriminator("global", name).to_vec();
+    data.extend_from_slice(args);
+    InstructionOriginal::new_with_bytes(SQUADS_PROGRAM_ID, &data, accounts).into()
+}
+
+/// Build a ``proposal_create`` instruction.
+///
+/// Args:
+///     multisig (Pubkey): The multisig address.
+///     transaction_index (int): The index of the transaction to propose.
+///     creator (Pubkey): The member creating the proposal.
+///     rent_payer (Optional[Pubkey]): Who pays for the proposal account. Defaults to ``creator``.
+///     draft (bool): Whether to create the proposal as a draft that can't be voted on yet.
+///
+/// Returns:
+///     Instruction: The instruction.
+///
+/// Example:
+///     >>> from solders.squads import proposal_create, proposal_pda
+///     >>> from solders.pubkey import Pubkey
+///     >>> multisig, creator = Pubkey.new_unique(), Pubkey.new_unique()
+///     >>> ix = proposal_create(multisig, 1, creator)
+///     >>> ix.accounts[1].pubkey == proposal_pda(multisig, 1)[0]
+///     True
+///
+#[pyfunction]
+#[pyo3(signature = (multisig, transaction_index, creator, rent_payer = None, draft = false))]
+pub fn proposal_create(
+    multisig: Pubkey,
+    transaction_index: u64,
+    creator: Pubkey,
+    rent_payer: Option<Pubkey>,
+    draft: bool,
+) -> Instruction {
+    let (proposal, _) = proposal_pda(multisig, transaction_index);
+    let mut args = transaction_index.to_le_bytes().to_vec();
+    args.push(u8::from(draft));
+    squads_instruction(
+        "proposal_create",
+        &args,
+        vec![
+            AccountMetaOriginal::new_readonly(multisig.into(), false),
+            AccountMetaOriginal::new(proposal.into(), false),
+            AccountMetaOriginal::new_readonly(creator.into(), true),
+            AccountMetaOriginal::new(rent_payer.unwrap_or(creator).into(), true),
+            AccountMetaOriginal::new_readonly(system_program::ID, false),
+        ],
+    )
+}
+
+fn vote_instruction(
+    name: &str,
+    multisig: Pubkey,
+    transaction_index: u64,
+    member: Pubkey,
+    memo: Option<&str>,
+) -> Instruction {
+    let args = match memo {
+        Some(memo) => {
+            let mut args = vec![1];
+            args.extend_from_slice(&(memo.len() as u32).to_le_bytes());
+            args.extend_from_slice(memo.as_bytes());
+            args
+        }
+        None => vec![0],
+    };
+    let (proposal, _) = proposal_pda(multisig, transaction_index);
+    squads_instruction(
+        name,
+        &args,
+        vec![
+            AccountMetaOriginal::new_readonly(multisig.into(), false),
+            AccountMetaOriginal::new(member.into(), true),
+            AccountMetaOriginal::new(proposal.into(), false),
+        ],
+    )
+}
+
+/// Build a ``proposal_approve`` instruction.
+///
+/// Args:
+///     multisig (Pubkey): The multisig address.
+///     transaction_index (int): The index of the proposed transaction.
+///     member (Pubkey): The member approving.
+///     memo (Optional[str]): A memo to log with the vote.
+///
+/// Returns:
+///     Instruction: The instruction.
+///
+#[pyfunction]
+#[pyo3(signature = (multisig, transaction_index, member, memo = None))]
+pub fn proposal_approve(
+    multisig: Pubkey,
+    transaction_index: u64,
+    member: Pubkey,
+    memo: Option<&str>,
+) -> Instruction {
+    vote_instruction(
+        "proposal_approve",
+        multisig,
+        transaction_index,
+        member,
+        memo,
+    )
+}
+
+/// Build a ``proposal_reject`` instruction.
+///
+/// Args:
+///     multisig (Pubkey): The multisig address.
+///     transaction_index (int): The index of the proposed transaction.
+///     member (Pubkey): The member rejecting.
+///     memo (Optional[str]): A memo to log with the vote.
+///
+/// Returns:
+///     Instruction: The instruction.
+///
+#[pyfunction]
+#[pyo3(signature = (multisig, transaction_index, member, memo = None))]
+pub fn proposal_reject(
+    multisig: Pubkey,
+    transaction_index: u64,
+    member: Pubkey,
+    memo: Option<&str>,
+) -> Instruction {
+    vote_instruction("proposal_reject", multisig, transaction_index, member, memo)
+}
+
+/// Build a ``vault_transaction_execute`` instruction.
+///
+/// The accounts the vault transaction uses are appended in the order the program
+/// expects: lookup tables, static account keys, then the writable and read-only
+/// accounts loaded from the lookup tables.
+///
+/// Args:
+///     transaction (VaultTransaction): The fetched vault transaction.
+///     member (Pubkey): The member executing it.
+///     address_lookup_table_accounts (Optional[Sequence[AddressLookupTableAccount]]):
+///         The lookup tables the transaction uses, if any.
+///
+/// Returns:
+///     Instruction: The instruction.
+///
+/// Raises:
+///     ValueError: If a lookup table the transaction uses is missing or too short.
+///
+#[pyfunction]
+#[pyo3(signature = (transaction, member, address_lookup_table_accounts = None))]
+pub fn vault_transaction_execute(
+    transaction: &VaultTransaction,
+    member: Pubkey,
+    address_lookup_table_accounts: Option<Vec<AddressLookupTableAccount>>,
+) -> PyResult<Instruction> {
+    let tables: Vec<AddressLookupTableAccountOriginal> = address_lookup_table_accounts
+        .unwrap_or_default()
+        .into_iter()
+        .map(Into::into)
+        .collect();
+    let (proposal, _) = proposal_pda(transaction.multisig, transaction.index);
+    let (transaction_address, _) = transaction_pda(transaction.multisig, transaction.index);
+    let mut accounts = vec![
+        AccountMetaOriginal::new_readonly(transaction.multisig.into(), false),
+        AccountMetaOriginal::new(proposal.into(), false),
+        AccountMetaOriginal::new_readonly(transaction_address.into(), false),
+        AccountMetaOriginal::new_readonly(member.into(), true),
+    ];
+    let mut writable = Vec::new();
+    let mut readonly = Vec::new();
+    for lookup in &transaction.address_table_lookups {
+        let table = tables
+            .iter()
+            .find(|t| t.key == lookup.account_key)
+            .ok_or_else(|| {
+                to_py_value_err(&format!("missing lookup table {}", lookup.account_key))
+            })?;
+        let load = |indexes: &[u8]| -> PyResult<Vec<PubkeyOriginal>> {
+            indexes
+                .iter()
+                .map(|i| {
+                    table
+                        .addresses
+                        .get(usize::from(*i))
+                        .copied()
+                        .ok_or_else(|| {
+                            to_py_value_err(&format!(
+                                "lookup table {} has no index {}",
+                                table.key, i
+                            ))
+                        })
+                })
+                .collect()
+        };
+        writable.extend(load(&lookup.writable_indexes)?);
+        readonly.extend(load(&lookup.readonly_indexes)?);
+        accounts.push(AccountMetaOriginal::new_readonly(table.key, false));
+    }
+    for (i, key) in transaction.account_keys.iter().enumerate() {
+        accounts.push(if transaction.is_static_writable(i) {
+            AccountMetaOriginal::new(*key, false)
+        } else {
+            AccountMetaOriginal::new_readonly(*key, false)
+        });
+    }
+    accounts.extend(
+        writable
+            .into_iter()
+            .map(|key| AccountMetaOriginal::new(key, false)),
+    );
+    accounts.extend(
+        readonly
+            .into_iter()
+            .map(|key| AccountMetaOriginal::new_readonly(key, false)),
+    );
+    Ok(squads_instruction(
+        "vault_transaction_execute",
+        &[],
+        accounts,
+    ))
+}
+
+pub(crate) fn create_squads_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "squads")?;
+    m.add("SQUADS_PROGRAM_ID", Pubkey::from(SQUADS_PROGRAM_ID))?;
+    m.add_class::<Member>()?;
+    m.add_class::<Multisig>()?;
+    m.add_class::<ProposalStatus>()?;
+    m.add_class::<Proposal>()?;
+    m.add_class::<VaultTransaction>()?;
+    let funcs = [
+        wrap_pyfunction!(multisig_pda, m)?,
+        wrap_pyfunction!(vault_pda, m)?,
+        wrap_pyfunction!(transaction_pda, m)?,
+        wrap_pyfunction!(proposal_pda, m)?,
+        wrap_pyfunction!(proposal_create, m)?,
+        wrap_pyfunction!(proposal_approve, m)?,
+        wrap_pyfunction!(proposal_reject, m)?,
+        wrap_pyfunction!(vault_transaction_execute, m)?,
+    ];
+    for func in funcs {
+        m.add_function(func)?;
+    }
+    Ok(m)
+}
@@ -0,0 +1,259 @@
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use solana_sdk::{
+    account::ReadableAccount,
+    clock::{Clock as ClockOriginal, Epoch, UnixTimestamp},
+    stake::{
+        self,
+        state::{Lockup, StakeState},
+    },
+    stake_history::StakeHistory,
+    sysvar,
+};
+use solders_macros::{enum_string, richcmp_eq_only};
+use solders_primitives::pubkey::Pubkey;
+use solders_traits::{to_py_value_err, EnumString, RichcmpEqualityOnly};
+
+use crate::{account::Account, clock::Clock};
+
+/// Where a stake account is in its lifecycle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[pyclass(module = "solders.stake")]
+pub enum StakeLifecycleState {
+    /// The account has no authorities set.
+    Uninitialized,
+    /// The account has authorities but has never been delegated.
+    Initialized,
+    /// Delegated stake is warming up.
+    Activating,
+    /// All delegated stake is effective.
+    Active,
+    /// Delegated stake is cooling down.
+    Deactivating,
+    /// The delegation has fully cooled down, or never became effective.
+    Inactive,
+}
+
+impl EnumString for StakeLifecycleState {}
+
+#[enum_string]
+#[pymethods]
+impl StakeLifecycleState {}
+
+/// The lifecycle state of a stake account at an epoch.
+///
+/// Args:
+///     state (StakeLifecycleState): The lifecycle state.
+///     voter (Optional[Pubkey]): The vote account the stake is delegated to.
+///     delegated_stake (int): The delegated lamports.
+///     effective (int): The delegated lamports earning rewards.
+///     activating (int): The delegated lamports still warming up.
+///     deactivating (int): The delegated lamports still cooling down.
+///     locked_until_epoch (Optional[int]): The epoch the lockup ends, if it is still in force.
+///     locked_until_timestamp (Optional[int]): The unix timestamp the lockup ends, if it is still in force.
+///
+#[pyclass(module = "solders.stake", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StakeLifecycle {
+    /// StakeLifecycleState: The lifecycle state.
+    #[pyo3(get)]
+    pub state: StakeLifecycleState,
+    /// Optional[Pubkey]: The vote account the stake is delegated to.
+    #[pyo3(get)]
+    pub voter: Option<Pubkey>,
+    /// int: The delegated lamports.
+    #[pyo3(get)]
+    pub delegated_stake: u64,
+    /// int: The delegated lamports earning rewards.
+    #[pyo3(get)]
+    pub effective: u64,
+    /// int: The delegated lamports still warming up.
+    #[pyo3(get)]
+    pub activating: u64,
+    /// int: The delegated lamports still cooling down.
+    #[pyo3(get)]
+    pub deactivating: u64,
+    /// Optional[int]: The epoch the lockup ends, if it is still in force.
+    #[pyo3(get)]
+    pub locked_until_epoch: Option<Epoch>,
+    /// Optional[int]: The unix timestamp the lockup ends, if it is still in force.
+    #[pyo3(get)]
+    pub locked_until_timestamp: Option<UnixTimestamp>,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl StakeLifecycle {
+    #[allow(clippy::too_many_arguments)]
+    #[new]
+    pub fn new(
+        state: StakeLifecycleState,
+        voter: Option<Pubkey>,
+        delegated_stake: u64,
+        effective: u64,
+        activating: u64,
+        deactivating: u64,
+        locked_until_epoch: Option<Epoch>,
+        locked_until_timestamp: Option<UnixTimestamp>,
+    ) -> Self {
+        Self {
+            state,
+            voter,
+            delegated_stake,
+            effective,
+            activating,
+            deactivating,
+            locked_until_epoch,
+            locked_until_timestamp,
+        }
+    }
+
+    /// float: How far the current transition has progressed, from 0.0 to 1.0.
+    ///
+    /// The effective fraction while activating, the deactivated fraction while
+    /// deactivating, 1.0 once active or inactive and 0.0 before delegation.
+    /// A transition of zero delegated stake has nothing left to move, so it is 1.0.
+    #[getter]
+    pub fn progress(&self) -> f64 {
+        let stake = self.delegated_stake as f64;
+        match self.state {
+            StakeLifecycleState::Activating | StakeLifecycleState::Deactivating
+                if self.delegated_stake == 0 =>
+            {
+                1.0
+            }
+            StakeLifecycleState::Activating => self.effective as f64 / stake,
+            StakeLifecycleState::Deactivating => 1.0 - self.effective as f64 / stake,
+            StakeLifecycleState::Active | StakeLifecycleState::Inactive => 1.0,
+            StakeLifecycleState::Uninitialized | StakeLifecycleState::Initialized => 0.0,
+        }
+    }
+
+    /// bool: Whether a lockup is still in force.
+    #[getter]
+    pub fn is_locked(&self) -> bool {
+        self.locked_until_epoch.is_some() || self.locked_until_timestamp.is_some()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for StakeLifecycle {}
+
+fn lockup_fields(lockup: &Lockup, clock: &ClockOriginal) -> (Option<Epoch>, Option<UnixTimestamp>) {
+    (
+        (lockup.epoch > clock.epoch).then_some(lockup.epoch),
+        (lockup.unix_timestamp > clock.unix_timestamp).then_some(lockup.unix_timestamp),
+    )
+}
+
+/// Classify a stake account's lifecycle state at the clock's epoch.
+///
+/// Args:
+///     stake_account (Account): The fetched stake account.
+///     clock (Clock): The current clock, e.g. from the Clock sysvar.
+///     stake_history (Optional[Account]): The fetched StakeHistory sysvar account.
+///         Without it, delegations are treated as having warmed up and cooled down instantly.
+///
+/// Returns:
+///     StakeLifecycle: The lifecycle state.
+///
+/// Raises:
+///     ValueError: If either account isn't what it should be.
+///
+/// Example:
+///     >>> from solders.stake import stake_lifecycle, StakeLifecycleState
+///     >>> from solders.account import Account
+///     >>> from solders.clock import Clock
+///     >>> from solders.pubkey import Pubkey
+///     >>> stake_program = Pubkey.from_string("Stake11111111111111111111111111111111111111")
+///     >>> lifecycle = stake_lifecycle(Account(1, bytes(200), stake_program), Clock.default())
+///     >>> lifecycle.state == StakeLifecycleState.Uninitialized
+///     True
+///
+#[pyfunction]
+#[pyo3(signature = (stake_account, clock, stake_history = None))]
+pub fn stake_lifecycle(
+    stake_account: &Account,
+    clock: Clock,
+    stake_history: Option<&Account>,
+) -> PyResult<StakeLifecycle> {
+    if *stake_account.owner() != stake::program::id() {
+        return Err(to_py_value_err(&format!(
+            "account is owned by {}, not the stake program",
+            stake_account.owner()
+        )));
+    }
+    let history = match stake_history {
+        Some(account) => {
+            if *account.owner() != sysvar::id() {
+                return Err(to_py_value_err(&"stake history account is not a sysvar"));
+            }
+            Some(
+                bincode::deserialize::<StakeHistory>(account.data())
+                    .map_err(|e| to_py_value_err(&e))?,
+            )
+        }
+        None => None,
+    };
+    let clock = ClockOriginal::from(clock);
+    let state: StakeState =
+        bincode::deserialize(stake_account.data()).map_err(|e| to_py_value_err(&e))?;
+    let inactive = |state, meta: Option<&stake::state::Meta>| {
+        let (locked_until_epoch, locked_until_timestamp) =
+            meta.map_or((None, None), |m| lockup_fields(&m.lockup, &clock));
+        StakeLifecycle::new(
+            state,
+            None,
+            0,
+            0,
+            0,
+            0,
+            locked_until_epoch,
+            locked_until_timestamp,
+        )
+    };
+    match state {
+        StakeState::Uninitialized => Ok(inactive(StakeLifecycleState::Uninitialized, None)),
+        StakeState::Initialized(meta) => {
+            Ok(inactive(StakeLifecycleState::Initialized, Some(&meta)))
+        }
+        StakeState::Stake(meta, stake) => {
+            let delegation = stake.delegation;
+            let status =
+                delegation.stake_activating_and_deactivating(clock.epoch, history.as_ref());
+            let state = if status.activating > 0 {
+                StakeLifecycleState::Activating
+            } else if status.deactivating > 0 {
+                StakeLifecycleState::Deactivating
+            } else if status.effective > 0 {
+                StakeLifecycleState::Active
+            } else {
+                StakeLifecycleState::Inactive
+            };
+            let (locked_until_epoch, locked_until_timestamp) = lockup_fields(&meta.lockup, &clock);
+            Ok(StakeLifecycle::new(
+                state,
+                Some(delegation.voter_pubkey.into()),
+                delegation.stake,
+                status.effective,
+                status.activating,
+                status.deactivating,
+                locked_until_epoch,
+                locked_until_timestamp,
+            ))
+        }
+        StakeState::RewardsPool => Err(to_py_value_err(&"account is a rewards pool")),
+    }
+}
+
+pub(crate) fn create_stake_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "stake")?;
+    m.add_class::<StakeLifecycleState>()?;
+    m.add_class::<StakeLifecycle>()?;
+    m.add_function(wrap_pyfunction!(stake_lifecycle, m)?)?;
+    Ok(m)
+}
@@ -1,28 +1,18 @@
-use pyo3::{
-    prelude::*,
-    types::{PyInt, PyTuple},
-    PyTypeInfo,
-};
+use pyo3::prelude::*;
 
 use solders_traits::{SanitizeError, TransactionError};
 
-use solders_primitives::transaction::{Legacy, Transaction, VersionedTransaction};
+use solders_primitives::transaction::{
+    Legacy, Transaction, TransactionVersion, VersionedTransaction,
+};
 
 pub(crate) fn create_transaction_mod(py: Python<'_>) -> PyResult<&PyModule> {
     let m = PyModule::new(py, "transaction")?;
     m.add_class::<Transaction>()?;
     m.add_class::<VersionedTransaction>()?;
     m.add_class::<Legacy>()?;
+    m.add_class::<TransactionVersion>()?;
     m.add("SanitizeError", py.get_type::<SanitizeError>())?;
     m.add("TransactionError", py.get_type::<TransactionError>())?;
-    let typing = py.import("typing")?;
-    let union = typing.getattr("Union")?;
-    m.add(
-        "TransactionVersion",
-        union.get_item(PyTuple::new(
-            py,
-            vec![Legacy::type_object(py), PyInt::type_object(py)],
-        ))?,
-    )?;
     Ok(m)
 }
@@ -1,9 +1,11 @@
 #![allow(clippy::too_many_arguments)]
 use derive_more::{From, Into};
+use dict_derive::IntoPyObject;
 extern crate base64;
 use pythonize::{depythonize, pythonize};
 use solders_primitives::{message::MessageHeader, pubkey::Pubkey, signature::Signature};
-use solders_traits::{handle_py_value_err, RichcmpEqualityOnly};
+use solders_traits::{handle_py_value_err, to_py_value_err, EnumString, RichcmpEqualityOnly};
+use std::collections::HashMap;
 use std::fmt::Display;
 use std::str::FromStr;
 
@@ -41,12 +43,23 @@ use pyo3::{
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use solana_sdk::{
-    clock::UnixTimestamp, instruction::InstructionError as InstructionErrorOriginal,
-    slot_history::Slot, transaction::TransactionError as TransactionErrorOriginal,
+    clock::UnixTimestamp,
+    instruction::InstructionError as InstructionErrorOriginal,
+    message::MessageHeader as MessageHeaderOriginal,
+    slot_history::Slot,
+    transaction::{
+        TransactionError as TransactionErrorOriginal,
+        VersionedTransaction as VersionedTransactionOriginal,
+    },
     transaction_context::TransactionReturnData as TransactionReturnDataOriginal,
+    vote,
+};
+use solders_macros::{
+    common_methods, enum_original_mapping, enum_string, richcmp_eq_only, EnumIntoPy,
+};
+use solders_primitives::transaction::{
+    TransactionVersion, TransactionVersionLike, VersionedTransaction,
 };
-use solders_macros::{common_methods, enum_original_mapping, richcmp_eq_only, EnumIntoPy};
-use solders_primitives::transaction::{TransactionVersion, VersionedTransaction};
 
 macro_rules! transaction_status_boilerplate {
     ($name:ident) => {
@@ -82,6 +95,12 @@ impl Default for UiTransactionEncoding {
     }
 }
 
+impl EnumString for UiTransactionEncoding {}
+
+#[enum_string]
+#[pymethods]
+impl UiTransactionEncoding {}
+
 /// Levels of transaction detail to return in RPC requests.
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -99,6 +118,12 @@ impl Default for TransactionDetails {
     }
 }
 
+impl EnumString for TransactionDetails {}
+
+#[enum_string]
+#[pymethods]
+impl TransactionDetails {}
+
 #[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[serde(rename_all = "camelCase")]
 #[enum_original_mapping(TransactionBinaryEncodingOriginal)]
@@ -108,6 +133,12 @@ pub enum TransactionBinaryEncoding {
     Base64,
 }
 
+impl EnumString for TransactionBinaryEncoding {}
+
+#[enum_string]
+#[pymethods]
+impl TransactionBinaryEncoding {}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, From, Into)]
 #[pyclass(module = "solders.transaction_status", subclass)]
 pub struct UiCompiledInstruction(UiCompiledInstructionOriginal);
@@ -738,7 +769,6 @@ impl UiTransactionTokenBalance {
 #[pyclass(module = "solders.transaction_status")]
 #[enum_original_mapping(RewardTypeOriginal)]
 #[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
-#[serde(rename_all = "camelCase")]
 pub enum RewardType {
     Fee,
     Rent,
@@ -746,6 +776,12 @@ pub enum RewardType {
     Voting,
 }
 
+impl EnumString for RewardType {}
+
+#[enum_string]
+#[pymethods]
+impl RewardType {}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, From, Into)]
 #[pyclass(module = "solders.transaction_status", subclass)]
 pub struct TransactionReturnData(TransactionReturnDataOriginal);
@@ -891,14 +927,15 @@ impl EncodedTransactionWithStatusMeta {
     pub fn new(
         transaction: EncodedVersionedTransaction,
         meta: Option<UiTransactionStatusMeta>,
-        version: Option<TransactionVersion>,
-    ) -> Self {
-        EncodedTransactionWithStatusMetaOriginal {
+        version: Option<TransactionVersionLike>,
+    ) -> PyResult<Self> {
+        let version = version.map(TransactionVersion::try_from).transpose()?;
+        Ok(EncodedTransactionWithStatusMetaOriginal {
             transaction: transaction.into(),
             meta: meta.map(|m| m.into()),
             version: version.map(|v| v.into()),
         }
-        .into()
+        .into())
     }
 
     #[getter]
@@ -915,6 +952,321 @@ impl EncodedTransactionWithStatusMeta {
     pub fn version(&self) -> Option<TransactionVersion> {
         self.0.version.clone().map(|v| v.into())
     }
+
+    /// Whether this is a vote transaction, i.e. every instruction it invokes targets the Vote program.
+    ///
+    /// Returns:
+    ///     bool: ``True`` for vote transactions.
+    ///
+    pub fn is_vote(&self) -> bool {
+        let program_ids = self.program_ids();
+        !program_ids.is_empty() && program_ids.iter().all(|id| id.0 == vote::program::ID)
+    }
+}
+
+fn ui_instruction_program_id(
+    ix: &UiInstructionOriginal,
+    account_keys: &[Pubkey],
+) -> Option<Pubkey> {
+    match ix {
+        UiInstructionOriginal::Compiled(c) => {
+            account_keys.get(c.program_id_index as usize).copied()
+        }
+        UiInstructionOriginal::Parsed(UiParsedInstructionOriginal::Parsed(p)) => {
+            Pubkey::from_str(&p.program_id).ok()
+        }
+        UiInstructionOriginal::Parsed(UiParsedInstructionOriginal::PartiallyDecoded(p)) => {
+            Pubkey::from_str(&p.program_id).ok()
+        }
+    }
+}
+
+fn ui_instruction_data(
+    ix: &UiInstructionOriginal,
+    account_keys: &[Pubkey],
+) -> Option<(Pubkey, Option<Vec<u8>>)> {
+    let program_id = ui_instruction_program_id(ix, account_keys)?;
+    let data = match ix {
+        UiInstructionOriginal::Compiled(c) => bs58::decode(&c.data).into_vec().ok(),
+        UiInstructionOriginal::Parsed(UiParsedInstructionOriginal::Parsed(_)) => None,
+        UiInstructionOriginal::Parsed(UiParsedInstructionOriginal::PartiallyDecoded(p)) => {
+            bs58::decode(&p.data).into_vec().ok()
+        }
+    };
+    Some((program_id, data))
+}
+
+/// Whether the static account key at ``index`` is writable according to ``header``.
+pub(crate) fn is_writable_index(
+    header: &MessageHeaderOriginal,
+    num_keys: usize,
+    index: usize,
+) -> bool {
+    let num_signed = usize::from(header.num_required_signatures);
+    if index < num_signed {
+        index < num_signed.saturating_sub(usize::from(header.num_readonly_signed_accounts))
+    } else {
+        index < num_keys.saturating_sub(usize::from(header.num_readonly_unsigned_accounts))
+    }
+}
+
+impl EncodedTransactionWithStatusMeta {
+    /// The first signature of the transaction, if any.
+    pub(crate) fn first_signature(&self) -> Option<Signature> {
+        match self.transaction() {
+            EncodedVersionedTransaction::Binary(tx) => tx.signatures().first().copied(),
+            EncodedVersionedTransaction::Json(tx) => tx.signatures().first().copied(),
+        }
+    }
+
+    /// The number of signatures on the transaction.
+    pub(crate) fn num_signatures(&self) -> usize {
+        match self.transaction() {
+            EncodedVersionedTransaction::Binary(tx) => tx.signatures().len(),
+            EncodedVersionedTransaction::Json(tx) => tx.signatures().len(),
+        }
+    }
+
+    /// All account keys used by the transaction, including those loaded from lookup tables.
+    pub(crate) fn account_keys(&self) -> Vec<Pubkey> {
+        let mut keys: Vec<Pubkey> = match self.transaction() {
+            EncodedVersionedTransaction::Binary(tx) => VersionedTransactionOriginal::from(tx)
+                .message
+                .static_account_keys()
+                .iter()
+                .map(|k| Pubkey(*k))
+                .collect(),
+            EncodedVersionedTransaction::Json(tx) => match tx.0.message {
+                UiMessageOriginal::Raw(msg) => msg
+                    .account_keys
+                    .iter()
+                    .filter_map(|k| Pubkey::from_str(k).ok())
+                    .collect(),
+                // parsed messages already include the loaded addresses
+                UiMessageOriginal::Parsed(msg) => {
+                    return msg
+                        .account_keys
+                        .iter()
+                        .filter_map(|k| Pubkey::from_str(&k.pubkey).ok())
+                        .collect()
+                }
+            },
+        };
+        if let Some(loaded) = self
+            .0
+            .meta
+            .as_ref()
+            .and_then(|m| m.loaded_addresses.as_ref())
+        {
+            keys.extend(
+                loaded
+                    .writable
+                    .iter()
+                    .chain(loaded.readonly.iter())
+                    .filter_map(|k| Pubkey::from_str(k).ok()),
+            );
+        }
+        keys
+    }
+
+    /// The account keys the transaction write-locks, including those loaded from lookup tables.
+    pub(crate) fn writable_account_keys(&self) -> Vec<Pubkey> {
+        let static_keys = |header: &MessageHeaderOriginal, keys: Vec<Pubkey>| {
+            let num_keys = keys.len();
+            keys.into_iter()
+                .enumerate()
+                .filter(|(i, _)| is_writable_index(header, num_keys, *i))
+                .map(|(_, k)| k)
+                .collect::<Vec<Pubkey>>()
+        };
+        let mut keys = match self.transaction() {
+            EncodedVersionedTransaction::Binary(tx) => {
+                let message = VersionedTransactionOriginal::from(tx).message;
+                let keys = message
+                    .static_account_keys()
+                    .iter()
+                    .map(|k| Pubkey(*k))
+                    .collect();
+                static_keys(message.header(), keys)
+            }
+            EncodedVersionedTransaction::Json(tx) => match tx.0.message {
+                UiMessageOriginal::Raw(msg) => {
+                    let keys = msg
+                        .account_keys
+                        .iter()
+                        .filter_map(|k| Pubkey::from_str(k).ok())
+                        .collect();
+                    static_keys(&msg.header, keys)
+                }
+                // parsed messages already include the loaded addresses
+                UiMessageOriginal::Parsed(msg) => {
+                    return msg
+                        .account_keys
+                        .iter()
+                        .filter(|k| k.writable)
+                        .filter_map(|k| Pubkey::from_str(&k.pubkey).ok())
+                        .collect()
+                }
+            },
+        };
+        if let Some(loaded) = self
+            .0
+            .meta
+            .as_ref()
+            .and_then(|m| m.loaded_addresses.as_ref())
+        {
+            keys.extend(
+                loaded
+                    .writable
+                    .iter()
+                    .filter_map(|k| Pubkey::from_str(k).ok()),
+            );
+        }
+        keys
+    }
+
+    /// The account keys that signed the transaction.
+    pub(crate) fn signer_keys(&self) -> Vec<Pubkey> {
+        let signers = |num_required_signatures: u8, keys: Vec<Pubkey>| {
+            keys.into_iter()
+                .take(usize::from(num_required_signatures))
+                .collect::<Vec<Pubkey>>()
+        };
+        match self.transaction() {
+            EncodedVersionedTransaction::Binary(tx) => {
+                let message = VersionedTransactionOriginal::from(tx).message;
+                let keys = message
+                    .static_account_keys()
+                    .iter()
+                    .map(|k| Pubkey(*k))
+                    .collect();
+                signers(message.header().num_required_signatures, keys)
+            }
+            EncodedVersionedTransaction::Json(tx) => match tx.0.message {
+                UiMessageOriginal::Raw(msg) => {
+                    let keys = msg
+                        .account_keys
+                        .iter()
+                        .filter_map(|k| Pubkey::from_str(k).ok())
+                        .collect();
+                    signers(msg.header.num_required_signatures, keys)
+                }
+                UiMessageOriginal::Parsed(msg) => msg
+                    .account_keys
+                    .iter()
+                    .filter(|k| k.signer)
+                    .filter_map(|k| Pubkey::from_str(&k.pubkey).ok())
+                    .collect(),
+            },
+        }
+    }
+
+    /// Program IDs of the outer and of the inner instructions, one per instruction.
+    pub(crate) fn instruction_program_ids(&self) -> (Vec<Pubkey>, Vec<Pubkey>) {
+        let keys = self.account_keys();
+        let outer: Vec<Pubkey> = match self.transaction() {
+            EncodedVersionedTransaction::Binary(tx) => VersionedTransactionOriginal::from(tx)
+                .message
+                .instructions()
+                .iter()
+                .filter_map(|ix| keys.get(ix.program_id_index as usize).copied())
+                .collect(),
+            EncodedVersionedTransaction::Json(tx) => match tx.0.message {
+                UiMessageOriginal::Raw(msg) => msg
+                    .instructions
+                    .iter()
+                    .filter_map(|ix| keys.get(ix.program_id_index as usize).copied())
+                    .collect(),
+                UiMessageOriginal::Parsed(msg) => msg
+                    .instructions
+                    .iter()
+                    .filter_map(|ix| ui_instruction_program_id(ix, &keys))
+                    .collect(),
+            },
+        };
+        let inner = self
+            .0
+            .meta
+            .as_ref()
+            .and_then(|m| m.inner_instructions.as_ref())
+            .map_or_else(Vec::new, |inner| {
+                inner
+                    .iter()
+                    .flat_map(|i| i.instructions.iter())
+                    .filter_map(|ix| ui_instruction_program_id(ix, &keys))
+                    .collect()
+            });
+        (outer, inner)
+    }
+
+    /// Program ID and raw data of every instruction, outer then inner.
+    /// The data is ``None`` for instructions the RPC returned fully parsed.
+    pub(crate) fn instruction_data(&self) -> Vec<(Pubkey, Option<Vec<u8>>)> {
+        let keys = self.account_keys();
+        let mut instructions: Vec<(Pubkey, Option<Vec<u8>>)> = match self.transaction() {
+            EncodedVersionedTransaction::Binary(tx) => VersionedTransactionOriginal::from(tx)
+                .message
+                .instructions()
+                .iter()
+                .filter_map(|ix| {
+                    let program_id = keys.get(ix.program_id_index as usize).copied()?;
+                    Some((program_id, Some(ix.data.clone())))
+                })
+                .collect(),
+            EncodedVersionedTransaction::Json(tx) => match tx.0.message {
+                UiMessageOriginal::Raw(msg) => msg
+                    .instructions
+                    .into_iter()
+                    .filter_map(|ix| {
+                        ui_instruction_data(&UiInstructionOriginal::Compiled(ix), &keys)
+                    })
+                    .collect(),
+                UiMessageOriginal::Parsed(msg) => msg
+                    .instructions
+                    .iter()
+                    .filter_map(|ix| ui_instruction_data(ix, &keys))
+                    .collect(),
+            },
+        };
+        if let Some(inner) = self
+            .0
+            .meta
+            .as_ref()
+            .and_then(|m| m.inner_instructions.as_ref())
+        {
+            instructions.extend(
+                inner
+                    .iter()
+                    .flat_map(|i| i.instructions.iter())
+                    .filter_map(|ix| ui_instruction_data(ix, &keys)),
+            );
+        }
+        instructions
+    }
+
+    /// Program IDs invoked by the transaction, including inner instructions,
+    /// in order of first appearance.
+    pub(crate) fn program_ids(&self) -> Vec<Pubkey> {
+        let (outer, inner) = self.instruction_program_ids();
+        let mut ids = Vec::with_capacity(outer.len() + inner.len());
+        for id in outer.into_iter().chain(inner) {
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+        ids
+    }
+
+    /// Per-account lamport changes (post balance minus pre balance).
+    pub(crate) fn balance_deltas(&self) -> Vec<i64> {
+        self.0.meta.as_ref().map_or_else(Vec::new, |m| {
+            m.pre_balances
+                .iter()
+                .zip(m.post_balances.iter())
+                .map(|(pre, post)| *post as i64 - *pre as i64)
+                .collect()
+        })
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, From, Into)]
@@ -1643,6 +1995,12 @@ pub enum TransactionConfirmationStatus {
     Finalized,
 }
 
+impl EnumString for TransactionConfirmationStatus {}
+
+#[enum_string]
+#[pymethods]
+impl TransactionConfirmationStatus {}
+
 pub type Rewards = Vec<Reward>;
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, From, Into)]
@@ -1748,6 +2106,567 @@ impl EncodedConfirmedTransactionWithStatusMeta {
     }
 }
 
+#[derive(IntoPyObject, Default)]
+pub struct TransactionHistoryColumns {
+    slot: Vec<Slot>,
+    block_time: Vec<Option<UnixTimestamp>>,
+    signature: Vec<Option<Signature>>,
+    fee: Vec<Option<u64>>,
+    success: Vec<Option<bool>>,
+    err: Vec<Option<TransactionErrorType>>,
+    program_ids: Vec<Vec<Pubkey>>,
+    balance_deltas: Vec<Vec<i64>>,
+}
+
+/// Flatten confirmed transactions into columns.
+///
+/// The result is a dict of equal-length lists that can be passed straight to
+/// ``pandas.DataFrame`` or ``pyarrow.table``. Columns that need the transaction
+/// meta (``fee``, ``success``, ``err``, ``balance_deltas``) are ``None``
+/// (or empty) when the meta is missing.
+///
+/// Args:
+///     transactions (Sequence[EncodedConfirmedTransactionWithStatusMeta]): The transactions to flatten.
+///
+/// Returns:
+///     dict: Columns ``slot``, ``block_time``, ``signature``, ``fee``, ``success``,
+///     ``err``, ``program_ids`` and ``balance_deltas``.
+///
+#[pyfunction]
+pub fn transactions_to_columns(
+    transactions: Vec<EncodedConfirmedTransactionWithStatusMeta>,
+) -> TransactionHistoryColumns {
+    let mut cols = TransactionHistoryColumns::default();
+    for confirmed in transactions {
+        let tx = confirmed.transaction();
+        let meta = tx.meta();
+        cols.slot.push(confirmed.slot());
+        cols.block_time.push(confirmed.block_time());
+        cols.signature.push(tx.first_signature());
+        cols.fee.push(meta.as_ref().map(|m| m.fee()));
+        cols.success.push(meta.as_ref().map(|m| m.0.err.is_none()));
+        cols.err.push(meta.and_then(|m| m.err()));
+        cols.program_ids.push(tx.program_ids());
+        cols.balance_deltas.push(tx.balance_deltas());
+    }
+    cols
+}
+
+/// Build an inverted index from account to the transactions that use it.
+///
+/// Every account key of a transaction counts, including program IDs and
+/// addresses loaded from lookup tables. Transactions without a signature
+/// are skipped.
+///
+/// Args:
+///     transactions (Sequence[EncodedTransactionWithStatusMeta]): The transactions to index,
+///         e.g. ``block.transactions``.
+///
+/// Returns:
+///     Dict[Pubkey, List[Signature]]: The signatures touching each account, in input order.
+///
+#[pyfunction]
+pub fn address_index(
+    transactions: Vec<EncodedTransactionWithStatusMeta>,
+) -> HashMap<Pubkey, Vec<Signature>> {
+    let mut index: HashMap<Pubkey, Vec<Signature>> = HashMap::new();
+    for tx in transactions {
+        let signature = match tx.first_signature() {
+            Some(signature) => signature,
+            None => continue,
+        };
+        for key in tx.account_keys() {
+            let signatures = index.entry(key).or_default();
+            if signatures.last() != Some(&signature) {
+                signatures.push(signature);
+            }
+        }
+    }
+    index
+}
+
+#[derive(IntoPyObject, Default)]
+pub struct BlockFeeSummary {
+    transaction_count: usize,
+    total_fees: u64,
+    base_fees: u64,
+    priority_fees: u64,
+    leader_rewards: HashMap<Pubkey, i64>,
+    burned_fees: Option<u64>,
+}
+
+/// Break down the fees and fee rewards of a block.
+///
+/// Base fees are ``lamports_per_signature`` times the signature count of each transaction;
+/// the rest of each fee is the priority fee. Leader rewards are the ``Fee`` rewards
+/// credited in the block, keyed by the leader's identity. ``burned_fees`` is the part of
+/// the fees not paid out as rewards, and is ``None`` if the block has no rewards.
+/// Transactions without a meta have no known fee and are left out, including from
+/// ``transaction_count``.
+///
+/// Args:
+///     block (UiConfirmedBlock): The block, fetched with ``transaction_details="full"``
+///         and with ``rewards=True`` for reward attribution.
+///     lamports_per_signature (int): The base fee per signature. Defaults to 5000.
+///
+/// Returns:
+///     dict: Keys ``transaction_count``, ``total_fees``, ``base_fees``, ``priority_fees``,
+///     ``leader_rewards`` and ``burned_fees``.
+///
+/// Raises:
+///     ValueError: If the block has no transactions list, e.g. because it was fetched
+///         with ``transaction_details="signatures"``.
+///
+#[pyfunction]
+#[pyo3(signature = (block, lamports_per_signature = 5000))]
+pub fn block_fee_summary(
+    block: &UiConfirmedBlock,
+    lamports_per_signature: u64,
+) -> PyResult<BlockFeeSummary> {
+    let transactions = block.transactions().ok_or_else(|| {
+        to_py_value_err(&"block has no transactions; fetch it with transaction_details=\"full\"")
+    })?;
+    let mut summary = BlockFeeSummary::default();
+    for tx in transactions {
+        let fee = match tx.meta() {
+            Some(meta) => meta.fee(),
+            None => continue,
+        };
+        summary.transaction_count += 1;
+        let base_fee = fee.min(lamports_per_signature.saturating_mul(tx.num_signatures() as u64));
+        summary.total_fees += fee;
+        summary.base_fees += base_fee;
+        summary.priority_fees += fee - base_fee;
+    }
+    if let Some(rewards) = &block.0.rewards {
+        let mut paid_out: i64 = 0;
+        for reward in rewards {
+            if reward.reward_type == Some(RewardTypeOriginal::Fee) {
+                if let Ok(pubkey) = Pubkey::from_str(&reward.pubkey) {
+                    *summary.leader_rewards.entry(pubkey).or_default() += reward.lamports;
+                }
+                paid_out += reward.lamports;
+            }
+        }
+        summary.burned_fees = Some(summary.total_fees.saturating_sub(paid_out.max(0) as u64));
+    }
+    Ok(summary)
+}
+
+/// The lamport balance sheet of a transaction, as returned by :func:`check_lamport_conservation`.
+#[pyclass(module = "solders.transaction_status", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LamportConservation {
+    /// int: The sum of the balances before the transaction.
+    #[pyo3(get)]
+    pub pre_total: u64,
+    /// int: The sum of the balances after the transaction.
+    #[pyo3(get)]
+    pub post_total: u64,
+    /// int: The fee, which leaves the transaction's accounts.
+    #[pyo3(get)]
+    pub fee: u64,
+    /// int: The net lamports of the rewards in the meta, such as rent. Positive if they credited the accounts.
+    #[pyo3(get)]
+    pub rewards_total: i64,
+    /// int: Lamports that appeared (positive) or disappeared (negative) without explanation.
+    #[pyo3(get)]
+    pub imbalance: i64,
+    /// List[str]: A description of each problem found.
+    #[pyo3(get)]
+    pub anomalies: Vec<String>,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl LamportConservation {
+    #[new]
+    pub fn new(
+        pre_total: u64,
+        post_total: u64,
+        fee: u64,
+        rewards_total: i64,
+        imbalance: i64,
+        anomalies: Vec<String>,
+    ) -> Self {
+        Self {
+            pre_total,
+            post_total,
+            fee,
+            rewards_total,
+            imbalance,
+            anomalies,
+        }
+    }
+
+    /// bool: Whether no anomalies were found.
+    #[getter]
+    pub fn is_conserved(&self) -> bool {
+        self.anomalies.is_empty()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for LamportConservation {}
+
+/// Check that a transaction neither created nor destroyed lamports.
+///
+/// The balances after the transaction must add up to the balances before it, minus
+/// the fee, plus the rewards listed in the meta (such as rent). Anything else is
+/// reported as an anomaly, which points to a node or parsing bug, or to a meta that
+/// was modified after the fact. Also reported are balance lists of different lengths,
+/// a fee payer that couldn't afford the fee, and a failed transaction whose fee payer
+/// paid less than the fee. A successful transaction can credit its fee payer, so its
+/// payer balance isn't checked.
+///
+/// Args:
+///     meta (UiTransactionStatusMeta): The transaction meta.
+///
+/// Returns:
+///     LamportConservation: The balance sheet and any anomalies.
+///
+/// Raises:
+///     ValueError: If a total or the imbalance doesn't fit in 64 bits, which only malformed metas cause.
+///
+/// Example:
+///     >>> from solders.transaction_status import UiTransactionStatusMeta, check_lamport_conservation
+///     >>> meta = UiTransactionStatusMeta(None, 5000, [100_000, 0], [45_000, 50_000], None, None, None, None, None, None, None)
+///     >>> check_lamport_conservation(meta).is_conserved
+///     True
+///     >>> meta = UiTransactionStatusMeta(None, 5000, [100_000, 0], [45_000, 60_000], None, None, None, None, None, None, None)
+///     >>> check_lamport_conservation(meta).anomalies
+///     ['balances gained 10000 lamports more than the fee and rewards explain']
+///
+#[pyfunction]
+pub fn check_lamport_conservation(meta: &UiTransactionStatusMeta) -> PyResult<LamportConservation> {
+    let meta = &meta.0;
+    let mut anomalies = Vec::new();
+    let (pre, post) = (&meta.pre_balances, &meta.post_balances);
+    if pre.len() != post.len() {
+        anomalies.push(format!(
+            "{} pre balances but {} post balances",
+            pre.len(),
+            post.len()
+        ));
+    }
+    let overflow = |what: &str| to_py_value_err(&format!("{what} overflows"));
+    let pre_total = pre
+        .iter()
+        .try_fold(0u64, |total, b| total.checked_add(*b))
+        .ok_or_else(|| overflow("sum of pre balances"))?;
+    let post_total = post
+        .iter()
+        .try_fold(0u64, |total, b| total.checked_add(*b))
+        .ok_or_else(|| overflow("sum of post balances"))?;
+    let rewards_total = meta
+        .rewards
+        .iter()
+        .flatten()
+        .try_fold(0i64, |total, reward| total.checked_add(reward.lamports))
+        .ok_or_else(|| overflow("sum of rewards"))?;
+    let expected = i128::from(pre_total) - i128::from(meta.fee) + i128::from(rewards_total);
+    let imbalance =
+        i64::try_from(i128::from(post_total) - expected).map_err(|_| overflow("imbalance"))?;
+    match imbalance.cmp(&0) {
+        std::cmp::Ordering::Greater => anomalies.push(format!(
+            "balances gained {} lamports more than the fee and rewards explain",
+            imbalance
+        )),
+        std::cmp::Ordering::Less => anomalies.push(format!(
+            "balances lost {} lamports more than the fee and rewards explain",
+            -imbalance
+        )),
+        std::cmp::Ordering::Equal => {}
+    }
+    match (pre.first(), post.first()) {
+        (Some(pre_payer), _) if *pre_payer < meta.fee => anomalies.push(format!(
+            "fee payer had {} lamports, less than the fee of {}",
+            pre_payer, meta.fee
+        )),
+        (Some(pre_payer), Some(post_payer))
+            if meta.err.is_some() && pre_payer.saturating_sub(*post_payer) < meta.fee =>
+        {
+            anomalies.push(format!(
+                "failed transaction's fee payer paid {} lamports, less than the fee of {}",
+                i128::from(*pre_payer) - i128::from(*post_payer),
+                meta.fee
+            ))
+        }
+        (None, _) if meta.fee > 0 => {
+            anomalies.push("fee was charged but there are no balances".to_string())
+        }
+        _ => {}
+    }
+    Ok(LamportConservation {
+        pre_total,
+        post_total,
+        fee: meta.fee,
+        rewards_total,
+        imbalance,
+        anomalies,
+    })
+}
+
+/// Compute units consumed by each top-level instruction, read from the program logs.
+///
+/// The units of an instruction include those of the CPIs it makes.
+fn top_level_compute_units(logs: &[String]) -> Vec<(Pubkey, u64)> {
+    let mut depth = 0usize;
+    let mut units = Vec::new();
+    for line in logs {
+        let rest = match line.strip_prefix("Program ") {
+            Some(rest) => rest,
+            None => continue,
+        };
+        let (program_id, event) = match rest.split_once(' ') {
+            Some(parts) => parts,
+            None => continue,
+        };
+        if event.starts_with("invoke [") {
+            depth += 1;
+        } else if event == "success" || event.starts_with("failed") {
+            depth = depth.saturating_sub(1);
+        } else if let Some(consumed) = event.strip_prefix("consumed ") {
+            if depth != 1 {
+                continue;
+            }
+            let parsed = consumed
+                .split_once(' ')
+                .and_then(|(n, _)| n.parse().ok())
+                .zip(Pubkey::from_str(program_id).ok());
+            if let Some((n, program_id)) = parsed {
+                units.push((program_id, n));
+            }
+        }
+    }
+    units
+}
+
+/// Usage of one program, as aggregated by :class:`ProgramUsageStats`.
+#[pyclass(module = "solders.transaction_status", subclass)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProgramUsage {
+    /// int: Top-level instructions invoking the program.
+    #[pyo3(get)]
+    pub invocations: u64,
+    /// int: Inner instructions (CPIs) invoking the program.
+    #[pyo3(get)]
+    pub inner_invocations: u64,
+    /// int: Transactions invoking the program at any level.
+    #[pyo3(get)]
+    pub transactions: u64,
+    /// int: How many of those transactions failed.
+    #[pyo3(get)]
+    pub failed_transactions: u64,
+    /// int: Compute units consumed by the program's top-level instructions, including their CPIs.
+    #[pyo3(get)]
+    pub compute_units: u64,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl ProgramUsage {
+    #[new]
+    pub fn new(
+        invocations: u64,
+        inner_invocations: u64,
+        transactions: u64,
+        failed_transactions: u64,
+        compute_units: u64,
+    ) -> Self {
+        Self {
+            invocations,
+            inner_invocations,
+            transactions,
+            failed_transactions,
+            compute_units,
+        }
+    }
+
+    /// float: The share of transactions invoking the program that failed.
+    #[getter]
+    pub fn failure_rate(&self) -> f64 {
+        if self.transactions == 0 {
+            0.0
+        } else {
+            self.failed_transactions as f64 / self.transactions as f64
+        }
+    }
+
+    /// Optional[int]: The mean compute units per top-level instruction,
+    /// or ``None`` if there were none.
+    #[getter]
+    pub fn mean_compute_units(&self) -> Option<u64> {
+        self.compute_units.checked_div(self.invocations)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for ProgramUsage {}
+
+/// Aggregates per-program invocation counts, compute units and failure rates over blocks.
+///
+/// Blocks are aggregated on the Rust side, so the transactions never become Python
+/// objects. Blocks must be fetched with ``transaction_details="full"``. Inner
+/// invocations need the inner instructions in the meta, and compute units need the
+/// log messages; without them those counts stay at zero.
+///
+/// Args:
+///     skip_votes (bool): Ignore vote transactions. Defaults to ``False``.
+///
+#[pyclass(module = "solders.transaction_status", subclass)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProgramUsageStats {
+    skip_votes: bool,
+    blocks: u64,
+    transactions: u64,
+    programs: HashMap<Pubkey, ProgramUsage>,
+}
+
+impl ProgramUsageStats {
+    fn add_transaction(&mut self, tx: &EncodedTransactionWithStatusMeta) {
+        let meta = tx.meta();
+        let failed = meta.as_ref().map_or(false, |m| m.0.err.is_some());
+        let (outer, inner) = tx.instruction_program_ids();
+        for id in outer {
+            self.programs.entry(id).or_default().invocations += 1;
+        }
+        for id in inner {
+            self.programs.entry(id).or_default().inner_invocations += 1;
+        }
+        for id in tx.program_ids() {
+            let usage = self.programs.entry(id).or_default();
+            usage.transactions += 1;
+            if failed {
+                usage.failed_transactions += 1;
+            }
+        }
+        if let Some(logs) = meta.as_ref().and_then(|m| m.0.log_messages.as_ref()) {
+            for (id, units) in top_level_compute_units(logs) {
+                self.programs.entry(id).or_default().compute_units += units;
+            }
+        }
+        self.transactions += 1;
+    }
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl ProgramUsageStats {
+    #[new]
+    #[pyo3(signature = (skip_votes = false))]
+    pub fn new(skip_votes: bool) -> Self {
+        Self {
+            skip_votes,
+            ..Self::default()
+        }
+    }
+
+    /// int: The number of blocks added.
+    #[getter]
+    pub fn blocks(&self) -> u64 {
+        self.blocks
+    }
+
+    /// int: The number of transactions aggregated.
+    #[getter]
+    pub fn transactions(&self) -> u64 {
+        self.transactions
+    }
+
+    /// Aggregate a block.
+    ///
+    /// Args:
+    ///     block (UiConfirmedBlock): The block.
+    ///
+    pub fn add_block(&mut self, block: &UiConfirmedBlock) {
+        self.blocks += 1;
+        for tx in block.transactions().unwrap_or_default() {
+            if !(self.skip_votes && tx.is_vote()) {
+                self.add_transaction(&tx);
+            }
+        }
+    }
+
+    /// Aggregate several blocks. See :meth:`add_block`.
+    ///
+    /// Args:
+    ///     blocks (Sequence[UiConfirmedBlock]): The blocks.
+    ///
+    pub fn add_blocks(&mut self, blocks: Vec<UiConfirmedBlock>) {
+        for block in &blocks {
+            self.add_block(block);
+        }
+    }
+
+    /// Usage of one program.
+    ///
+    /// Args:
+    ///     program_id (Pubkey): The program.
+    ///
+    /// Returns:
+    ///     Optional[ProgramUsage]: Its usage, or ``None`` if no transaction invoked it.
+    ///
+    pub fn get(&self, program_id: Pubkey) -> Option<ProgramUsage> {
+        self.programs.get(&program_id).cloned()
+    }
+
+    /// Usage of every program seen.
+    ///
+    /// Returns:
+    ///     Dict[Pubkey, ProgramUsage]: The usage of each program.
+    ///
+    pub fn programs(&self) -> HashMap<Pubkey, ProgramUsage> {
+        self.programs.clone()
+    }
+
+    /// The most invoked programs.
+    ///
+    /// Programs are ranked by top-level plus inner invocations.
+    ///
+    /// Args:
+    ///     n (int): How many programs to return.
+    ///
+    /// Returns:
+    ///     List[Tuple[Pubkey, ProgramUsage]]: The programs, most invoked first.
+    ///
+    pub fn top(&self, n: usize) -> Vec<(Pubkey, ProgramUsage)> {
+        let mut programs: Vec<(Pubkey, ProgramUsage)> = self
+            .programs
+            .iter()
+            .map(|(id, usage)| (*id, usage.clone()))
+            .collect();
+        programs.sort_by(|(a_id, a), (b_id, b)| {
+            (b.invocations + b.inner_invocations)
+                .cmp(&(a.invocations + a.inner_invocations))
+                .then_with(|| a_id.cmp(b_id))
+        });
+        programs.truncate(n);
+        programs
+    }
+
+    fn __len__(&self) -> usize {
+        self.programs.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "ProgramUsageStats(blocks={}, transactions={}, programs={})",
+            self.blocks,
+            self.transactions,
+            self.programs.len()
+        )
+    }
+}
+
+impl RichcmpEqualityOnly for ProgramUsageStats {}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone, From, Into)]
 #[pyclass(module = "solders.transaction_status", subclass)]
 pub struct UiConfirmedBlock(UiConfirmedBlockOriginal);
@@ -1826,6 +2745,47 @@ impl UiConfirmedBlock {
     pub fn block_height(&self) -> Option<u64> {
         self.0.block_height
     }
+
+    /// Copy the block with its vote transactions removed.
+    ///
+    /// Filtering happens before any transaction is converted to a Python object,
+    /// so this is much cheaper than filtering ``transactions`` in Python.
+    /// To drop them while parsing a ``getBlock`` response instead, use
+    /// :func:`~solders.rpc.responses.parse_block` with ``skip_votes=True``.
+    ///
+    /// Returns:
+    ///     UiConfirmedBlock: The block without vote transactions.
+    ///
+    /// Example:
+    ///     >>> from solders.transaction_status import UiConfirmedBlock
+    ///     >>> from solders.hash import Hash
+    ///     >>> block = UiConfirmedBlock(Hash.default(), Hash.default(), 0, [], None, None, None, None)
+    ///     >>> block.without_votes().transactions
+    ///     []
+    ///
+    pub fn without_votes(&self) -> Self {
+        let mut block = self.clone();
+        block.retain_transactions(|tx| !tx.is_vote());
+        block
+    }
+}
+
+impl UiConfirmedBlock {
+    /// Keep only the transactions for which ``keep`` returns true.
+    pub(crate) fn retain_transactions(
+        &mut self,
+        keep: impl FnMut(&EncodedTransactionWithStatusMeta) -> bool,
+    ) {
+        if let Some(txs) = self.0.transactions.take() {
+            self.0.transactions = Some(
+                txs.into_iter()
+                    .map(EncodedTransactionWithStatusMeta::from)
+                    .filter(keep)
+                    .map(|tx| tx.into())
+                    .collect(),
+            );
+        }
+    }
 }
 
 pub fn create_transaction_status_mod(py: Python<'_>) -> PyResult<&PyModule> {
@@ -1860,6 +2820,13 @@ pub fn create_transaction_status_mod(py: Python<'_>) -> PyResult<&PyModule> {
     m.add_class::<TransactionStatus>()?;
     m.add_class::<EncodedConfirmedTransactionWithStatusMeta>()?;
     m.add_class::<UiConfirmedBlock>()?;
+    m.add_class::<ProgramUsage>()?;
+    m.add_class::<ProgramUsageStats>()?;
+    m.add_class::<LamportConservation>()?;
+    m.add_function(wrap_pyfunction!(transactions_to_columns, m)?)?;
+    m.add_function(wrap_pyfunction!(block_fee_summary, m)?)?;
+    m.add_function(wrap_pyfunction!(address_index, m)?)?;
+    m.add_function(wrap_pyfunction!(check_lamport_conservation, m)?)?;
     let typing = py.import("typing")?;
     let union = typing.getattr("Union")?;
     let ui_parsed_instruction_members = vec![
@@ -0,0 +1,328 @@
+use std::collections::HashMap;
+
+use pyo3::{exceptions::PyValueError, prelude::*};
+use serde::{Deserialize, Serialize};
+use solana_sdk::{
+    pubkey::Pubkey as PubkeyOriginal, system_instruction::SystemInstruction, system_program,
+    transaction::VersionedTransaction as VersionedTransactionOriginal,
+};
+use solders_macros::{enum_string, richcmp_eq_only};
+use solders_primitives::{pubkey::Pubkey, transaction::VersionedTransaction};
+use solders_traits::{EnumString, RichcmpEqualityOnly};
+
+use crate::spl_errors::{TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID};
+
+const TOKEN_TRANSFER: u8 = 3;
+const TOKEN_APPROVE: u8 = 4;
+const TOKEN_SET_AUTHORITY: u8 = 6;
+const TOKEN_CLOSE_ACCOUNT: u8 = 9;
+const TOKEN_TRANSFER_CHECKED: u8 = 12;
+const TOKEN_APPROVE_CHECKED: u8 = 13;
+
+/// What a :class:`Transfer` hands over.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[pyclass(module = "solders.transfers")]
+pub enum TransferKind {
+    /// Lamports or tokens move from ``source`` to ``destination``.
+    Transfer,
+    /// ``destination`` becomes a delegate allowed to spend ``amount`` from ``source``.
+    Approve,
+    /// ``source`` is closed and its remaining lamports go to ``destination``.
+    CloseAccount,
+    /// ``destination`` becomes an authority of ``source``.
+    SetAuthority,
+}
+
+impl EnumString for TransferKind {}
+
+#[enum_string]
+#[pymethods]
+impl TransferKind {}
+
+/// A movement of SOL or tokens, or of control over them, found in a transaction's instructions.
+///
+/// Args:
+///     instruction_index (int): The index of the top-level instruction.
+///     program_id (Pubkey): The System, Token or Token-2022 program.
+///     source (Pubkey): The account debited.
+///     destination (Pubkey): The account credited.
+///     authority (Pubkey): The signer authorizing the transfer.
+///     amount (int): Lamports for SOL transfers, raw token units otherwise.
+///         ``0`` for ``CloseAccount`` and ``SetAuthority``, whose value is only known on chain.
+///     mint (Optional[Pubkey]): The token mint, if known. ``None`` for SOL transfers.
+///     decimals (Optional[int]): The mint decimals, if given by the instruction.
+///     kind (TransferKind): What is handed over. Defaults to ``TransferKind.Transfer``.
+///
+#[pyclass(module = "solders.transfers", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Transfer {
+    /// int: The index of the top-level instruction.
+    #[pyo3(get)]
+    pub instruction_index: usize,
+    /// Pubkey: The System, Token or Token-2022 program.
+    #[pyo3(get)]
+    pub program_id: Pubkey,
+    /// Pubkey: The account debited.
+    #[pyo3(get)]
+    pub source: Pubkey,
+    /// Pubkey: The account credited, delegate or new authority.
+    /// ``Pubkey.default()`` when ``SetAuthority`` removes the authority.
+    #[pyo3(get)]
+    pub destination: Pubkey,
+    /// Pubkey: The signer authorizing the transfer.
+    #[pyo3(get)]
+    pub authority: Pubkey,
+    /// int: Lamports for SOL transfers, raw token units otherwise.
+    #[pyo3(get)]
+    pub amount: u64,
+    /// Optional[Pubkey]: The token mint, if known. ``None`` for SOL transfers.
+    #[pyo3(get)]
+    pub mint: Option<Pubkey>,
+    /// Optional[int]: The mint decimals, if given by the instruction.
+    #[pyo3(get)]
+    pub decimals: Option<u8>,
+    /// TransferKind: What is handed over.
+    #[pyo3(get)]
+    pub kind: TransferKind,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl Transfer {
+    #[allow(clippy::too_many_arguments)]
+    #[new]
+    #[pyo3(signature = (instruction_index, program_id, source, destination, authority, amount, mint, decimals, kind = TransferKind::Transfer))]
+    pub fn new(
+        instruction_index: usize,
+        program_id: Pubkey,
+        source: Pubkey,
+        destination: Pubkey,
+        authority: Pubkey,
+        amount: u64,
+        mint: Option<Pubkey>,
+        decimals: Option<u8>,
+        kind: TransferKind,
+    ) -> Self {
+        Self {
+            instruction_index,
+            program_id,
+            source,
+            destination,
+            authority,
+            amount,
+            mint,
+            decimals,
+            kind,
+        }
+    }
+
+    /// bool: Whether this moves SOL rather than a token.
+    #[getter]
+    pub fn is_native(&self) -> bool {
+        self.program_id.0 == system_program::ID
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for Transfer {}
+
+fn key_at(keys: &[PubkeyOriginal], instruction_index: usize, index: usize) -> PyResult<Pubkey> {
+    keys.get(index).map(|k| Pubkey(*k)).ok_or_else(|| {
+        PyValueError::new_err(format!(
+            "instruction {instruction_index} references account index {index}, but only {} account keys are known",
+            keys.len()
+        ))
+    })
+}
+
+/// Summarize the SOL and token transfers a transaction requests, before it executes.
+///
+/// Recognizes System ``Transfer``, ``TransferWithSeed``, ``CreateAccount`` and
+/// ``WithdrawNonceAccount``, Token / Token-2022 ``Transfer`` and ``TransferChecked``,
+/// and the Token / Token-2022 instructions that hand over control of funds:
+/// ``Approve``, ``ApproveChecked``, ``CloseAccount`` and ``SetAuthority``.
+/// Other instructions, including transfers made through CPI, are not reported,
+/// so callers should treat instructions to unknown programs separately.
+///
+/// Args:
+///     tx (VersionedTransaction): The transaction to inspect.
+///     account_keys (Optional[Sequence[Pubkey]]): All account keys of the transaction,
+///         including those loaded from lookup tables. Defaults to the static account keys.
+///     token_mints (Optional[Dict[Pubkey, Pubkey]]): Mints of token accounts, used to fill in
+///         the mint of plain ``Transfer`` instructions. Keyed by source or destination account.
+///
+/// Returns:
+///     List[Transfer]: The transfers, in instruction order.
+///
+/// Raises:
+///     ValueError: If an instruction references an account missing from ``account_keys``.
+///
+/// Example:
+///     >>> from solders.transfers import summarize_transfers
+///     >>> from solders.system_program import transfer, TransferParams
+///     >>> from solders.message import Message
+///     >>> from solders.transaction import VersionedTransaction
+///     >>> from solders.keypair import Keypair
+///     >>> from solders.pubkey import Pubkey
+///     >>> payer, to = Keypair(), Pubkey.new_unique()
+///     >>> ix = transfer(TransferParams(from_pubkey=payer.pubkey(), to_pubkey=to, lamports=1000))
+///     >>> tx = VersionedTransaction(Message([ix], payer.pubkey()), [payer])
+///     >>> [(t.destination == to, t.amount) for t in summarize_transfers(tx)]
+///     [(True, 1000)]
+///
+#[pyfunction]
+#[pyo3(signature = (tx, account_keys = None, token_mints = None))]
+pub fn summarize_transfers(
+    tx: VersionedTransaction,
+    account_keys: Option<Vec<Pubkey>>,
+    token_mints: Option<HashMap<Pubkey, Pubkey>>,
+) -> PyResult<Vec<Transfer>> {
+    let tx = VersionedTransactionOriginal::from(tx);
+    let keys: Vec<PubkeyOriginal> = match account_keys {
+        Some(keys) => keys.into_iter().map(|k| k.0).collect(),
+        None => tx.message.static_account_keys().to_vec(),
+    };
+    let token_mints = token_mints.unwrap_or_default();
+    let mut transfers = Vec::new();
+    for (i, ix) in tx.message.instructions().iter().enumerate() {
+        let account = |position: usize| -> PyResult<Pubkey> {
+            let index = ix.accounts.get(position).ok_or_else(|| {
+                PyValueError::new_err(format!("instruction {i} has too few accounts"))
+            })?;
+            key_at(&keys, i, usize::from(*index))
+        };
+        let program_id = key_at(&keys, i, usize::from(ix.program_id_index))?;
+        if program_id.0 == system_program::ID {
+            // (lamports, source, destination, authority) account positions
+            let (amount, source, destination, authority) = match bincode::deserialize(&ix.data) {
+                Ok(SystemInstruction::Transfer { lamports }) => (lamports, 0, 1, 0),
+                Ok(SystemInstruction::TransferWithSeed { lamports, .. }) => (lamports, 0, 2, 1),
+                Ok(SystemInstruction::CreateAccount { lamports, .. }) => (lamports, 0, 1, 0),
+                Ok(SystemInstruction::WithdrawNonceAccount(lamports)) => (lamports, 0, 1, 4),
+                _ => continue,
+            };
+            transfers.push(Transfer {
+                instruction_index: i,
+                program_id,
+                source: account(source)?,
+                destination: account(destination)?,
+                authority: account(authority)?,
+                amount,
+                mint: None,
+                decimals: None,
+                kind: TransferKind::Transfer,
+            });
+        } else if program_id.0 == TOKEN_PROGRAM_ID || program_id.0 == TOKEN_2022_PROGRAM_ID {
+            let amount = ix
+                .data
+                .get(1..9)
+                .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()));
+            let transfer = match (ix.data.first().copied(), amount, ix.data.get(9)) {
+                (Some(TOKEN_TRANSFER), Some(amount), _) => {
+                    let source = account(0)?;
+                    let destination = account(1)?;
+                    let mint = token_mints
+                        .get(&source)
+                        .or_else(|| token_mints.get(&destination))
+                        .copied();
+                    Transfer {
+                        instruction_index: i,
+                        program_id,
+                        source,
+                        destination,
+                        authority: account(2)?,
+                        amount,
+                        mint,
+                        decimals: None,
+                        kind: TransferKind::Transfer,
+                    }
+                }
+                (Some(TOKEN_TRANSFER_CHECKED), Some(amount), Some(decimals)) => Transfer {
+                    instruction_index: i,
+                    program_id,
+                    source: account(0)?,
+                    destination: account(2)?,
+                    authority: account(3)?,
+                    amount,
+                    mint: Some(account(1)?),
+                    decimals: Some(*decimals),
+                    kind: TransferKind::Transfer,
+                },
+                (Some(TOKEN_APPROVE), Some(amount), _) => {
+                    let source = account(0)?;
+                    Transfer {
+                        instruction_index: i,
+                        program_id,
+                        source,
+                        destination: account(1)?,
+                        authority: account(2)?,
+                        amount,
+                        mint: token_mints.get(&source).copied(),
+                        decimals: None,
+                        kind: TransferKind::Approve,
+                    }
+                }
+                (Some(TOKEN_APPROVE_CHECKED), Some(amount), Some(decimals)) => Transfer {
+                    instruction_index: i,
+                    program_id,
+                    source: account(0)?,
+                    destination: account(2)?,
+                    authority: account(3)?,
+                    amount,
+                    mint: Some(account(1)?),
+                    decimals: Some(*decimals),
+                    kind: TransferKind::Approve,
+                },
+                (Some(TOKEN_CLOSE_ACCOUNT), ..) => {
+                    let source = account(0)?;
+                    Transfer {
+                        instruction_index: i,
+                        program_id,
+                        source,
+                        destination: account(1)?,
+                        authority: account(2)?,
+                        amount: 0,
+                        mint: token_mints.get(&source).copied(),
+                        decimals: None,
+                        kind: TransferKind::CloseAccount,
+                    }
+                }
+                (Some(TOKEN_SET_AUTHORITY), ..) => {
+                    // [6, authority type, COption tag, new authority]
+                    let new_authority = match (ix.data.get(2), ix.data.get(3..35)) {
+                        (Some(1), Some(bytes)) => PubkeyOriginal::try_from(bytes).unwrap(),
+                        (Some(0), _) => PubkeyOriginal::default(),
+                        _ => continue,
+                    };
+                    let source = account(0)?;
+                    Transfer {
+                        instruction_index: i,
+                        program_id,
+                        source,
+                        destination: Pubkey(new_authority),
+                        authority: account(1)?,
+                        amount: 0,
+                        mint: token_mints.get(&source).copied(),
+                        decimals: None,
+                        kind: TransferKind::SetAuthority,
+                    }
+                }
+                _ => continue,
+            };
+            transfers.push(transfer);
+        }
+    }
+    Ok(transfers)
+}
+
+pub(crate) fn create_transfers_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "transfers")?;
+    m.add_class::<TransferKind>()?;
+    m.add_class::<Transfer>()?;
+    m.add_function(wrap_pyfunction!(summarize_transfers, m)?)?;
+    Ok(m)
+}
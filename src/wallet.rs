@@ -0,0 +1,482 @@
+use pyo3::prelude::*;
+use solana_sdk::{
+    bpf_loader, bpf_loader_deprecated, bpf_loader_upgradeable, compute_budget, config,
+    ed25519_program, incinerator, message::VersionedMessage as VersionedMessageOriginal,
+    native_loader, pubkey, pubkey::Pubkey as PubkeyOriginal, secp256k1_program,
+    signature::Signature as SignatureOriginal, stake, system_program,
+    transaction::VersionedTransaction as VersionedTransactionOriginal, vote,
+};
+use solders_macros::richcmp_eq_only;
+use solders_primitives::{
+    message::VersionedMessage,
+    pubkey::Pubkey,
+    signature::Signature,
+    transaction::{Transaction, VersionedTransaction},
+};
+use solders_traits::{to_py_value_err, RichcmpEqualityOnly};
+
+use crate::{
+    presets::ata_address,
+    program_ids::{
+        ADDRESS_LOOKUP_TABLE_PROGRAM_ID, MEMO_PROGRAM_ID, MEMO_V1_PROGRAM_ID, NATIVE_MINT,
+        NATIVE_MINT_2022,
+    },
+    spl_errors::{ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID},
+};
+
+/// Programs, sysvars and other well-known addresses that can't receive deposits.
+const KNOWN_ADDRESSES: [(PubkeyOriginal, &str); 31] = [
+    (system_program::ID, "System Program"),
+    (TOKEN_PROGRAM_ID, "Token Program"),
+    (TOKEN_2022_PROGRAM_ID, "Token-2022 Program"),
+    (
+        ASSOCIATED_TOKEN_PROGRAM_ID,
+        "Associated Token Account Program",
+    ),
+    (MEMO_PROGRAM_ID, "Memo Program"),
+    (MEMO_V1_PROGRAM_ID, "Memo Program v1"),
+    (compute_budget::ID, "Compute Budget Program"),
+    (vote::program::ID, "Vote Program"),
+    (stake::program::ID, "Stake Program"),
+    (
+        pubkey!("StakeConfig11111111111111111111111111111111"),
+        "Stake Config",
+    ),
+    (config::program::ID, "Config Program"),
+    (
+        ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
+        "Address Lookup Table Program",
+    ),
+    (bpf_loader_deprecated::ID, "BPF Loader (deprecated)"),
+    (bpf_loader::ID, "BPF Loader"),
+    (bpf_loader_upgradeable::ID, "BPF Upgradeable Loader"),
+    (native_loader::ID, "Native Loader"),
+    (ed25519_program::ID, "Ed25519 Program"),
+    (secp256k1_program::ID, "Secp256k1 Program"),
+    (incinerator::ID, "Incinerator"),
+    (NATIVE_MINT, "Wrapped SOL Mint"),
+    (NATIVE_MINT_2022, "Token-2022 Wrapped SOL Mint"),
+    (
+        pubkey!("SysvarC1ock11111111111111111111111111111111"),
+        "Clock Sysvar",
+    ),
+    (
+        pubkey!("SysvarEpochSchedu1e111111111111111111111111"),
+        "Epoch Schedule Sysvar",
+    ),
+    (
+        pubkey!("SysvarFees111111111111111111111111111111111"),
+        "Fees Sysvar",
+    ),
+    (
+        pubkey!("Sysvar1nstructions1111111111111111111111111"),
+        "Instructions Sysvar",
+    ),
+    (
+        pubkey!("SysvarRecentB1ockHashes11111111111111111111"),
+        "Recent Blockhashes Sysvar",
+    ),
+    (
+        pubkey!("SysvarRent111111111111111111111111111111111"),
+        "Rent Sysvar",
+    ),
+    (
+        pubkey!("SysvarRewards111111111111111111111111111111"),
+        "Rewards Sysvar",
+    ),
+    (
+        pubkey!("SysvarS1otHashes111111111111111111111111111"),
+        "Slot Hashes Sysvar",
+    ),
+    (
+        pubkey!("SysvarS1otHistory11111111111111111111111111"),
+        "Slot History Sysvar",
+    ),
+    (
+        pubkey!("SysvarStakeHistory1111111111111111111111111"),
+        "Stake History Sysvar",
+    ),
+];
+
+/// A transaction to hand to a wallet.
+#[derive(FromPyObject, Clone, Debug)]
+pub enum WalletTransaction {
+    Legacy(Transaction),
+    Versioned(VersionedTransaction),
+}
+
+impl From<WalletTransaction> for VersionedTransactionOriginal {
+    fn from(tx: WalletTransaction) -> Self {
+        match tx {
+            WalletTransaction::Legacy(tx) => VersionedTransaction::from(tx).into(),
+            WalletTransaction::Versioned(tx) => tx.into(),
+        }
+    }
+}
+
+/// Encode a transaction for a wallet's ``signTransaction`` or ``signAndSendTransaction``.
+///
+/// Wallet-standard wallets take the serialized transaction. This returns it base64
+/// encoded for the trip to the browser, where it becomes the ``transaction`` bytes.
+///
+/// Args:
+///     transaction (Transaction | VersionedTransaction): The transaction, signed or not.
+///
+/// Returns:
+///     str: The base64 payload.
+///
+#[pyfunction]
+pub fn encode_transaction(transaction: WalletTransaction) -> String {
+    let tx = VersionedTransactionOriginal::from(transaction);
+    base64::encode(bincode::serialize(&tx).unwrap())
+}
+
+/// Encode transactions for a wallet's ``signAllTransactions``.
+///
+/// Args:
+///     transactions (Sequence[Transaction | VersionedTransaction]): The transactions.
+///
+/// Returns:
+///     List[str]: One base64 payload per transaction.
+///
+#[pyfunction]
+pub fn encode_transactions(transactions: Vec<WalletTransaction>) -> Vec<String> {
+    transactions.into_iter().map(encode_transaction).collect()
+}
+
+/// Decode and check the ``signedTransaction`` a wallet returned.
+///
+/// The transaction must sanitize, carry exactly as many signatures as its header
+/// requires and be signed by the fee payer. Every other signature that isn't the
+/// default signature must be valid, so slots left for co-signers are allowed. Pass
+/// ``expected_message`` to reject wallets that changed the message, e.g. by adding
+/// instructions or replacing the blockhash.
+///
+/// Args:
+///     payload (str): The base64 signed transaction.
+///     expected_message (Optional[Message | MessageV0]): The message the wallet was asked to sign.
+///
+/// Returns:
+///     VersionedTransaction: The signed transaction.
+///
+/// Raises:
+///     ValueError: If the payload doesn't decode or sanitize, the message changed, the
+///         signature count is wrong, the fee payer didn't sign or a signature is invalid.
+///
+/// Example:
+///     >>> from solders.wallet import encode_transaction, decode_signed_transaction
+///     >>> from solders.keypair import Keypair
+///     >>> from solders.message import Message
+///     >>> from solders.hash import Hash
+///     >>> from solders.transaction import VersionedTransaction
+///     >>> payer = Keypair()
+///     >>> message = Message.new_with_blockhash([], payer.pubkey(), Hash.default())
+///     >>> signed = VersionedTransaction(message, [payer])
+///     >>> decode_signed_transaction(encode_transaction(signed), message) == signed
+///     True
+///
+#[pyfunction]
+#[pyo3(signature = (payload, expected_message = None))]
+pub fn decode_signed_transaction(
+    payload: &str,
+    expected_message: Option<VersionedMessage>,
+) -> PyResult<VersionedTransaction> {
+    let raw = base64::decode(payload).map_err(|e| to_py_value_err(&e))?;
+    let tx: VersionedTransactionOriginal =
+        bincode::deserialize(&raw).map_err(|e| to_py_value_err(&e))?;
+    if let Some(expected) = expected_message {
+        if tx.message != VersionedMessageOriginal::from(expected) {
+            return Err(to_py_value_err(&"wallet changed the transaction message"));
+        }
+    }
+    tx.sanitize(false).map_err(|e| to_py_value_err(&e))?;
+    let num_required_signatures = usize::from(tx.message.header().num_required_signatures);
+    if tx.signatures.len() != num_required_signatures {
+        return Err(to_py_value_err(&format!(
+            "transaction has {} signatures but its message requires {}",
+            tx.signatures.len(),
+            num_required_signatures
+        )));
+    }
+    let default_signature = SignatureOriginal::default();
+    if tx.signatures.first() == Some(&default_signature) {
+        return Err(to_py_value_err(&"fee payer didn't sign the transaction"));
+    }
+    let message_bytes = tx.message.serialize();
+    for (signature, pubkey) in tx.signatures.iter().zip(tx.message.static_account_keys()) {
+        if *signature != default_signature && !signature.verify(pubkey.as_ref(), &message_bytes) {
+            return Err(to_py_value_err(&format!(
+                "invalid signature for {}",
+                pubkey
+            )));
+        }
+    }
+    Ok(tx.into())
+}
+
+/// Decode and check the ``signedTransactions`` from ``signAllTransactions``.
+///
+/// Args:
+///     payloads (Sequence[str]): The base64 signed transactions.
+///     expected_messages (Optional[Sequence[Message | MessageV0]]): The messages the
+///         wallet was asked to sign, in the same order.
+///
+/// Returns:
+///     List[VersionedTransaction]: The signed transactions.
+///
+/// Raises:
+///     ValueError: If the counts differ or any transaction fails :func:`decode_signed_transaction`.
+///
+#[pyfunction]
+#[pyo3(signature = (payloads, expected_messages = None))]
+pub fn decode_signed_transactions(
+    payloads: Vec<&str>,
+    expected_messages: Option<Vec<VersionedMessage>>,
+) -> PyResult<Vec<VersionedTransaction>> {
+    match expected_messages {
+        Some(messages) => {
+            if messages.len() != payloads.len() {
+                return Err(to_py_value_err(&format!(
+                    "got {} signed transactions for {} messages",
+                    payloads.len(),
+                    messages.len()
+                )));
+            }
+            payloads
+                .into_iter()
+                .zip(messages)
+                .map(|(payload, message)| decode_signed_transaction(payload, Some(message)))
+                .collect()
+        }
+        None => payloads
+            .into_iter()
+            .map(|payload| decode_signed_transaction(payload, None))
+            .collect(),
+    }
+}
+
+/// Check the result of a wallet's ``signMessage``.
+///
+/// Wallets may prefix the message before signing, so the signature is checked
+/// against ``signed_message``. Pass ``expected_message`` to also require that the
+/// wallet signed exactly the requested bytes.
+///
+/// Args:
+///     pubkey (Pubkey): The wallet account.
+///     signed_message (bytes): The ``signedMessage`` the wallet returned.
+///     signature (bytes): The ``signature`` the wallet returned.
+///     expected_message (Optional[bytes]): The message the wallet was asked to sign.
+///
+/// Returns:
+///     Signature: The verified signature.
+///
+/// Raises:
+///     ValueError: If the signature is malformed or invalid, or the message differs.
+///
+#[pyfunction]
+#[pyo3(signature = (pubkey, signed_message, signature, expected_message = None))]
+pub fn verify_signed_message(
+    pubkey: Pubkey,
+    signed_message: &[u8],
+    signature: &[u8],
+    expected_message: Option<&[u8]>,
+) -> PyResult<Signature> {
+    if expected_message.map_or(false, |m| m != signed_message) {
+        return Err(to_py_value_err(&"wallet signed a different message"));
+    }
+    if signature.len() != 64 {
+        return Err(to_py_value_err(&format!(
+            "expected a 64 byte signature, got {} bytes",
+            signature.len()
+        )));
+    }
+    let signature = SignatureOriginal::new(signature);
+    if !signature.verify(pubkey.as_ref(), signed_message) {
+        return Err(to_py_value_err(&format!(
+            "invalid signature for {}",
+            pubkey
+        )));
+    }
+    Ok(signature.into())
+}
+
+/// The findings of :func:`validate_address`.
+#[pyclass(module = "solders.wallet", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AddressValidation {
+    /// str: The address as given.
+    #[pyo3(get)]
+    pub address: String,
+    /// Optional[int]: The number of bytes the address decodes to, or ``None`` if it isn't base58.
+    #[pyo3(get)]
+    pub decoded_length: Option<usize>,
+    /// Optional[Pubkey]: The pubkey, if the address is valid.
+    #[pyo3(get)]
+    pub pubkey: Option<Pubkey>,
+    /// bool: Whether the pubkey is on the ed25519 curve, so a private key can exist for it.
+    /// ``False`` for program derived addresses and invalid addresses.
+    #[pyo3(get)]
+    pub is_on_curve: bool,
+    /// Optional[str]: The name of the program, sysvar or other well-known address it is.
+    #[pyo3(get)]
+    pub known_name: Option<String>,
+    /// Optional[Tuple[Pubkey, Pubkey]]: The ``(wallet, mint)`` pair it is the associated
+    /// token account of, among the candidates given.
+    #[pyo3(get)]
+    pub associated_token_account_of: Option<(Pubkey, Pubkey)>,
+}
+
+#[richcmp_eq_only]
+#[pymethods]
+impl AddressValidation {
+    #[new]
+    pub fn new(
+        address: String,
+        decoded_length: Option<usize>,
+        pubkey: Option<Pubkey>,
+        is_on_curve: bool,
+        known_name: Option<String>,
+        associated_token_account_of: Option<(Pubkey, Pubkey)>,
+    ) -> Self {
+        Self {
+            address,
+            decoded_length,
+            pubkey,
+            is_on_curve,
+            known_name,
+            associated_token_account_of,
+        }
+    }
+
+    /// bool: Whether the address is base58.
+    #[getter]
+    pub fn is_base58(&self) -> bool {
+        self.decoded_length.is_some()
+    }
+
+    /// bool: Whether the address is a valid pubkey: base58 that decodes to 32 bytes.
+    #[getter]
+    pub fn is_valid(&self) -> bool {
+        self.pubkey.is_some()
+    }
+
+    /// bool: Whether the address looks like a wallet that can receive deposits: valid,
+    /// on the curve, not a well-known address and not one of the candidate token accounts.
+    #[getter]
+    pub fn is_wallet(&self) -> bool {
+        self.is_valid()
+            && self.is_on_curve
+            && self.known_name.is_none()
+            && self.associated_token_account_of.is_none()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl RichcmpEqualityOnly for AddressValidation {}
+
+/// Check a user-supplied address before sending funds to it.
+///
+/// Each check is reported separately, so deposit flows can decide which to enforce.
+/// Most should accept only addresses where ``is_wallet`` is true. Whitespace is not
+/// stripped, so an address with surrounding spaces is not base58. The empty string
+/// is not base58 either.
+///
+/// An address can only be recognized as an associated token account of a
+/// ``(wallet, mint)`` pair that is passed in, since the derivation can't be reversed.
+/// Both the Token and Token-2022 programs are checked.
+///
+/// Args:
+///     address (str): The address to check.
+///     ata_candidates (Optional[Sequence[Tuple[Pubkey, Pubkey]]]): ``(wallet, mint)`` pairs,
+///         e.g. the user's wallet and the mints the exchange supports.
+///
+/// Returns:
+///     AddressValidation: The findings.
+///
+/// Example:
+///     >>> from solders.wallet import validate_address
+///     >>> from solders.keypair import Keypair
+///     >>> validate_address(str(Keypair().pubkey())).is_wallet
+///     True
+///     >>> sysvar = validate_address("SysvarC1ock11111111111111111111111111111111")
+///     >>> sysvar.is_valid, sysvar.is_wallet, sysvar.known_name
+///     (True, False, 'Clock Sysvar')
+///     >>> validate_address("not an address").is_base58
+///     False
+///
+#[pyfunction]
+#[pyo3(signature = (address, ata_candidates = None))]
+pub fn validate_address(
+    address: &str,
+    ata_candidates: Option<Vec<(Pubkey, Pubkey)>>,
+) -> AddressValidation {
+    let decoded = Some(address)
+        .filter(|address| !address.is_empty())
+        .and_then(|address| bs58::decode(address).into_vec().ok());
+    let pubkey = decoded
+        .as_ref()
+        .filter(|bytes| bytes.len() == 32)
+        .map(|bytes| PubkeyOriginal::new(bytes));
+    let known_name = pubkey.and_then(|pubkey| {
+        KNOWN_ADDRESSES
+            .iter()
+            .find(|(known, _)| *known == pubkey)
+            .map(|(_, name)| name.to_string())
+    });
+    let associated_token_account_of = pubkey.and_then(|pubkey| {
+        ata_candidates
+            .unwrap_or_default()
+            .into_iter()
+            .find(|(wallet, mint)| {
+                [TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID]
+                    .iter()
+                    .any(|program| ata_address(wallet.as_ref(), mint.as_ref(), program) == pubkey)
+            })
+    });
+    AddressValidation {
+        address: address.to_string(),
+        decoded_length: decoded.map(|bytes| bytes.len()),
+        pubkey: pubkey.map(Pubkey::from),
+        is_on_curve: pubkey.map_or(false, |pubkey| pubkey.is_on_curve()),
+        known_name,
+        associated_token_account_of,
+    }
+}
+
+/// Run :func:`validate_address` on many addresses.
+///
+/// Args:
+///     addresses (Sequence[str]): The addresses to check.
+///     ata_candidates (Optional[Sequence[Tuple[Pubkey, Pubkey]]]): ``(wallet, mint)`` pairs
+///         checked against every address.
+///
+/// Returns:
+///     List[AddressValidation]: The findings, in input order.
+///
+#[pyfunction]
+#[pyo3(signature = (addresses, ata_candidates = None))]
+pub fn validate_addresses(
+    addresses: Vec<&str>,
+    ata_candidates: Option<Vec<(Pubkey, Pubkey)>>,
+) -> Vec<AddressValidation> {
+    addresses
+        .into_iter()
+        .map(|address| validate_address(address, ata_candidates.clone()))
+        .collect()
+}
+
+pub(crate) fn create_wallet_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "wallet")?;
+    m.add_function(wrap_pyfunction!(encode_transaction, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_transactions, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_signed_transaction, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_signed_transactions, m)?)?;
+    m.add_function(wrap_pyfunction!(verify_signed_message, m)?)?;
+    m.add_class::<AddressValidation>()?;
+    m.add_function(wrap_pyfunction!(validate_address, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_addresses, m)?)?;
+    Ok(m)
+}
@@ -1,7 +1,36 @@
-from pytest import fixture
+from pytest import fixture, raises
+import hashlib
 import pickle
+import struct
+from typing import Optional
+from solders.hash import Hash
 from solders.pubkey import Pubkey
-from solders.account import Account
+from solders.account import (
+    Account,
+    diff_program_data,
+    executable_hash,
+    program_data_elf,
+    program_data_hash,
+    validate_mint_account,
+    validate_program_account,
+    validate_token_account,
+)
+
+TOKEN_PROGRAM_ID = Pubkey.from_string("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+TOKEN_2022_PROGRAM_ID = Pubkey.from_string(
+    "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+)
+UPGRADEABLE_LOADER_ID = Pubkey.from_string(
+    "BPFLoaderUpgradeab1e11111111111111111111111"
+)
+
+
+def make_elf(body: bytes) -> bytes:
+    """A 64-bit ELF with one zeroed section header at the end."""
+    shoff = 64 + len(body)
+    ident = b"\x7fELF" + bytes([2, 1, 1]) + bytes(9)
+    fields = (3, 247, 1, 0, 0, shoff, 0, 64, 56, 0, 64, 1, 0)
+    return ident + struct.pack("<HHIQQQIHHHHHH", *fields) + body + bytes(64)
 
 
 @fixture
@@ -21,3 +50,112 @@ def test_pickle(account: Account) -> None:
 def test_json() -> None:
     obj = Account.default()
     assert Account.from_json(obj.to_json()) == obj
+
+
+def test_data_slice() -> None:
+    account = Account(1, bytes(range(10)), Pubkey.default())
+    assert account.data_slice(2, 3) == bytes([2, 3, 4])
+    assert account.data_slice(8, 100) == bytes([8, 9])
+    assert account.data_slice(20, 5) == b""
+    assert account.data_slice(0, 0) == b""
+
+
+def _token_account_data(mint: Pubkey, owner: Pubkey, state: int = 1) -> bytes:
+    return bytes(mint) + bytes(owner) + bytes(44) + bytes([state]) + bytes(56)
+
+
+def test_validate_token_account() -> None:
+    mint, owner = Pubkey.new_unique(), Pubkey.new_unique()
+    data = _token_account_data(mint, owner)
+    assert len(data) == 165
+    acc = Account(2039280, data, TOKEN_PROGRAM_ID)
+    validate_token_account(acc, mint, owner)
+    with raises(ValueError, match="mint"):
+        validate_token_account(acc, expected_mint=owner)
+    with raises(ValueError, match="owner"):
+        validate_token_account(acc, expected_owner=mint)
+    with raises(ValueError, match="not initialized"):
+        validate_token_account(
+            Account(1, _token_account_data(mint, owner, 0), TOKEN_PROGRAM_ID)
+        )
+    with raises(ValueError, match="length"):
+        validate_token_account(Account(1, data + bytes(1), TOKEN_PROGRAM_ID))
+    extended = data + bytes([2]) + bytes(10)
+    validate_token_account(Account(1, extended, TOKEN_2022_PROGRAM_ID), mint, owner)
+    with raises(ValueError, match="not a token program"):
+        validate_token_account(Account(1, data, Pubkey.default()))
+
+
+def test_validate_mint_account() -> None:
+    data = bytes(45) + bytes([1]) + bytes(36)
+    validate_mint_account(Account(1, data, TOKEN_PROGRAM_ID))
+    with raises(ValueError, match="not initialized"):
+        validate_mint_account(Account(1, bytes(82), TOKEN_PROGRAM_ID))
+    with raises(ValueError, match="length"):
+        validate_mint_account(Account(1, bytes(165), TOKEN_PROGRAM_ID))
+
+
+def test_validate_program_account() -> None:
+    programdata = Pubkey.new_unique()
+    data = struct.pack("<I", 2) + bytes(programdata)
+    acc = Account(1, data, UPGRADEABLE_LOADER_ID, executable=True)
+    assert validate_program_account(acc) == programdata
+    with raises(ValueError, match="not executable"):
+        validate_program_account(Account(1, data, UPGRADEABLE_LOADER_ID))
+    with raises(ValueError, match="program state"):
+        validate_program_account(
+            Account(1, bytes(4), UPGRADEABLE_LOADER_ID, executable=True)
+        )
+    with raises(ValueError, match="not a loader"):
+        validate_program_account(Account(1, b"", Pubkey.default(), executable=True))
+
+
+def test_program_data_hash() -> None:
+    # the trailing section header is all zeros and must not be stripped
+    elf = make_elf(bytes(range(1, 200)))
+    metadata = struct.pack("<IQ", 3, 1234) + bytes([1]) + bytes(Pubkey.new_unique())
+    acc = Account(1, metadata + elf + bytes(1000), UPGRADEABLE_LOADER_ID)
+    assert program_data_elf(acc) == elf
+    expected = hashlib.sha256(elf).hexdigest()
+    assert program_data_hash(acc) == expected
+    assert executable_hash(elf + bytes(7)) == expected
+    closed = struct.pack("<IQ", 3, 1234) + bytes(33)
+    assert program_data_elf(Account(1, closed, UPGRADEABLE_LOADER_ID)) == b""
+    with raises(ValueError, match="ELF header"):
+        executable_hash(b"\x7fELF" + bytes(range(1, 100)))
+    with raises(ValueError, match="ELF header"):
+        executable_hash(elf[:-1])
+    program = struct.pack("<I", 2) + bytes(32)
+    with raises(ValueError, match="program data"):
+        program_data_hash(Account(1, program, UPGRADEABLE_LOADER_ID))
+    with raises(ValueError, match="upgradeable loader"):
+        program_data_elf(Account(1, metadata + elf, Pubkey.default()))
+
+
+def test_diff_program_data() -> None:
+    authority, new_authority = Pubkey.new_unique(), Pubkey.new_unique()
+
+    def programdata(slot: int, authority: Optional[Pubkey], elf: bytes) -> Account:
+        auth = bytes([0]) if authority is None else bytes([1]) + bytes(authority)
+        meta = (struct.pack("<IQ", 3, slot) + auth).ljust(45, b"\x00")
+        return Account(1, meta + elf + bytes(100), UPGRADEABLE_LOADER_ID)
+
+    elf = make_elf(bytes(range(1, 100)))
+    changed_elf = make_elf(bytes(range(1, 101)))
+    before = programdata(10, authority, elf)
+    same = diff_program_data(before, before)
+    assert not same.has_changes
+    assert same.summary() == []
+    assert same.before_size == same.after_size == len(elf)
+    upgraded = diff_program_data(before, programdata(20, new_authority, changed_elf))
+    assert upgraded.code_changed and upgraded.redeployed and upgraded.authority_changed
+    assert not upgraded.made_immutable
+    assert upgraded.after_size == len(elf) + 1
+    assert upgraded.after_hash == Hash(hashlib.sha256(changed_elf).digest())
+    assert upgraded.after_authority == new_authority
+    assert len(upgraded.summary()) == 4
+    frozen = diff_program_data(before, programdata(10, None, elf))
+    assert frozen.made_immutable and not frozen.code_changed
+    assert frozen.summary() == [f"upgrade authority changed: {authority} -> none"]
+    with raises(ValueError, match="upgradeable loader"):
+        diff_program_data(before, Account(1, b"", Pubkey.default()))
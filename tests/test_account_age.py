@@ -0,0 +1,82 @@
+from typing import List
+
+from pytest import mark, raises
+from solders.account_age import (
+    RENT_EXEMPT_RENT_EPOCH,
+    CreationSlotSearch,
+    RentEpochKind,
+    latest_creation_epoch,
+    rent_epoch_kind,
+)
+from solders.commitment_config import CommitmentLevel
+from solders.pubkey import Pubkey
+from solders.rpc.responses import (
+    GetSignaturesForAddressResp,
+    RpcConfirmedTransactionStatusWithSignature,
+)
+from solders.signature import Signature
+
+
+def _page(slots: List[int]) -> GetSignaturesForAddressResp:
+    return GetSignaturesForAddressResp(
+        [
+            RpcConfirmedTransactionStatusWithSignature(
+                Signature.new_unique(), slot, None, None, None, None
+            )
+            for slot in slots
+        ]
+    )
+
+
+@mark.parametrize(
+    "rent_epoch,kind,epoch",
+    [
+        (RENT_EXEMPT_RENT_EPOCH, RentEpochKind.Exempt, None),
+        (0, RentEpochKind.Unset, None),
+        (361, RentEpochKind.Scheduled, 361),
+    ],
+)
+def test_rent_epoch(rent_epoch: int, kind: RentEpochKind, epoch: int) -> None:
+    assert rent_epoch_kind(rent_epoch) == kind
+    assert latest_creation_epoch(rent_epoch) == epoch
+
+
+def test_creation_slot_search() -> None:
+    address = Pubkey.new_unique()
+    search = CreationSlotSearch(address, 3, CommitmentLevel.Confirmed)
+    assert search.creation_slot is None
+    assert search.age_slots(100) is None
+    first = search.next_request(5)
+    assert first is not None
+    assert first.id == 5
+    assert first.address == address
+    assert first.config is not None
+    assert first.config.before is None
+    assert first.config.limit == 3
+    page = _page([90, 80, 70])
+    search.handle_response(page)
+    assert not search.is_done
+    assert search.creation_slot == 70
+    second = search.next_request()
+    assert second is not None
+    assert second.config is not None
+    assert second.config.before == page.value[-1].signature
+    search.handle_response(_page([60]))
+    assert search.is_done
+    assert search.next_request() is None
+    assert search.creation_slot == 60
+    assert search.age_slots(100) == 40
+    with raises(ValueError):
+        search.handle_response(_page([]))
+
+
+def test_creation_slot_search_empty_page() -> None:
+    search = CreationSlotSearch(Pubkey.new_unique())
+    search.handle_response(_page([]))
+    assert search.is_done
+    assert search.oldest is None
+
+
+def test_creation_slot_search_limit() -> None:
+    with raises(ValueError):
+        CreationSlotSearch(Pubkey.new_unique(), 1001)
@@ -0,0 +1,58 @@
+from pytest import raises
+from solders.account import Account
+from solders.accounts_map import AccountsMap
+from solders.pubkey import Pubkey
+
+OWNER = Pubkey.new_unique()
+
+
+def _account(lamports: int) -> Account:
+    return Account(lamports, b"", OWNER)
+
+
+def test_overlay_reads_and_writes() -> None:
+    a, b, c = sorted(Pubkey.new_unique() for _ in range(3))
+    accounts = AccountsMap({a: _account(1), b: _account(2)})
+    assert not accounts.is_dirty
+    accounts[a] = _account(4)
+    accounts[c] = _account(3)
+    del accounts[b]
+    assert accounts.is_dirty
+    assert accounts.keys() == [a, c]
+    assert len(accounts) == 2
+    assert b not in accounts
+    assert accounts.get(b) is None
+    assert accounts.base() == {a: _account(1), b: _account(2)}
+    assert accounts.pending_writes() == {a: _account(4), b: None, c: _account(3)}
+    with raises(KeyError):
+        accounts[b]
+    with raises(KeyError):
+        del accounts[b]
+
+
+def test_commit_and_rollback() -> None:
+    key = Pubkey.new_unique()
+    accounts = AccountsMap()
+    accounts.set(key, _account(5))
+    accounts.rollback()
+    assert key not in accounts
+    accounts.set(key, _account(5))
+    accounts.commit()
+    assert not accounts.is_dirty
+    assert accounts.base() == {key: _account(5)}
+    assert accounts.delete(key)
+    assert not accounts.delete(key)
+
+
+def test_fork_is_independent() -> None:
+    key = Pubkey.new_unique()
+    accounts = AccountsMap({key: _account(1)})
+    accounts[key] = _account(2)
+    fork = accounts.fork()
+    assert not fork.is_dirty
+    assert fork.base() == {key: _account(2)}
+    fork[key] = _account(3)
+    assert accounts[key] == _account(2)
+    accounts.rollback()
+    assert fork[key] == _account(3)
+    assert accounts.to_dict() == {key: _account(1)}
@@ -0,0 +1,85 @@
+import struct
+from typing import Optional
+
+from pytest import raises
+from solders.account import Account
+from solders.address_lookup_table_account import (
+    LookupTableAction,
+    advise_lookup_table_cleanup,
+    lookup_table_earliest_closable_slot,
+)
+from solders.pubkey import Pubkey
+
+LOOKUP_TABLE_PROGRAM_ID = Pubkey.from_string(
+    "AddressLookupTab1e1111111111111111111111111"
+)
+U64_MAX = 2**64 - 1
+
+
+def lookup_table(
+    authority: Optional[Pubkey],
+    deactivation_slot: int = U64_MAX,
+    last_extended_slot: int = 0,
+    num_addresses: int = 3,
+) -> Account:
+    auth = bytes(33) if authority is None else bytes([1]) + bytes(authority)
+    meta = struct.pack("<IQQB", 1, deactivation_slot, last_extended_slot, 0) + auth
+    data = meta + bytes(2) + bytes(32 * num_addresses)
+    return Account(1_000_000, data, LOOKUP_TABLE_PROGRAM_ID)
+
+
+def test_lookup_table_earliest_closable_slot() -> None:
+    assert lookup_table_earliest_closable_slot(1000) == 1513
+    assert lookup_table_earliest_closable_slot(U64_MAX) == U64_MAX
+
+
+def test_advise_lookup_table_cleanup() -> None:
+    authority = Pubkey.new_unique()
+    keys = [Pubkey.new_unique() for _ in range(6)]
+    tables = [
+        (keys[0], lookup_table(authority, last_extended_slot=10)),
+        (keys[1], lookup_table(authority, last_extended_slot=10)),
+        (keys[2], lookup_table(None, last_extended_slot=10)),
+        (keys[3], lookup_table(authority, deactivation_slot=900)),
+        (keys[4], lookup_table(authority, deactivation_slot=600)),
+        (keys[5], lookup_table(Pubkey.new_unique(), last_extended_slot=10)),
+    ]
+    advice = advise_lookup_table_cleanup(
+        tables, {keys[1]: 1000}, 1200, idle_slots=500, authority=authority
+    )
+    assert [a.action for a in advice] == [
+        LookupTableAction.Deactivate,
+        LookupTableAction.Keep,
+        LookupTableAction.Keep,
+        LookupTableAction.Wait,
+        LookupTableAction.Close,
+        LookupTableAction.Keep,
+    ]
+    assert [a.key for a in advice] == keys
+    assert advice[0].last_used_slot == 10
+    assert advice[1].last_used_slot == 1000
+    assert advice[2].authority is None
+    assert advice[3].earliest_closable_slot == 1413
+    assert advice[4].deactivation_slot == 600
+    assert advice[4].lamports == 1_000_000
+    assert advice[4].num_addresses == 3
+    anyone = advise_lookup_table_cleanup(tables, {}, 1200, idle_slots=500)
+    assert anyone[5].action == LookupTableAction.Deactivate
+    # skipped slots kept slot 600 in SlotHashes past its earliest closable slot
+    skipped = advise_lookup_table_cleanup(
+        tables[3:5], {}, 1200, authority=authority, slot_hashes=[600, 900, 1199]
+    )
+    assert [a.action for a in skipped] == [LookupTableAction.Wait] * 2
+    evicted = advise_lookup_table_cleanup(
+        tables[3:5], {}, 1200, authority=authority, slot_hashes=[900, 1199]
+    )
+    assert [a.action for a in evicted] == [
+        LookupTableAction.Wait,
+        LookupTableAction.Close,
+    ]
+    with raises(ValueError, match="not the address lookup table program"):
+        advise_lookup_table_cleanup([(keys[0], Account(1, b"", Pubkey.default()))], {}, 0)
+    with raises(ValueError, match="does not hold a lookup table"):
+        advise_lookup_table_cleanup(
+            [(keys[0], Account(1, bytes(56), LOOKUP_TABLE_PROGRAM_ID))], {}, 0
+        )
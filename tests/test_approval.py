@@ -0,0 +1,165 @@
+import json
+import struct
+from hashlib import sha256
+from typing import Any, List
+
+from pytest import raises
+from solders.account import Account
+from solders.approval import ApprovalStatus, ApprovalVote, ThresholdApproval
+from solders.keypair import Keypair
+from solders.pubkey import Pubkey
+from solders.signature import Signature
+from solders.squads import SQUADS_PROGRAM_ID, Proposal
+
+KEYPAIRS = [Keypair() for _ in range(3)]
+MEMBERS = [k.pubkey() for k in KEYPAIRS]
+PAYLOAD = b"rotate upgrade authority"
+
+
+def approval() -> ThresholdApproval:
+    state = ThresholdApproval(MEMBERS, 2)
+    assert state.propose("p1", PAYLOAD)
+    return state
+
+
+def squads_proposal(status: int, approved: List[Pubkey]) -> Proposal:
+    def keys(keys: List[Pubkey]) -> bytes:
+        return struct.pack("<I", len(keys)) + b"".join(bytes(k) for k in keys)
+
+    discriminator = sha256(b"account:Proposal").digest()[:8]
+    data = bytes(Pubkey.new_unique()) + struct.pack("<QBqB", 5, status, 0, 255)
+    data += keys(approved) + keys([]) + keys([])
+    return Proposal.from_account(Account(1, discriminator + data, SQUADS_PROGRAM_ID))
+
+
+def test_new() -> None:
+    with raises(ValueError, match="threshold"):
+        ThresholdApproval(MEMBERS, 0)
+    with raises(ValueError, match="threshold"):
+        ThresholdApproval(MEMBERS, 4)
+    with raises(ValueError, match="listed twice"):
+        ThresholdApproval(MEMBERS + MEMBERS[:1], 2)
+    state = approval()
+    assert not state.propose("p1", b"other")
+    assert len(state) == 1
+    assert state.proposal("nope") is None
+
+
+def test_signed_approvals() -> None:
+    state = approval()
+    signature = KEYPAIRS[0].sign_message(PAYLOAD)
+    assert state.approve("p1", MEMBERS[0], signature) == ApprovalStatus.Pending
+    with raises(ValueError, match="invalid signature"):
+        state.approve("p1", MEMBERS[1], KEYPAIRS[1].sign_message(b"other"))
+    with raises(ValueError, match="not a member"):
+        outsider = Keypair()
+        state.approve("p1", outsider.pubkey(), outsider.sign_message(PAYLOAD))
+    with raises(ValueError, match="unknown proposal"):
+        state.approve("p2", MEMBERS[0], signature)
+    assert state.missing("p1") == MEMBERS[1:]
+    proposal = state.proposal("p1")
+    assert proposal is not None
+    assert proposal.votes == [ApprovalVote(MEMBERS[0], True, signature)]
+    assert proposal.payload == PAYLOAD
+    second = KEYPAIRS[2].sign_message(PAYLOAD)
+    assert state.approve("p1", MEMBERS[2], second) == ApprovalStatus.Approved
+    assert state.ready() == ["p1"]
+
+
+def test_votes_and_lifecycle() -> None:
+    state = approval()
+    state.record_vote("p1", MEMBERS[0], True)
+    state.record_vote("p1", MEMBERS[0], False)
+    proposal = state.proposal("p1")
+    assert proposal is not None
+    assert proposal.approvals == [] and proposal.rejections == [MEMBERS[0]]
+    assert state.record_vote("p1", MEMBERS[1], False) == ApprovalStatus.Rejected
+    with raises(ValueError, match="no longer takes votes"):
+        state.record_vote("p1", MEMBERS[2], True)
+    with raises(ValueError, match="not approved"):
+        state.mark_executed("p1")
+    state.cancel("p1")
+    with raises(ValueError, match="Cancelled"):
+        state.record_vote("p1", MEMBERS[2], True)
+
+    state.propose("p2", PAYLOAD)
+    state.record_vote("p2", MEMBERS[0], True)
+    state.record_vote("p2", MEMBERS[1], True)
+    with raises(ValueError, match="Approved"):
+        state.record_vote("p2", MEMBERS[0], False)
+    assert state.ready() == ["p2"]
+    executed = Signature.new_unique()
+    state.mark_executed("p2", executed)
+    done = state.proposal("p2")
+    assert done is not None
+    assert done.status == ApprovalStatus.Executed
+    assert done.execution_signature == executed
+    assert state.ready() == []
+    with raises(ValueError, match="Executed"):
+        state.cancel("p2")
+
+
+def test_sync_squads() -> None:
+    state = approval()
+    assert (
+        state.sync_squads("p1", squads_proposal(1, MEMBERS[:1]))
+        == ApprovalStatus.Pending
+    )
+    assert (
+        state.sync_squads("p1", squads_proposal(5, MEMBERS[:2]))
+        == ApprovalStatus.Executed
+    )
+    state.propose("p2", PAYLOAD)
+    assert (
+        state.sync_squads("p2", squads_proposal(6, []))
+        == ApprovalStatus.Cancelled
+    )
+    with raises(ValueError, match="not a member"):
+        state.propose("p3", PAYLOAD)
+        state.sync_squads("p3", squads_proposal(1, [Pubkey.new_unique()]))
+
+
+def test_serialization() -> None:
+    state = approval()
+    state.approve("p1", MEMBERS[0], KEYPAIRS[0].sign_message(PAYLOAD))
+    assert ThresholdApproval.from_bytes(bytes(state)) == state
+    assert ThresholdApproval.from_json(state.to_json()) == state
+    with raises(ValueError, match="format version"):
+        ThresholdApproval.from_bytes(b"\x09" + bytes(state)[1:])
+    with raises(ValueError, match="threshold"):
+        ThresholdApproval.from_json(
+            state.to_json().replace('"threshold":2', '"threshold":5')
+        )
+    raw = bytes(state)
+    threshold_at = 1 + 8 + 32 * len(MEMBERS)
+    with raises(ValueError, match="threshold"):
+        ThresholdApproval.from_bytes(
+            raw[:threshold_at] + bytes([5]) + raw[threshold_at + 1 :]
+        )
+
+
+def test_load_checks_proposals() -> None:
+    state = approval()
+    state.record_vote("p1", MEMBERS[0], True)
+    fields = json.loads(state.to_json())
+
+    def load(**changes: Any) -> ThresholdApproval:
+        proposal = {**fields["proposals"][0], **changes}
+        raw = json.dumps({**fields, "proposals": [proposal]})
+        return ThresholdApproval.from_json(raw)
+
+    assert load() == state
+    vote = fields["proposals"][0]["votes"][0]
+    with raises(ValueError, match="not a member"):
+        load(votes=[{**vote, "member": list(bytes(Pubkey.new_unique()))}])
+    with raises(ValueError, match="voted twice"):
+        load(votes=[vote, vote])
+    with raises(ValueError, match="votes make it Pending"):
+        load(status="approved")
+    with raises(ValueError, match="votes make it Pending"):
+        load(status="executed")
+    with raises(ValueError, match="execution signature"):
+        load(execution_signature=list(bytes(Signature.new_unique())))
+    signature = list(bytes(KEYPAIRS[0].sign_message(b"other")))
+    with raises(ValueError, match="invalid signature"):
+        load(votes=[{**vote, "signature": signature}])
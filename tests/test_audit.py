@@ -0,0 +1,86 @@
+from typing import List
+
+from pytest import raises
+from solders.audit import (
+    AccountPrivilege,
+    InstructionAudit,
+    PrivilegeFlag,
+    audit_privileges,
+)
+from solders.hash import Hash
+from solders.instruction import AccountMeta, CompiledInstruction, Instruction
+from solders.keypair import Keypair
+from solders.message import Message
+from solders.pubkey import Pubkey
+from solders.signature import Signature
+from solders.system_program import (
+    AssignParams,
+    TransferParams,
+    assign,
+    transfer,
+)
+from solders.transaction import VersionedTransaction
+
+TOKEN_PROGRAM_ID = Pubkey.from_string("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+WALLET = Keypair()
+
+
+def audit(*ixs: Instruction) -> List[InstructionAudit]:
+    msg = Message(list(ixs), WALLET.pubkey())
+    return audit_privileges(VersionedTransaction(msg, [WALLET]))
+
+
+def test_known_program_privileges() -> None:
+    to = Pubkey.new_unique()
+    params = TransferParams(from_pubkey=WALLET.pubkey(), to_pubkey=to, lamports=1)
+    [result] = audit(transfer(params))
+    assert result.index == 0
+    assert result.accounts == [
+        AccountPrivilege(WALLET.pubkey(), True, True),
+        AccountPrivilege(to, False, True),
+    ]
+    assert result.signers == [WALLET.pubkey()]
+    assert result.writable == [WALLET.pubkey(), to]
+    assert not result.is_suspicious
+
+
+def test_unknown_program_flags() -> None:
+    program, other = Pubkey.new_unique(), Pubkey.new_unique()
+    readonly = Instruction(program, b"", [AccountMeta(WALLET.pubkey(), True, False)])
+    no_signer = Instruction(program, b"", [AccountMeta(other, False, True)])
+    first, second = audit(readonly, no_signer)
+    assert first.flags == [
+        PrivilegeFlag.UnknownProgram,
+        PrivilegeFlag.SignerToUnknownProgram,
+    ]
+    assert second.flags == [PrivilegeFlag.UnknownProgram]
+
+
+def test_known_program_patterns() -> None:
+    owner = Pubkey.new_unique()
+    assign_ix = assign(AssignParams(pubkey=WALLET.pubkey(), owner=owner))
+    token_account, delegate = Pubkey.new_unique(), Pubkey.new_unique()
+    approve = Instruction(
+        TOKEN_PROGRAM_ID,
+        bytes([4]) + (5).to_bytes(8, "little"),
+        [
+            AccountMeta(token_account, False, True),
+            AccountMeta(delegate, False, False),
+            AccountMeta(WALLET.pubkey(), True, False),
+        ],
+    )
+    results = audit(assign_ix, approve)
+    assert [r.flags for r in results] == [
+        [PrivilegeFlag.SystemAssign],
+        [PrivilegeFlag.TokenApprove],
+    ]
+
+
+def test_invalid_message() -> None:
+    ix = CompiledInstruction(3, b"", bytes([0]))
+    msg = Message.new_with_compiled_instructions(
+        1, 0, 0, [WALLET.pubkey()], Hash.default(), [ix]
+    )
+    tx = VersionedTransaction.populate(msg, [Signature.default()])
+    with raises(ValueError):
+        audit_privileges(tx)
@@ -0,0 +1,58 @@
+from hashlib import sha256
+
+from solders.account import Account
+from solders.bank_hash import (
+    MERKLE_FANOUT,
+    account_hash,
+    accounts_delta_hash,
+    bank_hash,
+)
+from solders.hash import Hash
+from solders.pubkey import Pubkey
+
+OWNER = Pubkey.new_unique()
+
+
+def _accounts(n: int) -> dict:
+    return {Pubkey.new_unique(): Account(i + 1, bytes([i]), OWNER) for i in range(n)}
+
+
+def test_account_hash() -> None:
+    pubkey = Pubkey.new_unique()
+    account = Account(10, b"data", OWNER)
+    assert account_hash(pubkey, account) == account_hash(pubkey, account)
+    assert account_hash(pubkey, account) != account_hash(Pubkey.new_unique(), account)
+    assert account_hash(pubkey, account) != account_hash(
+        pubkey, Account(10, b"data", OWNER, rent_epoch=1)
+    )
+    assert account_hash(pubkey, Account(0, b"data", OWNER)) == Hash.default()
+
+
+def test_accounts_delta_hash() -> None:
+    assert accounts_delta_hash({}) == Hash(sha256().digest())
+    accounts = _accounts(MERKLE_FANOUT + 1)
+    leaves = [bytes(account_hash(k, accounts[k])) for k in sorted(accounts)]
+    level = [
+        sha256(b"".join(leaves[:MERKLE_FANOUT])).digest(),
+        sha256(leaves[MERKLE_FANOUT]).digest(),
+    ]
+    assert accounts_delta_hash(accounts) == Hash(sha256(b"".join(level)).digest())
+    few = dict(list(accounts.items())[:3])
+    few_leaves = [bytes(account_hash(k, few[k])) for k in sorted(few)]
+    assert accounts_delta_hash(few) == Hash(sha256(b"".join(few_leaves)).digest())
+
+
+def test_bank_hash() -> None:
+    parent, delta, blockhash = Hash.new_unique(), Hash.new_unique(), Hash.new_unique()
+    count = (7).to_bytes(8, "little")
+    base = sha256(bytes(parent) + bytes(delta) + count + bytes(blockhash)).digest()
+    assert bank_hash(parent, delta, 7, blockhash) == Hash(base)
+    epoch_accounts_hash = Hash.new_unique()
+    with_eah = sha256(base + bytes(epoch_accounts_hash)).digest()
+    assert bank_hash(
+        parent, delta, 7, blockhash, epoch_accounts_hash=epoch_accounts_hash
+    ) == Hash(with_eah)
+    forked = sha256(with_eah + (2).to_bytes(8, "little")).digest()
+    assert bank_hash(parent, delta, 7, blockhash, epoch_accounts_hash, 2) == Hash(
+        forked
+    )
@@ -0,0 +1,61 @@
+from pytest import mark, raises
+from solders.bench import (
+    MAX_THREADS,
+    BenchOperation,
+    BenchResult,
+    run_benchmark,
+    run_suite,
+)
+
+
+@mark.parametrize(
+    "operation",
+    [
+        BenchOperation.Serialize,
+        BenchOperation.Deserialize,
+        BenchOperation.Sign,
+        BenchOperation.Verify,
+    ],
+)
+def test_run_benchmark(operation: BenchOperation) -> None:
+    result = run_benchmark(operation, batch_size=5, threads=2, rounds=3)
+    assert result.operation == operation
+    assert (result.batch_size, result.threads, result.rounds) == (5, 2, 3)
+    assert result.operations == 30
+    assert result.seconds > 0
+    assert result.ops_per_second > 0
+
+
+def test_run_benchmark_rejects_zero() -> None:
+    for kwargs in [{"batch_size": 0}, {"threads": 0}, {"rounds": 0}]:
+        with raises(ValueError):
+            run_benchmark(BenchOperation.Sign, **kwargs)
+    with raises(ValueError, match="threads must be at most"):
+        run_benchmark(BenchOperation.Sign, threads=MAX_THREADS + 1)
+
+
+def test_run_suite() -> None:
+    results = run_suite([2, 4], threads=[1, 2], operations=[BenchOperation.Verify])
+    assert [(r.batch_size, r.threads) for r in results] == [
+        (2, 1),
+        (2, 2),
+        (4, 1),
+        (4, 2),
+    ]
+    assert len(run_suite([1])) == 4
+    with raises(ValueError):
+        run_suite([1], threads=[0])
+    with raises(ValueError):
+        run_suite([1], threads=[1, MAX_THREADS + 1])
+
+
+def test_speedup() -> None:
+    baseline = BenchResult(BenchOperation.Sign, 100, 1, 1, 1.0)
+    result = BenchResult(BenchOperation.Sign, 100, 4, 1, 2.0)
+    assert result.ops_per_second == 200.0
+    assert result.speedup(baseline) == 2.0
+
+
+def test_operation_strings() -> None:
+    assert str(BenchOperation.Deserialize) == "deserialize"
+    assert BenchOperation.from_string("verify") == BenchOperation.Verify
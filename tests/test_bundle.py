@@ -0,0 +1,81 @@
+from typing import List
+
+from pytest import mark, raises
+from solders.bundle import plan_transactions
+from solders.instruction import AccountMeta, Instruction
+from solders.pubkey import Pubkey
+from solders.system_program import TransferParams, transfer
+
+PAYER = Pubkey.new_unique()
+
+
+def _transfers(n: int) -> List[Instruction]:
+    return [
+        transfer(
+            TransferParams(
+                from_pubkey=PAYER, to_pubkey=Pubkey.new_unique(), lamports=1
+            )
+        )
+        for _ in range(n)
+    ]
+
+
+def _write(account: Pubkey, data: bytes = b"") -> Instruction:
+    return Instruction(
+        Pubkey.new_unique(), data, [AccountMeta(account, False, True)]
+    )
+
+
+def test_packs_by_size() -> None:
+    ixs = _transfers(50)
+    plan = plan_transactions(ixs, PAYER)
+    assert [i for p in plan for i in p.instruction_indexes] == list(range(50))
+    assert all(bytes(p.message) for p in plan)
+    assert [p.stage for p in plan] == [0, 1, 2]
+
+
+def test_account_limit() -> None:
+    plan = plan_transactions(_transfers(10), PAYER, max_accounts=5)
+    assert [len(p.instruction_indexes) for p in plan] == [3, 3, 3, 1]
+
+
+def test_dependencies_reorder() -> None:
+    ixs = _transfers(3)
+    plan = plan_transactions(ixs, PAYER, dependencies=[(2, 0)])
+    assert plan[0].instruction_indexes == [1, 2, 0]
+
+
+def test_write_lock_conflicts_get_later_stages() -> None:
+    shared = Pubkey.new_unique()
+    big = bytes(900)
+    ixs = [_write(shared, big), _write(Pubkey.new_unique(), big), _write(shared, big)]
+    plan = plan_transactions(ixs, PAYER)
+    assert [p.instruction_indexes for p in plan] == [[0], [1], [2]]
+    # every transaction write-locks the shared fee payer
+    assert [p.stage for p in plan] == [0, 1, 2]
+
+
+def test_dependency_across_transactions_gets_later_stage() -> None:
+    big = bytes(900)
+    ixs = [_write(Pubkey.new_unique(), big), _write(Pubkey.new_unique(), big)]
+    plan = plan_transactions(ixs, PAYER, dependencies=[(0, 1)])
+    assert [p.stage for p in plan] == [0, 1]
+
+
+@mark.parametrize(
+    "dependencies", [[(0, 1), (1, 0)], [(0, 5)]], ids=["cycle", "missing"]
+)
+def test_invalid_dependencies(dependencies) -> None:
+    with raises(ValueError):
+        plan_transactions(_transfers(2), PAYER, dependencies=dependencies)
+
+
+def test_oversized_instruction() -> None:
+    with raises(ValueError, match="instruction 0"):
+        plan_transactions([_write(Pubkey.new_unique(), bytes(1300))], PAYER)
+
+
+def test_jito_bundle_limit() -> None:
+    assert len(plan_transactions(_transfers(50), PAYER, jito_bundle=True)) == 3
+    with raises(ValueError, match="bundle"):
+        plan_transactions(_transfers(200), PAYER, jito_bundle=True)
@@ -0,0 +1,34 @@
+from solders.cluster import (
+    DEVNET_GENESIS_HASH,
+    MAINNET_BETA_GENESIS_HASH,
+    TESTNET_GENESIS_HASH,
+    Cluster,
+    detect_cluster,
+)
+from solders.hash import Hash
+from solders.rpc.responses import GetGenesisHashResp
+
+
+def test_detect_cluster() -> None:
+    for hash_, cluster in [
+        (MAINNET_BETA_GENESIS_HASH, Cluster.MainnetBeta),
+        (TESTNET_GENESIS_HASH, Cluster.Testnet),
+        (DEVNET_GENESIS_HASH, Cluster.Devnet),
+    ]:
+        assert detect_cluster(Hash.from_string(hash_)) == cluster
+        assert cluster.genesis_hash == Hash.from_string(hash_)
+    assert detect_cluster(Hash.new_unique()) is None
+    resp = GetGenesisHashResp(Hash.from_string(DEVNET_GENESIS_HASH))
+    assert detect_cluster(resp) == Cluster.Devnet
+
+
+def test_cluster_urls() -> None:
+    assert Cluster.MainnetBeta.rpc_url == "https://api.mainnet-beta.solana.com"
+    assert Cluster.Devnet.ws_url == "wss://api.devnet.solana.com"
+    assert Cluster.Localnet.rpc_url == "http://127.0.0.1:8899"
+    assert Cluster.Localnet.genesis_hash is None
+
+
+def test_cluster_string() -> None:
+    assert str(Cluster.MainnetBeta) == "mainnet-beta"
+    assert Cluster.from_string("devnet") == Cluster.Devnet
@@ -0,0 +1,65 @@
+import random
+
+from pytest import raises
+from solders.account import Account
+from solders.accounts_map import AccountsMap
+from solders.compression import (
+    compress,
+    compress_account,
+    compress_accounts,
+    decompress,
+    decompress_account,
+    decompress_accounts,
+    train_dictionary,
+)
+from solders.pubkey import Pubkey
+
+OWNER = Pubkey.new_unique()
+
+
+def _account(rng: random.Random) -> Account:
+    noise = bytes(rng.getrandbits(8) for _ in range(16))
+    data = b"\x01\x02layout" + bytes(40) + noise + bytes(64)
+    return Account(rng.randrange(10**9), data, OWNER, rent_epoch=361)
+
+
+def test_compress_roundtrip() -> None:
+    data = bytes(range(256)) * 40
+    compressed = compress(data, level=19)
+    assert len(compressed) < len(data)
+    assert decompress(compressed) == data
+    with raises(ValueError):
+        decompress(b"not zstd")
+
+
+def test_decompress_max_size() -> None:
+    bomb = compress(bytes(1_000_000))
+    assert len(bomb) < 1000
+    assert len(decompress(bomb, max_size=1_000_000)) == 1_000_000
+    with raises(ValueError, match="max_size"):
+        decompress(bomb, max_size=999_999)
+    account = compress_account(_account(random.Random(0)))
+    with raises(ValueError, match="max_size"):
+        decompress_account(account, max_size=10)
+
+
+def test_compress_account_roundtrip() -> None:
+    account = _account(random.Random(0))
+    assert decompress_account(compress_account(account)) == account
+
+
+def test_dictionary() -> None:
+    rng = random.Random(1)
+    accounts = [_account(rng) for _ in range(500)]
+    dictionary = train_dictionary([bytes(a) for a in accounts], max_size=4096)
+    account = _account(rng)
+    with_dict = compress_account(account, dictionary=dictionary)
+    assert len(with_dict) < len(compress_account(account))
+    assert decompress_account(with_dict, dictionary) == account
+
+
+def test_compress_accounts_roundtrip() -> None:
+    rng = random.Random(2)
+    accounts = AccountsMap({Pubkey.new_unique(): _account(rng) for _ in range(10)})
+    compressed = compress_accounts(accounts.to_dict())
+    assert AccountsMap(decompress_accounts(compressed)) == accounts
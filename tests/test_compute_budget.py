@@ -0,0 +1,78 @@
+from typing import List
+
+from pytest import raises
+from solders.compute_budget import (
+    ComputeBudgetLimits,
+    compute_budget_limits,
+    estimate_compute_unit_price,
+)
+from solders.hash import Hash
+from solders.instruction import Instruction
+from solders.message import Message, MessageV0
+from solders.pubkey import Pubkey
+from solders.rpc.responses import RpcPrioritizationFee
+
+COMPUTE_BUDGET_ID = Pubkey.from_string("ComputeBudget111111111111111111111111111111")
+
+
+def set_limit(units: int) -> Instruction:
+    return Instruction(COMPUTE_BUDGET_ID, bytes([2]) + units.to_bytes(4, "little"), [])
+
+
+def set_price(micro_lamports: int) -> Instruction:
+    return Instruction(
+        COMPUTE_BUDGET_ID, bytes([3]) + micro_lamports.to_bytes(8, "little"), []
+    )
+
+
+def request_heap(size: int) -> Instruction:
+    return Instruction(COMPUTE_BUDGET_ID, bytes([1]) + size.to_bytes(4, "little"), [])
+
+
+def other_ix() -> Instruction:
+    return Instruction(Pubkey.new_unique(), b"", [])
+
+
+def legacy(ixs: List[Instruction]) -> Message:
+    return Message(ixs, Pubkey.new_unique())
+
+
+def test_defaults() -> None:
+    assert compute_budget_limits(legacy([other_ix(), other_ix()])) == (
+        ComputeBudgetLimits(400_000, 0, None)
+    )
+    many = [other_ix() for _ in range(10)]
+    assert compute_budget_limits(legacy(many)).compute_unit_limit == 1_400_000
+
+
+def test_explicit_limit_and_price() -> None:
+    ixs = [set_limit(50_000), set_price(10), request_heap(64 * 1024), other_ix()]
+    limits = compute_budget_limits(legacy(ixs))
+    assert limits == ComputeBudgetLimits(50_000, 10, 64 * 1024)
+    assert limits.prioritization_fee() == 1
+    v0 = MessageV0.try_compile(Pubkey.new_unique(), ixs, [], Hash.default())
+    assert compute_budget_limits(v0) == limits
+
+
+def test_duplicate_instruction() -> None:
+    with raises(ValueError, match="duplicate instruction \\(1\\)"):
+        compute_budget_limits(legacy([set_price(1), set_price(2)]))
+
+
+def test_invalid_instruction_data() -> None:
+    with raises(ValueError, match="Instruction 0"):
+        compute_budget_limits(legacy([Instruction(COMPUTE_BUDGET_ID, b"\x09", [])]))
+    with raises(ValueError, match="Instruction 0"):
+        compute_budget_limits(legacy([request_heap(1000)]))
+
+
+def test_estimate_compute_unit_price() -> None:
+    fees = [RpcPrioritizationFee(slot, fee) for slot, fee in enumerate([300, 0, 100])]
+    assert estimate_compute_unit_price(fees) == 100
+    assert estimate_compute_unit_price(fees, 0) == 0
+    assert estimate_compute_unit_price(fees, 100) == 300
+    assert estimate_compute_unit_price([]) == 0
+    limits = ComputeBudgetLimits(200_000, estimate_compute_unit_price(fees, 90))
+    assert limits.prioritization_fee() == 60
+    with raises(ValueError):
+        estimate_compute_unit_price(fees, 101)
@@ -0,0 +1,66 @@
+from typing import List
+
+from pytest import raises
+from solders.cpi import preview_cpi
+from solders.hash import Hash
+from solders.instruction import AccountMeta, CompiledInstruction, Instruction
+from solders.message import Message
+from solders.pubkey import Pubkey
+from solders.system_program import ID as SYSTEM_PROGRAM_ID
+from solders.system_program import TransferParams, transfer
+
+PAYER = Pubkey.new_unique()
+ROUTER = Pubkey.new_unique()
+POOL = Pubkey.new_unique()
+UNKNOWN = Pubkey.new_unique()
+
+
+def test_preview_cpi_tree() -> None:
+    vault = Pubkey.new_unique()
+    pay = transfer(TransferParams(from_pubkey=PAYER, to_pubkey=vault, lamports=5))
+    seen: List[Instruction] = []
+
+    def router(ix: Instruction) -> List[Instruction]:
+        seen.append(ix)
+        return [Instruction(POOL, ix.data, ix.accounts)]
+
+    def pool(ix: Instruction) -> List[Instruction]:
+        return [pay, Instruction(UNKNOWN, b"", [])]
+
+    accounts = [AccountMeta(PAYER, True, True), AccountMeta(vault, False, True)]
+    msg = Message([Instruction(ROUTER, b"\x01", accounts), pay], PAYER)
+    router_tree, transfer_tree = preview_cpi(msg, {ROUTER: router, POOL: pool})
+    assert seen[0].accounts == accounts
+    assert seen[0].data == b"\x01"
+    assert router_tree.program_ids() == [ROUTER, POOL, SYSTEM_PROGRAM_ID, UNKNOWN]
+    [pool_node] = router_tree.children
+    assert pool_node.stack_height == 2
+    assert [c.stack_height for c in pool_node.children] == [3, 3]
+    assert not pool_node.children[1].resolved
+    assert not router_tree.is_fully_resolved()
+    assert transfer_tree.resolved and transfer_tree.children == []
+    assert transfer_tree.is_fully_resolved()
+
+
+def test_unresolved_without_parser() -> None:
+    [tree] = preview_cpi(Message([Instruction(UNKNOWN, b"", [])], PAYER), {})
+    assert not tree.resolved
+    assert tree.program_ids() == [UNKNOWN]
+
+
+def test_max_invoke_depth() -> None:
+    def recurse(ix: Instruction) -> List[Instruction]:
+        return [ix]
+
+    msg = Message([Instruction(ROUTER, b"", [])], PAYER)
+    with raises(ValueError, match="invoke depth"):
+        preview_cpi(msg, {ROUTER: recurse})
+
+
+def test_out_of_range_account_index() -> None:
+    ix = CompiledInstruction(1, b"", bytes([0, 5]))
+    msg = Message.new_with_compiled_instructions(
+        1, 0, 1, [PAYER, UNKNOWN], Hash.default(), [ix]
+    )
+    with raises(ValueError, match="account index 5"):
+        preview_cpi(msg, {})
@@ -0,0 +1,116 @@
+from typing import List
+
+from solders.hash import Hash
+from solders.instruction import AccountMeta, Instruction
+from solders.keypair import Keypair
+from solders.pubkey import Pubkey
+from solders.rpc.diagnostics import LandingReport, LandingVerdict, diagnose_landing
+from solders.rpc.responses import RpcPrioritizationFee, RpcSimulateTransactionResult
+from solders.transaction import Transaction, VersionedTransaction
+from solders.transaction_status import (
+    EncodedTransactionWithStatusMeta,
+    TransactionConfirmationStatus,
+    TransactionErrorFieldless,
+    TransactionStatus,
+    UiConfirmedBlock,
+)
+
+POOL = Pubkey.new_unique()
+PAYER = Keypair()
+
+
+def transaction(writable: List[Pubkey], payer: Keypair = PAYER) -> Transaction:
+    accounts = [AccountMeta(key, False, True) for key in writable]
+    ix = Instruction(Pubkey.new_unique(), b"", accounts)
+    return Transaction.new_signed_with_payer(
+        [ix], payer.pubkey(), [payer], Hash.new_unique()
+    )
+
+
+def block(txs: List[Transaction]) -> UiConfirmedBlock:
+    encoded = [
+        EncodedTransactionWithStatusMeta(
+            VersionedTransaction.from_bytes(bytes(tx)), None, None
+        )
+        for tx in txs
+    ]
+    return UiConfirmedBlock(Hash.default(), Hash.default(), 0, encoded)
+
+
+def test_landed() -> None:
+    tx = transaction([POOL])
+    status = TransactionStatus(
+        9, None, None, None, TransactionConfirmationStatus.Finalized
+    )
+    report = diagnose_landing(tx, 200, 150, status=status)
+    assert report.verdict == LandingVerdict.Landed
+    assert report.signature == tx.signatures[0]
+    assert report.explanation == "landed in slot 9"
+    err = TransactionErrorFieldless.AccountInUse
+    failed = TransactionStatus(
+        9, None, err, err, TransactionConfirmationStatus.Finalized
+    )
+    report = diagnose_landing(tx, 200, 150, status=failed)
+    assert report.verdict == LandingVerdict.FailedOnChain
+    assert report.error == err
+
+
+def test_preflight_and_still_valid() -> None:
+    tx = transaction([POOL])
+    err = TransactionErrorFieldless.InsufficientFundsForFee
+    preflight = RpcSimulateTransactionResult(err=err)
+    report = diagnose_landing(tx, 100, 150, preflight=preflight)
+    assert report.verdict == LandingVerdict.PreflightFailed
+    assert report.error == err
+    report = diagnose_landing(tx, 100, 150, preflight=RpcSimulateTransactionResult())
+    assert report.verdict == LandingVerdict.StillValid
+    assert not report.blockhash_expired
+    assert "50 more blocks" in report.explanation
+
+
+def test_fee_too_low() -> None:
+    fees = [RpcPrioritizationFee(slot, slot * 10) for slot in range(4)]
+    report = diagnose_landing(transaction([POOL]), 151, 150, prioritization_fees=fees)
+    assert report.verdict == LandingVerdict.FeeTooLow
+    assert report.compute_unit_price == 0
+    assert report.fee_percentile == 25.0
+    assert report.median_compute_unit_price == 10
+
+
+def test_write_lock_contention() -> None:
+    tx = transaction([POOL])
+    others = [
+        transaction([POOL], Keypair()),
+        transaction([Pubkey.new_unique()], Keypair()),
+    ]
+    fees = [RpcPrioritizationFee(0, 0)]
+    report = diagnose_landing(
+        tx, 151, 150, prioritization_fees=fees, recent_blocks=[block([tx, *others])]
+    )
+    assert report.verdict == LandingVerdict.WriteLockContention
+    assert report.contention_ratio == 0.5
+    assert report.contended_accounts == [(POOL, 1)]
+    assert report.fee_percentile == 100.0
+
+
+def test_blockhash_expired() -> None:
+    tx = transaction([POOL])
+    quiet = [transaction([Pubkey.new_unique()], Keypair()) for _ in range(3)]
+    report = diagnose_landing(tx, 151, 150, recent_blocks=[block(quiet)])
+    assert report.verdict == LandingVerdict.BlockhashExpired
+    assert report.blockhash_expired
+    assert report.contention_ratio == 0.0
+    assert report.contended_accounts == []
+    assert report.fee_percentile is None
+    assert report == LandingReport(
+        report.signature,
+        report.verdict,
+        report.explanation,
+        None,
+        True,
+        0,
+        None,
+        None,
+        0.0,
+        [],
+    )
@@ -0,0 +1,97 @@
+from pytest import raises
+from solders.display import (
+    format_transaction,
+    keypair_from_json,
+    keypair_to_json,
+    pubkey_from_json,
+    pubkey_to_json,
+)
+from solders.hash import Hash
+from solders.instruction import CompiledInstruction
+from solders.keypair import Keypair
+from solders.message import Message
+from solders.pubkey import Pubkey
+from solders.system_program import TransferParams, transfer
+from solders.transaction import VersionedTransaction
+from solders.transaction_status import UiTransactionStatusMeta
+
+
+def test_keygen_files() -> None:
+    kp = Keypair.from_seed(bytes(range(32)))
+    raw = keypair_to_json(kp)
+    assert raw.startswith("[0,1,2,")
+    assert " " not in raw
+    assert keypair_from_json(raw) == kp
+    pubkey = kp.pubkey()
+    assert pubkey_to_json(pubkey) == f'"{pubkey}"'
+    assert pubkey_from_json(pubkey_to_json(pubkey)) == pubkey
+    with raises(ValueError):
+        keypair_from_json("[1,2,3]")
+    with raises(ValueError):
+        pubkey_from_json(str(pubkey))
+
+
+def test_format_transaction() -> None:
+    payer = Keypair()
+    recipient = Pubkey.new_unique()
+    ix = transfer(
+        TransferParams(from_pubkey=payer.pubkey(), to_pubkey=recipient, lamports=1000)
+    )
+    tx = VersionedTransaction(Message([ix], payer.pubkey()), [payer])
+    logs = [
+        "Program 11111111111111111111111111111111 invoke [1]",
+        "Program 11111111111111111111111111111111 success",
+    ]
+    meta = UiTransactionStatusMeta(
+        err=None,
+        fee=5000,
+        pre_balances=[1_000_000_000, 0, 1],
+        post_balances=[999_994_000, 1000, 1],
+        inner_instructions=None,
+        log_messages=logs,
+        pre_token_balances=None,
+        post_token_balances=None,
+        rewards=None,
+        loaded_addresses=None,
+        return_data=None,
+    )
+    expected = f"""  Version: legacy
+  Recent Blockhash: 11111111111111111111111111111111
+  Signature 0: {tx.signatures[0]}
+  Account 0: srw- {payer.pubkey()} (fee payer)
+  Account 1: -rw- {recipient}
+  Account 2: -r-x 11111111111111111111111111111111
+  Instruction 0
+    Program:   11111111111111111111111111111111 (2)
+    Account 0: {payer.pubkey()} (0)
+    Account 1: {recipient} (1)
+    Transfer {{ lamports: 1000 }}
+  Status: Ok
+    Fee: ◎0.000005
+    Account 0 balance: ◎1 -> ◎0.999994
+    Account 1 balance: ◎0 -> ◎0.000001
+    Account 2 balance: ◎0.000000001
+  Log Messages:
+    {logs[0]}
+    {logs[1]}
+"""
+    assert format_transaction(tx, meta, prefix="  ") == expected
+
+
+def test_format_transaction_block_time() -> None:
+    payer = Keypair()
+    tx = VersionedTransaction(Message([], payer.pubkey()), [payer])
+    first = format_transaction(tx, block_time=0).splitlines()[0]
+    assert first.startswith("Block Time: 19")
+    assert first[-6] in "+-"
+
+
+def test_format_transaction_out_of_range_account_index() -> None:
+    payer = Keypair()
+    ix = CompiledInstruction(0, b"", bytes([0, 5]))
+    msg = Message.new_with_compiled_instructions(
+        1, 0, 0, [payer.pubkey()], Hash.default(), [ix]
+    )
+    tx = VersionedTransaction.populate(msg, [payer.sign_message(bytes(msg))])
+    with raises(ValueError, match="account index 5"):
+        format_transaction(tx)
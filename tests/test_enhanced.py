@@ -0,0 +1,106 @@
+import json
+
+from pytest import raises
+from solders.enhanced import (
+    EnhancedTransaction,
+    NativeTransfer,
+    RawTokenAmount,
+    parse_enhanced_transactions,
+)
+from solders.pubkey import Pubkey
+from solders.signature import Signature
+
+PAYER = Pubkey.new_unique()
+RECIPIENT = Pubkey.new_unique()
+MINT = Pubkey.new_unique()
+TOKEN_ACCOUNT = Pubkey.new_unique()
+SYSTEM_PROGRAM = "11111111111111111111111111111111"
+SIG = Signature.new_unique()
+
+PAYLOAD = [
+    {
+        "description": "sent 0.1 SOL",
+        "type": "TRANSFER",
+        "source": "SYSTEM_PROGRAM",
+        "fee": 5000,
+        "feePayer": str(PAYER),
+        "signature": str(SIG),
+        "slot": 171942732,
+        "timestamp": 1673445241,
+        "nativeTransfers": [
+            {
+                "fromUserAccount": str(PAYER),
+                "toUserAccount": str(RECIPIENT),
+                "amount": 100000000,
+            }
+        ],
+        "tokenTransfers": [
+            {
+                "fromUserAccount": "",
+                "toUserAccount": str(RECIPIENT),
+                "fromTokenAccount": "",
+                "toTokenAccount": str(TOKEN_ACCOUNT),
+                "tokenAmount": 1.5,
+                "mint": str(MINT),
+                "tokenStandard": "Fungible",
+            }
+        ],
+        "accountData": [
+            {
+                "account": str(TOKEN_ACCOUNT),
+                "nativeBalanceChange": -5000,
+                "tokenBalanceChanges": [
+                    {
+                        "userAccount": str(RECIPIENT),
+                        "tokenAccount": str(TOKEN_ACCOUNT),
+                        "mint": str(MINT),
+                        "rawTokenAmount": {"tokenAmount": "150", "decimals": 2},
+                    }
+                ],
+            }
+        ],
+        "transactionError": None,
+        "instructions": [
+            {
+                "accounts": [str(PAYER), str(RECIPIENT)],
+                "data": "3Bxs411Dtc7pkFQj",
+                "programId": SYSTEM_PROGRAM,
+                "innerInstructions": [],
+            }
+        ],
+        "events": {},
+    }
+]
+
+
+def test_parse_enhanced_transactions() -> None:
+    [tx] = parse_enhanced_transactions(json.dumps(PAYLOAD))
+    assert tx.transaction_type == "TRANSFER"
+    assert tx.fee_payer == PAYER
+    assert tx.signature == SIG
+    assert tx.native_transfers == [NativeTransfer(PAYER, RECIPIENT, 100000000)]
+    [token_transfer] = tx.token_transfers
+    assert token_transfer.from_user_account is None
+    assert token_transfer.to_token_account == TOKEN_ACCOUNT
+    assert token_transfer.token_amount == 1.5
+    [change] = tx.account_data[0].token_balance_changes
+    assert change.raw_token_amount == RawTokenAmount("150", 2)
+    assert tx.instructions[0].decoded_data() == bytes([2, 0, 0, 0]) + (
+        100000000
+    ).to_bytes(8, "little")
+    assert tx.transaction_error is None
+    assert not tx.is_err()
+    assert EnhancedTransaction.from_bytes(bytes(tx)) == tx
+    assert EnhancedTransaction.from_json(tx.to_json()) == tx
+
+
+def test_transaction_error() -> None:
+    failed = dict(PAYLOAD[0], transactionError={"error": "custom program error"})
+    tx = EnhancedTransaction.from_json(json.dumps(failed))
+    assert tx.is_err()
+    assert tx.transaction_error == "custom program error"
+
+
+def test_invalid_payload() -> None:
+    with raises(ValueError):
+        parse_enhanced_transactions(json.dumps([{"type": "TRANSFER"}]))
@@ -0,0 +1,71 @@
+from hashlib import sha256
+
+from pytest import raises
+from solders.entry import (
+    Entry,
+    entries_from_bytes,
+    hash_transactions,
+    next_hash,
+    verify_entries,
+)
+from solders.hash import Hash
+from solders.keypair import Keypair
+from solders.message import Message
+from solders.transaction import VersionedTransaction
+
+
+def _tx() -> VersionedTransaction:
+    payer = Keypair()
+    return VersionedTransaction(Message([], payer.pubkey()), [payer])
+
+
+def test_tick_matches_repeated_sha256() -> None:
+    start = Hash.new_unique()
+    expected = bytes(start)
+    for _ in range(5):
+        expected = sha256(expected).digest()
+    tick = Entry.new_tick(5, start)
+    assert tick.hash == Hash(expected)
+    assert tick.is_tick()
+    assert tick.verify(start)
+    assert not tick.verify(Hash.default())
+    assert next_hash(start, 0, []) == start
+
+
+def test_transaction_mixin() -> None:
+    txs = [_tx(), _tx(), _tx()]
+    leaves = [sha256(b"\x00" + bytes(tx.signatures[0])).digest() for tx in txs]
+    left = sha256(b"\x01" + leaves[0] + leaves[1]).digest()
+    right = sha256(b"\x01" + leaves[2] + leaves[2]).digest()
+    root = sha256(b"\x01" + left + right).digest()
+    assert hash_transactions(txs) == Hash(root)
+    assert hash_transactions([]) == Hash.default()
+    start = Hash.new_unique()
+    expected = sha256(sha256(bytes(start)).digest() + root).digest()
+    entry = Entry(2, Hash(expected), txs)
+    assert not entry.is_tick()
+    assert entry.verify(start)
+    assert entry.transactions == txs
+
+
+def test_verify_entries() -> None:
+    start = Hash.new_unique()
+    first = Entry.new_tick(3, start)
+    txs = [_tx()]
+    second = Entry(1, next_hash(first.hash, 1, txs), txs)
+    third = Entry.new_tick(2, second.hash)
+    assert verify_entries([first, second, third], start) is None
+    tampered = Entry(1, second.hash, [_tx()])
+    assert verify_entries([first, tampered, third], start) == 1
+    assert verify_entries([], start) is None
+
+
+def test_entries_bytes_roundtrip() -> None:
+    start = Hash.new_unique()
+    txs = [_tx()]
+    entry = Entry(4, next_hash(start, 4, txs), txs)
+    assert Entry.from_bytes(bytes(entry)) == entry
+    raw = len([entry]).to_bytes(8, "little") + bytes(entry)
+    assert entries_from_bytes(raw) == [entry]
+    with raises(ValueError):
+        entries_from_bytes(b"\x01")
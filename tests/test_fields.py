@@ -0,0 +1,51 @@
+from pytest import raises
+from solders.account_decoder import UiAccountEncoding
+from solders.commitment_config import CommitmentLevel
+from solders.pubkey import Pubkey
+from solders.rpc.fields import AccountField, AccountFields
+from solders.rpc.filter import Memcmp
+from solders.rpc.requests import GetAccountInfo, GetProgramAccounts
+
+
+def test_slice_covers_fields() -> None:
+    fields = AccountFields([AccountField("a", 40, 8), AccountField("b", 8, 4)])
+    assert (fields.data_slice.offset, fields.data_slice.length) == (8, 40)
+    assert fields.transfer_size == 40
+    assert fields.encoding() == UiAccountEncoding.Base64
+    assert fields.encoding(zstd_threshold=40) == UiAccountEncoding.Base64Zstd
+    data = bytes(range(40))
+    assert fields.extract(data) == {"a": data[32:40], "b": data[0:4]}
+    with raises(ValueError):
+        fields.extract(data[:39])
+
+
+def test_whole_data() -> None:
+    assert AccountFields().data_slice is None
+    assert AccountFields().encoding() == UiAccountEncoding.Base64Zstd
+    assert AccountFields(data_size=64).encoding() == UiAccountEncoding.Base64
+    assert AccountFields().extract(b"abc") == {}
+    nothing = AccountFields([])
+    assert (nothing.data_slice.offset, nothing.data_slice.length) == (0, 0)
+
+
+def test_known_layouts() -> None:
+    mint = AccountFields.mint(["decimals"])
+    assert (mint.data_slice.offset, mint.data_slice.length) == (44, 1)
+    assert AccountFields.token_account().data_size == 165
+    with raises(ValueError):
+        AccountFields.token_account(["decimals"])
+    with raises(ValueError):
+        AccountFields([AccountField("x", 80, 8)], data_size=82)
+
+
+def test_request_builders() -> None:
+    fields = AccountFields.token_account(["amount"])
+    request = GetAccountInfo.for_fields(
+        Pubkey.default(), fields, CommitmentLevel.Confirmed, id=2
+    )
+    assert request.config == fields.account_info_config(CommitmentLevel.Confirmed)
+    assert request.id == 2
+    owner = Memcmp(offset=32, bytes_=bytes(Pubkey.default()))
+    scan = GetProgramAccounts.for_fields(Pubkey.default(), fields, [165, owner])
+    assert scan.config.filters[0] == 165
+    assert scan.config.account_config.data_slice.offset == 64
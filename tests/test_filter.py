@@ -0,0 +1,53 @@
+from pytest import raises
+from solders.account import Account
+from solders.pubkey import Pubkey
+from solders.rpc.filter import (
+    AccountFilter,
+    Memcmp,
+    METADATA_UPDATE_AUTHORITY_OFFSET,
+    STAKE_AUTHORIZED_WITHDRAWER_OFFSET,
+    TOKEN_ACCOUNT_MINT_OFFSET,
+    TOKEN_ACCOUNT_OWNER_OFFSET,
+    memcmp_matches,
+)
+
+
+def test_memcmp_matches_token_account() -> None:
+    mint, owner = Pubkey.new_unique(), Pubkey.new_unique()
+    data = bytes(mint) + bytes(owner) + bytes(101)
+    assert memcmp_matches(data, TOKEN_ACCOUNT_MINT_OFFSET, str(mint))
+    assert memcmp_matches(data, TOKEN_ACCOUNT_OWNER_OFFSET, list(bytes(owner)))
+    assert not memcmp_matches(data, TOKEN_ACCOUNT_OWNER_OFFSET, str(mint))
+
+
+def test_memcmp_matches_offsets() -> None:
+    withdrawer = Pubkey.new_unique()
+    stake = bytes(STAKE_AUTHORIZED_WITHDRAWER_OFFSET) + bytes(withdrawer)
+    assert memcmp_matches(stake, STAKE_AUTHORIZED_WITHDRAWER_OFFSET, str(withdrawer))
+    authority = Pubkey.new_unique()
+    metadata = bytes([4]) + bytes(authority) + bytes(32)
+    assert memcmp_matches(metadata, METADATA_UPDATE_AUTHORITY_OFFSET, str(authority))
+
+
+def test_memcmp_matches_out_of_bounds() -> None:
+    assert not memcmp_matches(bytes(4), 2, [0, 0, 0])
+    assert not memcmp_matches(bytes(4), 5, [])
+    assert memcmp_matches(bytes(4), 4, [])
+    assert not memcmp_matches(bytes(4), 0, "0OIl")
+
+
+def test_account_filter() -> None:
+    owner = Pubkey.new_unique()
+    account = Account(1, b"abcdef", owner)
+    assert AccountFilter().matches(account)
+    assert AccountFilter(owner).matches(account)
+    assert not AccountFilter(Pubkey.new_unique()).matches(account)
+    assert AccountFilter(min_data_len=6, max_data_len=6).matches(account)
+    assert not AccountFilter(min_data_len=7).matches(account)
+    assert not AccountFilter(max_data_len=5).matches(account)
+    memcmp = [Memcmp(0, b"ab"), Memcmp(4, b"ef")]
+    assert AccountFilter(memcmp=memcmp).matches(account)
+    assert not AccountFilter(memcmp=[*memcmp, Memcmp(5, b"ff")]).matches(account)
+    assert AccountFilter(owner, memcmp=memcmp).memcmp == memcmp
+    with raises(ValueError, match="greater than max_data_len"):
+        AccountFilter(min_data_len=7, max_data_len=6)
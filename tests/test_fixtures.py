@@ -0,0 +1,58 @@
+import json
+
+from pytest import raises
+from solders.fixtures import TestAddressAllocator
+from solders.pubkey import Pubkey
+
+USDC = Pubkey.from_string("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v")
+
+
+def test_stable_addresses() -> None:
+    first, second = TestAddressAllocator("suite"), TestAddressAllocator("suite")
+    assert first.pubkey("alice") == second.pubkey("alice")
+    assert first.pubkey("alice") == first.pubkey("alice")
+    assert first.pubkey("alice") != first.pubkey("bob")
+    assert first.pubkey("alice") != TestAddressAllocator("other").pubkey("alice")
+    assert first.keypair("alice").pubkey() == first.pubkey("alice")
+    assert first.keypair("carol").pubkey() == first.pubkey("carol")
+    assert len(first) == 3
+    assert "bob" in first and "dave" not in first
+    assert TestAddressAllocator().namespace == "solders"
+    with raises(ValueError, match="can't contain"):
+        TestAddressAllocator("a:b")
+
+
+def test_register_and_collisions() -> None:
+    addresses = TestAddressAllocator()
+    assert addresses.register("usdc-mint", USDC) == USDC
+    addresses.register("usdc-mint", USDC)
+    assert addresses.pubkey("usdc-mint") == USDC
+    with raises(ValueError, match="no keypair"):
+        addresses.keypair("usdc-mint")
+    with raises(ValueError, match="already labeled"):
+        addresses.register("usdc", USDC)
+    with raises(ValueError, match="is already"):
+        addresses.register("usdc-mint", Pubkey.new_unique())
+    alice = addresses.pubkey("alice")
+    with raises(ValueError, match="already labeled"):
+        addresses.register("alias", alice)
+    with raises(ValueError, match="empty"):
+        addresses.pubkey("")
+
+
+def test_lookup_and_manifest() -> None:
+    addresses = TestAddressAllocator()
+    vault = addresses.pubkey("pool-vault")
+    addresses.register("usdc-mint", USDC)
+    stranger = Pubkey.new_unique()
+    assert addresses.label_of(vault) == "pool-vault"
+    assert addresses.label_of(stranger) is None
+    assert (
+        addresses.annotate(f"[{vault}, {stranger}]: {USDC}.")
+        == f"[pool-vault, {stranger}]: usdc-mint."
+    )
+    assert addresses.manifest() == {"pool-vault": vault, "usdc-mint": USDC}
+    assert json.loads(addresses.manifest_json()) == {
+        "pool-vault": str(vault),
+        "usdc-mint": str(USDC),
+    }
@@ -0,0 +1,96 @@
+from solders.hash import Hash
+from solders.rpc.forks import (
+    BlockChainIssue,
+    BlockChainIssueKind,
+    ForkTracker,
+    SlotStatus,
+    verify_block_chain,
+)
+from solders.rpc.responses import (
+    SlotInfo,
+    SlotUpdateCompleted,
+    SlotUpdateCreatedBank,
+    SlotUpdateDead,
+    SlotUpdateOptimisticConfirmation,
+    SlotUpdateRoot,
+)
+from solders.transaction_status import UiConfirmedBlock
+
+
+def test_fork_tracker() -> None:
+    tracker = ForkTracker()
+    for slot, parent in [(11, 10), (12, 11), (13, 11), (14, 13)]:
+        tracker.update(SlotUpdateCreatedBank(slot, 0, parent))
+    tracker.update(SlotUpdateCompleted(15, 0))
+    assert tracker.status(12) == SlotStatus.Processed
+    assert tracker.status(16) is None
+    tracker.update(SlotUpdateOptimisticConfirmation(14, 0))
+    assert tracker.status(14) == SlotStatus.Confirmed
+    assert tracker.status(13) == SlotStatus.Confirmed
+    assert tracker.status(11) == SlotStatus.Confirmed
+    assert tracker.status(12) == SlotStatus.Processed
+    tracker.update(SlotUpdateCompleted(14, 0))
+    assert tracker.status(14) == SlotStatus.Confirmed
+    tracker.update(SlotUpdateRoot(13, 0))
+    assert tracker.root == 13
+    assert tracker.status(13) == SlotStatus.Finalized
+    assert tracker.status(10) == SlotStatus.Finalized
+    assert tracker.status(12) == SlotStatus.Abandoned
+    assert tracker.status(14) == SlotStatus.Confirmed
+    assert tracker.status(15) == SlotStatus.Processed
+    tracker.update(SlotUpdateDead(15, 0, "dead"))
+    assert tracker.status(15) == SlotStatus.Abandoned
+    tracker.update(SlotUpdateRoot(12, 0))
+    assert tracker.status(12) == SlotStatus.Abandoned
+    assert tracker.root == 13
+    tracker.prune(13)
+    assert tracker.status(11) is None
+    assert len(tracker) == 3
+
+
+def test_slot_subscribe() -> None:
+    tracker = ForkTracker()
+    tracker.update(SlotInfo(100, 99, 68))
+    tracker.update(SlotInfo(101, 99, 69))
+    assert tracker.status(100) == SlotStatus.Processed
+    assert tracker.status(69) == SlotStatus.Finalized
+    assert tracker.root == 69
+    assert str(SlotStatus.Abandoned) == "abandoned"
+
+
+def _block(previous: Hash, blockhash: Hash, parent_slot: int) -> UiConfirmedBlock:
+    return UiConfirmedBlock(previous, blockhash, parent_slot)
+
+
+def test_verify_block_chain() -> None:
+    hashes = [Hash.new_unique() for _ in range(6)]
+    chain = [
+        (100, _block(hashes[0], hashes[1], 99)),
+        (101, _block(hashes[1], hashes[2], 100)),
+        (104, _block(hashes[2], hashes[3], 101)),
+    ]
+    assert verify_block_chain(chain) == []
+    assert verify_block_chain(chain[:1]) == []
+    assert verify_block_chain([]) == []
+    # 105 and 106 were produced but are missing from the sequence
+    gap = (107, _block(hashes[4], hashes[5], 106))
+    assert verify_block_chain(chain + [gap]) == [
+        BlockChainIssue(107, BlockChainIssueKind.Gap, 104, 106, hashes[3], hashes[4])
+    ]
+    assert verify_block_chain(chain + [gap])[0].missing_range == (105, 106)
+
+
+def test_verify_block_chain_mismatches() -> None:
+    a, b, c = Hash.new_unique(), Hash.new_unique(), Hash.new_unique()
+    first = (10, _block(Hash.default(), a, 9))
+    wrong_hash = (11, _block(c, b, 10))
+    fork = (12, _block(Hash.default(), c, 9))
+    repeated = (10, _block(a, b, 10))
+    issues = verify_block_chain([first, wrong_hash])
+    assert [i.kind for i in issues] == [BlockChainIssueKind.HashMismatch]
+    assert issues[0].missing_range is None
+    issues = verify_block_chain([first, fork])
+    assert [i.kind for i in issues] == [BlockChainIssueKind.Fork]
+    issues = verify_block_chain([first, repeated])
+    assert [i.kind for i in issues] == [BlockChainIssueKind.OutOfOrder]
+    assert str(BlockChainIssueKind.HashMismatch) == "hashMismatch"
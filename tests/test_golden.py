@@ -0,0 +1,36 @@
+import json
+
+from pytest import raises
+from solders.golden import diff_golden_vectors, dump_golden_vectors, golden_vectors
+from solders.pubkey import Pubkey
+from solders.transaction import VersionedTransaction
+
+
+def test_golden_vectors_are_deterministic() -> None:
+    first, second = golden_vectors(), golden_vectors()
+    names = [v.name for v in first]
+    assert names == sorted(names)
+    assert [(v.serialized, v.json) for v in first] == [
+        (v.serialized, v.json) for v in second
+    ]
+    assert dump_golden_vectors() == dump_golden_vectors()
+
+
+def test_golden_vector_values() -> None:
+    vectors = {v.name: v for v in golden_vectors()}
+    pubkey = vectors["pubkey"]
+    assert pubkey.serialized == bytes(pubkey.value)
+    assert isinstance(pubkey.value, Pubkey)
+    tx = vectors["versioned_transaction"]
+    assert VersionedTransaction.from_bytes(tx.serialized) == tx.value
+    assert tx.value.verify_with_results() == [True]
+
+
+def test_diff_golden_vectors() -> None:
+    stored = json.loads(dump_golden_vectors())
+    stored["hash"]["bytes"] = "AAAA"
+    stored["removed_type"] = stored["pubkey"]
+    del stored["rent"]
+    assert diff_golden_vectors(json.dumps(stored)) == ["hash", "removed_type"]
+    with raises(ValueError):
+        diff_golden_vectors("not json")
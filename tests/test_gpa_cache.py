@@ -0,0 +1,90 @@
+from solders.account import Account
+from solders.pubkey import Pubkey
+from solders.rpc.filter import Memcmp
+from solders.rpc.gpa_cache import ProgramAccountsCache
+from solders.rpc.responses import (
+    GetProgramAccountsWithContextResp,
+    ProgramNotification,
+    ProgramNotificationResult,
+    RpcKeyedAccount,
+    RpcResponseContext,
+)
+
+PROGRAM = Pubkey.new_unique()
+TAG = Memcmp(offset=0, bytes_=[1])
+OTHER_PROGRAM = Pubkey.new_unique()
+
+
+def notification(slot: int, pubkey: Pubkey, account: Account) -> ProgramNotification:
+    keyed = RpcKeyedAccount(pubkey, account)
+    return ProgramNotification(
+        ProgramNotificationResult(keyed, RpcResponseContext(slot)), 0
+    )
+
+
+def test_delta_refresh() -> None:
+    tagged, other = Pubkey.new_unique(), Pubkey.new_unique()
+    cache = ProgramAccountsCache(PROGRAM)
+    resp = GetProgramAccountsWithContextResp(
+        [RpcKeyedAccount(tagged, Account(1, bytes([1, 0]), PROGRAM))],
+        RpcResponseContext(10),
+    )
+    cache.load(resp, [2, TAG])
+    cache.load(resp)
+    assert len(cache) == 2
+    assert cache.apply(notification(11, other, Account(5, bytes([2, 0]), PROGRAM)))
+    filtered = cache.snapshot([2, TAG])
+    assert filtered is not None
+    assert filtered.slot == 11
+    assert [a.pubkey for a in filtered.accounts] == [tagged]
+    unfiltered = cache.snapshot()
+    assert unfiltered is not None
+    assert len(unfiltered) == 2
+    assert unfiltered.get(other) == Account(5, bytes([2, 0]), PROGRAM)
+    # the account no longer matches the memcmp filter
+    assert cache.apply(notification(12, tagged, Account(1, bytes([2, 0]), PROGRAM)))
+    filtered = cache.snapshot([2, TAG])
+    assert filtered is not None
+    assert filtered.get(tagged) is None
+
+
+def test_closed_and_stale() -> None:
+    key = Pubkey.new_unique()
+    cache = ProgramAccountsCache(PROGRAM)
+    cache.load(
+        GetProgramAccountsWithContextResp(
+            [RpcKeyedAccount(key, Account(1, b"", PROGRAM))], RpcResponseContext(10)
+        )
+    )
+    assert not cache.apply(notification(9, key, Account(0, b"", Pubkey.default())))
+    assert cache.apply(notification(10, key, Account(0, b"", Pubkey.default())))
+    snapshot = cache.snapshot()
+    assert snapshot is not None
+    assert snapshot.accounts == []
+    assert cache.snapshot([8]) is None
+    assert cache.invalidate()
+    assert cache.snapshot() is None
+
+
+def test_account_stops_matching() -> None:
+    key = Pubkey.new_unique()
+    cache = ProgramAccountsCache(PROGRAM)
+    resp = GetProgramAccountsWithContextResp(
+        [RpcKeyedAccount(key, Account(1, bytes([1, 0]), PROGRAM))],
+        RpcResponseContext(10),
+    )
+    cache.load(resp, [2])
+    cache.load(resp, [TAG])
+    # resized, so it only leaves the data size snapshot
+    assert cache.apply(notification(11, key, Account(1, bytes([1, 0, 0]), PROGRAM)))
+    sized = cache.snapshot([2])
+    tagged = cache.snapshot([TAG])
+    assert sized is not None and tagged is not None
+    assert sized.accounts == []
+    assert tagged.get(key) == Account(1, bytes([1, 0, 0]), PROGRAM)
+    # reassigned to another program, so it leaves every snapshot
+    reassigned = Account(1, bytes([1, 0, 0]), OTHER_PROGRAM)
+    assert cache.apply(notification(12, key, reassigned))
+    tagged = cache.snapshot([TAG])
+    assert tagged is not None
+    assert tagged.accounts == []
@@ -0,0 +1,130 @@
+from typing import Optional
+
+from pytest import raises
+from solders.idempotency import (
+    IDEMPOTENCY_MEMO_PREFIX,
+    find_tagged,
+    idempotency_memo,
+    tag_keypair,
+    verify_tagged,
+)
+from solders.keypair import Keypair
+from solders.hash import Hash
+from solders.instruction import AccountMeta, Instruction
+from solders.message import Message
+from solders.pubkey import Pubkey
+from solders.rpc.responses import RpcConfirmedTransactionStatusWithSignature
+from solders.signature import Signature
+from solders.transaction import VersionedTransaction
+from solders.transaction_status import (
+    EncodedConfirmedTransactionWithStatusMeta,
+    EncodedTransactionWithStatusMeta,
+    TransactionErrorFieldless,
+    TransactionErrorType,
+    UiTransactionStatusMeta,
+)
+
+SECRET = bytes(range(32))
+MEMO_PROGRAM = "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+
+
+def status(
+    memo: Optional[str], err: Optional[TransactionErrorFieldless] = None
+) -> RpcConfirmedTransactionStatusWithSignature:
+    return RpcConfirmedTransactionStatusWithSignature(
+        Signature.new_unique(), 1, err, memo, None, None
+    )
+
+
+def test_memo() -> None:
+    ix = idempotency_memo("payout-1")
+    assert str(ix.program_id) == MEMO_PROGRAM
+    assert bytes(ix.data) == IDEMPOTENCY_MEMO_PREFIX.encode() + b"payout-1"
+    assert ix.accounts == []
+    with raises(ValueError):
+        idempotency_memo("")
+
+
+def test_tag_keypair() -> None:
+    tag = tag_keypair(SECRET, "payout-1")
+    assert tag == tag_keypair(SECRET, "payout-1")
+    assert tag != tag_keypair(SECRET, "payout-2")
+    assert tag != tag_keypair(bytes(32), "payout-1")
+    # moving bytes between the secret and the key changes the seed
+    assert tag_keypair(SECRET + b"p", "ayout-1") != tag
+    with raises(ValueError):
+        tag_keypair(SECRET[:31], "payout-1")
+    with raises(ValueError):
+        tag_keypair(SECRET, "")
+
+
+def test_tagged_transaction_signs() -> None:
+    payer = Keypair()
+    tag = tag_keypair(SECRET, "payout-1")
+    ix = idempotency_memo("payout-1", tag.pubkey())
+    assert ix.accounts[0].pubkey == tag.pubkey()
+    assert ix.accounts[0].is_signer
+    tx = VersionedTransaction(Message([ix], payer.pubkey()), [payer, tag])
+    assert tag.pubkey() in tx.message.account_keys
+    assert tx.verify_with_results() == [True, True]
+
+
+def test_find_tagged() -> None:
+    tagged = status("[20] idempotency:payout-1")
+    statuses = [status(None), status("[6] other!"), tagged]
+    assert find_tagged(statuses, "payout-1") == [tagged]
+    assert find_tagged(statuses, "payout-") == []
+    assert find_tagged(statuses, "payout-2") == []
+    impostor = status("[20] idempotency:payout-1")
+    assert find_tagged([impostor, tagged], "payout-1") == [impostor, tagged]
+
+
+def test_find_tagged_multiple_memos() -> None:
+    # the separator inside a memo must not split it
+    tagged = status("[4] a; b; [20] idempotency:payout-1")
+    assert find_tagged([tagged], "payout-1") == [tagged]
+    assert find_tagged([status("[4] a; b")], "b") == []
+
+
+def test_find_tagged_skips_failed() -> None:
+    failed = status("[20] idempotency:payout-1", TransactionErrorFieldless.AccountInUse)
+    assert find_tagged([failed], "payout-1") == []
+
+
+def fetched(
+    tx: VersionedTransaction, err: Optional[TransactionErrorType] = None
+) -> EncodedConfirmedTransactionWithStatusMeta:
+    meta = UiTransactionStatusMeta(
+        err, 5000, [], [], None, None, None, None, None, None, None
+    )
+    return EncodedConfirmedTransactionWithStatusMeta(
+        1, EncodedTransactionWithStatusMeta(tx, meta, None)
+    )
+
+
+def test_verify_tagged() -> None:
+    payer = Keypair()
+    tag = tag_keypair(SECRET, "payout-1")
+    ix = idempotency_memo("payout-1", tag.pubkey())
+    message = Message.new_with_blockhash([ix], payer.pubkey(), Hash.default())
+    real = fetched(VersionedTransaction(message, [payer, tag]))
+    assert verify_tagged(real, "payout-1", tag.pubkey())
+    assert verify_tagged(real, "payout-1", payer.pubkey())
+    assert not verify_tagged(real, "payout-2", tag.pubkey())
+    failed = fetched(
+        VersionedTransaction(message, [payer, tag]),
+        TransactionErrorFieldless.AccountInUse,
+    )
+    assert not verify_tagged(failed, "payout-1", tag.pubkey())
+
+
+def test_verify_tagged_rejects_impostor() -> None:
+    # anyone can reference the payer with the same memo, but can't sign for it
+    payer, attacker = Keypair(), Keypair()
+    ix = idempotency_memo("payout-1")
+    spam = Instruction(
+        Pubkey.new_unique(), b"", [AccountMeta(payer.pubkey(), False, False)]
+    )
+    message = Message.new_with_blockhash([ix, spam], attacker.pubkey(), Hash.default())
+    impostor = fetched(VersionedTransaction(message, [attacker]))
+    assert not verify_tagged(impostor, "payout-1", payer.pubkey())
@@ -1,7 +1,13 @@
 import pickle
 from typing import cast, Union
 from pytest import mark, raises, fixture
-from solders.instruction import Instruction, CompiledInstruction, AccountMeta
+from solders.instruction import (
+    Instruction,
+    CompiledInstruction,
+    AccountMeta,
+    AccountMetaTemplate,
+    InstructionTemplate,
+)
 from solders.pubkey import Pubkey
 
 
@@ -80,3 +86,37 @@ def test_json_ix(ix: Instruction) -> None:
 
 def test_json_compiled_ix(compiled_ix: CompiledInstruction) -> None:
     assert CompiledInstruction.from_json(compiled_ix.to_json()) == compiled_ix
+
+
+def test_instruction_template() -> None:
+    program_id, mint = Pubkey.new_unique(), Pubkey.new_unique()
+    template = InstructionTemplate(
+        program_id,
+        b"\x03",
+        [
+            AccountMetaTemplate("ata", False, True),
+            AccountMetaTemplate(mint, False, False),
+            AccountMetaTemplate("owner", True, False),
+            AccountMetaTemplate("ata", False, True),
+        ],
+    )
+    assert template.placeholders() == ["ata", "owner"]
+    assert template.accounts[1].key == mint
+    assert template.accounts[0].key == "ata"
+    users = [
+        {"ata": Pubkey.new_unique(), "owner": Pubkey.new_unique()} for _ in range(2)
+    ]
+    resolved = template.resolve_many(users)
+    assert resolved[1] == Instruction(
+        program_id,
+        b"\x03",
+        [
+            AccountMeta(users[1]["ata"], False, True),
+            AccountMeta(mint, False, False),
+            AccountMeta(users[1]["owner"], True, False),
+            AccountMeta(users[1]["ata"], False, True),
+        ],
+    )
+    assert template.resolve({**users[0], "unused": mint}) == resolved[0]
+    with raises(ValueError, match="missing placeholders: ata, owner"):
+        template.resolve({})
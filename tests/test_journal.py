@@ -0,0 +1,128 @@
+from typing import List
+
+from solders.account import Account
+from solders.accounts_map import AccountsMap
+from solders.instruction import Instruction
+from solders.journal import AccountChange, AccountJournal
+from solders.keypair import Keypair
+from solders.message import Message
+from solders.pubkey import Pubkey
+from solders.simulation import RuntimeParams
+from solders.system_program import ID as SYSTEM_PROGRAM_ID
+from solders.system_program import (
+    CreateAccountParams,
+    TransferParams,
+    create_account,
+    transfer,
+)
+from solders.transaction import VersionedTransaction
+from solders.transaction_status import (
+    InstructionErrorCustom,
+    TransactionErrorInstructionError,
+)
+
+PAYER = Keypair()
+TO = Pubkey.new_unique()
+PROGRAM = Pubkey.new_unique()
+
+
+def _tx(ixs: List[Instruction], *extra: Keypair) -> VersionedTransaction:
+    return VersionedTransaction(Message(ixs, PAYER.pubkey()), [PAYER, *extra])
+
+
+def _transfer(lamports: int) -> Instruction:
+    return transfer(
+        TransferParams(from_pubkey=PAYER.pubkey(), to_pubkey=TO, lamports=lamports)
+    )
+
+
+def _funded() -> AccountsMap:
+    return AccountsMap({PAYER.pubkey(): Account(10_000_000, b"", SYSTEM_PROGRAM_ID)})
+
+
+def test_records_changes_and_applies_them() -> None:
+    accounts = _funded()
+    journal = AccountJournal(accounts)
+    tx = _tx([_transfer(2_000_000)])
+    first = journal.process(tx)
+    assert first.signature == tx.signatures[0]
+    assert first.err is None
+    assert first.fee == 5000
+    assert first.fully_simulated
+    assert [c.pubkey for c in first.changes] == sorted([PAYER.pubkey(), TO])
+    payer_change = first.change(PAYER.pubkey())
+    assert payer_change is not None
+    assert payer_change.lamport_delta == -2_005_000
+    assert not payer_change.created
+    to_change = first.change(TO)
+    assert to_change is not None
+    assert to_change.created
+    assert to_change.before is None
+    second = journal.process(_tx([_transfer(1_000_000)]))
+    assert second.change(TO).before == to_change.after  # type: ignore
+    assert journal.accounts[TO].lamports == 3_000_000
+    assert len(journal) == 2
+    assert [c.lamport_delta for c in journal.history(TO)] == [2_000_000, 1_000_000]
+    assert journal.net_lamport_delta(PAYER.pubkey()) == -3_010_000
+    assert not accounts.is_dirty
+    assert TO not in accounts
+
+
+def test_failed_transaction_records_fee() -> None:
+    journal = AccountJournal(_funded())
+    entry = journal.process(_tx([_transfer(20_000_000)]))
+    assert entry.err == TransactionErrorInstructionError(0, InstructionErrorCustom(1))
+    assert [c.lamport_delta for c in entry.changes] == [-5000]
+    assert journal.accounts[PAYER.pubkey()].lamports == 9_995_000
+
+
+def test_program_scope() -> None:
+    new_account = Keypair()
+    ix = create_account(
+        CreateAccountParams(
+            from_pubkey=PAYER.pubkey(),
+            to_pubkey=new_account.pubkey(),
+            lamports=2_000_000,
+            space=8,
+            owner=PROGRAM,
+        )
+    )
+    journal = AccountJournal(_funded(), programs=[PROGRAM])
+    entry = journal.process(_tx([ix], new_account))
+    assert entry.err is None
+    assert len(entry.changes) == 1
+    change = entry.changes[0]
+    assert change.pubkey == new_account.pubkey()
+    assert change.created and change.data_changed and not change.owner_changed
+    assert entry.change(PAYER.pubkey()) is None
+    assert journal.accounts[PAYER.pubkey()].lamports == 7_995_000
+
+
+def test_params_and_clear() -> None:
+    journal = AccountJournal(_funded(), params=RuntimeParams(lamports_per_signature=0))
+    assert journal.process(_tx([_transfer(2_000_000)])).fee == 0
+    journal.clear()
+    assert journal.entries == []
+    assert journal.accounts[TO].lamports == 2_000_000
+
+
+def test_account_change() -> None:
+    before = Account(10, b"\x00", SYSTEM_PROGRAM_ID)
+    after = Account(4, b"\x00", PROGRAM)
+    change = AccountChange(Pubkey.new_unique(), before, after)
+    assert change.lamport_delta == -6
+    assert change.owner_changed
+    assert not change.data_changed
+    closed = AccountChange(Pubkey.new_unique(), before, None)
+    assert closed.closed and closed.lamport_delta == -10
+
+
+def test_unsimulated_instructions() -> None:
+    accounts = _funded()
+    accounts[PROGRAM] = Account(1, b"", SYSTEM_PROGRAM_ID, executable=True)
+    journal = AccountJournal(accounts)
+    other = Instruction(PROGRAM, b"\x01", [])
+    entry = journal.process(_tx([_transfer(1_000_000), other]))
+    assert entry.err is None
+    assert entry.unsimulated_instructions == [1]
+    assert not entry.fully_simulated
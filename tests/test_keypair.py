@@ -1,10 +1,13 @@
 from operator import ge, gt, le, lt
+import hashlib
+import hmac
 import pickle
 from typing import Callable, Any
 from pytest import raises, mark
 
 from pybip39 import Mnemonic, Seed
 from solders.keypair import Keypair
+from solders.presigner import Presigner
 
 
 def test_from_bytes() -> None:
@@ -55,6 +58,28 @@ def test_from_bytes_wrong_size(test_input: bytes) -> None:
     )
 
 
+def test_from_bytes_seed_hint() -> None:
+    with raises(ValueError) as excinfo:
+        Keypair.from_bytes(bytes(32))
+    msg = excinfo.value.args[0]
+    assert msg.startswith("expected a sequence of length 64 (got 32)")
+    assert "use Keypair.from_seed instead" in msg
+
+
+def test_from_seed_or_bytes() -> None:
+    kp = Keypair.from_seed(bytes([1] * 32))
+    assert Keypair.from_seed_or_bytes(bytes([1] * 32)) == kp
+    assert Keypair.from_seed_or_bytes(bytes(kp)) == kp
+    assert Keypair.from_seed_or_bytes([1] * 32) == kp
+    assert Keypair.from_seed_or_bytes(kp.to_bytes_array()) == kp
+    with raises(ValueError) as excinfo:
+        Keypair.from_seed_or_bytes(bytes(33))
+    assert (
+        excinfo.value.args[0]
+        == "expected a 32-byte seed or a 64-byte keypair (got 33 bytes)"
+    )
+
+
 def test_from_bytes_invalid_input() -> None:
     with raises(ValueError) as excinfo:
         Keypair.from_bytes(b"a" * 64)
@@ -74,6 +99,10 @@ def test_set_operations() -> None:
 
 def test_equal() -> None:
     assert Keypair.from_seed(bytes([0] * 32)) == Keypair.from_seed(bytes([0] * 32))
+    kp = Keypair.from_seed(bytes([0] * 32))
+    # other signers still compare by pubkey
+    presigner = Presigner(kp.pubkey(), kp.sign_message(b"msg"))
+    assert kp == presigner and not kp != presigner
 
 
 def test_not_equal() -> None:
@@ -98,6 +127,53 @@ def test_from_seed() -> None:
     assert excinfo.value.args[0] == "expected a sequence of length 32 (got 31)"
 
 
+def test_new_with_rng() -> None:
+    keypairs = [Keypair.new_with_rng(seed) for seed in range(5)]
+    assert len(set(keypairs)) == 5
+    assert [Keypair.new_with_rng(seed) for seed in range(5)] == keypairs
+    assert Keypair.new_with_rng(0) != Keypair.from_seed(bytes(32))
+    with raises(OverflowError):
+        Keypair.new_with_rng(-1)
+
+
+def test_from_seed_and_derivation_bytes() -> None:
+    # SLIP-0010 ed25519 test vector 1
+    seed = bytes.fromhex("000102030405060708090a0b0c0d0e0f")
+    master = Keypair.from_seed_and_derivation_bytes(seed, [])
+    assert (
+        master.secret().hex()
+        == "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7"
+    )
+    assert (
+        bytes(master.pubkey()).hex()
+        == "a4b2856bfec510abab89753fac1ac0e1112364e7d250545963f135f2a33188ed"
+    )
+    child = Keypair.from_seed_and_derivation_bytes(seed, [bytes.fromhex("80000000")])
+    assert (
+        bytes(child.pubkey()).hex()
+        == "8c8a13df77a28f3445213a0f432fde644acaa215fc72dcdf300d5efaa85d350c"
+    )
+    custom = Keypair.from_seed_and_derivation_bytes(seed, [b"custodian", b"vault-7"])
+    assert custom != child
+    assert custom == Keypair.from_seed_and_derivation_bytes(
+        seed, [b"custodian", b"vault-7"]
+    )
+
+
+def test_derive_ephemeral() -> None:
+    master = Keypair.from_seed(bytes(range(32)))
+    prk = hmac.new(b"solders ephemeral keypair", master.secret(), hashlib.sha256)
+    info = (7).to_bytes(8, "big") + b"deposits"
+    okm = hmac.new(prk.digest(), info + b"\x01", hashlib.sha256).digest()
+    derived = Keypair.derive_ephemeral(master, "deposits", 7)
+    assert derived == Keypair.from_seed(okm)
+    assert derived == Keypair.derive_ephemeral(master, "deposits", 7)
+    assert derived != Keypair.derive_ephemeral(master, "deposits", 8)
+    assert derived != Keypair.derive_ephemeral(master, "withdrawals", 7)
+    other = Keypair.from_seed(bytes(32))
+    assert derived != Keypair.derive_ephemeral(other, "deposits", 7)
+
+
 def test_from_seed_phrase_and_passphrase() -> None:
     mnemonic = Mnemonic()
     passphrase = "42"  # noqa: S105
@@ -0,0 +1,87 @@
+import json
+
+from pytest import raises
+from solders.account import Account
+from solders.errors import SerdeJSONError
+from solders.keypair import Keypair
+from solders.lenient import from_bytes_lenient, from_json_lenient
+from solders.message import Message
+from solders.pubkey import Pubkey
+from solders.rpc.responses import GetBlockHeightResp, GetSignatureStatusesResp
+from solders.transaction import VersionedTransaction
+
+
+def status(confirmation_status: str) -> dict:
+    return {
+        "slot": 72,
+        "confirmations": 10,
+        "err": None,
+        "status": {"Ok": None},
+        "confirmationStatus": confirmation_status,
+    }
+
+
+def statuses_resp(*statuses: dict) -> str:
+    return json.dumps(
+        {
+            "jsonrpc": "2.0",
+            "result": {"context": {"slot": 82}, "value": list(statuses)},
+            "id": 1,
+        }
+    )
+
+
+def test_from_bytes_lenient() -> None:
+    payer = Keypair()
+    tx = VersionedTransaction(Message([], payer.pubkey()), [payer])
+    clean = from_bytes_lenient(bytes(tx), VersionedTransaction)
+    assert clean.value == tx
+    assert clean.is_clean
+    padded = from_bytes_lenient(bytes(tx) + bytes(3), VersionedTransaction)
+    assert padded.value == tx
+    assert padded.warnings == ["ignored 3 trailing bytes"]
+    account = Account(5, b"data", Pubkey.default())
+    assert from_bytes_lenient(bytes(account) + b"x", Account).value == account
+    with raises(ValueError):
+        from_bytes_lenient(b"\x01", Account)
+
+
+def test_from_json_lenient() -> None:
+    raw = statuses_resp(status("confirmed"), status("superConfirmed"), status("nope"))
+    parsed = from_json_lenient(raw, GetSignatureStatusesResp)
+    assert isinstance(parsed.value, GetSignatureStatusesResp)
+    assert [s.confirmation_status is None for s in parsed.value.value] == [
+        False,
+        True,
+        True,
+    ]
+    assert parsed.warnings == [
+        'replaced "superConfirmed" at result.value.1.confirmationStatus with null',
+        'replaced "nope" at result.value.2.confirmationStatus with null',
+    ]
+    assert parsed.dropped == [
+        "result.value.1.confirmationStatus",
+        "result.value.2.confirmationStatus",
+    ]
+    clean = from_json_lenient(statuses_resp(status("finalized")), GetSignatureStatusesResp)
+    assert clean.is_clean
+    assert clean.value == GetSignatureStatusesResp.from_json(
+        statuses_resp(status("finalized"))
+    )
+
+
+def test_from_json_lenient_unrepairable() -> None:
+    with raises(SerdeJSONError):
+        from_json_lenient('{"jsonrpc": "2.0", "result": "high", "id": 1}', GetBlockHeightResp)
+    with raises(SerdeJSONError):
+        from_json_lenient("not json", GetBlockHeightResp)
+
+
+def test_from_json_lenient_keeps_errors() -> None:
+    failed = {
+        **status("finalized"),
+        "err": {"Bogus": 1},
+        "status": {"Err": {"Bogus": 1}},
+    }
+    with raises(SerdeJSONError):
+        from_json_lenient(statuses_resp(failed), GetSignatureStatusesResp)
@@ -0,0 +1,89 @@
+import logging
+import sys
+from typing import Any, Iterator, List
+
+from pytest import LogCaptureFixture, MonkeyPatch, fixture
+from solders.keypair import Keypair
+from solders.log import LogEvent, LogLevel, set_log_handler, use_python_logging
+from solders.message import Message
+from solders.outbox import Outbox
+from solders.rpc.responses import SlotInfo
+from solders.rpc.streams import SlotTracker
+from solders.transaction import VersionedTransaction
+
+
+@fixture(autouse=True)
+def reset_handler() -> Iterator[None]:
+    yield
+    set_log_handler(None)
+
+
+def expiring_outbox() -> Outbox:
+    payer = Keypair()
+    outbox = Outbox()
+    outbox.add(VersionedTransaction(Message([], payer.pubkey()), [payer]), 100)
+    return outbox
+
+
+def test_log_level() -> None:
+    assert [int(level) for level in (LogLevel.Debug, LogLevel.Error)] == [10, 40]
+
+
+def test_set_log_handler() -> None:
+    events: List[LogEvent] = []
+    set_log_handler(events.append)
+    outbox = expiring_outbox()
+    [signature] = outbox.expire(101)
+    assert events == [
+        LogEvent(
+            LogLevel.Warning,
+            "solders.outbox",
+            f"transaction {signature} expired",
+            {
+                "signature": str(signature),
+                "last_valid_block_height": "100",
+                "block_height": "101",
+            },
+        )
+    ]
+    tracker = SlotTracker()
+    tracker.process_slot(SlotInfo(slot=10, parent=9, root=0))
+    tracker.process_slot(SlotInfo(slot=14, parent=13, root=0))
+    assert events[-1].message == "missed slots 11 to 13"
+    assert events[-1].context == {"first": "11", "last": "13"}
+
+
+def test_min_level_and_removal() -> None:
+    events: List[LogEvent] = []
+    set_log_handler(events.append, LogLevel.Error)
+    expiring_outbox().expire(101)
+    assert events == []
+    set_log_handler(events.append)
+    set_log_handler(None)
+    expiring_outbox().expire(101)
+    assert events == []
+
+
+def test_handler_exceptions_are_unraisable(monkeypatch: MonkeyPatch) -> None:
+    def fail(event: LogEvent) -> None:
+        raise RuntimeError(event.message)
+
+    unraisable: List[Any] = []
+    monkeypatch.setattr(sys, "unraisablehook", unraisable.append)
+    set_log_handler(fail)
+    outbox = expiring_outbox()
+    [signature] = outbox.expire(101)
+    assert outbox.pending() == []
+    [report] = unraisable
+    assert isinstance(report.exc_value, RuntimeError)
+    assert str(signature) in str(report.exc_value)
+
+
+def test_use_python_logging(caplog: LogCaptureFixture) -> None:
+    use_python_logging(LogLevel.Info)
+    with caplog.at_level(logging.DEBUG, logger="solders"):
+        [signature] = expiring_outbox().expire(101)
+    [record] = caplog.records
+    assert record.name == "solders.outbox"
+    assert record.levelno == logging.WARNING
+    assert record.solders_context["signature"] == str(signature)  # type: ignore
@@ -3,7 +3,7 @@ from typing import List
 from solders.message import Message, MessageHeader
 from solders.keypair import Keypair
 from solders.pubkey import Pubkey
-from solders.transaction import Transaction
+from solders.transaction import Transaction, VersionedTransaction
 from solders.hash import Hash
 from solders.instruction import Instruction, AccountMeta, CompiledInstruction
 
@@ -62,6 +62,35 @@ def test_message_payer_first() -> None:
     assert message.header.num_required_signatures == 2
 
 
+def test_required_signers() -> None:
+    program_id = Pubkey.new_unique()
+    payer, reader, writer, promoted = (Pubkey.new_unique() for _ in range(4))
+    ixs = [
+        Instruction(
+            program_id,
+            ZERO_BYTES,
+            [
+                AccountMeta(reader, True, False),
+                AccountMeta(promoted, True, False),
+                AccountMeta(writer, True, True),
+            ],
+        ),
+        Instruction(program_id, ZERO_BYTES, [AccountMeta(promoted, True, True)]),
+    ]
+    signers = Message.required_signers(ixs, payer)
+    assert signers == [payer, promoted, writer, reader]
+    message = Message(ixs, payer)
+    num_signers = message.header.num_required_signatures
+    assert signers == message.account_keys[:num_signers]
+    assert Message.required_signers(ixs, None) == [promoted, writer, reader]
+    keypairs = [Keypair() for _ in range(2)]
+    payer, signer = (kp.pubkey() for kp in keypairs)
+    ix = Instruction(program_id, ZERO_BYTES, [AccountMeta(signer, True, True)])
+    assert Message.required_signers([ix], payer) == [payer, signer]
+    tx = VersionedTransaction(Message([ix], payer), keypairs[::-1])
+    assert tx.verify_with_results() == [True, True]
+
+
 def test_program_position() -> None:
     program_id0 = Pubkey.default()
     program_id1 = Pubkey.new_unique()
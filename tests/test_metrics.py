@@ -0,0 +1,153 @@
+from typing import Iterator
+
+from pytest import fixture, raises
+from solders.errors import SerdeJSONError
+from solders.keypair import Keypair
+from solders.message import Message
+from solders.metrics import (
+    HistogramSnapshot,
+    MetricsSnapshot,
+    metrics,
+    record,
+    reset_metrics,
+    set_metrics_enabled,
+)
+from solders.outbox import Outbox
+from solders.rpc.polling import BlockBackfill, StatusPoller
+from solders.rpc.responses import (
+    GetBlocksResp,
+    GetSignatureStatusesResp,
+    RpcResponseContext,
+    SlotInfo,
+    parse_notification,
+    parse_websocket_message,
+)
+from solders.rpc.streams import SlotTracker
+from solders.signature import Signature
+from solders.transaction import VersionedTransaction
+from solders.transaction_status import TransactionConfirmationStatus, TransactionStatus
+
+
+@fixture(autouse=True)
+def reset() -> Iterator[None]:
+    reset_metrics()
+    set_metrics_enabled(True)
+    yield
+    set_metrics_enabled(False)
+    reset_metrics()
+
+
+def test_starts_at_zero() -> None:
+    snapshot = metrics()
+    assert set(snapshot.counters.values()) == {0}
+    confirmation = snapshot.histograms["solders_status_poller_confirmation_seconds"]
+    assert confirmation.count == 0
+    assert confirmation.sum == 0.0
+    assert all(count == 0 for _, count in confirmation.buckets)
+
+
+def test_status_poller() -> None:
+    landed, dropped = Signature.new_unique(), Signature.new_unique()
+    poller = StatusPoller()
+    poller.track(landed, 100)
+    poller.track(dropped, 100)
+    [request] = poller.requests()
+    status = TransactionStatus(
+        5, None, None, None, TransactionConfirmationStatus.Finalized
+    )
+    resp = GetSignatureStatusesResp([status, None], RpcResponseContext(5))
+    poller.handle_response(request, resp, 101)
+    snapshot = metrics()
+    assert snapshot.counters["solders_status_poller_requests_total"] == 1
+    assert snapshot.counters["solders_status_poller_resolved_total"] == 1
+    assert snapshot.counters["solders_status_poller_expired_total"] == 1
+    confirmation = snapshot.histograms["solders_status_poller_confirmation_seconds"]
+    assert confirmation.count == 1
+    assert confirmation.buckets[0] == (0.5, 1)
+
+
+def test_components() -> None:
+    backfill = BlockBackfill(0, chunk_size=10)
+    backfill.update_tip(9)
+    request = backfill.next_request()
+    assert request is not None
+    backfill.handle_response(request, GetBlocksResp([1, 2, 3]))
+    payer = Keypair()
+    outbox = Outbox()
+    outbox.add(VersionedTransaction(Message([], payer.pubkey()), [payer]), 100)
+    outbox.expire(101)
+    tracker = SlotTracker()
+    tracker.process_slot(SlotInfo(slot=10, parent=9, root=0))
+    tracker.process_slot(SlotInfo(slot=14, parent=13, root=0))
+    raw = '{ "jsonrpc": "2.0", "method": "rootNotification", "params": { "result": 4, "subscription": 0 } }'
+    parse_websocket_message(raw)
+    counters = metrics().counters
+    assert counters["solders_block_backfill_responses_total"] == 1
+    assert counters["solders_block_backfill_blocks_total"] == 3
+    assert counters["solders_outbox_added_total"] == 1
+    assert counters["solders_outbox_expired_total"] == 1
+    assert counters["solders_slot_tracker_missed_slots_total"] == 3
+    assert counters["solders_websocket_messages_total"] == 1
+    assert metrics().histograms["solders_websocket_parse_seconds"].count == 1
+
+
+
+def test_websocket_parse_errors() -> None:
+    with raises(SerdeJSONError):
+        parse_notification("{}")
+    with raises(SerdeJSONError):
+        parse_websocket_message("not json")
+    counters = metrics().counters
+    assert counters["solders_websocket_messages_total"] == 0
+    assert counters["solders_websocket_parse_errors_total"] == 2
+
+
+def test_disabled() -> None:
+    set_metrics_enabled(False)
+    poller = StatusPoller()
+    poller.track(Signature.new_unique(), 100)
+    poller.requests()
+    record("solders_retries_total")
+    assert metrics().counters["solders_status_poller_requests_total"] == 0
+    assert metrics().counters["solders_retries_total"] == 0
+
+
+def test_record() -> None:
+    record("solders_requests_sent_total", 3)
+    record("solders_retries_total")
+    record("solders_websocket_reconnects_total")
+    counters = metrics().counters
+    assert counters["solders_requests_sent_total"] == 3
+    assert counters["solders_retries_total"] == 1
+    assert counters["solders_websocket_reconnects_total"] == 1
+    with raises(ValueError):
+        record("solders_outbox_added_total")
+
+def test_reset() -> None:
+    poller = StatusPoller()
+    poller.track(Signature.new_unique(), 100)
+    poller.requests()
+    assert metrics().counters["solders_status_poller_requests_total"] == 1
+    reset_metrics()
+    assert metrics().counters["solders_status_poller_requests_total"] == 0
+
+
+def test_to_prometheus() -> None:
+    histogram = HistogramSnapshot([(0.5, 1), (1.0, 2)], 1.25, 3)
+    snapshot = MetricsSnapshot(
+        {"solders_outbox_added_total": 2},
+        {"solders_status_poller_confirmation_seconds": histogram},
+    )
+    assert snapshot.to_prometheus().splitlines() == [
+        "# HELP solders_outbox_added_total Transactions added to an Outbox.",
+        "# TYPE solders_outbox_added_total counter",
+        "solders_outbox_added_total 2",
+        "# HELP solders_status_poller_confirmation_seconds Time from StatusPoller.track"
+        " to the signature reaching the poller's commitment.",
+        "# TYPE solders_status_poller_confirmation_seconds histogram",
+        'solders_status_poller_confirmation_seconds_bucket{le="0.5"} 1',
+        'solders_status_poller_confirmation_seconds_bucket{le="1"} 2',
+        'solders_status_poller_confirmation_seconds_bucket{le="+Inf"} 3',
+        "solders_status_poller_confirmation_seconds_sum 1.25",
+        "solders_status_poller_confirmation_seconds_count 3",
+    ]
@@ -0,0 +1,69 @@
+from pytest import raises
+from solders.hash import Hash
+from solders.keypair import Keypair
+from solders.message import Message
+from solders.outbox import Outbox, OutboxEntry, OutboxStatus
+from solders.system_program import TransferParams, transfer
+from solders.transaction import VersionedTransaction
+
+
+def _tx(payer: Keypair, lamports: int) -> VersionedTransaction:
+    ix = transfer(
+        TransferParams(
+            from_pubkey=payer.pubkey(), to_pubkey=payer.pubkey(), lamports=lamports
+        )
+    )
+    msg = Message.new_with_blockhash([ix], payer.pubkey(), Hash.default())
+    return VersionedTransaction(msg, [payer])
+
+
+def test_outbox_roundtrip() -> None:
+    payer = Keypair()
+    txs = [_tx(payer, i) for i in range(3)]
+    outbox = Outbox()
+    assert all(outbox.add(tx, 100 + i) for i, tx in enumerate(txs))
+    assert not outbox.add(txs[0], 500)
+    assert len(outbox) == 3
+    sigs = [tx.signatures[0] for tx in txs]
+    assert outbox.set_status(sigs[1], OutboxStatus.Confirmed)
+    restored = Outbox.from_bytes(bytes(outbox))
+    assert restored == outbox
+    assert sigs[2] in restored
+    assert restored.get(sigs[0]) == OutboxEntry(txs[0], 100, OutboxStatus.Pending)
+    assert restored.expire(102) == [sigs[0]]
+    assert [e.signature for e in restored.pending()] == [sigs[2]]
+    assert restored.prune() == 2
+    assert restored.entries == [OutboxEntry(txs[2], 102, OutboxStatus.Pending)]
+    assert restored.remove(sigs[2]) is not None
+    assert len(restored) == 0
+
+
+def test_outbox_rejects_unknown_version() -> None:
+    raw = bytearray(bytes(Outbox()))
+    raw[0] = 99
+    with raises(ValueError, match="version"):
+        Outbox.from_bytes(bytes(raw))
+    with raises(ValueError):
+        Outbox.from_bytes(b"")
+
+
+def test_outbox_uses_attached_expiry() -> None:
+    payer = Keypair()
+    tx = _tx(payer, 1)
+    outbox = Outbox()
+    with raises(ValueError, match="last_valid_block_height"):
+        outbox.add(tx)
+    tx.last_valid_block_height = 100
+    assert outbox.add(tx)
+    assert outbox.get(tx.signatures[0]).last_valid_block_height == 100
+
+
+def test_outbox_validates_json_and_entries() -> None:
+    outbox = Outbox()
+    outbox.add(_tx(Keypair(), 1), 100)
+    assert Outbox.from_json(outbox.to_json()) == outbox
+    with raises(ValueError, match="version"):
+        Outbox.from_json(outbox.to_json().replace('"version":1', '"version":99'))
+    raw = bytes(outbox.entries[0])
+    with raises(ValueError, match="no signatures"):
+        OutboxEntry.from_bytes(b"\x00" + raw[65:])
@@ -0,0 +1,140 @@
+from typing import List, Optional, Tuple
+
+from pytest import raises
+from solders.commitment_config import CommitmentConfig, CommitmentLevel
+from solders.rpc.polling import BlockBackfill, StatusPoller
+from solders.rpc.requests import GetBlocks
+from solders.rpc.responses import (
+    GetBlocksResp,
+    GetSignatureStatusesResp,
+    RpcResponseContext,
+)
+from solders.signature import Signature
+from solders.transaction_status import (
+    TransactionConfirmationStatus,
+    TransactionStatus,
+)
+
+CONFIRMED = TransactionStatus(1, 0, None, None, TransactionConfirmationStatus.Confirmed)
+
+
+def test_batches_requests() -> None:
+    poller = StatusPoller(search_transaction_history=True)
+    signatures = [Signature.new_unique() for _ in range(300)]
+    for sig in signatures:
+        assert poller.track(sig, 100)
+    assert not poller.track(signatures[0], 100)
+    requests = poller.requests(start_id=7)
+    assert [len(r.signatures) for r in requests] == [256, 44]
+    assert [r.id for r in requests] == [7, 8]
+    assert requests[0].config is not None
+    assert requests[1].signatures == signatures[256:]
+
+
+def test_resolves_with_callbacks() -> None:
+    confirmed, pending, expired = (Signature.new_unique() for _ in range(3))
+    calls: List[Tuple[Signature, Optional[TransactionStatus]]] = []
+    poller = StatusPoller(CommitmentConfig.confirmed())
+    for sig in (confirmed, pending, expired):
+        poller.track(sig, 100, lambda s, st: calls.append((s, st)))
+    processed = TransactionStatus(
+        1, 1, None, None, TransactionConfirmationStatus.Processed
+    )
+    [request] = poller.requests()
+    resp = GetSignatureStatusesResp([CONFIRMED, processed, None], RpcResponseContext(1))
+    resolved = poller.handle_response(request, resp, 101)
+    assert resolved == [(confirmed, CONFIRMED), (expired, None)]
+    assert calls == resolved
+    assert poller.pending() == [pending]
+    assert pending in poller
+    assert poller.untrack(pending)
+    assert len(poller) == 0
+
+
+
+def test_callback_exceptions_dont_skip_later_callbacks() -> None:
+    first, second = Signature.new_unique(), Signature.new_unique()
+    calls: List[Signature] = []
+
+    def failing(sig: Signature, status: Optional[TransactionStatus]) -> None:
+        calls.append(sig)
+        raise RuntimeError("boom")
+
+    poller = StatusPoller(CommitmentConfig.confirmed())
+    poller.track(first, 100, failing)
+    poller.track(second, 100, lambda s, st: calls.append(s))
+    [request] = poller.requests()
+    resp = GetSignatureStatusesResp([CONFIRMED, CONFIRMED], RpcResponseContext(1))
+    with raises(RuntimeError, match="boom"):
+        poller.handle_response(request, resp, 1)
+    assert calls == [first, second]
+    assert len(poller) == 0
+
+def test_not_expired_before_last_valid_block_height() -> None:
+    sig = Signature.new_unique()
+    poller = StatusPoller()
+    poller.track(sig, 100)
+    [request] = poller.requests()
+    resp = GetSignatureStatusesResp([None], RpcResponseContext(1))
+    assert poller.handle_response(request, resp, 100) == []
+    with raises(ValueError):
+        poller.handle_response(request, GetSignatureStatusesResp([], resp.context), 1)
+
+
+def test_backfill_follows_moving_tip() -> None:
+    backfill = BlockBackfill(10, chunk_size=5)
+    assert backfill.next_request() is None
+    assert backfill.update_tip(17)
+    request = backfill.next_request(id=3)
+    assert (request.start, request.end, request.id) == (10, 14, 3)
+    assert backfill.handle_response(request, GetBlocksResp([14, 11, 12, 9])) == [
+        11,
+        12,
+        14,
+    ]
+    request = backfill.next_request()
+    assert (request.start, request.end) == (15, 17)
+    assert backfill.handle_response(request, GetBlocksResp([15, 17])) == [15, 17]
+    assert backfill.is_caught_up
+    assert backfill.next_request() is None
+    assert not backfill.update_tip(16)
+    assert backfill.update_tip(20)
+    assert not backfill.is_caught_up
+    assert backfill.next_request().start == 18
+
+
+def test_backfill_rerequests_from_lagging_node() -> None:
+    backfill = BlockBackfill(10, chunk_size=10)
+    backfill.update_tip(19)
+    request = backfill.next_request()
+    assert backfill.handle_response(request, GetBlocksResp([10, 12])) == [10, 12]
+    assert backfill.next_slot == 13
+    request = backfill.next_request()
+    assert (request.start, request.end) == (13, 19)
+    assert backfill.handle_response(request, GetBlocksResp([])) == []
+    assert backfill.next_slot == 13
+    assert backfill.handle_response(request, GetBlocksResp([]), root=16) == []
+    assert backfill.next_slot == 17
+    request = backfill.next_request()
+    assert backfill.handle_response(request, GetBlocksResp([18]), root=25) == [18]
+    assert backfill.is_caught_up
+
+
+def test_backfill_tip_request() -> None:
+    backfill = BlockBackfill(0, commitment=CommitmentLevel.Confirmed)
+    assert backfill.tip_request(id=7).config.commitment == CommitmentLevel.Confirmed
+    with raises(ValueError):
+        BlockBackfill(0, commitment=CommitmentLevel.Processed)
+    with raises(ValueError):
+        BlockBackfill(0, chunk_size=0)
+
+
+def test_backfill_rejects_stale_request() -> None:
+    backfill = BlockBackfill(0, chunk_size=10)
+    backfill.update_tip(100)
+    request = backfill.next_request()
+    backfill.handle_response(request, GetBlocksResp([9]))
+    with raises(ValueError):
+        backfill.handle_response(request, GetBlocksResp([]))
+    with raises(ValueError):
+        backfill.handle_response(GetBlocks(10), GetBlocksResp([]))
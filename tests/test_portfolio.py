@@ -0,0 +1,135 @@
+from typing import List, Optional
+
+from pytest import raises
+from solders.account import Account
+from solders.account_decoder import UiAccountEncoding
+from solders.pubkey import Pubkey
+from solders.rpc.portfolio import PortfolioQuery, TokenHolding
+from solders.rpc.responses import (
+    GetMultipleAccountsResp,
+    GetTokenAccountsByOwnerResp,
+    RpcKeyedAccount,
+    RpcResponseContext,
+)
+
+TOKEN_PROGRAM_ID = Pubkey.from_string("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+TOKEN_2022_PROGRAM_ID = Pubkey.from_string(
+    "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+)
+
+
+def _token_account(mint: Pubkey, owner: Pubkey, amount: int, state: int = 1) -> bytes:
+    return (
+        bytes(mint)
+        + bytes(owner)
+        + amount.to_bytes(8, "little")
+        + bytes(36)
+        + bytes([state])
+        + bytes(56)
+    )
+
+
+def _mint(decimals: int) -> bytes:
+    return bytes(44) + bytes([decimals, 1]) + bytes(36)
+
+
+def _accounts_resp(accounts: List[RpcKeyedAccount]) -> GetTokenAccountsByOwnerResp:
+    return GetTokenAccountsByOwnerResp(accounts, RpcResponseContext(1))
+
+
+def _mints_resp(accounts: List[Optional[Account]]) -> GetMultipleAccountsResp:
+    return GetMultipleAccountsResp(accounts, RpcResponseContext(1))
+
+
+def test_portfolio() -> None:
+    owner = Pubkey.new_unique()
+    usdc, bonk = Pubkey.new_unique(), Pubkey.new_unique()
+    addresses = [Pubkey.new_unique() for _ in range(3)]
+    query = PortfolioQuery(owner)
+    token_req, token_2022_req = query.token_account_requests(start_id=3)
+    assert (token_req.id, token_2022_req.id) == (3, 4)
+    assert token_req.filter_.program_id == TOKEN_PROGRAM_ID
+    assert token_2022_req.filter_.program_id == TOKEN_2022_PROGRAM_ID
+    assert token_req.config is not None
+    assert token_req.config.encoding == UiAccountEncoding.Base64
+    funded = _token_account(usdc, owner, 1_500_000)
+    frozen = _token_account(usdc, owner, 0, 2)
+    query.handle_token_accounts(
+        _accounts_resp(
+            [
+                RpcKeyedAccount(addresses[0], Account(1, funded, TOKEN_PROGRAM_ID)),
+                RpcKeyedAccount(addresses[1], Account(1, frozen, TOKEN_PROGRAM_ID)),
+            ]
+        )
+    )
+    extended = _token_account(bonk, owner, 12) + bytes([2]) + bytes(10)
+    query.handle_token_accounts(
+        _accounts_resp(
+            [RpcKeyedAccount(addresses[2], Account(1, extended, TOKEN_2022_PROGRAM_ID))]
+        )
+    )
+    with raises(ValueError):
+        query.holdings()
+    [mint_req] = query.mint_requests()
+    assert mint_req.accounts == [usdc, bonk]
+    query.handle_mints(
+        mint_req,
+        _mints_resp(
+            [
+                Account(1, _mint(6), TOKEN_PROGRAM_ID),
+                Account(1, _mint(0), TOKEN_2022_PROGRAM_ID),
+            ]
+        ),
+    )
+    assert query.mint_requests() == []
+    holdings = query.holdings()
+    assert holdings == [
+        TokenHolding(addresses[0], usdc, 1_500_000, 6, False, TOKEN_PROGRAM_ID),
+        TokenHolding(addresses[1], usdc, 0, 6, True, TOKEN_PROGRAM_ID),
+        TokenHolding(addresses[2], bonk, 12, 0, False, TOKEN_2022_PROGRAM_ID),
+    ]
+    assert holdings[0].ui_amount == 1.5
+    assert holdings[0].ui_amount_string == "1.5"
+    assert holdings[1].ui_amount_string == "0"
+    assert holdings[2].ui_amount_string == "12"
+    assert [h.address for h in query.holdings(include_empty=False)] == [
+        addresses[0],
+        addresses[2],
+    ]
+
+
+def test_ui_amount_string() -> None:
+    key = Pubkey.default()
+    holding = TokenHolding(key, key, 5, 9, False, TOKEN_PROGRAM_ID)
+    assert holding.ui_amount_string == "0.000000005"
+
+
+def test_set_decimals_skips_mint_request() -> None:
+    owner, mint = Pubkey.new_unique(), Pubkey.new_unique()
+    query = PortfolioQuery(owner)
+    query.set_decimals(mint, 2)
+    account = Account(1, _token_account(mint, owner, 250), TOKEN_PROGRAM_ID)
+    query.handle_token_accounts(
+        _accounts_resp([RpcKeyedAccount(Pubkey.new_unique(), account)])
+    )
+    assert query.mint_requests() == []
+    assert query.holdings()[0].ui_amount_string == "2.5"
+
+
+def test_rejects_foreign_accounts() -> None:
+    owner, mint = Pubkey.new_unique(), Pubkey.new_unique()
+    query = PortfolioQuery(owner)
+    other = Account(1, _token_account(mint, Pubkey.new_unique(), 1), TOKEN_PROGRAM_ID)
+    with raises(ValueError, match="owner"):
+        query.handle_token_accounts(
+            _accounts_resp([RpcKeyedAccount(Pubkey.new_unique(), other)])
+        )
+    account = Account(1, _token_account(mint, owner, 1), TOKEN_PROGRAM_ID)
+    query.handle_token_accounts(
+        _accounts_resp([RpcKeyedAccount(Pubkey.new_unique(), account)])
+    )
+    [mint_req] = query.mint_requests()
+    with raises(ValueError):
+        query.handle_mints(mint_req, _mints_resp([]))
+    with raises(ValueError):
+        query.handle_mints(mint_req, _mints_resp([account]))
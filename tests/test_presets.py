@@ -0,0 +1,240 @@
+from typing import Any, Dict, List, Optional
+
+from pytest import raises
+from solders.account import Account, AccountJSON
+from solders.account_decoder import ParsedAccount
+from solders.accounts_map import AccountsMap
+from solders.instruction import AccountMeta, Instruction
+from solders.presets import (
+    NATIVE_MINT,
+    SweepSkipReason,
+    close_token_accounts,
+    filter_associated_token_accounts,
+    get_associated_token_address,
+    plan_associated_token_accounts,
+    plan_token_sweep,
+    transfer_to_wallet,
+    wrap_sol_around,
+)
+from solders.pubkey import Pubkey
+from solders.rpc.responses import RpcKeyedAccount, RpcKeyedAccountJsonParsed
+from solders.system_program import ID as SYSTEM_PROGRAM_ID
+
+TOKEN_PROGRAM_ID = Pubkey.from_string("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+TOKEN_2022_PROGRAM_ID = Pubkey.from_string(
+    "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+)
+ATA_PROGRAM_ID = Pubkey.from_string("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")
+
+
+def test_get_associated_token_address() -> None:
+    wallet, mint = Pubkey.new_unique(), Pubkey.new_unique()
+    expected = Pubkey.find_program_address(
+        [bytes(wallet), bytes(TOKEN_PROGRAM_ID), bytes(mint)], ATA_PROGRAM_ID
+    )[0]
+    assert get_associated_token_address(wallet, mint) == expected
+    assert get_associated_token_address(wallet, mint, TOKEN_2022_PROGRAM_ID) != expected
+    with raises(ValueError):
+        get_associated_token_address(wallet, mint, Pubkey.new_unique())
+
+
+def test_filter_associated_token_accounts() -> None:
+    wallet, mint = Pubkey.new_unique(), Pubkey.new_unique()
+    data = bytes(mint) + bytes(wallet) + bytes(101)
+    ata = get_associated_token_address(wallet, mint)
+    keep = RpcKeyedAccount(ata, Account(1, data, TOKEN_PROGRAM_ID))
+    aux = RpcKeyedAccount(Pubkey.new_unique(), Account(1, data, TOKEN_PROGRAM_ID))
+    not_token = RpcKeyedAccount(ata, Account(1, data, SYSTEM_PROGRAM_ID))
+    assert filter_associated_token_accounts([aux, keep, not_token]) == [keep]
+
+def test_transfer_to_wallet() -> None:
+    payer, source, recipient, mint = (Pubkey.new_unique() for _ in range(4))
+    create, transfer = transfer_to_wallet(payer, source, payer, recipient, mint, 500, 6)
+    ata = get_associated_token_address(recipient, mint)
+    assert create.program_id == ATA_PROGRAM_ID
+    assert bytes(create.data) == b"\x01"
+    assert [meta.pubkey for meta in create.accounts] == [
+        payer,
+        ata,
+        recipient,
+        mint,
+        SYSTEM_PROGRAM_ID,
+        TOKEN_PROGRAM_ID,
+    ]
+    assert transfer.program_id == TOKEN_PROGRAM_ID
+    assert bytes(transfer.data) == b"\x0c" + (500).to_bytes(8, "little") + b"\x06"
+    assert transfer.accounts == [
+        AccountMeta(source, False, True),
+        AccountMeta(mint, False, False),
+        AccountMeta(ata, False, True),
+        AccountMeta(payer, True, False),
+    ]
+
+
+def test_wrap_sol_around() -> None:
+    owner = Pubkey.new_unique()
+    swap = Instruction(Pubkey.new_unique(), b"swap", [])
+    ixs = wrap_sol_around(owner, 1_000, [swap])
+    wrapped = get_associated_token_address(owner, NATIVE_MINT)
+    assert [ix.program_id for ix in ixs] == [
+        ATA_PROGRAM_ID,
+        SYSTEM_PROGRAM_ID,
+        TOKEN_PROGRAM_ID,
+        swap.program_id,
+        TOKEN_PROGRAM_ID,
+    ]
+    assert ixs[1].accounts[1].pubkey == wrapped
+    assert bytes(ixs[2].data) == b"\x11"
+    assert ixs[3] == swap
+    assert bytes(ixs[4].data) == b"\x09"
+    assert [meta.pubkey for meta in ixs[4].accounts] == [wrapped, owner, owner]
+    with raises(ValueError):
+        wrap_sol_around(owner, 0, [swap])
+    with raises(ValueError, match="already close"):
+        wrap_sol_around(owner, 1_000, [swap, ixs[4]])
+
+
+def test_close_token_accounts() -> None:
+    owner, first, second = (Pubkey.new_unique() for _ in range(3))
+    ixs = close_token_accounts([first, second, first], owner, owner)
+    assert [ix.accounts[0].pubkey for ix in ixs] == [first, second]
+    assert all(ix.accounts[1].pubkey == owner for ix in ixs)
+    with raises(ValueError):
+        close_token_accounts([first, owner], owner, owner)
+
+
+def test_plan_associated_token_accounts() -> None:
+    payer, mint = Pubkey.new_unique(), Pubkey.new_unique()
+    wallets = [Pubkey.new_unique() for _ in range(30)]
+    atas = [get_associated_token_address(wallet, mint) for wallet in wallets]
+    initialized = bytes(108) + b"\x01" + bytes(56)
+    token_account = Account(2_039_280, initialized, TOKEN_PROGRAM_ID)
+    uninitialized = Account(2_039_280, bytes(165), TOKEN_PROGRAM_ID)
+    squatted = Account(2_039_280, initialized, SYSTEM_PROGRAM_ID)
+    pairs = [(wallet, mint) for wallet in wallets] + [(wallets[1], mint)]
+    plan = plan_associated_token_accounts(
+        payer,
+        pairs,
+        AccountsMap(
+            {atas[0]: token_account, atas[1]: uninitialized, atas[2]: squatted}
+        ),
+    )
+    assert plan.existing == [atas[0]]
+    assert plan.missing == atas[1:]
+    assert not plan.is_complete
+    assert [ix.accounts[1].pubkey for ix in plan.instructions] == atas[1:]
+    assert all(bytes(ix.data) == b"\x01" for ix in plan.instructions)
+    planned = [i for tx in plan.transactions for i in tx.instruction_indexes]
+    assert planned == list(range(29))
+    assert len(plan.transactions) == 3
+    assert {tx.message.account_keys[0] for tx in plan.transactions} == {payer}
+
+    calls: List[List[Pubkey]] = []
+
+    def fetch(addresses: List[Pubkey]) -> List[Optional[Account]]:
+        calls.append(addresses)
+        return [token_account for _ in addresses]
+
+    done = plan_associated_token_accounts(payer, pairs, fetch)
+    assert done.is_complete
+    assert done.existing == atas
+    assert done.transactions == []
+    assert calls == [atas]
+    with raises(ValueError):
+        plan_associated_token_accounts(payer, pairs, lambda addresses: [])
+    with raises(ValueError):
+        plan_associated_token_accounts(
+            payer, pairs, {}, token_program_id=Pubkey.new_unique()
+        )
+
+
+def parsed_token_account(
+    owner: Pubkey, mint: Pubkey, amount: int, **extra: Any
+) -> RpcKeyedAccountJsonParsed:
+    info: Dict[str, Any] = {
+        "mint": str(mint),
+        "owner": str(owner),
+        "state": "initialized",
+        "isNative": mint == NATIVE_MINT,
+        "tokenAmount": {
+            "amount": str(amount),
+            "decimals": 6,
+            "uiAmount": None,
+            "uiAmountString": "",
+        },
+    }
+    info.update(extra)
+    data = ParsedAccount("spl-token", {"type": "account", "info": info}, 165)
+    return RpcKeyedAccountJsonParsed(
+        Pubkey.new_unique(), AccountJSON(2_039_280, data, TOKEN_PROGRAM_ID)
+    )
+
+
+def test_plan_token_sweep() -> None:
+    owner, destination, mint = (Pubkey.new_unique() for _ in range(3))
+    empty = parsed_token_account(owner, mint, 0)
+    dust = parsed_token_account(owner, mint, 7)
+    wrapped = parsed_token_account(owner, NATIVE_MINT, 5_000)
+    rich = parsed_token_account(owner, mint, 1_000)
+    frozen = parsed_token_account(owner, mint, 0, state="frozen")
+    other = parsed_token_account(Pubkey.new_unique(), mint, 0)
+    delegated_close = parsed_token_account(
+        owner, mint, 0, closeAuthority=str(Pubkey.new_unique())
+    )
+    own_close = parsed_token_account(owner, mint, 0, closeAuthority=str(owner))
+    not_token = RpcKeyedAccountJsonParsed(
+        Pubkey.new_unique(),
+        AccountJSON(1, ParsedAccount("vote", {"type": "vote"}, 3762), owner),
+    )
+    accounts = [
+        empty,
+        dust,
+        wrapped,
+        rich,
+        frozen,
+        other,
+        delegated_close,
+        own_close,
+        not_token,
+        empty,
+    ]
+    plan = plan_token_sweep(owner, accounts, destination, {mint: 10})
+    assert plan.closed == [a.pubkey for a in (empty, dust, wrapped, own_close)]
+    assert plan.consolidated == [dust.pubkey]
+    assert plan.skipped == [
+        (rich.pubkey, SweepSkipReason.HasBalance),
+        (frozen.pubkey, SweepSkipReason.Frozen),
+        (other.pubkey, SweepSkipReason.WrongOwner),
+        (delegated_close.pubkey, SweepSkipReason.CloseAuthority),
+        (not_token.pubkey, SweepSkipReason.NotTokenAccount),
+    ]
+    assert plan.reclaimed_lamports == 4 * 2_039_280
+    create, transfer = plan.instructions[1:3]
+    destination_ata = get_associated_token_address(destination, mint)
+    assert create.program_id == ATA_PROGRAM_ID
+    assert create.accounts[0].pubkey == owner
+    assert transfer.accounts[2].pubkey == destination_ata
+    assert transfer.data == bytes([12]) + (7).to_bytes(8, "little") + bytes([6])
+    closes = [plan.instructions[i] for i in (0, 3, 4, 5)]
+    assert all(ix.data == bytes([9]) for ix in closes)
+    assert all(ix.accounts[1].pubkey == destination for ix in closes)
+    assert [i for tx in plan.transactions for i in tx.instruction_indexes] == list(
+        range(6)
+    )
+
+    own_ata = parsed_token_account(owner, mint, 3)
+    sweep_to_self = plan_token_sweep(
+        owner, [own_ata], owner, {mint: 10}, payer=destination
+    )
+    assert sweep_to_self.consolidated == [own_ata.pubkey]
+    assert sweep_to_self.instructions[0].accounts[0].pubkey == destination
+    ata = RpcKeyedAccountJsonParsed(
+        get_associated_token_address(owner, mint), own_ata.account
+    )
+    assert plan_token_sweep(owner, [ata], owner, {mint: 10}).skipped == [
+        (ata.pubkey, SweepSkipReason.Destination)
+    ]
+    empty_ata = RpcKeyedAccountJsonParsed(ata.pubkey, empty.account)
+    empty_to_self = plan_token_sweep(owner, [empty_ata], owner)
+    assert empty_to_self.closed == []
+    assert empty_to_self.skipped == [(ata.pubkey, SweepSkipReason.Destination)]
@@ -28,6 +28,15 @@ def test_wrong_size() -> None:
     assert excinfo.value.args[0] == msg
 
 
+def test_from_bytes_keypair_hint() -> None:
+    with raises(ValueError) as excinfo:
+        Pubkey.from_bytes(bytes(64))
+    assert "use Keypair.from_bytes(raw).pubkey() instead" in excinfo.value.args[0]
+    with raises(ValueError) as excinfo:
+        Pubkey.from_bytes(bytes(31))
+    assert excinfo.value.args[0] == "expected a sequence of length 32 (got 31)"
+
+
 @mark.parametrize("test_input,expected", on_curve_data)
 def test_is_on_curve_method(test_input: bytes, expected: bool) -> None:
     pubkey = Pubkey(test_input)
@@ -48,6 +57,24 @@ def test_bytes_representation() -> None:
     assert bytes(pubkey) == data
 
 
+def test_to_short_string() -> None:
+    pubkey = Pubkey(bytes([1] * 32))
+    assert pubkey.to_short_string() == "4vJ9..kLKi"
+    assert pubkey.to_short_string(2, 0) == "4v.."
+    assert pubkey.to_short_string(30, 30) == str(pubkey)
+
+
+def test_explorer_url() -> None:
+    pubkey = Pubkey(bytes([1] * 32))
+    base = f"https://explorer.solana.com/address/{pubkey}"
+    assert pubkey.explorer_url() == base
+    assert pubkey.explorer_url("testnet") == f"{base}?cluster=testnet"
+    assert (
+        pubkey.explorer_url("http://localhost:8899")
+        == f"{base}?cluster=custom&customUrl=http%3A%2F%2Flocalhost%3A8899"
+    )
+
+
 def test_equality() -> None:
     assert Pubkey.default() == Pubkey.default()
 
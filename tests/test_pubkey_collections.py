@@ -0,0 +1,124 @@
+import gc
+import weakref
+
+from pytest import raises
+from solders.pubkey import Pubkey
+from solders.keypair import Keypair
+from solders.pubkey_collections import (
+    PubkeyMap,
+    PubkeySet,
+    array_to_pubkeys,
+    array_to_signatures,
+    pubkeys_to_array,
+    signatures_to_array,
+)
+
+KEYS = sorted(Pubkey.new_unique() for _ in range(4))
+
+
+def test_set_membership() -> None:
+    pubkeys = PubkeySet(KEYS[:2])
+    assert KEYS[0] in pubkeys
+    assert KEYS[2] not in pubkeys
+    assert pubkeys.add(KEYS[2])
+    assert not pubkeys.add(KEYS[2])
+    assert pubkeys.discard(KEYS[0])
+    assert not pubkeys.discard(KEYS[0])
+    assert len(pubkeys) == 2
+    assert pubkeys.update(KEYS) == 2
+    assert sorted(pubkeys) == KEYS
+    keys = iter(pubkeys)
+    assert keys.__length_hint__() == len(KEYS)
+    pubkeys.add(Pubkey.new_unique())
+    assert len(list(keys)) == len(KEYS)
+
+
+def test_set_insert_new() -> None:
+    pubkeys = PubkeySet([KEYS[0]])
+    assert pubkeys.insert_new([KEYS[1], KEYS[0], KEYS[1], KEYS[2]]) == KEYS[1:3]
+    assert pubkeys.insert_new(KEYS[:3]) == []
+
+
+def test_set_operations() -> None:
+    left = PubkeySet(KEYS[:3])
+    right = PubkeySet(KEYS[1:])
+    assert (left | right).to_list() == KEYS
+    assert (left & right).to_list() == KEYS[1:3]
+    assert (left - right).to_list() == KEYS[:1]
+    assert left.union(right) == PubkeySet(KEYS)
+    assert left != right
+
+
+def test_set_bytes_roundtrip() -> None:
+    data = b"".join(bytes(pubkey) for pubkey in KEYS)
+    pubkeys = PubkeySet.from_bytes(data)
+    assert bytes(pubkeys) == data
+    assert PubkeySet.from_bytes(bytes(pubkeys)) == pubkeys
+    assert PubkeySet().update_from_bytes(data + bytes(KEYS[0])) == 4
+    with raises(ValueError):
+        PubkeySet.from_bytes(data[:-1])
+
+
+def test_map() -> None:
+    balances = PubkeyMap({KEYS[0]: 1})
+    balances[KEYS[1]] = 2
+    assert balances[KEYS[0]] == 1
+    assert balances.get(KEYS[2]) is None
+    assert balances.get(KEYS[2], 0) == 0
+    assert KEYS[1] in balances
+    assert sorted(balances) == KEYS[:2]
+    assert balances.items() == [(KEYS[0], 1), (KEYS[1], 2)]
+    assert balances.key_set() == PubkeySet(KEYS[:2])
+    assert balances.pop(KEYS[0]) == 1
+    assert balances.pop(KEYS[0], "gone") == "gone"
+    assert balances.pop(KEYS[0], None) is None
+    with raises(KeyError):
+        balances.pop(KEYS[0])
+    assert balances.to_dict() == {KEYS[1]: 2}
+    del balances[KEYS[1]]
+    assert len(balances) == 0
+    with raises(KeyError):
+        balances[KEYS[1]]
+    with raises(KeyError):
+        del balances[KEYS[1]]
+
+
+class _Marker:
+    pass
+
+
+def test_map_gc() -> None:
+    balances = PubkeyMap()
+    marker = _Marker()
+    balances[KEYS[0]] = (balances, marker)
+    assert gc.is_tracked(balances)
+    ref = weakref.ref(marker)
+    del balances, marker
+    gc.collect()
+    assert ref() is None
+
+
+def test_map_from_bytes() -> None:
+    data = b"".join(bytes(pubkey) for pubkey in KEYS[:2])
+    balances = PubkeyMap.from_bytes(data, ["a", "b"])
+    assert balances.keys() == KEYS[:2]
+    assert balances[KEYS[1]] == "b"
+    with raises(ValueError):
+        PubkeyMap.from_bytes(data, ["a"])
+    with raises(ValueError):
+        PubkeyMap.from_bytes(data[:-1], ["a", "b"])
+
+
+def test_array_roundtrip() -> None:
+    pubkeys = [Pubkey.new_unique() for _ in range(3)]
+    buf = pubkeys_to_array(pubkeys)
+    assert buf == b"".join(bytes(p) for p in pubkeys)
+    assert array_to_pubkeys(buf) == pubkeys
+    kp = Keypair()
+    signatures = [kp.sign_message(bytes([i])) for i in range(3)]
+    buf = signatures_to_array(signatures)
+    assert len(buf) == 192
+    assert array_to_signatures(buf) == signatures
+    assert array_to_pubkeys(b"") == []
+    with raises(ValueError):
+        array_to_signatures(bytes(65))
@@ -0,0 +1,68 @@
+from pytest import approx, raises
+from solders.pubkey import Pubkey
+from solders.rpc.quorum import SUPERMAJORITY_PERCENT, StakeWeights
+from solders.rpc.responses import (
+    RpcContactInfo,
+    RpcVoteAccountInfo,
+    RpcVoteAccountStatus,
+)
+
+NODES = [Pubkey.new_unique() for _ in range(4)]
+
+
+def _voter(node: Pubkey, stake: int) -> RpcVoteAccountInfo:
+    return RpcVoteAccountInfo(Pubkey.new_unique(), node, stake, 0, True, [], 0, 0)
+
+
+def _node(node: Pubkey, version: str) -> RpcContactInfo:
+    return RpcContactInfo(node, None, None, None, version, None, None)
+
+
+WEIGHTS = StakeWeights(
+    RpcVoteAccountStatus(
+        [_voter(NODES[0], 500), _voter(NODES[1], 200), _voter(NODES[2], 100)],
+        [_voter(NODES[3], 200)],
+    )
+)
+GOSSIP = [
+    _node(NODES[0], "1.14.10"),
+    _node(NODES[1], "1.14.5-rc1"),
+    _node(NODES[3], "1.13.5"),
+]
+
+
+def test_totals() -> None:
+    assert len(WEIGHTS) == 4
+    assert WEIGHTS.total_stake == 1000
+    assert WEIGHTS.delinquent_stake == 200
+    assert WEIGHTS.stake_of([NODES[0], NODES[2]]) == 600
+    assert WEIGHTS.percent(250) == 25.0
+    assert WEIGHTS.min_stake_for(SUPERMAJORITY_PERCENT) == 667
+    assert WEIGHTS.percent(667) >= SUPERMAJORITY_PERCENT > WEIGHTS.percent(666)
+    assert WEIGHTS.min_stake_for(100) == 1000
+    assert WEIGHTS.min_stake_for(0) == 0
+    for percent in (float("nan"), -1, 100.5):
+        with raises(ValueError):
+            WEIGHTS.min_stake_for(percent)
+
+
+def test_versions() -> None:
+    assert WEIGHTS.stake_by_version(GOSSIP) == {
+        "1.14.10": 500,
+        "1.14.5-rc1": 200,
+        "1.13.5": 200,
+        None: 100,
+    }
+    assert WEIGHTS.stake_at_least_version(GOSSIP, "1.14.5") == 700
+    assert WEIGHTS.stake_at_least_version(GOSSIP, "1.14.6") == 500
+    assert WEIGHTS.stake_at_least_version(GOSSIP, "1.14") == 700
+    assert WEIGHTS.percent_at_least_version(GOSSIP, "1.13") == approx(90.0)
+    assert WEIGHTS.percent_at_least_version(GOSSIP, "1.14.6") < SUPERMAJORITY_PERCENT
+    with raises(ValueError):
+        WEIGHTS.stake_at_least_version(GOSSIP, "latest")
+
+
+def test_empty() -> None:
+    weights = StakeWeights(RpcVoteAccountStatus([], []))
+    assert weights.percent(0) == 0.0
+    assert weights.min_stake_for(SUPERMAJORITY_PERCENT) == 0
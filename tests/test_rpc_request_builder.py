@@ -1,6 +1,8 @@
 """These tests are mainly about getting mypy to check stuff, as it doesn't check doc examples."""
 
-from typing import List, Union
+import json
+from typing import Any, List, Union
+from pytest import mark, raises
 from solders.rpc.requests import (
     GetAccountInfo,
     GetBalance,
@@ -32,6 +34,7 @@ from solders.rpc.requests import (
     GetMultipleAccounts,
     GetProgramAccounts,
     GetRecentPerformanceSamples,
+    GetRecentPrioritizationFees,
     GetSignaturesForAddress,
     GetSignatureStatuses,
     GetSlot,
@@ -104,13 +107,21 @@ from solders.rpc.config import (
     RpcSimulateTransactionAccountsConfig,
     RpcSimulateTransactionConfig,
 )
-from solders.rpc.filter import Memcmp
+from solders.rpc.filter import Memcmp, MemcmpEncoding
+from solders.rpc.responses import StakeActivationState
 from solders.hash import Hash
 from solders.keypair import Keypair
-from solders.transaction import Transaction
-from solders.transaction_status import UiTransactionEncoding, TransactionDetails
+from solders.transaction import Legacy, Transaction
+from solders.transaction_status import (
+    Reward,
+    RewardType,
+    TransactionBinaryEncoding,
+    TransactionConfirmationStatus,
+    TransactionDetails,
+    UiTransactionEncoding,
+)
 from solders.signature import Signature
-from solders.message import Message
+from solders.message import Message, MessageV0
 from solders.instruction import Instruction, AccountMeta
 from solders.commitment_config import CommitmentLevel
 from solders.account_decoder import UiAccountEncoding, UiDataSliceConfig
@@ -211,6 +222,16 @@ def test_get_fee_for_message() -> None:
     assert GetFeeForMessage.from_json(as_json) == req
 
 
+def test_get_fee_for_message_v0() -> None:
+    msg = MessageV0.try_compile(Pubkey.new_unique(), [], [], Hash.new_unique())
+    req = GetFeeForMessage(msg)
+    assert req.message == msg
+    assert GetFeeForMessage.from_json(req.to_json()) == req
+    raw = '{"method":"getFeeForMessage","jsonrpc":"2.0","id":0,"params":["AAAA"]}'
+    with raises(ValueError):
+        GetFeeForMessage.from_json(raw)
+
+
 def test_get_first_available_block() -> None:
     req = GetFirstAvailableBlock(123)
     as_json = req.to_json()
@@ -344,12 +365,42 @@ def test_get_program_accounts() -> None:
     assert GetProgramAccounts.from_json(as_json) == req
 
 
+def test_program_accounts_config_presets() -> None:
+    wallet = Pubkey.new_unique()
+    by_owner = RpcProgramAccountsConfig.token_accounts_by_owner(wallet)
+    assert by_owner.filters == [165, Memcmp(offset=32, bytes_=str(wallet))]
+    assert by_owner.account_config == RpcAccountInfoConfig.default()
+    by_mint = RpcProgramAccountsConfig.token_accounts_by_mint(wallet)
+    assert by_mint.filters == [165, Memcmp(offset=0, bytes_=str(wallet))]
+    acc_info_config = RpcAccountInfoConfig(encoding=UiAccountEncoding.Base64)
+    by_withdrawer = RpcProgramAccountsConfig.stake_accounts_by_withdrawer(
+        wallet, acc_info_config
+    )
+    assert by_withdrawer.filters == [200, Memcmp(offset=44, bytes_=str(wallet))]
+    assert by_withdrawer.account_config == acc_info_config
+    by_staker = RpcProgramAccountsConfig.stake_accounts_by_staker(wallet)
+    assert by_staker.filters == [200, Memcmp(offset=12, bytes_=str(wallet))]
+
+
 def test_get_recent_performance_samples() -> None:
     req = GetRecentPerformanceSamples(5)
     as_json = req.to_json()
     assert GetRecentPerformanceSamples.from_json(as_json) == req
 
 
+def test_get_recent_prioritization_fees() -> None:
+    accounts = [Pubkey.new_unique(), Pubkey.new_unique()]
+    req = GetRecentPrioritizationFees(accounts)
+    assert req.locked_writable_accounts == accounts
+    as_json = req.to_json()
+    assert json.loads(as_json)["params"] == [[str(a) for a in accounts]]
+    assert GetRecentPrioritizationFees.from_json(as_json) == req
+    unscoped = GetRecentPrioritizationFees()
+    assert unscoped.locked_writable_accounts is None
+    assert "params" not in json.loads(unscoped.to_json())
+    assert GetRecentPrioritizationFees.from_json(unscoped.to_json()) == unscoped
+
+
 def test_get_signatures_for_address() -> None:
     config = RpcSignaturesForAddressConfig(limit=10)
     req = GetSignaturesForAddress(Pubkey.default(), config)
@@ -546,6 +597,27 @@ def test_logs_subscribe() -> None:
     req2 = LogsSubscribe(RpcTransactionLogsFilterMentions(Pubkey.default()), config)
     as_json2 = req2.to_json()
     assert LogsSubscribe.from_json(as_json2) == req2
+    req3 = LogsSubscribe(RpcTransactionLogsFilter.AllWithVotes)
+    assert LogsSubscribe.from_json(req3.to_json()) == req3
+    assert req3.filter_ == RpcTransactionLogsFilter.AllWithVotes
+
+
+@mark.parametrize(
+    "mentions",
+    [
+        "[]",
+        '["11111111111111111111111111111111", "11111111111111111111111111111111"]',
+        '["foo"]',
+    ],
+)
+def test_logs_subscribe_invalid_mentions(mentions: str) -> None:
+    raw = (
+        '{"method":"logsSubscribe","jsonrpc":"2.0","id":0,"params":[{"mentions":'
+        + mentions
+        + "}]}"
+    )
+    with raises(ValueError):
+        LogsSubscribe.from_json(raw)
 
 
 def test_program_subscribe() -> None:
@@ -655,3 +727,62 @@ def test_batch() -> None:
         '"params":["11111111111111111111111111111111",1000]}]'
     )
     assert batch_from_json(as_json) == reqs
+
+
+@mark.parametrize(
+    "val,expected",
+    [
+        (UiAccountEncoding.Binary, "binary"),
+        (UiAccountEncoding.Base58, "base58"),
+        (UiAccountEncoding.Base64, "base64"),
+        (UiAccountEncoding.JsonParsed, "jsonParsed"),
+        (UiAccountEncoding.Base64Zstd, "base64+zstd"),
+        (CommitmentLevel.Processed, "processed"),
+        (CommitmentLevel.Confirmed, "confirmed"),
+        (CommitmentLevel.Finalized, "finalized"),
+        (RpcLargestAccountsFilter.Circulating, "circulating"),
+        (RpcLargestAccountsFilter.NonCirculating, "nonCirculating"),
+        (RpcTransactionLogsFilter.All, "all"),
+        (RpcTransactionLogsFilter.AllWithVotes, "allWithVotes"),
+        (RpcBlockSubscribeFilter.All, "all"),
+        (MemcmpEncoding.Binary, "binary"),
+        (StakeActivationState.Activating, "activating"),
+        (StakeActivationState.Active, "active"),
+        (StakeActivationState.Deactivating, "deactivating"),
+        (StakeActivationState.Inactive, "inactive"),
+        (UiTransactionEncoding.Binary, "binary"),
+        (UiTransactionEncoding.Base64, "base64"),
+        (UiTransactionEncoding.Base58, "base58"),
+        (UiTransactionEncoding.Json, "json"),
+        (UiTransactionEncoding.JsonParsed, "jsonParsed"),
+        (TransactionDetails.Full, "full"),
+        (TransactionDetails.Signatures, "signatures"),
+        (TransactionDetails.None_, "none"),
+        (TransactionBinaryEncoding.Base58, "base58"),
+        (TransactionBinaryEncoding.Base64, "base64"),
+        (RewardType.Fee, "Fee"),
+        (RewardType.Rent, "Rent"),
+        (RewardType.Staking, "Staking"),
+        (RewardType.Voting, "Voting"),
+        (TransactionConfirmationStatus.Processed, "processed"),
+        (TransactionConfirmationStatus.Confirmed, "confirmed"),
+        (TransactionConfirmationStatus.Finalized, "finalized"),
+        (Legacy.Legacy, "legacy"),
+    ],
+)
+def test_enum_string_round_trip(val: Any, expected: str) -> None:
+    assert str(val) == expected
+    assert type(val).from_string(expected) == val
+
+
+def test_enum_from_string_invalid() -> None:
+    with raises(ValueError):
+        UiTransactionEncoding.from_string("base32")
+
+
+def test_reward_type_string_matches_rpc() -> None:
+    raw = '{"pubkey":"11111111111111111111111111111111","lamports":5,"postBalance":10,"rewardType":"Fee","commission":null}'
+    reward_type = Reward.from_json(raw).reward_type
+    assert reward_type is not None
+    assert reward_type == RewardType.Fee
+    assert RewardType.from_string(str(reward_type)) == reward_type
@@ -1,5 +1,5 @@
 from pathlib import Path
-from typing import List, Union, Dict
+from typing import List, Optional, Union, Dict
 from pytest import mark, raises
 from jsonalias import Json
 from solders.errors import SerdeJSONError
@@ -45,6 +45,7 @@ from solders.rpc.responses import (
     GetProgramAccountsWithContextMaybeJsonParsedResp,
     GetProgramAccountsMaybeJsonParsedResp,
     GetRecentPerformanceSamplesResp,
+    GetRecentPrioritizationFeesResp,
     GetSignaturesForAddressResp,
     GetSignatureStatusesResp,
     GetSlotResp,
@@ -82,6 +83,7 @@ from solders.rpc.responses import (
     RpcKeyedAccount,
     RpcKeyedAccountJsonParsed,
     RpcPerfSample,
+    RpcPrioritizationFee,
     RpcConfirmedTransactionStatusWithSignature,
     RpcSimulateTransactionResult,
     RpcStakeActivation,
@@ -116,7 +118,13 @@ from solders.rpc.responses import (
     SubscriptionResult,
     batch_from_json,
     batch_to_json,
+    from_json_with_extras,
+    WithContext,
+    parse_block,
+    parse_account_notification,
     parse_notification,
+    parse_program_accounts,
+    parse_token_accounts,
     parse_websocket_message,
 )
 from solders.rpc.errors import (
@@ -129,6 +137,7 @@ from solders.hash import Hash
 from solders.account import Account, AccountJSON
 from solders.epoch_schedule import EpochSchedule
 from solders.pubkey import Pubkey
+from solders.rpc.filter import AccountFilter, Memcmp
 from solders.account_decoder import ParsedAccount, UiTokenAmount
 from solders.signature import Signature
 from solders.transaction_status import (
@@ -146,6 +155,15 @@ from solders.transaction_status import (
     TransactionConfirmationStatus,
     TransactionErrorInstructionError,
     InstructionErrorCustom,
+    transactions_to_columns,
+    block_fee_summary,
+    UiConfirmedBlock,
+    EncodedTransactionWithStatusMeta,
+    address_index,
+    ProgramUsage,
+    ProgramUsageStats,
+    LamportConservation,
+    check_lamport_conservation,
 )
 from solders.message import MessageHeader, Message
 from solders.transaction import VersionedTransaction
@@ -360,6 +378,11 @@ def test_get_block_commitment() -> None:
         42,
     )
     assert parsed == expected
+    assert expected.voted_stake() == 42
+    assert expected.voted_stake(31) == 32
+    assert expected.voted_stake_fraction(31) == 32 / 42
+    assert GetBlockCommitmentResp(None, 42).voted_stake() == 0
+    assert GetBlockCommitmentResp(None, 0).voted_stake_fraction() == 0.0
 
 
 @mark.parametrize(
@@ -1374,6 +1397,29 @@ def test_get_recent_performance_samples() -> None:
     )
 
 
+def test_get_recent_prioritization_fees() -> None:
+    raw = """{
+  "jsonrpc": "2.0",
+  "result": [
+    {
+      "slot": 348125,
+      "prioritizationFee": 0
+    },
+    {
+      "slot": 348126,
+      "prioritizationFee": 1000
+    }
+  ],
+  "id": 1
+}"""
+    parsed = GetRecentPrioritizationFeesResp.from_json(raw)
+    assert isinstance(parsed, GetRecentPrioritizationFeesResp)
+    assert parsed.value == [
+        RpcPrioritizationFee(slot=348125, prioritization_fee=0),
+        RpcPrioritizationFee(slot=348126, prioritization_fee=1000),
+    ]
+
+
 def test_get_signatures_for_address() -> None:
     raw = """{
   "jsonrpc": "2.0",
@@ -1679,6 +1725,9 @@ def test_get_token_accounts_by_owner_base64() -> None:
         executable=False,
         rent_epoch=341,
     )
+    with_context = parsed.with_context()
+    assert with_context.slot == 147478898
+    assert with_context.value == parsed.value
 
 
 def test_get_token_accounts_by_delegate_json_parsed() -> None:
@@ -1991,6 +2040,221 @@ def test_get_transaction(path: str) -> None:
         # don't need so many assertions here since we already have tests for Message
 
 
+@mark.parametrize(
+    "path",
+    [
+        "get_transaction_json_encoding.json",
+        "get_transaction_base64_encoding.json",
+        "get_transaction_json_parsed_encoding.json",
+    ],
+)
+def test_transactions_to_columns(path: str) -> None:
+    raw = (Path(__file__).parent / "data" / path).read_text()
+    parsed = GetTransactionResp.from_json(raw)
+    encoded = parsed.value
+    assert encoded is not None
+    cols = transactions_to_columns([encoded, encoded])
+    assert cols["slot"] == [147558327, 147558327]
+    assert cols["block_time"] == [1661417105, 1661417105]
+    assert cols["signature"][0] == Signature.from_string(
+        "4zExa66hUSr28i2ma6EJwEsm3nZo4VFryEyRYE5Zrr4Q6dZpP1ctzsoMqUhLnp6iHGPp5MB722rZXRBKg927WXN9"
+    )
+    assert cols["fee"] == [5000, 5000]
+    assert cols["success"] == [True, True]
+    assert cols["err"] == [None, None]
+    assert cols["program_ids"][0] == [
+        Pubkey.from_string("Vote111111111111111111111111111111111111111")
+    ]
+    assert cols["balance_deltas"][0] == [-5000, 0, 0, 0, 0]
+
+
+@mark.parametrize(
+    "path",
+    [
+        "get_block_json_encoding.json",
+        "get_block_base64_encoding.json",
+        "get_block_json_parsed_encoding.json",
+    ],
+)
+def test_block_fee_summary(path: str) -> None:
+    raw = (Path(__file__).parent / "data" / path).read_text()
+    block = GetBlockResp.from_json(raw).value
+    assert block is not None
+    summary = block_fee_summary(block)
+    assert summary["transaction_count"] == 25
+    assert summary["total_fees"] == 145000
+    assert summary["base_fees"] == 125000
+    assert summary["priority_fees"] == 20000
+    leader = Pubkey.from_string("dv1ZAGvdsz5hHLwWXsVnM94hWf1pjbKVau1QVkaMJ92")
+    assert summary["leader_rewards"] == {leader: 72500}
+    assert summary["burned_fees"] == 72500
+    signatures_only = UiConfirmedBlock(
+        block.previous_blockhash,
+        block.blockhash,
+        block.parent_slot,
+        None,
+        [Signature.default()],
+        block.rewards,
+        block.block_time,
+        block.block_height,
+    )
+    with raises(ValueError, match="transaction_details"):
+        block_fee_summary(signatures_only)
+    first_tx = block.transactions[0].transaction
+    without_meta = UiConfirmedBlock(
+        block.previous_blockhash,
+        block.blockhash,
+        block.parent_slot,
+        [
+            *block.transactions,
+            EncodedTransactionWithStatusMeta(first_tx, None, None),
+        ],
+        None,
+        block.rewards,
+        block.block_time,
+        block.block_height,
+    )
+    assert block_fee_summary(without_meta) == summary
+
+
+@mark.parametrize(
+    "path",
+    [
+        "get_block_json_encoding.json",
+        "get_block_base64_encoding.json",
+        "get_block_json_parsed_encoding.json",
+    ],
+)
+def test_program_usage_stats(path: str) -> None:
+    raw = (Path(__file__).parent / "data" / path).read_text()
+    block = GetBlockResp.from_json(raw).value
+    assert block is not None
+    stats = ProgramUsageStats()
+    stats.add_blocks([block, block])
+    assert stats.blocks == 2
+    assert stats.transactions == 50
+    assert len(stats) == 11
+    token = Pubkey.from_string("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+    assert stats.get(token) == ProgramUsage(0, 22, 14, 0, 0)
+    top_program = Pubkey.from_string("2TfB33aLaneQb5TNVwyDz3jSZXS6jdW2ARw1Dgf84XCG")
+    usage = stats.programs()[top_program]
+    assert usage == ProgramUsage(36, 0, 36, 0, 1446338)
+    assert usage.failure_rate == 0.0
+    assert usage.mean_compute_units == 40176
+    assert [program for program, _ in stats.top(2)] == [top_program, token]
+    vote = Pubkey.from_string("Vote111111111111111111111111111111111111111")
+    assert stats.get(vote) == ProgramUsage(4, 0, 4, 0, 0)
+    without_votes = ProgramUsageStats(skip_votes=True)
+    without_votes.add_block(block)
+    assert without_votes.transactions == 23
+    assert without_votes.get(vote) is None
+
+
+@mark.parametrize(
+    "path",
+    [
+        "get_block_json_encoding.json",
+        "get_block_base64_encoding.json",
+        "get_block_json_parsed_encoding.json",
+    ],
+)
+def test_block_without_votes(path: str) -> None:
+    raw = (Path(__file__).parent / "data" / path).read_text()
+    block = GetBlockResp.from_json(raw).value
+    assert block is not None
+    txs = block.transactions
+    assert txs is not None
+    assert [i for i, tx in enumerate(txs) if tx.is_vote()] == [0, 17]
+    stripped = block.without_votes()
+    stripped_txs = stripped.transactions
+    assert stripped_txs is not None
+    assert len(stripped_txs) == 23
+    assert not any(tx.is_vote() for tx in stripped_txs)
+    assert stripped.blockhash == block.blockhash
+    assert stripped.rewards == block.rewards
+
+
+@mark.parametrize(
+    "path",
+    [
+        "get_block_json_encoding.json",
+        "get_block_base64_encoding.json",
+        "get_block_json_parsed_encoding.json",
+    ],
+)
+def test_address_index(path: str) -> None:
+    raw = (Path(__file__).parent / "data" / path).read_text()
+    block = GetBlockResp.from_json(raw).value
+    assert block is not None
+    txs = block.transactions
+    assert txs is not None
+    index = address_index(txs)
+    vote_program = Pubkey.from_string("Vote111111111111111111111111111111111111111")
+    vote_sigs = [tx.transaction.signatures[0] for tx in txs if tx.is_vote()]
+    assert len(vote_sigs) == 2
+    assert index[vote_program] == vote_sigs
+    all_sigs = {sig for sigs in index.values() for sig in sigs}
+    assert all_sigs == {tx.transaction.signatures[0] for tx in txs}
+
+
+@mark.parametrize(
+    "path",
+    [
+        "get_block_json_encoding.json",
+        "get_block_base64_encoding.json",
+        "get_block_json_parsed_encoding.json",
+    ],
+)
+def test_lamport_conservation_block(path: str) -> None:
+    raw = (Path(__file__).parent / "data" / path).read_text()
+    block = GetBlockResp.from_json(raw).value
+    assert block is not None
+    txs = block.transactions
+    assert txs is not None
+    for tx in txs:
+        assert tx.meta is not None
+        checked = check_lamport_conservation(tx.meta)
+        assert checked.is_conserved, checked.anomalies
+        assert checked.pre_total - checked.post_total == checked.fee
+
+
+def _meta(
+    fee: int,
+    pre: List[int],
+    post: List[int],
+    err: Optional[TransactionErrorInstructionError] = None,
+    rewards: Optional[List[Reward]] = None,
+) -> UiTransactionStatusMeta:
+    return UiTransactionStatusMeta(
+        err, fee, pre, post, None, None, None, None, rewards, None, None
+    )
+
+
+def test_lamport_conservation_anomalies() -> None:
+    rent = Reward(Pubkey.default(), -10, 90, RewardType.Rent, None)
+    checked = check_lamport_conservation(_meta(5, [105, 0], [0, 90], rewards=[rent]))
+    assert checked == LamportConservation(105, 90, 5, -10, 0, [])
+    lost = check_lamport_conservation(_meta(5, [100, 0], [50, 40]))
+    assert lost.imbalance == -5
+    assert lost.anomalies == [
+        "balances lost 5 lamports more than the fee and rewards explain"
+    ]
+    mismatched = check_lamport_conservation(_meta(5, [100, 0], [95]))
+    assert mismatched.anomalies == ["2 pre balances but 1 post balances"]
+    broke = check_lamport_conservation(_meta(5, [3], [0]))
+    assert "fee payer had 3 lamports, less than the fee of 5" in broke.anomalies
+    err = TransactionErrorInstructionError(0, InstructionErrorCustom(1))
+    refunded = check_lamport_conservation(_meta(5, [100, 10], [100, 5], err))
+    assert refunded.imbalance == 0
+    assert refunded.anomalies == [
+        "failed transaction's fee payer paid 0 lamports, less than the fee of 5"
+    ]
+    with raises(ValueError, match="sum of pre balances overflows"):
+        check_lamport_conservation(_meta(0, [2**64 - 1, 1], [0, 0]))
+    with raises(ValueError, match="imbalance overflows"):
+        check_lamport_conservation(_meta(0, [0], [2**63]))
+
+
 def test_get_transaction_count() -> None:
     raw = '{ "jsonrpc": "2.0", "result": 268, "id": 1 }'
     parsed = GetTransactionCountResp.from_json(raw)
@@ -2173,6 +2437,30 @@ def test_batch() -> None:
     )
 
 
+def test_from_json_with_extras() -> None:
+    raw = """{
+"jsonrpc": "2.0",
+"result": { "context": { "slot": 1, "region": "eu" }, "value": 0, "pending": null },
+"id": 1,
+"servedBy": ["node-3", "node-4"]
+}"""
+    parsed = from_json_with_extras(raw, GetBalanceResp)
+    assert parsed.value == GetBalanceResp.from_json(raw)
+    assert parsed.has_extras
+    assert parsed.extras() == {
+        "result.context.region": "eu",
+        "result.pending": None,
+        "servedBy": ["node-3", "node-4"],
+    }
+    plain = from_json_with_extras(GetBalanceResp.from_json(raw).to_json(), GetBalanceResp)
+    assert not plain.has_extras
+    assert plain.extras() == {}
+    error = '{"jsonrpc":"2.0","error":{"code":-32601,"message":"Method not found"},"id":1}'
+    assert not from_json_with_extras(error, GetBalanceResp).has_extras
+    with raises(ValueError):
+        from_json_with_extras(raw, Pubkey)
+
+
 def test_account_notification() -> None:
     raw = """{
   "jsonrpc": "2.0",
@@ -2413,6 +2701,9 @@ def test_block_notification() -> None:
     result = parsed.result
     assert isinstance(result, BlockNotificationResult)
     assert isinstance(result.value, RpcBlockUpdate)
+    [filtered] = parse_websocket_message(raw, AccountFilter(Pubkey.new_unique()))
+    assert filtered.result.value.block.transactions == []
+    assert parse_websocket_message(raw, AccountFilter()) == [parsed]
 
 
 def test_logs_notification() -> None:
@@ -2473,8 +2764,79 @@ def test_program_notification() -> None:
     result = parsed.result
     assert isinstance(result, ProgramNotificationResult)
     assert isinstance(result.value, RpcKeyedAccount)
+    with_context = parsed.with_context()
+    assert with_context == WithContext(result.value, result.context)
+    assert with_context == result.with_context()
+    assert with_context.slot == 5208469
+    system = Pubkey.default()
+    assert parse_websocket_message(raw, AccountFilter(system)) == [parsed]
+    assert parse_websocket_message(raw, AccountFilter(Pubkey.new_unique())) == []
+    assert parse_websocket_message(raw, AccountFilter(max_data_len=10)) == []
+
+
+def test_parse_program_accounts() -> None:
+    owner = Pubkey.new_unique()
+    keyed = [
+        RpcKeyedAccount(Pubkey.new_unique(), Account(1, b"match", owner)),
+        RpcKeyedAccount(Pubkey.new_unique(), Account(1, b"other", owner)),
+        RpcKeyedAccount(Pubkey.new_unique(), Account(1, b"match", Pubkey.default())),
+    ]
+    account_filter = AccountFilter(owner, memcmp=[Memcmp(0, b"ma")])
+    raw = GetProgramAccountsResp(keyed).to_json()
+    parsed = parse_program_accounts(raw, account_filter)
+    assert parsed == GetProgramAccountsResp(keyed[:1])
+    context = RpcResponseContext(100)
+    raw_with_context = GetProgramAccountsWithContextResp(keyed, context).to_json()
+    parsed_with_context = parse_program_accounts(raw_with_context, account_filter)
+    assert parsed_with_context == WithContext(keyed[:1], context)
+    raw_err = '{"jsonrpc":"2.0","error":{"code":-32602,"message":"Invalid param: WrongSize"},"id":1}'
+    parsed_err = parse_program_accounts(raw_err, account_filter)
+    assert isinstance(parsed_err, InvalidParamsMessage)
 
 
+def test_parse_token_accounts() -> None:
+    mint = Pubkey.new_unique()
+    keyed = [
+        RpcKeyedAccount(Pubkey.new_unique(), Account(1, bytes(mint), Pubkey.default())),
+        RpcKeyedAccount(Pubkey.new_unique(), Account(1, bytes(32), Pubkey.default())),
+    ]
+    context = RpcResponseContext(100)
+    raw = GetTokenAccountsByOwnerResp(keyed, context).to_json()
+    assert parse_token_accounts(raw) == WithContext(keyed, context)
+    account_filter = AccountFilter(memcmp=[Memcmp(0, bytes(mint))])
+    assert parse_token_accounts(raw, account_filter) == WithContext(keyed[:1], context)
+
+
+def test_parse_account_notification() -> None:
+    keyed = RpcKeyedAccount(Pubkey.new_unique(), Account(1, b"", Pubkey.default()))
+    result = ProgramNotificationResult(keyed, RpcResponseContext(5))
+    raw = ProgramNotification(result, 3).to_json()
+    assert parse_account_notification(raw) == (3, WithContext(keyed, result.context))
+    root = '{"jsonrpc":"2.0","method":"rootNotification","params":{"result":4,"subscription":0}}'
+    with raises(ValueError):
+        parse_account_notification(root)
+
+
+
+def test_parse_block() -> None:
+    raw = (Path(__file__).parent / "data" / "get_block_json_encoding.json").read_text()
+    txs = GetBlockResp.from_json(raw).value.transactions
+    assert parse_block(raw, AccountFilter()) == GetBlockResp.from_json(raw)
+    assert parse_block(raw, AccountFilter(Pubkey.new_unique())).value.transactions == []
+    vote = Pubkey.from_string("Vote111111111111111111111111111111111111111")
+    votes = parse_block(raw, AccountFilter(vote)).value.transactions
+    assert votes == [tx for tx in txs if tx.is_vote()]
+    without_votes = parse_block(raw, skip_votes=True).value
+    assert without_votes == GetBlockResp.from_json(raw).value.without_votes()
+    assert parse_block(raw, AccountFilter(vote), skip_votes=True).value.transactions == []
+    program = Pubkey.from_string("2TfB33aLaneQb5TNVwyDz3jSZXS6jdW2ARw1Dgf84XCG")
+    long_data = AccountFilter(program, min_data_len=100)
+    assert len(parse_block(raw, long_data).value.transactions) == 3
+    prefix = AccountFilter(program, memcmp=[Memcmp(0, bytes.fromhex("4239d8fb"))])
+    assert len(parse_block(raw, prefix).value.transactions) == 15
+    raw_err = '{"jsonrpc":"2.0","error":{"code":-32602,"message":"Invalid param: WrongSize"},"id":1}'
+    assert isinstance(parse_block(raw_err, AccountFilter(vote)), InvalidParamsMessage)
+
 def test_signature_notification() -> None:
     raw = """{
   "jsonrpc": "2.0",
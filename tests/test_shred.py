@@ -0,0 +1,109 @@
+import struct
+from hashlib import sha256
+
+from pytest import raises
+from solders.entry import Entry
+from solders.hash import Hash
+from solders.keypair import Keypair
+from solders.shred import Shred, ShredType, deshred, entries_from_shreds
+
+LEADER = Keypair()
+DATA_COMPLETE = 0b0100_0000
+LAST_IN_SLOT = 0b1100_0000
+
+
+def _legacy_data_shred(index: int, data: bytes, flags: int = 0) -> bytes:
+    headers = bytes([0x5A]) + struct.pack("<QIHI", 100, index, 7, 0)
+    headers += struct.pack("<HBH", 1, flags, 88 + len(data))
+    body = headers + data
+    return bytes(LEADER.sign_message(body)) + body
+
+
+def _merkle_data_shred(data: bytes, sibling: bytes) -> Shred:
+    headers = bytes([0x81]) + struct.pack("<QIHI", 100, 5, 7, 5)
+    headers += struct.pack("<HBH", 2, DATA_COMPLETE | 3, 88 + len(data))
+    body = (headers + data).ljust(1203 - 64 - 20, b"\0")
+    leaf = sha256(b"\x00SOLANA_MERKLE_SHREDS_LEAF" + body).digest()
+    root = sha256(b"\x01SOLANA_MERKLE_SHREDS_NODE" + leaf[:20] + sibling).digest()
+    payload = bytes(LEADER.sign_message(root)) + body + sibling
+    shred = Shred.from_bytes(payload + b"nonce")
+    assert shred.merkle_root == Hash(root)
+    return shred
+
+
+def test_legacy_data_shred() -> None:
+    raw = _legacy_data_shred(3, b"entries", LAST_IN_SLOT | 2)
+    shred = Shred.from_bytes(raw)
+    assert shred.shred_type == ShredType.Data
+    assert (shred.slot, shred.index, shred.version) == (100, 3, 7)
+    assert shred.parent_slot == 99
+    assert shred.reference_tick == 2
+    assert shred.data_complete and shred.last_in_slot
+    assert shred.data == b"entries"
+    assert not shred.is_merkle and shred.merkle_root is None
+    assert shred.num_data_shreds is None
+    assert shred.verify(LEADER.pubkey())
+    assert not shred.verify(Keypair().pubkey())
+    assert bytes(shred) == raw
+
+
+def test_merkle_data_shred() -> None:
+    shred = _merkle_data_shred(b"entries", bytes(range(20)))
+    assert shred.is_merkle and shred.proof_size == 1
+    assert not shred.chained and not shred.resigned
+    assert shred.index - shred.fec_set_index == 0
+    assert shred.parent_slot == 98
+    assert shred.data == b"entries"
+    assert shred.data_complete and not shred.last_in_slot
+    assert len(bytes(shred)) == 1203
+    assert shred.verify(LEADER.pubkey())
+
+
+def test_code_shred() -> None:
+    body = bytes([0x40]) + struct.pack("<QIHI", 100, 2, 7, 0)
+    body += struct.pack("<HHH", 4, 4, 0)
+    body = body.ljust(1228 - 64, b"\1")
+    shred = Shred.from_bytes(bytes(64) + body)
+    assert shred.shred_type == ShredType.Code
+    assert (shred.num_data_shreds, shred.num_coding_shreds, shred.position) == (
+        4,
+        4,
+        0,
+    )
+    assert shred.data is None and shred.parent_slot is None
+    # without proof entries only erasure shard 0 can reach the root
+    assert shred.merkle_root is None
+    assert not shred.verify(LEADER.pubkey())
+
+
+def test_invalid_shreds() -> None:
+    with raises(ValueError, match="too short"):
+        Shred.from_bytes(bytes(10))
+    with raises(ValueError, match="unknown shred variant"):
+        Shred.from_bytes(bytes(64) + b"\x11" + bytes(100))
+    with raises(ValueError, match="expected at least"):
+        Shred.from_bytes(bytes(64) + b"\x80" + bytes(100))
+    raw = bytearray(_legacy_data_shred(0, b"x"))
+    raw[86:88] = struct.pack("<H", 500)
+    with raises(ValueError, match="invalid data size"):
+        Shred.from_bytes(bytes(raw))
+
+
+def test_entries_from_shreds() -> None:
+    start = Hash.default()
+    first = Entry.new_tick(2, start)
+    entries = [first, Entry.new_tick(3, first.hash)]
+    payload = struct.pack("<Q", len(entries)) + b"".join(bytes(e) for e in entries)
+    half = len(payload) // 2
+    shreds = [
+        Shred.from_bytes(_legacy_data_shred(1, payload[half:], DATA_COMPLETE)),
+        Shred.from_bytes(_legacy_data_shred(0, payload[:half])),
+    ]
+    assert deshred(shreds) == payload
+    assert entries_from_shreds(shreds + shreds[:1]) == entries
+    with raises(ValueError, match="missing data shred 1"):
+        deshred([shreds[1], Shred.from_bytes(_legacy_data_shred(2, b""))])
+    with raises(ValueError, match="doesn't complete"):
+        deshred(shreds[1:])
+    with raises(ValueError, match="no data shreds"):
+        deshred([])
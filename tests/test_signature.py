@@ -69,6 +69,15 @@ def test_to_bytes_array(signature: Signature) -> None:
     assert bytes(signature.to_bytes_array()) == bytes(signature)
 
 
+def test_short_string_and_explorer_url(signature: Signature) -> None:
+    as_str = str(signature)
+    assert signature.to_short_string() == f"{as_str[:4]}..{as_str[-4:]}"
+    assert (
+        signature.explorer_url("devnet")
+        == f"https://explorer.solana.com/tx/{as_str}?cluster=devnet"
+    )
+
+
 def test_hash() -> None:
     msg = bytes([0])
     keypair = Keypair()
@@ -0,0 +1,158 @@
+import pickle
+
+from pytest import raises
+from solders.address_lookup_table_account import AddressLookupTableAccount
+from solders.instruction import AccountMeta, Instruction
+from solders.hash import Hash
+from solders.keypair import Keypair
+from solders.message import Message, MessageHeader, MessageV0
+from solders.pubkey import Pubkey
+from solders.signing import SigningPackage, SigningSession, rebuild_transaction
+from solders.transaction import Transaction, VersionedTransaction
+
+PAYER = Keypair()
+APPROVERS = [Keypair(), Keypair()]
+
+
+def _message() -> Message:
+    metas = [AccountMeta(a.pubkey(), True, False) for a in APPROVERS]
+    ix = Instruction(Pubkey.new_unique(), b"", metas)
+    return Message([ix], PAYER.pubkey())
+
+
+def test_signing_session() -> None:
+    message = _message()
+    session = SigningSession(Transaction.new_unsigned(message))
+    assert session.required_signers == [PAYER.pubkey()] + [
+        a.pubkey() for a in APPROVERS
+    ]
+    assert session.message == message
+    session.sign([PAYER])
+    assert session.signed == [PAYER.pubkey()]
+    with raises(ValueError, match="missing signatures"):
+        session.transaction()
+    for approver in APPROVERS:
+        signature = approver.sign_message(session.message_bytes)
+        session.add_signature(approver.pubkey(), signature)
+    assert session.missing == [] and session.is_complete
+    assert session.transaction() == VersionedTransaction(
+        message, [PAYER] + APPROVERS
+    )
+
+
+def test_signing_session_rejects_bad_signatures() -> None:
+    session = SigningSession(Transaction.new_unsigned(_message()))
+    outsider = Keypair()
+    with raises(ValueError, match="not a required signer"):
+        session.sign([outsider])
+    forged = outsider.sign_message(session.message_bytes)
+    with raises(ValueError, match="invalid signature"):
+        session.add_signature(PAYER.pubkey(), forged)
+    assert session.signed == []
+
+
+def test_signing_session_keeps_valid_signatures() -> None:
+    message = _message()
+    signed = VersionedTransaction(message, [PAYER] + APPROVERS)
+    assert SigningSession(signed).is_complete
+
+
+def test_malformed_header() -> None:
+    header = MessageHeader(3, 0, 0)
+    message = MessageV0(header, [PAYER.pubkey()], Hash.default(), [], [])
+    tx = VersionedTransaction.populate(message, [])
+    with raises(ValueError):
+        SigningSession(tx)
+    with raises(ValueError):
+        rebuild_transaction(tx, Hash.new_unique())
+    with raises(ValueError):
+        SigningPackage([tx])
+    message = _message()
+    raw = bytes(SigningPackage([Transaction.new_unsigned(message)]))
+    start = raw.index(bytes(message))
+    forged = raw[:start] + bytes([9]) + raw[start + 1 :]
+    with raises(ValueError):
+        SigningPackage.from_bytes(forged)
+
+
+def test_rebuild_transaction_new_blockhash() -> None:
+    signed = VersionedTransaction(_message(), [PAYER] + APPROVERS)
+    unchanged = rebuild_transaction(signed, signed.message.recent_blockhash)
+    assert unchanged.session.is_complete
+    assert unchanged.resign == [] and unchanged.new_signers == []
+    rebuilt = rebuild_transaction(signed, Hash.new_unique())
+    assert rebuilt.still_valid == []
+    assert rebuilt.resign == [PAYER.pubkey()] + [a.pubkey() for a in APPROVERS]
+    assert rebuilt.dropped_signers == []
+    rebuilt.session.sign([PAYER] + APPROVERS)
+    assert rebuilt.session.is_complete
+
+
+def test_rebuild_transaction_rotates_fee_payer() -> None:
+    metas = [AccountMeta(a.pubkey(), True, False) for a in APPROVERS]
+    ix = Instruction(Pubkey.new_unique(), b"", metas)
+    message = Message([ix], PAYER.pubkey())
+    signed = Transaction([PAYER] + APPROVERS, message, Hash.default())
+    blockhash = Hash.new_unique()
+    new_payer = APPROVERS[1]
+    rebuilt = rebuild_transaction(signed, blockhash, new_payer.pubkey())
+    assert rebuilt.session.message == Message.new_with_blockhash(
+        [ix], new_payer.pubkey(), blockhash
+    )
+    assert rebuilt.dropped_signers == [PAYER.pubkey()]
+    assert rebuilt.new_signers == []
+    assert rebuilt.resign == [new_payer.pubkey(), APPROVERS[0].pubkey()]
+    outsider = Keypair()
+    rotated = rebuild_transaction(signed, blockhash, outsider.pubkey())
+    assert rotated.new_signers == [outsider.pubkey()]
+    assert rotated.dropped_signers == [PAYER.pubkey()]
+
+
+def test_signing_package_ceremony() -> None:
+    txs = [Transaction.new_unsigned(_message()) for _ in range(2)]
+    package = SigningPackage(txs, metadata={"ceremony": "7"})
+    assert len(package) == 2
+    assert package.required_signers[0] == [PAYER.pubkey()] + [
+        a.pubkey() for a in APPROVERS
+    ]
+    assert package.missing == [PAYER.pubkey()] + [a.pubkey() for a in APPROVERS]
+    assert package.sign([PAYER]) == 2
+    exported = bytes(package)
+    parts = [SigningPackage.from_bytes(exported) for _ in APPROVERS]
+    for approver, part in zip(APPROVERS, parts):
+        part.sign([approver])
+        package.merge(SigningPackage.from_bytes(bytes(part)))
+    assert package.is_complete and package.metadata == {"ceremony": "7"}
+    signed = package.signed_transactions()
+    assert all(tx.verify_with_results() == [True] * 3 for tx in signed)
+    assert pickle.loads(pickle.dumps(package)) == package
+    assert SigningPackage.from_json(package.to_json()) == package
+
+
+def test_signing_package_rejects_bad_input() -> None:
+    package = SigningPackage([Transaction.new_unsigned(_message())])
+    with raises(ValueError, match="not a required signer"):
+        package.sign([Keypair()])
+    with raises(ValueError, match="no transaction"):
+        package.add_signature(1, PAYER.pubkey(), PAYER.sign_message(b""))
+    with raises(ValueError, match="missing signatures"):
+        package.signed_transactions()
+    with raises(ValueError, match="different messages"):
+        package.merge(SigningPackage([Transaction.new_unsigned(_message())]))
+    with raises(ValueError, match="version"):
+        SigningPackage.from_bytes(b"\x09" + bytes(package)[1:])
+
+
+def test_signing_package_requires_lookup_tables() -> None:
+    table = AddressLookupTableAccount(Pubkey.new_unique(), [APPROVERS[0].pubkey()])
+    ix = Instruction(
+        Pubkey.new_unique(), b"", [AccountMeta(table.addresses[0], False, True)]
+    )
+    message = MessageV0.try_compile(PAYER.pubkey(), [ix], [table], Hash.default())
+    tx = VersionedTransaction.populate(message, [])
+    with raises(ValueError, match="missing lookup table"):
+        SigningPackage([tx])
+    package = SigningPackage([tx], [table])
+    assert package.address_lookup_table_accounts == [table]
+    package.sign([PAYER])
+    assert package.is_complete
@@ -0,0 +1,163 @@
+from typing import List
+
+from pytest import raises
+from solders.account import Account
+from solders.accounts_map import AccountsMap
+from solders.instruction import Instruction
+from solders.keypair import Keypair
+from solders.message import Message
+from solders.pubkey import Pubkey
+from solders.rpc.responses import (
+    RpcResponseContext,
+    RpcSimulateTransactionResult,
+    SimulateTransactionResp,
+)
+from solders.rent import Rent
+from solders.simulation import (
+    ComputeUnitHistogram,
+    RuntimeParams,
+    simulate_locally,
+)
+from solders.system_program import ID as SYSTEM_PROGRAM_ID
+from solders.system_program import TransferParams, transfer
+from solders.transaction import VersionedTransaction
+from solders.transaction_status import (
+    InstructionErrorCustom,
+    TransactionErrorFieldless,
+    TransactionErrorInstructionError,
+    TransactionErrorInsufficientFundsForRent,
+)
+
+COMPUTE_BUDGET_ID = Pubkey.from_string("ComputeBudget111111111111111111111111111111")
+PAYER = Keypair()
+TO = Pubkey.new_unique()
+
+
+def _tx(ixs: List[Instruction]) -> VersionedTransaction:
+    return VersionedTransaction(Message(ixs, PAYER.pubkey()), [PAYER])
+
+
+def _transfer(lamports: int) -> Instruction:
+    return transfer(
+        TransferParams(from_pubkey=PAYER.pubkey(), to_pubkey=TO, lamports=lamports)
+    )
+
+
+def _funded(lamports: int = 10_000_000) -> AccountsMap:
+    return AccountsMap({PAYER.pubkey(): Account(lamports, b"", SYSTEM_PROGRAM_ID)})
+
+
+def test_transfer_succeeds() -> None:
+    accounts = _funded()
+    result = simulate_locally(_tx([_transfer(2_000_000)]), accounts)
+    assert result.err is None
+    assert result.fee == 5000
+    assert result.accounts[PAYER.pubkey()].lamports == 7_995_000
+    assert result.accounts[TO].lamports == 2_000_000
+    assert result.unsimulated_instructions == []
+    assert not accounts.is_dirty
+
+
+def test_prioritization_fee() -> None:
+    set_price = Instruction(
+        COMPUTE_BUDGET_ID, bytes([3]) + (1000).to_bytes(8, "little"), []
+    )
+    result = simulate_locally(_tx([set_price, _transfer(2_000_000)]), _funded())
+    assert result.err is None
+    assert result.fee == 5200
+
+
+def test_fee_payer_errors() -> None:
+    tx = _tx([_transfer(2_000_000)])
+    not_found = simulate_locally(tx, AccountsMap())
+    assert not_found.err == TransactionErrorFieldless.AccountNotFound
+    assert not_found.fee == 0
+    poor = simulate_locally(tx, _funded(1000))
+    assert poor.err == TransactionErrorFieldless.InsufficientFundsForFee
+
+
+def test_failed_instruction_still_charges_fee() -> None:
+    result = simulate_locally(_tx([_transfer(20_000_000)]), _funded())
+    assert result.err == TransactionErrorInstructionError(0, InstructionErrorCustom(1))
+    assert result.fee == 5000
+    assert result.accounts[PAYER.pubkey()].lamports == 9_995_000
+    assert TO not in result.accounts
+
+
+def test_rent_exempt_requirement_is_feature_gated() -> None:
+    tx = _tx([_transfer(1000)])
+    result = simulate_locally(tx, _funded())
+    assert result.err == TransactionErrorInsufficientFundsForRent(1)
+    assert TO not in result.accounts
+    assert simulate_locally(tx, _funded(), feature_set=[]).err is None
+
+
+def test_program_accounts() -> None:
+    program_id = Pubkey.new_unique()
+    tx = _tx([Instruction(program_id, b"", [])])
+    accounts = _funded()
+    missing = simulate_locally(tx, accounts)
+    assert missing.err == TransactionErrorFieldless.ProgramAccountNotFound
+    accounts[program_id] = Account(1, b"", SYSTEM_PROGRAM_ID)
+    not_executable = simulate_locally(tx, accounts)
+    assert not_executable.err == TransactionErrorFieldless.InvalidProgramForExecution
+    accounts[program_id] = Account(1, b"", SYSTEM_PROGRAM_ID, executable=True)
+    result = simulate_locally(tx, accounts)
+    assert result.err is None
+    assert result.unsimulated_instructions == [0]
+
+
+def _simulated(units: int, failed: bool = False) -> RpcSimulateTransactionResult:
+    err = TransactionErrorFieldless.AccountNotFound if failed else None
+    return RpcSimulateTransactionResult(err, None, None, units, None)
+
+
+def test_compute_unit_histogram() -> None:
+    histogram = ComputeUnitHistogram()
+    assert histogram.percentile(50) is None and histogram.mean is None
+    histogram.record_all(
+        [
+            1000,
+            _simulated(2000),
+            SimulateTransactionResp(_simulated(3500), RpcResponseContext(1)),
+            _simulated(9000, failed=True),
+        ]
+    )
+    assert histogram.samples == [1000, 2000, 3500]
+    assert histogram.failures == 1
+    assert len(histogram) == 3
+    assert (histogram.min, histogram.max) == (1000, 3500)
+    assert histogram.mean == 6500 / 3
+    assert [histogram.percentile(p) for p in (0, 50, 100)] == [1000, 2000, 3500]
+    assert histogram.buckets(1000) == [(1000, 1), (2000, 1), (3000, 1)]
+    assert histogram.exceeding(2000) == 1
+    with raises(ValueError, match="no units_consumed"):
+        histogram.record(RpcSimulateTransactionResult(None, None, None, None, None))
+    with raises(ValueError):
+        histogram.percentile(101)
+    with raises(ValueError):
+        histogram.buckets(0)
+
+
+def test_runtime_params() -> None:
+    feature = Pubkey.new_unique()
+    params = RuntimeParams(Rent(1, 1.0, 50), 10_000, feature_set=[])
+    assert not params.require_rent_exempt_accounts
+    assert not params.is_active(feature)
+    assert params.with_activated([feature]).is_active(feature)
+    assert RuntimeParams().require_rent_exempt_accounts
+    assert params.minimum_balance(0) == 128
+    assert params.max_compute_unit_limit == 1_400_000
+    price_data = bytes([3]) + (1000).to_bytes(8, "little")
+    price = Instruction(COMPUTE_BUDGET_ID, price_data, [])
+    message = Message([price, _transfer(1)], PAYER.pubkey())
+    assert params.calculate_fee(message) == 10_000 + 200
+    result = params.simulate(_tx([_transfer(1)]), _funded())
+    assert result.err is None and result.fee == 10_000
+
+
+def test_compute_unit_histogram_run() -> None:
+    histogram = ComputeUnitHistogram()
+    histogram.run([1, 2, 3], lambda n: _simulated(1000 * n, failed=n == 2))
+    assert histogram.samples == [1000, 3000]
+    assert histogram.failures == 1
@@ -0,0 +1,146 @@
+import io
+import tarfile
+from pathlib import Path
+from typing import List, Tuple
+
+from pytest import raises
+from solders.account import Account
+from solders.compression import compress
+from solders.hash import Hash
+from solders.pubkey import Pubkey
+from solders.snapshot import (
+    SnapshotReader,
+    StoredAccount,
+    latest_accounts,
+    read_append_vec,
+)
+
+OWNER = Pubkey.new_unique()
+OTHER_OWNER = Pubkey.new_unique()
+
+
+def stored_account(write_version: int, pubkey: Pubkey, account: Account) -> bytes:
+    data = bytes(account.data)
+    raw = (
+        write_version.to_bytes(8, "little")
+        + len(data).to_bytes(8, "little")
+        + bytes(pubkey)
+        + account.lamports.to_bytes(8, "little")
+        + account.rent_epoch.to_bytes(8, "little")
+        + bytes(account.owner)
+        + bytes([account.executable])
+        + bytes(7)
+        + bytes(32)
+        + data
+    )
+    return raw + bytes(-len(raw) % 8)
+
+
+def append_vec(entries: List[Tuple[int, Pubkey, Account]], capacity: int = 0) -> bytes:
+    raw = b"".join(stored_account(*entry) for entry in entries)
+    return raw + bytes(max(capacity - len(raw), 0))
+
+
+def test_read_append_vec() -> None:
+    first, second = Pubkey.new_unique(), Pubkey.new_unique()
+    first_account = Account(10, b"abc", OWNER, True, 5)
+    second_account = Account(20, bytes(range(16)), OTHER_OWNER)
+    data = append_vec([(1, first, first_account), (2, second, second_account)], 4096)
+    stored = read_append_vec(data, 42)
+    assert [s.pubkey for s in stored] == [first, second]
+    assert [s.account for s in stored] == [first_account, second_account]
+    assert [s.write_version for s in stored] == [1, 2]
+    assert all(s.slot == 42 and s.hash == Hash.default() for s in stored)
+    assert read_append_vec(bytes(1024), 0) == []
+    with raises(ValueError, match="truncated"):
+        read_append_vec(data[:138], 42)
+
+
+def test_latest_accounts() -> None:
+    updated, closed, reassigned = (Pubkey.new_unique() for _ in range(3))
+    old = read_append_vec(
+        append_vec(
+            [
+                (1, updated, Account(1, b"old", OWNER)),
+                (2, closed, Account(1, b"", OWNER)),
+                (3, reassigned, Account(1, b"", OWNER)),
+            ]
+        ),
+        10,
+    )
+    new = read_append_vec(
+        append_vec(
+            [
+                (1, updated, Account(2, b"new", OWNER)),
+                (2, closed, Account(0, b"", Pubkey.default())),
+                (3, reassigned, Account(1, b"", OTHER_OWNER)),
+            ]
+        ),
+        11,
+    )
+    assert latest_accounts(new + old) == {
+        updated: Account(2, b"new", OWNER),
+        reassigned: Account(1, b"", OTHER_OWNER),
+    }
+    assert latest_accounts(old + new, owners=[OWNER]) == {
+        updated: Account(2, b"new", OWNER)
+    }
+
+
+def write_archive(path: Path, files: List[Tuple[str, bytes]], zstd: bool) -> None:
+    buffer = io.BytesIO()
+    with tarfile.open(fileobj=buffer, mode="w") as archive:
+        for name, data in files:
+            info = tarfile.TarInfo(name)
+            info.size = len(data)
+            archive.addfile(info, io.BytesIO(data))
+    raw = buffer.getvalue()
+    path.write_bytes(compress(raw) if zstd else raw)
+
+
+def test_snapshot_reader(tmp_path: Path) -> None:
+    pubkey, other = Pubkey.new_unique(), Pubkey.new_unique()
+    files = [
+        ("version", b"1.2.0"),
+        ("snapshots/100/100", b"bank fields"),
+        ("accounts/99.3", append_vec([(1, pubkey, Account(1, b"a", OWNER))], 1024)),
+        (
+            "accounts/100.7",
+            append_vec(
+                [
+                    (1, pubkey, Account(2, b"b", OWNER)),
+                    (2, other, Account(3, b"", OTHER_OWNER)),
+                ]
+            ),
+        ),
+    ]
+    for zstd in (True, False):
+        path = tmp_path / f"snapshot-100-{zstd}.tar"
+        write_archive(path, files, zstd)
+        stored = list(SnapshotReader(str(path)))
+        assert [(s.pubkey, s.slot) for s in stored] == [
+            (pubkey, 99),
+            (pubkey, 100),
+            (other, 100),
+        ]
+        assert isinstance(stored[0], StoredAccount)
+        assert latest_accounts(SnapshotReader(str(path))) == {
+            pubkey: Account(2, b"b", OWNER),
+            other: Account(3, b"", OTHER_OWNER),
+        }
+        owned = SnapshotReader(str(path), owners=[OTHER_OWNER])
+        assert [s.pubkey for s in owned] == [other]
+    with raises(OSError):
+        SnapshotReader(str(tmp_path / "missing.tar.zst"))
+
+
+def test_snapshot_reader_base256_size(tmp_path: Path) -> None:
+    pubkey = Pubkey.new_unique()
+    data = append_vec([(1, pubkey, Account(1, b"a", OWNER))])
+    path = tmp_path / "snapshot-base256.tar"
+    write_archive(path, [("accounts/5.0", data)], zstd=False)
+    raw = bytearray(path.read_bytes())
+    # Rewrite the size field of the first header in GNU base-256 form.
+    raw[124:136] = b"\x80" + len(data).to_bytes(11, "big")
+    path.write_bytes(bytes(raw))
+    assert [s.pubkey for s in SnapshotReader(str(path))] == [pubkey]
@@ -0,0 +1,49 @@
+from hashlib import sha256
+from typing import List, Optional
+
+from solders.pubkey import Pubkey
+from solders.sniff import LayoutKind, sniff_layout
+
+TOKEN_PROGRAM_ID = Pubkey.from_string("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9ss623VQ5DA")
+LOOKUP_TABLE_ID = Pubkey.from_string("AddressLookupTab1e1111111111111111111111111")
+
+
+def _kinds(data: bytes, owner: Optional[Pubkey] = None) -> List[LayoutKind]:
+    return [c.kind for c in sniff_layout(data, owner)]
+
+
+def test_token_layouts() -> None:
+    assert _kinds(bytes(165)) == [LayoutKind.TokenAccount]
+    [mint] = sniff_layout(bytes(82), owner=TOKEN_PROGRAM_ID)
+    assert mint.kind == LayoutKind.Mint and mint.confidence > 0.8
+    assert sniff_layout(bytes(82), owner=Pubkey.new_unique()) == []
+    multisig = bytes([2, 3, 1]) + bytes(352)
+    assert _kinds(multisig) == [LayoutKind.TokenMultisig]
+    assert _kinds(bytes([4, 3, 1]) + bytes(352)) == []
+    extended_mint = bytes(165) + bytes([1]) + bytes(20)
+    assert _kinds(extended_mint) == [LayoutKind.Mint]
+    assert _kinds(extended_mint, owner=TOKEN_PROGRAM_ID) == []
+
+
+def test_native_layouts() -> None:
+    table = (1).to_bytes(4, "little") + bytes(52) + bytes(64)
+    [candidate] = sniff_layout(table, owner=LOOKUP_TABLE_ID)
+    assert candidate.kind == LayoutKind.LookupTable
+    assert candidate.notes == ["lookup table metadata and 2 addresses"]
+    nonce = (1).to_bytes(4, "little") * 2 + bytes(72)
+    assert _kinds(nonce) == [LayoutKind.Nonce]
+
+
+def test_anchor_and_borsh() -> None:
+    disc = sha256(b"account:Pool").digest()[:8]
+    body = (3).to_bytes(4, "little") + b"abc" + bytes(32)
+    [known] = sniff_layout(disc + body, anchor_names=["Pool"])
+    assert known.name == "Pool" and known.confidence > 0.8
+    [unknown] = sniff_layout(disc + body)
+    assert unknown.kind == LayoutKind.Anchor and unknown.name is None
+    assert unknown.confidence < known.confidence
+    assert unknown.notes[1] == 'string "abc" at offset 8'
+    [borsh] = sniff_layout(bytes(2) + body)
+    assert borsh.kind == LayoutKind.Borsh
+    assert borsh.notes == ['string "abc" at offset 2']
+    assert sniff_layout(bytes(10)) == []
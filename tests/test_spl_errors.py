@@ -0,0 +1,56 @@
+from solders.pubkey import Pubkey
+from solders.spl_errors import (
+    custom_error_message,
+    custom_error_name,
+    describe_transaction_error,
+)
+from solders.transaction_status import (
+    InstructionErrorCustom,
+    TransactionErrorFieldless,
+    TransactionErrorInstructionError,
+)
+
+TOKEN_PROGRAM_ID = Pubkey.from_string("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+TOKEN_2022_PROGRAM_ID = Pubkey.from_string(
+    "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+)
+ATA_PROGRAM_ID = Pubkey.from_string("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")
+STAKE_POOL_PROGRAM_ID = Pubkey.from_string(
+    "SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy"
+)
+
+
+def test_custom_error_name() -> None:
+    assert custom_error_name(TOKEN_PROGRAM_ID, 17) == "AccountFrozen"
+    assert custom_error_name(TOKEN_PROGRAM_ID, 20) is None
+    assert custom_error_name(TOKEN_2022_PROGRAM_ID, 20) == "ExtensionTypeMismatch"
+    assert custom_error_name(ATA_PROGRAM_ID, 0) == "InvalidOwner"
+    assert custom_error_name(STAKE_POOL_PROGRAM_ID, 14) == "ValidatorNotFound"
+    assert custom_error_name(Pubkey.new_unique(), 0) is None
+    assert custom_error_message(TOKEN_PROGRAM_ID, 1) == "Insufficient funds"
+
+
+def test_describe_transaction_error() -> None:
+    outer = Pubkey.new_unique()
+    logs = [
+        f"Program {outer} invoke [1]",
+        f"Program {STAKE_POOL_PROGRAM_ID} invoke [2]",
+        f"Program {STAKE_POOL_PROGRAM_ID} failed: custom program error: 0x7",
+        f"Program {outer} failed: custom program error: 0x7",
+    ]
+    err = TransactionErrorInstructionError(0, InstructionErrorCustom(7))
+    assert describe_transaction_error(err, logs) == (
+        "Error processing Instruction 0: custom program error: 0x7 "
+        "(SignatureMissing: Signature missing)"
+    )
+    raw = "Error processing Instruction 0: custom program error: 0x7"
+    assert describe_transaction_error(err, logs[:1] + logs[3:]) == raw
+    assert describe_transaction_error(err, []) == raw
+    other_code = TransactionErrorInstructionError(0, InstructionErrorCustom(8))
+    assert describe_transaction_error(other_code, logs) == (
+        "Error processing Instruction 0: custom program error: 0x8"
+    )
+    assert (
+        describe_transaction_error(TransactionErrorFieldless.AccountInUse, logs)
+        == "Account in use"
+    )
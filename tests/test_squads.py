@@ -0,0 +1,120 @@
+import struct
+from hashlib import sha256
+from typing import List
+
+from pytest import raises
+from solders.account import Account
+from solders.address_lookup_table_account import AddressLookupTableAccount
+from solders.pubkey import Pubkey
+from solders.squads import (
+    SQUADS_PROGRAM_ID,
+    Multisig,
+    Proposal,
+    ProposalStatus,
+    VaultTransaction,
+    proposal_approve,
+    proposal_create,
+    proposal_pda,
+    transaction_pda,
+    vault_pda,
+    vault_transaction_execute,
+)
+
+MULTISIG = Pubkey.new_unique()
+MEMBERS = [Pubkey.new_unique(), Pubkey.new_unique()]
+
+
+def _discriminator(namespace: str, name: str) -> bytes:
+    return sha256(f"{namespace}:{name}".encode()).digest()[:8]
+
+
+def _account(name: str, data: bytes) -> Account:
+    return Account(1, _discriminator("account", name) + data, SQUADS_PROGRAM_ID)
+
+
+def _keys(keys: List[Pubkey]) -> bytes:
+    return struct.pack("<I", len(keys)) + b"".join(bytes(k) for k in keys)
+
+
+def _vec(data: bytes) -> bytes:
+    return struct.pack("<I", len(data)) + data
+
+
+def test_multisig() -> None:
+    create_key, authority = Pubkey.new_unique(), Pubkey.default()
+    members = struct.pack("<I", 2) + bytes(MEMBERS[0]) + b"\x07"
+    members += bytes(MEMBERS[1]) + b"\x02"
+    data = bytes(create_key) + bytes(authority) + struct.pack("<HIQQ", 2, 0, 5, 3)
+    multisig = Multisig.from_account(_account("Multisig", data + b"\x00\xff" + members))
+    assert multisig.create_key == create_key
+    assert (multisig.threshold, multisig.transaction_index) == (2, 5)
+    assert multisig.rent_collector is None
+    voter = multisig.member(MEMBERS[1])
+    assert voter is not None and voter.can_vote and not voter.can_execute
+    assert multisig.member(Pubkey.new_unique()) is None
+    with raises(ValueError, match="not a Multisig"):
+        Multisig.from_account(_account("Proposal", data))
+    with raises(ValueError, match="not the Squads program"):
+        Multisig.from_account(Account(1, b"", Pubkey.default()))
+
+
+def test_proposal() -> None:
+    data = bytes(MULTISIG) + struct.pack("<QBqB", 5, 3, 1_700_000_000, 255)
+    data += _keys(MEMBERS) + _keys([]) + _keys([])
+    proposal = Proposal.from_account(_account("Proposal", data))
+    assert proposal.status == ProposalStatus.Approved
+    assert proposal.status_timestamp == 1_700_000_000
+    assert proposal.approved == MEMBERS
+    header = bytes(Pubkey.new_unique()) + bytes(32) + struct.pack("<HIQQ", 2, 0, 5, 0)
+    data = header + b"\x00\xff" + _keys([])
+    multisig = Multisig.from_account(_account("Multisig", data))
+    assert proposal.is_executable(multisig)
+
+
+def test_pdas() -> None:
+    index = struct.pack("<Q", 5)
+    seeds = [b"multisig", bytes(MULTISIG), b"transaction", index, b"proposal"]
+    expected = Pubkey.find_program_address(seeds, SQUADS_PROGRAM_ID)
+    assert proposal_pda(MULTISIG, 5) == expected
+    vault_seeds = [b"multisig", bytes(MULTISIG), b"vault", b"\x00"]
+    assert vault_pda(MULTISIG) == Pubkey.find_program_address(
+        vault_seeds, SQUADS_PROGRAM_ID
+    )
+
+
+def test_proposal_instructions() -> None:
+    create = proposal_create(MULTISIG, 5, MEMBERS[0])
+    args = struct.pack("<Q?", 5, False)
+    assert create.data == _discriminator("global", "proposal_create") + args
+    assert [m.is_signer for m in create.accounts] == [False, False, True, True, False]
+    approve = proposal_approve(MULTISIG, 5, MEMBERS[1], memo="ok")
+    memo = b"\x01" + _vec(b"ok")
+    assert approve.data == _discriminator("global", "proposal_approve") + memo
+    assert approve.accounts[2].pubkey == proposal_pda(MULTISIG, 5)[0]
+
+
+def test_vault_transaction_execute() -> None:
+    vault = vault_pda(MULTISIG)[0]
+    destination, program, table_key = (Pubkey.new_unique() for _ in range(3))
+    loaded = Pubkey.new_unique()
+    message = b"\x01\x01\x01" + _keys([vault, destination, program])
+    message += struct.pack("<I", 1) + b"\x02" + _vec(b"\x00\x01") + _vec(b"")
+    message += struct.pack("<I", 1) + bytes(table_key) + _vec(b"") + _vec(b"\x00")
+    header = bytes(MULTISIG) + bytes(MEMBERS[0]) + struct.pack("<QBBB", 5, 255, 0, 254)
+    account = _account("VaultTransaction", header + _vec(b"") + message)
+    transaction = VaultTransaction.from_account(account)
+    assert transaction.account_keys == [vault, destination, program]
+    assert transaction.address_lookup_tables == [table_key]
+    with raises(ValueError, match="missing lookup table"):
+        vault_transaction_execute(transaction, MEMBERS[0])
+    table = AddressLookupTableAccount(table_key, [loaded])
+    ix = vault_transaction_execute(transaction, MEMBERS[0], [table])
+    assert ix.accounts[2].pubkey == transaction_pda(MULTISIG, 5)[0]
+    remaining = [(m.pubkey, m.is_writable) for m in ix.accounts[4:]]
+    assert remaining == [
+        (table_key, False),
+        (vault, True),
+        (destination, True),
+        (program, False),
+        (loaded, False),
+    ]
@@ -0,0 +1,100 @@
+import struct
+from typing import Optional
+
+from pytest import raises
+from solders.account import Account
+from solders.clock import Clock
+from solders.pubkey import Pubkey
+from solders.stake import StakeLifecycle, StakeLifecycleState, stake_lifecycle
+
+STAKE_PROGRAM_ID = Pubkey.from_string("Stake11111111111111111111111111111111111111")
+SYSVAR_ID = Pubkey.from_string("Sysvar1111111111111111111111111111111111111")
+VOTER = Pubkey.new_unique()
+
+
+def _meta(lockup_epoch: int = 0) -> bytes:
+    authorities = bytes(Pubkey.new_unique()) + bytes(Pubkey.new_unique())
+    lockup = struct.pack("<qQ", 0, lockup_epoch) + bytes(32)
+    return struct.pack("<Q", 2282880) + authorities + lockup
+
+
+def _stake_account(
+    activation: int, deactivation: int = 2**64 - 1, lockup_epoch: int = 0
+) -> Account:
+    delegation = bytes(VOTER) + struct.pack(
+        "<QQQd", 100, activation, deactivation, 0.25
+    )
+    data = struct.pack("<I", 2) + _meta(lockup_epoch) + delegation + bytes(8)
+    return Account(1, data + bytes(200 - len(data)), STAKE_PROGRAM_ID)
+
+
+def _clock(epoch: int) -> Clock:
+    return Clock(0, 0, epoch, epoch, 0)
+
+
+def _lifecycle(
+    state: StakeLifecycleState,
+    effective: int,
+    activating: int = 0,
+    deactivating: int = 0,
+    locked_until_epoch: Optional[int] = None,
+) -> StakeLifecycle:
+    return StakeLifecycle(
+        state,
+        VOTER,
+        100,
+        effective,
+        activating,
+        deactivating,
+        locked_until_epoch,
+        None,
+    )
+
+
+def test_without_history() -> None:
+    account = _stake_account(5, 8, lockup_epoch=7)
+    activating = stake_lifecycle(account, _clock(5))
+    assert activating == _lifecycle(
+        StakeLifecycleState.Activating, 0, activating=100, locked_until_epoch=7
+    )
+    assert activating.progress == 0.0
+    assert activating.is_locked
+    active = stake_lifecycle(account, _clock(7))
+    assert active == _lifecycle(StakeLifecycleState.Active, 100)
+    assert not active.is_locked
+    deactivating = stake_lifecycle(account, _clock(8))
+    assert deactivating.state == StakeLifecycleState.Deactivating
+    assert deactivating.progress == 0.0
+    inactive = stake_lifecycle(account, _clock(9))
+    assert inactive == _lifecycle(StakeLifecycleState.Inactive, 0)
+    assert inactive.progress == 1.0
+
+
+def test_with_history() -> None:
+    entries = struct.pack("<Q", 1) + struct.pack("<QQQQ", 5, 200, 100, 0)
+    history = Account(1, entries, SYSVAR_ID)
+    lifecycle = stake_lifecycle(_stake_account(5), _clock(6), history)
+    assert lifecycle == _lifecycle(StakeLifecycleState.Activating, 50, activating=50)
+    assert lifecycle.progress == 0.5
+    with raises(ValueError, match="sysvar"):
+        stake_lifecycle(_stake_account(5), _clock(6), Account(1, entries, VOTER))
+
+
+def test_undelegated() -> None:
+    data = struct.pack("<I", 1) + _meta(lockup_epoch=3)
+    initialized = Account(1, data + bytes(200 - len(data)), STAKE_PROGRAM_ID)
+    lifecycle = stake_lifecycle(initialized, _clock(1))
+    assert lifecycle.state == StakeLifecycleState.Initialized
+    assert lifecycle.voter is None
+    assert lifecycle.locked_until_epoch == 3
+    assert str(lifecycle.state) == "initialized"
+    with raises(ValueError, match="stake program"):
+        stake_lifecycle(Account(1, data, Pubkey.default()), _clock(1))
+    with raises(ValueError, match="rewards pool"):
+        rewards_pool = struct.pack("<I", 3) + bytes(196)
+        stake_lifecycle(Account(1, rewards_pool, STAKE_PROGRAM_ID), _clock(1))
+
+
+def test_progress_without_delegated_stake() -> None:
+    for state in (StakeLifecycleState.Activating, StakeLifecycleState.Deactivating):
+        assert StakeLifecycle(state, VOTER, 0, 0, 0, 0, None, None).progress == 1.0
@@ -0,0 +1,64 @@
+from solders.account import Account
+from solders.pubkey import Pubkey
+from solders.rpc.responses import (
+    RpcKeyedAccount,
+    SlotInfo,
+    SlotUpdateCreatedBank,
+    SlotUpdateDead,
+    SlotUpdateOptimisticConfirmation,
+    SlotUpdateRoot,
+)
+from solders.rpc.streams import ProgramUpdateDeduper, SlotTracker, SlotTrackerReport
+
+
+def test_slot_tracker_gaps_and_forks() -> None:
+    tracker = SlotTracker()
+    assert tracker.process_slot(SlotInfo(slot=10, parent=9, root=5)).is_empty()
+    # slot 11 was skipped by its leader, which is not a gap
+    assert not tracker.process_slot(SlotInfo(slot=12, parent=10, root=5))
+    report = tracker.process_slot(SlotInfo(slot=16, parent=15, root=5))
+    assert report == SlotTrackerReport(gaps=[(13, 15)], forks=[], dead=[])
+    # a late ancestor is not a fork
+    assert tracker.process_slot(SlotInfo(slot=15, parent=12, root=5)).is_empty()
+    report = tracker.process_slot(SlotInfo(slot=17, parent=12, root=5))
+    assert report.forks == [(17, 12)]
+    assert tracker.processed == 17
+    assert tracker.finalized == 5
+
+
+def test_slot_tracker_slot_updates() -> None:
+    tracker = SlotTracker()
+    tracker.process_slot_update(SlotUpdateCreatedBank(slot=3, timestamp=0, parent=2))
+    tracker.process_slot_update(SlotUpdateOptimisticConfirmation(slot=3, timestamp=0))
+    assert tracker.confirmed == 3
+    report = tracker.process_slot_update(SlotUpdateDead(slot=4, timestamp=0, err="x"))
+    assert report.dead == [4]
+    tracker.process_slot_update(SlotUpdateRoot(slot=2, timestamp=0))
+    assert tracker.finalized == 2
+    assert tracker.confirmed == 3
+    tracker.process_root(3)
+    assert tracker.finalized == 3
+
+
+def test_program_update_deduper() -> None:
+    deduper = ProgramUpdateDeduper()
+    owner = Pubkey.new_unique()
+    first, second = Pubkey.new_unique(), Pubkey.new_unique()
+    snapshot = [
+        RpcKeyedAccount(first, Account(1, b"a", owner)),
+        RpcKeyedAccount(second, Account(1, b"b", owner)),
+    ]
+    assert deduper.filter(snapshot) == snapshot
+    assert len(deduper) == 2
+    assert not deduper.process(RpcKeyedAccount(first, Account(1, b"a", owner)))
+    assert deduper.process(RpcKeyedAccount(first, Account(5, b"a", owner)))
+    assert deduper.process(RpcKeyedAccount(first, Account(5, b"c", owner)))
+    new_owner = Pubkey.new_unique()
+    assert deduper.process(RpcKeyedAccount(first, Account(5, b"c", new_owner)))
+    executable = Account(5, b"c", new_owner, executable=True)
+    assert deduper.process(RpcKeyedAccount(first, executable))
+    assert not deduper.process(RpcKeyedAccount(first, executable))
+    assert deduper.forget(second)
+    assert deduper.process(RpcKeyedAccount(second, Account(1, b"b", owner)))
+    deduper.clear()
+    assert len(deduper) == 0
@@ -0,0 +1,77 @@
+from struct import pack
+
+from pytest import raises
+from solders.account_decoder import ParsedAccount
+from solders.clock import Clock
+from solders.epoch_schedule import EpochSchedule
+from solders.rent import Rent
+
+
+def test_clock_bytes() -> None:
+    clock = Clock(
+        slot=1,
+        epoch_start_timestamp=2,
+        epoch=3,
+        leader_schedule_epoch=4,
+        unix_timestamp=5,
+    )
+    raw = bytes(clock)
+    assert raw == pack("<QqQQq", 1, 2, 3, 4, 5)
+    assert Clock.from_bytes(raw) == clock
+
+
+def test_rent_bytes() -> None:
+    rent = Rent(lamports_per_byte_year=1, exemption_threshold=2.0, burn_percent=50)
+    raw = bytes(rent)
+    assert raw == pack("<QdB", 1, 2.0, 50)
+    assert Rent.from_bytes(raw) == rent
+    assert Rent.from_bytes(bytes(Rent.default())) == Rent.default()
+
+
+def test_epoch_schedule_bytes() -> None:
+    schedule = EpochSchedule.custom(64, 64, False)
+    raw = bytes(schedule)
+    assert raw == pack("<QQ?QQ", 64, 64, False, 0, 0)
+    assert EpochSchedule.from_bytes(raw) == schedule
+
+
+def test_json_parsed_sysvars() -> None:
+    clock_info = {
+        "slot": 165768577,
+        "epoch": 383,
+        "epochStartTimestamp": 1666934458,
+        "leaderScheduleEpoch": 384,
+        "unixTimestamp": 1667007853,
+    }
+    clock = ParsedAccount("sysvar", {"type": "clock", "info": clock_info}, 40)
+    assert clock.to_sysvar() == Clock(
+        slot=165768577,
+        epoch_start_timestamp=1666934458,
+        epoch=383,
+        leader_schedule_epoch=384,
+        unix_timestamp=1667007853,
+    )
+    rent_info = {
+        "lamportsPerByteYear": "3480",
+        "exemptionThreshold": 2.0,
+        "burnPercent": 50,
+    }
+    rent = ParsedAccount("sysvar", {"type": "rent", "info": rent_info}, 17)
+    assert rent.to_sysvar() == Rent.default()
+    schedule_info = {
+        "slotsPerEpoch": 432000,
+        "leaderScheduleSlotOffset": 432000,
+        "warmup": False,
+        "firstNormalEpoch": 0,
+        "firstNormalSlot": 0,
+    }
+    schedule = ParsedAccount(
+        "sysvar", {"type": "epochSchedule", "info": schedule_info}, 33
+    )
+    assert schedule.to_sysvar() == EpochSchedule.custom(432000, 432000, False)
+    for kind in ("fees", "slotHashes", "stakeHistory"):
+        expected = f"clock, rent and epochSchedule sysvars, got {kind}"
+        with raises(ValueError, match=expected):
+            ParsedAccount("sysvar", {"type": kind, "info": {}}, 8).to_sysvar()
+    with raises(ValueError, match="got vote. Only the clock, rent and epochSchedule"):
+        ParsedAccount("vote", {"type": "vote", "info": {}}, 3731).to_sysvar()
@@ -0,0 +1,71 @@
+from pytest import raises
+from solders.pubkey import Pubkey
+from solders.rpc.responses import EpochInfo, RpcPerfSample
+from solders.rpc.timing import (
+    BlockhashExpiry,
+    EpochProgress,
+    epoch_progress,
+    estimate_blockhash_expiry,
+    next_leader_slots,
+)
+
+
+def test_estimate_blockhash_expiry() -> None:
+    samples = [
+        RpcPerfSample(
+            slot=1000, num_transactions=100, num_slots=100, sample_period_secs=60
+        ),
+        RpcPerfSample(
+            slot=900, num_transactions=100, num_slots=140, sample_period_secs=60
+        ),
+    ]
+    expiry = estimate_blockhash_expiry(samples, 1000, 1150)
+    assert expiry == BlockhashExpiry(
+        seconds_per_slot=0.5,
+        blocks_remaining=150,
+        seconds_remaining=75.0,
+        resubmit_interval=2.0,
+    )
+    assert not expiry.is_expired()
+    assert expiry.max_resubmissions() == 37
+
+
+def test_estimate_blockhash_expiry_fallback() -> None:
+    empty = RpcPerfSample(slot=1, num_transactions=0, num_slots=0, sample_period_secs=60)
+    expiry = estimate_blockhash_expiry([empty], 10, 12)
+    assert expiry.seconds_per_slot == 0.4
+    assert expiry.resubmit_interval == expiry.seconds_remaining
+    expired = estimate_blockhash_expiry([], 20, 12)
+    assert expired.is_expired()
+    assert expired.max_resubmissions() == 0
+
+
+def test_epoch_progress() -> None:
+    info = EpochInfo(
+        epoch=5,
+        slot_index=300,
+        slots_in_epoch=1000,
+        absolute_slot=5300,
+        block_height=5000,
+    )
+    assert epoch_progress(info, 0.5) == EpochProgress(
+        epoch=5,
+        slots_remaining=700,
+        seconds_remaining=350.0,
+        percent_complete=30.0,
+        next_epoch_start_slot=6000,
+    )
+    me, other = Pubkey.new_unique(), Pubkey.new_unique()
+    schedule = {me: [0, 1, 2, 3, 800, 801, 400, 401], other: [300, 301]}
+    assert next_leader_slots(info, schedule, me) == [5400, 5401, 5800, 5801]
+    assert next_leader_slots(info, schedule, me, limit=1) == [5400]
+    assert next_leader_slots(info, schedule, Pubkey.new_unique()) == []
+    bad = EpochInfo(
+        epoch=5,
+        slot_index=300,
+        slots_in_epoch=1000,
+        absolute_slot=200,
+        block_height=5000,
+    )
+    with raises(ValueError):
+        next_leader_slots(bad, schedule, me)
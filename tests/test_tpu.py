@@ -0,0 +1,101 @@
+import socket
+from typing import Any
+
+from pytest import raises
+from solders.hash import Hash
+from solders.instruction import Instruction
+from solders.keypair import Keypair
+from solders.message import Message
+from solders.pubkey import Pubkey
+from solders.rpc.responses import (
+    GetClusterNodesResp,
+    GetSlotLeadersResp,
+    RpcContactInfo,
+)
+from solders.rpc.tpu import (
+    MAX_FANOUT_SLOTS,
+    TpuLeaderPlanner,
+    wire_transaction,
+)
+from solders.transaction import Transaction, VersionedTransaction
+
+LEADERS = [Pubkey.new_unique() for _ in range(3)]
+
+
+def planner(**kwargs: Any) -> TpuLeaderPlanner:
+    tpu = TpuLeaderPlanner(**kwargs)
+    nodes = [
+        RpcContactInfo(LEADERS[0], None, "10.0.0.1:8003", None),
+        RpcContactInfo(LEADERS[1], None, "[2001:db8::1]:8003", None),
+        RpcContactInfo(LEADERS[2], None, None, None),
+    ]
+    assert tpu.update_cluster_nodes(GetClusterNodesResp(nodes)) == 2
+    schedule = [leader for leader in LEADERS for _ in range(4)] * 8
+    tpu.update_leaders(tpu.leaders_request(1000), GetSlotLeadersResp(schedule[:100]))
+    return tpu
+
+
+def test_new() -> None:
+    with raises(ValueError, match="fanout_slots"):
+        TpuLeaderPlanner(fanout_slots=0)
+    with raises(ValueError, match="fanout_slots"):
+        TpuLeaderPlanner(fanout_slots=MAX_FANOUT_SLOTS + 1)
+    tpu = TpuLeaderPlanner()
+    assert tpu.fanout_slots == 12 and not tpu.use_quic
+    assert tpu.needs_leaders(0)
+    assert tpu.leader_tpus(0) == []
+    request = tpu.leaders_request(10, id=3)
+    assert (request.start, request.limit, request.id) == (10, MAX_FANOUT_SLOTS, 3)
+    assert tpu.cluster_nodes_request(4).id == 4
+
+
+def test_leader_tpus() -> None:
+    tpu = planner()
+    assert tpu.leader_tpus(1000) == [("10.0.0.1", 8003), ("2001:db8::1", 8003)]
+    assert tpu.unknown_leaders(1000) == [LEADERS[2]]
+    assert tpu.leader_tpus(1004) == [("2001:db8::1", 8003), ("10.0.0.1", 8003)]
+    quic = planner(use_quic=True)
+    assert quic.leader_tpus(1008) == [("10.0.0.1", 8009), ("2001:db8::1", 8009)]
+
+
+def test_update_leaders() -> None:
+    tpu = planner()
+    assert not tpu.needs_leaders(1088)
+    assert tpu.needs_leaders(1089)
+    assert tpu.needs_leaders(999)
+    request = tpu.leaders_request(1050)
+    tpu.update_leaders(request, GetSlotLeadersResp([LEADERS[0]] * 100))
+    assert tpu.needs_leaders(1000)
+    assert tpu.leader_tpus(1140) == [("10.0.0.1", 8003)]
+    with raises(ValueError, match="requested 100 leaders"):
+        tpu.update_leaders(request, GetSlotLeadersResp([LEADERS[0]] * 101))
+
+
+def test_send_transaction() -> None:
+    payer = Keypair()
+    tx = Transaction.new_signed_with_payer([], payer.pubkey(), [payer], Hash.default())
+    with socket.socket(socket.AF_INET, socket.SOCK_DGRAM) as receiver:
+        receiver.bind(("127.0.0.1", 0))
+        receiver.settimeout(5)
+        port = receiver.getsockname()[1]
+        tpu = TpuLeaderPlanner(fanout_slots=4)
+        node = RpcContactInfo(LEADERS[0], None, f"127.0.0.1:{port}", None)
+        tpu.update_cluster_nodes(GetClusterNodesResp([node]))
+        schedule = [LEADERS[0]] * 4 + [LEADERS[1]] * 96
+        tpu.update_leaders(tpu.leaders_request(1000), GetSlotLeadersResp(schedule))
+        assert tpu.send_transaction(tx, 1000, retries=2) == [("127.0.0.1", port)]
+        assert receiver.recv(2048) == bytes(tx)
+        with raises(ValueError, match="no TPU address"):
+            tpu.send_transaction(tx, 1004)
+    with raises(ValueError, match="only sends over UDP"):
+        planner(use_quic=True).send_transaction(tx, 1000)
+
+
+def test_wire_transaction() -> None:
+    payer = Keypair()
+    tx = Transaction.new_signed_with_payer([], payer.pubkey(), [payer], Hash.default())
+    assert wire_transaction(tx) == bytes(tx)
+    big = Instruction(Pubkey.default(), bytes(1200), [])
+    message = Message.new_with_blockhash([big], payer.pubkey(), Hash.default())
+    with raises(ValueError, match="packet holds 1232"):
+        wire_transaction(VersionedTransaction(message, [payer]))
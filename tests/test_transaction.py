@@ -3,12 +3,20 @@ from typing import Tuple, Optional, List, Union
 from base64 import b64decode, b64encode
 from based58 import b58encode
 from pytest import raises
-from solders.transaction import Transaction, SanitizeError, TransactionError
+from solders.transaction import (
+    Legacy,
+    Transaction,
+    TransactionVersion,
+    VersionedTransaction,
+    SanitizeError,
+    TransactionError,
+)
+from solders.address_lookup_table_account import AddressLookupTableAccount
 from solders.pubkey import Pubkey
 from solders.keypair import Keypair
 from solders.instruction import CompiledInstruction, Instruction, AccountMeta
 from solders.hash import Hash
-from solders.message import Message
+from solders.message import Message, MessageV0
 from solders import system_program
 from solders.signature import Signature
 from solders.sysvar import RECENT_BLOCKHASHES
@@ -1405,3 +1413,131 @@ def test_pickle() -> None:
 def test_json() -> None:
     obj = Transaction.default()
     assert Transaction.from_json(obj.to_json()) == obj
+
+
+def test_transaction_version() -> None:
+    legacy_tx = VersionedTransaction(Message([], SENDER.pubkey()), [SENDER])
+    legacy = legacy_tx.version()
+    assert isinstance(legacy, TransactionVersion)
+    assert legacy == TransactionVersion.LEGACY
+    assert legacy == Legacy.Legacy and Legacy.Legacy == legacy
+    assert legacy == "legacy"
+    assert legacy != 0
+    assert legacy.is_legacy and legacy.number is None
+    msg = MessageV0.try_compile(SENDER.pubkey(), [], [], BLOCKHASH)
+    assert VersionedTransaction(msg, [SENDER]).version() == TransactionVersion.V0
+    v0 = TransactionVersion(0)
+    assert v0 == 0 and v0 == TransactionVersion("0") and v0.number == 0
+    assert v0 != "0"
+    assert {legacy: "a", v0: "b"}[TransactionVersion(Legacy.Legacy)] == "a"
+    assert {legacy: "a", v0: "b"}["legacy"] == "a"
+    assert {legacy: "a", v0: "b"}[0] == "b"
+    assert hash(v0) == hash(0)
+    assert hash(legacy) == hash("legacy")
+    for version in (legacy, v0):
+        assert TransactionVersion.from_json(version.to_json()) == version
+        assert pickle.loads(pickle.dumps(version)) == version
+    assert str(legacy) == "legacy" and str(v0) == "0"
+    with raises(ValueError):
+        TransactionVersion("v1")
+    with raises(TypeError):
+        legacy < v0  # noqa: B015
+
+
+def test_versioned_transaction_expiry_metadata() -> None:
+    tx = VersionedTransaction(Message([], SENDER.pubkey()), [SENDER])
+    raw = bytes(tx)
+    assert tx.last_valid_block_height is None and tx.is_expired(10) is None
+    tx.last_valid_block_height = 100
+    tx.created_at = 1_700_000_000
+    assert bytes(tx) == raw
+    assert tx == VersionedTransaction.from_bytes(raw)
+    for restored in (
+        VersionedTransaction.from_json(tx.to_json()),
+        pickle.loads(pickle.dumps(tx)),
+    ):
+        assert restored.last_valid_block_height == 100
+        assert restored.created_at == 1_700_000_000
+    assert VersionedTransaction.from_bytes(raw).created_at is None
+    assert not tx.is_expired(100) and tx.is_expired(101)
+    tx.last_valid_block_height = None
+    assert "lastValidBlockHeight" not in tx.to_json()
+
+
+def test_versioned_transaction_v0_round_trip() -> None:
+    table = AddressLookupTableAccount(Pubkey.new_unique(), [Pubkey.new_unique()])
+    ix = Instruction(
+        Pubkey.new_unique(), b"", [AccountMeta(table.addresses[0], False, True)]
+    )
+    msg = MessageV0.try_compile(SENDER.pubkey(), [ix], [table], BLOCKHASH)
+    assert msg.address_table_lookups
+    tx = VersionedTransaction(msg, [SENDER])
+    tx.last_valid_block_height = 100
+    for restored in (
+        VersionedTransaction.from_json(tx.to_json()),
+        pickle.loads(pickle.dumps(tx)),
+    ):
+        assert restored == tx
+        assert bytes(restored) == bytes(tx)
+        assert restored.last_valid_block_height == 100
+        assert restored.created_at is None
+    plain = VersionedTransaction.from_bytes(bytes(tx))
+    assert pickle.loads(pickle.dumps(plain)).last_valid_block_height is None
+
+
+def test_freeze() -> None:
+    message = Message([], SENDER.pubkey())
+    tx = Transaction.new_unsigned(message)
+    assert not tx.signatures_match_message()
+    with raises(ValueError, match="don't match"):
+        tx.freeze()
+    tx.sign([SENDER], BLOCKHASH)
+    assert tx.signatures_match_message()
+    tx.freeze()
+    assert tx.is_frozen
+    with raises(ValueError, match="frozen"):
+        tx.partial_sign([SENDER], Hash.new_unique())
+    with raises(ValueError, match="frozen"):
+        tx.replace_signatures([(SENDER.pubkey(), Signature.default())])
+    assert tx.signatures_match_message()
+    copy = Transaction.from_bytes(bytes(tx))
+    assert copy == tx and not copy.is_frozen
+    assert Transaction.from_bytes_frozen(bytes(tx)).is_frozen
+    assert not Transaction.from_bytes_frozen(bytes(tx), frozen=False).is_frozen
+    assert pickle.loads(pickle.dumps(tx)).is_frozen
+    assert not pickle.loads(pickle.dumps(copy)).is_frozen
+    assert '"frozen":true' in tx.to_json()
+    assert "frozen" not in copy.to_json()
+    assert Transaction.from_json(tx.to_json()).is_frozen
+    assert not Transaction.from_json(copy.to_json()).is_frozen
+    with raises(ValueError, match="don't match"):
+        unsigned = Transaction.new_unsigned(message)
+        Transaction.from_bytes_frozen(bytes(unsigned))
+    versioned = VersionedTransaction(message, [SENDER])
+    assert versioned.signatures_match_message()
+    assert not VersionedTransaction.populate(
+        message, [Signature.default()]
+    ).signatures_match_message()
+
+
+def test_freeze_versioned() -> None:
+    message = Message([], SENDER.pubkey())
+    unsigned = VersionedTransaction.populate(message, [Signature.default()])
+    with raises(ValueError, match="don't match"):
+        unsigned.freeze()
+    tx = VersionedTransaction(message, [SENDER])
+    tx.last_valid_block_height = 100
+    tx.freeze()
+    assert tx.is_frozen
+    with raises(ValueError, match="frozen"):
+        tx.last_valid_block_height = 200
+    with raises(ValueError, match="frozen"):
+        tx.created_at = 1
+    assert tx.last_valid_block_height == 100
+    assert pickle.loads(pickle.dumps(tx)).is_frozen
+    assert VersionedTransaction.from_json(tx.to_json()).is_frozen
+    assert not VersionedTransaction.from_bytes(bytes(tx)).is_frozen
+    raw = bytes(tx)
+    assert VersionedTransaction.from_bytes_with_metadata(raw, frozen=True).is_frozen
+    with raises(ValueError, match="don't match"):
+        VersionedTransaction.from_bytes_with_metadata(bytes(unsigned), frozen=True)
@@ -0,0 +1,128 @@
+from pytest import raises
+from solders.hash import Hash
+from solders.instruction import AccountMeta, CompiledInstruction, Instruction
+from solders.keypair import Keypair
+from solders.message import (
+    Message,
+    MessageAddressTableLookup,
+    MessageHeader,
+    MessageV0,
+)
+from solders.pubkey import Pubkey
+from solders.system_program import (
+    TransferParams,
+    WithdrawNonceAccountParams,
+    transfer,
+    withdraw_nonce_account,
+)
+from solders.transaction import VersionedTransaction
+from solders.transfers import Transfer, TransferKind, summarize_transfers
+
+TOKEN_PROGRAM_ID = Pubkey.from_string("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+SYSTEM_PROGRAM_ID = Pubkey.from_string("11111111111111111111111111111111")
+
+
+def test_summarize_transfers() -> None:
+    payer = Keypair()
+    wallet = Pubkey.new_unique()
+    source, destination, mint = (Pubkey.new_unique() for _ in range(3))
+    sol = transfer(
+        TransferParams(from_pubkey=payer.pubkey(), to_pubkey=wallet, lamports=5)
+    )
+    token = Instruction(
+        TOKEN_PROGRAM_ID,
+        bytes([3]) + (100).to_bytes(8, "little"),
+        [
+            AccountMeta(source, False, True),
+            AccountMeta(destination, False, True),
+            AccountMeta(payer.pubkey(), True, False),
+        ],
+    )
+    checked = Instruction(
+        TOKEN_PROGRAM_ID,
+        bytes([12]) + (7).to_bytes(8, "little") + bytes([6]),
+        [
+            AccountMeta(source, False, True),
+            AccountMeta(mint, False, False),
+            AccountMeta(destination, False, True),
+            AccountMeta(payer.pubkey(), True, False),
+        ],
+    )
+    sync = Instruction(
+        TOKEN_PROGRAM_ID, bytes([17]), [AccountMeta(source, False, True)]
+    )
+    msg = Message([sol, token, sync, checked], payer.pubkey())
+    tx = VersionedTransaction(msg, [payer])
+    transfers = summarize_transfers(tx, token_mints={destination: mint})
+    authority = payer.pubkey()
+    assert transfers == [
+        Transfer(0, SYSTEM_PROGRAM_ID, authority, wallet, authority, 5, None, None),
+        Transfer(1, TOKEN_PROGRAM_ID, source, destination, authority, 100, mint, None),
+        Transfer(3, TOKEN_PROGRAM_ID, source, destination, authority, 7, mint, 6),
+    ]
+    assert transfers[0].is_native
+    assert not transfers[1].is_native
+
+
+def test_summarize_transfers_needs_loaded_keys() -> None:
+    payer = Pubkey.new_unique()
+    loaded = Pubkey.new_unique()
+    data = (2).to_bytes(4, "little") + (9).to_bytes(8, "little")
+    lookup = MessageAddressTableLookup(Pubkey.new_unique(), bytes([0]), bytes())
+    msg = MessageV0(
+        MessageHeader(1, 0, 1),
+        [payer, SYSTEM_PROGRAM_ID],
+        Hash.default(),
+        [CompiledInstruction(1, data, bytes([0, 2]))],
+        [lookup],
+    )
+    tx = VersionedTransaction.populate(msg, [])
+    with raises(ValueError):
+        summarize_transfers(tx)
+    (only,) = summarize_transfers(tx, [payer, SYSTEM_PROGRAM_ID, loaded])
+    assert only.destination == loaded
+    assert only.amount == 9
+
+
+def test_summarize_transfers_reports_control_changes() -> None:
+    payer = Keypair()
+    owner = payer.pubkey()
+    nonce, account, delegate, new_owner = (Pubkey.new_unique() for _ in range(4))
+    withdraw = withdraw_nonce_account(
+        WithdrawNonceAccountParams(
+            nonce_pubkey=nonce, authorized_pubkey=owner, to_pubkey=owner, lamports=3
+        )
+    )
+    approve = Instruction(
+        TOKEN_PROGRAM_ID,
+        bytes([4]) + (50).to_bytes(8, "little"),
+        [
+            AccountMeta(account, False, True),
+            AccountMeta(delegate, False, False),
+            AccountMeta(owner, True, False),
+        ],
+    )
+    close = Instruction(
+        TOKEN_PROGRAM_ID,
+        bytes([9]),
+        [
+            AccountMeta(account, False, True),
+            AccountMeta(owner, False, True),
+            AccountMeta(owner, True, False),
+        ],
+    )
+    set_authority = Instruction(
+        TOKEN_PROGRAM_ID,
+        bytes([6, 2, 1]) + bytes(new_owner),
+        [AccountMeta(account, False, True), AccountMeta(owner, True, False)],
+    )
+    msg = Message([withdraw, approve, close, set_authority], owner)
+    transfers = summarize_transfers(VersionedTransaction(msg, [payer]))
+    assert transfers[0] == Transfer(
+        0, SYSTEM_PROGRAM_ID, nonce, owner, owner, 3, None, None
+    )
+    assert [(t.kind, t.source, t.destination, t.amount) for t in transfers[1:]] == [
+        (TransferKind.Approve, account, delegate, 50),
+        (TransferKind.CloseAccount, account, owner, 0),
+        (TransferKind.SetAuthority, account, new_owner, 0),
+    ]
@@ -1,9 +1,16 @@
 from pytest import fixture, raises
 from solders.address_lookup_table_account import AddressLookupTableAccount
-from solders.message import MessageHeader, MessageV0, MessageAddressTableLookup
+from solders.message import (
+    CompileError,
+    Message,
+    MessageHeader,
+    MessageV0,
+    MessageAddressTableLookup,
+)
 from solders.pubkey import Pubkey
 from solders.hash import Hash
-from solders.transaction import SanitizeError
+from solders.keypair import Keypair
+from solders.transaction import SanitizeError, VersionedTransaction
 from solders.instruction import CompiledInstruction, Instruction, AccountMeta
 
 
@@ -338,3 +345,81 @@ def test_try_compile(
             )
         ],
     )
+
+
+def test_expand_to_legacy() -> None:
+    keys = [Pubkey.new_unique() for i in range(6)]
+    payer = keys[0]
+    program_id = keys[5]
+    instructions = [
+        Instruction(
+            program_id,
+            accounts=[
+                AccountMeta(keys[1], True, True),
+                AccountMeta(keys[2], False, True),
+                AccountMeta(keys[3], False, True),
+                AccountMeta(keys[4], False, False),
+            ],
+            data=bytes([1]),
+        )
+    ]
+    table = AddressLookupTableAccount(
+        key=Pubkey.new_unique(), addresses=[keys[3], keys[4]]
+    )
+    recent_blockhash = Hash.new_unique()
+    msg = MessageV0.try_compile(payer, instructions, [table], recent_blockhash)
+    assert msg.address_table_lookups
+    expected = Message.new_with_blockhash(instructions, payer, recent_blockhash)
+    legacy = msg.expand_to_legacy([table])
+    assert legacy.header == expected.header
+    assert set(legacy.account_keys) == set(expected.account_keys)
+    legacy_ix = legacy.instructions[0]
+    assert legacy.account_keys[legacy_ix.program_id_index] == program_id
+    assert [legacy.account_keys[i] for i in legacy_ix.accounts] == keys[1:5]
+    assert legacy_ix.data == bytes([1])
+
+
+def test_expand_to_legacy_missing_table() -> None:
+    payer, program_id, looked_up = (Pubkey.new_unique() for _ in range(3))
+    ix = Instruction(program_id, bytes([]), [AccountMeta(looked_up, False, True)])
+    table = AddressLookupTableAccount(key=Pubkey.new_unique(), addresses=[looked_up])
+    msg = MessageV0.try_compile(payer, [ix], [table], Hash.new_unique())
+    with raises(CompileError):
+        msg.expand_to_legacy([])
+
+
+def test_expand_to_legacy_transaction() -> None:
+    payer = Keypair()
+    program_id, looked_up = Pubkey.new_unique(), Pubkey.new_unique()
+    ix = Instruction(program_id, bytes([]), [AccountMeta(looked_up, False, True)])
+    table = AddressLookupTableAccount(key=Pubkey.new_unique(), addresses=[looked_up])
+    msg = MessageV0.try_compile(payer.pubkey(), [ix], [table], Hash.new_unique())
+    tx = VersionedTransaction(msg, [payer])
+    legacy = tx.expand_to_legacy_transaction([table])
+    assert legacy.signatures == tx.signatures
+    assert legacy.message == msg.expand_to_legacy([table])
+
+
+def test_replace_lookup_tables() -> None:
+    old, other, new = Pubkey.new_unique(), Pubkey.new_unique(), Pubkey.new_unique()
+    lookups = [
+        MessageAddressTableLookup(old, bytes([0, 1]), bytes([2])),
+        MessageAddressTableLookup(other, bytes([]), bytes([5])),
+    ]
+    header = MessageHeader(1, 0, 0)
+    msg = MessageV0(header, [Pubkey.new_unique()], Hash.default(), [], lookups)
+    replaced = msg.replace_lookup_table(old, new)
+    assert [lookup.account_key for lookup in replaced.address_table_lookups] == [
+        new,
+        other,
+    ]
+    assert replaced.address_table_lookups[0].writable_indexes == bytes([0, 1])
+    assert replaced.account_keys == msg.account_keys
+    with raises(ValueError):
+        msg.replace_lookup_table(new, old)
+    reordered = msg.with_address_table_lookups(
+        [MessageAddressTableLookup(new, bytes([3, 4]), bytes([6, 7]))]
+    )
+    assert len(reordered.address_table_lookups) == 1
+    with raises(ValueError):
+        msg.with_address_table_lookups([])
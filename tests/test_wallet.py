@@ -0,0 +1,167 @@
+from base64 import b64decode, b64encode
+
+from pytest import raises
+from solders.hash import Hash
+from solders.instruction import AccountMeta, Instruction
+from solders.keypair import Keypair
+from solders.message import Message
+from solders.null_signer import NullSigner
+from solders.pubkey import Pubkey
+from solders.signature import Signature
+from solders.transaction import Transaction, VersionedTransaction
+from solders.presets import get_associated_token_address
+from solders.wallet import (
+    AddressValidation,
+    decode_signed_transaction,
+    decode_signed_transactions,
+    encode_transaction,
+    encode_transactions,
+    validate_address,
+    validate_addresses,
+    verify_signed_message,
+)
+
+PAYER = Keypair()
+COSIGNER = Keypair()
+
+
+def _message(blockhash: Hash = Hash.default()) -> Message:
+    ix = Instruction(
+        Pubkey.new_unique(), b"\x01", [AccountMeta(COSIGNER.pubkey(), True, False)]
+    )
+    return Message.new_with_blockhash([ix], PAYER.pubkey(), blockhash)
+
+
+def test_encode_transaction() -> None:
+    message = _message()
+    legacy = Transaction.new_unsigned(message)
+    versioned = VersionedTransaction.populate(message, legacy.signatures)
+    assert encode_transaction(legacy) == encode_transaction(versioned)
+    assert b64decode(encode_transaction(versioned)) == bytes(versioned)
+    assert encode_transactions([legacy, versioned]) == [
+        encode_transaction(legacy)
+    ] * 2
+
+
+def test_decode_signed_transaction() -> None:
+    message = _message()
+    signed = VersionedTransaction(message, [PAYER, COSIGNER])
+    decoded = decode_signed_transaction(encode_transaction(signed), message)
+    assert decoded == signed
+
+
+def test_decode_allows_missing_cosigner() -> None:
+    message = _message()
+    partial = VersionedTransaction(message, [PAYER, NullSigner(COSIGNER.pubkey())])
+    assert decode_signed_transaction(encode_transaction(partial)) == partial
+
+
+def test_decode_rejects_changed_message() -> None:
+    signed = VersionedTransaction(_message(Hash.new_unique()), [PAYER, COSIGNER])
+    with raises(ValueError, match="changed the transaction message"):
+        decode_signed_transaction(encode_transaction(signed), _message())
+
+
+def test_decode_rejects_bad_signature() -> None:
+    message = _message()
+    forged = VersionedTransaction.populate(
+        message, [Keypair().sign_message(bytes(message))] * 2
+    )
+    with raises(ValueError, match="invalid signature"):
+        decode_signed_transaction(encode_transaction(forged))
+
+
+def test_decode_rejects_wrong_signature_count() -> None:
+    message = _message()
+    short = VersionedTransaction.populate(
+        message, [PAYER.sign_message(bytes(message))]
+    )
+    with raises(ValueError, match="1 signatures but its message requires 2"):
+        decode_signed_transaction(encode_transaction(short))
+
+
+def test_decode_rejects_unsigned() -> None:
+    message = _message()
+    unsigned = VersionedTransaction.populate(message, [Signature.default()] * 2)
+    with raises(ValueError, match="fee payer didn't sign"):
+        decode_signed_transaction(encode_transaction(unsigned))
+    cosigned = VersionedTransaction.populate(
+        message, [Signature.default(), COSIGNER.sign_message(bytes(message))]
+    )
+    with raises(ValueError, match="fee payer didn't sign"):
+        decode_signed_transaction(encode_transaction(cosigned))
+
+
+def test_decode_rejects_unsanitary_message() -> None:
+    signed = VersionedTransaction(_message(), [PAYER, COSIGNER])
+    raw = bytearray(bytes(signed))
+    # Mark both signers read-only, leaving no writable fee payer.
+    raw[1 + 64 * 2 + 1] = 2
+    with raises(ValueError):
+        decode_signed_transaction(b64encode(bytes(raw)).decode())
+
+
+def test_decode_signed_transactions() -> None:
+    messages = [_message(), _message(Hash.new_unique())]
+    signed = [VersionedTransaction(m, [PAYER, COSIGNER]) for m in messages]
+    payloads = encode_transactions(signed)
+    assert decode_signed_transactions(payloads, messages) == signed
+    with raises(ValueError, match="2 signed transactions for 1 messages"):
+        decode_signed_transactions(payloads, messages[:1])
+
+
+def test_verify_signed_message() -> None:
+    message = b"sign in to example.com"
+    signature = PAYER.sign_message(message)
+    assert (
+        verify_signed_message(PAYER.pubkey(), message, bytes(signature), message)
+        == signature
+    )
+    with raises(ValueError, match="different message"):
+        verify_signed_message(PAYER.pubkey(), message, bytes(signature), b"other")
+    with raises(ValueError, match="invalid signature"):
+        verify_signed_message(COSIGNER.pubkey(), message, bytes(signature))
+    with raises(ValueError, match="64 byte signature"):
+        verify_signed_message(PAYER.pubkey(), message, b"\x00")
+
+
+def test_validate_wallet_address() -> None:
+    wallet = PAYER.pubkey()
+    result = validate_address(str(wallet))
+    assert result == AddressValidation(str(wallet), 32, wallet, True, None, None)
+    assert result.is_base58 and result.is_valid and result.is_wallet
+
+
+def test_validate_invalid_addresses() -> None:
+    not_base58 = validate_address("0OIl")
+    assert not not_base58.is_base58
+    assert not_base58.decoded_length is None
+    assert not not_base58.is_wallet
+    short = validate_address("1111")
+    assert short.is_base58 and not short.is_valid
+    assert short.decoded_length == 4
+    padded = validate_address(f" {PAYER.pubkey()}")
+    assert not padded.is_valid
+    empty = validate_address("")
+    assert not empty.is_base58 and not empty.is_valid and not empty.is_wallet
+
+
+def test_validate_known_and_derived_addresses() -> None:
+    token = validate_address("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+    assert token.known_name == "Token Program"
+    assert not token.is_wallet
+    mint = Pubkey.new_unique()
+    ata = get_associated_token_address(PAYER.pubkey(), mint)
+    plain = validate_address(str(ata))
+    assert plain.is_valid and not plain.is_on_curve and not plain.is_wallet
+    assert plain.associated_token_account_of is None
+    candidates = [(COSIGNER.pubkey(), mint), (PAYER.pubkey(), mint)]
+    found = validate_address(str(ata), candidates)
+    assert found.associated_token_account_of == (PAYER.pubkey(), mint)
+    token_2022 = Pubkey.from_string("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb")
+    ata_2022 = get_associated_token_address(PAYER.pubkey(), mint, token_2022)
+    results = validate_addresses([str(ata_2022), str(PAYER.pubkey())], candidates)
+    assert [r.associated_token_account_of for r in results] == [
+        (PAYER.pubkey(), mint),
+        None,
+    ]
@@ -6,7 +6,7 @@ use pyo3::{
     pyclass::CompareOp,
     types::PyBytes,
 };
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use solana_sdk::{
     commitment_config::ParseCommitmentLevelError as ParseCommitmentLevelErrorOriginal,
     hash::ParseHashError as ParseHashErrorOriginal,
@@ -167,7 +167,7 @@ impl From<serde_cbor::Error> for PyErrWrapper {
     }
 }
 
-fn richcmp_type_error(op: &str) -> PyErr {
+pub fn richcmp_type_error(op: &str) -> PyErr {
     let msg = format!("{op} not supported.");
     PyTypeError::new_err(msg)
 }
@@ -243,6 +243,21 @@ pub trait RichcmpFull: PartialEq + PartialOrd {
     }
 }
 
+/// String conversion for fieldless enums, matching their JSON representation.
+pub trait EnumString: Serialize + DeserializeOwned {
+    fn to_canonical_string(&self) -> String {
+        match serde_json::to_value(self).unwrap() {
+            serde_json::Value::String(s) => s,
+            other => other.to_string(),
+        }
+    }
+
+    fn from_canonical_string(s: &str) -> PyResult<Self> {
+        serde_json::from_value(serde_json::Value::String(s.to_owned()))
+            .map_err(|e| to_py_value_err(&e))
+    }
+}
+
 #[macro_export]
 macro_rules! impl_display {
     ($ident:ident) => {
@@ -320,15 +335,33 @@ macro_rules! pybytes_general_for_pybytes_cbor {
     };
 }
 
+/// Serialize a value with bincode directly into a new ``bytes`` buffer,
+/// without an intermediate ``Vec``.
+pub fn bincode_pybytes<'a, T: Serialize + ?Sized>(py: Python<'a>, value: &T) -> &'a PyBytes {
+    let len = bincode::serialized_size(value).unwrap() as usize;
+    PyBytes::new_with(py, len, |mut buf| {
+        bincode::serialize_into(&mut buf, value).map_err(to_py_err)
+    })
+    .unwrap()
+}
+
 pub trait PyBytesBincode: Serialize {
     fn pybytes_bincode<'a>(&self, py: Python<'a>) -> &'a PyBytes {
-        PyBytes::new(py, &bincode::serialize(self).unwrap())
+        bincode_pybytes(py, self)
     }
 }
 
+/// Serialize a value with CBOR into a new ``bytes`` buffer.
+///
+/// CBOR has no cheap way to compute the encoded size up front, so the value is
+/// encoded once into a ``Vec`` and copied, rather than encoded twice.
+pub fn cbor_pybytes<'a, T: Serialize>(py: Python<'a>, value: &T) -> &'a PyBytes {
+    PyBytes::new(py, &serde_cbor::to_vec(value).unwrap())
+}
+
 pub trait PyBytesCbor: Serialize + std::marker::Sized {
     fn pybytes_cbor<'a>(&self, py: Python<'a>) -> &'a PyBytes {
-        PyBytes::new(py, &serde_cbor::to_vec(self).unwrap())
+        cbor_pybytes(py, self)
     }
 }
 